@@ -165,6 +165,7 @@ pub async fn build_toc_segment(storage: Arc<Storage>, events: Vec<Event>) -> Toc
         overlap_time_ms: 0,
         overlap_tokens: 0,
         max_tool_result_chars: 1000,
+        ..Default::default()
     };
 
     let segments = segment_events(events, config);
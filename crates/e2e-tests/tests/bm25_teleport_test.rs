@@ -3,12 +3,21 @@
 //! E2E-02: BM25 ingest -> index -> search with relevance ranking
 //! Verifies BM25 keyword search returns results ranked by relevance score.
 
+use std::sync::Arc;
+
 use pretty_assertions::assert_eq;
+use tonic::Request;
 
-use e2e_tests::{build_toc_segment, create_test_events, ingest_events, TestHarness};
+use e2e_tests::{
+    build_toc_segment, create_proto_event, create_test_events, ingest_events, TestHarness,
+};
+use memory_indexing::{Bm25IndexUpdater, IndexingPipeline, PipelineConfig};
 use memory_search::{
     DocType, SearchIndex, SearchIndexConfig, SearchIndexer, SearchOptions, TeleportSearcher,
 };
+use memory_service::pb::memory_service_server::MemoryService;
+use memory_service::pb::IngestEventRequest;
+use memory_service::MemoryServiceImpl;
 use memory_types::{TocBullet, TocLevel, TocNode};
 
 /// E2E-02: BM25 search pipeline with relevance ranking.
@@ -361,3 +370,53 @@ async fn test_bm25_search_with_agent_attribution() {
         "Agent field should be None for node without contributing_agents"
     );
 }
+
+/// Events ingested over gRPC are searchable once the indexing pipeline runs
+/// with raw event indexing enabled, and not otherwise.
+#[tokio::test]
+async fn test_bm25_raw_events_indexed_from_ingest() {
+    let harness = TestHarness::new();
+    let service = MemoryServiceImpl::new(harness.storage.clone());
+
+    let ts = 1_706_540_400_000i64;
+    let event_id = ulid::Ulid::from_parts(ts as u64, 1).to_string();
+    service
+        .ingest_event(Request::new(IngestEventRequest {
+            event: Some(create_proto_event(
+                &event_id,
+                "session-raw",
+                ts,
+                2, // UserMessage
+                "Migration stalled on the orders table during the nightly deploy",
+            )),
+        }))
+        .await
+        .expect("ingest should succeed");
+
+    let bm25_index =
+        SearchIndex::open_or_create(SearchIndexConfig::new(&harness.bm25_index_path)).unwrap();
+    let run_pipeline = |index_events: bool| {
+        let indexer = Arc::new(SearchIndexer::new(&bm25_index).unwrap());
+        let updater = Bm25IndexUpdater::new(indexer, harness.storage.clone())
+            .with_event_indexing(index_events);
+        let mut pipeline =
+            IndexingPipeline::new(harness.storage.clone(), PipelineConfig::default());
+        pipeline.add_updater(Box::new(updater));
+        pipeline.process_until_caught_up(10).unwrap();
+        pipeline.commit().unwrap();
+    };
+    let searcher = TeleportSearcher::new(&bm25_index).unwrap();
+
+    // Disabled: the outbox entry is consumed without indexing the text
+    run_pipeline(false);
+    searcher.reload().unwrap();
+    assert!(searcher.search_events("orders", 10).unwrap().is_empty());
+
+    // Enabled: a fresh pipeline starts from the outbox again
+    run_pipeline(true);
+    searcher.reload().unwrap();
+    let hits = searcher.search_events("orders", 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].doc_id, event_id);
+    assert_eq!(hits[0].doc_type, DocType::Event);
+}
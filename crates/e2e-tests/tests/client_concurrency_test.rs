@@ -0,0 +1,95 @@
+//! Concurrent client E2E tests for agent-memory.
+//!
+//! Verifies that cloned `MemoryClient`s share one multiplexed channel and can
+//! issue many RPCs concurrently without connection exhaustion.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+
+use e2e_tests::TestHarness;
+use memory_client::MemoryClient;
+use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer, TeleportSearcher};
+use memory_service::pb::memory_service_server::MemoryServiceServer;
+use memory_service::MemoryServiceImpl;
+use memory_types::config::StalenessConfig;
+use memory_types::{TocLevel, TocNode};
+
+/// Number of concurrent teleport searches issued through client clones.
+const CONCURRENT_CALLS: usize = 50;
+
+#[tokio::test]
+async fn test_cloned_clients_handle_concurrent_teleport_searches() {
+    let harness = TestHarness::new();
+
+    // Index a handful of TOC nodes so searches have something to rank
+    let bm25_index =
+        SearchIndex::open_or_create(SearchIndexConfig::new(&harness.bm25_index_path)).unwrap();
+    let indexer = SearchIndexer::new(&bm25_index).unwrap();
+    for (i, topic) in ["rust ownership", "python asyncio", "sql indexing"]
+        .iter()
+        .enumerate()
+    {
+        let now = Utc::now();
+        let mut node = TocNode::new(
+            format!("toc:day:2026-01-0{}", i + 1),
+            TocLevel::Day,
+            format!("Notes on {}", topic),
+            now,
+            now,
+        );
+        node.keywords = topic.split(' ').map(str::to_string).collect();
+        indexer.index_toc_node(&node).unwrap();
+    }
+    indexer.commit().unwrap();
+    let searcher = Arc::new(TeleportSearcher::new(&bm25_index).unwrap());
+
+    // Serve on an ephemeral port
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+    let service = MemoryServiceImpl::with_search(
+        harness.storage.clone(),
+        searcher,
+        StalenessConfig::default(),
+    );
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(async move {
+        Server::builder()
+            .add_service(MemoryServiceServer::new(service))
+            .serve_with_incoming_shutdown(incoming, async {
+                shutdown_rx.await.ok();
+            })
+            .await
+    });
+
+    let client = MemoryClient::connect(&format!("http://{}", addr))
+        .await
+        .unwrap();
+
+    let queries = ["rust", "python", "sql", "ownership", "missing"];
+    let handles: Vec<_> = (0..CONCURRENT_CALLS)
+        .map(|i| {
+            let mut client = client.clone();
+            let query = queries[i % queries.len()];
+            tokio::spawn(async move { client.teleport_search(query, 0, 5).await })
+        })
+        .collect();
+
+    let mut completed = 0;
+    for handle in handles {
+        let response = handle
+            .await
+            .expect("task panicked")
+            .expect("concurrent teleport_search failed");
+        assert_eq!(response.total_docs, 3);
+        completed += 1;
+    }
+    assert_eq!(completed, CONCURRENT_CALLS);
+
+    let _ = shutdown_tx.send(());
+    let _ = server.await;
+}
@@ -17,7 +17,7 @@ use e2e_tests::{
 };
 use memory_service::novelty::EmbedderTrait;
 use memory_service::pb::memory_service_server::MemoryService;
-use memory_service::pb::IngestEventRequest;
+use memory_service::pb::{GetNoveltyMetricsRequest, IngestEventRequest};
 use memory_service::{MemoryServiceImpl, NoveltyChecker};
 use memory_types::config::DedupConfig;
 use memory_types::dedup::InFlightBuffer;
@@ -399,3 +399,78 @@ async fn test_dedup_response_fields() {
         "Structural: deduplicated should be false"
     );
 }
+
+/// Novelty metrics report suppression counts for a mix of events.
+///
+/// Two topics, each seen once as novel and then repeated, plus one event
+/// too short to check: 6 seen, 3 suppressed, 2 novel, 1 skipped.
+#[tokio::test]
+async fn test_novelty_metrics_count_suppressed_events() {
+    let harness = TestHarness::new();
+    let dim = 384;
+    let mut topic_a = vec![0.0; dim];
+    topic_a[0] = 1.0;
+    let mut topic_b = vec![0.0; dim];
+    topic_b[1] = 1.0;
+
+    let buffer = Arc::new(RwLock::new(InFlightBuffer::new(64, dim)));
+    let embedder: Arc<dyn EmbedderTrait> = Arc::new(SequentialEmbedder::new(vec![
+        topic_a.clone(),
+        topic_a.clone(),
+        topic_b.clone(),
+        topic_b,
+        topic_a,
+    ]));
+    let checker = Arc::new(NoveltyChecker::with_in_flight_buffer(
+        Some(embedder),
+        buffer,
+        DedupConfig {
+            enabled: true,
+            threshold: 0.85,
+            min_text_length: 10,
+            buffer_capacity: 64,
+            ..Default::default()
+        },
+    ));
+
+    let mut service = MemoryServiceImpl::new(harness.storage.clone());
+    service.set_novelty_checker(checker);
+
+    let ts = 1_706_540_400_000u64;
+    let texts = [
+        "How do I configure the RocksDB block cache?",
+        "What's the right RocksDB block cache size?",
+        "Write a migration for the users table",
+        "Add a users table migration please",
+        "ok",
+        "Remind me how the RocksDB cache is configured",
+    ];
+    for (i, text) in texts.iter().enumerate() {
+        let ts_i = ts + i as u64 * 100;
+        service
+            .ingest_event(Request::new(IngestEventRequest {
+                event: Some(create_proto_event(
+                    &make_ulid(ts_i, 5000 + i as u128),
+                    "session-metrics-1",
+                    ts_i as i64,
+                    2, // UserMessage
+                    text,
+                )),
+            }))
+            .await
+            .unwrap();
+    }
+
+    let metrics = service
+        .get_novelty_metrics(Request::new(GetNoveltyMetricsRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(metrics.enabled);
+    assert_eq!(metrics.total_seen, 6);
+    assert_eq!(metrics.suppressed, 3);
+    assert_eq!(metrics.stored_novel, 2);
+    assert_eq!(metrics.skipped, 1);
+    assert!((metrics.suppression_rate - 0.5).abs() < f64::EPSILON);
+    assert_eq!(metrics.window_size, 64);
+}
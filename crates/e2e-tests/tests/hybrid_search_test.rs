@@ -10,15 +10,16 @@ use tonic::Request;
 
 use e2e_tests::{build_toc_segment, create_test_events, ingest_events, TestHarness};
 use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer, TeleportSearcher};
-use memory_service::hybrid::HybridSearchHandler;
+use memory_service::hybrid::{HybridCacheConfig, HybridSearchHandler};
 use memory_service::pb::{HybridMode, HybridSearchRequest};
 use memory_service::VectorTeleportHandler;
 use memory_vector::{HnswConfig, HnswIndex, VectorMetadata};
 
 /// Minimal VectorTeleportHandler whose index is empty so `is_available()` returns false.
+///
+/// Uses the hash embedder so these tests run without a model download.
 fn empty_vector_handler(harness: &TestHarness) -> Arc<VectorTeleportHandler> {
-    let embedder =
-        memory_embeddings::CandleEmbedder::load_default().expect("Failed to load embedding model");
+    let embedder = memory_embeddings::HashEmbedder::new(384);
     let hnsw_config = HnswConfig::new(384, &harness.vector_index_path).with_capacity(10);
     let hnsw = HnswIndex::open_or_create(hnsw_config).expect("HNSW create");
     let meta_path = harness.vector_index_path.join("metadata");
@@ -56,7 +57,6 @@ fn build_bm25_searcher(
 
 /// E2E: BM25-only fallback when vector index is empty/unavailable.
 #[tokio::test]
-#[ignore = "requires model download (~80MB on first run)"]
 async fn test_hybrid_bm25_fallback_when_vector_unavailable() {
     let harness = TestHarness::new();
 
@@ -93,6 +93,7 @@ async fn test_hybrid_bm25_fallback_when_vector_unavailable() {
         time_filter: None,
         target: 0,
         agent_filter: None,
+        diversity: None,
     });
 
     let response = handler.hybrid_search(request).await.unwrap();
@@ -119,7 +120,6 @@ async fn test_hybrid_bm25_fallback_when_vector_unavailable() {
 
 /// E2E: bm25_available reports correctly based on searcher presence.
 #[tokio::test]
-#[ignore = "requires model download (~80MB on first run)"]
 async fn test_hybrid_bm25_available_reports_true() {
     let harness = TestHarness::new();
 
@@ -149,7 +149,6 @@ async fn test_hybrid_bm25_available_reports_true() {
 
 /// E2E: BM25-only mode returns real BM25 results.
 #[tokio::test]
-#[ignore = "requires model download (~80MB on first run)"]
 async fn test_hybrid_bm25_only_mode() {
     let harness = TestHarness::new();
 
@@ -175,6 +174,7 @@ async fn test_hybrid_bm25_only_mode() {
         time_filter: None,
         target: 0,
         agent_filter: None,
+        diversity: None,
     });
 
     let response = handler.hybrid_search(request).await.unwrap();
@@ -186,3 +186,123 @@ async fn test_hybrid_bm25_only_mode() {
     );
     assert!(inner.bm25_available, "bm25_available should be true");
 }
+
+/// E2E: repeated identical queries are served from the result cache.
+#[tokio::test]
+async fn test_hybrid_repeated_query_served_from_cache() {
+    let harness = TestHarness::new();
+
+    let events_rust = create_test_events(
+        "session-rust",
+        6,
+        "Rust ownership and borrow checker ensures memory safety without garbage collection",
+    );
+    ingest_events(&harness.storage, &events_rust);
+    let node_rust = build_toc_segment(harness.storage.clone(), events_rust).await;
+
+    let searcher = build_bm25_searcher(&harness, &[&node_rust]);
+    let vector_handler = empty_vector_handler(&harness);
+
+    let handler = HybridSearchHandler::new(vector_handler, Some(searcher))
+        .with_cache(HybridCacheConfig::default());
+    assert!(handler.cache_enabled());
+
+    let make_request = |query: &str| {
+        Request::new(HybridSearchRequest {
+            query: query.to_string(),
+            top_k: 10,
+            mode: HybridMode::Bm25Only as i32,
+            bm25_weight: 0.5,
+            vector_weight: 0.5,
+            time_filter: None,
+            target: 0,
+            agent_filter: None,
+            diversity: None,
+        })
+    };
+
+    let first = handler
+        .hybrid_search(make_request("rust ownership borrow"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(!first.cache_hit, "First query should miss the cache");
+
+    let second = handler
+        .hybrid_search(make_request("rust ownership borrow"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        second.cache_hit,
+        "Repeated query should be served from cache"
+    );
+    assert_eq!(
+        first.matches.iter().map(|m| &m.doc_id).collect::<Vec<_>>(),
+        second.matches.iter().map(|m| &m.doc_id).collect::<Vec<_>>()
+    );
+
+    let other = handler
+        .hybrid_search(make_request("garbage collection"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(!other.cache_hit, "Different query should miss the cache");
+}
+
+/// E2E: editing an indexed document in place invalidates cached results
+/// even though the document counts do not change.
+#[tokio::test]
+async fn test_hybrid_cache_invalidated_by_in_place_edit() {
+    let harness = TestHarness::new();
+
+    let events = create_test_events(
+        "session-rust",
+        6,
+        "Rust ownership and borrow checker ensures memory safety without garbage collection",
+    );
+    ingest_events(&harness.storage, &events);
+    let node = build_toc_segment(harness.storage.clone(), events).await;
+
+    let searcher = build_bm25_searcher(&harness, &[&node]);
+    let handler = HybridSearchHandler::new(empty_vector_handler(&harness), Some(searcher.clone()))
+        .with_cache(HybridCacheConfig::default());
+
+    let make_request = || {
+        Request::new(HybridSearchRequest {
+            query: "rust ownership".to_string(),
+            top_k: 10,
+            mode: HybridMode::Bm25Only as i32,
+            bm25_weight: 0.5,
+            vector_weight: 0.5,
+            time_filter: None,
+            target: 0,
+            agent_filter: None,
+            diversity: None,
+        })
+    };
+
+    handler.hybrid_search(make_request()).await.unwrap();
+    let docs_before = searcher.num_docs();
+
+    // Re-index the same node with new text: same ID, same counts
+    let mut edited = node.clone();
+    edited.title = format!("{} (edited)", edited.title);
+    let bm25_index =
+        SearchIndex::open_or_create(SearchIndexConfig::new(&harness.bm25_index_path)).unwrap();
+    let indexer = SearchIndexer::new(&bm25_index).unwrap();
+    indexer.index_toc_node(&edited).unwrap();
+    indexer.commit().unwrap();
+    searcher.reload().unwrap();
+    assert_eq!(searcher.num_docs(), docs_before);
+
+    let after = handler
+        .hybrid_search(make_request())
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        !after.cache_hit,
+        "Edited document should invalidate the cached result"
+    );
+}
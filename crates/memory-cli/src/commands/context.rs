@@ -68,6 +68,7 @@ mod tests {
             explanation: None,
             has_results: true,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
 
         let results_json = build_results_json(&response);
@@ -109,6 +110,7 @@ mod tests {
             explanation: None,
             has_results: false,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
 
         let results_json = build_results_json(&response);
@@ -121,6 +121,7 @@ mod tests {
             explanation: None,
             has_results: true,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
         let json = build_results_json(&response);
         let arr = json.as_array().unwrap();
@@ -144,6 +145,7 @@ mod tests {
             }),
             has_results: true,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
         let meta = build_meta(&response);
         assert_eq!(meta.retrieval_ms, 42);
@@ -160,6 +162,7 @@ mod tests {
             explanation: None,
             has_results: true,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
         let meta = build_meta(&response);
         assert_eq!(meta.retrieval_ms, 0);
@@ -172,6 +175,7 @@ mod tests {
             explanation: None,
             has_results: false,
             layers_attempted: vec![],
+            request_id: String::new(),
         };
         let json = build_results_json(&response);
         assert_eq!(json, json!([]));
@@ -2,31 +2,55 @@
 //!
 //! Per HOOK-02: Hook handlers call daemon's IngestEvent RPC.
 
+use std::future::Future;
+use std::io::BufRead;
+
 use tonic::transport::Channel;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use memory_service::pb::{
-    memory_service_client::MemoryServiceClient, BrowseTocRequest, Event as ProtoEvent,
-    EventRole as ProtoEventRole, EventType as ProtoEventType, ExpandGripRequest,
-    GetDedupStatusRequest, GetDedupStatusResponse, GetEventsRequest, GetNodeRequest,
-    GetRankingStatusRequest, GetRankingStatusResponse, GetRelatedTopicsRequest, GetTocRootRequest,
-    GetTopTopicsRequest, GetTopicGraphStatusRequest, GetTopicsByQueryRequest,
-    GetVectorIndexStatusRequest, Grip as ProtoGrip, HybridSearchRequest, HybridSearchResponse,
-    IngestEventRequest, RouteQueryRequest, RouteQueryResponse, TeleportSearchRequest,
-    TeleportSearchResponse, TocNode as ProtoTocNode, Topic as ProtoTopic, VectorIndexStatus,
-    VectorTeleportRequest, VectorTeleportResponse,
+    memory_service_client::MemoryServiceClient, BrowseTocRequest, ClassifyQueryIntentBatchRequest,
+    ClassifyQueryIntentResponse, Event as ProtoEvent, EventRole as ProtoEventRole,
+    EventType as ProtoEventType, ExpandGripRequest, FindSimilarNodesRequest,
+    FindSimilarNodesResponse, GetDedupStatusRequest, GetDedupStatusResponse, GetEventsRequest,
+    GetGripRequest, GetMetricsRequest, GetNodeHistoryRequest, GetNodeRequest, GetNodesRequest,
+    GetRankingStatusRequest, GetRankingStatusResponse, GetRelatedTopicsRequest, GetSubtreeRequest,
+    GetTocRootRequest, GetTopTopicsRequest, GetTopicGraphStatusRequest, GetTopicNodesRequest,
+    GetTopicsByQueryRequest, GetTopicsByQueryResponse, GetVectorIndexStatusRequest,
+    Grip as ProtoGrip, HybridSearchRequest, HybridSearchResponse, IngestEventRequest,
+    PruneVectorIndexRequest, PruneVectorIndexResponse, RouteQueryRequest, RouteQueryResponse,
+    SearchEventsRequest, SearchEventsResponse, SubtreeNode, TeleportSearchRequest,
+    TeleportSearchResponse, TimeRange, TocNode as ProtoTocNode, TocNodeChange, Topic as ProtoTopic,
+    VectorIndexStatus, VectorTeleportRequest, VectorTeleportResponse,
 };
 use memory_types::{Event, EventRole, EventType};
 
 use crate::error::ClientError;
+use crate::hook_mapping::{map_hook_event, parse_cch_line};
+use crate::retry::{is_transport_error, RetryConfig};
+use crate::teleport::{DocType, TeleportSearchResult};
 
 /// Default endpoint for the memory daemon.
 /// Uses IPv4 loopback to match the daemon's default `0.0.0.0` bind address.
 pub const DEFAULT_ENDPOINT: &str = "http://127.0.0.1:50051";
 
 /// Client for communicating with the memory daemon.
+///
+/// Cloning is cheap: clones share the same underlying `tonic` channel, which
+/// multiplexes concurrent calls over a single HTTP/2 connection. To fan out
+/// many concurrent RPCs, clone the client into each task rather than
+/// connecting again. The client is `Send + Sync`.
+///
+/// A clone that reconnects after a transport error (see
+/// [`connect_with_retry`](Self::connect_with_retry)) replaces only its own
+/// channel; other clones reconnect independently on their next failure.
+#[derive(Clone)]
 pub struct MemoryClient {
     inner: MemoryServiceClient<Channel>,
+    endpoint: String,
+    /// Reconnect policy; `None` propagates transport errors immediately.
+    retry: Option<RetryConfig>,
+    reconnects: u64,
 }
 
 impl MemoryClient {
@@ -44,7 +68,51 @@ impl MemoryClient {
         let inner = MemoryServiceClient::connect(endpoint.to_string())
             .await
             .map_err(ClientError::Connection)?;
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            endpoint: endpoint.to_string(),
+            retry: None,
+            reconnects: 0,
+        })
+    }
+
+    /// Connect to the memory daemon, reconnecting transparently on transport errors.
+    ///
+    /// The initial connection and every RPC that fails with a transport error
+    /// are retried with bounded exponential backoff per `retry`. Application
+    /// errors (e.g. `NotFound`) are returned immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Connection` if the daemon is unreachable after
+    /// all attempts.
+    pub async fn connect_with_retry(
+        endpoint: &str,
+        retry: RetryConfig,
+    ) -> Result<Self, ClientError> {
+        info!("Connecting to memory daemon at {} (with retry)", endpoint);
+        let mut attempt = 0;
+        let inner = loop {
+            match MemoryServiceClient::connect(endpoint.to_string()).await {
+                Ok(inner) => break inner,
+                Err(e) if attempt < retry.max_retries => {
+                    attempt += 1;
+                    let delay = retry.backoff_for(attempt);
+                    warn!(
+                        "Connect to {} failed ({}), retrying in {:?} (attempt {}/{})",
+                        endpoint, e, delay, attempt, retry.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(ClientError::Connection(e)),
+            }
+        };
+        Ok(Self {
+            inner,
+            endpoint: endpoint.to_string(),
+            retry: Some(retry),
+            reconnects: 0,
+        })
     }
 
     /// Connect to the default endpoint.
@@ -52,6 +120,62 @@ impl MemoryClient {
         Self::connect(DEFAULT_ENDPOINT).await
     }
 
+    /// Number of times this client has re-established its connection.
+    ///
+    /// Always 0 for clients created without a retry policy.
+    pub fn reconnect_count(&self) -> u64 {
+        self.reconnects
+    }
+
+    /// Issue an RPC, reconnecting and retrying on transport errors.
+    ///
+    /// `rpc` is invoked with a fresh client handle and request per attempt.
+    async fn call<Req, Resp, F, Fut>(
+        &mut self,
+        request: Req,
+        mut rpc: F,
+    ) -> Result<tonic::Response<Resp>, ClientError>
+    where
+        Req: Clone,
+        F: FnMut(MemoryServiceClient<Channel>, tonic::Request<Req>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Resp>, tonic::Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let status = match rpc(self.inner.clone(), tonic::Request::new(request.clone())).await {
+                Ok(response) => return Ok(response),
+                Err(status) => status,
+            };
+
+            let Some(retry) = self.retry.as_ref() else {
+                return Err(status.into());
+            };
+            if !is_transport_error(&status) || attempt >= retry.max_retries {
+                return Err(status.into());
+            }
+
+            attempt += 1;
+            let delay = retry.backoff_for(attempt);
+            warn!(
+                "RPC transport error ({}), reconnecting in {:?} (attempt {}/{})",
+                status.message(),
+                delay,
+                attempt,
+                retry.max_retries
+            );
+            tokio::time::sleep(delay).await;
+
+            match MemoryServiceClient::connect(self.endpoint.clone()).await {
+                Ok(inner) => {
+                    self.inner = inner;
+                    self.reconnects += 1;
+                    info!("Reconnected to memory daemon at {}", self.endpoint);
+                }
+                Err(e) => debug!("Reconnect to {} failed: {}", self.endpoint, e),
+            }
+        }
+    }
+
     /// Ingest an event into the memory system.
     ///
     /// Per HOOK-02: Hook handlers call daemon's IngestEvent RPC.
@@ -63,11 +187,13 @@ impl MemoryClient {
         debug!("Ingesting event: {}", event.event_id);
 
         let proto_event = event_to_proto(event);
-        let request = tonic::Request::new(IngestEventRequest {
+        let request = IngestEventRequest {
             event: Some(proto_event),
-        });
+        };
 
-        let response = self.inner.ingest_event(request).await?;
+        let response = self
+            .call(request, |mut c, r| async move { c.ingest_event(r).await })
+            .await?;
         let resp = response.into_inner();
 
         if resp.created {
@@ -95,6 +221,56 @@ impl MemoryClient {
         Ok(created_count)
     }
 
+    /// Replay a stream of newline-delimited CCH JSON events.
+    ///
+    /// Each line is parsed and mapped the same way `memory-ingest` handles a
+    /// single hook invocation, then ingested. Malformed lines and failed RPCs
+    /// are recorded in the summary and do not stop the replay; blank lines
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Io` only if reading from `reader` fails.
+    pub async fn ingest_hook_stream<R: BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<IngestSummary, ClientError> {
+        let mut summary = IngestSummary::default();
+
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line_number = idx + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let result = match parse_cch_line(&line) {
+                Ok(hook) => self.ingest(map_hook_event(hook)).await,
+                Err(e) => Err(ClientError::Serialization(e.to_string())),
+            };
+
+            match result {
+                Ok((_, true)) => summary.created += 1,
+                Ok((_, false)) => summary.skipped += 1,
+                Err(e) => {
+                    warn!("Replay line {} failed: {}", line_number, e);
+                    summary.errors.push(ReplayError {
+                        line: line_number,
+                        message: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        info!(
+            "Hook stream replay complete: created={}, skipped={}, errors={}",
+            summary.created,
+            summary.skipped,
+            summary.errors.len()
+        );
+        Ok(summary)
+    }
+
     // ===== Query Methods =====
 
     /// Get root TOC nodes (year level).
@@ -102,8 +278,10 @@ impl MemoryClient {
     /// Per QRY-01: Returns top-level time nodes sorted by time descending.
     pub async fn get_toc_root(&mut self) -> Result<Vec<ProtoTocNode>, ClientError> {
         debug!("GetTocRoot request");
-        let request = tonic::Request::new(GetTocRootRequest {});
-        let response = self.inner.get_toc_root(request).await?;
+        let request = GetTocRootRequest {};
+        let response = self
+            .call(request, |mut c, r| async move { c.get_toc_root(r).await })
+            .await?;
         Ok(response.into_inner().nodes)
     }
 
@@ -112,13 +290,81 @@ impl MemoryClient {
     /// Per QRY-02: Returns node with children and summary.
     pub async fn get_node(&mut self, node_id: &str) -> Result<Option<ProtoTocNode>, ClientError> {
         debug!("GetNode request: {}", node_id);
-        let request = tonic::Request::new(GetNodeRequest {
+        let request = GetNodeRequest {
             node_id: node_id.to_string(),
-        });
-        let response = self.inner.get_node(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_node(r).await })
+            .await?;
         Ok(response.into_inner().node)
     }
 
+    /// Get several TOC nodes in one call.
+    ///
+    /// Nodes come back in request order; IDs with no node are listed in
+    /// `not_found`.
+    pub async fn get_nodes(&mut self, node_ids: &[String]) -> Result<GetNodesResult, ClientError> {
+        debug!("GetNodes request: {} ids", node_ids.len());
+        let request = GetNodesRequest {
+            node_ids: node_ids.to_vec(),
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_nodes(r).await })
+            .await?
+            .into_inner();
+        Ok(GetNodesResult {
+            nodes: response.nodes,
+            not_found: response.not_found,
+        })
+    }
+
+    /// Get a TOC node and its descendants down to `max_depth` levels.
+    ///
+    /// Nodes are flattened breadth-first with parent pointers; the server
+    /// caps the number returned and sets `truncated` when it does.
+    pub async fn get_subtree(
+        &mut self,
+        root_id: &str,
+        max_depth: u32,
+    ) -> Result<GetSubtreeResult, ClientError> {
+        debug!("GetSubtree request: root={}, depth={}", root_id, max_depth);
+        let request = GetSubtreeRequest {
+            root_id: root_id.to_string(),
+            max_depth,
+            max_nodes: 0,
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_subtree(r).await })
+            .await?
+            .into_inner();
+        Ok(GetSubtreeResult {
+            nodes: response.nodes,
+            truncated: response.truncated,
+        })
+    }
+
+    /// Get the version history of a TOC node, oldest version first.
+    ///
+    /// Each entry lists what that version changed relative to the one
+    /// before it.
+    pub async fn get_node_history(
+        &mut self,
+        node_id: &str,
+    ) -> Result<Vec<TocNodeChange>, ClientError> {
+        debug!("GetNodeHistory request: {}", node_id);
+        let request = GetNodeHistoryRequest {
+            node_id: node_id.to_string(),
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_node_history(r).await },
+            )
+            .await?
+            .into_inner();
+        Ok(response.changes)
+    }
+
     /// Browse children of a TOC node with pagination.
     ///
     /// Per QRY-03: Supports pagination of children.
@@ -129,12 +375,14 @@ impl MemoryClient {
         continuation_token: Option<String>,
     ) -> Result<BrowseTocResult, ClientError> {
         debug!("BrowseToc request: parent={}, limit={}", parent_id, limit);
-        let request = tonic::Request::new(BrowseTocRequest {
+        let request = BrowseTocRequest {
             parent_id: parent_id.to_string(),
             limit: limit as i32,
             continuation_token,
-        });
-        let response = self.inner.browse_toc(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.browse_toc(r).await })
+            .await?;
         let resp = response.into_inner();
         Ok(BrowseTocResult {
             children: resp.children,
@@ -151,17 +399,34 @@ impl MemoryClient {
         from_timestamp_ms: i64,
         to_timestamp_ms: i64,
         limit: u32,
+    ) -> Result<GetEventsResult, ClientError> {
+        self.get_events_of_types(from_timestamp_ms, to_timestamp_ms, limit, Vec::new())
+            .await
+    }
+
+    /// Get events in a time range, keeping only the given event types.
+    ///
+    /// `event_types` holds proto `EventType` values; empty means all types.
+    pub async fn get_events_of_types(
+        &mut self,
+        from_timestamp_ms: i64,
+        to_timestamp_ms: i64,
+        limit: u32,
+        event_types: Vec<i32>,
     ) -> Result<GetEventsResult, ClientError> {
         debug!(
-            "GetEvents request: from={} to={} limit={}",
-            from_timestamp_ms, to_timestamp_ms, limit
+            "GetEvents request: from={} to={} limit={} types={:?}",
+            from_timestamp_ms, to_timestamp_ms, limit, event_types
         );
-        let request = tonic::Request::new(GetEventsRequest {
+        let request = GetEventsRequest {
             from_timestamp_ms,
             to_timestamp_ms,
             limit: limit as i32,
-        });
-        let response = self.inner.get_events(request).await?;
+            event_types,
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_events(r).await })
+            .await?;
         let resp = response.into_inner();
         Ok(GetEventsResult {
             events: resp.events,
@@ -179,12 +444,14 @@ impl MemoryClient {
         events_after: Option<u32>,
     ) -> Result<ExpandGripResult, ClientError> {
         debug!("ExpandGrip request: {}", grip_id);
-        let request = tonic::Request::new(ExpandGripRequest {
+        let request = ExpandGripRequest {
             grip_id: grip_id.to_string(),
             events_before: events_before.map(|v| v as i32),
             events_after: events_after.map(|v| v as i32),
-        });
-        let response = self.inner.expand_grip(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.expand_grip(r).await })
+            .await?;
         let resp = response.into_inner();
         Ok(ExpandGripResult {
             grip: resp.grip,
@@ -194,6 +461,20 @@ impl MemoryClient {
         })
     }
 
+    /// Get a grip's stored record without expanding its context.
+    ///
+    /// Returns None if no grip has this ID.
+    pub async fn get_grip(&mut self, grip_id: &str) -> Result<Option<ProtoGrip>, ClientError> {
+        debug!("GetGrip request: {}", grip_id);
+        let request = GetGripRequest {
+            grip_id: grip_id.to_string(),
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_grip(r).await })
+            .await?;
+        Ok(response.into_inner().grip)
+    }
+
     // ===== Teleport Search Methods =====
 
     /// Search for TOC nodes or grips using BM25 keyword search.
@@ -205,14 +486,97 @@ impl MemoryClient {
         doc_type: i32,
         limit: i32,
     ) -> Result<TeleportSearchResponse, ClientError> {
-        debug!("TeleportSearch request: query={}", query);
-        let request = tonic::Request::new(TeleportSearchRequest {
+        self.teleport_search_in_range(query, doc_type, limit, None, None)
+            .await
+    }
+
+    /// BM25 keyword search restricted to documents in `[start_ms, end_ms)`.
+    ///
+    /// Either bound may be `None` to leave that side open.
+    pub async fn teleport_search_in_range(
+        &mut self,
+        query: &str,
+        doc_type: i32,
+        limit: i32,
+        start_ms: Option<i64>,
+        end_ms: Option<i64>,
+    ) -> Result<TeleportSearchResponse, ClientError> {
+        self.teleport_search_with(TeleportSearchRequest {
             query: query.to_string(),
             doc_type,
             limit,
             agent_filter: None,
-        });
-        let response = self.inner.teleport_search(request).await?;
+            start_ms,
+            end_ms,
+            min_score: 0.0,
+            normalize_scores: false,
+        })
+        .await
+    }
+
+    /// BM25 keyword search with every request option available
+    /// (time range, score cutoff, normalization).
+    pub async fn teleport_search_with(
+        &mut self,
+        request: TeleportSearchRequest,
+    ) -> Result<TeleportSearchResponse, ClientError> {
+        debug!("TeleportSearch request: query={}", request.query);
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.teleport_search(r).await },
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// BM25 keyword search returning decoded results.
+    ///
+    /// Same RPC as [`teleport_search`](Self::teleport_search), but with
+    /// `doc_type` mapped to [`DocType`] and keywords split into a list.
+    /// Results keep the server's ranking order.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search query (keywords)
+    /// * `doc_type` - Restrict to one document type (`None` searches all)
+    /// * `limit` - Maximum results to return
+    pub async fn teleport_search_typed(
+        &mut self,
+        query: &str,
+        doc_type: Option<DocType>,
+        limit: i32,
+    ) -> Result<Vec<TeleportSearchResult>, ClientError> {
+        let doc_type = doc_type.map_or(0, DocType::to_proto);
+        let response = self.teleport_search(query, doc_type, limit).await?;
+        Ok(response
+            .results
+            .into_iter()
+            .map(TeleportSearchResult::from)
+            .collect())
+    }
+
+    /// Full-text search over raw event text.
+    ///
+    /// Returns no results unless the daemon runs with `index_raw_events`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search query (keywords)
+    /// * `limit` - Maximum results to return
+    pub async fn search_events(
+        &mut self,
+        query: &str,
+        limit: i32,
+    ) -> Result<SearchEventsResponse, ClientError> {
+        debug!("SearchEvents request: query={}", query);
+        let request = SearchEventsRequest {
+            query: query.to_string(),
+            limit,
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.search_events(r).await })
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -236,15 +600,46 @@ impl MemoryClient {
         target: i32,
     ) -> Result<VectorTeleportResponse, ClientError> {
         debug!("VectorTeleport request: query={}", query);
-        let request = tonic::Request::new(VectorTeleportRequest {
+        let request = VectorTeleportRequest {
             query: query.to_string(),
             top_k,
             min_score,
             time_filter: None,
             target,
             agent_filter: None,
-        });
-        let response = self.inner.vector_teleport(request).await?;
+            ef_search: None,
+            diversity: None,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.vector_teleport(r).await },
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Find TOC nodes similar to `node_id` ("more like this").
+    ///
+    /// The node itself is never among the matches.
+    pub async fn find_similar_nodes(
+        &mut self,
+        node_id: &str,
+        top_k: i32,
+        min_score: f32,
+    ) -> Result<FindSimilarNodesResponse, ClientError> {
+        debug!("FindSimilarNodes request: node_id={}", node_id);
+        let request = FindSimilarNodesRequest {
+            node_id: node_id.to_string(),
+            top_k,
+            min_score,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.find_similar_nodes(r).await },
+            )
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -268,19 +663,41 @@ impl MemoryClient {
         bm25_weight: f32,
         vector_weight: f32,
         target: i32,
+    ) -> Result<HybridSearchResponse, ClientError> {
+        self.hybrid_search_in_range(query, top_k, mode, bm25_weight, vector_weight, target, None)
+            .await
+    }
+
+    /// Hybrid search restricted to documents within `time_filter`.
+    ///
+    /// Same as [`hybrid_search`](Self::hybrid_search); the time filter
+    /// applies to both the BM25 and vector results.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn hybrid_search_in_range(
+        &mut self,
+        query: &str,
+        top_k: i32,
+        mode: i32,
+        bm25_weight: f32,
+        vector_weight: f32,
+        target: i32,
+        time_filter: Option<TimeRange>,
     ) -> Result<HybridSearchResponse, ClientError> {
         debug!("HybridSearch request: query={}, mode={}", query, mode);
-        let request = tonic::Request::new(HybridSearchRequest {
+        let request = HybridSearchRequest {
             query: query.to_string(),
             top_k,
             mode,
             bm25_weight,
             vector_weight,
-            time_filter: None,
+            time_filter,
             target,
             agent_filter: None,
-        });
-        let response = self.inner.hybrid_search(request).await?;
+            diversity: None,
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.hybrid_search(r).await })
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -301,7 +718,7 @@ impl MemoryClient {
         agent_filter: Option<String>,
     ) -> Result<RouteQueryResponse, ClientError> {
         debug!("RouteQuery request: query={}, limit={}", query, limit);
-        let request = tonic::Request::new(RouteQueryRequest {
+        let request = RouteQueryRequest {
             query: query.to_string(),
             intent_override: None,
             stop_conditions: None,
@@ -309,18 +726,50 @@ impl MemoryClient {
             limit,
             agent_filter,
             all_projects: false,
-        });
-        let response = self.inner.route_query(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.route_query(r).await })
+            .await?;
         Ok(response.into_inner())
     }
 
+    /// Classify the intent of many queries in one call.
+    ///
+    /// Uses the daemon's `ClassifyQueryIntentBatch` RPC, which applies the
+    /// same classifier as `ClassifyQueryIntent`. Results are in the order of
+    /// `queries`; `timeout_ms` applies to each query separately.
+    pub async fn classify_batch(
+        &mut self,
+        queries: Vec<String>,
+        timeout_ms: Option<u64>,
+    ) -> Result<Vec<ClassifyQueryIntentResponse>, ClientError> {
+        debug!(
+            "ClassifyQueryIntentBatch request: {} queries",
+            queries.len()
+        );
+        let request = ClassifyQueryIntentBatchRequest {
+            queries,
+            timeout_ms,
+        };
+        let response = self
+            .call(request, |mut c, r| async move {
+                c.classify_query_intent_batch(r).await
+            })
+            .await?;
+        Ok(response.into_inner().results)
+    }
+
     /// Get vector index status and statistics.
     ///
     /// Per VEC-03: Observable index health and stats.
     pub async fn get_vector_index_status(&mut self) -> Result<VectorIndexStatus, ClientError> {
         debug!("GetVectorIndexStatus request");
-        let request = tonic::Request::new(GetVectorIndexStatusRequest {});
-        let response = self.inner.get_vector_index_status(request).await?;
+        let request = GetVectorIndexStatusRequest {};
+        let response = self
+            .call(request, |mut c, r| async move {
+                c.get_vector_index_status(r).await
+            })
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -329,16 +778,64 @@ impl MemoryClient {
     /// Get dedup gate status and metrics.
     pub async fn get_dedup_status(&mut self) -> Result<GetDedupStatusResponse, ClientError> {
         debug!("GetDedupStatus request");
-        let request = tonic::Request::new(GetDedupStatusRequest {});
-        let response = self.inner.get_dedup_status(request).await?;
+        let request = GetDedupStatusRequest {};
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_dedup_status(r).await },
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Prune old vector metadata per lifecycle policy.
+    ///
+    /// `level` limits pruning to one level, `age_days_override` replaces the
+    /// configured retention, and `dry_run` only reports what would be pruned.
+    pub async fn prune_vector_index(
+        &mut self,
+        level: Option<&str>,
+        age_days_override: Option<u32>,
+        dry_run: bool,
+    ) -> Result<PruneVectorIndexResponse, ClientError> {
+        debug!(
+            "PruneVectorIndex request: level={:?}, dry_run={}",
+            level, dry_run
+        );
+        let request = PruneVectorIndexRequest {
+            level: level.unwrap_or_default().to_string(),
+            age_days_override: age_days_override.unwrap_or(0),
+            dry_run,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.prune_vector_index(r).await },
+            )
+            .await?;
         Ok(response.into_inner())
     }
 
+    /// Get service metrics in Prometheus text format.
+    pub async fn get_metrics(&mut self) -> Result<String, ClientError> {
+        debug!("GetMetrics request");
+        let request = GetMetricsRequest {};
+        let response = self
+            .call(request, |mut c, r| async move { c.get_metrics(r).await })
+            .await?;
+        Ok(response.into_inner().text)
+    }
+
     /// Get ranking status and metrics (salience, usage, novelty, lifecycle).
     pub async fn get_ranking_status(&mut self) -> Result<GetRankingStatusResponse, ClientError> {
         debug!("GetRankingStatus request");
-        let request = tonic::Request::new(GetRankingStatusRequest {});
-        let response = self.inner.get_ranking_status(request).await?;
+        let request = GetRankingStatusRequest {};
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_ranking_status(r).await },
+            )
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -349,8 +846,12 @@ impl MemoryClient {
     /// Per TOPIC-08: Topic graph discovery.
     pub async fn get_topic_graph_status(&mut self) -> Result<TopicGraphStatus, ClientError> {
         debug!("GetTopicGraphStatus request");
-        let request = tonic::Request::new(GetTopicGraphStatusRequest {});
-        let response = self.inner.get_topic_graph_status(request).await?;
+        let request = GetTopicGraphStatusRequest {};
+        let response = self
+            .call(request, |mut c, r| async move {
+                c.get_topic_graph_status(r).await
+            })
+            .await?;
         let resp = response.into_inner();
         Ok(TopicGraphStatus {
             topic_count: resp.topic_count,
@@ -373,13 +874,35 @@ impl MemoryClient {
         query: &str,
         limit: u32,
     ) -> Result<Vec<ProtoTopic>, ClientError> {
+        Ok(self
+            .get_topics_by_query_weighted(query, limit, None)
+            .await?
+            .topics)
+    }
+
+    /// Get topics matching a query with their score breakdown.
+    ///
+    /// `relevance_weight` (0.0-1.0) trades query relevance against topic
+    /// importance; `None` uses the server default.
+    pub async fn get_topics_by_query_weighted(
+        &mut self,
+        query: &str,
+        limit: u32,
+        relevance_weight: Option<f32>,
+    ) -> Result<GetTopicsByQueryResponse, ClientError> {
         debug!("GetTopicsByQuery request: query={}", query);
-        let request = tonic::Request::new(GetTopicsByQueryRequest {
+        let request = GetTopicsByQueryRequest {
             query: query.to_string(),
             limit,
-        });
-        let response = self.inner.get_topics_by_query(request).await?;
-        Ok(response.into_inner().topics)
+            relevance_weight,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_topics_by_query(r).await },
+            )
+            .await?;
+        Ok(response.into_inner())
     }
 
     /// Get topics related to a specific topic.
@@ -389,19 +912,27 @@ impl MemoryClient {
     /// * `topic_id` - Topic to find related topics for
     /// * `rel_type` - Optional relationship type filter ("co-occurrence", "semantic", "hierarchical")
     /// * `limit` - Maximum results to return
+    /// * `min_strength` - Drop relationships weaker than this (0.0 keeps all)
     pub async fn get_related_topics(
         &mut self,
         topic_id: &str,
         rel_type: Option<&str>,
         limit: u32,
+        min_strength: f32,
     ) -> Result<RelatedTopicsResult, ClientError> {
         debug!("GetRelatedTopics request: topic_id={}", topic_id);
-        let request = tonic::Request::new(GetRelatedTopicsRequest {
+        let request = GetRelatedTopicsRequest {
             topic_id: topic_id.to_string(),
             relationship_type: rel_type.unwrap_or("").to_string(),
             limit,
-        });
-        let response = self.inner.get_related_topics(request).await?;
+            min_strength,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_related_topics(r).await },
+            )
+            .await?;
         let resp = response.into_inner();
         Ok(RelatedTopicsResult {
             related_topics: resp.related_topics,
@@ -409,6 +940,41 @@ impl MemoryClient {
         })
     }
 
+    /// Get the TOC nodes that contributed to a topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic_id` - Topic to navigate from
+    /// * `limit` - Maximum nodes per page (server caps at 100)
+    /// * `continuation_token` - Token from a previous page
+    pub async fn get_topic_nodes(
+        &mut self,
+        topic_id: &str,
+        limit: u32,
+        continuation_token: Option<String>,
+    ) -> Result<TopicNodesResult, ClientError> {
+        debug!("GetTopicNodes request: topic_id={}", topic_id);
+        let request = GetTopicNodesRequest {
+            topic_id: topic_id.to_string(),
+            limit,
+            continuation_token,
+        };
+        let response = self
+            .call(
+                request,
+                |mut c, r| async move { c.get_topic_nodes(r).await },
+            )
+            .await?;
+        let resp = response.into_inner();
+        Ok(TopicNodesResult {
+            nodes: resp.nodes,
+            missing_node_ids: resp.missing_node_ids,
+            total_nodes: resp.total_nodes,
+            continuation_token: resp.continuation_token,
+            has_more: resp.has_more,
+        })
+    }
+
     /// Get top topics by importance score.
     ///
     /// # Arguments
@@ -421,12 +987,14 @@ impl MemoryClient {
         days: u32,
     ) -> Result<Vec<ProtoTopic>, ClientError> {
         debug!("GetTopTopics request: limit={}, days={}", limit, days);
-        let request = tonic::Request::new(GetTopTopicsRequest {
+        let request = GetTopTopicsRequest {
             limit,
             days,
             agent_filter: None,
-        });
-        let response = self.inner.get_top_topics(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_top_topics(r).await })
+            .await?;
         Ok(response.into_inner().topics)
     }
 
@@ -447,12 +1015,14 @@ impl MemoryClient {
             "GetTopTopics request: limit={}, days={}, agent={}",
             limit, days, agent_id
         );
-        let request = tonic::Request::new(GetTopTopicsRequest {
+        let request = GetTopTopicsRequest {
             limit,
             days,
             agent_filter: Some(agent_id.to_string()),
-        });
-        let response = self.inner.get_top_topics(request).await?;
+        };
+        let response = self
+            .call(request, |mut c, r| async move { c.get_top_topics(r).await })
+            .await?;
         Ok(response.into_inner().topics)
     }
 }
@@ -473,6 +1043,36 @@ pub struct RelatedTopicsResult {
     pub relationships: Vec<memory_service::pb::TopicRelationship>,
 }
 
+/// Result of get_topic_nodes operation.
+#[derive(Debug)]
+pub struct TopicNodesResult {
+    pub nodes: Vec<ProtoTocNode>,
+    pub missing_node_ids: Vec<String>,
+    pub total_nodes: u32,
+    pub continuation_token: Option<String>,
+    pub has_more: bool,
+}
+
+/// Result of ingest_hook_stream operation.
+#[derive(Debug, Default)]
+pub struct IngestSummary {
+    /// Events newly stored by the daemon
+    pub created: usize,
+    /// Events the daemon already had (idempotent re-ingest)
+    pub skipped: usize,
+    /// Lines that could not be parsed or ingested
+    pub errors: Vec<ReplayError>,
+}
+
+/// A replayed line that failed to parse or ingest.
+#[derive(Debug)]
+pub struct ReplayError {
+    /// 1-based line number in the input stream
+    pub line: usize,
+    /// Parse or RPC error description
+    pub message: String,
+}
+
 /// Result of browse_toc operation.
 #[derive(Debug)]
 pub struct BrowseTocResult {
@@ -481,6 +1081,20 @@ pub struct BrowseTocResult {
     pub has_more: bool,
 }
 
+/// Result of get_nodes operation.
+#[derive(Debug)]
+pub struct GetNodesResult {
+    pub nodes: Vec<ProtoTocNode>,
+    pub not_found: Vec<String>,
+}
+
+/// Result of get_subtree operation.
+#[derive(Debug)]
+pub struct GetSubtreeResult {
+    pub nodes: Vec<SubtreeNode>,
+    pub truncated: bool,
+}
+
 /// Result of get_events operation.
 #[derive(Debug)]
 pub struct GetEventsResult {
@@ -535,6 +1149,12 @@ mod tests {
     use chrono::Utc;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_client_is_send_sync_clone() {
+        fn assert_traits<T: Send + Sync + Clone + 'static>() {}
+        assert_traits::<MemoryClient>();
+    }
+
     #[test]
     fn test_event_to_proto_user_message() {
         let event = Event::new(
@@ -20,4 +20,8 @@ pub enum ClientError {
     /// Invalid endpoint URL
     #[error("Invalid endpoint: {0}")]
     InvalidEndpoint(String),
+
+    /// Reading input failed
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
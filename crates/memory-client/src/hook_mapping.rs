@@ -2,9 +2,13 @@
 //!
 //! Per HOOK-03: Event types map 1:1 from hook events
 //! (SessionStart, UserPromptSubmit, PostToolUse, Stop, etc.)
+//!
+//! Also parses raw CCH (code_agent_context_hooks) JSON lines into hook events,
+//! shared by the `memory-ingest` hook handler and stream replay.
 
 use chrono::{DateTime, Utc};
 use memory_types::{Event, EventRole, EventType};
+use serde::Deserialize;
 
 /// Hook event types from code_agent_context_hooks.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -87,6 +91,118 @@ impl HookEvent {
         self.agent = Some(agent.into());
         self
     }
+
+    /// Record the working directory (`cwd` metadata key).
+    pub fn with_cwd(self, cwd: impl Into<String>) -> Self {
+        self.with_extra("cwd", cwd)
+    }
+
+    /// Record the git branch (`git_branch` metadata key).
+    pub fn with_git_branch(self, branch: impl Into<String>) -> Self {
+        self.with_extra("git_branch", branch)
+    }
+
+    /// Add a single metadata entry, keeping any existing entries.
+    pub fn with_extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(Default::default)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// CCH event format from code_agent_context_hooks.
+#[derive(Debug, Deserialize)]
+pub struct CchEvent {
+    /// Event type name (e.g., "SessionStart", "UserPromptSubmit")
+    pub hook_event_name: String,
+    /// Session identifier
+    pub session_id: String,
+    /// Message content (for prompts/responses)
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Tool name (for tool events)
+    #[serde(default)]
+    pub tool_name: Option<String>,
+    /// Tool input JSON (for tool events)
+    #[serde(default)]
+    pub tool_input: Option<serde_json::Value>,
+    /// Event timestamp
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Current working directory
+    #[serde(default)]
+    pub cwd: Option<String>,
+    /// Agent identifier (e.g., "opencode", "claude")
+    #[serde(default)]
+    pub agent: Option<String>,
+    /// Any other top-level fields (string values are forwarded as metadata)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Map CCH event name to HookEventType.
+pub fn map_cch_event_type(name: &str) -> HookEventType {
+    match name {
+        "SessionStart" => HookEventType::SessionStart,
+        "UserPromptSubmit" => HookEventType::UserPromptSubmit,
+        "AssistantResponse" => HookEventType::AssistantResponse,
+        "PreToolUse" => HookEventType::ToolUse,
+        "PostToolUse" => HookEventType::ToolResult,
+        "Stop" | "SessionEnd" => HookEventType::Stop,
+        "SubagentStart" => HookEventType::SubagentStart,
+        "SubagentStop" => HookEventType::SubagentStop,
+        // Default to user prompt for unknown types
+        _ => HookEventType::UserPromptSubmit,
+    }
+}
+
+/// Convert CchEvent to HookEvent.
+pub fn map_cch_to_hook(cch: &CchEvent) -> HookEvent {
+    let event_type = map_cch_event_type(&cch.hook_event_name);
+
+    // Build content from message or tool_input
+    let content = if let Some(msg) = &cch.message {
+        msg.clone()
+    } else if let Some(input) = &cch.tool_input {
+        serde_json::to_string(input).unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let mut hook = HookEvent::new(&cch.session_id, event_type, content);
+
+    // Add optional fields
+    if let Some(ts) = cch.timestamp {
+        hook = hook.with_timestamp(ts);
+    }
+    if let Some(tool) = &cch.tool_name {
+        hook = hook.with_tool_name(tool);
+    }
+    if let Some(cwd) = &cch.cwd {
+        hook = hook.with_cwd(cwd);
+    }
+    if let Some(agent) = &cch.agent {
+        hook = hook.with_agent(agent.clone());
+    }
+    // Forward unknown string fields (e.g. git_branch, ticket_id) as metadata
+    for (key, value) in &cch.extra {
+        if let serde_json::Value::String(value) = value {
+            hook = hook.with_extra(key, value);
+        }
+    }
+
+    hook
+}
+
+/// Parse a single CCH JSON line into a hook event.
+///
+/// # Errors
+///
+/// Returns the JSON error if the line is not a valid CCH event.
+pub fn parse_cch_line(line: &str) -> Result<HookEvent, serde_json::Error> {
+    let cch: CchEvent = serde_json::from_str(line)?;
+    Ok(map_cch_to_hook(&cch))
 }
 
 /// Map a hook event to a memory event.
@@ -231,4 +347,74 @@ mod tests {
         let event = map_hook_event(hook);
         assert!(event.agent.is_none());
     }
+
+    #[test]
+    fn test_metadata_builders_survive_mapping() {
+        let hook = HookEvent::new("session-1", HookEventType::UserPromptSubmit, "Test")
+            .with_cwd("/home/user/project")
+            .with_git_branch("feature/agent-metadata")
+            .with_extra("ticket_id", "MEM-42")
+            .with_tool_name("Read");
+        let event = map_hook_event(hook);
+
+        assert_eq!(
+            event.metadata.get("cwd"),
+            Some(&"/home/user/project".to_string())
+        );
+        assert_eq!(
+            event.metadata.get("git_branch"),
+            Some(&"feature/agent-metadata".to_string())
+        );
+        assert_eq!(event.metadata.get("ticket_id"), Some(&"MEM-42".to_string()));
+        assert_eq!(event.metadata.get("tool_name"), Some(&"Read".to_string()));
+    }
+
+    #[test]
+    fn test_with_extra_keeps_existing_metadata() {
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("key".to_string(), "value".to_string());
+
+        let hook = HookEvent::new("session-1", HookEventType::UserPromptSubmit, "Test")
+            .with_metadata(metadata)
+            .with_extra("other", "x");
+        let event = map_hook_event(hook);
+
+        assert_eq!(event.metadata.get("key"), Some(&"value".to_string()));
+        assert_eq!(event.metadata.get("other"), Some(&"x".to_string()));
+    }
+
+    #[test]
+    fn test_parse_cch_line_forwards_unknown_string_fields() {
+        let line = r#"{"hook_event_name":"UserPromptSubmit","session_id":"s1","message":"hi","cwd":"/repo","git_branch":"main","ticket_id":"MEM-7","retries":3,"nested":{"a":"b"}}"#;
+        let event = map_hook_event(parse_cch_line(line).unwrap());
+
+        assert_eq!(event.metadata.get("cwd"), Some(&"/repo".to_string()));
+        assert_eq!(event.metadata.get("git_branch"), Some(&"main".to_string()));
+        assert_eq!(event.metadata.get("ticket_id"), Some(&"MEM-7".to_string()));
+        // Non-string extras are dropped
+        assert!(!event.metadata.contains_key("retries"));
+        assert!(!event.metadata.contains_key("nested"));
+        // Known fields are not duplicated into metadata
+        assert!(!event.metadata.contains_key("message"));
+        assert!(!event.metadata.contains_key("session_id"));
+    }
+
+    #[test]
+    fn test_parse_cch_line() {
+        let line = r#"{"hook_event_name":"PostToolUse","session_id":"s1","tool_name":"Read","tool_input":{"path":"/a.rs"},"agent":"claude"}"#;
+        let hook = parse_cch_line(line).unwrap();
+
+        assert_eq!(hook.session_id, "s1");
+        assert_eq!(hook.event_type, HookEventType::ToolResult);
+        assert_eq!(hook.tool_name, Some("Read".to_string()));
+        assert!(hook.content.contains("/a.rs"));
+        assert_eq!(hook.agent, Some("claude".to_string()));
+    }
+
+    #[test]
+    fn test_parse_cch_line_malformed() {
+        assert!(parse_cch_line("{not json").is_err());
+        // Missing required session_id
+        assert!(parse_cch_line(r#"{"hook_event_name":"Stop"}"#).is_err());
+    }
 }
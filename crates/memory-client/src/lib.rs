@@ -34,18 +34,28 @@
 pub mod client;
 pub mod error;
 pub mod hook_mapping;
+pub mod retry;
+pub mod teleport;
 
 pub use client::{
-    BrowseTocResult, ExpandGripResult, GetEventsResult, MemoryClient, DEFAULT_ENDPOINT,
+    BrowseTocResult, ExpandGripResult, GetEventsResult, GetNodesResult, GetSubtreeResult,
+    IngestSummary, MemoryClient, ReplayError, DEFAULT_ENDPOINT,
 };
 
 // Re-export vector search response types for convenience
 pub use error::ClientError;
-pub use hook_mapping::{map_hook_event, HookEvent, HookEventType};
+pub use hook_mapping::{
+    map_cch_event_type, map_cch_to_hook, map_hook_event, parse_cch_line, CchEvent, HookEvent,
+    HookEventType,
+};
 pub use memory_service::pb::{
-    Event as ProtoEvent, ExplainabilityPayload, HybridSearchResponse, RetrievalResult,
-    RouteQueryResponse, VectorIndexStatus, VectorMatch, VectorTeleportResponse,
+    Event as ProtoEvent, EventSearchResult, ExplainabilityPayload, FindSimilarNodesResponse,
+    GetTopicsByQueryResponse, HybridSearchResponse, RetrievalResult, RouteQueryResponse,
+    SearchEventsResponse, SubtreeNode, TeleportSearchRequest, TimeRange, TocNodeChange, TopicScore,
+    VectorIndexStatus, VectorMatch, VectorTeleportResponse,
 };
+pub use retry::RetryConfig;
+pub use teleport::{DocType, TeleportSearchResult};
 
 // Re-export Event type for convenience
 pub use memory_types::Event;
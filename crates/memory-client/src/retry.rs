@@ -0,0 +1,102 @@
+//! Reconnect policy for the memory client.
+//!
+//! When the daemon restarts, in-flight channels start failing with
+//! transport errors. A client built with [`RetryConfig`] reconnects with
+//! bounded exponential backoff and retries the call before giving up.
+
+use std::error::Error as StdError;
+use std::time::Duration;
+
+use tonic::{Code, Status};
+
+/// Backoff settings for reconnecting to the daemon.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum reconnect attempts per call (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first reconnect attempt
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each failed attempt
+    pub multiplier: f64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Set the maximum number of reconnect attempts.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the initial backoff delay.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the maximum backoff delay.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Delay before the given (1-based) attempt.
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(32) as i32;
+        let millis = self.initial_backoff.as_millis() as f64 * self.multiplier.max(1.0).powi(exp);
+        Duration::from_millis(millis as u64).min(self.max_backoff)
+    }
+}
+
+/// Whether an RPC failure indicates a broken connection worth reconnecting for.
+///
+/// Application errors (NotFound, InvalidArgument, ...) are never retried.
+pub(crate) fn is_transport_error(status: &Status) -> bool {
+    match status.code() {
+        Code::Unavailable => true,
+        Code::Unknown => status
+            .source()
+            .is_some_and(|e| e.downcast_ref::<tonic::transport::Error>().is_some()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let config = RetryConfig::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500));
+
+        assert_eq!(config.backoff_for(1), Duration::from_millis(100));
+        assert_eq!(config.backoff_for(2), Duration::from_millis(200));
+        assert_eq!(config.backoff_for(3), Duration::from_millis(400));
+        assert_eq!(config.backoff_for(4), Duration::from_millis(500));
+        assert_eq!(config.backoff_for(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_transport_error_classification() {
+        assert!(is_transport_error(&Status::unavailable(
+            "connection refused"
+        )));
+        assert!(!is_transport_error(&Status::not_found("missing")));
+        assert!(!is_transport_error(&Status::invalid_argument("bad")));
+        assert!(!is_transport_error(&Status::unknown("app error")));
+    }
+}
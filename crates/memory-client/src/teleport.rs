@@ -0,0 +1,165 @@
+//! Typed teleport (BM25) search results.
+//!
+//! The raw `TeleportSearchResponse` carries `doc_type` as a proto integer and
+//! keywords as a single space-joined string. These types decode both once so
+//! callers don't have to.
+
+use memory_service::pb::{TeleportDocType, TeleportSearchResult as ProtoTeleportSearchResult};
+
+/// Kind of document a teleport result points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocType {
+    /// A TOC node (year/month/week/day/segment)
+    TocNode,
+    /// A grip (excerpt anchored to source events)
+    Grip,
+    /// Unset or unrecognized proto value
+    Unknown,
+}
+
+impl DocType {
+    /// Proto value to use as a search filter.
+    pub fn to_proto(self) -> i32 {
+        match self {
+            DocType::TocNode => TeleportDocType::TocNode as i32,
+            DocType::Grip => TeleportDocType::Grip as i32,
+            DocType::Unknown => TeleportDocType::Unspecified as i32,
+        }
+    }
+
+    /// Short display label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocType::TocNode => "TOC",
+            DocType::Grip => "Grip",
+            DocType::Unknown => "?",
+        }
+    }
+}
+
+impl From<i32> for DocType {
+    fn from(value: i32) -> Self {
+        match TeleportDocType::try_from(value) {
+            Ok(TeleportDocType::TocNode) => DocType::TocNode,
+            Ok(TeleportDocType::Grip) => DocType::Grip,
+            Ok(TeleportDocType::Unspecified) | Err(_) => DocType::Unknown,
+        }
+    }
+}
+
+/// A single teleport search hit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeleportSearchResult {
+    /// Document ID (node_id or grip_id)
+    pub doc_id: String,
+    /// Document type
+    pub doc_type: DocType,
+    /// BM25 relevance score
+    pub score: f32,
+    /// Keywords from the document (empty for grips)
+    pub keywords: Vec<String>,
+    /// Timestamp in milliseconds
+    pub timestamp_ms: Option<i64>,
+    /// Agent that produced this result
+    pub agent: Option<String>,
+}
+
+impl From<ProtoTeleportSearchResult> for TeleportSearchResult {
+    fn from(proto: ProtoTeleportSearchResult) -> Self {
+        let keywords = proto
+            .keywords
+            .as_deref()
+            .map(|k| k.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        Self {
+            doc_id: proto.doc_id,
+            doc_type: DocType::from(proto.doc_type),
+            score: proto.score,
+            keywords,
+            timestamp_ms: proto.timestamp_ms,
+            agent: proto.agent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proto(
+        doc_id: &str,
+        doc_type: i32,
+        score: f32,
+        keywords: Option<&str>,
+    ) -> ProtoTeleportSearchResult {
+        ProtoTeleportSearchResult {
+            doc_id: doc_id.to_string(),
+            doc_type,
+            score,
+            keywords: keywords.map(str::to_string),
+            timestamp_ms: Some(1_706_540_400_000),
+            agent: Some("claude".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_doc_type_from_i32() {
+        assert_eq!(DocType::from(0), DocType::Unknown);
+        assert_eq!(DocType::from(1), DocType::TocNode);
+        assert_eq!(DocType::from(2), DocType::Grip);
+        assert_eq!(DocType::from(99), DocType::Unknown);
+        assert_eq!(DocType::from(-1), DocType::Unknown);
+    }
+
+    #[test]
+    fn test_doc_type_proto_round_trip() {
+        for doc_type in [DocType::TocNode, DocType::Grip, DocType::Unknown] {
+            assert_eq!(DocType::from(doc_type.to_proto()), doc_type);
+        }
+    }
+
+    #[test]
+    fn test_typed_conversion_preserves_order_and_scores() {
+        let raw = vec![
+            proto("toc:day:2024-01-29", 1, 4.25, Some("rust  memory auth")),
+            proto("grip:01HN", 2, 3.5, None),
+            proto("toc:week:2024-W05", 1, 1.125, Some("")),
+            proto("mystery", 7, 0.5, None),
+        ];
+
+        let typed: Vec<TeleportSearchResult> =
+            raw.into_iter().map(TeleportSearchResult::from).collect();
+
+        let ids: Vec<&str> = typed.iter().map(|r| r.doc_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "toc:day:2024-01-29",
+                "grip:01HN",
+                "toc:week:2024-W05",
+                "mystery"
+            ]
+        );
+
+        let types: Vec<DocType> = typed.iter().map(|r| r.doc_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                DocType::TocNode,
+                DocType::Grip,
+                DocType::TocNode,
+                DocType::Unknown
+            ]
+        );
+
+        let scores: Vec<f32> = typed.iter().map(|r| r.score).collect();
+        assert_eq!(scores, vec![4.25, 3.5, 1.125, 0.5]);
+
+        assert_eq!(typed[0].keywords, vec!["rust", "memory", "auth"]);
+        assert!(typed[1].keywords.is_empty());
+        assert!(typed[2].keywords.is_empty());
+        assert_eq!(typed[0].timestamp_ms, Some(1_706_540_400_000));
+        assert_eq!(typed[0].agent.as_deref(), Some("claude"));
+    }
+}
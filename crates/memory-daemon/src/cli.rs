@@ -4,6 +4,7 @@
 //! Per CFG-01: CLI flags override all other config sources.
 
 use clap::{Parser, Subcommand};
+use memory_types::LogFormat;
 
 /// Agent Memory Daemon
 ///
@@ -20,6 +21,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub log_level: Option<String>,
 
+    /// Set log output format (pretty, json)
+    #[arg(long, global = true)]
+    pub log_format: Option<LogFormat>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -102,9 +107,47 @@ pub enum Commands {
     #[command(subcommand)]
     Agents(AgentsCommand),
 
+    /// Novelty (dedup) gate commands
+    #[command(subcommand)]
+    Novelty(NoveltyCommand),
+
     /// CLOD format commands (convert and validate)
     #[command(subcommand)]
     Clod(ClodCliCommand),
+
+    /// Configuration commands
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// Embedding model commands
+    #[command(subcommand)]
+    Embeddings(EmbeddingsCommand),
+}
+
+/// Configuration commands
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+    /// Load settings and report invalid fields without starting the server
+    ///
+    /// Uses the global --config file if given.
+    Validate,
+}
+
+/// Embedding model commands
+#[derive(Subcommand, Debug, Clone)]
+pub enum EmbeddingsCommand {
+    /// Time the local embedding model on synthetic texts
+    ///
+    /// Reports embeddings/sec and p50/p95 batch latency.
+    Bench {
+        /// Number of texts to embed
+        #[arg(long, default_value = "256")]
+        count: usize,
+
+        /// Texts per batch
+        #[arg(long, default_value = "32")]
+        batch: usize,
+    },
 }
 
 /// CLOD (Cross-Language Operation Definition) commands
@@ -141,6 +184,36 @@ pub enum QueryCommands {
         node_id: String,
     },
 
+    /// Show a node and its descendants as a tree
+    Subtree {
+        /// Root node ID
+        node_id: String,
+
+        /// Levels below the root to include
+        #[arg(short, long, default_value = "2")]
+        depth: u32,
+    },
+
+    /// Show how a node changed across its stored versions
+    NodeHistory {
+        /// Node ID
+        node_id: String,
+    },
+
+    /// Find nodes similar to a node ("more like this")
+    Similar {
+        /// Node ID
+        node_id: String,
+
+        /// Number of results to return
+        #[arg(long, default_value = "10")]
+        top_k: i32,
+
+        /// Minimum similarity score (0.0-1.0)
+        #[arg(long, default_value = "0.0")]
+        min_score: f32,
+    },
+
     /// Browse children of a node
     Browse {
         /// Parent node ID
@@ -168,6 +241,10 @@ pub enum QueryCommands {
         /// Maximum results
         #[arg(short, long, default_value = "50")]
         limit: u32,
+
+        /// Only show these event types (e.g. user,assistant); default all
+        #[arg(long, value_delimiter = ',')]
+        types: Vec<String>,
     },
 
     /// Expand a grip to show context
@@ -232,6 +309,30 @@ pub enum AdminCommands {
         dry_run: bool,
     },
 
+    /// Show how events in a date range would be segmented, without
+    /// summarizing or writing anything
+    SegmentPreview {
+        /// First day to include (YYYY-MM-DD)
+        #[arg(long)]
+        from: String,
+
+        /// Last day to include (YYYY-MM-DD)
+        #[arg(long)]
+        to: String,
+
+        /// Override the time gap (minutes) that starts a new segment
+        #[arg(long)]
+        time_threshold_mins: Option<i64>,
+
+        /// Override the token count that starts a new segment
+        #[arg(long)]
+        token_threshold: Option<usize>,
+
+        /// Override the hard cap on tokens in a single segment
+        #[arg(long)]
+        max_segment_tokens: Option<usize>,
+    },
+
     /// Rebuild search indexes from storage
     RebuildIndexes {
         /// Which index to rebuild: bm25, vector, or all
@@ -253,6 +354,18 @@ pub enum AdminCommands {
         /// Path to vector index directory (default from config)
         #[arg(long)]
         vector_path: Option<String>,
+
+        /// Print time spent reading, embedding, indexing and committing
+        #[arg(long)]
+        profile: bool,
+
+        /// Only index documents covering events from this outbox sequence on
+        #[arg(long)]
+        from_sequence: Option<u64>,
+
+        /// Only index documents covering events at or after this time (Unix ms)
+        #[arg(long, conflicts_with = "from_sequence")]
+        since: Option<i64>,
     },
 
     /// Show search index statistics
@@ -266,6 +379,59 @@ pub enum AdminCommands {
         vector_path: Option<String>,
     },
 
+    /// Check BM25 and vector index files for corruption without modifying them
+    IndexCheck {
+        /// Which index to check: bm25, vector, or all
+        #[arg(long, default_value = "all")]
+        index: String,
+
+        /// Path to search index directory (default from config)
+        #[arg(long)]
+        search_path: Option<String>,
+
+        /// Path to vector index directory (default from config)
+        #[arg(long)]
+        vector_path: Option<String>,
+    },
+
+    /// Inspect pending outbox entries
+    Outbox {
+        #[command(subcommand)]
+        command: OutboxCommands,
+    },
+
+    /// Compare storage against search indexes and report drift
+    VerifyConsistency {
+        /// Which index to check: bm25, vector, or all
+        #[arg(long, default_value = "all")]
+        index: String,
+
+        /// Reindex missing documents and delete orphaned ones
+        #[arg(long)]
+        repair: bool,
+
+        /// Path to search index directory (default from config)
+        #[arg(long)]
+        search_path: Option<String>,
+
+        /// Path to vector index directory (default from config)
+        #[arg(long)]
+        vector_path: Option<String>,
+    },
+
+    /// Replay outbox entries into the BM25 index from a sequence number
+    ///
+    /// Rewinds the BM25 indexing checkpoint and reprocesses the outbox from
+    /// there. Entries already indexed are overwritten, so replay is safe.
+    ReindexFrom {
+        /// Outbox sequence to replay from (inclusive)
+        sequence: u64,
+
+        /// Path to search index directory (default from config)
+        #[arg(long)]
+        search_path: Option<String>,
+    },
+
     /// Clear and reset a search index
     ClearIndex {
         /// Which index to clear: bm25, vector, or all
@@ -310,6 +476,45 @@ pub enum AdminCommands {
         #[arg(long)]
         search_path: Option<String>,
     },
+
+    /// Verify that grip source events still exist
+    GripAudit,
+
+    /// Permanently delete a session's events, grips and index entries
+    PurgeSession {
+        /// Session ID to purge
+        session_id: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Path to search index directory (default from config)
+        #[arg(long)]
+        search_path: Option<String>,
+
+        /// Path to vector index directory (default from config)
+        #[arg(long)]
+        vector_path: Option<String>,
+    },
+}
+
+/// Outbox inspection subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum OutboxCommands {
+    /// List pending outbox entries in sequence order
+    List {
+        /// Maximum entries to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        /// First sequence number to show
+        #[arg(long, default_value = "0")]
+        from_seq: u64,
+    },
+
+    /// Show the outbox backlog and sequence range
+    Stats,
 }
 
 /// Scheduler subcommands
@@ -351,6 +556,36 @@ pub enum TeleportCommand {
         #[arg(long, short = 'a')]
         agent: Option<String>,
 
+        /// Only include documents from this time on (YYYY-MM-DD or Unix ms)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only include documents before this time (YYYY-MM-DD or Unix ms)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Drop results scoring below this (raw BM25 unless --normalize)
+        #[arg(long, default_value = "0.0")]
+        min_score: f32,
+
+        /// Scale scores so the top result is 1.0
+        #[arg(long)]
+        normalize: bool,
+
+        /// gRPC server address
+        #[arg(long, default_value = "http://127.0.0.1:50051")]
+        addr: String,
+    },
+
+    /// Search raw event text by keyword (requires index_raw_events)
+    Events {
+        /// Search query (keywords)
+        query: String,
+
+        /// Maximum results to return
+        #[arg(long, short = 'n', default_value = "10")]
+        limit: usize,
+
         /// gRPC server address
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
@@ -413,6 +648,14 @@ pub enum TeleportCommand {
         #[arg(long, short = 'a')]
         agent: Option<String>,
 
+        /// Only include documents from this time on (YYYY-MM-DD or Unix ms)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only include documents before this time (YYYY-MM-DD or Unix ms)
+        #[arg(long)]
+        until: Option<String>,
+
         /// gRPC server address
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
@@ -432,6 +675,25 @@ pub enum TeleportCommand {
         addr: String,
     },
 
+    /// Prune old vectors per lifecycle retention policy
+    VectorPrune {
+        /// Prune only this level: segment, grip, day, week (default: all)
+        #[arg(long)]
+        level: Option<String>,
+
+        /// Override retention days for every level
+        #[arg(long)]
+        age_days: Option<u32>,
+
+        /// Count vectors that would be pruned without removing them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// gRPC server address
+        #[arg(long, default_value = "http://127.0.0.1:50051")]
+        addr: String,
+    },
+
     /// Rebuild the search index from storage
     Rebuild {
         /// gRPC server address (for triggering rebuild)
@@ -459,6 +721,10 @@ pub enum TopicsCommand {
         #[arg(long, short = 'n', default_value = "10")]
         limit: u32,
 
+        /// Weight of query relevance against importance (0.0-1.0, default: 0.7)
+        #[arg(long, short = 'w')]
+        relevance_weight: Option<f32>,
+
         /// gRPC server address
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
@@ -477,6 +743,28 @@ pub enum TopicsCommand {
         #[arg(long, short = 'n', default_value = "10")]
         limit: u32,
 
+        /// Hide relationships weaker than this (0.0-1.0)
+        #[arg(long, default_value = "0.0")]
+        min_strength: f32,
+
+        /// gRPC server address
+        #[arg(long, default_value = "http://127.0.0.1:50051")]
+        addr: String,
+    },
+
+    /// List the TOC nodes that contributed to a topic
+    Nodes {
+        /// Topic ID to navigate from
+        topic_id: String,
+
+        /// Maximum nodes per page (max 100)
+        #[arg(long, short = 'n', default_value = "20")]
+        limit: u32,
+
+        /// Continuation token for pagination
+        #[arg(long)]
+        token: Option<String>,
+
         /// gRPC server address
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
@@ -569,6 +857,10 @@ pub enum RetrievalCommand {
         #[arg(long, short = 'a')]
         agent: Option<String>,
 
+        /// Output format (text, md)
+        #[arg(long, default_value = "text", value_parser = ["text", "md"])]
+        format: String,
+
         /// gRPC server address
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
@@ -595,7 +887,7 @@ pub enum AgentsCommand {
         /// End time (YYYY-MM-DD or Unix ms)
         #[arg(long)]
         to: Option<String>,
-        /// Bucket granularity: day, week
+        /// Bucket granularity: hour, day, week, month
         #[arg(long, default_value = "day")]
         bucket: String,
         /// gRPC server address
@@ -614,6 +906,30 @@ pub enum AgentsCommand {
         #[arg(long, default_value = "http://127.0.0.1:50051")]
         addr: String,
     },
+    /// Compare two agents' top topics (shared vs. unique)
+    Compare {
+        /// First agent ID
+        agent_a: String,
+        /// Second agent ID
+        agent_b: String,
+        /// Size of each agent's top-topic set
+        #[arg(long, short = 'n', default_value = "10")]
+        limit: u32,
+        /// gRPC server address
+        #[arg(long, default_value = "http://127.0.0.1:50051")]
+        addr: String,
+    },
+}
+
+/// Novelty (dedup) gate commands
+#[derive(Subcommand, Debug, Clone)]
+pub enum NoveltyCommand {
+    /// Show how many events the novelty gate has suppressed
+    Stats {
+        /// gRPC server address
+        #[arg(long, default_value = "http://127.0.0.1:50051")]
+        addr: String,
+    },
 }
 
 impl Cli {
@@ -687,6 +1003,13 @@ mod tests {
         assert_eq!(cli.log_level, Some("debug".to_string()));
     }
 
+    #[test]
+    fn test_cli_with_log_format() {
+        let cli = Cli::parse_from(["memory-daemon", "start", "--log-format", "json"]);
+        assert_eq!(cli.log_format, Some(LogFormat::Json));
+        assert!(Cli::try_parse_from(["memory-daemon", "--log-format", "xml", "start"]).is_err());
+    }
+
     #[test]
     fn test_cli_scheduler_status() {
         let cli = Cli::parse_from(["memory-daemon", "scheduler", "status"]);
@@ -761,6 +1084,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_teleport_events() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "teleport",
+            "events",
+            "kafka timeout",
+            "-n",
+            "5",
+        ]);
+        match cli.command {
+            Commands::Teleport(TeleportCommand::Events { query, limit, addr }) => {
+                assert_eq!(query, "kafka timeout");
+                assert_eq!(limit, 5);
+                assert_eq!(addr, "http://127.0.0.1:50051");
+            }
+            _ => panic!("Expected Teleport Events command"),
+        }
+    }
+
     #[test]
     fn test_cli_teleport_search_with_options() {
         let cli = Cli::parse_from([
@@ -792,6 +1135,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_teleport_vector_prune() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "teleport",
+            "vector-prune",
+            "--level",
+            "grip",
+            "--dry-run",
+        ]);
+        match cli.command {
+            Commands::Teleport(TeleportCommand::VectorPrune {
+                level,
+                age_days,
+                dry_run,
+                ..
+            }) => {
+                assert_eq!(level, Some("grip".to_string()));
+                assert_eq!(age_days, None);
+                assert!(dry_run);
+            }
+            _ => panic!("Expected Teleport VectorPrune command"),
+        }
+    }
+
     #[test]
     fn test_cli_teleport_stats() {
         let cli = Cli::parse_from(["memory-daemon", "teleport", "stats"]);
@@ -912,6 +1280,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_teleport_search_min_score() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "teleport",
+            "search",
+            "deploy",
+            "--min-score",
+            "0.5",
+            "--normalize",
+        ]);
+        match cli.command {
+            Commands::Teleport(TeleportCommand::Search {
+                min_score,
+                normalize,
+                ..
+            }) => {
+                assert!((min_score - 0.5).abs() < f32::EPSILON);
+                assert!(normalize);
+            }
+            _ => panic!("Expected Teleport Search command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_teleport_search_time_range() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "teleport",
+            "search",
+            "deploy",
+            "--since",
+            "2026-01-01",
+            "--until",
+            "2026-01-08",
+        ]);
+        match cli.command {
+            Commands::Teleport(TeleportCommand::Search { since, until, .. }) => {
+                assert_eq!(since.as_deref(), Some("2026-01-01"));
+                assert_eq!(until.as_deref(), Some("2026-01-08"));
+            }
+            _ => panic!("Expected Teleport Search command"),
+        }
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "teleport",
+            "hybrid-search",
+            "-q",
+            "deploy",
+            "--since",
+            "2026-01-01",
+        ]);
+        match cli.command {
+            Commands::Teleport(TeleportCommand::HybridSearch { since, until, .. }) => {
+                assert_eq!(since.as_deref(), Some("2026-01-01"));
+                assert_eq!(until, None);
+            }
+            _ => panic!("Expected Teleport HybridSearch command"),
+        }
+    }
+
     #[test]
     fn test_cli_teleport_hybrid_search_with_options() {
         let cli = Cli::parse_from([
@@ -994,20 +1424,113 @@ mod tests {
     }
 
     #[test]
-    fn test_cli_query_search_with_node() {
+    fn test_cli_query_subtree() {
         let cli = Cli::parse_from([
             "memory-daemon",
             "query",
-            "search",
-            "--query",
-            "debugging",
-            "--node",
-            "toc:month:2026-01",
+            "subtree",
+            "toc:year:2026",
+            "--depth",
+            "3",
         ]);
         match cli.command {
             Commands::Query { command, .. } => match command {
-                QueryCommands::Search {
-                    query,
+                QueryCommands::Subtree { node_id, depth } => {
+                    assert_eq!(node_id, "toc:year:2026");
+                    assert_eq!(depth, 3);
+                }
+                _ => panic!("Expected Subtree command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_query_node_history() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "query",
+            "node-history",
+            "toc:day:2026-01-15",
+        ]);
+        match cli.command {
+            Commands::Query { command, .. } => match command {
+                QueryCommands::NodeHistory { node_id } => {
+                    assert_eq!(node_id, "toc:day:2026-01-15");
+                }
+                _ => panic!("Expected NodeHistory command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_query_similar() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "query",
+            "similar",
+            "toc:day:2026-01-15",
+            "--top-k",
+            "5",
+        ]);
+        match cli.command {
+            Commands::Query { command, .. } => match command {
+                QueryCommands::Similar {
+                    node_id,
+                    top_k,
+                    min_score,
+                } => {
+                    assert_eq!(node_id, "toc:day:2026-01-15");
+                    assert_eq!(top_k, 5);
+                    assert_eq!(min_score, 0.0);
+                }
+                _ => panic!("Expected Similar command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_query_events_types() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "query",
+            "events",
+            "--from",
+            "1000",
+            "--to",
+            "2000",
+            "--types",
+            "user,assistant",
+        ]);
+        match cli.command {
+            Commands::Query { command, .. } => match command {
+                QueryCommands::Events { limit, types, .. } => {
+                    assert_eq!(limit, 50);
+                    assert_eq!(types, vec!["user", "assistant"]);
+                }
+                _ => panic!("Expected Events command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_query_search_with_node() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "query",
+            "search",
+            "--query",
+            "debugging",
+            "--node",
+            "toc:month:2026-01",
+        ]);
+        match cli.command {
+            Commands::Query { command, .. } => match command {
+                QueryCommands::Search {
+                    query,
                     node,
                     parent,
                     ..
@@ -1069,12 +1592,18 @@ mod tests {
                     force,
                     search_path,
                     vector_path,
+                    profile,
+                    from_sequence,
+                    since,
                 } => {
                     assert_eq!(index, "all");
                     assert_eq!(batch_size, 100);
                     assert!(!force);
                     assert!(search_path.is_none());
                     assert!(vector_path.is_none());
+                    assert!(!profile);
+                    assert!(from_sequence.is_none());
+                    assert!(since.is_none());
                 }
                 _ => panic!("Expected RebuildIndexes command"),
             },
@@ -1082,6 +1611,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_admin_rebuild_indexes_since() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "admin",
+            "rebuild-indexes",
+            "--since",
+            "1706540400000",
+        ]);
+        match cli.command {
+            Commands::Admin {
+                command: AdminCommands::RebuildIndexes { since, .. },
+                ..
+            } => assert_eq!(since, Some(1706540400000)),
+            _ => panic!("Expected RebuildIndexes command"),
+        }
+
+        let result = Cli::try_parse_from([
+            "memory-daemon",
+            "admin",
+            "rebuild-indexes",
+            "--since",
+            "1706540400000",
+            "--from-sequence",
+            "42",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_cli_admin_rebuild_indexes_with_options() {
         let cli = Cli::parse_from([
@@ -1095,6 +1653,9 @@ mod tests {
             "--force",
             "--search-path",
             "/custom/search",
+            "--profile",
+            "--from-sequence",
+            "42",
         ]);
         match cli.command {
             Commands::Admin { command, .. } => match command {
@@ -1104,12 +1665,18 @@ mod tests {
                     force,
                     search_path,
                     vector_path,
+                    profile,
+                    from_sequence,
+                    since,
                 } => {
                     assert_eq!(index, "bm25");
                     assert_eq!(batch_size, 50);
                     assert!(force);
                     assert_eq!(search_path, Some("/custom/search".to_string()));
                     assert!(vector_path.is_none());
+                    assert!(profile);
+                    assert_eq!(from_sequence, Some(42));
+                    assert!(since.is_none());
                 }
                 _ => panic!("Expected RebuildIndexes command"),
             },
@@ -1135,6 +1702,217 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_admin_index_check() {
+        let cli = Cli::parse_from(["memory-daemon", "admin", "index-check"]);
+        match cli.command {
+            Commands::Admin { command, .. } => match command {
+                AdminCommands::IndexCheck {
+                    index,
+                    search_path,
+                    vector_path,
+                } => {
+                    assert_eq!(index, "all");
+                    assert!(search_path.is_none());
+                    assert!(vector_path.is_none());
+                }
+                _ => panic!("Expected IndexCheck command"),
+            },
+            _ => panic!("Expected Admin command"),
+        }
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "admin",
+            "index-check",
+            "--index",
+            "vector",
+            "--vector-path",
+            "/tmp/vector",
+        ]);
+        match cli.command {
+            Commands::Admin {
+                command:
+                    AdminCommands::IndexCheck {
+                        index, vector_path, ..
+                    },
+                ..
+            } => {
+                assert_eq!(index, "vector");
+                assert_eq!(vector_path, Some("/tmp/vector".to_string()));
+            }
+            _ => panic!("Expected Admin IndexCheck command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_admin_segment_preview() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "admin",
+            "segment-preview",
+            "--from",
+            "2026-01-01",
+            "--to",
+            "2026-01-07",
+            "--token-threshold",
+            "2000",
+        ]);
+        match cli.command {
+            Commands::Admin {
+                command:
+                    AdminCommands::SegmentPreview {
+                        from,
+                        to,
+                        time_threshold_mins,
+                        token_threshold,
+                        max_segment_tokens,
+                    },
+                ..
+            } => {
+                assert_eq!(from, "2026-01-01");
+                assert_eq!(to, "2026-01-07");
+                assert_eq!(time_threshold_mins, None);
+                assert_eq!(token_threshold, Some(2000));
+                assert_eq!(max_segment_tokens, None);
+            }
+            _ => panic!("Expected Admin SegmentPreview command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_admin_outbox() {
+        let cli = Cli::parse_from(["memory-daemon", "admin", "outbox", "list"]);
+        match cli.command {
+            Commands::Admin {
+                command:
+                    AdminCommands::Outbox {
+                        command: OutboxCommands::List { limit, from_seq },
+                    },
+                ..
+            } => {
+                assert_eq!(limit, 20);
+                assert_eq!(from_seq, 0);
+            }
+            _ => panic!("Expected Admin Outbox List command"),
+        }
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "admin",
+            "outbox",
+            "list",
+            "--limit",
+            "5",
+            "--from-seq",
+            "42",
+        ]);
+        match cli.command {
+            Commands::Admin {
+                command:
+                    AdminCommands::Outbox {
+                        command: OutboxCommands::List { limit, from_seq },
+                    },
+                ..
+            } => {
+                assert_eq!(limit, 5);
+                assert_eq!(from_seq, 42);
+            }
+            _ => panic!("Expected Admin Outbox List command"),
+        }
+
+        let cli = Cli::parse_from(["memory-daemon", "admin", "outbox", "stats"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Admin {
+                command: AdminCommands::Outbox {
+                    command: OutboxCommands::Stats
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_admin_verify_consistency() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "admin",
+            "verify-consistency",
+            "--index",
+            "vector",
+            "--repair",
+        ]);
+        match cli.command {
+            Commands::Admin { command, .. } => match command {
+                AdminCommands::VerifyConsistency {
+                    index,
+                    repair,
+                    search_path,
+                    vector_path,
+                } => {
+                    assert_eq!(index, "vector");
+                    assert!(repair);
+                    assert!(search_path.is_none());
+                    assert!(vector_path.is_none());
+                }
+                _ => panic!("Expected VerifyConsistency command"),
+            },
+            _ => panic!("Expected Admin command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_admin_reindex_from() {
+        let cli = Cli::parse_from(["memory-daemon", "admin", "reindex-from", "42"]);
+        match cli.command {
+            Commands::Admin { command, .. } => match command {
+                AdminCommands::ReindexFrom {
+                    sequence,
+                    search_path,
+                } => {
+                    assert_eq!(sequence, 42);
+                    assert!(search_path.is_none());
+                }
+                _ => panic!("Expected ReindexFrom command"),
+            },
+            _ => panic!("Expected Admin command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_admin_grip_audit() {
+        let cli = Cli::parse_from(["memory-daemon", "admin", "grip-audit"]);
+        match cli.command {
+            Commands::Admin { command, .. } => {
+                assert!(matches!(command, AdminCommands::GripAudit));
+            }
+            _ => panic!("Expected Admin command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_admin_purge_session() {
+        let cli = Cli::parse_from(["memory-daemon", "admin", "purge-session", "session-123"]);
+        match cli.command {
+            Commands::Admin { command, .. } => match command {
+                AdminCommands::PurgeSession {
+                    session_id,
+                    force,
+                    search_path,
+                    vector_path,
+                } => {
+                    assert_eq!(session_id, "session-123");
+                    assert!(!force);
+                    assert!(search_path.is_none());
+                    assert!(vector_path.is_none());
+                }
+                _ => panic!("Expected PurgeSession command"),
+            },
+            _ => panic!("Expected Admin command"),
+        }
+    }
+
     #[test]
     fn test_cli_admin_clear_index() {
         let cli = Cli::parse_from([
@@ -1220,15 +1998,34 @@ mod tests {
             "http://localhost:9999",
         ]);
         match cli.command {
-            Commands::Topics(TopicsCommand::Explore { query, limit, addr }) => {
+            Commands::Topics(TopicsCommand::Explore {
+                query,
+                limit,
+                relevance_weight,
+                addr,
+            }) => {
                 assert_eq!(query, "authentication");
                 assert_eq!(limit, 5);
+                assert_eq!(relevance_weight, None);
                 assert_eq!(addr, "http://localhost:9999");
             }
             _ => panic!("Expected Topics Explore command"),
         }
     }
 
+    #[test]
+    fn test_cli_topics_explore_relevance_weight() {
+        let cli = Cli::parse_from(["memory-daemon", "topics", "explore", "rust", "-w", "0.3"]);
+        match cli.command {
+            Commands::Topics(TopicsCommand::Explore {
+                relevance_weight, ..
+            }) => {
+                assert_eq!(relevance_weight, Some(0.3));
+            }
+            _ => panic!("Expected Topics Explore command"),
+        }
+    }
+
     #[test]
     fn test_cli_topics_related() {
         let cli = Cli::parse_from(["memory-daemon", "topics", "related", "topic-123"]);
@@ -1237,11 +2034,13 @@ mod tests {
                 topic_id,
                 rel_type,
                 limit,
+                min_strength,
                 addr,
             }) => {
                 assert_eq!(topic_id, "topic-123");
                 assert!(rel_type.is_none());
                 assert_eq!(limit, 10);
+                assert_eq!(min_strength, 0.0);
                 assert_eq!(addr, "http://127.0.0.1:50051");
             }
             _ => panic!("Expected Topics Related command"),
@@ -1259,22 +2058,54 @@ mod tests {
             "semantic",
             "-n",
             "20",
+            "--min-strength",
+            "0.5",
         ]);
         match cli.command {
             Commands::Topics(TopicsCommand::Related {
                 topic_id,
                 rel_type,
                 limit,
+                min_strength,
                 ..
             }) => {
                 assert_eq!(topic_id, "topic-abc");
                 assert_eq!(rel_type, Some("semantic".to_string()));
                 assert_eq!(limit, 20);
+                assert_eq!(min_strength, 0.5);
             }
             _ => panic!("Expected Topics Related command"),
         }
     }
 
+    #[test]
+    fn test_cli_topics_nodes() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "topics",
+            "nodes",
+            "topic-abc",
+            "-n",
+            "5",
+            "--token",
+            "10",
+        ]);
+        match cli.command {
+            Commands::Topics(TopicsCommand::Nodes {
+                topic_id,
+                limit,
+                token,
+                addr,
+            }) => {
+                assert_eq!(topic_id, "topic-abc");
+                assert_eq!(limit, 5);
+                assert_eq!(token, Some("10".to_string()));
+                assert_eq!(addr, "http://127.0.0.1:50051");
+            }
+            _ => panic!("Expected Topics Nodes command"),
+        }
+    }
+
     #[test]
     fn test_cli_topics_top() {
         let cli = Cli::parse_from(["memory-daemon", "topics", "top"]);
@@ -1488,8 +2319,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_retrieval_route_format() {
+        let cli = Cli::parse_from(["memory-daemon", "retrieval", "route", "jwt bug"]);
+        match cli.command {
+            Commands::Retrieval(RetrievalCommand::Route { format, .. }) => {
+                assert_eq!(format, "text");
+            }
+            _ => panic!("Expected Retrieval Route command"),
+        }
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "retrieval",
+            "route",
+            "jwt bug",
+            "--format",
+            "md",
+        ]);
+        match cli.command {
+            Commands::Retrieval(RetrievalCommand::Route { format, .. }) => {
+                assert_eq!(format, "md");
+            }
+            _ => panic!("Expected Retrieval Route command"),
+        }
+
+        assert!(Cli::try_parse_from([
+            "memory-daemon",
+            "retrieval",
+            "route",
+            "jwt bug",
+            "--format",
+            "html",
+        ])
+        .is_err());
+    }
+
     // === Phase 23: Agent Discovery Tests ===
 
+    #[test]
+    fn test_cli_novelty_stats() {
+        let cli = Cli::parse_from(["memory-daemon", "novelty", "stats"]);
+        match cli.command {
+            Commands::Novelty(NoveltyCommand::Stats { addr }) => {
+                assert_eq!(addr, "http://127.0.0.1:50051");
+            }
+            _ => panic!("Expected Novelty Stats command"),
+        }
+    }
+
     #[test]
     fn test_cli_agents_list() {
         let cli = Cli::parse_from(["memory-daemon", "agents", "list"]);
@@ -1623,6 +2501,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_agents_compare() {
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "agents",
+            "compare",
+            "claude",
+            "opencode",
+            "-n",
+            "20",
+        ]);
+        match cli.command {
+            Commands::Agents(AgentsCommand::Compare {
+                agent_a,
+                agent_b,
+                limit,
+                addr,
+            }) => {
+                assert_eq!(agent_a, "claude");
+                assert_eq!(agent_b, "opencode");
+                assert_eq!(limit, 20);
+                assert_eq!(addr, "http://127.0.0.1:50051");
+            }
+            _ => panic!("Expected Agents Compare command"),
+        }
+    }
+
     // === Phase 23: CLOD CLI Tests ===
 
     #[test]
@@ -1669,6 +2574,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_embeddings_bench() {
+        let cli = Cli::parse_from(["memory-daemon", "embeddings", "bench"]);
+        match cli.command {
+            Commands::Embeddings(EmbeddingsCommand::Bench { count, batch }) => {
+                assert_eq!(count, 256);
+                assert_eq!(batch, 32);
+            }
+            _ => panic!("Expected Embeddings Bench command"),
+        }
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "embeddings",
+            "bench",
+            "--count",
+            "64",
+            "--batch",
+            "8",
+        ]);
+        match cli.command {
+            Commands::Embeddings(EmbeddingsCommand::Bench { count, batch }) => {
+                assert_eq!(count, 64);
+                assert_eq!(batch, 8);
+            }
+            _ => panic!("Expected Embeddings Bench command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_config_validate() {
+        let cli = Cli::parse_from(["memory-daemon", "config", "validate"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Config(ConfigCommand::Validate)
+        ));
+        assert!(cli.config.is_none());
+
+        let cli = Cli::parse_from([
+            "memory-daemon",
+            "config",
+            "validate",
+            "--config",
+            "/etc/agent-memory.toml",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Commands::Config(ConfigCommand::Validate)
+        ));
+        assert_eq!(cli.config.as_deref(), Some("/etc/agent-memory.toml"));
+    }
+
     #[test]
     fn test_cli_clod_validate() {
         let cli = Cli::parse_from(["memory-daemon", "clod", "validate", "memory-search.toml"]);
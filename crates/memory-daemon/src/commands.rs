@@ -10,34 +10,44 @@ use std::io::{self, Write};
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use chrono::TimeZone;
 use tokio::signal;
 use tracing::{info, warn};
 
-use memory_client::MemoryClient;
+use memory_client::{DocType, MemoryClient, TeleportSearchResult};
+use memory_embeddings::CandleEmbedder;
+use memory_retrieval::{
+    ExplainabilityPayload, FallbackChainConfig, LayerLatency, RetrievalLayer, RetrievalReport,
+    SearchResult, StopConditions,
+};
 use memory_scheduler::{
-    create_compaction_job, create_indexing_job, create_rollup_jobs, CompactionJobConfig,
-    IndexingJobConfig, RollupJobConfig, SchedulerConfig, SchedulerService,
+    create_compaction_job, create_indexing_job, create_rollup_jobs, create_toc_version_prune_job,
+    CompactionJobConfig, IndexingJobConfig, RollupJobConfig, SchedulerConfig, SchedulerService,
+    TocVersionPruneJobConfig,
 };
+use memory_search::NoResultsReason;
+use memory_service::conversions::{domain_from_i32, ProtoEnumName};
 use memory_service::novelty::{CandleEmbedderAdapter, NoveltyChecker};
 use memory_service::pb::{
-    GetSchedulerStatusRequest, JobResultStatus, PauseJobRequest, ResumeJobRequest,
-    SearchChildrenRequest, SearchField as ProtoSearchField, SearchNodeRequest,
-    TocLevel as ProtoTocLevel,
+    EventType as ProtoEventType, GetSchedulerStatusRequest, HybridMode, JobResultStatus,
+    NoResultsReason as ProtoNoResultsReason, PauseJobRequest, PruneVectorIndexResponse,
+    ResumeJobRequest, SearchChildrenRequest, SearchField as ProtoSearchField, SearchNodeRequest,
+    TeleportSearchRequest, TimeRange, TocLevel as ProtoTocLevel,
 };
-use memory_service::run_server_with_scheduler;
-use memory_storage::Storage;
+use memory_service::{run_server_with_scheduler, DegradedMode, HybridCacheConfig, Redactor};
+use memory_storage::{Storage, StorageConfig};
 use memory_toc::summarizer::{ApiSummarizer, ApiSummarizerConfig, MockSummarizer};
 use memory_types::config::SummarizerSettings;
 use memory_types::dedup::InFlightBuffer;
-use memory_types::Settings;
+use memory_types::{LogFormat, Settings};
 
 use crate::cli::{
-    AdminCommands, AgentsCommand, ClodCliCommand, QueryCommands, RetrievalCommand,
-    SchedulerCommands, TeleportCommand, TopicsCommand,
+    AdminCommands, AgentsCommand, ClodCliCommand, ConfigCommand, EmbeddingsCommand, NoveltyCommand,
+    OutboxCommands, QueryCommands, RetrievalCommand, SchedulerCommands, TeleportCommand,
+    TopicsCommand,
 };
 
 /// Get the PID file path
@@ -106,6 +116,103 @@ fn is_process_running(_pid: u32) -> bool {
     true
 }
 
+/// Indexes built by [`ensure_indexes`].
+#[derive(Debug, Default, PartialEq, Eq)]
+struct EnsuredIndexes {
+    bm25_built: bool,
+    vector_built: bool,
+}
+
+/// Build missing search indexes from storage (`auto_rebuild_on_start`).
+///
+/// An index is built only when storage has documents and the index is
+/// absent or empty, so this is a no-op on every start after the first.
+/// Populated indexes are left alone even if they have drifted; `admin
+/// verify-consistency --repair` handles that. The vector index is skipped
+/// when `embedder` is `None`.
+fn ensure_indexes<E: memory_embeddings::EmbeddingModel>(
+    storage: Arc<Storage>,
+    db_path: &Path,
+    embedder: Option<Arc<E>>,
+    vector: &memory_types::VectorSettings,
+) -> Result<EnsuredIndexes> {
+    use memory_indexing::{
+        rebuild_bm25_index, rebuild_vector_index, Bm25IndexUpdater, LoggingProgressCallback,
+        RebuildConfig, VectorIndexUpdater,
+    };
+    use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer, TeleportSearcher};
+    use memory_vector::{HnswIndex, VectorMetadata};
+
+    let mut built = EnsuredIndexes::default();
+    let stats = storage.get_stats().context("Failed to get stats")?;
+    if stats.toc_node_count + stats.grip_count == 0 {
+        return Ok(built);
+    }
+
+    let config = RebuildConfig::default();
+    let progress = LoggingProgressCallback::new(config.batch_size);
+
+    let search_dir = db_path.join("search");
+    let bm25_empty = !search_dir.exists()
+        || SearchIndex::open_or_create(SearchIndexConfig::new(&search_dir))
+            .and_then(|index| TeleportSearcher::new(&index))
+            .map(|searcher| searcher.num_docs() == 0)
+            .unwrap_or(false);
+    if bm25_empty {
+        info!(path = ?search_dir, "BM25 index missing, building from storage");
+        fs::create_dir_all(&search_dir).context("Failed to create search index directory")?;
+        let index = SearchIndex::open_or_create(SearchIndexConfig::new(&search_dir))
+            .context("Failed to open search index")?;
+        let indexer =
+            Arc::new(SearchIndexer::new(&index).context("Failed to create search indexer")?);
+        let updater = Bm25IndexUpdater::new(indexer, storage.clone());
+        let result = rebuild_bm25_index(storage.clone(), &updater, &config, &progress)
+            .map_err(|e| anyhow::anyhow!("BM25 rebuild failed: {}", e))?;
+        info!(
+            toc_nodes = result.toc_nodes_indexed,
+            grips = result.grips_indexed,
+            errors = result.errors,
+            "BM25 index built"
+        );
+        built.bm25_built = true;
+    }
+
+    let Some(embedder) = embedder else {
+        return Ok(built);
+    };
+    let vector_dir = db_path.join("vector");
+    let metadata_path = vector_dir.join("metadata");
+    let vector_empty = !metadata_path.exists()
+        || VectorMetadata::open(&metadata_path)
+            .and_then(|metadata| metadata.count())
+            .map(|count| count == 0)
+            .unwrap_or(false);
+    if vector_empty {
+        info!(path = ?vector_dir, "Vector index missing, building from storage");
+        fs::create_dir_all(&metadata_path).context("Failed to create vector index directory")?;
+        let hnsw_config = vector_hnsw_config(vector, embedder.info().dimension, &vector_dir)?;
+        let hnsw_index = Arc::new(RwLock::new(
+            HnswIndex::open_or_create(hnsw_config).context("Failed to open HNSW index")?,
+        ));
+        let metadata = Arc::new(
+            VectorMetadata::open(&metadata_path).context("Failed to open vector metadata")?,
+        );
+        let updater = VectorIndexUpdater::new(hnsw_index, embedder, metadata, storage.clone());
+        let result = rebuild_vector_index(storage.clone(), &updater, &config, &progress)
+            .map_err(|e| anyhow::anyhow!("Vector rebuild failed: {}", e))?;
+        info!(
+            toc_nodes = result.toc_nodes_indexed,
+            grips = result.grips_indexed,
+            skipped = result.skipped,
+            errors = result.errors,
+            "Vector index built"
+        );
+        built.vector_built = true;
+    }
+
+    Ok(built)
+}
+
 /// Register the indexing job if search indexes are available.
 ///
 /// This function attempts to:
@@ -113,12 +220,17 @@ fn is_process_running(_pid: u32) -> bool {
 /// 2. Create an indexing pipeline with the BM25 updater
 /// 3. Register the pipeline with the scheduler
 ///
+/// When `index_raw_events` is set, the BM25 updater also indexes the text
+/// of each ingested event.
+///
 /// If the search index doesn't exist, returns an error. Users should
 /// run `rebuild-indexes` first to initialize the search index.
 async fn register_indexing_job(
     scheduler: &SchedulerService,
     storage: Arc<Storage>,
     db_path: &Path,
+    index_raw_events: bool,
+    summary_trigger: Option<memory_indexing::SummaryTrigger>,
 ) -> Result<()> {
     use memory_indexing::{Bm25IndexUpdater, IndexingPipeline, PipelineConfig};
     use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
@@ -137,7 +249,8 @@ async fn register_indexing_job(
         Arc::new(SearchIndexer::new(&search_index).context("Failed to create search indexer")?);
 
     // Create BM25 updater
-    let bm25_updater = Bm25IndexUpdater::new(indexer, storage.clone());
+    let bm25_updater =
+        Bm25IndexUpdater::new(indexer, storage.clone()).with_event_indexing(index_raw_events);
 
     // Create indexing pipeline with BM25 updater
     let mut pipeline = IndexingPipeline::new(storage.clone(), PipelineConfig::default());
@@ -145,6 +258,9 @@ async fn register_indexing_job(
     pipeline
         .load_checkpoints()
         .context("Failed to load indexing checkpoints")?;
+    if let Some(trigger) = summary_trigger {
+        pipeline.set_summary_trigger(trigger);
+    }
 
     let pipeline = Arc::new(tokio::sync::Mutex::new(pipeline));
 
@@ -157,16 +273,151 @@ async fn register_indexing_job(
     Ok(())
 }
 
+/// Link new TOC segments to existing topics each time `signals` fires.
+///
+/// Runs between scheduled topic extractions so recent conversations join
+/// the topics they belong to without waiting for the next full pass. Only
+/// segments created since the previous pass are embedded; a failed pass
+/// keeps its segments for the next one.
+fn spawn_topic_refresh(
+    storage: Arc<Storage>,
+    embedder: Arc<CandleEmbedder>,
+    extraction: memory_topics::config::ExtractionConfig,
+    mut signals: memory_indexing::SummarySignals,
+) {
+    storage.track_new_segments();
+    let extraction = Arc::new(extraction);
+    tokio::spawn(async move {
+        let mut node_ids = Vec::new();
+        while let Some(summaries) = signals.recv().await {
+            node_ids.extend(storage.take_new_segment_ids());
+            let batch = std::mem::take(&mut node_ids);
+            let storage = storage.clone();
+            let embedder = embedder.clone();
+            let extraction = extraction.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let linked =
+                    assign_new_nodes_to_topics(&storage, embedder.as_ref(), &extraction, &batch);
+                (linked, batch)
+            })
+            .await;
+            match result {
+                Ok((Ok(linked), _)) => {
+                    info!(summaries, linked, "Incremental topic assignment complete");
+                }
+                Ok((Err(e), batch)) => {
+                    warn!("Incremental topic assignment failed: {:#}", e);
+                    node_ids = batch;
+                }
+                Err(e) => warn!("Incremental topic assignment panicked: {}", e),
+            }
+        }
+    });
+}
+
+/// Link the given segments to existing topics, returning the number of
+/// links written. Segments no longer in storage are skipped.
+fn assign_new_nodes_to_topics(
+    storage: &Arc<Storage>,
+    embedder: &CandleEmbedder,
+    extraction: &memory_topics::config::ExtractionConfig,
+    node_ids: &[String],
+) -> Result<usize> {
+    use memory_embeddings::EmbeddingModel;
+    use memory_topics::extraction::NodeEmbedding;
+    use memory_topics::{TopicExtractor, TopicStorage};
+
+    if node_ids.is_empty() {
+        return Ok(0);
+    }
+    let topic_storage = TopicStorage::new(storage.clone());
+    let topics = topic_storage.list_topics()?;
+    if topics.is_empty() {
+        return Ok(0);
+    }
+
+    let mut embedded = Vec::with_capacity(node_ids.len());
+    for node_id in node_ids {
+        let Some(node) = storage.get_toc_node(node_id)? else {
+            continue;
+        };
+        let mut parts = vec![node.title.clone()];
+        parts.extend(node.bullets.iter().map(|b| b.text.clone()));
+        let summary = parts.join(". ");
+        let embedding = embedder
+            .embed(&summary)
+            .context("Failed to embed TOC node")?;
+        embedded.push(NodeEmbedding {
+            node_id: node.node_id,
+            embedding: embedding.values,
+            summary,
+        });
+    }
+
+    let extractor = TopicExtractor::new(extraction.clone());
+    let links = extractor.assign_to_existing(&embedded, &topics);
+    for link in &links {
+        topic_storage.save_link(link)?;
+    }
+    Ok(links.len())
+}
+
+/// Validate the `[lifecycle]` settings and build the prune job configs.
+fn lifecycle_configs(
+    lifecycle: &memory_types::LifecycleConfig,
+) -> Result<(
+    memory_search::Bm25LifecycleConfig,
+    memory_vector::VectorLifecycleConfig,
+)> {
+    let bm25 = memory_search::Bm25LifecycleConfig::from_settings(&lifecycle.bm25)
+        .map_err(anyhow::Error::msg)
+        .context("Invalid [lifecycle.bm25] settings")?;
+    let vector = memory_vector::VectorLifecycleConfig::from_settings(&lifecycle.vector)
+        .map_err(anyhow::Error::msg)
+        .context("Invalid [lifecycle.vector] settings")?;
+    Ok((bm25, vector))
+}
+
+/// Vector indexing pipeline configuration from the `[vector]` settings.
+fn vector_pipeline_config(vector: &memory_types::VectorSettings) -> memory_vector::PipelineConfig {
+    memory_vector::PipelineConfig {
+        embed_concurrency: vector.embed_concurrency,
+        ..Default::default()
+    }
+}
+
+/// Build the HNSW config for `vector_dir` from the `[vector]` settings.
+fn vector_hnsw_config(
+    vector: &memory_types::VectorSettings,
+    dimension: usize,
+    vector_dir: &Path,
+) -> Result<memory_vector::HnswConfig> {
+    memory_vector::HnswConfig::from_settings(dimension, vector_dir, vector)
+        .map_err(anyhow::Error::msg)
+        .context("Invalid [vector] settings")
+}
+
 /// Register lifecycle prune jobs if indexes are available.
 ///
 /// This function registers:
 /// 1. BM25 prune job - prunes old documents from Tantivy index
 /// 2. Vector prune job - prunes old vectors from HNSW index
 ///
-/// Both jobs use per-level retention configured in lifecycle settings.
-/// BM25 pruning is DISABLED by default (per PRD append-only philosophy).
-/// Vector pruning is ENABLED by default.
-async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Result<()> {
+/// Both jobs use per-level retention from the `[lifecycle]` settings, already
+/// validated into `bm25_lifecycle` and `vector_lifecycle` (see
+/// [`lifecycle_configs`]). BM25 pruning is DISABLED by default (per PRD
+/// append-only philosophy). Vector pruning is ENABLED by default, but is
+/// skipped when `embedder` is `None` (vector features disabled, see
+/// [`load_embedder`]).
+async fn register_prune_jobs(
+    scheduler: &SchedulerService,
+    db_path: &Path,
+    embedder: Option<Arc<CandleEmbedder>>,
+    bm25_lifecycle: memory_search::Bm25LifecycleConfig,
+    vector_lifecycle: memory_vector::VectorLifecycleConfig,
+    vector_prune_schedule: &str,
+    vector: &memory_types::VectorSettings,
+) -> Result<()> {
     use memory_embeddings::EmbeddingModel;
     use memory_scheduler::{
         register_bm25_prune_job, register_bm25_rebuild_job, register_vector_prune_job,
@@ -174,10 +425,7 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
         VectorPruneJobConfig,
     };
     use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
-    use memory_vector::{
-        HnswConfig, HnswIndex, PipelineConfig as VectorPipelineConfig, VectorIndexPipeline,
-        VectorMetadata,
-    };
+    use memory_vector::{HnswIndex, VectorIndexPipeline, VectorMetadata};
 
     let search_dir = db_path.join("search");
     let vector_dir = db_path.join("vector");
@@ -194,7 +442,10 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
                         // Create prune job with callback
                         let indexer_for_prune = Arc::clone(&indexer);
                         let bm25_job = Bm25PruneJob::with_prune_fn(
-                            Bm25PruneJobConfig::default(),
+                            Bm25PruneJobConfig {
+                                lifecycle: bm25_lifecycle,
+                                ..Default::default()
+                            },
                             move |age_days, level, dry_run| {
                                 let idx = Arc::clone(&indexer_for_prune);
                                 async move {
@@ -244,11 +495,10 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
 
     // Register vector prune job if vector index exists
     if vector_dir.exists() {
-        // Try to create embedder
-        match memory_embeddings::CandleEmbedder::load_default() {
-            Ok(embedder) => {
-                let embedder = Arc::new(embedder);
-                let hnsw_config = HnswConfig::new(embedder.info().dimension, &vector_dir);
+        match embedder {
+            Some(embedder) => {
+                let hnsw_config =
+                    vector_hnsw_config(vector, embedder.info().dimension, &vector_dir)?;
 
                 match HnswIndex::open_or_create(hnsw_config) {
                     Ok(hnsw_index) => {
@@ -264,16 +514,20 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
                                         embedder,
                                         hnsw_index,
                                         metadata,
-                                        VectorPipelineConfig::default(),
+                                        vector_pipeline_config(vector),
                                     ));
 
                                     // Create prune job with callback
                                     let vector_job = VectorPruneJob::with_prune_fn(
-                                        VectorPruneJobConfig::default(),
-                                        move |age_days, level| {
+                                        VectorPruneJobConfig {
+                                            cron_schedule: vector_prune_schedule.to_string(),
+                                            lifecycle: vector_lifecycle,
+                                            ..Default::default()
+                                        },
+                                        move |age_days, level, dry_run| {
                                             let p = Arc::clone(&pipeline);
                                             async move {
-                                                p.prune_level(age_days, level.as_deref())
+                                                p.prune_level(age_days, level.as_deref(), dry_run)
                                                     .map_err(|e| e.to_string())
                                             }
                                         },
@@ -300,8 +554,8 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
                     }
                 }
             }
-            Err(e) => {
-                warn!(error = %e, "Failed to load embedder for vector prune job");
+            None => {
+                info!("Vector features disabled, skipping vector prune job registration");
             }
         }
     } else {
@@ -313,7 +567,7 @@ async fn register_prune_jobs(scheduler: &SchedulerService, db_path: &Path) -> Re
 
 /// Which summarizer the daemon will construct for a given settings block.
 ///
-/// Separated from [`build_summarizer`] so the decision logic can be unit-tested
+/// Separated from [`build_api_summarizer`] so the decision logic can be unit-tested
 /// without constructing an HTTP client or mutating process-global env vars.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum SummarizerKind {
@@ -343,7 +597,7 @@ fn default_env_var_for_provider(provider: &str) -> &'static str {
 
 /// Pure decision: which summarizer kind should we use given settings + key availability?
 ///
-/// Tests should prefer this over [`build_summarizer`] because it avoids env-var
+/// Tests should prefer this over [`build_api_summarizer`] because it avoids env-var
 /// mutation and HTTP-client construction, making provider-selection assertions
 /// directly observable.
 pub(crate) fn pick_summarizer_kind(
@@ -388,14 +642,16 @@ pub(crate) fn env_var_for_provider(settings: &SummarizerSettings) -> String {
         .unwrap_or_else(|| default_env_var_for_provider(&settings.provider).to_string())
 }
 
-/// Build a [`memory_toc::summarizer::Summarizer`] from `SummarizerSettings`.
+/// Build the [`ApiSummarizer`] described by `SummarizerSettings`.
 ///
-/// Falls back to [`MockSummarizer`] with a `warn!` when no API key is found
-/// or when API client construction fails. An unrecognized provider produces
+/// Returns None, meaning the caller should use [`MockSummarizer`], with a
+/// `warn!` when no API key is found or when the HTTP client can't be built.
+/// The concrete type is returned so rollups can skip the boxed futures of
+/// `dyn Summarizer`. An unrecognized provider produces
 /// a warning and is treated as OpenAI (preserving fail-open behavior).
-pub(crate) fn build_summarizer(
-    settings: &SummarizerSettings,
-) -> Arc<dyn memory_toc::summarizer::Summarizer> {
+/// Custom prompt templates missing their required placeholder are an error,
+/// with or without an API key, so a typo can't silently disable summaries.
+pub(crate) fn build_api_summarizer(settings: &SummarizerSettings) -> Result<Option<ApiSummarizer>> {
     if !is_anthropic(&settings.provider) && !is_openai(&settings.provider) {
         warn!(
             provider = %settings.provider,
@@ -405,23 +661,28 @@ pub(crate) fn build_summarizer(
 
     let api_key = resolve_api_key(settings);
     let kind = pick_summarizer_kind(settings, api_key.is_some());
+    let key = api_key.unwrap_or_default();
 
-    let Some(key) = api_key else {
+    let mut config = if is_anthropic(&settings.provider) {
+        ApiSummarizerConfig::claude(key, &settings.model)
+    } else {
+        ApiSummarizerConfig::openai(key, &settings.model)
+    };
+    config.prompt_template = settings.prompt_template.clone();
+    config.rollup_prompt_template = settings.rollup_prompt_template.clone();
+    config
+        .validate()
+        .context("Invalid [summarizer] prompt template")?;
+
+    if kind == SummarizerKind::Mock {
         let env_var = env_var_for_provider(settings);
         warn!(
             env_var = %env_var,
             kind = ?kind,
             "No API key found for summarizer, using mock"
         );
-        return Arc::new(MockSummarizer::new());
-    };
-
-    let config = match kind {
-        SummarizerKind::Anthropic => ApiSummarizerConfig::claude(key, &settings.model),
-        SummarizerKind::OpenAi => ApiSummarizerConfig::openai(key, &settings.model),
-        // Mock branch is handled by the `let Some(key) = ...` early return above.
-        SummarizerKind::Mock => unreachable!("Mock kind handled before key match"),
-    };
+        return Ok(None);
+    }
 
     match ApiSummarizer::new(config) {
         Ok(s) => {
@@ -431,20 +692,109 @@ pub(crate) fn build_summarizer(
                 kind = ?kind,
                 "Using API summarizer"
             );
-            Arc::new(s)
+            Ok(Some(s))
         }
         Err(e) => {
             warn!(error = %e, "Failed to create ApiSummarizer, falling back to mock");
-            Arc::new(MockSummarizer::new())
+            Ok(None)
+        }
+    }
+}
+
+/// Load the embedding model once for every vector feature.
+///
+/// If the model can't be loaded (e.g. not downloaded yet), vector features
+/// are disabled in the returned [`DegradedMode`] and the daemon carries on
+/// with BM25, TOC and the scheduler.
+fn load_embedder<E: std::fmt::Display>(
+    load: impl FnOnce() -> Result<CandleEmbedder, E>,
+) -> (Option<Arc<CandleEmbedder>>, DegradedMode) {
+    let mut degraded = DegradedMode::new();
+    match load() {
+        Ok(embedder) => (Some(Arc::new(embedder)), degraded),
+        Err(e) => {
+            warn!("Embedding model unavailable, vector features disabled: {e}");
+            degraded.disable_vector(format!("embedding model failed to load: {e}"));
+            (None, degraded)
+        }
+    }
+}
+
+/// Run a warm-up step, logging how long it took.
+///
+/// Warm-up failures are logged and otherwise ignored; the first real query
+/// will simply pay the lazy-load cost instead.
+fn log_warm_up<E: std::fmt::Display>(name: &str, warm_up: impl FnOnce() -> Result<(), E>) {
+    let start = Instant::now();
+    match warm_up() {
+        Ok(()) => info!(
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "{} warmed up", name
+        ),
+        Err(e) => warn!("{} warm-up failed: {}", name, e),
+    }
+}
+
+/// Open the BM25 searcher the server will use, if the index exists, and
+/// warm up its reader.
+fn open_search_searcher(db_path: &Path) -> Option<Arc<memory_search::TeleportSearcher>> {
+    use memory_search::{SearchIndex, SearchIndexConfig, TeleportSearcher};
+
+    let search_dir = db_path.join("search");
+    if !search_dir.exists() {
+        return None;
+    }
+    let searcher = SearchIndex::open_or_create(SearchIndexConfig::new(&search_dir))
+        .and_then(|index| TeleportSearcher::new(&index));
+    match searcher {
+        Ok(searcher) => {
+            log_warm_up("BM25 index", || searcher.warm_up());
+            Some(Arc::new(searcher))
+        }
+        Err(e) => {
+            warn!("BM25 index unavailable, search disabled: {}", e);
+            None
         }
     }
 }
 
+/// Build the daemon's tracing subscriber.
+///
+/// `RUST_LOG` takes precedence over `log_level`. The JSON format writes one
+/// object per line with the event's fields (including `message`) at the top
+/// level, alongside `level`, `target`, the current span and the span list.
+fn build_subscriber<W>(
+    log_level: &str,
+    log_format: LogFormat,
+    writer: W,
+) -> Box<dyn tracing::Subscriber + Send + Sync>
+where
+    W: for<'a> tracing_subscriber::fmt::MakeWriter<'a> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(log_level)),
+        )
+        .with_writer(writer);
+    match log_format {
+        LogFormat::Pretty => Box::new(builder.finish()),
+        LogFormat::Json => Box::new(
+            builder
+                .json()
+                .flatten_event(true)
+                .with_current_span(true)
+                .with_span_list(true)
+                .finish(),
+        ),
+    }
+}
+
 /// Start the memory daemon.
 ///
 /// 1. Load configuration (CFG-01: defaults -> file -> env -> CLI)
 /// 2. Open RocksDB storage
-/// 3. Create and start scheduler with rollup and compaction jobs
+/// 3. Create and start scheduler with rollup, version prune and compaction jobs
 /// 4. Start gRPC server with scheduler integration
 /// 5. Handle graceful shutdown on SIGINT/SIGTERM
 pub async fn start_daemon(
@@ -453,6 +803,7 @@ pub async fn start_daemon(
     port_override: Option<u16>,
     db_path_override: Option<&str>,
     log_level_override: Option<&str>,
+    log_format_override: Option<LogFormat>,
 ) -> Result<()> {
     // Load configuration (CFG-01)
     let mut settings = Settings::load(config_path).context("Failed to load configuration")?;
@@ -467,14 +818,41 @@ pub async fn start_daemon(
     if let Some(log_level) = log_level_override {
         settings.log_level = log_level.to_string();
     }
+    if let Some(log_format) = log_format_override {
+        settings.log_format = log_format;
+    }
+
+    // Topic settings live in memory-topics, so they load as their own section
+    let topics_config: memory_topics::TopicsConfig = Settings::load_section(config_path, "topics")
+        .context("Failed to load [topics] configuration")?
+        .unwrap_or_default();
+    topics_config
+        .validate()
+        .context("Invalid [topics] configuration")?;
+
+    // Retrieval fallback chains are owned by memory-retrieval too
+    let fallback_config: FallbackChainConfig =
+        Settings::load_section(config_path, "fallback_chain")
+            .context("Failed to load [fallback_chain] configuration")?
+            .unwrap_or_default();
+    fallback_config
+        .validate()
+        .map_err(anyhow::Error::msg)
+        .context("Invalid [fallback_chain] configuration")?;
+
+    // Compile redaction patterns up front so a bad regex fails fast
+    let redactor = Arc::new(
+        Redactor::new(&settings.redaction_patterns)
+            .context("Invalid redaction_patterns in configuration")?,
+    );
+    settings
+        .vector
+        .validate()
+        .map_err(anyhow::Error::msg)
+        .context("Invalid [vector] settings")?;
 
     // Initialize logging
-    let subscriber = tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&settings.log_level)),
-        )
-        .finish();
+    let subscriber = build_subscriber(&settings.log_level, settings.log_format, io::stdout);
     tracing::subscriber::set_global_default(subscriber)
         .context("Failed to set tracing subscriber")?;
 
@@ -483,6 +861,11 @@ pub async fn start_daemon(
     info!("  Database path: {}", settings.db_path);
     info!("  gRPC address: {}", settings.grpc_addr());
     info!("  Log level: {}", settings.log_level);
+    info!("  Log format: {}", settings.log_format.as_str());
+    info!(
+        "  Redaction patterns: {}",
+        settings.redaction_patterns.len()
+    );
 
     if !foreground {
         // TODO: Implement actual daemonization (double-fork on Unix)
@@ -500,26 +883,34 @@ pub async fn start_daemon(
         fs::create_dir_all(parent).context("Failed to create database directory")?;
     }
 
-    let storage = Storage::open(&db_path).context("Failed to open storage")?;
+    let storage_config = StorageConfig::default().with_block_cache_mb(settings.block_cache_mb);
+    let storage =
+        Storage::open_with_config(&db_path, storage_config).context("Failed to open storage")?;
     let storage = Arc::new(storage);
 
     // Create scheduler
     info!("Initializing scheduler...");
-    let scheduler = SchedulerService::new(SchedulerConfig::default())
+    let scheduler = SchedulerService::new(scheduler_config(&settings))
         .await
         .context("Failed to create scheduler")?;
 
-    // Create summarizer for rollup jobs
-    let summarizer = build_summarizer(&settings.summarizer);
-
-    // Register rollup jobs (day/week/month)
-    create_rollup_jobs(
-        &scheduler,
-        storage.clone(),
-        summarizer,
-        RollupJobConfig::default(),
-    )
-    .await
+    // Register rollup jobs (day/week/month) with the concrete summarizer
+    let rollup_config = RollupJobConfig::default();
+    match build_api_summarizer(&settings.summarizer)? {
+        Some(summarizer) => {
+            create_rollup_jobs(
+                &scheduler,
+                storage.clone(),
+                Arc::new(summarizer),
+                rollup_config,
+            )
+            .await
+        }
+        None => {
+            let summarizer = Arc::new(MockSummarizer::new());
+            create_rollup_jobs(&scheduler, storage.clone(), summarizer, rollup_config).await
+        }
+    }
     .context("Failed to register rollup jobs")?;
 
     // Register compaction job
@@ -527,16 +918,91 @@ pub async fn start_daemon(
         .await
         .context("Failed to register compaction job")?;
 
+    // Register TOC version prune job
+    let toc_versions = &settings.lifecycle.toc_versions;
+    if toc_versions.enabled {
+        create_toc_version_prune_job(
+            &scheduler,
+            storage.clone(),
+            TocVersionPruneJobConfig {
+                cron: toc_versions.prune_schedule.clone(),
+                keep_last: toc_versions.keep_last,
+                ..Default::default()
+            },
+        )
+        .await
+        .context("Failed to register TOC version prune job")?;
+    }
+
+    // Load the embedder once; without it vector features run degraded
+    let (embedder, degraded_mode) = load_embedder(CandleEmbedder::load_default);
+    if let Some(embedder) = &embedder {
+        log_warm_up("Embedding model", || embedder.warm_up());
+    }
+
+    // Build indexes on a fresh database so search works without a manual
+    // rebuild. The rebuild is synchronous, so run it on the blocking pool;
+    // it is awaited because the indexing job below needs the index on disk.
+    if settings.auto_rebuild_on_start {
+        let storage = storage.clone();
+        let db_path = db_path.clone();
+        let embedder = embedder.clone();
+        let vector = settings.vector.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            ensure_indexes(storage, &db_path, embedder, &vector)
+        })
+        .await;
+        match result {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => warn!("Initial index build failed: {:#}", e),
+            Err(e) => warn!("Initial index build panicked: {}", e),
+        }
+    }
+
+    // Link new segments to existing topics every N summaries
+    let summary_trigger = match (&embedder, settings.topic_refresh_threshold) {
+        (Some(embedder), threshold) if threshold > 0 => {
+            let (trigger, signals) = memory_indexing::SummaryTrigger::channel(threshold);
+            spawn_topic_refresh(
+                storage.clone(),
+                embedder.clone(),
+                topics_config.extraction.clone(),
+                signals,
+            );
+            Some(trigger)
+        }
+        _ => None,
+    };
+
     // Register indexing job if search index exists
     // The indexing pipeline processes outbox entries into search indexes
-    if let Err(e) = register_indexing_job(&scheduler, storage.clone(), &db_path).await {
+    if let Err(e) = register_indexing_job(
+        &scheduler,
+        storage.clone(),
+        &db_path,
+        settings.index_raw_events,
+        summary_trigger,
+    )
+    .await
+    {
         warn!("Indexing job not registered: {}", e);
         info!("Run 'rebuild-indexes' to initialize the search index");
     }
 
     // Register lifecycle prune jobs if indexes exist
     // These jobs prune old documents/vectors based on per-level retention policies
-    if let Err(e) = register_prune_jobs(&scheduler, &db_path).await {
+    let (bm25_lifecycle, vector_lifecycle) = lifecycle_configs(&settings.lifecycle)?;
+    if let Err(e) = register_prune_jobs(
+        &scheduler,
+        &db_path,
+        embedder.clone(),
+        bm25_lifecycle,
+        vector_lifecycle,
+        &settings.lifecycle.vector.prune_schedule,
+        &settings.vector,
+    )
+    .await
+    {
         warn!("Prune jobs not fully registered: {}", e);
     }
 
@@ -545,11 +1011,15 @@ pub async fn start_daemon(
         scheduler.registry().job_count()
     );
 
+    // Open the server's BM25 searcher, faulting in its index so the first
+    // query after startup isn't slow
+    let teleport_searcher = open_search_searcher(&db_path);
+
     // Create NoveltyChecker for dedup gate (DEDUP-02, DEDUP-03)
     let novelty_checker = if settings.dedup.enabled {
-        match memory_embeddings::CandleEmbedder::load_default() {
-            Ok(embedder) => {
-                let adapter = Arc::new(CandleEmbedderAdapter::new(embedder))
+        match embedder {
+            Some(embedder) => {
+                let adapter = Arc::new(CandleEmbedderAdapter::from_shared(embedder))
                     as Arc<dyn memory_service::novelty::EmbedderTrait>;
                 let buffer = Arc::new(RwLock::new(InFlightBuffer::new(
                     settings.dedup.buffer_capacity,
@@ -559,10 +1029,11 @@ pub async fn start_daemon(
                 // Try to open HNSW index for cross-session dedup (DEDUP-02)
                 let vector_dir = PathBuf::from(&settings.db_path).join("vector");
                 let hnsw_opt = if vector_dir.exists() {
-                    let hnsw_config = memory_vector::HnswConfig::new(384, &vector_dir);
+                    let hnsw_config = vector_hnsw_config(&settings.vector, 384, &vector_dir)?;
                     match memory_vector::HnswIndex::open_or_create(hnsw_config) {
                         Ok(hnsw) => {
                             info!("HNSW index loaded for cross-session dedup");
+                            log_warm_up("HNSW index", || hnsw.warm_up());
                             Some(Arc::new(std::sync::RwLock::new(hnsw)))
                         }
                         Err(e) => {
@@ -597,8 +1068,8 @@ pub async fn start_daemon(
                 );
                 Some(Arc::new(checker))
             }
-            Err(e) => {
-                warn!("Failed to load CandleEmbedder for dedup, disabling: {e}");
+            None => {
+                warn!("Embedding model unavailable, dedup gate disabled");
                 None
             }
         }
@@ -607,6 +1078,22 @@ pub async fn start_daemon(
         None
     };
 
+    // Reload the dedup buffer saved before the last shutdown and keep saving it
+    let persisted_checker = novelty_checker
+        .clone()
+        .filter(|_| settings.dedup.persist_interval_secs > 0);
+    if let Some(ref checker) = persisted_checker {
+        match checker.load_state(&storage) {
+            Ok(restored) => info!(restored, "Restored dedup buffer"),
+            Err(e) => warn!("Failed to restore dedup buffer: {}", e),
+        }
+        spawn_novelty_persist(
+            storage.clone(),
+            checker.clone(),
+            Duration::from_secs(settings.dedup.persist_interval_secs),
+        );
+    }
+
     // Write PID file
     write_pid_file()?;
 
@@ -617,7 +1104,8 @@ pub async fn start_daemon(
         .context("Invalid gRPC address")?;
 
     // Create shutdown signal handler
-    let shutdown_signal = async {
+    let shutdown_timeout_secs = settings.shutdown_timeout_secs;
+    let shutdown_signal = async move {
         let ctrl_c = async {
             signal::ctrl_c()
                 .await
@@ -637,10 +1125,10 @@ pub async fn start_daemon(
 
         tokio::select! {
             _ = ctrl_c => {
-                info!("Received Ctrl+C, shutting down...");
+                info!(timeout_secs = shutdown_timeout_secs, "Received Ctrl+C, shutting down...");
             }
             _ = terminate => {
-                info!("Received SIGTERM, shutting down...");
+                info!(timeout_secs = shutdown_timeout_secs, "Received SIGTERM, shutting down...");
             }
         }
     };
@@ -655,20 +1143,77 @@ pub async fn start_daemon(
     // Start server with scheduler
     let result = run_server_with_scheduler(
         addr,
-        storage,
+        storage.clone(),
         scheduler,
         shutdown_signal,
         novelty_checker,
+        teleport_searcher,
         settings.staleness.clone(),
+        degraded_mode,
+        settings.outbox_lag_warn_threshold,
+        settings.ingest_rate_limit_per_sec,
+        settings.max_route_limit,
+        fallback_config,
+        Some(redactor),
+        hybrid_cache_config(&settings),
+        drain_timeout(&settings),
     )
     .await;
 
     // Cleanup
+    if let Some(ref checker) = persisted_checker {
+        save_novelty_state(&storage, checker);
+    }
     remove_pid_file();
 
     result.map_err(|e| anyhow::anyhow!("Server error: {}", e))
 }
 
+/// HybridSearch result cache configuration from `[hybrid_cache]`.
+fn hybrid_cache_config(settings: &Settings) -> HybridCacheConfig {
+    HybridCacheConfig::new(
+        settings.hybrid_cache.capacity,
+        Duration::from_secs(settings.hybrid_cache.ttl_secs),
+    )
+}
+
+/// Scheduler configuration for the daemon, sharing the shutdown grace
+/// period with the server.
+fn scheduler_config(settings: &Settings) -> SchedulerConfig {
+    SchedulerConfig {
+        default_timezone: settings.scheduler.default_timezone.clone(),
+        shutdown_timeout_secs: settings.shutdown_timeout_secs,
+        max_concurrent_jobs: settings.scheduler.max_concurrent_jobs,
+    }
+}
+
+/// How long the server waits for in-flight requests after a shutdown signal.
+fn drain_timeout(settings: &Settings) -> Duration {
+    Duration::from_secs(settings.shutdown_timeout_secs)
+}
+
+/// Save the dedup buffer every `interval` so a crash loses little of it.
+fn spawn_novelty_persist(storage: Arc<Storage>, checker: Arc<NoveltyChecker>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately; nothing new to save yet
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            save_novelty_state(&storage, &checker);
+        }
+    });
+}
+
+/// Save the dedup buffer if it changed, logging instead of failing.
+fn save_novelty_state(storage: &Storage, checker: &NoveltyChecker) {
+    match checker.save_state(storage) {
+        Ok(Some(saved)) => tracing::debug!(saved, "Saved dedup buffer"),
+        Ok(None) => tracing::trace!("Dedup buffer unchanged, not saved"),
+        Err(e) => warn!("Failed to save dedup buffer: {}", e),
+    }
+}
+
 /// Stop the running daemon by sending SIGTERM.
 pub fn stop_daemon() -> Result<()> {
     let pid = read_pid_file().context("No PID file found - daemon may not be running")?;
@@ -816,7 +1361,7 @@ pub async fn handle_query(endpoint: &str, command: QueryCommands) -> Result<()>
             } else {
                 println!("Root TOC Nodes ({} found):\n", nodes.len());
                 for node in nodes {
-                    let level = level_to_string(node.level);
+                    let level = cli_label::<ProtoTocLevel>(node.level);
                     println!("  {} [{}]", node.title, level);
                     println!("    ID: {}", node.node_id);
                     println!("    Children: {}", node.child_node_ids.len());
@@ -840,6 +1385,50 @@ pub async fn handle_query(endpoint: &str, command: QueryCommands) -> Result<()>
             }
         }
 
+        QueryCommands::Subtree { node_id, depth } => {
+            let result = client
+                .get_subtree(&node_id, depth)
+                .await
+                .context("Failed to get subtree")?;
+            print!("{}", format_subtree(&node_id, &result));
+        }
+
+        QueryCommands::NodeHistory { node_id } => {
+            let changes = client
+                .get_node_history(&node_id)
+                .await
+                .context("Failed to get node history")?;
+            print!("{}", format_node_history(&node_id, &changes));
+        }
+
+        QueryCommands::Similar {
+            node_id,
+            top_k,
+            min_score,
+        } => {
+            let response = client
+                .find_similar_nodes(&node_id, top_k, min_score)
+                .await
+                .context("Failed to find similar nodes")?;
+
+            if response.matches.is_empty() {
+                println!("No similar nodes found for: {}", node_id);
+            } else {
+                println!(
+                    "Nodes similar to {} ({} found):\n",
+                    node_id,
+                    response.matches.len()
+                );
+                for (i, m) in response.matches.iter().enumerate() {
+                    println!("{}. {} (score: {:.4})", i + 1, m.doc_id, m.score);
+                    println!("   {}", truncate_text(&m.text_preview, 80));
+                }
+            }
+            if response.embedded_on_the_fly {
+                println!("\n(node has no stored vector; embedded its text for this search)");
+            }
+        }
+
         QueryCommands::Browse {
             parent_id,
             limit,
@@ -859,7 +1448,7 @@ pub async fn handle_query(endpoint: &str, command: QueryCommands) -> Result<()>
                     result.children.len()
                 );
                 for child in result.children {
-                    let level = level_to_string(child.level);
+                    let level = cli_label::<ProtoTocLevel>(child.level);
                     println!("  {} [{}]", child.title, level);
                     println!("    ID: {}", child.node_id);
                 }
@@ -872,9 +1461,22 @@ pub async fn handle_query(endpoint: &str, command: QueryCommands) -> Result<()>
             }
         }
 
-        QueryCommands::Events { from, to, limit } => {
+        QueryCommands::Events {
+            from,
+            to,
+            limit,
+            types,
+        } => {
+            let event_types = types
+                .iter()
+                .map(|name| {
+                    ProtoEventType::from_name(name)
+                        .map(|t| t as i32)
+                        .ok_or_else(|| anyhow::anyhow!("Unknown event type: {}", name))
+                })
+                .collect::<Result<Vec<_>>>()?;
             let result = client
-                .get_events(from, to, limit)
+                .get_events_of_types(from, to, limit, event_types)
                 .await
                 .context("Failed to get events")?;
 
@@ -960,19 +1562,74 @@ pub async fn handle_query(endpoint: &str, command: QueryCommands) -> Result<()>
     Ok(())
 }
 
-fn level_to_string(level: i32) -> &'static str {
-    match level {
-        l if l == ProtoTocLevel::Year as i32 => "Year",
-        l if l == ProtoTocLevel::Month as i32 => "Month",
-        l if l == ProtoTocLevel::Week as i32 => "Week",
-        l if l == ProtoTocLevel::Day as i32 => "Day",
-        l if l == ProtoTocLevel::Segment as i32 => "Segment",
-        _ => "Unknown",
+/// CLI label for a raw proto enum value.
+///
+/// Uses the canonical [`ProtoEnumName`] name with its first letter
+/// capitalized ("Year", "Time-boxed", "Unknown"), keeping the casing the
+/// CLI has always printed; the BM25 layer stays "BM25".
+fn cli_label<P: ProtoEnumName>(value: i32) -> String {
+    let name = P::name_of(value);
+    if name == "bm25" {
+        return "BM25".to_string();
     }
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Format a subtree as an indented tree, one node per line.
+fn format_subtree(root_id: &str, result: &memory_client::GetSubtreeResult) -> String {
+    let mut out = format!("Subtree of {} ({} nodes):\n\n", root_id, result.nodes.len());
+    for entry in &result.nodes {
+        let Some(node) = &entry.node else { continue };
+        out.push_str(&format!(
+            "{}{} [{}]  {}\n",
+            "  ".repeat(entry.depth as usize + 1),
+            node.title,
+            cli_label::<ProtoTocLevel>(node.level),
+            node.node_id
+        ));
+    }
+    if result.truncated {
+        out.push_str("\nNode limit reached; use a smaller --depth or a deeper root.\n");
+    }
+    out
+}
+
+/// Format a node's version history, one block per version.
+fn format_node_history(node_id: &str, changes: &[memory_client::TocNodeChange]) -> String {
+    let mut out = format!("History of {} ({} versions):\n", node_id, changes.len());
+    for change in changes {
+        out.push_str(&format!(
+            "\nv{}  {}  {}\n",
+            change.version,
+            format_timestamp(change.created_at_ms),
+            change.title
+        ));
+        if let Some(previous) = &change.previous_title {
+            out.push_str(&format!("  title: {} -> {}\n", previous, change.title));
+        }
+        let lists = [
+            ("+ bullet", &change.bullets_added),
+            ("- bullet", &change.bullets_removed),
+            ("+ keyword", &change.keywords_added),
+            ("- keyword", &change.keywords_removed),
+            ("+ child", &change.children_added),
+            ("- child", &change.children_removed),
+        ];
+        for (label, items) in lists {
+            for item in items {
+                out.push_str(&format!("  {}: {}\n", label, item));
+            }
+        }
+    }
+    out
 }
 
 fn print_node_details(node: &memory_service::pb::TocNode) {
-    let level = level_to_string(node.level);
+    let level = cli_label::<ProtoTocLevel>(node.level);
     println!("TOC Node: {}", node.title);
     println!("  ID: {}", node.node_id);
     println!("  Level: {}", level);
@@ -1075,13 +1732,7 @@ async fn handle_search(
             println!("No matches found.");
         } else {
             for (i, m) in resp.matches.iter().enumerate() {
-                let field_name = match ProtoSearchField::try_from(m.field) {
-                    Ok(ProtoSearchField::Title) => "title",
-                    Ok(ProtoSearchField::Summary) => "summary",
-                    Ok(ProtoSearchField::Bullets) => "bullets",
-                    Ok(ProtoSearchField::Keywords) => "keywords",
-                    _ => "unknown",
-                };
+                let field_name = ProtoSearchField::name_of(m.field);
                 println!("{}. [{}] score={:.2}", i + 1, field_name, m.score);
                 println!("   Text: {}", truncate_text(&m.text, 100));
                 if !m.grip_ids.is_empty() {
@@ -1129,13 +1780,7 @@ async fn handle_search(
                 println!("  Title: {}", result.title);
                 println!("  Matches:");
                 for m in result.matches.iter().take(3) {
-                    let field_name = match ProtoSearchField::try_from(m.field) {
-                        Ok(ProtoSearchField::Title) => "title",
-                        Ok(ProtoSearchField::Summary) => "summary",
-                        Ok(ProtoSearchField::Bullets) => "bullets",
-                        Ok(ProtoSearchField::Keywords) => "keywords",
-                        _ => "unknown",
-                    };
+                    let field_name = ProtoSearchField::name_of(m.field);
                     println!("    - [{}] {}", field_name, truncate_text(&m.text, 80));
                 }
                 if result.matches.len() > 3 {
@@ -1158,9 +1803,25 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
     let db_path = db_path.unwrap_or_else(|| settings.db_path.clone());
     let expanded_path = shellexpand::tilde(&db_path).to_string();
 
-    // Open storage directly (not via gRPC)
-    let storage = Storage::open(std::path::Path::new(&expanded_path))
-        .context(format!("Failed to open storage at {}", expanded_path))?;
+    // Open storage directly (not via gRPC). Inspection-only commands open
+    // read-only so they can run while the daemon holds the database.
+    let read_only = matches!(
+        command,
+        AdminCommands::Stats
+            | AdminCommands::IndexStats { .. }
+            | AdminCommands::IndexCheck { .. }
+            | AdminCommands::Outbox { .. }
+            | AdminCommands::SegmentPreview { .. }
+            | AdminCommands::GripAudit
+            | AdminCommands::VerifyConsistency { repair: false, .. }
+    );
+    let path = std::path::Path::new(&expanded_path);
+    let storage = if read_only {
+        Storage::open_read_only(path)
+    } else {
+        Storage::open(path)
+    }
+    .context(format!("Failed to open storage at {}", expanded_path))?;
     let storage = Arc::new(storage);
 
     match command {
@@ -1177,22 +1838,35 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
             println!("Outbox:       {:>10}", stats.outbox_count);
             println!();
             println!("Disk Usage:   {:>10}", format_bytes(stats.disk_usage_bytes));
+            println!();
+            println!("Column Families (estimated):");
+            for cf in &stats.cf_sizes {
+                println!(
+                    "  {:<16} {:>10}  (sst {}, memtable {})",
+                    cf.name,
+                    format_bytes(cf.estimated_bytes()),
+                    format_bytes(cf.sst_bytes),
+                    format_bytes(cf.memtable_bytes)
+                );
+            }
         }
 
-        AdminCommands::Compact { cf } => match cf {
-            Some(cf_name) => {
-                println!("Compacting column family: {}", cf_name);
-                storage
-                    .compact_cf(&cf_name)
-                    .context(format!("Failed to compact {}", cf_name))?;
-                println!("Compaction complete.");
-            }
-            None => {
-                println!("Compacting all column families...");
-                storage.compact().context("Failed to compact")?;
-                println!("Compaction complete.");
-            }
-        },
+        AdminCommands::Compact { cf } => {
+            let report = match cf {
+                Some(cf_name) => {
+                    println!("Compacting column family: {}", cf_name);
+                    storage
+                        .compact_cf(&cf_name)
+                        .context(format!("Failed to compact {}", cf_name))?
+                }
+                None => {
+                    println!("Compacting all column families...");
+                    storage.compact().context("Failed to compact")?
+                }
+            };
+            println!("Compaction complete.");
+            print!("{}", format_compaction_report(&report));
+        }
 
         AdminCommands::RebuildToc { from_date, dry_run } => {
             if dry_run {
@@ -1200,15 +1874,7 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
                 println!();
             }
 
-            let from_timestamp = if let Some(date_str) = from_date {
-                // Parse YYYY-MM-DD date
-                let date = chrono::NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
-                    .context(format!("Invalid date format: {}. Use YYYY-MM-DD", date_str))?;
-                let datetime = date.and_hms_opt(0, 0, 0).unwrap();
-                Some(chrono::Utc.from_utc_datetime(&datetime).timestamp_millis())
-            } else {
-                None
-            };
+            let from_timestamp = from_date.as_deref().map(parse_day_start_ms).transpose()?;
 
             // Get events to process
             let start_ms = from_timestamp.unwrap_or(0);
@@ -1239,30 +1905,90 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
                 println!();
                 println!("To actually rebuild, run without --dry-run");
             } else {
-                // TODO: Full TOC rebuild would require integrating with memory-toc
-                // For now, just report what would be done
+                // Mock summaries would overwrite the real ones on every node
+                let Some(summarizer) = build_api_summarizer(&settings.summarizer)? else {
+                    anyhow::bail!(
+                        "TOC rebuild needs a configured summarizer API key; \
+                         refusing to replace summaries with placeholders"
+                    );
+                };
+                let rebuild_id = format!("rebuild-{}", ulid::Ulid::new());
+
+                // handle_admin is synchronous but runs inside the CLI's runtime
+                let rebuilt = tokio::task::block_in_place(|| {
+                    tokio::runtime::Handle::current().block_on(rebuild_toc(
+                        storage.clone(),
+                        Arc::new(summarizer),
+                        start_ms,
+                        end_ms,
+                        &rebuild_id,
+                    ))
+                })?;
+
                 println!();
-                println!("TOC rebuild not yet fully implemented.");
-                println!("This would require re-running segmentation and summarization.");
-                println!("Events are intact and can be manually processed.");
+                println!("Rebuilt {} segments (rebuild id: {})", rebuilt, rebuild_id);
+                println!("Segment nodes were overwritten and their grips replaced.");
             }
         }
 
-        AdminCommands::RebuildIndexes {
-            index,
-            batch_size,
-            force,
-            search_path,
-            vector_path,
+        AdminCommands::SegmentPreview {
+            from,
+            to,
+            time_threshold_mins,
+            token_threshold,
+            max_segment_tokens,
+        } => {
+            let start_ms = parse_day_start_ms(&from)?;
+            // --to is inclusive: run through the end of that day
+            let end_ms = parse_day_start_ms(&to)? + 24 * 60 * 60 * 1000;
+            if end_ms <= start_ms {
+                anyhow::bail!("--to ({}) is before --from ({})", to, from);
+            }
+
+            let mut config = memory_toc::SegmentationConfig::default();
+            if let Some(mins) = time_threshold_mins {
+                config.time_threshold_ms = mins * 60 * 1000;
+            }
+            if let Some(tokens) = token_threshold {
+                config.token_threshold = tokens;
+            }
+            if let Some(tokens) = max_segment_tokens {
+                config.max_segment_tokens = tokens;
+            }
+
+            println!(
+                "Segmentation: {} min gap, {} token threshold, {} token cap",
+                config.time_threshold_ms / 60_000,
+                config.token_threshold,
+                config.max_segment_tokens
+            );
+            let segments = preview_segments(&storage, start_ms, end_ms, config)?;
+            print!("{}", format_segment_preview(&segments));
+        }
+
+        AdminCommands::RebuildIndexes {
+            index,
+            batch_size,
+            force,
+            search_path,
+            vector_path,
+            profile,
+            from_sequence,
+            since,
         } => {
             handle_rebuild_indexes(
                 storage,
                 &expanded_path,
+                &settings.vector,
                 &index,
                 batch_size,
                 force,
                 search_path,
                 vector_path,
+                profile,
+                from_sequence,
+                since,
+                settings.index_raw_events,
             )?;
         }
 
@@ -1273,6 +1999,63 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
             handle_index_stats(&expanded_path, search_path, vector_path)?;
         }
 
+        AdminCommands::IndexCheck {
+            index,
+            search_path,
+            vector_path,
+        } => {
+            handle_index_check(&expanded_path, &index, search_path, vector_path)?;
+        }
+
+        AdminCommands::Outbox { command } => match command {
+            OutboxCommands::List { limit, from_seq } => {
+                let entries = storage
+                    .get_outbox_entries(from_seq, limit)
+                    .context("Failed to read outbox")?;
+                print!("{}", format_outbox_entries(&entries));
+            }
+            OutboxCommands::Stats => {
+                let lag = storage.outbox_lag().context("Failed to read outbox")?;
+                let range = storage
+                    .outbox_sequence_range()
+                    .context("Failed to read outbox")?;
+                let oldest = match range {
+                    Some((first, _)) => storage
+                        .get_outbox_entries(first, 1)
+                        .context("Failed to read outbox")?
+                        .into_iter()
+                        .next()
+                        .map(|(_, entry)| entry.timestamp_ms),
+                    None => None,
+                };
+                print!("{}", format_outbox_stats(lag, range, oldest));
+            }
+        },
+
+        AdminCommands::VerifyConsistency {
+            index,
+            repair,
+            search_path,
+            vector_path,
+        } => {
+            handle_verify_consistency(
+                storage,
+                &expanded_path,
+                &settings.vector,
+                &index,
+                repair,
+                search_path,
+                vector_path,
+            )?;
+        }
+
+        AdminCommands::ReindexFrom {
+            sequence,
+            search_path,
+        } => {
+            handle_reindex_from(storage, &expanded_path, sequence, search_path)?;
+        }
+
         AdminCommands::ClearIndex {
             index,
             force,
@@ -1287,7 +2070,13 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
             vector_path,
             dry_run,
         } => {
-            handle_prune_vectors(&expanded_path, age_days, vector_path, dry_run)?;
+            handle_prune_vectors(
+                &expanded_path,
+                &settings.vector,
+                age_days,
+                vector_path,
+                dry_run,
+            )?;
         }
 
         AdminCommands::RebuildBm25 {
@@ -1296,8 +2085,181 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
         } => {
             handle_rebuild_bm25(&expanded_path, &min_level, search_path)?;
         }
+
+        AdminCommands::GripAudit => {
+            let report = memory_toc::GripExpander::new(storage.clone())
+                .audit()
+                .context("Failed to audit grips")?;
+
+            println!("Grip Provenance Audit");
+            println!("=====================");
+            println!("Grips checked: {:>8}", report.total_grips);
+            println!("Valid:         {:>8}", report.valid_grips());
+            println!("Dangling:      {:>8}", report.dangling.len());
+
+            if !report.by_provenance.is_empty() {
+                println!();
+                println!("By producing run:");
+                for (run, count) in &report.by_provenance {
+                    println!("  {:>8}  {}", count, run);
+                }
+            }
+
+            if !report.is_clean() {
+                println!();
+                for grip in &report.dangling {
+                    println!(
+                        "  {} (missing: {})",
+                        grip.grip_id,
+                        grip.missing_event_ids.join(", ")
+                    );
+                }
+            }
+        }
+
+        AdminCommands::PurgeSession {
+            session_id,
+            force,
+            search_path,
+            vector_path,
+        } => {
+            handle_purge_session(
+                &storage,
+                &expanded_path,
+                &settings.vector,
+                &session_id,
+                force,
+                search_path,
+                vector_path,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the purge-session command.
+///
+/// Deletes the session from storage first, then removes the returned
+/// event and grip IDs from whichever search indexes exist. TOC segments
+/// overlapping the session are listed, since their summaries are kept.
+fn handle_purge_session(
+    storage: &Storage,
+    db_path: &str,
+    vector: &memory_types::VectorSettings,
+    session_id: &str,
+    force: bool,
+    search_path: Option<String>,
+    vector_path: Option<String>,
+) -> Result<()> {
+    println!("Purge Session");
+    println!("=============");
+    println!("Session: {}", session_id);
+    println!();
+
+    if !force {
+        print!(
+            "This will PERMANENTLY DELETE all data for session {}. Continue? [y/N] ",
+            session_id
+        );
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let report = storage
+        .purge_session(session_id)
+        .context("Failed to purge session")?;
+
+    println!("Events deleted:         {:>8}", report.events_deleted);
+    println!("Grips deleted:          {:>8}", report.grips_deleted);
+    println!(
+        "Outbox entries deleted: {:>8}",
+        report.outbox_entries_deleted
+    );
+    println!(
+        "Dedup buffer entries:   {:>8}",
+        report.novelty_entries_deleted
+    );
+
+    if report.is_empty() {
+        println!();
+        println!("No data found for session {}.", session_id);
+        return Ok(());
+    }
+
+    let doc_ids = report.doc_ids();
+
+    // BM25 index holds events and grips
+    let search_dir = search_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(db_path).join("search"));
+    if search_dir.exists() {
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+
+        let search_index = SearchIndex::open_or_create(SearchIndexConfig::new(&search_dir))
+            .context("Failed to open search index")?;
+        let indexer =
+            SearchIndexer::new(&search_index).context("Failed to create search indexer")?;
+        let deleted = indexer
+            .delete_by_session(session_id, &doc_ids)
+            .context("Failed to delete BM25 documents")?;
+        indexer.commit().context("Failed to commit BM25 changes")?;
+        println!("BM25 deletes:           {:>8}", deleted);
+    } else {
+        println!("BM25 index not found at {:?}, skipping", search_dir);
+    }
+
+    // Vector index holds grips
+    let vector_dir = vector_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(db_path).join("vector"));
+    let metadata_path = vector_dir.join("metadata");
+    if metadata_path.exists() {
+        use memory_embeddings::EmbeddingModel;
+        use memory_vector::{HnswIndex, VectorIndexPipeline, VectorMetadata};
+
+        let embedder = memory_embeddings::CandleEmbedder::load_default()
+            .context("Failed to load embedding model")?;
+        let embedder = Arc::new(embedder);
+        let hnsw_config = vector_hnsw_config(vector, embedder.info().dimension, &vector_dir)?;
+        let hnsw_index =
+            HnswIndex::open_or_create(hnsw_config).context("Failed to open HNSW index")?;
+        let metadata =
+            VectorMetadata::open(&metadata_path).context("Failed to open vector metadata")?;
+
+        let pipeline = VectorIndexPipeline::new(
+            embedder,
+            Arc::new(RwLock::new(hnsw_index)),
+            Arc::new(metadata),
+            vector_pipeline_config(vector),
+        );
+        let removed = pipeline
+            .delete_by_session(session_id, &report.grip_ids)
+            .context("Failed to delete session vectors")?;
+        println!("Vectors removed:        {:>8}", removed);
+    } else {
+        println!("Vector index not found at {:?}, skipping", vector_dir);
+    }
+
+    if !report.toc_node_ids.is_empty() {
+        println!();
+        println!(
+            "{} TOC segment(s) overlap the purged events; their summaries were not",
+            report.toc_node_ids.len()
+        );
+        println!("rewritten and may still reflect this session:");
+        for node_id in &report.toc_node_ids {
+            println!("  {}", node_id);
+        }
     }
 
+    println!();
+    println!("Session {} purged.", session_id);
     Ok(())
 }
 
@@ -1306,15 +2268,13 @@ pub fn handle_admin(db_path: Option<String>, command: AdminCommands) -> Result<(
 /// Prunes old vectors from the HNSW index based on age.
 fn handle_prune_vectors(
     db_path: &str,
+    vector: &memory_types::VectorSettings,
     age_days: u32,
     vector_path: Option<String>,
     dry_run: bool,
 ) -> Result<()> {
     use memory_embeddings::EmbeddingModel;
-    use memory_vector::{
-        HnswConfig, HnswIndex, PipelineConfig as VectorPipelineConfig, VectorIndexPipeline,
-        VectorMetadata,
-    };
+    use memory_vector::{HnswIndex, VectorIndexPipeline, VectorMetadata};
 
     let vector_dir = vector_path
         .map(PathBuf::from)
@@ -1335,7 +2295,7 @@ fn handle_prune_vectors(
     let embedder = memory_embeddings::CandleEmbedder::load_default()
         .context("Failed to load embedding model")?;
     let embedder = Arc::new(embedder);
-    let hnsw_config = HnswConfig::new(embedder.info().dimension, &vector_dir);
+    let hnsw_config = vector_hnsw_config(vector, embedder.info().dimension, &vector_dir)?;
 
     let hnsw_index = HnswIndex::open_or_create(hnsw_config).context("Failed to open HNSW index")?;
     let hnsw_index = Arc::new(std::sync::RwLock::new(hnsw_index));
@@ -1353,7 +2313,7 @@ fn handle_prune_vectors(
         embedder,
         hnsw_index,
         metadata,
-        VectorPipelineConfig::default(),
+        vector_pipeline_config(vector),
     );
 
     // Prune each non-protected level
@@ -1361,31 +2321,34 @@ fn handle_prune_vectors(
     let mut total_pruned = 0usize;
 
     for level in &levels {
-        if dry_run {
-            println!(
-                "  [DRY RUN] Would prune '{}' vectors older than {} days",
-                level, age_days
-            );
-        } else {
-            match pipeline.prune_level(age_days as u64, Some(level)) {
-                Ok(count) => {
-                    println!(
-                        "  Pruned {} '{}' vectors older than {} days",
-                        count, level, age_days
-                    );
-                    total_pruned += count;
-                }
-                Err(e) => {
-                    warn!(level, error = %e, "Failed to prune level");
-                    println!("  ERROR pruning '{}': {}", level, e);
-                }
+        match pipeline.prune_level(age_days as u64, Some(level), dry_run) {
+            Ok(count) if dry_run => {
+                println!(
+                    "  [DRY RUN] Would prune {} '{}' vectors older than {} days",
+                    count, level, age_days
+                );
+                total_pruned += count;
+            }
+            Ok(count) => {
+                println!(
+                    "  Pruned {} '{}' vectors older than {} days",
+                    count, level, age_days
+                );
+                total_pruned += count;
+            }
+            Err(e) => {
+                warn!(level, error = %e, "Failed to prune level");
+                println!("  ERROR pruning '{}': {}", level, e);
             }
         }
     }
 
     println!();
     if dry_run {
-        println!("Dry run complete. No vectors were removed.");
+        println!(
+            "Dry run complete. {} vectors would be removed; none were.",
+            total_pruned
+        );
     } else {
         println!("Pruning complete. Total vectors removed: {}", total_pruned);
     }
@@ -1465,22 +2428,28 @@ fn handle_rebuild_bm25(db_path: &str, min_level: &str, search_path: Option<Strin
 }
 
 /// Handle the rebuild-indexes command.
+#[allow(clippy::too_many_arguments)]
 fn handle_rebuild_indexes(
     storage: Arc<Storage>,
     db_path: &str,
+    vector: &memory_types::VectorSettings,
     index: &str,
     batch_size: usize,
     force: bool,
     search_path: Option<String>,
     vector_path: Option<String>,
+    profile: bool,
+    from_sequence: Option<u64>,
+    since_ms: Option<i64>,
+    index_raw_events: bool,
 ) -> Result<()> {
     use memory_embeddings::EmbeddingModel;
     use memory_indexing::{
-        rebuild_bm25_index, rebuild_vector_index, Bm25IndexUpdater, RebuildConfig,
+        rebuild_bm25_index, rebuild_vector_index, Bm25IndexUpdater, PhaseTimings, RebuildConfig,
         VectorIndexUpdater,
     };
     use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
-    use memory_vector::{HnswConfig, HnswIndex, VectorMetadata};
+    use memory_vector::{HnswIndex, VectorMetadata};
 
     // Determine which indexes to rebuild
     let rebuild_bm25 = index == "all" || index == "bm25";
@@ -1508,11 +2477,27 @@ fn handle_rebuild_indexes(
         total_docs, stats.toc_node_count, stats.grip_count
     );
     println!("Batch size:   {}", batch_size);
+    if let Some(sequence) = from_sequence {
+        println!("From sequence: {}", sequence);
+    }
+    if let Some(since) = since_ms {
+        println!("Since:        {}", format_utc_timestamp(since));
+    }
     println!();
 
     // Confirmation prompt
     if !force {
-        print!("This will rebuild the index from scratch. Continue? [y/N] ");
+        match (from_sequence, since_ms) {
+            (Some(sequence), _) => print!(
+                "This will index documents from outbox sequence {} on. Continue? [y/N] ",
+                sequence
+            ),
+            (None, Some(since)) => print!(
+                "This will index documents from {} on. Continue? [y/N] ",
+                format_utc_timestamp(since)
+            ),
+            (None, None) => print!("This will rebuild the index from scratch. Continue? [y/N] "),
+        }
         io::stdout().flush()?;
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
@@ -1523,10 +2508,17 @@ fn handle_rebuild_indexes(
     }
 
     let start_time = Instant::now();
-    let config = RebuildConfig::default().with_batch_size(batch_size);
+    let mut config = RebuildConfig::default().with_batch_size(batch_size);
+    if let Some(sequence) = from_sequence {
+        config = config.with_from_sequence(sequence).with_clear_first(false);
+    }
+    if let Some(since) = since_ms {
+        config = config.with_since_ms(since).with_clear_first(false);
+    }
 
     // Progress callback that prints to console
     let progress_callback = ConsoleProgressCallback::new(batch_size);
+    let mut timings = PhaseTimings::default();
 
     // Rebuild BM25 index
     if rebuild_bm25 {
@@ -1548,15 +2540,22 @@ fn handle_rebuild_indexes(
         let indexer =
             Arc::new(SearchIndexer::new(&search_index).context("Failed to create search indexer")?);
 
-        let updater = Bm25IndexUpdater::new(indexer, storage.clone());
+        // Also backfills raw events ingested before event indexing was enabled
+        let updater =
+            Bm25IndexUpdater::new(indexer, storage.clone()).with_event_indexing(index_raw_events);
 
         let progress = rebuild_bm25_index(storage.clone(), &updater, &config, &progress_callback)
             .map_err(|e| anyhow::anyhow!("BM25 rebuild failed: {}", e))?;
 
+        timings.merge(&progress.timings);
+
         println!();
         println!("BM25 index rebuilt:");
         println!("  TOC nodes: {}", progress.toc_nodes_indexed);
         println!("  Grips:     {}", progress.grips_indexed);
+        if index_raw_events {
+            println!("  Events:    {}", progress.events_indexed);
+        }
         println!("  Errors:    {}", progress.errors);
     }
 
@@ -1580,7 +2579,7 @@ fn handle_rebuild_indexes(
         );
 
         // Open or create HNSW index
-        let hnsw_config = HnswConfig::new(embedder.info().dimension, vector_path);
+        let hnsw_config = vector_hnsw_config(vector, embedder.info().dimension, vector_path)?;
         let hnsw_index = Arc::new(RwLock::new(
             HnswIndex::open_or_create(hnsw_config).context("Failed to open HNSW index")?,
         ));
@@ -1597,6 +2596,8 @@ fn handle_rebuild_indexes(
         let progress = rebuild_vector_index(storage.clone(), &updater, &config, &progress_callback)
             .map_err(|e| anyhow::anyhow!("Vector rebuild failed: {}", e))?;
 
+        timings.merge(&progress.timings);
+
         println!();
         println!("Vector index rebuilt:");
         println!("  TOC nodes: {}", progress.toc_nodes_indexed);
@@ -1609,9 +2610,362 @@ fn handle_rebuild_indexes(
     println!();
     println!("Rebuild complete in {:.2}s", elapsed.as_secs_f64());
 
+    if profile {
+        println!();
+        print!("{}", format_phase_timings(&timings, elapsed));
+    }
+
+    Ok(())
+}
+
+/// Format rebuild phase timings as an aligned table.
+fn format_phase_timings(
+    timings: &memory_indexing::PhaseTimings,
+    elapsed: std::time::Duration,
+) -> String {
+    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+    let mut out = String::from("Phase Timings\n");
+    for (name, ms) in [
+        ("read", timings.read_ms),
+        ("embed", timings.embed_ms),
+        ("index", timings.index_ms),
+        ("commit", timings.commit_ms),
+    ] {
+        out.push_str(&format!("  {:<8}{:>10.1}ms\n", name, ms));
+    }
+    out.push_str(&format!(
+        "  {:<8}{:>10.1}ms (of {:.1}ms elapsed)\n",
+        "total",
+        timings.total_ms(),
+        elapsed_ms
+    ));
+    out
+}
+
+/// Handle the verify-consistency command.
+fn handle_verify_consistency(
+    storage: Arc<Storage>,
+    db_path: &str,
+    vector: &memory_types::VectorSettings,
+    index: &str,
+    repair: bool,
+    search_path: Option<String>,
+    vector_path: Option<String>,
+) -> Result<()> {
+    use memory_embeddings::EmbeddingModel;
+    use memory_indexing::{
+        verify_bm25_consistency, verify_vector_consistency, Bm25IndexUpdater, VectorIndexUpdater,
+    };
+    use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+    use memory_vector::{HnswIndex, VectorMetadata};
+
+    let check_bm25 = index == "all" || index == "bm25";
+    let check_vector = index == "all" || index == "vector";
+
+    if !check_bm25 && !check_vector {
+        anyhow::bail!("Invalid index type: {}. Use bm25, vector, or all.", index);
+    }
+
+    println!("Index Consistency");
+    println!("=================");
+    println!("Storage path: {}", db_path);
+    println!("Mode:         {}", if repair { "repair" } else { "report" });
+
+    if check_bm25 {
+        let search_dir = search_path.unwrap_or_else(|| format!("{}/search", db_path));
+        let search_dir = shellexpand::tilde(&search_dir).to_string();
+        let search_path = Path::new(&search_dir);
+
+        if !search_path.exists() {
+            println!();
+            println!("BM25 index not found at {}", search_dir);
+        } else {
+            let search_index = SearchIndex::open_or_create(SearchIndexConfig::new(search_path))
+                .context("Failed to open search index")?;
+            let indexer = Arc::new(
+                SearchIndexer::new(&search_index).context("Failed to create search indexer")?,
+            );
+            let updater = Bm25IndexUpdater::new(indexer, storage.clone());
+
+            let report = verify_bm25_consistency(&storage, &updater, repair)
+                .map_err(|e| anyhow::anyhow!("BM25 consistency check failed: {}", e))?;
+
+            println!();
+            print!("{}", format_consistency("BM25", &report, repair));
+        }
+    }
+
+    if check_vector {
+        let vector_dir = vector_path.unwrap_or_else(|| format!("{}/vector", db_path));
+        let vector_dir = shellexpand::tilde(&vector_dir).to_string();
+        let vector_path = Path::new(&vector_dir);
+
+        if !vector_path.exists() {
+            println!();
+            println!("Vector index not found at {}", vector_dir);
+        } else {
+            let embedder = Arc::new(
+                memory_embeddings::CandleEmbedder::load_default()
+                    .context("Failed to create embedder")?,
+            );
+            let hnsw_config = vector_hnsw_config(vector, embedder.info().dimension, vector_path)?;
+            let hnsw_index = Arc::new(RwLock::new(
+                HnswIndex::open_or_create(hnsw_config).context("Failed to open HNSW index")?,
+            ));
+            let metadata = Arc::new(
+                VectorMetadata::open(vector_path.join("metadata"))
+                    .context("Failed to open vector metadata")?,
+            );
+            let updater = VectorIndexUpdater::new(hnsw_index, embedder, metadata, storage.clone());
+
+            let report = verify_vector_consistency(&storage, &updater, repair)
+                .map_err(|e| anyhow::anyhow!("Vector consistency check failed: {}", e))?;
+
+            println!();
+            print!("{}", format_consistency("Vector", &report, repair));
+        }
+    }
+
+    Ok(())
+}
+
+/// Maximum doc IDs listed per category in consistency output.
+const CONSISTENCY_SAMPLE: usize = 10;
+
+/// Format outbox entries as one line each, in the order given.
+fn format_outbox_entries(entries: &[(u64, memory_types::OutboxEntry)]) -> String {
+    use memory_types::OutboxAction;
+
+    if entries.is_empty() {
+        return "Outbox is empty\n".to_string();
+    }
+    let mut out = format!(
+        "{:>10}  {:<6}  {:<16}  {}\n",
+        "SEQ", "TARGET", "TIMESTAMP", "EVENT"
+    );
+    for (sequence, entry) in entries {
+        let target = match entry.action {
+            OutboxAction::IndexEvent => "index",
+            OutboxAction::UpdateToc => "toc",
+        };
+        out.push_str(&format!(
+            "{:>10}  {:<6}  {:<16}  {}\n",
+            sequence,
+            target,
+            format_timestamp(entry.timestamp_ms),
+            entry.event_id
+        ));
+    }
+    out
+}
+
+/// Format the outbox backlog summary.
+fn format_outbox_stats(lag: u64, range: Option<(u64, u64)>, oldest_ms: Option<i64>) -> String {
+    let mut out = "Outbox:\n".to_string();
+    out.push_str(&format!("  Pending:   {}\n", lag));
+    match range {
+        Some((first, last)) => out.push_str(&format!("  Sequences: {} - {}\n", first, last)),
+        None => out.push_str("  Sequences: none\n"),
+    }
+    if let Some(ms) = oldest_ms {
+        out.push_str(&format!("  Oldest:    {}\n", format_timestamp(ms)));
+    }
+    out
+}
+
+/// Milliseconds at 00:00 UTC of a YYYY-MM-DD date.
+fn parse_day_start_ms(date_str: &str) -> Result<i64> {
+    let date = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+        .context(format!("Invalid date format: {}. Use YYYY-MM-DD", date_str))?;
+    let datetime = date.and_hms_opt(0, 0, 0).unwrap();
+    Ok(chrono::Utc.from_utc_datetime(&datetime).timestamp_millis())
+}
+
+/// Segment the events in `[start_ms, end_ms)` without summarizing or
+/// storing anything.
+fn preview_segments(
+    storage: &Storage,
+    start_ms: i64,
+    end_ms: i64,
+    config: memory_toc::SegmentationConfig,
+) -> Result<Vec<memory_types::Segment>> {
+    let events = storage
+        .get_events_in_range(start_ms, end_ms)
+        .context("Failed to query events")?
+        .into_iter()
+        .map(|(_, bytes)| memory_types::Event::from_bytes(&bytes))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to decode event")?;
+    Ok(memory_toc::segment_events(events, config))
+}
+
+/// Re-segment the events in `[start_ms, end_ms)` and rebuild their TOC nodes.
+///
+/// Segment node IDs are derived from the events, so running this again over
+/// the same range overwrites the same nodes and replaces their grips.
+/// Returns the number of segments processed.
+async fn rebuild_toc(
+    storage: Arc<Storage>,
+    summarizer: Arc<dyn memory_toc::Summarizer>,
+    start_ms: i64,
+    end_ms: i64,
+    rebuild_id: &str,
+) -> Result<usize> {
+    let segments = preview_segments(
+        &storage,
+        start_ms,
+        end_ms,
+        memory_toc::SegmentationConfig::default(),
+    )?;
+    let builder = memory_toc::TocBuilder::new(storage, summarizer).with_rebuild_id(rebuild_id);
+    for segment in &segments {
+        builder
+            .process_segment(segment)
+            .await
+            .context("Failed to rebuild segment")?;
+    }
+    Ok(segments.len())
+}
+
+/// Format one line per previewed segment plus a total.
+fn format_segment_preview(segments: &[memory_types::Segment]) -> String {
+    if segments.is_empty() {
+        return "No events in range\n".to_string();
+    }
+    let mut out = format!(
+        "{:>4}  {:>6}  {:>7}  {:<16}  {:<16}  {}\n",
+        "#", "EVENTS", "TOKENS", "START", "END", "SPAN"
+    );
+    for (i, segment) in segments.iter().enumerate() {
+        let span = segment.end_time - segment.start_time;
+        out.push_str(&format!(
+            "{:>4}  {:>6}  {:>7}  {:<16}  {:<16}  {}m\n",
+            i + 1,
+            segment.events.len(),
+            segment.token_count,
+            format_timestamp(segment.start_time.timestamp_millis()),
+            format_timestamp(segment.end_time.timestamp_millis()),
+            span.num_minutes()
+        ));
+    }
+    let events: usize = segments.iter().map(|s| s.events.len()).sum();
+    out.push_str(&format!("{} segments, {} events\n", segments.len(), events));
+    out
+}
+
+/// Format the before/after sizes of a compaction.
+fn format_compaction_report(report: &memory_storage::CompactionReport) -> String {
+    let scope = match &report.cf_name {
+        Some(cf) => format!("{} SST files", cf),
+        None => "Disk usage".to_string(),
+    };
+    format!(
+        "  {}: {} -> {} (reclaimed {})\n",
+        scope,
+        format_bytes(report.bytes_before),
+        format_bytes(report.bytes_after),
+        format_bytes(report.reclaimed)
+    )
+}
+
+/// Format a consistency report for one index.
+fn format_consistency(
+    name: &str,
+    report: &memory_indexing::IndexConsistency,
+    repair: bool,
+) -> String {
+    let mut out = format!("{} index:\n", name);
+    out.push_str(&format!("  In storage: {}\n", report.storage_docs));
+    out.push_str(&format!("  In index:   {}\n", report.indexed_docs));
+    for (label, ids) in [("Missing", &report.missing), ("Orphaned", &report.orphaned)] {
+        out.push_str(&format!("  {:<11} {}\n", format!("{}:", label), ids.len()));
+        for id in ids.iter().take(CONSISTENCY_SAMPLE) {
+            out.push_str(&format!("    {}\n", id));
+        }
+        if ids.len() > CONSISTENCY_SAMPLE {
+            out.push_str(&format!(
+                "    ... and {} more\n",
+                ids.len() - CONSISTENCY_SAMPLE
+            ));
+        }
+    }
+
+    if repair {
+        out.push_str(&format!("  Reindexed:  {}\n", report.reindexed));
+        out.push_str(&format!("  Removed:    {}\n", report.removed));
+    } else if !report.is_consistent() {
+        out.push_str("  Run with --repair to fix\n");
+    } else {
+        out.push_str("  Consistent\n");
+    }
+    out
+}
+
+/// Handle the reindex-from command.
+///
+/// Rewinds the BM25 checkpoint to `sequence` and replays the outbox until
+/// caught up. Run with the daemon stopped.
+fn handle_reindex_from(
+    storage: Arc<Storage>,
+    db_path: &str,
+    sequence: u64,
+    search_path: Option<String>,
+) -> Result<()> {
+    use memory_indexing::{Bm25IndexUpdater, IndexingPipeline, PipelineConfig};
+    use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+
+    let search_dir = search_path.unwrap_or_else(|| format!("{}/search", db_path));
+    let search_dir = shellexpand::tilde(&search_dir).to_string();
+    let search_path = Path::new(&search_dir);
+    if !search_path.exists() {
+        anyhow::bail!(
+            "BM25 index not found at {}. Run rebuild-indexes first.",
+            search_dir
+        );
+    }
+
+    let search_index = SearchIndex::open_or_create(SearchIndexConfig::new(search_path))
+        .context("Failed to open search index")?;
+    let indexer =
+        Arc::new(SearchIndexer::new(&search_index).context("Failed to create search indexer")?);
+
+    let mut pipeline = IndexingPipeline::new(storage.clone(), PipelineConfig::default());
+    pipeline.add_updater(Box::new(Bm25IndexUpdater::new(indexer, storage)));
+    pipeline
+        .load_checkpoints()
+        .context("Failed to load indexing checkpoints")?;
+    pipeline
+        .reset_checkpoint(0, sequence)
+        .map_err(|e| anyhow::anyhow!("Failed to rewind checkpoint: {}", e))?;
+
+    println!(
+        "Replaying outbox from sequence {} into {}",
+        sequence, search_dir
+    );
+    let result = pipeline
+        .process_until_caught_up(usize::MAX)
+        .map_err(|e| anyhow::anyhow!("Replay failed: {}", e))?;
+
+    print!("{}", format_replay(sequence, &result));
     Ok(())
 }
 
+/// Format the outcome of an outbox replay.
+fn format_replay(from_sequence: u64, result: &memory_indexing::ProcessResult) -> String {
+    let mut out = String::from("Replay complete:\n");
+    out.push_str(&format!("  From sequence: {}\n", from_sequence));
+    match result.last_sequence {
+        Some(last) => out.push_str(&format!("  To sequence:   {}\n", last)),
+        None => out.push_str("  To sequence:   (nothing to replay)\n"),
+    }
+    out.push_str(&format!("  Processed:     {}\n", result.total_processed));
+    let errors: usize = result.by_index.values().map(|r| r.errors).sum();
+    if errors > 0 {
+        out.push_str(&format!("  Errors:        {}\n", errors));
+    }
+    out
+}
+
 /// Handle the index-stats command.
 fn handle_index_stats(
     db_path: &str,
@@ -1705,6 +3059,108 @@ fn handle_index_stats(
     Ok(())
 }
 
+/// Handle the index-check command.
+///
+/// Inspects each index in place without creating, loading, or writing to
+/// it, and runs its integrity self-check. Fails if any checked index is
+/// corrupted so scripts can act on the exit code.
+fn handle_index_check(
+    db_path: &str,
+    index: &str,
+    search_path: Option<String>,
+    vector_path: Option<String>,
+) -> Result<()> {
+    use memory_search::{SearchIndex, TeleportSearcher};
+    use memory_vector::{HnswConfig, HnswIndex, VectorMetadata};
+
+    let check_bm25 = index == "all" || index == "bm25";
+    let check_vector = index == "all" || index == "vector";
+    if !check_bm25 && !check_vector {
+        anyhow::bail!("Invalid index type: {}. Use bm25, vector, or all.", index);
+    }
+
+    println!("Index Integrity Check");
+    println!("=====================");
+    let mut failures = 0;
+
+    if check_bm25 {
+        let search_dir = search_path.unwrap_or_else(|| format!("{}/search", db_path));
+        let search_dir = shellexpand::tilde(&search_dir).to_string();
+        let search_path = Path::new(&search_dir);
+
+        println!();
+        println!("BM25 Index:");
+        println!("  Path: {}", search_dir);
+
+        if !search_path.exists() {
+            println!("  Status:    Not found");
+        } else if !search_path.join("meta.json").exists() {
+            println!("  Status:    FAILED - meta.json is missing");
+            failures += 1;
+        } else {
+            let result = SearchIndex::open_existing(search_path)
+                .and_then(|index| TeleportSearcher::new(&index))
+                .and_then(|searcher| searcher.verify());
+            match result {
+                Ok(report) => {
+                    println!("  Segments:  {}", report.segments);
+                    println!("  Files:     {}", report.files_checked);
+                    println!("  Documents: {}", report.num_docs);
+                    println!("  Status:    OK");
+                }
+                Err(e) => {
+                    println!("  Status:    FAILED - {}", e);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if check_vector {
+        let vector_dir = vector_path.unwrap_or_else(|| format!("{}/vector", db_path));
+        let vector_dir = shellexpand::tilde(&vector_dir).to_string();
+        let vector_path = Path::new(&vector_dir);
+
+        println!();
+        println!("Vector Index:");
+        println!("  Path: {}", vector_dir);
+
+        let metadata_path = vector_path.join("metadata");
+        if !vector_path.exists() {
+            println!("  Status:    Not found");
+        } else if !metadata_path.exists() {
+            println!("  Status:    FAILED - metadata store is missing");
+            failures += 1;
+        } else {
+            // The daemon always embeds with the default model
+            let hnsw_config = HnswConfig::new(memory_embeddings::EMBEDDING_DIM, vector_path);
+            let result = VectorMetadata::open_read_only(&metadata_path)
+                .and_then(|metadata| HnswIndex::verify_file(&hnsw_config, &metadata));
+            match result {
+                Ok(report) if !report.file_present => {
+                    println!("  Status:    OK (no vectors saved yet)");
+                }
+                Ok(report) => {
+                    println!("  Dimension: {}", report.dimension);
+                    println!("  Vectors:   {}", report.vectors_present);
+                    println!("  Deleted:   {}", report.vectors_deleted);
+                    println!("  File size: {}", format_bytes(report.file_size));
+                    println!("  Status:    OK");
+                }
+                Err(e) => {
+                    println!("  Status:    FAILED - {}", e);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{} index(es) failed the integrity check", failures);
+    }
+    Ok(())
+}
+
 /// Handle the clear-index command.
 fn handle_clear_index(
     index: &str,
@@ -1846,6 +3302,10 @@ pub async fn handle_scheduler(endpoint: &str, command: SchedulerCommands) -> Res
                 "STOPPED"
             };
             println!("Scheduler: {}", status_str);
+            println!("Outbox lag: {}", response.outbox_lag);
+            if let Some(warning) = &response.outbox_warning {
+                println!("WARNING: {}", warning);
+            }
             println!();
 
             if response.jobs.is_empty() {
@@ -1957,9 +3417,35 @@ pub async fn handle_teleport_command(cmd: TeleportCommand) -> Result<()> {
             query,
             doc_type,
             limit,
+            since,
+            until,
+            min_score,
+            normalize,
             addr,
             ..
-        } => teleport_search(&query, &doc_type, limit, &addr).await,
+        } => {
+            let (start_ms, end_ms) = parse_time_range(since.as_deref(), until.as_deref())?;
+            // Map doc_type string to a filter (None searches all types)
+            let doc_type_filter = match doc_type.to_lowercase().as_str() {
+                "toc" | "toc_node" => Some(DocType::TocNode),
+                "grip" | "grips" => Some(DocType::Grip),
+                _ => None,
+            };
+            let request = TeleportSearchRequest {
+                query,
+                doc_type: doc_type_filter.map_or(0, DocType::to_proto),
+                limit: limit as i32,
+                agent_filter: None,
+                start_ms,
+                end_ms,
+                min_score,
+                normalize_scores: normalize,
+            };
+            teleport_search(request, &doc_type, &addr).await
+        }
+        TeleportCommand::Events { query, limit, addr } => {
+            teleport_events(&query, limit, &addr).await
+        }
         TeleportCommand::VectorSearch {
             query,
             top_k,
@@ -1975,9 +3461,16 @@ pub async fn handle_teleport_command(cmd: TeleportCommand) -> Result<()> {
             bm25_weight,
             vector_weight,
             target,
+            since,
+            until,
             addr,
             ..
         } => {
+            let (start_ms, end_ms) = parse_time_range(since.as_deref(), until.as_deref())?;
+            let time_filter = (start_ms.is_some() || end_ms.is_some()).then(|| TimeRange {
+                start_ms: start_ms.unwrap_or(i64::MIN),
+                end_ms: end_ms.unwrap_or(i64::MAX),
+            });
             hybrid_search(
                 &query,
                 top_k,
@@ -1985,65 +3478,66 @@ pub async fn handle_teleport_command(cmd: TeleportCommand) -> Result<()> {
                 bm25_weight,
                 vector_weight,
                 &target,
+                time_filter,
                 &addr,
             )
             .await
         }
         TeleportCommand::Stats { addr } => teleport_stats(&addr).await,
         TeleportCommand::VectorStats { addr } => vector_stats(&addr).await,
+        TeleportCommand::VectorPrune {
+            level,
+            age_days,
+            dry_run,
+            addr,
+        } => vector_prune(level.as_deref(), age_days, dry_run, &addr).await,
         TeleportCommand::Rebuild { addr } => teleport_rebuild(&addr).await,
     }
 }
 
 /// Execute teleport search via gRPC.
-async fn teleport_search(query: &str, doc_type: &str, limit: usize, addr: &str) -> Result<()> {
-    println!("Searching for: \"{}\"", query);
-    println!("Filter: {}, Limit: {}", doc_type, limit);
+///
+/// `doc_type` is the filter as the user typed it, for display.
+async fn teleport_search(request: TeleportSearchRequest, doc_type: &str, addr: &str) -> Result<()> {
+    println!("Searching for: \"{}\"", request.query);
+    println!("Filter: {}, Limit: {}", doc_type, request.limit);
     println!();
 
     let mut client = MemoryClient::connect(addr)
         .await
         .context("Failed to connect to daemon")?;
 
-    // Map doc_type string to enum value
-    let doc_type_value = match doc_type.to_lowercase().as_str() {
-        "toc" | "toc_node" => 1, // TeleportDocType::TocNode
-        "grip" | "grips" => 2,   // TeleportDocType::Grip
-        _ => 0,                  // TeleportDocType::Unspecified (all)
-    };
-
     let response = client
-        .teleport_search(query, doc_type_value, limit as i32)
+        .teleport_search_with(request)
         .await
         .context("Teleport search failed")?;
 
     if response.results.is_empty() {
         println!("No results found.");
+        let reason = response
+            .no_results_reason
+            .and_then(|r| domain_from_i32::<ProtoNoResultsReason, NoResultsReason>(r).ok());
+        if let Some(reason) = reason {
+            println!("Reason: {}", reason.description());
+        }
         return Ok(());
     }
 
     println!("Found {} results:", response.results.len());
     println!("{:-<60}", "");
 
-    for (i, result) in response.results.iter().enumerate() {
-        let type_str = match result.doc_type {
-            1 => "TOC",
-            2 => "Grip",
-            _ => "?",
-        };
-
+    let results = response.results.into_iter().map(TeleportSearchResult::from);
+    for (i, result) in results.enumerate() {
         println!(
             "{}. [{}] {} (score: {:.4})",
             i + 1,
-            type_str,
+            result.doc_type.as_str(),
             result.doc_id,
             result.score
         );
 
-        if let Some(ref keywords) = result.keywords {
-            if !keywords.is_empty() {
-                println!("   Keywords: {}", keywords);
-            }
+        if !result.keywords.is_empty() {
+            println!("   Keywords: {}", result.keywords.join(" "));
         }
     }
 
@@ -2053,6 +3547,59 @@ async fn teleport_search(query: &str, doc_type: &str, limit: usize, addr: &str)
     Ok(())
 }
 
+/// Search raw event text via the SearchEvents RPC.
+async fn teleport_events(query: &str, limit: usize, addr: &str) -> Result<()> {
+    let mut client = MemoryClient::connect(addr)
+        .await
+        .context("Failed to connect to daemon")?;
+
+    let response = client
+        .search_events(query, limit as i32)
+        .await
+        .context("Event search failed")?;
+
+    print!("{}", format_event_results(query, &response));
+    Ok(())
+}
+
+/// Format event search results, one event per entry with a text excerpt.
+fn format_event_results(query: &str, response: &memory_client::SearchEventsResponse) -> String {
+    let mut out = format!("Searching events for: \"{}\"\n\n", query);
+
+    if response.results.is_empty() {
+        out.push_str("No results found.\n");
+        if response.event_docs == 0 {
+            out.push_str("No events are indexed; set index_raw_events = true to enable.\n");
+        }
+        return out;
+    }
+
+    out.push_str(&format!("Found {} results:\n", response.results.len()));
+    out.push_str(&format!("{:-<60}\n", ""));
+    for (i, result) in response.results.iter().enumerate() {
+        let Some(event) = &result.event else { continue };
+        out.push_str(&format!(
+            "{}. {} (score: {:.4})\n",
+            i + 1,
+            event.event_id,
+            result.score
+        ));
+        out.push_str(&format!(
+            "   {}  session: {}",
+            format_timestamp(event.timestamp_ms),
+            event.session_id
+        ));
+        if let Some(agent) = &event.agent {
+            out.push_str(&format!("  agent: {}", agent));
+        }
+        out.push('\n');
+        out.push_str(&format!("   {}\n", truncate_text(&event.text, 100)));
+    }
+    out.push_str(&format!("{:-<60}\n", ""));
+    out.push_str(&format!("Indexed events: {}\n", response.event_docs));
+    out
+}
+
 /// Show teleport index statistics.
 async fn teleport_stats(addr: &str) -> Result<()> {
     let mut client = MemoryClient::connect(addr)
@@ -2068,6 +3615,8 @@ async fn teleport_stats(addr: &str) -> Result<()> {
     println!("Teleport Index Statistics");
     println!("{:-<40}", "");
     println!("Total documents: {}", response.total_docs);
+    println!("  TOC nodes:     {}", response.toc_node_docs);
+    println!("  Grips:         {}", response.grip_docs);
 
     Ok(())
 }
@@ -2153,6 +3702,7 @@ async fn vector_search(
 }
 
 /// Execute hybrid BM25 + vector search via gRPC.
+#[allow(clippy::too_many_arguments)]
 async fn hybrid_search(
     query: &str,
     top_k: i32,
@@ -2160,6 +3710,7 @@ async fn hybrid_search(
     bm25_weight: f32,
     vector_weight: f32,
     target: &str,
+    time_filter: Option<TimeRange>,
     addr: &str,
 ) -> Result<()> {
     println!("Hybrid Search: \"{}\"", query);
@@ -2174,12 +3725,7 @@ async fn hybrid_search(
         .context("Failed to connect to daemon")?;
 
     // Map mode string to enum value
-    let mode_value = match mode.to_lowercase().as_str() {
-        "vector-only" | "vector" => 1, // HybridMode::VectorOnly
-        "bm25-only" | "bm25" => 2,     // HybridMode::Bm25Only
-        "hybrid" => 3,                 // HybridMode::Hybrid
-        _ => 0,                        // HybridMode::Unspecified
-    };
+    let mode_value = HybridMode::from_name(mode) as i32;
 
     // Map target string to enum value
     let target_value = match target.to_lowercase().as_str() {
@@ -2190,24 +3736,20 @@ async fn hybrid_search(
     };
 
     let response = client
-        .hybrid_search(
+        .hybrid_search_in_range(
             query,
             top_k,
             mode_value,
             bm25_weight,
             vector_weight,
             target_value,
+            time_filter,
         )
         .await
         .context("Hybrid search failed")?;
 
     // Show mode used and availability
-    let mode_used = match response.mode_used {
-        1 => "vector-only",
-        2 => "bm25-only",
-        3 => "hybrid",
-        _ => "unknown",
-    };
+    let mode_used = HybridMode::name_of(response.mode_used);
     println!(
         "Mode used: {} (BM25: {}, Vector: {})",
         mode_used,
@@ -2282,19 +3824,84 @@ async fn vector_stats(addr: &str) -> Result<()> {
     Ok(())
 }
 
+/// Prune vectors through the daemon, or count them with `dry_run`.
+async fn vector_prune(
+    level: Option<&str>,
+    age_days: Option<u32>,
+    dry_run: bool,
+    addr: &str,
+) -> Result<()> {
+    let mut client = MemoryClient::connect(addr)
+        .await
+        .context("Failed to connect to daemon")?;
+
+    let response = client
+        .prune_vector_index(level, age_days, dry_run)
+        .await
+        .context("Failed to prune vector index")?;
+
+    print!("{}", format_vector_prune(&response, dry_run));
+    Ok(())
+}
+
+/// Format a PruneVectorIndex response, listing per-level counts.
+fn format_vector_prune(response: &PruneVectorIndexResponse, dry_run: bool) -> String {
+    let mut out = String::new();
+    if dry_run {
+        out.push_str("Vector Prune (dry run)\n");
+        out.push_str(&format!("{:-<40}\n", ""));
+        let mut levels: Vec<_> = response.would_remove.iter().collect();
+        levels.sort();
+        for (level, count) in levels {
+            out.push_str(&format!("{:<14} {}\n", format!("{}:", level), count));
+        }
+        let total: u32 = response.would_remove.values().sum();
+        out.push_str(&format!("{:<14} {}\n", "Would remove:", total));
+    } else {
+        out.push_str("Vector Prune\n");
+        out.push_str(&format!("{:-<40}\n", ""));
+        let levels = [
+            ("segment", response.segments_pruned),
+            ("grip", response.grips_pruned),
+            ("day", response.days_pruned),
+            ("week", response.weeks_pruned),
+        ];
+        for (level, count) in levels {
+            out.push_str(&format!("{:<14} {}\n", format!("{}:", level), count));
+        }
+    }
+    if !response.success {
+        out.push_str("Completed with errors.\n");
+    }
+    out.push_str(&format!("{}\n", response.message));
+    out
+}
+
 /// Handle topics commands.
 ///
 /// Per TOPIC-08: Topic graph discovery and navigation.
 pub async fn handle_topics_command(cmd: TopicsCommand) -> Result<()> {
     match cmd {
         TopicsCommand::Status { addr } => topics_status(&addr).await,
-        TopicsCommand::Explore { query, limit, addr } => topics_explore(&query, limit, &addr).await,
+        TopicsCommand::Explore {
+            query,
+            limit,
+            relevance_weight,
+            addr,
+        } => topics_explore(&query, limit, relevance_weight, &addr).await,
         TopicsCommand::Related {
             topic_id,
             rel_type,
             limit,
+            min_strength,
+            addr,
+        } => topics_related(&topic_id, rel_type.as_deref(), limit, min_strength, &addr).await,
+        TopicsCommand::Nodes {
+            topic_id,
+            limit,
+            token,
             addr,
-        } => topics_related(&topic_id, rel_type.as_deref(), limit, &addr).await,
+        } => topics_nodes(&topic_id, limit, token, &addr).await,
         TopicsCommand::Top { limit, days, addr } => topics_top(limit, days, &addr).await,
         TopicsCommand::RefreshScores { db_path } => topics_refresh_scores(db_path).await,
         TopicsCommand::Prune {
@@ -2334,7 +3941,12 @@ async fn topics_status(addr: &str) -> Result<()> {
 }
 
 /// Explore topics matching a query.
-async fn topics_explore(query: &str, limit: u32, addr: &str) -> Result<()> {
+async fn topics_explore(
+    query: &str,
+    limit: u32,
+    relevance_weight: Option<f32>,
+    addr: &str,
+) -> Result<()> {
     let mut client = MemoryClient::connect(addr)
         .await
         .context("Failed to connect to daemon")?;
@@ -2342,10 +3954,11 @@ async fn topics_explore(query: &str, limit: u32, addr: &str) -> Result<()> {
     println!("Searching for topics: \"{}\"", query);
     println!();
 
-    let topics = client
-        .get_topics_by_query(query, limit)
+    let response = client
+        .get_topics_by_query_weighted(query, limit, relevance_weight)
         .await
         .context("Failed to search topics")?;
+    let topics = response.topics;
 
     if topics.is_empty() {
         println!("No topics found matching query.");
@@ -2363,6 +3976,12 @@ async fn topics_explore(query: &str, limit: u32, addr: &str) -> Result<()> {
             topic.importance_score
         );
         println!("   ID: {}", topic.id);
+        if let Some(score) = response.scores.get(i) {
+            println!(
+                "   Score: {:.3} (relevance {:.2}, relative importance {:.2})",
+                score.score, score.relevance, score.importance
+            );
+        }
         if !topic.keywords.is_empty() {
             println!("   Keywords: {}", topic.keywords.join(", "));
         }
@@ -2379,6 +3998,7 @@ async fn topics_related(
     topic_id: &str,
     rel_type: Option<&str>,
     limit: u32,
+    min_strength: f32,
     addr: &str,
 ) -> Result<()> {
     let mut client = MemoryClient::connect(addr)
@@ -2389,10 +4009,13 @@ async fn topics_related(
     if let Some(rt) = rel_type {
         println!("Filtering by relationship type: {}", rt);
     }
+    if min_strength > 0.0 {
+        println!("Minimum strength: {:.2}", min_strength);
+    }
     println!();
 
     let response = client
-        .get_related_topics(topic_id, rel_type, limit)
+        .get_related_topics(topic_id, rel_type, limit, min_strength)
         .await
         .context("Failed to get related topics")?;
 
@@ -2431,6 +4054,44 @@ async fn topics_related(
     Ok(())
 }
 
+/// List the TOC nodes behind a topic.
+async fn topics_nodes(topic_id: &str, limit: u32, token: Option<String>, addr: &str) -> Result<()> {
+    let mut client = MemoryClient::connect(addr)
+        .await
+        .context("Failed to connect to daemon")?;
+
+    let result = client
+        .get_topic_nodes(topic_id, limit, token)
+        .await
+        .context("Failed to get topic nodes")?;
+
+    if result.nodes.is_empty() && result.missing_node_ids.is_empty() {
+        println!("No nodes recorded for topic: {}", topic_id);
+        return Ok(());
+    }
+
+    println!(
+        "Nodes for topic {} ({} total):\n",
+        topic_id, result.total_nodes
+    );
+    for node in &result.nodes {
+        let level = cli_label::<ProtoTocLevel>(node.level);
+        println!("  {} [{}]", node.title, level);
+        println!("    ID: {}", node.node_id);
+    }
+    for node_id in &result.missing_node_ids {
+        println!("  (missing) {}", node_id);
+    }
+
+    if result.has_more {
+        if let Some(token) = result.continuation_token {
+            println!("\nMore results available. Use --token {}", token);
+        }
+    }
+
+    Ok(())
+}
+
 /// Show top topics by importance.
 async fn topics_top(limit: u32, days: u32, addr: &str) -> Result<()> {
     let mut client = MemoryClient::connect(addr)
@@ -2562,6 +4223,7 @@ pub async fn handle_retrieval_command(cmd: RetrievalCommand) -> Result<()> {
             mode,
             timeout_ms,
             agent,
+            format,
             addr,
         } => {
             retrieval_route(
@@ -2571,6 +4233,7 @@ pub async fn handle_retrieval_command(cmd: RetrievalCommand) -> Result<()> {
                 mode.as_deref(),
                 timeout_ms,
                 agent.as_deref(),
+                &format,
                 &addr,
             )
             .await
@@ -2716,6 +4379,7 @@ async fn retrieval_classify(query: &str, timeout_ms: Option<u64>, addr: &str) ->
 }
 
 /// Route query through optimal layers.
+#[allow(clippy::too_many_arguments)]
 async fn retrieval_route(
     query: &str,
     intent_override: Option<&str>,
@@ -2723,12 +4387,13 @@ async fn retrieval_route(
     mode_override: Option<&str>,
     timeout_ms: Option<u64>,
     agent_filter: Option<&str>,
+    format: &str,
     addr: &str,
 ) -> Result<()> {
     use memory_service::pb::memory_service_client::MemoryServiceClient;
     use memory_service::pb::{
-        ExecutionMode as ProtoExecMode, QueryIntent as ProtoIntent, RouteQueryRequest,
-        StopConditions as ProtoStopConditions,
+        CapabilityTier as ProtoTier, ExecutionMode as ProtoExecMode, QueryIntent as ProtoIntent,
+        RetrievalLayer as ProtoLayer, RouteQueryRequest, StopConditions as ProtoStopConditions,
     };
 
     let mut client = MemoryServiceClient::connect(addr.to_string())
@@ -2777,41 +4442,22 @@ async fn retrieval_route(
         .context("Failed to route query")?
         .into_inner();
 
+    if format == "md" {
+        print!("{}", route_report(query, &response)?.to_markdown());
+        return Ok(());
+    }
+
     println!("Query Routing");
     println!("{:-<70}", "");
     println!("Query: \"{}\"", query);
+    println!("Request ID: {}", response.request_id);
 
     // Print explanation
     if let Some(exp) = &response.explanation {
-        let intent_str = match exp.intent {
-            1 => "Explore",
-            2 => "Answer",
-            3 => "Locate",
-            4 => "Time-boxed",
-            _ => "Unknown",
-        };
-        let tier_str = match exp.tier {
-            1 => "Full",
-            2 => "Hybrid",
-            3 => "Semantic",
-            4 => "Keyword",
-            5 => "Agentic",
-            _ => "Unknown",
-        };
-        let mode_str = match exp.mode {
-            1 => "Sequential",
-            2 => "Parallel",
-            3 => "Hybrid",
-            _ => "Unknown",
-        };
-        let winner_str = match exp.winner {
-            1 => "Topics",
-            2 => "Hybrid",
-            3 => "Vector",
-            4 => "BM25",
-            5 => "Agentic",
-            _ => "Unknown",
-        };
+        let intent_str = cli_label::<ProtoIntent>(exp.intent);
+        let tier_str = cli_label::<ProtoTier>(exp.tier);
+        let mode_str = cli_label::<ProtoExecMode>(exp.mode);
+        let winner_str = cli_label::<ProtoLayer>(exp.winner);
 
         println!();
         println!("Execution:");
@@ -2828,6 +4474,19 @@ async fn retrieval_route(
         }
 
         println!("  Time: {}ms", exp.total_time_ms);
+
+        if !exp.layer_latencies.is_empty() {
+            let latencies: Vec<String> = exp
+                .layer_latencies
+                .iter()
+                .map(|l| format!("{} {}ms", cli_label::<ProtoLayer>(l.layer), l.latency_ms))
+                .collect();
+            println!("  Layer latencies: {}", latencies.join(", "));
+        }
+
+        for note in &exp.notes {
+            println!("  Note: {}", note);
+        }
     }
 
     // Print results
@@ -2839,14 +4498,7 @@ async fn retrieval_route(
         println!("{:-<70}", "");
 
         for (i, result) in response.results.iter().enumerate() {
-            let layer_str = match result.source_layer {
-                1 => "Topics",
-                2 => "Hybrid",
-                3 => "Vector",
-                4 => "BM25",
-                5 => "Agentic",
-                _ => "?",
-            };
+            let layer_str = cli_label::<ProtoLayer>(result.source_layer);
 
             println!(
                 "{}. [{}] {} (score: {:.4})",
@@ -2869,24 +4521,81 @@ async fn retrieval_route(
         }
     }
 
-    // Print layers attempted
-    if !response.layers_attempted.is_empty() {
-        let layers: Vec<&str> = response
+    // Print layers attempted
+    if !response.layers_attempted.is_empty() {
+        let layers: Vec<String> = response
+            .layers_attempted
+            .iter()
+            .map(|l| cli_label::<ProtoLayer>(*l))
+            .collect();
+        println!("Layers attempted: {}", layers.join(" -> "));
+    }
+
+    Ok(())
+}
+
+/// Build a shareable retrieval report from a route response.
+fn route_report(
+    query: &str,
+    response: &memory_service::pb::RouteQueryResponse,
+) -> Result<RetrievalReport> {
+    use memory_service::conversions::domain_from_i32;
+    use memory_service::pb::{
+        CapabilityTier as ProtoTier, ExecutionMode as ProtoExecMode, QueryIntent as ProtoIntent,
+        RetrievalLayer as ProtoLayer,
+    };
+
+    let exp = response
+        .explanation
+        .as_ref()
+        .context("Route response has no explanation")?;
+
+    let results = response
+        .results
+        .iter()
+        .map(|r| {
+            Ok(SearchResult {
+                doc_id: r.doc_id.clone(),
+                doc_type: r.doc_type.clone(),
+                score: r.score,
+                text_preview: r.text_preview.clone(),
+                source_layer: domain_from_i32::<ProtoLayer, _>(r.source_layer)?,
+                metadata: r.metadata.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let explanation = ExplainabilityPayload {
+        intent: domain_from_i32::<ProtoIntent, _>(exp.intent)?,
+        tier: domain_from_i32::<ProtoTier, _>(exp.tier)?,
+        mode: domain_from_i32::<ProtoExecMode, _>(exp.mode)?,
+        candidates_considered: response
             .layers_attempted
             .iter()
-            .map(|l| match *l {
-                1 => "Topics",
-                2 => "Hybrid",
-                3 => "Vector",
-                4 => "BM25",
-                5 => "Agentic",
-                _ => "?",
+            .map(|l| domain_from_i32::<ProtoLayer, RetrievalLayer>(*l))
+            .collect::<Result<_, _>>()?,
+        winner: domain_from_i32::<ProtoLayer, _>(exp.winner)?,
+        why_winner: exp.why_winner.clone(),
+        fallback_occurred: exp.fallback_occurred,
+        fallback_reason: exp.fallback_reason.clone(),
+        stop_conditions: StopConditions::default(),
+        bounds_hit: vec![],
+        total_time_ms: exp.total_time_ms,
+        layer_latencies: exp
+            .layer_latencies
+            .iter()
+            .map(|l| {
+                Ok(LayerLatency {
+                    layer: domain_from_i32::<ProtoLayer, _>(l.layer)?,
+                    latency_ms: l.latency_ms,
+                })
             })
-            .collect();
-        println!("Layers attempted: {}", layers.join(" -> "));
-    }
+            .collect::<Result<_>>()?,
+        result_count: results.len(),
+        grip_ids: exp.grip_ids.clone(),
+    };
 
-    Ok(())
+    Ok(RetrievalReport::new(query, explanation, results))
 }
 
 /// Handle agent discovery commands.
@@ -2912,6 +4621,12 @@ pub async fn handle_agents_command(cmd: AgentsCommand) -> Result<()> {
             .await
         }
         AgentsCommand::Topics { agent, limit, addr } => agents_topics(&agent, limit, &addr).await,
+        AgentsCommand::Compare {
+            agent_a,
+            agent_b,
+            limit,
+            addr,
+        } => agents_compare(&agent_a, &agent_b, limit, &addr).await,
     }
 }
 
@@ -3040,6 +4755,50 @@ async fn agents_topics(agent: &str, limit: u32, addr: &str) -> Result<()> {
     Ok(())
 }
 
+/// Compare two agents' top topics.
+async fn agents_compare(agent_a: &str, agent_b: &str, limit: u32, addr: &str) -> Result<()> {
+    use memory_service::pb::memory_service_client::MemoryServiceClient;
+    use memory_service::pb::{CompareAgentsRequest, Topic};
+
+    let mut client = MemoryServiceClient::connect(addr.to_string())
+        .await
+        .context("Failed to connect to daemon")?;
+
+    let response = client
+        .compare_agents(CompareAgentsRequest {
+            agent_a: agent_a.to_string(),
+            agent_b: agent_b.to_string(),
+            limit,
+        })
+        .await
+        .context("CompareAgents RPC failed")?
+        .into_inner();
+
+    let print_section = |title: &str, topics: &[Topic]| {
+        println!("{} ({}):", title, topics.len());
+        if topics.is_empty() {
+            println!("  (none)");
+        }
+        for topic in topics {
+            println!(
+                "  {:<30} {:>10.4}",
+                truncate_text(&topic.label, 28),
+                topic.importance_score
+            );
+        }
+    };
+
+    println!("Topic comparison: \"{}\" vs \"{}\"", agent_a, agent_b);
+    println!();
+    print_section("Shared", &response.shared);
+    println!();
+    print_section(&format!("Only {}", agent_a), &response.only_a);
+    println!();
+    print_section(&format!("Only {}", agent_b), &response.only_b);
+
+    Ok(())
+}
+
 /// Parse a time argument that can be either YYYY-MM-DD or Unix epoch milliseconds.
 fn parse_time_arg(s: &str) -> Result<i64> {
     // Try parsing as integer (epoch ms) first
@@ -3056,6 +4815,21 @@ fn parse_time_arg(s: &str) -> Result<i64> {
     Ok(chrono::Utc.from_utc_datetime(&datetime).timestamp_millis())
 }
 
+/// Parse optional `--since`/`--until` arguments into a `[start, end)` range.
+fn parse_time_range(
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<(Option<i64>, Option<i64>)> {
+    let start_ms = since.map(parse_time_arg).transpose()?;
+    let end_ms = until.map(parse_time_arg).transpose()?;
+    if let (Some(start), Some(end)) = (start_ms, end_ms) {
+        if start >= end {
+            anyhow::bail!("--since must be earlier than --until");
+        }
+    }
+    Ok((start_ms, end_ms))
+}
+
 /// Format a Unix timestamp in milliseconds as a human-readable UTC string.
 fn format_utc_timestamp(ms: i64) -> String {
     chrono::DateTime::<chrono::Utc>::from_timestamp_millis(ms)
@@ -3063,6 +4837,138 @@ fn format_utc_timestamp(ms: i64) -> String {
         .unwrap_or_else(|| "Invalid".to_string())
 }
 
+/// Handle novelty (dedup) gate commands.
+pub async fn handle_novelty_command(cmd: NoveltyCommand) -> Result<()> {
+    match cmd {
+        NoveltyCommand::Stats { addr } => novelty_stats(&addr).await,
+    }
+}
+
+/// Show novelty suppression counters.
+async fn novelty_stats(addr: &str) -> Result<()> {
+    use memory_service::pb::memory_service_client::MemoryServiceClient;
+    use memory_service::pb::GetNoveltyMetricsRequest;
+
+    let mut client = MemoryServiceClient::connect(addr.to_string())
+        .await
+        .context("Failed to connect to daemon")?;
+
+    let response = client
+        .get_novelty_metrics(GetNoveltyMetricsRequest {})
+        .await
+        .context("GetNoveltyMetrics RPC failed")?
+        .into_inner();
+
+    print!("{}", format_novelty_stats(&response));
+    Ok(())
+}
+
+/// Format novelty counters for display.
+fn format_novelty_stats(metrics: &memory_service::pb::GetNoveltyMetricsResponse) -> String {
+    let status = if metrics.enabled {
+        "enabled"
+    } else {
+        "disabled"
+    };
+    let mut out = String::from("Novelty Gate:\n");
+    out.push_str(&format!("  Status:      {}\n", status));
+    out.push_str(&format!("  Window:      {} events\n", metrics.window_size));
+    out.push_str(&format!("  Seen:        {}\n", metrics.total_seen));
+    out.push_str(&format!(
+        "  Suppressed:  {} ({:.1}%)\n",
+        metrics.suppressed,
+        metrics.suppression_rate * 100.0
+    ));
+    out.push_str(&format!("  Novel:       {}\n", metrics.stored_novel));
+    out.push_str(&format!("  Unchecked:   {}\n", metrics.skipped));
+    out
+}
+
+/// Handle configuration commands.
+///
+/// `validate` loads settings the same way `start` does and prints a
+/// pass/fail line per field. Fails if loading fails or any field is invalid.
+pub fn handle_config_command(config_path: Option<&str>, cmd: ConfigCommand) -> Result<()> {
+    use crate::config_check::validate_settings;
+
+    match cmd {
+        ConfigCommand::Validate => {
+            println!("Configuration Check");
+            println!("===================");
+            println!("Source: {}", config_path.unwrap_or("default locations"));
+            println!();
+
+            let settings = match Settings::load(config_path) {
+                Ok(settings) => settings,
+                Err(e) => {
+                    println!("  [FAIL] (load): {}", e);
+                    anyhow::bail!("Configuration could not be loaded");
+                }
+            };
+
+            let checks = validate_settings(&settings);
+            for check in &checks {
+                match &check.result {
+                    Ok(()) => println!("  [ OK ] {}", check.field),
+                    Err(e) => println!("  [FAIL] {}: {}", check.field, e),
+                }
+            }
+
+            let failed = checks.iter().filter(|c| !c.passed()).count();
+            println!();
+            if failed > 0 {
+                anyhow::bail!("{} of {} checks failed", failed, checks.len());
+            }
+            println!("All {} checks passed", checks.len());
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle embedding model commands.
+///
+/// `bench` loads the Candle model (downloading it if needed) and times
+/// batched embedding of synthetic texts.
+pub fn handle_embeddings_command(cmd: EmbeddingsCommand) -> Result<()> {
+    use memory_embeddings::EmbeddingModel;
+
+    match cmd {
+        EmbeddingsCommand::Bench { count, batch } => {
+            println!("Loading embedding model...");
+            let embedder =
+                CandleEmbedder::load_default().context("Failed to load embedding model")?;
+            println!(
+                "Embedding {} texts in batches of {} with {}...",
+                count,
+                batch,
+                embedder.info().name
+            );
+            let report = memory_embeddings::bench_embedder(&embedder, count, batch)
+                .context("Benchmark failed")?;
+            print!("{}", format_bench_report(&report));
+        }
+    }
+
+    Ok(())
+}
+
+/// Format embedding benchmark results.
+fn format_bench_report(report: &memory_embeddings::BenchReport) -> String {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    let mut out = "Embedding Benchmark\n".to_string();
+    out.push_str(&format!("  Texts:       {}\n", report.count));
+    out.push_str(&format!("  Batch size:  {}\n", report.batch_size));
+    out.push_str(&format!("  Total:       {:.1} ms\n", ms(report.total)));
+    out.push_str(&format!(
+        "  Throughput:  {:.1} embeddings/sec\n",
+        report.embeddings_per_sec
+    ));
+    out.push_str(&format!("  Batch p50:   {:.1} ms\n", ms(report.p50)));
+    out.push_str(&format!("  Batch p95:   {:.1} ms\n", ms(report.p95)));
+    out
+}
+
 /// Handle CLOD format commands (convert and validate).
 pub async fn handle_clod_command(cmd: ClodCliCommand) -> Result<()> {
     use crate::clod;
@@ -3152,6 +5058,49 @@ fn format_utc_date(ms: i64) -> String {
 mod tests {
     use super::*;
 
+    /// Log writer that keeps everything written to it.
+    #[derive(Clone, Default)]
+    struct CaptureWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Write for CaptureWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CaptureWriter {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_json_log_format() {
+        let capture = CaptureWriter::default();
+        let subscriber = build_subscriber("info", LogFormat::Json, capture.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("ingest", session_id = "session-123");
+            let _guard = span.enter();
+            info!(events = 3, "Indexed events");
+        });
+
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().expect("no log line written");
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(value["level"], "INFO");
+        assert_eq!(value["message"], "Indexed events");
+        assert_eq!(value["events"], 3);
+        assert_eq!(value["span"]["name"], "ingest");
+        assert_eq!(value["span"]["session_id"], "session-123");
+    }
+
     #[test]
     fn test_pid_file_path() {
         let path = pid_file_path();
@@ -3163,6 +5112,20 @@ mod tests {
             .contains("agent-memory"));
     }
 
+    #[tokio::test]
+    async fn test_shutdown_timeout_reaches_scheduler_and_server() {
+        let settings = Settings {
+            shutdown_timeout_secs: 120,
+            ..Default::default()
+        };
+
+        let scheduler = SchedulerService::new(scheduler_config(&settings))
+            .await
+            .unwrap();
+        assert_eq!(scheduler.config().shutdown_timeout_secs, 120);
+        assert_eq!(drain_timeout(&settings), Duration::from_secs(120));
+    }
+
     #[test]
     fn test_status_no_daemon() {
         // Just verify it doesn't panic
@@ -3171,10 +5134,564 @@ mod tests {
     }
 
     #[test]
-    fn test_level_to_string() {
-        assert_eq!(level_to_string(ProtoTocLevel::Year as i32), "Year");
-        assert_eq!(level_to_string(ProtoTocLevel::Month as i32), "Month");
-        assert_eq!(level_to_string(ProtoTocLevel::Segment as i32), "Segment");
+    fn test_cli_label() {
+        use memory_service::pb::{QueryIntent as ProtoIntent, RetrievalLayer as ProtoLayer};
+
+        assert_eq!(
+            cli_label::<ProtoTocLevel>(ProtoTocLevel::Year as i32),
+            "Year"
+        );
+        assert_eq!(
+            cli_label::<ProtoTocLevel>(ProtoTocLevel::Month as i32),
+            "Month"
+        );
+        assert_eq!(
+            cli_label::<ProtoTocLevel>(ProtoTocLevel::Segment as i32),
+            "Segment"
+        );
+        assert_eq!(cli_label::<ProtoTocLevel>(99), "Unknown");
+        assert_eq!(
+            cli_label::<ProtoIntent>(ProtoIntent::TimeBoxed as i32),
+            "Time-boxed"
+        );
+        assert_eq!(cli_label::<ProtoLayer>(ProtoLayer::Bm25 as i32), "BM25");
+        assert_eq!(cli_label::<ProtoLayer>(ProtoLayer::Topics as i32), "Topics");
+    }
+
+    #[test]
+    fn test_route_report_from_response() {
+        use memory_service::pb::{
+            CapabilityTier as ProtoTier, ExecutionMode as ProtoExecMode,
+            ExplainabilityPayload as ProtoExplainability, LayerLatency as ProtoLayerLatency,
+            QueryIntent as ProtoIntent, RetrievalLayer as ProtoLayer, RetrievalResult,
+            RouteQueryResponse,
+        };
+
+        let response = RouteQueryResponse {
+            results: vec![RetrievalResult {
+                doc_id: "toc:day:2024-01-15".to_string(),
+                doc_type: "toc_node".to_string(),
+                score: 0.75,
+                text_preview: "Fixed JWT validation".to_string(),
+                source_layer: ProtoLayer::Bm25 as i32,
+                metadata: Default::default(),
+                agent: None,
+                project: None,
+            }],
+            explanation: Some(ProtoExplainability {
+                intent: ProtoIntent::Answer as i32,
+                tier: ProtoTier::Keyword as i32,
+                mode: ProtoExecMode::Sequential as i32,
+                candidates_considered: vec![ProtoLayer::Bm25 as i32],
+                winner: ProtoLayer::Bm25 as i32,
+                why_winner: "BM25 matched".to_string(),
+                fallback_occurred: false,
+                fallback_reason: None,
+                total_time_ms: 7,
+                grip_ids: vec![],
+                layer_latencies: vec![ProtoLayerLatency {
+                    layer: ProtoLayer::Bm25 as i32,
+                    latency_ms: 5,
+                }],
+                notes: vec![],
+            }),
+            has_results: true,
+            layers_attempted: vec![ProtoLayer::Bm25 as i32],
+            request_id: "01J0000000000000000000000".to_string(),
+        };
+
+        let md = route_report("jwt bug", &response).unwrap().to_markdown();
+        assert!(md.contains("**Query:** jwt bug"));
+        assert!(md.contains("- **Intent:** answer"));
+        assert!(md.contains("- **Layer latencies:** bm25 5ms"));
+        assert!(md.contains("1. **toc:day:2024-01-15** (score: 0.7500"));
+
+        let missing = RouteQueryResponse {
+            explanation: None,
+            ..response
+        };
+        assert!(route_report("jwt bug", &missing).is_err());
+    }
+
+    #[test]
+    fn test_format_phase_timings() {
+        let timings = memory_indexing::PhaseTimings {
+            read_ms: 1.5,
+            embed_ms: 20.0,
+            index_ms: 3.2,
+            commit_ms: 0.5,
+        };
+        let out = format_phase_timings(&timings, std::time::Duration::from_millis(26));
+
+        assert!(out.starts_with("Phase Timings\n"));
+        assert!(out.contains("  read           1.5ms\n"));
+        assert!(out.contains("  embed         20.0ms\n"));
+        assert!(out.contains("  commit         0.5ms\n"));
+        assert!(out.contains("  total         25.2ms (of 26.0ms elapsed)\n"));
+    }
+
+    #[test]
+    fn test_load_embedder_failure_degrades_vector() {
+        let (embedder, degraded) = load_embedder(|| Err("model not downloaded"));
+
+        assert!(embedder.is_none());
+        assert!(degraded.is_degraded());
+        assert_eq!(
+            degraded.vector_disabled_reason(),
+            Some("embedding model failed to load: model not downloaded")
+        );
+    }
+
+    #[test]
+    fn test_format_consistency() {
+        let report = memory_indexing::IndexConsistency {
+            storage_docs: 2,
+            indexed_docs: 3,
+            missing: vec![],
+            orphaned: vec!["toc:day:2024-01-02".to_string()],
+            reindexed: 0,
+            removed: 1,
+        };
+
+        let out = format_consistency("Vector", &report, false);
+        assert!(out.starts_with("Vector index:\n"));
+        assert!(out.contains("  Missing:    0\n"));
+        assert!(out.contains("  Orphaned:   1\n    toc:day:2024-01-02\n"));
+        assert!(out.contains("Run with --repair"));
+
+        let out = format_consistency("Vector", &report, true);
+        assert!(out.contains("  Removed:    1\n"));
+        assert!(!out.contains("Run with --repair"));
+    }
+
+    #[test]
+    fn test_preview_segments_thresholds() {
+        use memory_types::{Event, EventRole, EventType};
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let storage = Storage::open(temp.path()).unwrap();
+        let day_start = parse_day_start_ms("2026-01-12").unwrap();
+        // Two bursts of three events, an hour apart
+        for (i, offset_mins) in [0, 1, 2, 62, 63, 64].into_iter().enumerate() {
+            let ts = day_start + offset_mins * 60 * 1000;
+            let event = Event::new(
+                ulid::Ulid::from_parts(ts as u64, i as u128).to_string(),
+                "preview".to_string(),
+                chrono::Utc.timestamp_millis_opt(ts).unwrap(),
+                EventType::UserMessage,
+                EventRole::User,
+                "hello world ".repeat(20),
+            );
+            storage
+                .put_event(&event.event_id, &event.to_bytes().unwrap(), b"")
+                .unwrap();
+        }
+
+        let end = parse_day_start_ms("2026-01-13").unwrap();
+        let preview = |config| preview_segments(&storage, day_start, end, config).unwrap();
+
+        // Default 30 minute gap splits the bursts
+        let segments = preview(memory_toc::SegmentationConfig::default());
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().all(|s| s.events.len() == 3));
+
+        // A 2 hour gap keeps them together
+        let segments = preview(memory_toc::SegmentationConfig {
+            time_threshold_ms: 2 * 60 * 60 * 1000,
+            ..Default::default()
+        });
+        assert_eq!(segments.len(), 1);
+
+        // A tiny token threshold puts every event in its own segment
+        let segments = preview(memory_toc::SegmentationConfig {
+            token_threshold: 1,
+            overlap_tokens: 0,
+            ..Default::default()
+        });
+        assert_eq!(segments.len(), 6);
+
+        let out = format_segment_preview(&segments);
+        assert!(out.ends_with("6 segments, 6 events\n"), "{out}");
+        assert!(preview_segments(&storage, end, end + 1, Default::default())
+            .unwrap()
+            .is_empty());
+        assert_eq!(format_segment_preview(&[]), "No events in range\n");
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_toc_is_idempotent() {
+        use memory_types::{Event, EventRole, EventType, TocLevel};
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(temp.path()).unwrap());
+        let day_start = parse_day_start_ms("2026-01-12").unwrap();
+        // Two bursts an hour apart, so two segments
+        for (i, offset_mins) in [0, 1, 62, 63].into_iter().enumerate() {
+            let ts = day_start + offset_mins * 60 * 1000;
+            let event = Event::new(
+                ulid::Ulid::from_parts(ts as u64, i as u128).to_string(),
+                "rebuild".to_string(),
+                chrono::Utc.timestamp_millis_opt(ts).unwrap(),
+                EventType::UserMessage,
+                EventRole::User,
+                format!("Deployed the billing service to staging, step {}", i),
+            );
+            storage
+                .put_event(&event.event_id, &event.to_bytes().unwrap(), b"")
+                .unwrap();
+        }
+
+        let end = parse_day_start_ms("2026-01-13").unwrap();
+        let counts = || {
+            let nodes = storage
+                .get_toc_nodes_by_level(TocLevel::Segment, None, None)
+                .unwrap()
+                .len();
+            (nodes, storage.get_all_grips().unwrap().len())
+        };
+
+        let summarizer = Arc::new(MockSummarizer::new());
+        let rebuilt = rebuild_toc(storage.clone(), summarizer.clone(), day_start, end, "r1")
+            .await
+            .unwrap();
+        assert_eq!(rebuilt, 2);
+        let first = counts();
+        assert_eq!(first.0, 2);
+        assert!(first.1 > 0, "mock bullets should yield grips");
+
+        rebuild_toc(storage.clone(), summarizer, day_start, end, "r2")
+            .await
+            .unwrap();
+        assert_eq!(counts(), first);
+        assert!(storage.get_all_grips().unwrap().iter().all(|g| g
+            .provenance
+            .rebuild_id
+            .as_deref()
+            == Some("r2")));
+    }
+
+    #[test]
+    fn test_format_bench_report() {
+        let embedder = memory_embeddings::HashEmbedder::new(16);
+        let report = memory_embeddings::bench_embedder(&embedder, 10, 3).unwrap();
+        let out = format_bench_report(&report);
+        assert!(out.contains("  Texts:       10\n"), "{out}");
+        assert!(out.contains("  Batch size:  3\n"), "{out}");
+        assert!(out.contains(" embeddings/sec\n"), "{out}");
+        assert!(out.contains("  Batch p95:   "), "{out}");
+    }
+
+    #[test]
+    fn test_format_compaction_report() {
+        use memory_storage::CompactionReport;
+
+        let report = CompactionReport {
+            cf_name: Some("topics".to_string()),
+            bytes_before: 3 * 1024 * 1024,
+            bytes_after: 1024 * 1024,
+            reclaimed: 2 * 1024 * 1024,
+        };
+        assert_eq!(
+            format_compaction_report(&report),
+            "  topics SST files: 3.00 MB -> 1.00 MB (reclaimed 2.00 MB)\n"
+        );
+
+        let report = CompactionReport {
+            cf_name: None,
+            bytes_before: 2048,
+            bytes_after: 4096,
+            reclaimed: 0,
+        };
+        assert_eq!(
+            format_compaction_report(&report),
+            "  Disk usage: 2.00 KB -> 4.00 KB (reclaimed 0 bytes)\n"
+        );
+    }
+
+    #[test]
+    fn test_format_outbox_entries_in_sequence_order() {
+        use memory_types::OutboxEntry;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let storage = Storage::open(temp.path()).unwrap();
+        assert_eq!(
+            format_outbox_entries(&storage.get_outbox_entries(0, 10).unwrap()),
+            "Outbox is empty\n"
+        );
+
+        let ids: Vec<String> = (0..3).map(|_| ulid::Ulid::new().to_string()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            let entry = if i == 1 {
+                OutboxEntry::for_toc(id.clone(), 1_706_540_400_000)
+            } else {
+                OutboxEntry::for_index(id.clone(), 1_706_540_400_000)
+            };
+            storage
+                .put_event(id, b"event", &entry.to_bytes().unwrap())
+                .unwrap();
+        }
+
+        let out = format_outbox_entries(&storage.get_outbox_entries(0, 10).unwrap());
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("SEQ"));
+        for (line, (seq, target, id)) in lines[1..].iter().zip([
+            (0, "index", &ids[0]),
+            (1, "toc", &ids[1]),
+            (2, "index", &ids[2]),
+        ]) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            assert_eq!(fields[0], seq.to_string());
+            assert_eq!(fields[1], target);
+            assert_eq!(*fields.last().unwrap(), id.as_str());
+        }
+
+        // --from-seq and --limit select a window
+        let out = format_outbox_entries(&storage.get_outbox_entries(1, 1).unwrap());
+        assert_eq!(out.lines().count(), 2);
+        assert!(out.contains(&ids[1]));
+
+        let stats = format_outbox_stats(
+            storage.outbox_lag().unwrap(),
+            storage.outbox_sequence_range().unwrap(),
+            None,
+        );
+        assert!(stats.contains("  Pending:   3\n"));
+        assert!(stats.contains("  Sequences: 0 - 2\n"));
+    }
+
+    #[test]
+    fn test_ensure_indexes_builds_missing_bm25() {
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchOptions};
+        use memory_types::{Grip, TocLevel, TocNode};
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path();
+        let storage = Arc::new(Storage::open(db_path).unwrap());
+        let no_embedder: Option<Arc<CandleEmbedder>> = None;
+
+        // Empty storage: nothing to build
+        let built = ensure_indexes(
+            storage.clone(),
+            db_path,
+            no_embedder.clone(),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(built, EnsuredIndexes::default());
+        assert!(!db_path.join("search").exists());
+
+        let now = chrono::Utc::now();
+        let mut node = TocNode::new(
+            "toc:day:2024-01-15".to_string(),
+            TocLevel::Day,
+            "Debugging the rocksdb compaction".to_string(),
+            now,
+            now,
+        );
+        node.keywords = vec!["rocksdb".to_string()];
+        storage.put_toc_node(&node).unwrap();
+        storage
+            .put_grip(&Grip::new(
+                "grip:1".to_string(),
+                "compaction stalls under write load".to_string(),
+                "event-1".to_string(),
+                "event-2".to_string(),
+                now,
+                "test".to_string(),
+            ))
+            .unwrap();
+
+        let built = ensure_indexes(
+            storage.clone(),
+            db_path,
+            no_embedder.clone(),
+            &Default::default(),
+        )
+        .unwrap();
+        assert!(built.bm25_built);
+        assert!(!built.vector_built);
+
+        let index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(db_path.join("search"))).unwrap();
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        assert_eq!(searcher.num_docs(), 2);
+        assert_eq!(open_search_searcher(db_path).unwrap().num_docs(), 2);
+        let results = searcher.search("compaction", SearchOptions::new()).unwrap();
+        assert_eq!(results.len(), 2);
+
+        // Populated indexes are left alone
+        let built = ensure_indexes(storage, db_path, no_embedder, &Default::default()).unwrap();
+        assert_eq!(built, EnsuredIndexes::default());
+    }
+
+    #[test]
+    fn test_format_subtree() {
+        use memory_service::pb::{SubtreeNode, TocNode as ProtoTocNode};
+
+        let entry = |id: &str, level: ProtoTocLevel, depth: u32| SubtreeNode {
+            node: Some(ProtoTocNode {
+                node_id: id.to_string(),
+                level: level as i32,
+                title: id.to_string(),
+                ..Default::default()
+            }),
+            parent_id: None,
+            depth,
+        };
+        let result = memory_client::GetSubtreeResult {
+            nodes: vec![
+                entry("toc:year:2026", ProtoTocLevel::Year, 0),
+                entry("toc:month:2026-01", ProtoTocLevel::Month, 1),
+            ],
+            truncated: true,
+        };
+
+        let text = format_subtree("toc:year:2026", &result);
+        assert!(text.starts_with("Subtree of toc:year:2026 (2 nodes):\n\n"));
+        assert!(text.contains("\n  toc:year:2026 [Year]  toc:year:2026\n"));
+        assert!(text.contains("\n    toc:month:2026-01 [Month]  toc:month:2026-01\n"));
+        assert!(text.contains("Node limit reached"));
+    }
+
+    #[test]
+    fn test_format_node_history() {
+        let changes = vec![
+            memory_client::TocNodeChange {
+                version: 1,
+                title: "Monday".to_string(),
+                children_added: vec!["toc:segment:2026-01-12:a".to_string()],
+                ..Default::default()
+            },
+            memory_client::TocNodeChange {
+                version: 2,
+                title: "Monday: auth work".to_string(),
+                previous_title: Some("Monday".to_string()),
+                bullets_added: vec!["Added JWT".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        let text = format_node_history("toc:day:2026-01-12", &changes);
+        assert!(text.starts_with("History of toc:day:2026-01-12 (2 versions):\n"));
+        assert!(text.contains("  + child: toc:segment:2026-01-12:a\n"));
+        assert!(text.contains("  title: Monday -> Monday: auth work\n"));
+        assert!(text.contains("  + bullet: Added JWT\n"));
+        assert!(!text.contains("- keyword"));
+    }
+
+    #[test]
+    fn test_format_novelty_stats() {
+        let metrics = memory_service::pb::GetNoveltyMetricsResponse {
+            enabled: true,
+            total_seen: 8,
+            suppressed: 2,
+            stored_novel: 5,
+            skipped: 1,
+            suppression_rate: 0.25,
+            window_size: 256,
+        };
+
+        let text = format_novelty_stats(&metrics);
+        assert!(text.contains("  Status:      enabled\n"));
+        assert!(text.contains("  Window:      256 events\n"));
+        assert!(text.contains("  Suppressed:  2 (25.0%)\n"));
+        assert!(text.contains("  Unchecked:   1\n"));
+    }
+
+    #[test]
+    fn test_format_event_results() {
+        use memory_service::pb::{Event as ProtoEvent, EventSearchResult};
+
+        let response = memory_client::SearchEventsResponse {
+            results: vec![EventSearchResult {
+                event: Some(ProtoEvent {
+                    event_id: "01HN4QXKN6YWXVKZ3JMHP4BCDE".to_string(),
+                    session_id: "session-1".to_string(),
+                    text: "Deploy failed with a kafka timeout".to_string(),
+                    agent: Some("claude".to_string()),
+                    ..Default::default()
+                }),
+                score: 1.5,
+            }],
+            event_docs: 3,
+        };
+        let text = format_event_results("kafka", &response);
+        assert!(text.contains("1. 01HN4QXKN6YWXVKZ3JMHP4BCDE (score: 1.5000)"));
+        assert!(text.contains("session: session-1  agent: claude"));
+        assert!(text.contains("   Deploy failed with a kafka timeout\n"));
+        assert!(text.contains("Indexed events: 3"));
+
+        let empty = memory_client::SearchEventsResponse::default();
+        let text = format_event_results("kafka", &empty);
+        assert!(text.contains("No results found."));
+        assert!(text.contains("index_raw_events"));
+    }
+
+    #[test]
+    fn test_lifecycle_configs() {
+        let mut lifecycle = memory_types::LifecycleConfig::default();
+        lifecycle.vector.retention.insert("segment".to_string(), 14);
+        let (bm25, vector) = lifecycle_configs(&lifecycle).unwrap();
+        assert!(!bm25.enabled);
+        assert_eq!(vector.segment_retention_days, 14);
+
+        lifecycle.bm25.retention.insert("year".to_string(), 3650);
+        let err = lifecycle_configs(&lifecycle).unwrap_err();
+        assert!(format!("{:#}", err).contains("[lifecycle.bm25]"));
+    }
+
+    #[test]
+    fn test_format_vector_prune() {
+        let response = PruneVectorIndexResponse {
+            success: true,
+            message: "3 vector metadata entries eligible for pruning".to_string(),
+            would_remove: [("grip".to_string(), 2), ("day".to_string(), 1)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let out = format_vector_prune(&response, true);
+        assert!(out.contains("day:           1\n"));
+        assert!(out.contains("grip:          2\n"));
+        assert!(out.contains("Would remove:  3\n"));
+        assert!(out.find("day:").unwrap() < out.find("grip:").unwrap());
+
+        let response = PruneVectorIndexResponse {
+            success: true,
+            grips_pruned: 2,
+            message: "2 pruned".to_string(),
+            ..Default::default()
+        };
+        let out = format_vector_prune(&response, false);
+        assert!(out.contains("grip:          2\n"));
+        assert!(!out.contains("Would remove"));
+    }
+
+    #[test]
+    fn test_format_replay() {
+        use memory_indexing::{IndexType, ProcessResult, UpdateResult};
+
+        let mut result = ProcessResult::new();
+        result.add_result(
+            IndexType::Bm25,
+            UpdateResult {
+                processed: 3,
+                skipped: 0,
+                errors: 1,
+                last_sequence: 9,
+            },
+        );
+
+        let out = format_replay(6, &result);
+        assert!(out.contains("  From sequence: 6\n"));
+        assert!(out.contains("  To sequence:   9\n"));
+        assert!(out.contains("  Processed:     3\n"));
+        assert!(out.contains("  Errors:        1\n"));
+
+        let out = format_replay(6, &ProcessResult::new());
+        assert!(out.contains("(nothing to replay)"));
+        assert!(!out.contains("Errors"));
     }
 
     #[test]
@@ -3195,6 +5712,15 @@ mod tests {
         assert_eq!(ms, 1707350400000);
     }
 
+    #[test]
+    fn test_parse_time_range() {
+        let (start, end) = parse_time_range(Some("2024-02-01"), Some("2024-02-08")).unwrap();
+        assert_eq!(start, Some(parse_time_arg("2024-02-01").unwrap()));
+        assert_eq!(end, Some(parse_time_arg("2024-02-08").unwrap()));
+        assert_eq!(parse_time_range(None, None).unwrap(), (None, None));
+        assert!(parse_time_range(Some("2024-02-08"), Some("2024-02-01")).is_err());
+    }
+
     #[test]
     fn test_parse_time_arg_invalid() {
         assert!(parse_time_arg("not-a-date").is_err());
@@ -3220,7 +5746,7 @@ mod tests {
     // 2. `pick_summarizer_kind` — pure decision logic (no env mutation)
     // 3. `resolve_api_key` — key resolution with precedence (uses env vars)
     //
-    // `build_summarizer` itself is just glue between (2) and (3) plus
+    // `build_api_summarizer` itself is just glue between (2) and (3) plus
     // `ApiSummarizer::new`; its behavior is verified through those layers.
 
     // ── env_var_for_provider ─────────────────────────────────────────────────
@@ -3326,7 +5852,7 @@ mod tests {
         );
     }
 
-    /// Unknown providers fall through to OpenAi (build_summarizer logs a warning).
+    /// Unknown providers fall through to OpenAi (build_api_summarizer logs a warning).
     #[test]
     fn test_pick_kind_unknown_provider_defaults_to_openai() {
         let settings = SummarizerSettings {
@@ -3422,22 +5948,49 @@ mod tests {
         );
     }
 
-    // ── build_summarizer smoke test ─────────────────────────────────────────
+    // ── build_api_summarizer smoke test ─────────────────────────────────────
 
-    /// Smoke test: build_summarizer wires resolve+pick+construct together
-    /// without panicking on the fallback path. Behavior of each layer is
-    /// asserted by the focused tests above.
+    /// Smoke test: build_api_summarizer wires resolve+pick+construct
+    /// together. Behavior of each layer is asserted by the focused tests above.
     #[test]
-    fn test_build_summarizer_smoke_no_key_returns_arc() {
+    fn test_build_api_summarizer_smoke() {
+        use memory_toc::summarizer::Summarizer;
+
         let settings = SummarizerSettings {
             provider: "openai".to_string(),
             api_key: None,
             api_key_env: Some("__TEST_BUILD_SMOKE_UNSET__".to_string()),
             ..SummarizerSettings::default()
         };
-        // Just verify the call returns and the Arc is usable as the trait object.
-        let summarizer: Arc<dyn memory_toc::summarizer::Summarizer> = build_summarizer(&settings);
-        // Arc::strong_count proves we got a real allocation, not a NULL/uninit value.
-        assert_eq!(Arc::strong_count(&summarizer), 1);
+        // No key: the caller falls back to the mock
+        assert!(build_api_summarizer(&settings).unwrap().is_none());
+
+        let with_key = SummarizerSettings {
+            api_key: Some("sk-test".to_string()),
+            ..settings
+        };
+        let summarizer = build_api_summarizer(&with_key).unwrap().unwrap();
+        assert_eq!(summarizer.summarizer_id(), "api:gpt-4o-mini");
+    }
+
+    /// A template missing its placeholder aborts startup instead of
+    /// falling back to the mock, even when no API key is configured.
+    #[test]
+    fn test_build_api_summarizer_rejects_invalid_template() {
+        let settings = SummarizerSettings {
+            provider: "openai".to_string(),
+            api_key: Some("sk-test".to_string()),
+            prompt_template: Some("Summarize the conversation.".to_string()),
+            ..SummarizerSettings::default()
+        };
+        let err = build_api_summarizer(&settings).err().unwrap();
+        assert!(format!("{err:#}").contains("{events}"));
+
+        let no_key = SummarizerSettings {
+            api_key: None,
+            api_key_env: Some("__TEST_BUILD_TEMPLATE_UNSET__".to_string()),
+            ..settings
+        };
+        assert!(build_api_summarizer(&no_key).is_err());
     }
 }
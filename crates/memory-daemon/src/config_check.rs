@@ -0,0 +1,267 @@
+//! Static validation of daemon settings.
+//!
+//! Runs the checks that would otherwise fail deep into `start`, without
+//! opening storage or binding the port. The BM25 analyzers are fixed per
+//! language in `memory-search` and have no settings, so there is nothing
+//! to check for them here.
+
+use std::net::SocketAddr;
+use std::path::Path;
+
+use memory_scheduler::{validate_cron_expression, SchedulerConfig};
+use memory_service::Redactor;
+use memory_toc::summarizer::ApiSummarizerConfig;
+use memory_types::Settings;
+
+/// Outcome of validating one settings field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigCheck {
+    /// Dotted settings key, as written in config.toml
+    pub field: String,
+    /// `Err` holds a description of the problem
+    pub result: Result<(), String>,
+}
+
+impl ConfigCheck {
+    fn new(field: &str, result: Result<(), String>) -> Self {
+        Self {
+            field: field.to_string(),
+            result,
+        }
+    }
+
+    /// Whether this field passed.
+    pub fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Validate every checkable field of `settings`.
+///
+/// Returns one entry per field in a stable order, passing or not.
+pub fn validate_settings(settings: &Settings) -> Vec<ConfigCheck> {
+    vec![
+        ConfigCheck::new("grpc_port", check_port(settings.grpc_port)),
+        ConfigCheck::new("grpc_host", check_addr(settings)),
+        ConfigCheck::new("db_path", check_writable_dir(&settings.expanded_db_path())),
+        ConfigCheck::new(
+            "scheduler.default_timezone",
+            check_timezone(&settings.scheduler.default_timezone),
+        ),
+        ConfigCheck::new(
+            "lifecycle.vector.prune_schedule",
+            check_cron(&settings.lifecycle.vector.prune_schedule),
+        ),
+        ConfigCheck::new(
+            "lifecycle.bm25.rebuild_schedule",
+            check_cron(&settings.lifecycle.bm25.rebuild_schedule),
+        ),
+        ConfigCheck::new(
+            "lifecycle.toc_versions.prune_schedule",
+            check_cron(&settings.lifecycle.toc_versions.prune_schedule),
+        ),
+        ConfigCheck::new(
+            "lifecycle.vector.retention",
+            memory_vector::VectorLifecycleConfig::from_settings(&settings.lifecycle.vector)
+                .map(|_| ()),
+        ),
+        ConfigCheck::new(
+            "lifecycle.bm25.retention",
+            memory_search::Bm25LifecycleConfig::from_settings(&settings.lifecycle.bm25).map(|_| ()),
+        ),
+        ConfigCheck::new("vector", settings.vector.validate()),
+        ConfigCheck::new(
+            "summarizer.prompt_template",
+            check_templates(settings.summarizer.prompt_template.clone(), None),
+        ),
+        ConfigCheck::new(
+            "summarizer.rollup_prompt_template",
+            check_templates(None, settings.summarizer.rollup_prompt_template.clone()),
+        ),
+        ConfigCheck::new(
+            "redaction_patterns",
+            Redactor::new(&settings.redaction_patterns)
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+        ),
+    ]
+}
+
+fn check_port(port: u16) -> Result<(), String> {
+    if port == 0 {
+        return Err("port must be between 1 and 65535".to_string());
+    }
+    Ok(())
+}
+
+fn check_addr(settings: &Settings) -> Result<(), String> {
+    settings
+        .grpc_addr()
+        .parse::<SocketAddr>()
+        .map(|_| ())
+        .map_err(|e| {
+            format!(
+                "'{}' is not a valid bind address: {}",
+                settings.grpc_host, e
+            )
+        })
+}
+
+/// The database directory, or the nearest existing ancestor the daemon
+/// would create it under, must be a directory we can create files in.
+///
+/// Permission bits do not account for ownership, ACLs or read-only mounts,
+/// so this probes by creating and removing a file.
+fn check_writable_dir(path: &Path) -> Result<(), String> {
+    let existing = path
+        .ancestors()
+        .find(|p| p.exists())
+        .ok_or_else(|| format!("no existing parent directory for {}", path.display()))?;
+    if !existing.is_dir() {
+        return Err(format!("{} is not a directory", existing.display()));
+    }
+    let probe = existing.join(format!(".memory-config-check-{}", std::process::id()));
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .map_err(|e| format!("{} is not writable: {}", existing.display(), e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Custom summarizer prompts must contain their required placeholders.
+fn check_templates(prompt: Option<String>, rollup: Option<String>) -> Result<(), String> {
+    let mut config = ApiSummarizerConfig::openai("", "");
+    config.prompt_template = prompt;
+    config.rollup_prompt_template = rollup;
+    config.validate().map_err(|e| e.to_string())
+}
+
+fn check_timezone(tz: &str) -> Result<(), String> {
+    SchedulerConfig {
+        default_timezone: tz.to_string(),
+        ..Default::default()
+    }
+    .parse_timezone()
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+/// Lifecycle schedules are written in 5-field cron; jobs prepend seconds.
+fn check_cron(expr: &str) -> Result<(), String> {
+    let expr = if expr.split_whitespace().count() == 5 {
+        format!("0 {}", expr)
+    } else {
+        expr.to_string()
+    };
+    validate_cron_expression(&expr).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn valid_settings(dir: &TempDir) -> Settings {
+        Settings {
+            db_path: dir.path().join("db").to_string_lossy().to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn failures(settings: &Settings) -> Vec<String> {
+        validate_settings(settings)
+            .into_iter()
+            .filter(|c| !c.passed())
+            .map(|c| c.field)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_settings_pass() {
+        let dir = TempDir::new().unwrap();
+        let checks = validate_settings(&valid_settings(&dir));
+        assert!(checks.iter().all(ConfigCheck::passed), "{checks:?}");
+    }
+
+    #[test]
+    fn test_invalid_port() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings {
+            grpc_port: 0,
+            ..valid_settings(&dir)
+        };
+        assert_eq!(failures(&settings), vec!["grpc_port"]);
+    }
+
+    #[test]
+    fn test_bad_cron() {
+        let dir = TempDir::new().unwrap();
+        let mut settings = valid_settings(&dir);
+        settings.lifecycle.vector.prune_schedule = "every day at 3".to_string();
+        assert_eq!(failures(&settings), vec!["lifecycle.vector.prune_schedule"]);
+    }
+
+    #[test]
+    fn test_bad_vector_settings() {
+        let dir = TempDir::new().unwrap();
+        let mut settings = valid_settings(&dir);
+        settings.vector.ef_construction = settings.vector.m - 1;
+        assert_eq!(failures(&settings), vec!["vector"]);
+    }
+
+    #[test]
+    fn test_bad_timezone() {
+        let dir = TempDir::new().unwrap();
+        let mut settings = valid_settings(&dir);
+        settings.scheduler.default_timezone = "Mars/Olympus_Mons".to_string();
+
+        let checks = validate_settings(&settings);
+        let tz = checks
+            .iter()
+            .find(|c| c.field == "scheduler.default_timezone")
+            .unwrap();
+        assert!(tz
+            .result
+            .as_ref()
+            .unwrap_err()
+            .contains("Mars/Olympus_Mons"));
+        assert_eq!(failures(&settings), vec!["scheduler.default_timezone"]);
+    }
+
+    #[test]
+    fn test_db_path_under_file_fails() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("not-a-dir");
+        std::fs::write(&file, b"").unwrap();
+        let settings = Settings {
+            db_path: file.join("db").to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        assert_eq!(failures(&settings), vec!["db_path"]);
+    }
+
+    #[test]
+    fn test_bad_prompt_templates() {
+        let dir = TempDir::new().unwrap();
+        let mut settings = valid_settings(&dir);
+        settings.summarizer.prompt_template = Some("Summarize {events}".to_string());
+        settings.summarizer.rollup_prompt_template = Some("Merge these".to_string());
+        assert_eq!(
+            failures(&settings),
+            vec!["summarizer.rollup_prompt_template"]
+        );
+
+        settings.summarizer.prompt_template = Some("Summarize this".to_string());
+        settings.summarizer.rollup_prompt_template = None;
+        assert_eq!(failures(&settings), vec!["summarizer.prompt_template"]);
+    }
+
+    #[test]
+    fn test_writable_dir_leaves_no_probe() {
+        let dir = TempDir::new().unwrap();
+        assert!(check_writable_dir(&dir.path().join("db")).is_ok());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
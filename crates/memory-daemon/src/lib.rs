@@ -6,17 +6,21 @@
 //!
 //! - `cli`: Command-line argument parsing with clap
 //! - `commands`: Command implementations (start, stop, status)
+//! - `config_check`: Settings validation for `config validate`
 
 pub mod cli;
 pub mod clod;
 pub mod commands;
+pub mod config_check;
 
 pub use cli::{
-    AdminCommands, AgentsCommand, Cli, ClodCliCommand, Commands, QueryCommands, RetrievalCommand,
-    SchedulerCommands, TeleportCommand, TopicsCommand,
+    AdminCommands, AgentsCommand, Cli, ClodCliCommand, Commands, ConfigCommand, EmbeddingsCommand,
+    NoveltyCommand, OutboxCommands, QueryCommands, RetrievalCommand, SchedulerCommands,
+    TeleportCommand, TopicsCommand,
 };
 pub use commands::{
-    handle_admin, handle_agents_command, handle_clod_command, handle_query,
-    handle_retrieval_command, handle_scheduler, handle_teleport_command, handle_topics_command,
-    show_status, show_verbose_status, start_daemon, stop_daemon,
+    handle_admin, handle_agents_command, handle_clod_command, handle_config_command,
+    handle_embeddings_command, handle_novelty_command, handle_query, handle_retrieval_command,
+    handle_scheduler, handle_teleport_command, handle_topics_command, show_status,
+    show_verbose_status, start_daemon, stop_daemon,
 };
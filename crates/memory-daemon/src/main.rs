@@ -8,6 +8,8 @@
 //! memory-daemon start [--foreground] [--port PORT] [--db-path PATH]
 //! memory-daemon stop
 //! memory-daemon status
+//! memory-daemon config validate [--config PATH]
+//! memory-daemon embeddings bench [--count N] [--batch B]
 //! ```
 //!
 //! # Configuration
@@ -22,9 +24,10 @@ use anyhow::Result;
 use clap::Parser;
 
 use memory_daemon::{
-    handle_admin, handle_agents_command, handle_clod_command, handle_query,
-    handle_retrieval_command, handle_scheduler, handle_teleport_command, handle_topics_command,
-    show_status, show_verbose_status, start_daemon, stop_daemon, Cli, Commands,
+    handle_admin, handle_agents_command, handle_clod_command, handle_config_command,
+    handle_embeddings_command, handle_novelty_command, handle_query, handle_retrieval_command,
+    handle_scheduler, handle_teleport_command, handle_topics_command, show_status,
+    show_verbose_status, start_daemon, stop_daemon, Cli, Commands,
 };
 
 #[tokio::main]
@@ -43,6 +46,7 @@ async fn main() -> Result<()> {
                 port,
                 db_path.as_deref(),
                 cli.log_level.as_deref(),
+                cli.log_format,
             )
             .await?;
         }
@@ -76,9 +80,18 @@ async fn main() -> Result<()> {
         Commands::Agents(cmd) => {
             handle_agents_command(cmd).await?;
         }
+        Commands::Novelty(cmd) => {
+            handle_novelty_command(cmd).await?;
+        }
         Commands::Clod(cmd) => {
             handle_clod_command(cmd).await?;
         }
+        Commands::Config(cmd) => {
+            handle_config_command(cli.config.as_deref(), cmd)?;
+        }
+        Commands::Embeddings(cmd) => {
+            handle_embeddings_command(cmd)?;
+        }
     }
 
     Ok(())
@@ -10,8 +10,8 @@ use std::time::Duration;
 use tempfile::TempDir;
 use tokio::time::sleep;
 
-use memory_client::{map_hook_event, HookEvent, HookEventType, MemoryClient};
-use memory_service::run_server_with_shutdown;
+use memory_client::{map_hook_event, HookEvent, HookEventType, MemoryClient, RetryConfig};
+use memory_service::{run_server_with_shutdown, DEFAULT_DRAIN_TIMEOUT};
 use memory_storage::Storage;
 use memory_types::{Event, EventRole, EventType};
 
@@ -35,9 +35,14 @@ impl TestHarness {
 
         let service_storage = storage.clone();
         let server_handle = tokio::spawn(async move {
-            run_server_with_shutdown(addr, service_storage, async {
-                shutdown_rx.await.ok();
-            })
+            run_server_with_shutdown(
+                addr,
+                service_storage,
+                async {
+                    shutdown_rx.await.ok();
+                },
+                DEFAULT_DRAIN_TIMEOUT,
+            )
             .await
         });
 
@@ -381,3 +386,117 @@ async fn test_hook_event_mapping_all_types() {
     let stats = harness.storage.get_stats().unwrap();
     assert_eq!(stats.event_count, 8);
 }
+
+// ==================== Hook Stream Replay Tests ====================
+
+/// Captured CCH stream with one malformed line (line 3).
+const REPLAY_FIXTURE: &str = r#"{"hook_event_name":"SessionStart","session_id":"replay-1","agent":"claude"}
+{"hook_event_name":"UserPromptSubmit","session_id":"replay-1","message":"How do I replay hooks?"}
+{"hook_event_name":"AssistantResponse","session_id":
+{"hook_event_name":"PostToolUse","session_id":"replay-1","tool_name":"Read","tool_input":{"path":"/tmp/a.rs"}}
+
+{"hook_event_name":"Stop","session_id":"replay-1"}
+"#;
+
+#[tokio::test]
+async fn test_ingest_hook_stream_replay() {
+    let harness = TestHarness::new(50111).await;
+    let mut client = harness.client().await;
+
+    let summary = client
+        .ingest_hook_stream(std::io::Cursor::new(REPLAY_FIXTURE))
+        .await
+        .unwrap();
+
+    assert_eq!(summary.created, 4);
+    assert_eq!(summary.skipped, 0);
+    assert_eq!(summary.errors.len(), 1);
+    assert_eq!(summary.errors[0].line, 3);
+
+    let stats = harness.storage.get_stats().unwrap();
+    assert_eq!(stats.event_count, 4);
+}
+
+// ==================== Reconnect Tests ====================
+
+type ServerHandle = tokio::task::JoinHandle<Result<(), Box<dyn std::error::Error + Send + Sync>>>;
+
+/// Start a server on `addr`, returning its shutdown sender and task handle.
+fn spawn_server(
+    addr: SocketAddr,
+    storage: Arc<Storage>,
+) -> (tokio::sync::oneshot::Sender<()>, ServerHandle) {
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+    let handle = tokio::spawn(async move {
+        run_server_with_shutdown(
+            addr,
+            storage,
+            async {
+                shutdown_rx.await.ok();
+            },
+            DEFAULT_DRAIN_TIMEOUT,
+        )
+        .await
+    });
+    (shutdown_tx, handle)
+}
+
+#[tokio::test]
+async fn test_client_reconnects_after_daemon_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Storage::open(temp_dir.path()).unwrap());
+    let addr: SocketAddr = "127.0.0.1:50112".parse().unwrap();
+    let endpoint = format!("http://{}", addr);
+
+    let (shutdown_tx, handle) = spawn_server(addr, storage.clone());
+    sleep(Duration::from_millis(200)).await;
+
+    let retry = RetryConfig::default()
+        .with_max_retries(10)
+        .with_initial_backoff(Duration::from_millis(50))
+        .with_max_backoff(Duration::from_millis(200));
+    let mut client = MemoryClient::connect_with_retry(&endpoint, retry)
+        .await
+        .unwrap();
+
+    let hook = HookEvent::new("reconnect", HookEventType::UserPromptSubmit, "before");
+    client.ingest(map_hook_event(hook)).await.unwrap();
+    assert_eq!(client.reconnect_count(), 0);
+
+    // Stop the daemon, then bring it back shortly after the next call starts
+    shutdown_tx.send(()).unwrap();
+    let _ = tokio::time::timeout(Duration::from_secs(5), handle).await;
+
+    let restart_storage = storage.clone();
+    let restart = tokio::spawn(async move {
+        sleep(Duration::from_millis(300)).await;
+        spawn_server(addr, restart_storage)
+    });
+
+    let hook = HookEvent::new("reconnect", HookEventType::UserPromptSubmit, "after");
+    let (_, created) = client.ingest(map_hook_event(hook)).await.unwrap();
+    assert!(created);
+    assert!(client.reconnect_count() >= 1);
+
+    let stats = storage.get_stats().unwrap();
+    assert_eq!(stats.event_count, 2);
+
+    let (shutdown_tx, _handle) = restart.await.unwrap();
+    let _ = shutdown_tx.send(());
+}
+
+#[tokio::test]
+async fn test_connect_with_retry_gives_up_when_unreachable() {
+    // Nothing listens on this port; the first RPC must fail immediately.
+    let result = MemoryClient::connect("http://127.0.0.1:50113").await;
+    assert!(result.is_err());
+
+    let retry = RetryConfig::default()
+        .with_max_retries(2)
+        .with_initial_backoff(Duration::from_millis(10));
+    let result = MemoryClient::connect_with_retry("http://127.0.0.1:50113", retry).await;
+    assert!(matches!(
+        result,
+        Err(memory_client::ClientError::Connection(_))
+    ));
+}
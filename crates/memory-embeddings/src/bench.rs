@@ -0,0 +1,107 @@
+//! Embedding throughput benchmark.
+//!
+//! Times [`EmbeddingModel::embed_batch`] over synthetic texts so users can
+//! check whether their hardware keeps up before enabling vector search.
+
+use std::time::{Duration, Instant};
+
+use crate::error::EmbeddingError;
+use crate::model::EmbeddingModel;
+
+/// Result of [`bench_embedder`].
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    /// Texts embedded
+    pub count: usize,
+    /// Texts per `embed_batch` call
+    pub batch_size: usize,
+    /// Wall time across all batches
+    pub total: Duration,
+    /// Texts embedded per second
+    pub embeddings_per_sec: f64,
+    /// Median batch latency
+    pub p50: Duration,
+    /// 95th percentile batch latency
+    pub p95: Duration,
+}
+
+/// Embed `count` synthetic texts in batches of `batch_size`, timing each batch.
+pub fn bench_embedder(
+    embedder: &dyn EmbeddingModel,
+    count: usize,
+    batch_size: usize,
+) -> Result<BenchReport, EmbeddingError> {
+    if count == 0 || batch_size == 0 {
+        return Err(EmbeddingError::InvalidInput(
+            "count and batch size must be > 0".to_string(),
+        ));
+    }
+
+    let texts: Vec<String> = (0..count).map(synthetic_text).collect();
+    let mut latencies = Vec::with_capacity(count.div_ceil(batch_size));
+    let start = Instant::now();
+    for batch in texts.chunks(batch_size) {
+        let refs: Vec<&str> = batch.iter().map(String::as_str).collect();
+        let batch_start = Instant::now();
+        embedder.embed_batch(&refs)?;
+        latencies.push(batch_start.elapsed());
+    }
+    let total = start.elapsed();
+
+    latencies.sort();
+    Ok(BenchReport {
+        count,
+        batch_size,
+        total,
+        embeddings_per_sec: count as f64 / total.as_secs_f64().max(f64::EPSILON),
+        p50: percentile(&latencies, 50),
+        p95: percentile(&latencies, 95),
+    })
+}
+
+/// A conversation-like sentence, distinct per index.
+fn synthetic_text(i: usize) -> String {
+    format!(
+        "Message {i}: the user asked how to configure the scheduler timezone \
+         and the assistant explained the cron format for job {}",
+        i % 17
+    )
+}
+
+/// Nearest-rank percentile of sorted, non-empty `values`.
+fn percentile(values: &[Duration], pct: usize) -> Duration {
+    let rank = (values.len() * pct).div_ceil(100).max(1);
+    values[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HashEmbedder;
+
+    #[test]
+    fn test_bench_hash_embedder() {
+        let report = bench_embedder(&HashEmbedder::new(32), 25, 4).unwrap();
+        assert_eq!(report.count, 25);
+        assert_eq!(report.batch_size, 4);
+        assert!(report.embeddings_per_sec > 0.0);
+        assert!(report.embeddings_per_sec.is_finite());
+        assert!(report.p50 <= report.p95);
+        assert!(report.p95 <= report.total);
+    }
+
+    #[test]
+    fn test_bench_rejects_zero_sizes() {
+        let embedder = HashEmbedder::new(8);
+        assert!(bench_embedder(&embedder, 0, 4).is_err());
+        assert!(bench_embedder(&embedder, 4, 0).is_err());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&values, 50), Duration::from_millis(10));
+        assert_eq!(percentile(&values, 95), Duration::from_millis(19));
+        assert_eq!(percentile(&values[..1], 95), Duration::from_millis(1));
+    }
+}
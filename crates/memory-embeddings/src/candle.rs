@@ -87,6 +87,14 @@ impl CandleEmbedder {
         })
     }
 
+    /// Run a dummy embedding so the first real call doesn't pay for
+    /// faulting in the mmapped weights.
+    pub fn warm_up(&self) -> Result<(), EmbeddingError> {
+        self.embed("warm up")?;
+        debug!("Embedding model warmed up");
+        Ok(())
+    }
+
     /// Mean pooling over token embeddings (excluding padding)
     fn mean_pooling(
         &self,
@@ -219,6 +227,15 @@ mod tests {
         assert_eq!(emb.dimension(), EMBEDDING_DIM);
     }
 
+    #[test]
+    #[ignore = "requires model download"]
+    fn test_warm_up() {
+        let embedder = CandleEmbedder::load_default().unwrap();
+        embedder.warm_up().unwrap();
+        let emb = embedder.embed("Hello, world!").unwrap();
+        assert_eq!(emb.dimension(), EMBEDDING_DIM);
+    }
+
     #[test]
     #[ignore = "requires model download"]
     fn test_embed_batch() {
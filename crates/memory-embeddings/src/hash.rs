@@ -0,0 +1,142 @@
+//! Deterministic hash embeddings for offline use.
+//!
+//! [`HashEmbedder`] maps text to vectors by feature hashing: each lowercase
+//! word is hashed to one dimension and a sign, and the counts are normalized
+//! to unit length. Texts sharing words land close together, identical texts
+//! get identical vectors, and nothing has to be downloaded. It carries no
+//! semantics beyond word overlap, so it is meant for tests and CI runs that
+//! exercise the vector pipeline without the Candle model.
+
+use crate::candle::EMBEDDING_DIM;
+use crate::error::EmbeddingError;
+use crate::model::{Embedding, EmbeddingModel, ModelInfo};
+
+/// FNV-1a offset basis (64-bit).
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime (64-bit).
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Embedder producing feature-hashed pseudo-embeddings.
+#[derive(Debug, Clone)]
+pub struct HashEmbedder {
+    info: ModelInfo,
+}
+
+impl HashEmbedder {
+    /// Create an embedder producing vectors of `dimension` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is 0.
+    pub fn new(dimension: usize) -> Self {
+        assert!(dimension > 0, "embedding dimension must be > 0");
+        Self {
+            info: ModelInfo {
+                name: "hash-embedder".to_string(),
+                dimension,
+                max_sequence_length: usize::MAX,
+            },
+        }
+    }
+}
+
+impl Default for HashEmbedder {
+    /// Same dimension as the Candle model, so it can stand in for it.
+    fn default() -> Self {
+        Self::new(EMBEDDING_DIM)
+    }
+}
+
+impl EmbeddingModel for HashEmbedder {
+    fn info(&self) -> &ModelInfo {
+        &self.info
+    }
+
+    fn embed(&self, text: &str) -> Result<Embedding, EmbeddingError> {
+        let dimension = self.info.dimension;
+        let mut values = vec![0.0f32; dimension];
+
+        let words = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty());
+        for word in words {
+            let hash = fnv1a(word.to_lowercase().as_bytes());
+            let bucket = (hash % dimension as u64) as usize;
+            // Top bit picks the sign so unrelated words tend to cancel out
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            values[bucket] += sign;
+        }
+
+        // Texts without words (or whose words cancel) still get a unit vector
+        if values.iter().all(|v| *v == 0.0) {
+            values[0] = 1.0;
+        }
+
+        Ok(Embedding::new(values))
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCS: [&str; 3] = [
+        "Configure the RocksDB block cache size for the event store",
+        "Bake the sourdough bread at high heat with steam",
+        "Deploy the gRPC service behind a load balancer",
+    ];
+
+    #[test]
+    fn test_identical_text_identical_vectors() {
+        let embedder = HashEmbedder::default();
+        let a = embedder.embed(DOCS[0]).unwrap();
+        let b = HashEmbedder::default().embed(DOCS[0]).unwrap();
+
+        assert_eq!(a.dimension(), embedder.info().dimension);
+        assert_eq!(a.dimension(), EMBEDDING_DIM);
+        assert_eq!(a.values, b.values);
+        assert!((a.cosine_similarity(&b) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_vectors_are_unit_length() {
+        let embedder = HashEmbedder::new(32);
+        for text in DOCS.iter().copied().chain(["", "  ?! "]) {
+            let emb = embedder.embed(text).unwrap();
+            let norm: f32 = emb.values.iter().map(|v| v * v).sum::<f32>().sqrt();
+            assert_eq!(emb.dimension(), 32);
+            assert!((norm - 1.0).abs() < 1e-5, "{text:?} has norm {norm}");
+        }
+    }
+
+    #[test]
+    fn test_distinct_text_nearest_neighbor() {
+        let embedder = HashEmbedder::default();
+        let docs = embedder.embed_batch(&DOCS).unwrap();
+
+        let queries = [
+            ("rocksdb block cache", 0),
+            ("sourdough bread steam", 1),
+            ("grpc load balancer", 2),
+        ];
+        for (query, expected) in queries {
+            let query = embedder.embed(query).unwrap();
+            let scores: Vec<f32> = docs.iter().map(|d| query.cosine_similarity(d)).collect();
+            let best = (0..scores.len())
+                .max_by(|&a, &b| scores[a].total_cmp(&scores[b]))
+                .unwrap();
+            assert_eq!(best, expected, "scores: {scores:?}");
+        }
+
+        // Different documents are clearly apart
+        assert!(docs[0].cosine_similarity(&docs[1]) < 0.5);
+    }
+}
@@ -10,18 +10,23 @@
 //! - all-MiniLM-L6-v2 model (384 dimensions)
 //! - Automatic model file caching
 //! - Batch embedding for efficiency
+//! - Deterministic hash embeddings for offline tests (`HashEmbedder`)
 //!
 //! ## Requirements
 //! - FR-01: Local embedding via Candle
 //! - No external API dependencies
 //! - Works offline after initial model download
 
+pub mod bench;
 pub mod cache;
 pub mod candle;
 pub mod error;
+pub mod hash;
 pub mod model;
 
-pub use crate::candle::CandleEmbedder;
+pub use crate::candle::{CandleEmbedder, EMBEDDING_DIM};
+pub use bench::{bench_embedder, BenchReport};
 pub use cache::{get_or_download_model, ModelCache, ModelPaths, DEFAULT_MODEL_REPO, MODEL_FILES};
 pub use error::EmbeddingError;
+pub use hash::HashEmbedder;
 pub use model::{Embedding, EmbeddingModel, ModelInfo};
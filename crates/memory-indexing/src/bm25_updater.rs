@@ -3,13 +3,14 @@
 //! Wraps SearchIndexer from memory-search to handle outbox-driven updates.
 //! Converts OutboxEntry references to searchable documents.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use tracing::{debug, warn};
 
 use memory_search::SearchIndexer;
 use memory_storage::Storage;
-use memory_types::{Grip, OutboxAction, OutboxEntry, TocNode};
+use memory_types::{Event, Grip, OutboxAction, OutboxEntry, TocNode};
 
 use crate::checkpoint::IndexType;
 use crate::error::IndexingError;
@@ -17,18 +18,35 @@ use crate::updater::{IndexUpdater, UpdateResult};
 
 /// BM25 index updater using Tantivy.
 ///
-/// Indexes TOC nodes and grips for full-text BM25 search.
+/// Indexes TOC nodes and grips for full-text BM25 search, and
+/// optionally the raw text of each event.
 /// Consumes outbox entries and fetches the corresponding
 /// data from storage for indexing.
 pub struct Bm25IndexUpdater {
     indexer: Arc<SearchIndexer>,
     storage: Arc<Storage>,
+    index_events: bool,
 }
 
 impl Bm25IndexUpdater {
     /// Create a new BM25 updater.
     pub fn new(indexer: Arc<SearchIndexer>, storage: Arc<Storage>) -> Self {
-        Self { indexer, storage }
+        Self {
+            indexer,
+            storage,
+            index_events: false,
+        }
+    }
+
+    /// Also index the raw text of each event named by an outbox entry.
+    pub fn with_event_indexing(mut self, enabled: bool) -> Self {
+        self.index_events = enabled;
+        self
+    }
+
+    /// Whether raw event text is indexed.
+    pub fn indexes_events(&self) -> bool {
+        self.index_events
     }
 
     /// Index a TOC node.
@@ -45,6 +63,17 @@ impl Bm25IndexUpdater {
             .map_err(|e| IndexingError::Index(format!("BM25 index error: {}", e)))
     }
 
+    /// Index the raw text of an event, if it is still in storage.
+    fn index_raw_event(&self, event_id: &str) -> Result<bool, IndexingError> {
+        let Some(bytes) = self.storage.get_event(event_id)? else {
+            debug!(event_id = %event_id, "Event not found in storage, skipping");
+            return Ok(false);
+        };
+        let event = Event::from_bytes(&bytes)?;
+        self.index_event_direct(&event)?;
+        Ok(true)
+    }
+
     /// Process an outbox entry by fetching the event and related data.
     ///
     /// For IndexEvent actions, we need to determine if this event
@@ -69,12 +98,22 @@ impl Bm25IndexUpdater {
                     return Ok(true);
                 }
 
+                if self.index_events {
+                    return self.index_raw_event(&entry.event_id);
+                }
+
                 // If no direct match, the event will be indexed when
                 // the summarizer creates TOC nodes/grips
                 debug!(event_id = %entry.event_id, "No grip found for event, skipping");
                 Ok(false)
             }
             OutboxAction::UpdateToc => {
+                // Ingest emits one of these per event, so this is where raw
+                // event text gets indexed
+                if self.index_events {
+                    return self.index_raw_event(&entry.event_id);
+                }
+
                 // For TOC updates, we'd need additional context about which
                 // TOC node was updated. For now, skip these as they're
                 // typically handled by the TOC expansion logic.
@@ -134,6 +173,20 @@ impl Bm25IndexUpdater {
         self.index_grip(grip)
     }
 
+    /// Index an event's raw text directly (for bulk indexing).
+    pub fn index_event_direct(&self, event: &Event) -> Result<(), IndexingError> {
+        self.indexer
+            .index_event(event)
+            .map_err(|e| IndexingError::Index(format!("BM25 index error: {}", e)))
+    }
+
+    /// Doc IDs currently in the BM25 index.
+    pub fn indexed_doc_ids(&self) -> Result<HashSet<String>, IndexingError> {
+        self.indexer
+            .doc_ids()
+            .map_err(|e| IndexingError::Index(format!("BM25 scan error: {}", e)))
+    }
+
     /// Get the underlying storage reference.
     pub fn storage(&self) -> &Arc<Storage> {
         &self.storage
@@ -216,6 +269,56 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn test_process_ingested_event_raw_text() {
+        use chrono::Utc;
+        use memory_search::{SearchOptions, TeleportSearcher};
+        use memory_types::{EventRole, EventType};
+
+        let (storage, temp_dir) = create_test_storage();
+        let search_path = temp_dir.path().join("search");
+        std::fs::create_dir_all(&search_path).unwrap();
+        let index = SearchIndex::open_or_create(SearchIndexConfig::new(&search_path)).unwrap();
+        let indexer = Arc::new(SearchIndexer::new(&index).unwrap());
+
+        let event = Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            EventType::UserMessage,
+            EventRole::User,
+            "Migration stalled on the orders table".to_string(),
+        );
+        // Stored the way ingest stores it
+        let outbox = OutboxEntry::for_toc(event.event_id.clone(), 1000);
+        storage
+            .put_event(
+                &event.event_id,
+                &event.to_bytes().unwrap(),
+                &outbox.to_bytes().unwrap(),
+            )
+            .unwrap();
+        let entries = storage.get_outbox_entries(0, 10).unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = entries[0].1.clone();
+
+        // Disabled by default
+        let updater = Bm25IndexUpdater::new(indexer.clone(), storage.clone());
+        assert!(!updater.process_entry(&entry).unwrap());
+
+        let updater = Bm25IndexUpdater::new(indexer, storage).with_event_indexing(true);
+        let result = updater.process_batch(&entries).unwrap();
+        assert_eq!(result.processed, 1);
+        updater.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let hits = searcher
+            .search("orders", SearchOptions::events_only().with_limit(10))
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].doc_id, event.event_id);
+    }
+
     #[test]
     fn test_process_batch_empty() {
         let (storage, temp_dir) = create_test_storage();
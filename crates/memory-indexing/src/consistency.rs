@@ -0,0 +1,310 @@
+//! Consistency checks between storage and the search indexes.
+//!
+//! A crash mid-indexing (or a prune that only reached one side) can leave
+//! storage and the BM25/vector indexes disagreeing about which documents
+//! exist. These checks compare storage doc IDs against index doc IDs and can
+//! optionally repair the difference in place.
+
+use std::collections::HashSet;
+
+use tracing::{info, warn};
+
+use memory_embeddings::EmbeddingModel;
+use memory_storage::Storage;
+
+use crate::bm25_updater::Bm25IndexUpdater;
+use crate::error::IndexingError;
+use crate::rebuild::{iter_all_grips, iter_all_toc_nodes};
+use crate::updater::IndexUpdater;
+use crate::vector_updater::VectorIndexUpdater;
+
+/// Result of checking one index against storage.
+#[derive(Debug, Clone, Default)]
+pub struct IndexConsistency {
+    /// Documents in storage that this index should contain
+    pub storage_docs: usize,
+    /// Documents currently in the index
+    pub indexed_docs: usize,
+    /// Doc IDs in storage but not in the index (sorted)
+    pub missing: Vec<String>,
+    /// Doc IDs in the index with no document in storage (sorted)
+    pub orphaned: Vec<String>,
+    /// Missing documents reindexed during repair
+    pub reindexed: usize,
+    /// Orphaned documents removed during repair
+    pub removed: usize,
+}
+
+impl IndexConsistency {
+    /// True if storage and index agree.
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty() && self.orphaned.is_empty()
+    }
+
+    fn compare(expected: &HashSet<String>, indexed: &HashSet<String>) -> Self {
+        let mut missing: Vec<String> = expected.difference(indexed).cloned().collect();
+        let mut orphaned: Vec<String> = indexed.difference(expected).cloned().collect();
+        missing.sort();
+        orphaned.sort();
+
+        Self {
+            storage_docs: expected.len(),
+            indexed_docs: indexed.len(),
+            missing,
+            orphaned,
+            ..Default::default()
+        }
+    }
+}
+
+/// Check the BM25 index against storage.
+///
+/// With `repair`, missing TOC nodes and grips are indexed, orphans are
+/// deleted, and the index is committed.
+pub fn verify_bm25_consistency(
+    storage: &Storage,
+    updater: &Bm25IndexUpdater,
+    repair: bool,
+) -> Result<IndexConsistency, IndexingError> {
+    let nodes = iter_all_toc_nodes(storage)?;
+    let grips = iter_all_grips(storage)?;
+
+    let expected: HashSet<String> = nodes
+        .iter()
+        .map(|n| n.node_id.clone())
+        .chain(grips.iter().map(|g| g.grip_id.clone()))
+        .collect();
+    let indexed = updater.indexed_doc_ids()?;
+    let mut report = IndexConsistency::compare(&expected, &indexed);
+
+    info!(
+        storage = report.storage_docs,
+        indexed = report.indexed_docs,
+        missing = report.missing.len(),
+        orphaned = report.orphaned.len(),
+        "BM25 consistency check"
+    );
+
+    if !repair || report.is_consistent() {
+        return Ok(report);
+    }
+
+    let missing: HashSet<&str> = report.missing.iter().map(String::as_str).collect();
+    for node in nodes
+        .iter()
+        .filter(|n| missing.contains(n.node_id.as_str()))
+    {
+        match updater.index_node(node) {
+            Ok(()) => report.reindexed += 1,
+            Err(e) => warn!(doc_id = %node.node_id, error = %e, "Failed to reindex TOC node"),
+        }
+    }
+    for grip in grips
+        .iter()
+        .filter(|g| missing.contains(g.grip_id.as_str()))
+    {
+        match updater.index_grip_direct(grip) {
+            Ok(()) => report.reindexed += 1,
+            Err(e) => warn!(doc_id = %grip.grip_id, error = %e, "Failed to reindex grip"),
+        }
+    }
+    for doc_id in &report.orphaned {
+        match updater.remove_document(doc_id) {
+            Ok(()) => report.removed += 1,
+            Err(e) => warn!(doc_id = %doc_id, error = %e, "Failed to remove orphan"),
+        }
+    }
+    updater.commit()?;
+
+    info!(
+        reindexed = report.reindexed,
+        removed = report.removed,
+        "BM25 consistency repair complete"
+    );
+    Ok(report)
+}
+
+/// Check the vector index against storage.
+///
+/// Documents with no embeddable text are never indexed, so they are not
+/// counted as missing. With `repair`, missing documents are embedded,
+/// orphaned vectors are removed, and the index is saved.
+pub fn verify_vector_consistency<E: EmbeddingModel>(
+    storage: &Storage,
+    updater: &VectorIndexUpdater<E>,
+    repair: bool,
+) -> Result<IndexConsistency, IndexingError> {
+    let nodes: Vec<_> = iter_all_toc_nodes(storage)?
+        .into_iter()
+        .filter(|n| {
+            !VectorIndexUpdater::<E>::extract_toc_text(n)
+                .trim()
+                .is_empty()
+        })
+        .collect();
+    let grips: Vec<_> = iter_all_grips(storage)?
+        .into_iter()
+        .filter(|g| !g.excerpt.trim().is_empty())
+        .collect();
+
+    let expected: HashSet<String> = nodes
+        .iter()
+        .map(|n| n.node_id.clone())
+        .chain(grips.iter().map(|g| g.grip_id.clone()))
+        .collect();
+    let indexed = updater.indexed_doc_ids()?;
+    let mut report = IndexConsistency::compare(&expected, &indexed);
+
+    info!(
+        storage = report.storage_docs,
+        indexed = report.indexed_docs,
+        missing = report.missing.len(),
+        orphaned = report.orphaned.len(),
+        "Vector consistency check"
+    );
+
+    if !repair || report.is_consistent() {
+        return Ok(report);
+    }
+
+    let missing: HashSet<&str> = report.missing.iter().map(String::as_str).collect();
+    for node in nodes
+        .iter()
+        .filter(|n| missing.contains(n.node_id.as_str()))
+    {
+        match updater.index_node(node) {
+            Ok(true) => report.reindexed += 1,
+            Ok(false) => {}
+            Err(e) => warn!(doc_id = %node.node_id, error = %e, "Failed to reindex TOC node"),
+        }
+    }
+    for grip in grips
+        .iter()
+        .filter(|g| missing.contains(g.grip_id.as_str()))
+    {
+        match updater.index_grip_direct(grip) {
+            Ok(true) => report.reindexed += 1,
+            Ok(false) => {}
+            Err(e) => warn!(doc_id = %grip.grip_id, error = %e, "Failed to reindex grip"),
+        }
+    }
+    for doc_id in &report.orphaned {
+        match updater.remove_by_doc_id(doc_id) {
+            Ok(true) => report.removed += 1,
+            Ok(false) => {}
+            Err(e) => warn!(doc_id = %doc_id, error = %e, "Failed to remove orphan"),
+        }
+    }
+    updater.commit()?;
+
+    info!(
+        reindexed = report.reindexed,
+        removed = report.removed,
+        "Vector consistency repair complete"
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, RwLock};
+
+    use chrono::Utc;
+    use memory_embeddings::{Embedding, EmbeddingError, ModelInfo};
+    use memory_storage::CF_TOC_LATEST;
+    use memory_types::{TocLevel, TocNode};
+    use memory_vector::{HnswConfig, HnswIndex, VectorMetadata};
+    use tempfile::TempDir;
+
+    struct MockEmbedder {
+        info: ModelInfo,
+    }
+
+    impl EmbeddingModel for MockEmbedder {
+        fn info(&self) -> &ModelInfo {
+            &self.info
+        }
+
+        fn embed(&self, _text: &str) -> Result<Embedding, EmbeddingError> {
+            let values: Vec<f32> = (0..self.info.dimension).map(|i| i as f32).collect();
+            Ok(Embedding::new(values))
+        }
+    }
+
+    fn day_node(day: u32) -> TocNode {
+        TocNode::new(
+            format!("toc:day:2024-01-{:02}", day),
+            TocLevel::Day,
+            format!("Day {}", day),
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected: HashSet<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let indexed: HashSet<String> = ["b", "c", "d"].iter().map(|s| s.to_string()).collect();
+
+        let report = IndexConsistency::compare(&expected, &indexed);
+        assert_eq!(report.storage_docs, 3);
+        assert_eq!(report.indexed_docs, 3);
+        assert_eq!(report.missing, vec!["a"]);
+        assert_eq!(report.orphaned, vec!["d"]);
+        assert!(!report.is_consistent());
+    }
+
+    #[test]
+    fn test_vector_orphan_detected_and_repaired() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("storage")).unwrap());
+        let index = HnswIndex::open_or_create(
+            HnswConfig::new(16, temp_dir.path().join("vector")).with_capacity(100),
+        )
+        .unwrap();
+        let metadata = VectorMetadata::open(temp_dir.path().join("metadata")).unwrap();
+        let embedder = MockEmbedder {
+            info: ModelInfo {
+                name: "mock".to_string(),
+                dimension: 16,
+                max_sequence_length: 512,
+            },
+        };
+        let updater = VectorIndexUpdater::new(
+            Arc::new(RwLock::new(index)),
+            Arc::new(embedder),
+            Arc::new(metadata),
+            storage.clone(),
+        );
+
+        for day in 1..=3 {
+            let node = day_node(day);
+            storage.put_toc_node(&node).unwrap();
+            updater.index_node(&node).unwrap();
+        }
+        let report = verify_vector_consistency(&storage, &updater, false).unwrap();
+        assert!(report.is_consistent());
+
+        // Drop a node from storage but leave its vector behind
+        let orphan = day_node(2).node_id;
+        storage
+            .delete(CF_TOC_LATEST, format!("latest:{}", orphan).as_bytes())
+            .unwrap();
+
+        let report = verify_vector_consistency(&storage, &updater, false).unwrap();
+        assert_eq!(report.storage_docs, 2);
+        assert_eq!(report.indexed_docs, 3);
+        assert!(report.missing.is_empty());
+        assert_eq!(report.orphaned, vec![orphan.clone()]);
+        assert_eq!(report.removed, 0);
+
+        let report = verify_vector_consistency(&storage, &updater, true).unwrap();
+        assert_eq!(report.orphaned, vec![orphan.clone()]);
+        assert_eq!(report.removed, 1);
+        assert!(!updater.indexed_doc_ids().unwrap().contains(&orphan));
+
+        let report = verify_vector_consistency(&storage, &updater, false).unwrap();
+        assert!(report.is_consistent());
+    }
+}
@@ -12,6 +12,8 @@
 //! - [`Bm25IndexUpdater`]: BM25 full-text search updater using Tantivy
 //! - [`VectorIndexUpdater`]: Vector similarity search updater using HNSW
 //! - [`IndexingPipeline`]: Coordinates multiple updaters with checkpointing
+//! - [`SummaryTrigger`]: Signals when enough new TOC summaries have been written
+//! - [`verify_bm25_consistency`]/[`verify_vector_consistency`]: Storage vs index drift checks
 //!
 //! ## Architecture
 //!
@@ -41,20 +43,24 @@
 
 pub mod bm25_updater;
 pub mod checkpoint;
+pub mod consistency;
 pub mod error;
 pub mod pipeline;
 pub mod rebuild;
+pub mod trigger;
 pub mod updater;
 pub mod vector_updater;
 
 pub use bm25_updater::Bm25IndexUpdater;
 pub use checkpoint::{IndexCheckpoint, IndexType};
+pub use consistency::{verify_bm25_consistency, verify_vector_consistency, IndexConsistency};
 pub use error::IndexingError;
 pub use pipeline::{IndexingPipeline, PipelineConfig, ProcessResult};
 pub use rebuild::{
     iter_all_grips, iter_all_toc_nodes, rebuild_bm25_index, rebuild_vector_index,
-    LoggingProgressCallback, NoOpProgressCallback, ProgressCallback, RebuildConfig,
+    LoggingProgressCallback, NoOpProgressCallback, PhaseTimings, ProgressCallback, RebuildConfig,
     RebuildProgress, RebuildResult,
 };
+pub use trigger::{SummarySignals, SummaryTrigger};
 pub use updater::{IndexUpdater, UpdateResult};
 pub use vector_updater::VectorIndexUpdater;
@@ -12,6 +12,7 @@ use memory_storage::Storage;
 
 use crate::checkpoint::{IndexCheckpoint, IndexType};
 use crate::error::IndexingError;
+use crate::trigger::SummaryTrigger;
 use crate::updater::{IndexUpdater, UpdateResult};
 
 /// Result of processing a batch of outbox entries.
@@ -61,6 +62,8 @@ pub struct PipelineConfig {
     pub continue_on_error: bool,
     /// Whether to commit after each batch
     pub commit_after_batch: bool,
+    /// Processed entries kept behind the slowest updater during outbox cleanup
+    pub cleanup_safety_margin: u64,
 }
 
 impl Default for PipelineConfig {
@@ -69,6 +72,7 @@ impl Default for PipelineConfig {
             batch_size: 100,
             continue_on_error: true,
             commit_after_batch: true,
+            cleanup_safety_margin: 1,
         }
     }
 }
@@ -91,6 +95,12 @@ impl PipelineConfig {
         self.commit_after_batch = commit;
         self
     }
+
+    /// Set how many processed entries cleanup keeps behind the slowest updater.
+    pub fn with_cleanup_safety_margin(mut self, margin: u64) -> Self {
+        self.cleanup_safety_margin = margin;
+        self
+    }
 }
 
 /// Indexing pipeline that coordinates multiple index updaters.
@@ -102,6 +112,9 @@ pub struct IndexingPipeline {
     updaters: Vec<Box<dyn IndexUpdater>>,
     checkpoints: HashMap<IndexType, IndexCheckpoint>,
     config: PipelineConfig,
+    summary_trigger: Option<SummaryTrigger>,
+    /// `Storage::segments_created` when summaries were last counted
+    segments_counted: u64,
 }
 
 impl IndexingPipeline {
@@ -112,9 +125,18 @@ impl IndexingPipeline {
             updaters: Vec::new(),
             checkpoints: HashMap::new(),
             config,
+            summary_trigger: None,
+            segments_counted: 0,
         }
     }
 
+    /// Signal `trigger` with the number of segment summaries written between
+    /// batches. Summaries written before this call are not counted.
+    pub fn set_summary_trigger(&mut self, trigger: SummaryTrigger) {
+        self.segments_counted = self.storage.segments_created();
+        self.summary_trigger = Some(trigger);
+    }
+
     /// Add an index updater to the pipeline.
     pub fn add_updater(&mut self, updater: Box<dyn IndexUpdater>) {
         let index_type = updater.index_type();
@@ -180,6 +202,8 @@ impl IndexingPipeline {
     ///
     /// Returns the processing result including per-index stats.
     pub fn process_batch(&mut self, batch_size: usize) -> Result<ProcessResult, IndexingError> {
+        self.count_summaries();
+
         let start_sequence = self.min_checkpoint_sequence();
         let limit = batch_size.max(1);
 
@@ -292,6 +316,17 @@ impl IndexingPipeline {
         Ok(result)
     }
 
+    /// Feed segment summaries written since the last count to the trigger.
+    fn count_summaries(&mut self) {
+        let Some(trigger) = &self.summary_trigger else {
+            return;
+        };
+        let created = self.storage.segments_created();
+        let new_summaries = created.saturating_sub(self.segments_counted);
+        self.segments_counted = created;
+        trigger.record(new_summaries as usize);
+    }
+
     /// Commit all indexes.
     pub fn commit(&self) -> Result<(), IndexingError> {
         for updater in &self.updaters {
@@ -346,21 +381,20 @@ impl IndexingPipeline {
 
     /// Clean up processed outbox entries.
     ///
-    /// Deletes entries up to the minimum checkpoint sequence.
-    /// Only call after confirming all indexes are caught up.
+    /// Deletes only entries every registered updater has processed, keeping
+    /// `cleanup_safety_margin` processed entries behind the slowest one, so
+    /// no updater loses entries it still needs.
     pub fn cleanup_outbox(&self) -> Result<usize, IndexingError> {
-        let min_seq = self.min_checkpoint_sequence();
-        if min_seq == 0 {
+        let Some(up_to) = self.cleanup_up_to() else {
+            debug!("No outbox entries processed by all updaters");
             return Ok(0);
-        }
+        };
 
-        // Delete entries that all indexes have processed
-        // Use min_seq - 1 to keep the last processed entry for safety
-        let up_to = min_seq.saturating_sub(1);
         let deleted = self.storage.delete_outbox_entries(up_to)?;
 
         info!(
-            min_sequence = min_seq,
+            up_to_sequence = up_to,
+            safety_margin = self.config.cleanup_safety_margin,
             deleted = deleted,
             "Cleaned up outbox entries"
         );
@@ -368,11 +402,106 @@ impl IndexingPipeline {
         Ok(deleted)
     }
 
+    /// Highest outbox sequence that is safe to delete, if any.
+    ///
+    /// An updater that has processed nothing yet blocks cleanup entirely.
+    fn cleanup_up_to(&self) -> Option<u64> {
+        let mut min_seq: Option<u64> = None;
+        for updater in &self.updaters {
+            let checkpoint = self.checkpoints.get(&updater.index_type())?;
+            if checkpoint.processed_count == 0 {
+                return None;
+            }
+            min_seq = Some(min_seq.map_or(checkpoint.last_sequence, |m| {
+                m.min(checkpoint.last_sequence)
+            }));
+        }
+        min_seq?.checked_sub(self.config.cleanup_safety_margin)
+    }
+
+    /// Rewind one updater's checkpoint so the next run reprocesses outbox
+    /// entries from `to_sequence` (inclusive).
+    ///
+    /// Used to replay from a known-good point after a crash instead of a
+    /// full rebuild; relies on updaters being idempotent. Fails if there is
+    /// no updater at `updater_index`, if `to_sequence` is past the next
+    /// unprocessed entry, or if the entries to replay were already cleaned
+    /// up. The rewound checkpoint is saved immediately.
+    pub fn reset_checkpoint(
+        &mut self,
+        updater_index: usize,
+        to_sequence: u64,
+    ) -> Result<(), IndexingError> {
+        let updater = self.updaters.get(updater_index).ok_or_else(|| {
+            IndexingError::Checkpoint(format!(
+                "No updater at index {} ({} registered)",
+                updater_index,
+                self.updaters.len()
+            ))
+        })?;
+        let index_type = updater.index_type();
+        let name = updater.name().to_string();
+
+        let checkpoint = self
+            .checkpoints
+            .entry(index_type)
+            .or_insert_with(|| IndexCheckpoint::new(index_type));
+        let next_sequence = if checkpoint.processed_count == 0 {
+            0
+        } else {
+            checkpoint.last_sequence + 1
+        };
+        if to_sequence > next_sequence {
+            return Err(IndexingError::Checkpoint(format!(
+                "Cannot rewind {} to sequence {}: next unprocessed sequence is {}",
+                name, to_sequence, next_sequence
+            )));
+        }
+
+        let oldest = self
+            .storage
+            .get_outbox_entries(0, 1)?
+            .first()
+            .map(|(seq, _)| *seq);
+        if to_sequence < next_sequence && oldest.is_none_or(|seq| seq > to_sequence) {
+            return Err(IndexingError::Checkpoint(format!(
+                "Outbox entries from sequence {} were already cleaned up; run a full rebuild",
+                to_sequence
+            )));
+        }
+
+        // Checkpoints record the last processed sequence; a zero processed
+        // count marks a fresh checkpoint that starts at sequence 0.
+        checkpoint.last_sequence = to_sequence.saturating_sub(1);
+        checkpoint.last_processed_time = chrono::Utc::now();
+        if to_sequence == 0 {
+            checkpoint.processed_count = 0;
+        }
+
+        info!(
+            index = %name,
+            to_sequence = to_sequence,
+            "Rewound checkpoint for replay"
+        );
+        self.save_checkpoints()
+    }
+
     /// Get the current checkpoint for an index type.
     pub fn get_checkpoint(&self, index_type: IndexType) -> Option<&IndexCheckpoint> {
         self.checkpoints.get(&index_type)
     }
 
+    /// Outbox sequences between the most and least advanced checkpoints.
+    ///
+    /// 0 means every index has seen the same entries. A BM25 and vector pair
+    /// drifting apart shows up here before it shows up as missing documents.
+    pub fn checkpoint_spread(&self) -> u64 {
+        let sequences = self.checkpoints.values().map(|c| c.last_sequence);
+        let max = sequences.clone().max().unwrap_or(0);
+        let min = sequences.min().unwrap_or(0);
+        max - min
+    }
+
     /// Get all registered updater names.
     pub fn updater_names(&self) -> Vec<&str> {
         self.updaters.iter().map(|u| u.name()).collect()
@@ -537,6 +666,57 @@ mod tests {
         assert!(result.committed);
     }
 
+    #[test]
+    fn test_summary_trigger_counts_segments_written() {
+        use memory_types::{TocLevel, TocNode};
+
+        let (storage, _temp_dir) = create_test_storage();
+        let write_segments = |range: std::ops::Range<usize>| {
+            for i in range {
+                let node = TocNode::new(
+                    format!("toc:segment:2024-01-15:{}", i),
+                    TocLevel::Segment,
+                    format!("Segment {}", i),
+                    chrono::Utc::now(),
+                    chrono::Utc::now(),
+                );
+                storage.put_toc_node(&node).unwrap();
+            }
+        };
+
+        // Many events, few summaries: only the summaries count
+        for i in 0..20 {
+            let entry = OutboxEntry::for_toc(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+        write_segments(0..3);
+
+        let config = PipelineConfig::default().with_batch_size(4);
+        let mut pipeline = IndexingPipeline::new(storage.clone(), config);
+        pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+        pipeline.load_checkpoints().unwrap();
+        let (trigger, mut signals) = SummaryTrigger::channel(5);
+        pipeline.set_summary_trigger(trigger);
+
+        // Summaries from before the trigger was set are not counted
+        write_segments(3..7);
+        pipeline.process_until_caught_up(100).unwrap();
+        assert!(signals.try_recv().is_none());
+        assert_eq!(pipeline.summary_trigger.as_ref().unwrap().pending(), 4);
+
+        // Counted even when the outbox is already caught up
+        write_segments(7..9);
+        pipeline.process_batch(4).unwrap();
+        assert_eq!(signals.try_recv(), Some(6));
+        assert_eq!(pipeline.summary_trigger.as_ref().unwrap().pending(), 0);
+    }
+
     #[test]
     fn test_process_until_caught_up() {
         let (storage, _temp_dir) = create_test_storage();
@@ -591,6 +771,322 @@ mod tests {
         assert!(remaining.len() <= 2); // At most entries 4 and maybe 5
     }
 
+    #[test]
+    fn test_outbox_lag_drops_after_processing() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        for i in 0..6 {
+            let outbox_entry = OutboxEntry::for_index(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &outbox_entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+        // Nothing has been indexed yet
+        assert_eq!(storage.outbox_lag().unwrap(), 6);
+
+        let mut pipeline = IndexingPipeline::new(storage.clone(), PipelineConfig::default());
+        pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+        pipeline.load_checkpoints().unwrap();
+        pipeline.process_until_caught_up(100).unwrap();
+        pipeline.cleanup_outbox().unwrap();
+
+        // Cleanup keeps only the last processed entry
+        assert!(storage.outbox_lag().unwrap() <= 1);
+    }
+
+    /// Embedder returning the same vector for any text.
+    struct ConstantEmbedder(memory_embeddings::ModelInfo);
+
+    impl memory_embeddings::EmbeddingModel for ConstantEmbedder {
+        fn info(&self) -> &memory_embeddings::ModelInfo {
+            &self.0
+        }
+
+        fn embed(
+            &self,
+            _text: &str,
+        ) -> Result<memory_embeddings::Embedding, memory_embeddings::EmbeddingError> {
+            Ok(memory_embeddings::Embedding::new(vec![1.0; 8]))
+        }
+    }
+
+    #[test]
+    fn test_bm25_and_vector_checkpoints_advance_together() {
+        use crate::bm25_updater::Bm25IndexUpdater;
+        use crate::vector_updater::VectorIndexUpdater;
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+        use memory_vector::{HnswConfig, HnswIndex, VectorMetadata};
+        use std::sync::RwLock;
+
+        let (storage, temp_dir) = create_test_storage();
+        for i in 0..6 {
+            let outbox_entry = OutboxEntry::for_index(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &outbox_entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+
+        let search_index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("bm25")))
+                .unwrap();
+        let bm25 = Bm25IndexUpdater::new(
+            Arc::new(SearchIndexer::new(&search_index).unwrap()),
+            storage.clone(),
+        );
+        let hnsw = HnswIndex::open_or_create(
+            HnswConfig::new(8, temp_dir.path().join("hnsw")).with_capacity(100),
+        )
+        .unwrap();
+        let metadata = VectorMetadata::open(temp_dir.path().join("vector-meta")).unwrap();
+        let embedder = ConstantEmbedder(memory_embeddings::ModelInfo {
+            name: "constant".to_string(),
+            dimension: 8,
+            max_sequence_length: 512,
+        });
+        let vector = VectorIndexUpdater::new(
+            Arc::new(RwLock::new(hnsw)),
+            Arc::new(embedder),
+            Arc::new(metadata),
+            storage.clone(),
+        );
+
+        let mut pipeline = IndexingPipeline::new(storage.clone(), PipelineConfig::default());
+        pipeline.add_updater(Box::new(bm25));
+        pipeline.add_updater(Box::new(vector));
+        pipeline.load_checkpoints().unwrap();
+
+        let mut last_sequence = 0;
+        for _ in 0..10 {
+            if !pipeline.process_batch(2).unwrap().has_updates() {
+                break;
+            }
+            let bm25_cp = pipeline.get_checkpoint(IndexType::Bm25).unwrap();
+            let vector_cp = pipeline.get_checkpoint(IndexType::Vector).unwrap();
+            assert_eq!(bm25_cp.last_sequence, vector_cp.last_sequence);
+            assert_eq!(bm25_cp.processed_count, vector_cp.processed_count);
+            assert!(vector_cp.last_sequence > last_sequence);
+            assert_eq!(pipeline.checkpoint_spread(), 0);
+            last_sequence = vector_cp.last_sequence;
+        }
+        assert_eq!(last_sequence, 5);
+
+        // Vector progress is persisted as an IndexCheckpoint like BM25's
+        let bytes = storage
+            .get_checkpoint(memory_vector::VECTOR_INDEX_CHECKPOINT)
+            .unwrap()
+            .unwrap();
+        let saved = IndexCheckpoint::from_bytes(&bytes).unwrap();
+        assert_eq!(saved.index_type, IndexType::Vector);
+        assert_eq!(saved.last_sequence, last_sequence);
+
+        // Cleanup stops at the shared checkpoint minus the safety margin
+        assert_eq!(pipeline.cleanup_outbox().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_cleanup_outbox_respects_slowest_updater() {
+        let (storage, _temp_dir) = create_test_storage();
+        for i in 0..10 {
+            let outbox_entry = OutboxEntry::for_index(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &outbox_entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+
+        let pipeline_at = |margin: u64| {
+            let config = PipelineConfig::default().with_cleanup_safety_margin(margin);
+            let mut pipeline = IndexingPipeline::new(storage.clone(), config);
+            pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+            pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Vector, "vector")));
+            // BM25 has processed through 7, vector only through 3
+            pipeline
+                .checkpoints
+                .get_mut(&IndexType::Bm25)
+                .unwrap()
+                .update(7, 8);
+            pipeline
+                .checkpoints
+                .get_mut(&IndexType::Vector)
+                .unwrap()
+                .update(3, 4);
+            pipeline
+        };
+
+        // Margin of 2 keeps entries 2 and 3 even though both updaters are past them
+        assert_eq!(pipeline_at(2).cleanup_outbox().unwrap(), 2);
+        assert_eq!(storage.get_outbox_entries(0, 100).unwrap()[0].0, 2);
+
+        // Without a margin, cleanup stops at the vector checkpoint
+        assert_eq!(pipeline_at(0).cleanup_outbox().unwrap(), 2);
+        let remaining = storage.get_outbox_entries(0, 100).unwrap();
+        assert_eq!(remaining.len(), 6);
+        assert_eq!(remaining[0].0, 4);
+    }
+
+    #[test]
+    fn test_cleanup_outbox_blocked_by_fresh_updater() {
+        let (storage, _temp_dir) = create_test_storage();
+        let outbox_entry = OutboxEntry::for_index("event-0".to_string(), 0);
+        storage
+            .put_event(
+                &ulid::Ulid::new().to_string(),
+                b"test",
+                &outbox_entry.to_bytes().unwrap(),
+            )
+            .unwrap();
+
+        let config = PipelineConfig::default().with_cleanup_safety_margin(0);
+        let mut pipeline = IndexingPipeline::new(storage.clone(), config);
+        pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+        pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Vector, "vector")));
+        pipeline
+            .checkpoints
+            .get_mut(&IndexType::Bm25)
+            .unwrap()
+            .update(5, 6);
+
+        // Vector has processed nothing, so nothing may be deleted
+        assert_eq!(pipeline.cleanup_outbox().unwrap(), 0);
+        assert_eq!(storage.outbox_lag().unwrap(), 1);
+    }
+
+    /// Updater that records indexed event IDs and counts calls.
+    struct RecordingUpdater {
+        docs: Arc<std::sync::Mutex<std::collections::BTreeSet<String>>>,
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl IndexUpdater for RecordingUpdater {
+        fn index_document(&self, entry: &OutboxEntry) -> Result<(), IndexingError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.docs.lock().unwrap().insert(entry.event_id.clone());
+            Ok(())
+        }
+
+        fn remove_document(&self, doc_id: &str) -> Result<(), IndexingError> {
+            self.docs.lock().unwrap().remove(doc_id);
+            Ok(())
+        }
+
+        fn commit(&self) -> Result<(), IndexingError> {
+            Ok(())
+        }
+
+        fn index_type(&self) -> IndexType {
+            IndexType::Bm25
+        }
+
+        fn name(&self) -> &str {
+            "recording"
+        }
+    }
+
+    #[test]
+    fn test_reset_checkpoint_replays_idempotently() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let (storage, _temp_dir) = create_test_storage();
+        for i in 0..8 {
+            let outbox_entry = OutboxEntry::for_index(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &outbox_entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+
+        let docs = Arc::new(std::sync::Mutex::new(std::collections::BTreeSet::new()));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let config = PipelineConfig::default().with_batch_size(3);
+        let mut pipeline = IndexingPipeline::new(storage.clone(), config);
+        pipeline.add_updater(Box::new(RecordingUpdater {
+            docs: docs.clone(),
+            calls: calls.clone(),
+        }));
+        pipeline.load_checkpoints().unwrap();
+
+        pipeline.process_until_caught_up(100).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 8);
+        let before = docs.lock().unwrap().clone();
+        assert_eq!(before.len(), 8);
+
+        // Rewind to sequence 5 and replay entries 5, 6 and 7
+        pipeline.reset_checkpoint(0, 5).unwrap();
+        assert_eq!(
+            pipeline
+                .get_checkpoint(IndexType::Bm25)
+                .unwrap()
+                .last_sequence,
+            4
+        );
+        pipeline.process_until_caught_up(100).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 11);
+        assert_eq!(*docs.lock().unwrap(), before);
+        assert_eq!(
+            pipeline
+                .get_checkpoint(IndexType::Bm25)
+                .unwrap()
+                .last_sequence,
+            7
+        );
+
+        // The rewind was persisted
+        let mut reloaded = IndexingPipeline::new(storage, PipelineConfig::default());
+        reloaded.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+        reloaded.load_checkpoints().unwrap();
+        assert_eq!(
+            reloaded
+                .get_checkpoint(IndexType::Bm25)
+                .unwrap()
+                .last_sequence,
+            7
+        );
+    }
+
+    #[test]
+    fn test_reset_checkpoint_rejects_invalid_targets() {
+        let (storage, _temp_dir) = create_test_storage();
+        for i in 0..4 {
+            let outbox_entry = OutboxEntry::for_index(format!("event-{}", i), i * 1000);
+            storage
+                .put_event(
+                    &ulid::Ulid::new().to_string(),
+                    b"test",
+                    &outbox_entry.to_bytes().unwrap(),
+                )
+                .unwrap();
+        }
+
+        let mut pipeline = IndexingPipeline::new(storage.clone(), PipelineConfig::default());
+        pipeline.add_updater(Box::new(MockUpdater::new(IndexType::Bm25, "bm25")));
+        pipeline.load_checkpoints().unwrap();
+        pipeline.process_until_caught_up(100).unwrap();
+
+        // Unknown updater
+        assert!(pipeline.reset_checkpoint(1, 0).is_err());
+        // Forward past the next unprocessed entry
+        assert!(pipeline.reset_checkpoint(0, 10).is_err());
+
+        // Entries 0 and 1 cleaned up
+        storage.delete_outbox_entries(1).unwrap();
+        assert!(pipeline.reset_checkpoint(0, 1).is_err());
+        assert!(pipeline.reset_checkpoint(0, 2).is_ok());
+    }
+
     #[test]
     fn test_min_checkpoint_sequence() {
         let (storage, _temp) = create_test_storage();
@@ -634,6 +1130,7 @@ mod tests {
         assert_eq!(config.batch_size, 50);
         assert!(!config.continue_on_error);
         assert!(!config.commit_after_batch);
+        assert_eq!(config.cleanup_safety_margin, 1);
     }
 
     #[test]
@@ -1,14 +1,18 @@
 //! Index rebuild functionality for reconstructing search indexes from storage.
 //!
 //! Provides utilities for rebuilding BM25 and vector indexes from scratch
-//! by iterating through all TOC nodes and grips in storage.
+//! by iterating through all TOC nodes and grips in storage, plus raw events
+//! when the BM25 updater indexes them.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
 
 use tracing::{debug, info, warn};
 
 use memory_storage::Storage;
-use memory_types::{Grip, TocLevel, TocNode};
+use memory_types::{is_rolling_node_id, Event, Grip, TocLevel, TocNode};
 
 use crate::bm25_updater::Bm25IndexUpdater;
 use crate::checkpoint::{IndexCheckpoint, IndexType};
@@ -27,6 +31,12 @@ pub struct RebuildConfig {
     pub clear_first: bool,
     /// Whether to continue on individual document errors.
     pub continue_on_error: bool,
+    /// Only index documents covering events from this outbox sequence on
+    /// (None = every document).
+    pub from_sequence: Option<u64>,
+    /// Only index documents covering events at or after this time, in ms
+    /// (None = every document). Takes precedence over `from_sequence`.
+    pub since_ms: Option<i64>,
 }
 
 impl Default for RebuildConfig {
@@ -36,6 +46,8 @@ impl Default for RebuildConfig {
             index_types: vec![IndexType::Bm25, IndexType::Vector],
             clear_first: true,
             continue_on_error: true,
+            from_sequence: None,
+            since_ms: None,
         }
     }
 }
@@ -74,6 +86,58 @@ impl RebuildConfig {
         self.continue_on_error = continue_on_error;
         self
     }
+
+    /// Only index documents covering events from `sequence` on.
+    pub fn with_from_sequence(mut self, sequence: u64) -> Self {
+        self.from_sequence = Some(sequence);
+        self
+    }
+
+    /// Only index documents covering events at or after `since_ms`.
+    pub fn with_since_ms(mut self, since_ms: i64) -> Self {
+        self.since_ms = Some(since_ms);
+        self
+    }
+}
+
+/// Wall-clock time spent in each phase of a rebuild, in milliseconds.
+///
+/// Measured around the real work, so `total_ms` is close to (and never
+/// more than) the rebuild's elapsed time.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhaseTimings {
+    /// Reading TOC nodes and grips from storage.
+    pub read_ms: f64,
+    /// Generating embeddings (vector index only).
+    pub embed_ms: f64,
+    /// Adding documents to the index, excluding embedding.
+    pub index_ms: f64,
+    /// Committing or saving the index.
+    pub commit_ms: f64,
+}
+
+impl PhaseTimings {
+    fn from_durations(read: Duration, embed: Duration, index: Duration, commit: Duration) -> Self {
+        Self {
+            read_ms: read.as_secs_f64() * 1000.0,
+            embed_ms: embed.as_secs_f64() * 1000.0,
+            index_ms: index.as_secs_f64() * 1000.0,
+            commit_ms: commit.as_secs_f64() * 1000.0,
+        }
+    }
+
+    /// Sum of all phases.
+    pub fn total_ms(&self) -> f64 {
+        self.read_ms + self.embed_ms + self.index_ms + self.commit_ms
+    }
+
+    /// Add another rebuild's timings to these.
+    pub fn merge(&mut self, other: &PhaseTimings) {
+        self.read_ms += other.read_ms;
+        self.embed_ms += other.embed_ms;
+        self.index_ms += other.index_ms;
+        self.commit_ms += other.commit_ms;
+    }
 }
 
 /// Progress tracking for rebuild operations.
@@ -85,12 +149,16 @@ pub struct RebuildProgress {
     pub toc_nodes_indexed: u64,
     /// Number of grips indexed.
     pub grips_indexed: u64,
+    /// Number of raw events indexed (BM25 with event indexing only).
+    pub events_indexed: u64,
     /// Number of errors encountered.
     pub errors: u64,
     /// Number of documents skipped (already indexed or empty).
     pub skipped: u64,
     /// Whether the rebuild completed successfully.
     pub completed: bool,
+    /// Time spent in each phase, filled in when the rebuild completes.
+    pub timings: PhaseTimings,
 }
 
 impl RebuildProgress {
@@ -111,6 +179,12 @@ impl RebuildProgress {
         self.total_processed += 1;
     }
 
+    /// Record a successful raw event index.
+    pub fn record_event(&mut self) {
+        self.events_indexed += 1;
+        self.total_processed += 1;
+    }
+
     /// Record an error.
     pub fn record_error(&mut self) {
         self.errors += 1;
@@ -199,7 +273,12 @@ pub fn iter_all_toc_nodes(storage: &Storage) -> Result<Vec<TocNode>, IndexingErr
         let nodes = storage
             .get_toc_nodes_by_level(*level, None, None)
             .map_err(IndexingError::Storage)?;
-        all_nodes.extend(nodes);
+        // Rolling windows repeat calendar content and are not indexed
+        all_nodes.extend(
+            nodes
+                .into_iter()
+                .filter(|n| !is_rolling_node_id(&n.node_id)),
+        );
     }
 
     debug!(count = all_nodes.len(), "Found TOC nodes in storage");
@@ -236,6 +315,48 @@ pub fn iter_all_grips(storage: &Storage) -> Result<Vec<Grip>, IndexingError> {
     Ok(grips)
 }
 
+/// Earliest event time, in ms, that a partial rebuild covers.
+///
+/// `since_ms` is used as is. Outbox entries point at events rather than
+/// documents, so `from_sequence` is mapped to the oldest event time among
+/// the entries written from that sequence on; an event backfilled with an
+/// old timestamp pulls the cutoff back to cover it. Processed entries are
+/// deleted by outbox cleanup, so a sequence that no longer exists is an
+/// error rather than a silently partial rebuild.
+fn since_ms(storage: &Storage, config: &RebuildConfig) -> Result<Option<i64>, IndexingError> {
+    if let Some(since) = config.since_ms {
+        info!(since_ms = since, "Rebuilding recent documents only");
+        return Ok(Some(since));
+    }
+    let Some(sequence) = config.from_sequence else {
+        return Ok(None);
+    };
+    let entries = storage.get_outbox_entries(sequence, usize::MAX)?;
+    if entries.first().map(|(seq, _)| *seq) != Some(sequence) {
+        return Err(IndexingError::Index(format!(
+            "outbox sequence {} no longer exists (processed entries are cleaned up); \
+             rebuild with a timestamp cutoff instead",
+            sequence
+        )));
+    }
+    let since = entries
+        .iter()
+        .map(|(_, entry)| entry.timestamp_ms)
+        .min()
+        .unwrap_or(i64::MAX);
+    info!(
+        from_sequence = sequence,
+        since_ms = since,
+        "Rebuilding recent documents only"
+    );
+    Ok(Some(since))
+}
+
+/// True if a document ending at `time` falls within the rebuild.
+fn is_recent(time: DateTime<Utc>, since_ms: Option<i64>) -> bool {
+    since_ms.is_none_or(|since| time.timestamp_millis() >= since)
+}
+
 /// Rebuild BM25 index from storage.
 pub fn rebuild_bm25_index<P: ProgressCallback>(
     storage: Arc<Storage>,
@@ -244,13 +365,20 @@ pub fn rebuild_bm25_index<P: ProgressCallback>(
     progress_callback: &P,
 ) -> Result<RebuildProgress, IndexingError> {
     let mut progress = RebuildProgress::new();
+    let mut read = Duration::ZERO;
+    let mut index = Duration::ZERO;
 
     info!("Starting BM25 index rebuild...");
 
     // Iterate through all TOC nodes
-    let nodes = iter_all_toc_nodes(&storage)?;
+    let phase = Instant::now();
+    let since_ms = since_ms(&storage, config)?;
+    let mut nodes = iter_all_toc_nodes(&storage)?;
+    nodes.retain(|node| is_recent(node.end_time, since_ms));
+    read += phase.elapsed();
     info!(count = nodes.len(), "Found TOC nodes to index");
 
+    let phase = Instant::now();
     for node in nodes {
         match updater.index_node(&node) {
             Ok(()) => {
@@ -273,11 +401,16 @@ pub fn rebuild_bm25_index<P: ProgressCallback>(
             progress_callback.on_progress(&progress);
         }
     }
+    index += phase.elapsed();
 
     // Iterate through all grips
-    let grips = iter_all_grips(&storage)?;
+    let phase = Instant::now();
+    let mut grips = iter_all_grips(&storage)?;
+    grips.retain(|grip| is_recent(grip.timestamp, since_ms));
+    read += phase.elapsed();
     info!(count = grips.len(), "Found grips to index");
 
+    let phase = Instant::now();
     for grip in grips {
         match updater.index_grip_direct(&grip) {
             Ok(()) => {
@@ -300,15 +433,57 @@ pub fn rebuild_bm25_index<P: ProgressCallback>(
             progress_callback.on_progress(&progress);
         }
     }
+    index += phase.elapsed();
+
+    // Backfill raw events, including those ingested before event indexing
+    // was turned on
+    if updater.indexes_events() {
+        let phase = Instant::now();
+        let events = storage.get_events_in_range(since_ms.unwrap_or(0), i64::MAX)?;
+        read += phase.elapsed();
+        info!(count = events.len(), "Found events to index");
+
+        let phase = Instant::now();
+        for (key, bytes) in events {
+            let indexed = Event::from_bytes(&bytes)
+                .map_err(IndexingError::from)
+                .and_then(|event| updater.index_event_direct(&event));
+            match indexed {
+                Ok(()) => {
+                    progress.record_event();
+                }
+                Err(e) => {
+                    if config.continue_on_error {
+                        warn!(event_id = %key.ulid, error = %e, "Failed to index event");
+                        progress.record_error();
+                    } else {
+                        return Err(e);
+                    }
+                }
+            }
+
+            if progress
+                .total_processed
+                .is_multiple_of(config.batch_size as u64)
+            {
+                progress_callback.on_progress(&progress);
+            }
+        }
+        index += phase.elapsed();
+    }
 
     // Commit the index
+    let phase = Instant::now();
     updater.commit()?;
+    let commit = phase.elapsed();
+    progress.timings = PhaseTimings::from_durations(read, Duration::ZERO, index, commit);
     progress.mark_completed();
     progress_callback.on_progress(&progress);
 
     info!(
         toc_nodes = progress.toc_nodes_indexed,
         grips = progress.grips_indexed,
+        events = progress.events_indexed,
         errors = progress.errors,
         "BM25 index rebuild complete"
     );
@@ -324,13 +499,21 @@ pub fn rebuild_vector_index<P: ProgressCallback, E: memory_embeddings::Embedding
     progress_callback: &P,
 ) -> Result<RebuildProgress, IndexingError> {
     let mut progress = RebuildProgress::new();
+    let mut read = Duration::ZERO;
+    let mut index = Duration::ZERO;
+    let embed_before = updater.embed_time();
 
     info!("Starting vector index rebuild...");
 
     // Iterate through all TOC nodes
-    let nodes = iter_all_toc_nodes(&storage)?;
+    let phase = Instant::now();
+    let since_ms = since_ms(&storage, config)?;
+    let mut nodes = iter_all_toc_nodes(&storage)?;
+    nodes.retain(|node| is_recent(node.end_time, since_ms));
+    read += phase.elapsed();
     info!(count = nodes.len(), "Found TOC nodes to index");
 
+    let phase = Instant::now();
     for node in nodes {
         match updater.index_node(&node) {
             Ok(true) => {
@@ -356,11 +539,16 @@ pub fn rebuild_vector_index<P: ProgressCallback, E: memory_embeddings::Embedding
             progress_callback.on_progress(&progress);
         }
     }
+    index += phase.elapsed();
 
     // Iterate through all grips
-    let grips = iter_all_grips(&storage)?;
+    let phase = Instant::now();
+    let mut grips = iter_all_grips(&storage)?;
+    grips.retain(|grip| is_recent(grip.timestamp, since_ms));
+    read += phase.elapsed();
     info!(count = grips.len(), "Found grips to index");
 
+    let phase = Instant::now();
     for grip in grips {
         match updater.index_grip_direct(&grip) {
             Ok(true) => {
@@ -386,9 +574,17 @@ pub fn rebuild_vector_index<P: ProgressCallback, E: memory_embeddings::Embedding
             progress_callback.on_progress(&progress);
         }
     }
+    index += phase.elapsed();
 
     // Commit the index
+    let phase = Instant::now();
     updater.commit()?;
+    let commit = phase.elapsed();
+
+    // Embedding happens inside the index loop; report it separately
+    let embed = updater.embed_time().saturating_sub(embed_before);
+    progress.timings =
+        PhaseTimings::from_durations(read, embed, index.saturating_sub(embed), commit);
     progress.mark_completed();
     progress_callback.on_progress(&progress);
 
@@ -414,6 +610,8 @@ mod tests {
         assert_eq!(config.index_types.len(), 2);
         assert!(config.clear_first);
         assert!(config.continue_on_error);
+        assert!(config.from_sequence.is_none());
+        assert!(config.since_ms.is_none());
     }
 
     #[test]
@@ -468,6 +666,156 @@ mod tests {
         assert!(progress.completed);
     }
 
+    #[test]
+    fn test_phase_timings_total_and_merge() {
+        let mut timings = PhaseTimings::from_durations(
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+            Duration::from_millis(3),
+            Duration::from_millis(4),
+        );
+        assert!((timings.total_ms() - 10.0).abs() < 1e-9);
+
+        timings.merge(&PhaseTimings {
+            read_ms: 1.0,
+            ..Default::default()
+        });
+        assert!((timings.read_ms - 2.0).abs() < 1e-9);
+        assert!((timings.total_ms() - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rebuild_from_sequence_indexes_only_new_docs() {
+        use chrono::TimeZone;
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+        use memory_types::OutboxEntry;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("db")).unwrap());
+        let search_index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("search")))
+                .unwrap();
+        let updater = Bm25IndexUpdater::new(
+            Arc::new(SearchIndexer::new(&search_index).unwrap()),
+            storage.clone(),
+        );
+
+        // An event and the day node summarizing it
+        let add_day = |day: u32| {
+            let time = Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+            let event_id = ulid::Ulid::new().to_string();
+            let outbox = OutboxEntry::for_index(event_id.clone(), time.timestamp_millis());
+            storage
+                .put_event(&event_id, b"event", &outbox.to_bytes().unwrap())
+                .unwrap();
+            let node = TocNode::new(
+                format!("toc:day:2024-01-{:02}", day),
+                TocLevel::Day,
+                format!("Day {}", day),
+                time,
+                time,
+            );
+            storage.put_toc_node(&node).unwrap();
+        };
+
+        for day in 10..13 {
+            add_day(day);
+        }
+        let config = RebuildConfig::bm25_only();
+        let full =
+            rebuild_bm25_index(storage.clone(), &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(full.toc_nodes_indexed, 3);
+
+        let last_sequence = storage
+            .get_outbox_entries(0, 100)
+            .unwrap()
+            .last()
+            .unwrap()
+            .0;
+        add_day(20);
+        add_day(21);
+        let new_sequence = storage.get_outbox_entries(last_sequence + 1, 1).unwrap()[0].0;
+
+        let config = RebuildConfig::bm25_only().with_from_sequence(new_sequence);
+        let recent =
+            rebuild_bm25_index(storage.clone(), &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(recent.toc_nodes_indexed, 2);
+        assert_eq!(recent.total_processed, 2);
+
+        // A sequence that isn't in the outbox is an error, not an empty rebuild
+        let config = RebuildConfig::bm25_only().with_from_sequence(new_sequence + 100);
+        let result = rebuild_bm25_index(storage.clone(), &updater, &config, &NoOpProgressCallback);
+        assert!(matches!(result, Err(IndexingError::Index(_))));
+
+        // An event backfilled into an old day is covered by its sequence
+        let backfill_sequence = storage
+            .get_outbox_entries(0, 100)
+            .unwrap()
+            .last()
+            .unwrap()
+            .0
+            + 1;
+        add_day(11);
+        let config = RebuildConfig::bm25_only().with_from_sequence(backfill_sequence);
+        let backfill =
+            rebuild_bm25_index(storage.clone(), &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(backfill.toc_nodes_indexed, 4);
+
+        // A timestamp cutoff works without the outbox
+        let since = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        let config = RebuildConfig::bm25_only().with_since_ms(since.timestamp_millis());
+        let recent = rebuild_bm25_index(storage, &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(recent.toc_nodes_indexed, 2);
+    }
+
+    #[test]
+    fn test_rebuild_bm25_backfills_raw_events() {
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer, TeleportSearcher};
+        use memory_types::{EventRole, EventType, OutboxEntry};
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("db")).unwrap());
+        let search_index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("search")))
+                .unwrap();
+        let indexer = Arc::new(SearchIndexer::new(&search_index).unwrap());
+
+        // Ingested while event indexing was off
+        let event = Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            EventType::UserMessage,
+            EventRole::User,
+            "Migration stalled on the orders table".to_string(),
+        );
+        let outbox = OutboxEntry::for_toc(event.event_id.clone(), 1000);
+        storage
+            .put_event(
+                &event.event_id,
+                &event.to_bytes().unwrap(),
+                &outbox.to_bytes().unwrap(),
+            )
+            .unwrap();
+
+        let config = RebuildConfig::bm25_only();
+        let updater = Bm25IndexUpdater::new(indexer.clone(), storage.clone());
+        let off =
+            rebuild_bm25_index(storage.clone(), &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(off.events_indexed, 0);
+
+        let updater = Bm25IndexUpdater::new(indexer, storage.clone()).with_event_indexing(true);
+        let on = rebuild_bm25_index(storage, &updater, &config, &NoOpProgressCallback).unwrap();
+        assert_eq!(on.events_indexed, 1);
+
+        let searcher = TeleportSearcher::new(&search_index).unwrap();
+        let hits = searcher.search_events("orders", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].doc_id, event.event_id);
+    }
+
     #[test]
     fn test_no_op_progress_callback() {
         let callback = NoOpProgressCallback;
@@ -0,0 +1,141 @@
+//! Signal raised after enough new TOC summaries have been written.
+//!
+//! The indexing pipeline counts the segment summaries written since its last
+//! batch and, once a configured number have accumulated, wakes a consumer
+//! (the daemon's incremental topic pass) that lives outside this crate and
+//! only holds the [`SummarySignals`] end. Counts accumulate in an atomic, so
+//! summaries recorded while a signal is still waiting to be received are
+//! handed over with it rather than lost.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Counts new summaries and signals every time a threshold is crossed.
+#[derive(Debug)]
+pub struct SummaryTrigger {
+    threshold: usize,
+    pending: Arc<AtomicUsize>,
+    sender: mpsc::Sender<()>,
+}
+
+/// Receiving end of a [`SummaryTrigger`].
+#[derive(Debug)]
+pub struct SummarySignals {
+    pending: Arc<AtomicUsize>,
+    receiver: mpsc::Receiver<()>,
+}
+
+impl SummaryTrigger {
+    /// Create a trigger firing every `threshold` summaries (0 is treated as 1).
+    pub fn channel(threshold: usize) -> (Self, SummarySignals) {
+        let (sender, receiver) = mpsc::channel(1);
+        let pending = Arc::new(AtomicUsize::new(0));
+        let trigger = Self {
+            threshold: threshold.max(1),
+            pending: pending.clone(),
+            sender,
+        };
+        (trigger, SummarySignals { pending, receiver })
+    }
+
+    /// Summaries needed before a signal is sent.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Summaries counted and not yet handed to the consumer.
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// Count `summaries` new summaries, returning true if a signal was sent.
+    pub fn record(&self, summaries: usize) -> bool {
+        if summaries == 0 {
+            return false;
+        }
+        let pending = self.pending.fetch_add(summaries, Ordering::SeqCst) + summaries;
+        if pending < self.threshold {
+            return false;
+        }
+
+        match self.sender.try_send(()) {
+            Ok(()) => {
+                debug!(summaries = pending, "Summary threshold reached");
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(())) => {
+                debug!(summaries = pending, "Summary signal already pending");
+                false
+            }
+            Err(mpsc::error::TrySendError::Closed(())) => false,
+        }
+    }
+}
+
+impl SummarySignals {
+    /// Wait for the threshold to be crossed, then take every summary counted
+    /// so far. Returns None once the trigger is dropped.
+    pub async fn recv(&mut self) -> Option<usize> {
+        self.receiver.recv().await?;
+        Some(self.pending.swap(0, Ordering::SeqCst))
+    }
+
+    /// Like [`recv`](Self::recv), but returns None if no signal is waiting.
+    pub fn try_recv(&mut self) -> Option<usize> {
+        self.receiver.try_recv().ok()?;
+        Some(self.pending.swap(0, Ordering::SeqCst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fires_at_threshold() {
+        let (trigger, mut signals) = SummaryTrigger::channel(3);
+
+        assert!(!trigger.record(2));
+        assert!(signals.try_recv().is_none());
+        assert_eq!(trigger.pending(), 2);
+
+        assert!(trigger.record(2));
+        assert_eq!(signals.try_recv(), Some(4));
+        assert_eq!(trigger.pending(), 0);
+    }
+
+    #[test]
+    fn test_counts_accumulate_while_signal_pending() {
+        let (trigger, mut signals) = SummaryTrigger::channel(1);
+
+        assert!(trigger.record(1));
+        assert!(!trigger.record(2));
+        assert!(!trigger.record(3));
+
+        // One signal carrying every summary recorded before it was received
+        assert_eq!(signals.try_recv(), Some(6));
+        assert!(signals.try_recv().is_none());
+        assert_eq!(trigger.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_recv_ends_when_trigger_dropped() {
+        let (trigger, mut signals) = SummaryTrigger::channel(2);
+        trigger.record(2);
+        drop(trigger);
+
+        assert_eq!(signals.recv().await, Some(2));
+        assert_eq!(signals.recv().await, None);
+    }
+
+    #[test]
+    fn test_zero_threshold_treated_as_one() {
+        let (trigger, _signals) = SummaryTrigger::channel(0);
+        assert_eq!(trigger.threshold(), 1);
+        assert!(!trigger.record(0));
+        assert!(trigger.record(1));
+    }
+}
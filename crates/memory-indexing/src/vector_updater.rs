@@ -3,11 +3,14 @@
 //! Wraps HnswIndex and CandleEmbedder to handle outbox-driven vector indexing.
 //! Generates embeddings from text content and stores vectors with metadata.
 
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use tracing::{debug, warn};
 
-use memory_embeddings::{CandleEmbedder, EmbeddingModel};
+use memory_embeddings::{CandleEmbedder, Embedding, EmbeddingModel};
 use memory_storage::Storage;
 use memory_types::{Grip, OutboxAction, OutboxEntry, TocNode};
 use memory_vector::{DocType, HnswIndex, VectorEntry, VectorIndex, VectorMetadata};
@@ -25,6 +28,8 @@ pub struct VectorIndexUpdater<E: EmbeddingModel = CandleEmbedder> {
     embedder: Arc<E>,
     metadata: Arc<VectorMetadata>,
     storage: Arc<Storage>,
+    /// Cumulative time spent generating embeddings, in nanoseconds
+    embed_nanos: AtomicU64,
 }
 
 impl<E: EmbeddingModel> VectorIndexUpdater<E> {
@@ -40,11 +45,29 @@ impl<E: EmbeddingModel> VectorIndexUpdater<E> {
             embedder,
             metadata,
             storage,
+            embed_nanos: AtomicU64::new(0),
         }
     }
 
+    /// Cumulative time this updater has spent generating embeddings.
+    pub fn embed_time(&self) -> Duration {
+        Duration::from_nanos(self.embed_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Generate an embedding, adding the time taken to `embed_time`.
+    fn embed(&self, text: &str) -> Result<Embedding, IndexingError> {
+        let start = Instant::now();
+        let result = self
+            .embedder
+            .embed(text)
+            .map_err(|e| IndexingError::Index(format!("Embedding error: {}", e)));
+        self.embed_nanos
+            .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+        result
+    }
+
     /// Extract text content from a TOC node for embedding.
-    fn extract_toc_text(node: &TocNode) -> String {
+    pub(crate) fn extract_toc_text(node: &TocNode) -> String {
         let mut parts = vec![node.title.clone()];
 
         for bullet in &node.bullets {
@@ -80,10 +103,7 @@ impl<E: EmbeddingModel> VectorIndexUpdater<E> {
         }
 
         // Generate embedding
-        let embedding = self
-            .embedder
-            .embed(&text)
-            .map_err(|e| IndexingError::Index(format!("Embedding error: {}", e)))?;
+        let embedding = self.embed(&text)?;
 
         // Get next vector ID
         let vector_id = self
@@ -140,10 +160,7 @@ impl<E: EmbeddingModel> VectorIndexUpdater<E> {
         }
 
         // Generate embedding
-        let embedding = self
-            .embedder
-            .embed(text)
-            .map_err(|e| IndexingError::Index(format!("Embedding error: {}", e)))?;
+        let embedding = self.embed(text)?;
 
         // Get next vector ID
         let vector_id = self
@@ -281,6 +298,15 @@ impl<E: EmbeddingModel> VectorIndexUpdater<E> {
         }
     }
 
+    /// Doc IDs that have a vector in the index metadata.
+    pub fn indexed_doc_ids(&self) -> Result<HashSet<String>, IndexingError> {
+        let entries = self
+            .metadata
+            .get_all()
+            .map_err(|e| IndexingError::Index(format!("Metadata scan error: {}", e)))?;
+        Ok(entries.into_iter().map(|entry| entry.doc_id).collect())
+    }
+
     /// Get the underlying storage reference.
     pub fn storage(&self) -> &Arc<Storage> {
         &self.storage
@@ -455,6 +481,59 @@ mod tests {
         assert_eq!(idx.len(), 1);
     }
 
+    /// Embedder that takes a fixed time per call.
+    struct SlowEmbedder(MockEmbedder);
+
+    impl EmbeddingModel for SlowEmbedder {
+        fn info(&self) -> &ModelInfo {
+            self.0.info()
+        }
+
+        fn embed(&self, text: &str) -> Result<Embedding, EmbeddingError> {
+            std::thread::sleep(Duration::from_millis(2));
+            self.0.embed(text)
+        }
+    }
+
+    #[test]
+    fn test_rebuild_phase_timings() {
+        use crate::rebuild::{rebuild_vector_index, NoOpProgressCallback, RebuildConfig};
+
+        let temp_dir = TempDir::new().unwrap();
+        let (index, _, metadata, storage) = create_test_components(&temp_dir);
+        for day in 1..=5 {
+            let node = TocNode::new(
+                format!("toc:day:2024-01-{:02}", day),
+                TocLevel::Day,
+                format!("Day {}", day),
+                Utc::now(),
+                Utc::now(),
+            );
+            storage.put_toc_node(&node).unwrap();
+        }
+        let embedder = Arc::new(SlowEmbedder(MockEmbedder::new(64)));
+        let updater = VectorIndexUpdater::new(index, embedder, metadata, storage.clone());
+
+        let start = Instant::now();
+        let progress = rebuild_vector_index(
+            storage,
+            &updater,
+            &RebuildConfig::default(),
+            &NoOpProgressCallback,
+        )
+        .unwrap();
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+        assert_eq!(progress.toc_nodes_indexed, 5);
+        let timings = progress.timings;
+        assert!(timings.read_ms > 0.0);
+        assert!(timings.embed_ms >= 10.0);
+        assert!(timings.index_ms > 0.0);
+        assert!(timings.commit_ms > 0.0);
+        assert!(timings.total_ms() <= elapsed_ms);
+        assert!(timings.total_ms() >= elapsed_ms * 0.9);
+    }
+
     #[test]
     fn test_index_toc_node_duplicate() {
         let temp_dir = TempDir::new().unwrap();
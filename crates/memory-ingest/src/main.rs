@@ -11,103 +11,103 @@
 //! ```bash
 //! echo '{"hook_event_name":"UserPromptSubmit","session_id":"test","message":"Hello"}' | memory-ingest
 //! ```
+//!
+//! # Replay
+//!
+//! `memory-ingest --replay <file>` ingests a captured file of newline-delimited
+//! CCH events and prints a created/skipped/error summary. Unlike hook mode,
+//! replay reports failures and exits non-zero when any line fails.
+
+use std::io::{self, BufRead, BufReader};
+use std::process::ExitCode;
+
+use memory_client::{map_cch_to_hook, map_hook_event, CchEvent, MemoryClient};
 
-use std::io::{self, BufRead};
-
-use chrono::{DateTime, Utc};
-use memory_client::{map_hook_event, HookEvent, HookEventType, MemoryClient};
-use serde::Deserialize;
-
-/// CCH event format from code_agent_context_hooks.
-#[derive(Debug, Deserialize)]
-struct CchEvent {
-    /// Event type name (e.g., "SessionStart", "UserPromptSubmit")
-    hook_event_name: String,
-    /// Session identifier
-    session_id: String,
-    /// Message content (for prompts/responses)
-    #[serde(default)]
-    message: Option<String>,
-    /// Tool name (for tool events)
-    #[serde(default)]
-    tool_name: Option<String>,
-    /// Tool input JSON (for tool events)
-    #[serde(default)]
-    tool_input: Option<serde_json::Value>,
-    /// Event timestamp
-    #[serde(default)]
-    timestamp: Option<DateTime<Utc>>,
-    /// Current working directory
-    #[serde(default)]
-    cwd: Option<String>,
-    /// Agent identifier (e.g., "opencode", "claude")
-    #[serde(default)]
-    agent: Option<String>,
+/// Output success response to CCH.
+fn output_success() {
+    println!(r#"{{"continue":true}}"#);
 }
 
-/// Map CCH event name to HookEventType.
-fn map_cch_event_type(name: &str) -> HookEventType {
-    match name {
-        "SessionStart" => HookEventType::SessionStart,
-        "UserPromptSubmit" => HookEventType::UserPromptSubmit,
-        "AssistantResponse" => HookEventType::AssistantResponse,
-        "PreToolUse" => HookEventType::ToolUse,
-        "PostToolUse" => HookEventType::ToolResult,
-        "Stop" | "SessionEnd" => HookEventType::Stop,
-        "SubagentStart" => HookEventType::SubagentStart,
-        "SubagentStop" => HookEventType::SubagentStop,
-        // Default to user prompt for unknown types
-        _ => HookEventType::UserPromptSubmit,
+/// Connect to the daemon at `MEMORY_DAEMON_ADDR`, or the default endpoint.
+async fn connect() -> Result<MemoryClient, memory_client::ClientError> {
+    if let Ok(addr) = std::env::var("MEMORY_DAEMON_ADDR") {
+        MemoryClient::connect(&addr).await
+    } else {
+        MemoryClient::connect_default().await
     }
 }
 
-/// Convert CchEvent to HookEvent.
-fn map_cch_to_hook(cch: &CchEvent) -> HookEvent {
-    let event_type = map_cch_event_type(&cch.hook_event_name);
-
-    // Build content from message or tool_input
-    let content = if let Some(msg) = &cch.message {
-        msg.clone()
-    } else if let Some(input) = &cch.tool_input {
-        serde_json::to_string(input).unwrap_or_default()
-    } else {
-        String::new()
+/// Replay a file of CCH JSON lines into the daemon.
+fn replay(path: &str) -> ExitCode {
+    let file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("Failed to open {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
     };
 
-    let mut hook = HookEvent::new(&cch.session_id, event_type, content);
+    let rt = match tokio::runtime::Runtime::new() {
+        Ok(rt) => rt,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    // Add optional fields
-    if let Some(ts) = cch.timestamp {
-        hook = hook.with_timestamp(ts);
-    }
-    if let Some(tool) = &cch.tool_name {
-        hook = hook.with_tool_name(tool);
-    }
-    if let Some(cwd) = &cch.cwd {
-        let mut metadata = std::collections::HashMap::new();
-        metadata.insert("cwd".to_string(), cwd.clone());
-        hook = hook.with_metadata(metadata);
-    }
-    if let Some(agent) = &cch.agent {
-        hook = hook.with_agent(agent.clone());
-    }
+    rt.block_on(async {
+        let mut client = match connect().await {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("Failed to connect to daemon: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
 
-    hook
+        match client.ingest_hook_stream(BufReader::new(file)).await {
+            Ok(summary) => {
+                for err in &summary.errors {
+                    eprintln!("line {}: {}", err.line, err.message);
+                }
+                println!(
+                    "created={} skipped={} errors={}",
+                    summary.created,
+                    summary.skipped,
+                    summary.errors.len()
+                );
+                if summary.errors.is_empty() {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                }
+            }
+            Err(e) => {
+                eprintln!("Replay failed: {}", e);
+                ExitCode::FAILURE
+            }
+        }
+    })
 }
 
-/// Output success response to CCH.
-fn output_success() {
-    println!(r#"{{"continue":true}}"#);
-}
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("--replay") {
+        return match args.get(1) {
+            Some(path) => replay(path),
+            None => {
+                eprintln!("Usage: memory-ingest --replay <file>");
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-fn main() {
     // Read single line from stdin
     let stdin = io::stdin();
     let mut input = String::new();
     if stdin.lock().read_line(&mut input).is_err() {
         // Can't read stdin, but still succeed (fail-open)
         output_success();
-        return;
+        return ExitCode::SUCCESS;
     }
 
     // Parse CCH event
@@ -116,7 +116,7 @@ fn main() {
         Err(_) => {
             // Invalid JSON, but still succeed (fail-open)
             output_success();
-            return;
+            return ExitCode::SUCCESS;
         }
     };
 
@@ -129,28 +129,26 @@ fn main() {
         Ok(rt) => rt,
         Err(_) => {
             output_success();
-            return;
+            return ExitCode::SUCCESS;
         }
     };
 
     rt.block_on(async {
-        let client_result = if let Ok(addr) = std::env::var("MEMORY_DAEMON_ADDR") {
-            MemoryClient::connect(&addr).await
-        } else {
-            MemoryClient::connect_default().await
-        };
-        if let Ok(mut client) = client_result {
+        if let Ok(mut client) = connect().await {
             let _ = client.ingest(event).await;
         }
     });
 
     // Always return success to CCH
     output_success();
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
+    use memory_client::{map_cch_event_type, HookEventType};
 
     #[test]
     fn test_parse_session_start() {
@@ -251,6 +249,7 @@ mod tests {
             timestamp: None,
             cwd: None,
             agent: None,
+            extra: Default::default(),
         };
 
         let hook = map_cch_to_hook(&cch);
@@ -271,6 +270,7 @@ mod tests {
             timestamp: None,
             cwd: None,
             agent: None,
+            extra: Default::default(),
         };
 
         let hook = map_cch_to_hook(&cch);
@@ -295,6 +295,7 @@ mod tests {
             timestamp: Some(ts),
             cwd: None,
             agent: None,
+            extra: Default::default(),
         };
 
         let hook = map_cch_to_hook(&cch);
@@ -313,6 +314,7 @@ mod tests {
             timestamp: None,
             cwd: Some("/home/user".to_string()),
             agent: None,
+            extra: Default::default(),
         };
 
         let hook = map_cch_to_hook(&cch);
@@ -51,6 +51,10 @@ pub struct ExplainabilityPayload {
     /// Total retrieval time in milliseconds
     pub total_time_ms: u64,
 
+    /// Time spent in each layer call
+    #[serde(default)]
+    pub layer_latencies: Vec<LayerLatency>,
+
     /// Number of results returned
     pub result_count: usize,
 
@@ -58,6 +62,16 @@ pub struct ExplainabilityPayload {
     pub grip_ids: Vec<String>,
 }
 
+/// Time spent in a single layer call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerLatency {
+    /// Layer that was called
+    pub layer: RetrievalLayer,
+
+    /// Wall-clock time in milliseconds (the timeout value if it timed out)
+    pub latency_ms: u64,
+}
+
 /// Record of a bound being hit during execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoundHit {
@@ -137,6 +151,14 @@ impl ExplainabilityPayload {
             stop_conditions: conditions.clone(),
             bounds_hit: vec![], // Populated by executor if needed
             total_time_ms: result.total_time_ms,
+            layer_latencies: result
+                .layer_latencies
+                .iter()
+                .map(|(layer, latency)| LayerLatency {
+                    layer: *layer,
+                    latency_ms: latency.as_millis() as u64,
+                })
+                .collect(),
             result_count: result.results.len(),
             grip_ids,
         }
@@ -156,6 +178,7 @@ impl ExplainabilityPayload {
             stop_conditions: StopConditions::default(),
             bounds_hit: vec![],
             total_time_ms: 0,
+            layer_latencies: vec![],
             result_count: 0,
             grip_ids: vec![],
         }
@@ -217,6 +240,18 @@ impl ExplainabilityPayload {
             lines.push(format!("- {}{}{}", marker, layer.as_str(), marker));
         }
 
+        if !self.layer_latencies.is_empty() {
+            lines.push(String::new());
+            lines.push("### Layer Latencies".to_string());
+            for entry in &self.layer_latencies {
+                lines.push(format!(
+                    "- {}: {}ms",
+                    entry.layer.as_str(),
+                    entry.latency_ms
+                ));
+            }
+        }
+
         lines.push(String::new());
         lines.push(format!(
             "*Found {} results in {}ms*",
@@ -548,6 +583,7 @@ mod tests {
             stop_conditions: StopConditions::default(),
             bounds_hit: vec![],
             total_time_ms: 150,
+            layer_latencies: vec![],
             result_count: 5,
             grip_ids: vec!["grip-1".to_string(), "grip-2".to_string()],
         };
@@ -574,6 +610,16 @@ mod tests {
             stop_conditions: StopConditions::default(),
             bounds_hit: vec![],
             total_time_ms: 50,
+            layer_latencies: vec![
+                LayerLatency {
+                    layer: RetrievalLayer::BM25,
+                    latency_ms: 30,
+                },
+                LayerLatency {
+                    layer: RetrievalLayer::Agentic,
+                    latency_ms: 20,
+                },
+            ],
             result_count: 1,
             grip_ids: vec![],
         };
@@ -582,6 +628,7 @@ mod tests {
         assert!(md.contains("## Retrieval Method"));
         assert!(md.contains("**Tier:**"));
         assert!(md.contains("locate"));
+        assert!(md.contains("### Layer Latencies\n- bm25: 30ms\n- agentic: 20ms"));
     }
 
     #[test]
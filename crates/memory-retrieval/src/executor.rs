@@ -5,13 +5,15 @@
 //!
 //! Per PRD Section 5.4: Retrieval Execution Modes
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, warn};
+use tracing::{debug, info_span, warn, Instrument};
 
+use crate::rerank::Reranker;
 use crate::types::{CapabilityTier, ExecutionMode, QueryIntent, RetrievalLayer, StopConditions};
 
 /// A single search result item.
@@ -123,8 +125,53 @@ pub struct ExecutionResult {
     /// Detailed results from each layer
     pub layer_results: Vec<LayerResults>,
 
+    /// Wall-clock time of each layer call, in call order.
+    /// Layers that timed out are recorded at the timeout value.
+    pub layer_latencies: Vec<(RetrievalLayer, Duration)>,
+
     /// Explanation of why this result was chosen
     pub explanation: String,
+
+    /// Whether a second-stage reranker was applied to the results
+    pub reranked: bool,
+
+    /// Why sequential execution stopped before the end of the chain, if it did
+    pub early_exit: Option<EarlyExit>,
+}
+
+/// Reason sequential execution stopped before trying every layer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EarlyExit {
+    /// Top result met `StopConditions::min_confidence`
+    ConfidenceReached {
+        layer: RetrievalLayer,
+        score: f32,
+        min_confidence: f32,
+    },
+
+    /// Layer returned results above the default sufficiency score
+    Sufficient { layer: RetrievalLayer },
+}
+
+impl EarlyExit {
+    /// Human-readable description of the early exit.
+    pub fn description(&self) -> String {
+        match self {
+            EarlyExit::ConfidenceReached {
+                layer,
+                score,
+                min_confidence,
+            } => format!(
+                "{} top score {:.2} met min_confidence {:.2}",
+                layer.as_str(),
+                score,
+                min_confidence
+            ),
+            EarlyExit::Sufficient { layer } => {
+                format!("{} provided sufficient results", layer.as_str())
+            }
+        }
+    }
 }
 
 impl ExecutionResult {
@@ -227,10 +274,380 @@ impl FallbackChain {
     }
 }
 
+/// Builder for a [`FallbackChain`] with a customized layer sequence.
+///
+/// Starts from [`FallbackChain::for_intent`] and lets callers reorder or
+/// drop layers. [`build`](Self::build) rejects layers the tier cannot serve
+/// and repeated layers.
+#[derive(Debug, Clone)]
+pub struct FallbackChainBuilder {
+    tier: CapabilityTier,
+    chain: FallbackChain,
+}
+
+impl FallbackChainBuilder {
+    /// Start from the default chain for `intent` and `tier`.
+    pub fn new(intent: QueryIntent, tier: CapabilityTier) -> Self {
+        Self {
+            tier,
+            chain: FallbackChain::for_intent(intent, tier),
+        }
+    }
+
+    /// Replace the layer sequence.
+    pub fn layers(mut self, layers: Vec<RetrievalLayer>) -> Self {
+        self.chain.layers = layers;
+        self
+    }
+
+    /// Remove a layer from the sequence.
+    pub fn without(mut self, layer: RetrievalLayer) -> Self {
+        self.chain.layers.retain(|l| *l != layer);
+        self
+    }
+
+    /// Set whether results from multiple layers are merged.
+    pub fn merge_results(mut self, merge: bool) -> Self {
+        self.chain.merge_results = merge;
+        self
+    }
+
+    /// Set the maximum number of layers tried.
+    pub fn max_layers(mut self, max_layers: usize) -> Self {
+        self.chain.max_layers = max_layers;
+        self
+    }
+
+    /// Validate and build the chain.
+    ///
+    /// An empty chain is allowed; executing it returns no results.
+    pub fn build(self) -> Result<FallbackChain, String> {
+        validate_layers(&self.chain.layers, Some(self.tier))?;
+        Ok(self.chain)
+    }
+}
+
+/// Check a layer sequence for repeats and, if given, tier support.
+fn validate_layers(layers: &[RetrievalLayer], tier: Option<CapabilityTier>) -> Result<(), String> {
+    for (i, layer) in layers.iter().enumerate() {
+        if layers[..i].contains(layer) {
+            return Err(format!("layer {} appears more than once", layer));
+        }
+        if let Some(tier) = tier {
+            if !tier.supports(*layer) {
+                return Err(format!(
+                    "layer {} is not available in tier {:?}",
+                    layer, tier
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Layer sequence override for one intent, optionally limited to one tier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FallbackOverride {
+    /// Intent the override applies to
+    pub intent: QueryIntent,
+
+    /// Tier the override applies to; `None` applies to every tier
+    #[serde(default)]
+    pub tier: Option<CapabilityTier>,
+
+    /// Layers to try, in order
+    pub layers: Vec<RetrievalLayer>,
+
+    /// Maximum layers to try (default: the chain's default)
+    #[serde(default)]
+    pub max_layers: Option<usize>,
+}
+
+/// User customization of fallback chains.
+///
+/// Overrides replace the default layer order for an intent. One naming the
+/// current tier wins over one without a tier. Layers in a tier-less
+/// override that the current tier cannot serve are skipped. Disabled layers
+/// are removed from every chain, including the defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FallbackChainConfig {
+    /// Layers never tried (e.g., Agentic when it is too slow)
+    pub disabled_layers: Vec<RetrievalLayer>,
+
+    /// Per-intent, per-tier layer sequences
+    pub overrides: Vec<FallbackOverride>,
+}
+
+impl FallbackChainConfig {
+    /// Never try `layer`.
+    pub fn with_disabled_layer(mut self, layer: RetrievalLayer) -> Self {
+        if !self.disabled_layers.contains(&layer) {
+            self.disabled_layers.push(layer);
+        }
+        self
+    }
+
+    /// Add a layer sequence override.
+    pub fn with_override(mut self, override_: FallbackOverride) -> Self {
+        self.overrides.push(override_);
+        self
+    }
+
+    /// Check overrides for repeated layers, layers their tier cannot serve,
+    /// and duplicate intent/tier pairs.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, o) in self.overrides.iter().enumerate() {
+            validate_layers(&o.layers, o.tier)
+                .map_err(|e| format!("override for {} intent: {}", o.intent.as_str(), e))?;
+            if self.overrides[..i]
+                .iter()
+                .any(|other| other.intent == o.intent && other.tier == o.tier)
+            {
+                return Err(format!(
+                    "more than one override for {} intent and tier {:?}",
+                    o.intent.as_str(),
+                    o.tier
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the chain for `intent` and `tier` with overrides applied.
+    pub fn chain_for(&self, intent: QueryIntent, tier: CapabilityTier) -> FallbackChain {
+        let override_ = self
+            .overrides
+            .iter()
+            .find(|o| o.intent == intent && o.tier == Some(tier))
+            .or_else(|| {
+                self.overrides
+                    .iter()
+                    .find(|o| o.intent == intent && o.tier.is_none())
+            });
+
+        let mut builder = FallbackChainBuilder::new(intent, tier);
+        if let Some(o) = override_ {
+            let mut layers: Vec<RetrievalLayer> = Vec::with_capacity(o.layers.len());
+            for layer in &o.layers {
+                if tier.supports(*layer) && !layers.contains(layer) {
+                    layers.push(*layer);
+                }
+            }
+            builder = builder.layers(layers);
+            if let Some(max_layers) = o.max_layers {
+                builder = builder.max_layers(max_layers);
+            }
+        }
+        for layer in &self.disabled_layers {
+            builder = builder.without(*layer);
+        }
+
+        builder.build().unwrap_or_else(|e| {
+            warn!(error = %e, "Invalid fallback chain override, using default");
+            FallbackChain::for_intent(intent, tier)
+        })
+    }
+}
+
+/// Default number of top candidates passed to the reranker.
+pub const DEFAULT_RERANK_TOP_N: usize = 20;
+
+/// Configuration for [`CircuitBreaker`].
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit for a layer
+    pub failure_threshold: u32,
+
+    /// Failures must all fall within this window to count as consecutive
+    pub window: Duration,
+
+    /// How long an open circuit skips the layer before probing it again
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Failure tracking for a single layer.
+#[derive(Debug, Default)]
+struct LayerCircuit {
+    /// Consecutive failures in the current window
+    failures: u32,
+    /// When the current run of failures started
+    first_failure: Option<Instant>,
+    /// Skip the layer until this time
+    open_until: Option<Instant>,
+    /// A probe call is in flight after the cooldown
+    probing: bool,
+}
+
+/// Per-layer circuit breaker shared across queries.
+///
+/// After `failure_threshold` consecutive failures within `window`, the layer
+/// is skipped for `cooldown`. The first call after the cooldown is a probe:
+/// success closes the circuit, failure opens it for another cooldown.
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Mutex<HashMap<RetrievalLayer, LayerCircuit>>,
+}
+
+impl CircuitBreaker {
+    /// Create a circuit breaker with the given configuration.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check whether a call to `layer` should go ahead.
+    ///
+    /// Returns `None` while the circuit is open, or while a probe is already
+    /// in flight after the cooldown. The returned permit holds the probe slot
+    /// until its outcome is recorded; dropping it unrecorded frees the slot
+    /// for the next caller.
+    pub fn allow(&self, layer: RetrievalLayer) -> Option<CircuitPermit<'_>> {
+        let mut circuits = self.circuits.lock().expect("circuit mutex poisoned");
+        let probe = match circuits.get_mut(&layer) {
+            None => false,
+            Some(circuit) => match circuit.open_until {
+                None => false,
+                Some(until) if Instant::now() < until => return None,
+                Some(_) if circuit.probing => return None,
+                Some(_) => {
+                    debug!(layer = ?layer, "Circuit half-open, probing layer");
+                    circuit.probing = true;
+                    true
+                }
+            },
+        };
+        Some(CircuitPermit {
+            breaker: Some(self),
+            layer,
+            probe,
+        })
+    }
+
+    /// Check whether the circuit for `layer` is currently open.
+    pub fn is_open(&self, layer: RetrievalLayer) -> bool {
+        let circuits = self.circuits.lock().expect("circuit mutex poisoned");
+        circuits.get(&layer).is_some_and(|c| c.open_until.is_some())
+    }
+
+    /// Record a successful call, closing the circuit.
+    pub fn record_success(&self, layer: RetrievalLayer) {
+        let mut circuits = self.circuits.lock().expect("circuit mutex poisoned");
+        if let Some(circuit) = circuits.remove(&layer) {
+            if circuit.open_until.is_some() {
+                debug!(layer = ?layer, "Circuit closed after successful probe");
+            }
+        }
+    }
+
+    /// Record a failed call (error or timeout).
+    pub fn record_failure(&self, layer: RetrievalLayer) {
+        let now = Instant::now();
+        let mut circuits = self.circuits.lock().expect("circuit mutex poisoned");
+        let circuit = circuits.entry(layer).or_default();
+
+        if circuit.probing {
+            warn!(layer = ?layer, "Probe failed, reopening circuit");
+            circuit.probing = false;
+            circuit.open_until = Some(now + self.config.cooldown);
+            return;
+        }
+
+        let in_window = circuit
+            .first_failure
+            .is_some_and(|first| now.duration_since(first) <= self.config.window);
+        if !in_window {
+            circuit.failures = 0;
+            circuit.first_failure = Some(now);
+        }
+        circuit.failures += 1;
+
+        if circuit.failures >= self.config.failure_threshold && circuit.open_until.is_none() {
+            warn!(
+                layer = ?layer,
+                failures = circuit.failures,
+                cooldown_ms = self.config.cooldown.as_millis() as u64,
+                "Opening circuit for failing layer"
+            );
+            circuit.open_until = Some(now + self.config.cooldown);
+        }
+    }
+
+    /// Free a probe slot whose call never reported an outcome.
+    fn release_probe(&self, layer: RetrievalLayer) {
+        let mut circuits = self.circuits.lock().expect("circuit mutex poisoned");
+        if let Some(circuit) = circuits.get_mut(&layer) {
+            circuit.probing = false;
+        }
+    }
+}
+
+/// Permission to call a layer, returned by [`CircuitBreaker::allow`].
+///
+/// Report the call's outcome with [`CircuitPermit::record`]. If the permit is
+/// dropped first (the query was cancelled or the task panicked), a held
+/// half-open probe slot is released so the layer can be probed again.
+#[derive(Debug)]
+pub struct CircuitPermit<'a> {
+    breaker: Option<&'a CircuitBreaker>,
+    layer: RetrievalLayer,
+    probe: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Permit for an executor without a circuit breaker.
+    fn unguarded(layer: RetrievalLayer) -> Self {
+        Self {
+            breaker: None,
+            layer,
+            probe: false,
+        }
+    }
+
+    /// Record the outcome of the layer call.
+    pub fn record(mut self, layer_result: &LayerResults) {
+        debug_assert_eq!(layer_result.layer, self.layer);
+        if let Some(breaker) = self.breaker {
+            if layer_result.success {
+                breaker.record_success(self.layer);
+            } else {
+                breaker.record_failure(self.layer);
+            }
+        }
+        self.probe = false;
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if let (true, Some(breaker)) = (self.probe, self.breaker) {
+            debug!(layer = ?self.layer, "Probe abandoned, releasing half-open slot");
+            breaker.release_probe(self.layer);
+        }
+    }
+}
+
 /// Retrieval executor that orchestrates search across layers.
 pub struct RetrievalExecutor<E: LayerExecutor + 'static> {
     executor: Arc<E>,
     default_limit: usize,
+    reranker: Option<Arc<dyn Reranker>>,
+    rerank_top_n: usize,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    request_id: Option<String>,
 }
 
 impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
@@ -239,6 +656,10 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
         Self {
             executor,
             default_limit: 10,
+            reranker: None,
+            rerank_top_n: DEFAULT_RERANK_TOP_N,
+            circuit_breaker: None,
+            request_id: None,
         }
     }
 
@@ -248,6 +669,63 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
         self
     }
 
+    /// Apply a second-stage reranker to the top candidates before returning.
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Set how many top candidates are passed to the reranker.
+    ///
+    /// Candidates beyond this cutoff keep their first-stage order after the
+    /// reranked head.
+    pub fn with_rerank_top_n(mut self, top_n: usize) -> Self {
+        self.rerank_top_n = top_n;
+        self
+    }
+
+    /// Skip layers whose circuit is open.
+    ///
+    /// Share one breaker across executors so failure state carries over
+    /// between queries.
+    pub fn with_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Tag tracing spans with a correlation ID for this request.
+    ///
+    /// The `retrieval_execute` span and every `retrieval_layer` span carry it
+    /// as `request_id`, so a layer executor's own logs fall under it too.
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Correlation ID for tracing spans, empty if none was set.
+    fn request_id(&self) -> &str {
+        self.request_id.as_deref().unwrap_or_default()
+    }
+
+    /// Span wrapping a single layer call.
+    fn layer_span(&self, layer: RetrievalLayer) -> tracing::Span {
+        info_span!(
+            "retrieval_layer",
+            request_id = self.request_id(),
+            layer = layer.as_str()
+        )
+    }
+
+    /// Check the circuit breaker, if any, before calling `layer`.
+    ///
+    /// Returns `None` if the layer's circuit is open.
+    fn circuit_permit(&self, layer: RetrievalLayer) -> Option<CircuitPermit<'_>> {
+        match &self.circuit_breaker {
+            Some(breaker) => breaker.allow(layer),
+            None => Some(CircuitPermit::unguarded(layer)),
+        }
+    }
+
     /// Execute a retrieval operation.
     pub async fn execute(
         &self,
@@ -259,21 +737,62 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
     ) -> ExecutionResult {
         let timeout = conditions.timeout();
         let limit = self.default_limit.min(conditions.max_nodes as usize);
+        let span = info_span!(
+            "retrieval_execute",
+            request_id = self.request_id(),
+            mode = mode.as_str(),
+            tier = ?tier
+        );
 
-        match mode {
-            ExecutionMode::Sequential => {
-                self.execute_sequential(query, chain, limit, timeout, tier)
-                    .await
-            }
-            ExecutionMode::Parallel => {
-                self.execute_parallel(query, chain, limit, timeout, tier, conditions.beam_width)
-                    .await
-            }
-            ExecutionMode::Hybrid => {
-                self.execute_hybrid(query, chain, limit, timeout, tier, conditions)
+        let result = async {
+            match mode {
+                ExecutionMode::Sequential => {
+                    self.execute_sequential(
+                        query,
+                        chain,
+                        limit,
+                        timeout,
+                        tier,
+                        conditions.min_confidence,
+                    )
                     .await
+                }
+                ExecutionMode::Parallel => {
+                    self.execute_parallel(query, chain, limit, timeout, tier, conditions.beam_width)
+                        .await
+                }
+                ExecutionMode::Hybrid => {
+                    self.execute_hybrid(query, chain, limit, timeout, tier, conditions)
+                        .await
+                }
             }
         }
+        .instrument(span)
+        .await;
+
+        self.apply_reranker(query, result)
+    }
+
+    /// Rerank the top-N candidates if a reranker is configured.
+    fn apply_reranker(&self, query: &str, mut result: ExecutionResult) -> ExecutionResult {
+        let Some(reranker) = &self.reranker else {
+            return result;
+        };
+        if result.results.is_empty() {
+            return result;
+        }
+
+        let top_n = self.rerank_top_n.min(result.results.len());
+        let tail = result.results.split_off(top_n);
+        let head = std::mem::take(&mut result.results);
+
+        let mut reranked = reranker.rerank(query, head);
+        reranked.extend(tail);
+
+        debug!(candidates = top_n, "Applied reranker");
+        result.results = reranked;
+        result.reranked = true;
+        result
     }
 
     async fn execute_sequential(
@@ -283,10 +802,13 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
         limit: usize,
         timeout: Duration,
         tier: CapabilityTier,
+        min_confidence: f32,
     ) -> ExecutionResult {
         let start = Instant::now();
+        let mut early_exit = None;
         let mut layers_attempted = Vec::new();
         let mut layer_results = Vec::new();
+        let mut layer_latencies = Vec::new();
         let mut primary_layer = RetrievalLayer::Agentic;
         let mut final_results = Vec::new();
         let mut fallback_occurred = false;
@@ -306,6 +828,16 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
                 continue;
             }
 
+            // Skip straight to fallback if the layer's circuit is open
+            let Some(permit) = self.circuit_permit(*layer) else {
+                debug!(layer = ?layer, "Circuit open, skipping layer");
+                layer_results.push(LayerResults::failure(*layer, "Circuit open".to_string(), 0));
+                if i == 0 {
+                    fallback_occurred = true;
+                }
+                continue;
+            };
+
             layers_attempted.push(*layer);
 
             // Calculate remaining time for this layer
@@ -313,10 +845,19 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
             let layer_start = Instant::now();
 
             // Execute with timeout
-            let result =
-                tokio::time::timeout(remaining, self.executor.execute(query, *layer, limit)).await;
-
-            let execution_time = layer_start.elapsed().as_millis() as u64;
+            let call = self
+                .executor
+                .execute(query, *layer, limit)
+                .instrument(self.layer_span(*layer));
+            let result = tokio::time::timeout(remaining, call).await;
+
+            let latency = if result.is_err() {
+                remaining
+            } else {
+                layer_start.elapsed()
+            };
+            layer_latencies.push((*layer, latency));
+            let execution_time = latency.as_millis() as u64;
 
             let layer_result = match result {
                 Ok(Ok(results)) => {
@@ -333,6 +874,7 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
                 }
             };
 
+            permit.record(&layer_result);
             let is_sufficient = layer_result.is_sufficient(0.3);
             layer_results.push(layer_result.clone());
 
@@ -344,12 +886,27 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
                     fallback_occurred = true;
                 }
 
+                // Stop as soon as the top result meets min_confidence
+                let top_score = layer_result.results[0].score;
+                if min_confidence > 0.0 && top_score >= min_confidence {
+                    let exit = EarlyExit::ConfidenceReached {
+                        layer: *layer,
+                        score: top_score,
+                        min_confidence,
+                    };
+                    debug!(reason = %exit.description(), "Stopping early");
+                    explanation = exit.description();
+                    early_exit = Some(exit);
+                    break;
+                }
+
                 // If results are sufficient, stop here
                 if is_sufficient {
                     explanation = format!(
                         "{} provided sufficient results (score >= 0.3)",
                         layer.as_str()
                     );
+                    early_exit = Some(EarlyExit::Sufficient { layer: *layer });
                     break;
                 } else {
                     explanation = format!(
@@ -376,7 +933,10 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
             fallback_occurred,
             total_time_ms: start.elapsed().as_millis() as u64,
             layer_results,
+            layer_latencies,
             explanation,
+            reranked: false,
+            early_exit,
         }
     }
 
@@ -391,14 +951,16 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
     ) -> ExecutionResult {
         let start = Instant::now();
 
-        // Take only up to beam_width layers for parallel execution
-        let parallel_layers: Vec<_> = chain
+        // Take only up to beam_width layers for parallel execution, skipping
+        // open circuits first so they don't use up beam slots
+        let (parallel_layers, permits): (Vec<_>, Vec<_>) = chain
             .layers
             .iter()
             .filter(|l| self.executor.supports(**l))
+            .filter_map(|l| self.circuit_permit(*l).map(|permit| (*l, permit)))
             .take(beam_width as usize)
-            .copied()
-            .collect();
+            .unzip();
+        let mut permits: HashMap<_, _> = parallel_layers.iter().copied().zip(permits).collect();
 
         if parallel_layers.is_empty() {
             return ExecutionResult {
@@ -410,40 +972,63 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
                 fallback_occurred: false,
                 total_time_ms: start.elapsed().as_millis() as u64,
                 layer_results: vec![],
+                layer_latencies: vec![],
                 explanation: "No supported layers available".to_string(),
+                reranked: false,
+                early_exit: None,
             };
         }
 
-        // Execute all layers in parallel
+        // Execute all layers in parallel, each bounded by the timeout
         let mut handles = Vec::new();
         for layer in &parallel_layers {
             let executor = self.executor.clone();
             let query = query.to_string();
             let layer = *layer;
+            let span = self.layer_span(layer);
 
             let handle = tokio::spawn(async move {
                 let start = Instant::now();
-                let result = executor.execute(&query, layer, limit).await;
-                let execution_time = start.elapsed().as_millis() as u64;
+                let call = executor.execute(&query, layer, limit).instrument(span);
+                let result = tokio::time::timeout(timeout, call).await;
 
                 match result {
-                    Ok(results) => LayerResults::success(layer, results, execution_time),
-                    Err(e) => LayerResults::failure(layer, e, execution_time),
+                    Ok(Ok(results)) => {
+                        let latency = start.elapsed();
+                        let time_ms = latency.as_millis() as u64;
+                        (LayerResults::success(layer, results, time_ms), latency)
+                    }
+                    Ok(Err(e)) => {
+                        let latency = start.elapsed();
+                        let time_ms = latency.as_millis() as u64;
+                        (LayerResults::failure(layer, e, time_ms), latency)
+                    }
+                    Err(_) => {
+                        warn!(layer = ?layer, "Layer execution timed out");
+                        let time_ms = timeout.as_millis() as u64;
+                        (
+                            LayerResults::failure(layer, "Timeout".to_string(), time_ms),
+                            timeout,
+                        )
+                    }
                 }
             });
             handles.push(handle);
         }
 
-        // Wait for all with timeout
-        let all_results = tokio::time::timeout(timeout, futures::future::join_all(handles)).await;
-
-        let layer_results: Vec<LayerResults> = match all_results {
-            Ok(results) => results.into_iter().filter_map(|r| r.ok()).collect(),
-            Err(_) => {
-                warn!("Parallel execution timed out");
-                vec![]
-            }
-        };
+        let (layer_results, layer_latencies): (Vec<LayerResults>, Vec<_>) =
+            futures::future::join_all(handles)
+                .await
+                .into_iter()
+                .filter_map(|r| r.ok())
+                .map(|(lr, latency)| {
+                    if let Some(permit) = permits.remove(&lr.layer) {
+                        permit.record(&lr);
+                    }
+                    let layer = lr.layer;
+                    (lr, (layer, latency))
+                })
+                .unzip();
 
         // Merge and deduplicate results
         let (merged_results, primary_layer, explanation) = if chain.merge_results {
@@ -462,7 +1047,10 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
             fallback_occurred: false, // No fallback in parallel mode
             total_time_ms: start.elapsed().as_millis() as u64,
             layer_results,
+            layer_latencies,
             explanation,
+            reranked: false,
+            early_exit: None,
         }
     }
 
@@ -496,7 +1084,10 @@ impl<E: LayerExecutor + 'static> RetrievalExecutor<E> {
                 fallback_occurred: false,
                 total_time_ms: start.elapsed().as_millis() as u64,
                 layer_results: vec![],
+                layer_latencies: vec![],
                 explanation: "No supported layers available".to_string(),
+                reranked: false,
+                early_exit: None,
             };
         }
 
@@ -632,6 +1223,8 @@ pub struct MockLayerExecutor {
     pub delays: std::collections::HashMap<RetrievalLayer, Duration>,
     /// Which layers to fail
     pub fail_layers: std::collections::HashSet<RetrievalLayer>,
+    /// Layers invoked, in call order
+    pub calls: Mutex<Vec<RetrievalLayer>>,
 }
 
 impl MockLayerExecutor {
@@ -652,6 +1245,11 @@ impl MockLayerExecutor {
         self.fail_layers.insert(layer);
         self
     }
+
+    /// Layers invoked so far, in call order.
+    pub fn calls(&self) -> Vec<RetrievalLayer> {
+        self.calls.lock().expect("calls mutex poisoned").clone()
+    }
 }
 
 #[async_trait]
@@ -662,6 +1260,8 @@ impl LayerExecutor for MockLayerExecutor {
         layer: RetrievalLayer,
         limit: usize,
     ) -> Result<Vec<SearchResult>, String> {
+        self.calls.lock().expect("calls mutex poisoned").push(layer);
+
         // Apply delay if configured
         if let Some(delay) = self.delays.get(&layer) {
             tokio::time::sleep(*delay).await;
@@ -754,6 +1354,128 @@ mod tests {
         assert_eq!(result.primary_layer, RetrievalLayer::Agentic);
     }
 
+    #[tokio::test]
+    async fn test_custom_chain_never_invokes_dropped_layer() {
+        let executor = Arc::new(
+            MockLayerExecutor::default()
+                .with_failure(RetrievalLayer::BM25)
+                .with_failure(RetrievalLayer::Vector)
+                .with_results(
+                    RetrievalLayer::Agentic,
+                    sample_results(RetrievalLayer::Agentic, 3, 0.9),
+                ),
+        );
+
+        let chain = FallbackChainBuilder::new(QueryIntent::Locate, CapabilityTier::Hybrid)
+            .without(RetrievalLayer::Agentic)
+            .max_layers(4)
+            .build()
+            .unwrap();
+        assert_eq!(
+            chain.layers,
+            vec![
+                RetrievalLayer::BM25,
+                RetrievalLayer::Hybrid,
+                RetrievalLayer::Vector
+            ]
+        );
+
+        let retrieval = RetrievalExecutor::new(executor.clone());
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Hybrid,
+            )
+            .await;
+
+        assert!(!result.has_results());
+        assert!(!executor.calls().contains(&RetrievalLayer::Agentic));
+        assert_eq!(executor.calls().len(), 3);
+    }
+
+    #[test]
+    fn test_chain_builder_validates_layers() {
+        let unavailable = FallbackChainBuilder::new(QueryIntent::Locate, CapabilityTier::Keyword)
+            .layers(vec![RetrievalLayer::Vector, RetrievalLayer::BM25])
+            .build();
+        assert!(unavailable.unwrap_err().contains("not available"));
+
+        let repeated = FallbackChainBuilder::new(QueryIntent::Locate, CapabilityTier::Keyword)
+            .layers(vec![RetrievalLayer::BM25, RetrievalLayer::BM25])
+            .build();
+        assert!(repeated.unwrap_err().contains("more than once"));
+    }
+
+    #[test]
+    fn test_chain_config_overrides() {
+        let config = FallbackChainConfig::default()
+            .with_disabled_layer(RetrievalLayer::Agentic)
+            .with_override(FallbackOverride {
+                intent: QueryIntent::Answer,
+                tier: None,
+                layers: vec![
+                    RetrievalLayer::Topics,
+                    RetrievalLayer::Vector,
+                    RetrievalLayer::BM25,
+                ],
+                max_layers: None,
+            })
+            .with_override(FallbackOverride {
+                intent: QueryIntent::Answer,
+                tier: Some(CapabilityTier::Keyword),
+                layers: vec![RetrievalLayer::BM25],
+                max_layers: Some(1),
+            });
+        assert!(config.validate().is_ok());
+
+        // Tier-specific override wins
+        let keyword = config.chain_for(QueryIntent::Answer, CapabilityTier::Keyword);
+        assert_eq!(keyword.layers, vec![RetrievalLayer::BM25]);
+        assert_eq!(keyword.max_layers, 1);
+
+        // Tier-less override skips layers the tier cannot serve
+        let hybrid = config.chain_for(QueryIntent::Answer, CapabilityTier::Hybrid);
+        assert_eq!(
+            hybrid.layers,
+            vec![RetrievalLayer::Vector, RetrievalLayer::BM25]
+        );
+
+        // Disabled layers are dropped from default chains too
+        let locate = config.chain_for(QueryIntent::Locate, CapabilityTier::Keyword);
+        assert_eq!(locate.layers, vec![RetrievalLayer::BM25]);
+    }
+
+    #[test]
+    fn test_chain_config_deserializes() {
+        let config: FallbackChainConfig = serde_json::from_value(serde_json::json!({
+            "disabled_layers": ["agentic"],
+            "overrides": [{
+                "intent": "answer",
+                "tier": "keyword",
+                "layers": ["bm25"],
+            }],
+        }))
+        .unwrap();
+        assert_eq!(config.disabled_layers, vec![RetrievalLayer::Agentic]);
+        assert_eq!(config.overrides[0].tier, Some(CapabilityTier::Keyword));
+        assert_eq!(config.overrides[0].layers, vec![RetrievalLayer::BM25]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_chain_config_validate_rejects_unavailable_layer() {
+        let config = FallbackChainConfig::default().with_override(FallbackOverride {
+            intent: QueryIntent::Explore,
+            tier: Some(CapabilityTier::Semantic),
+            layers: vec![RetrievalLayer::Topics],
+            max_layers: None,
+        });
+        assert!(config.validate().is_err());
+    }
+
     #[tokio::test]
     async fn test_parallel_execution() {
         let executor = MockLayerExecutor::default()
@@ -851,6 +1573,520 @@ mod tests {
         assert_eq!(result.primary_layer, RetrievalLayer::Agentic);
     }
 
+    #[tokio::test]
+    async fn test_sequential_stops_on_min_confidence() {
+        // BM25 scores below the 0.3 sufficiency bar but meets min_confidence
+        let executor = Arc::new(
+            MockLayerExecutor::default()
+                .with_results(
+                    RetrievalLayer::BM25,
+                    sample_results(RetrievalLayer::BM25, 2, 0.25),
+                )
+                .with_results(
+                    RetrievalLayer::Agentic,
+                    sample_results(RetrievalLayer::Agentic, 2, 0.9),
+                ),
+        );
+
+        let retrieval = RetrievalExecutor::new(executor.clone());
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+        let conditions = StopConditions::default().with_min_confidence(0.2);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &conditions,
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        assert_eq!(executor.calls(), vec![RetrievalLayer::BM25]);
+        assert_eq!(result.primary_layer, RetrievalLayer::BM25);
+        assert_eq!(
+            result.early_exit,
+            Some(EarlyExit::ConfidenceReached {
+                layer: RetrievalLayer::BM25,
+                score: 0.25,
+                min_confidence: 0.2,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sequential_continues_below_min_confidence() {
+        let executor = Arc::new(
+            MockLayerExecutor::default()
+                .with_results(
+                    RetrievalLayer::BM25,
+                    sample_results(RetrievalLayer::BM25, 2, 0.25),
+                )
+                .with_results(
+                    RetrievalLayer::Agentic,
+                    sample_results(RetrievalLayer::Agentic, 2, 0.9),
+                ),
+        );
+
+        let retrieval = RetrievalExecutor::new(executor.clone());
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        assert_eq!(
+            executor.calls(),
+            vec![RetrievalLayer::BM25, RetrievalLayer::Agentic]
+        );
+        assert_eq!(
+            result.early_exit,
+            Some(EarlyExit::Sufficient {
+                layer: RetrievalLayer::Agentic
+            })
+        );
+    }
+
+    /// Tracing layer recording each span's name and `request_id` field.
+    #[derive(Clone, Default)]
+    struct SpanCapture {
+        spans: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanCapture {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            struct RequestId(String);
+
+            impl tracing::field::Visit for RequestId {
+                fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                    if field.name() == "request_id" {
+                        self.0 = value.to_string();
+                    }
+                }
+
+                fn record_debug(
+                    &mut self,
+                    _field: &tracing::field::Field,
+                    _value: &dyn std::fmt::Debug,
+                ) {
+                }
+            }
+
+            let mut visitor = RequestId(String::new());
+            attrs.record(&mut visitor);
+            self.spans
+                .lock()
+                .unwrap()
+                .push((attrs.metadata().name().to_string(), visitor.0));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_id_on_execution_spans() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let capture = SpanCapture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        // BM25 returns nothing, so both layers of the chain are called
+        let executor = MockLayerExecutor::default().with_results(
+            RetrievalLayer::Agentic,
+            sample_results(RetrievalLayer::Agentic, 2, 0.9),
+        );
+        let retrieval = RetrievalExecutor::new(Arc::new(executor)).with_request_id("req-42");
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        let spans = capture.spans.lock().unwrap().clone();
+        let names: Vec<&str> = spans.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["retrieval_execute", "retrieval_layer", "retrieval_layer"]
+        );
+        assert!(spans.iter().all(|(_, id)| id == "req-42"));
+    }
+
+    /// Assert a recorded latency is within tolerance of the expected delay.
+    fn assert_latency(latencies: &[(RetrievalLayer, Duration)], layer: RetrievalLayer, ms: u64) {
+        let (_, latency) = latencies
+            .iter()
+            .find(|(l, _)| *l == layer)
+            .unwrap_or_else(|| panic!("no latency recorded for {}", layer.as_str()));
+        let expected = Duration::from_millis(ms);
+        assert!(
+            *latency >= expected && *latency < expected + Duration::from_millis(100),
+            "{} latency {:?}, expected ~{:?}",
+            layer.as_str(),
+            latency,
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sequential_layer_latencies() {
+        // BM25 is slow and empty, so execution falls back to Agentic
+        let executor = MockLayerExecutor::default()
+            .with_delay(RetrievalLayer::BM25, Duration::from_millis(80))
+            .with_delay(RetrievalLayer::Agentic, Duration::from_millis(30))
+            .with_results(
+                RetrievalLayer::Agentic,
+                sample_results(RetrievalLayer::Agentic, 2, 0.9),
+            );
+
+        let retrieval = RetrievalExecutor::new(Arc::new(executor));
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        let layers: Vec<_> = result.layer_latencies.iter().map(|(l, _)| *l).collect();
+        assert_eq!(layers, vec![RetrievalLayer::BM25, RetrievalLayer::Agentic]);
+        assert_latency(&result.layer_latencies, RetrievalLayer::BM25, 80);
+        assert_latency(&result.layer_latencies, RetrievalLayer::Agentic, 30);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_layer_latencies_with_timeout() {
+        // Vector outlasts the 150ms timeout and is recorded at the timeout value
+        let executor = MockLayerExecutor::default()
+            .with_delay(RetrievalLayer::BM25, Duration::from_millis(40))
+            .with_delay(RetrievalLayer::Vector, Duration::from_secs(5))
+            .with_results(
+                RetrievalLayer::BM25,
+                sample_results(RetrievalLayer::BM25, 2, 0.9),
+            );
+
+        let retrieval = RetrievalExecutor::new(Arc::new(executor));
+        let chain = FallbackChain {
+            layers: vec![RetrievalLayer::BM25, RetrievalLayer::Vector],
+            max_layers: 2,
+            merge_results: true,
+        };
+        let conditions =
+            StopConditions::with_timeout(Duration::from_millis(150)).with_beam_width(2);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &conditions,
+                ExecutionMode::Parallel,
+                CapabilityTier::Hybrid,
+            )
+            .await;
+
+        assert_eq!(result.layer_latencies.len(), 2);
+        assert_latency(&result.layer_latencies, RetrievalLayer::BM25, 40);
+        let (_, vector) = result.layer_latencies[1];
+        assert_eq!(vector, Duration::from_millis(150));
+        assert!(result.has_results());
+
+        let timed_out = &result.layer_results[1];
+        assert!(!timed_out.success);
+        assert_eq!(timed_out.error.as_deref(), Some("Timeout"));
+    }
+
+    /// Layer executor whose BM25 backend can be switched between failing
+    /// slowly and succeeding, counting how often it is called.
+    struct FlakyExecutor {
+        failing: std::sync::atomic::AtomicBool,
+        bm25_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LayerExecutor for FlakyExecutor {
+        async fn execute(
+            &self,
+            _query: &str,
+            layer: RetrievalLayer,
+            _limit: usize,
+        ) -> Result<Vec<SearchResult>, String> {
+            use std::sync::atomic::Ordering;
+
+            if layer != RetrievalLayer::BM25 {
+                return Ok(sample_results(layer, 1, 0.5));
+            }
+            self.bm25_calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Err("connection refused".to_string())
+            } else {
+                Ok(sample_results(layer, 2, 0.9))
+            }
+        }
+
+        fn supports(&self, _layer: RetrievalLayer) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_skips_failing_layer() {
+        use std::sync::atomic::Ordering;
+
+        let flaky = Arc::new(FlakyExecutor {
+            failing: std::sync::atomic::AtomicBool::new(true),
+            bm25_calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_millis(100),
+        }));
+
+        let run = || {
+            let retrieval =
+                RetrievalExecutor::new(flaky.clone()).with_circuit_breaker(breaker.clone());
+            async move {
+                retrieval
+                    .execute(
+                        "test query",
+                        FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword),
+                        &StopConditions::default(),
+                        ExecutionMode::Sequential,
+                        CapabilityTier::Keyword,
+                    )
+                    .await
+            }
+        };
+
+        // Two failures open the circuit
+        for _ in 0..2 {
+            let result = run().await;
+            assert_eq!(result.primary_layer, RetrievalLayer::Agentic);
+        }
+        assert_eq!(flaky.bm25_calls.load(Ordering::SeqCst), 2);
+        assert!(breaker.is_open(RetrievalLayer::BM25));
+
+        // While open, BM25 is skipped without being called
+        let start = Instant::now();
+        let result = run().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(flaky.bm25_calls.load(Ordering::SeqCst), 2);
+        assert!(result.fallback_occurred);
+        assert_eq!(result.primary_layer, RetrievalLayer::Agentic);
+        assert_eq!(
+            result.layer_results[0].error.as_deref(),
+            Some("Circuit open")
+        );
+        assert!(!result.layers_attempted.contains(&RetrievalLayer::BM25));
+
+        // After the cooldown a probe goes through and closes the circuit
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        flaky.failing.store(false, Ordering::SeqCst);
+        let result = run().await;
+        assert_eq!(flaky.bm25_calls.load(Ordering::SeqCst), 3);
+        assert_eq!(result.primary_layer, RetrievalLayer::BM25);
+        assert!(!breaker.is_open(RetrievalLayer::BM25));
+    }
+
+    #[test]
+    fn test_circuit_breaker_failed_probe_reopens() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            window: Duration::from_secs(60),
+            cooldown: Duration::ZERO,
+        });
+
+        breaker.record_failure(RetrievalLayer::Vector);
+        assert!(breaker.is_open(RetrievalLayer::Vector));
+
+        // Only one probe is allowed at a time
+        let probe = breaker
+            .allow(RetrievalLayer::Vector)
+            .expect("probe allowed");
+        assert!(breaker.allow(RetrievalLayer::Vector).is_none());
+
+        probe.record(&LayerResults::failure(
+            RetrievalLayer::Vector,
+            "down".to_string(),
+            0,
+        ));
+        assert!(breaker.is_open(RetrievalLayer::Vector));
+        let probe = breaker
+            .allow(RetrievalLayer::Vector)
+            .expect("probe allowed");
+
+        probe.record(&LayerResults::success(RetrievalLayer::Vector, vec![], 0));
+        assert!(!breaker.is_open(RetrievalLayer::Vector));
+        assert!(breaker.allow(RetrievalLayer::BM25).is_some());
+    }
+
+    #[test]
+    fn test_circuit_breaker_dropped_probe_releases_slot() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            window: Duration::from_secs(60),
+            cooldown: Duration::ZERO,
+        });
+
+        breaker.record_failure(RetrievalLayer::Vector);
+        let probe = breaker
+            .allow(RetrievalLayer::Vector)
+            .expect("probe allowed");
+        assert!(breaker.allow(RetrievalLayer::Vector).is_none());
+
+        // A cancelled probe frees the slot without closing the circuit
+        drop(probe);
+        assert!(breaker.is_open(RetrievalLayer::Vector));
+        assert!(breaker.allow(RetrievalLayer::Vector).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_parallel_open_circuit_does_not_use_beam_slot() {
+        let flaky = Arc::new(FlakyExecutor {
+            failing: std::sync::atomic::AtomicBool::new(true),
+            bm25_calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(60),
+        }));
+        breaker.record_failure(RetrievalLayer::BM25);
+
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+        assert_eq!(chain.layers[0], RetrievalLayer::BM25);
+        let result = RetrievalExecutor::new(flaky.clone())
+            .with_circuit_breaker(breaker)
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default().with_beam_width(1),
+                ExecutionMode::Parallel,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        assert_eq!(
+            flaky.bm25_calls.load(std::sync::atomic::Ordering::SeqCst),
+            0
+        );
+        assert_eq!(result.layers_attempted.len(), 1);
+        assert_ne!(result.layers_attempted[0], RetrievalLayer::BM25);
+        assert!(result.has_results());
+    }
+
+    /// Reranker that reverses candidate order.
+    struct ReverseReranker;
+
+    impl Reranker for ReverseReranker {
+        fn rerank(&self, _query: &str, mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+            results.reverse();
+            results
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reranker_applied_to_results() {
+        let executor = MockLayerExecutor::default().with_results(
+            RetrievalLayer::BM25,
+            sample_results(RetrievalLayer::BM25, 4, 0.8),
+        );
+
+        let retrieval =
+            RetrievalExecutor::new(Arc::new(executor)).with_reranker(Arc::new(ReverseReranker));
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        assert!(result.reranked);
+        let ids: Vec<_> = result.results.iter().map(|r| r.doc_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["doc-bm25-3", "doc-bm25-2", "doc-bm25-1", "doc-bm25-0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reranker_limited_to_top_n() {
+        let executor = MockLayerExecutor::default().with_results(
+            RetrievalLayer::BM25,
+            sample_results(RetrievalLayer::BM25, 4, 0.8),
+        );
+
+        let retrieval = RetrievalExecutor::new(Arc::new(executor))
+            .with_reranker(Arc::new(ReverseReranker))
+            .with_rerank_top_n(2);
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        let ids: Vec<_> = result.results.iter().map(|r| r.doc_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["doc-bm25-1", "doc-bm25-0", "doc-bm25-2", "doc-bm25-3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_reranker_by_default() {
+        let executor = MockLayerExecutor::default().with_results(
+            RetrievalLayer::BM25,
+            sample_results(RetrievalLayer::BM25, 3, 0.8),
+        );
+
+        let retrieval = RetrievalExecutor::new(Arc::new(executor));
+        let chain = FallbackChain::for_intent(QueryIntent::Locate, CapabilityTier::Keyword);
+
+        let result = retrieval
+            .execute(
+                "test query",
+                chain,
+                &StopConditions::default(),
+                ExecutionMode::Sequential,
+                CapabilityTier::Keyword,
+            )
+            .await;
+
+        assert!(!result.reranked);
+        assert_eq!(result.results[0].doc_id, "doc-bm25-0");
+    }
+
     #[test]
     fn test_fallback_chain_for_intent() {
         let chain = FallbackChain::for_intent(QueryIntent::Explore, CapabilityTier::Full);
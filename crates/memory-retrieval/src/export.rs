@@ -0,0 +1,235 @@
+//! Export of retrieval results for sharing.
+//!
+//! Renders a routed query, its explainability payload, and the ranked
+//! results as a Markdown report suitable for pasting into a PR or issue.
+
+use crate::contracts::ExplainabilityPayload;
+use crate::executor::{ExecutionResult, SearchResult};
+use crate::types::{QueryIntent, StopConditions};
+
+/// Maximum characters of a result preview included in a report.
+const MAX_PREVIEW_CHARS: usize = 200;
+
+/// A shareable report of a single retrieval.
+#[derive(Debug, Clone)]
+pub struct RetrievalReport {
+    /// The query that was routed
+    pub query: String,
+
+    /// How and why the retrieval was performed
+    pub explanation: ExplainabilityPayload,
+
+    /// Ranked results
+    pub results: Vec<SearchResult>,
+}
+
+impl RetrievalReport {
+    /// Create a report from its parts.
+    pub fn new(
+        query: impl Into<String>,
+        explanation: ExplainabilityPayload,
+        results: Vec<SearchResult>,
+    ) -> Self {
+        Self {
+            query: query.into(),
+            explanation,
+            results,
+        }
+    }
+
+    /// Create a report from an execution result.
+    pub fn from_execution(
+        query: impl Into<String>,
+        intent: QueryIntent,
+        result: &ExecutionResult,
+        conditions: &StopConditions,
+    ) -> Self {
+        Self::new(
+            query,
+            ExplainabilityPayload::from_execution(intent, result, conditions),
+            result.results.clone(),
+        )
+    }
+
+    /// Render the report as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let exp = &self.explanation;
+        let mut lines = Vec::new();
+
+        lines.push("# Retrieval Report".to_string());
+        lines.push(String::new());
+        lines.push(format!("**Query:** {}", single_line(&self.query)));
+        lines.push(String::new());
+
+        lines.push("## Routing".to_string());
+        lines.push(String::new());
+        lines.push(format!("- **Intent:** {}", exp.intent.as_str()));
+        lines.push(format!("- **Tier:** {}", exp.tier.description()));
+        lines.push(format!("- **Mode:** {}", exp.mode.as_str()));
+        lines.push(format!(
+            "- **Winner:** {} ({})",
+            exp.winner.as_str(),
+            single_line(&exp.why_winner)
+        ));
+        if exp.fallback_occurred {
+            lines.push(format!(
+                "- **Fallback:** {}",
+                exp.fallback_reason
+                    .as_deref()
+                    .map_or_else(|| "Yes".to_string(), single_line)
+            ));
+        }
+        if !exp.candidates_considered.is_empty() {
+            let layers: Vec<&str> = exp
+                .candidates_considered
+                .iter()
+                .map(|l| l.as_str())
+                .collect();
+            lines.push(format!("- **Layers attempted:** {}", layers.join(" -> ")));
+        }
+        if !exp.layer_latencies.is_empty() {
+            let latencies: Vec<String> = exp
+                .layer_latencies
+                .iter()
+                .map(|l| format!("{} {}ms", l.layer.as_str(), l.latency_ms))
+                .collect();
+            lines.push(format!("- **Layer latencies:** {}", latencies.join(", ")));
+        }
+        lines.push(format!("- **Time:** {}ms", exp.total_time_ms));
+        lines.push(String::new());
+
+        lines.push(format!("## Results ({})", self.results.len()));
+        lines.push(String::new());
+        if self.results.is_empty() {
+            lines.push("_No results found._".to_string());
+        }
+        for (i, result) in self.results.iter().enumerate() {
+            lines.push(format!(
+                "{}. **{}** (score: {:.4}, type: `{}`, layer: {})",
+                i + 1,
+                result.doc_id,
+                result.score,
+                result.doc_type,
+                result.source_layer.as_str()
+            ));
+            let preview = preview(&result.text_preview);
+            if !preview.is_empty() {
+                lines.push(format!("   > {}", preview));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Collapse whitespace so text stays on one Markdown line.
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Single-line preview truncated to `MAX_PREVIEW_CHARS`.
+fn preview(text: &str) -> String {
+    let line = single_line(text);
+    if line.chars().count() <= MAX_PREVIEW_CHARS {
+        return line;
+    }
+    let truncated: String = line.chars().take(MAX_PREVIEW_CHARS).collect();
+    format!("{}...", truncated.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CapabilityTier, ExecutionMode, RetrievalLayer};
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn result(doc_id: &str, doc_type: &str, score: f32, preview: &str) -> SearchResult {
+        SearchResult {
+            doc_id: doc_id.to_string(),
+            doc_type: doc_type.to_string(),
+            score,
+            text_preview: preview.to_string(),
+            source_layer: RetrievalLayer::BM25,
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn execution(results: Vec<SearchResult>) -> ExecutionResult {
+        ExecutionResult {
+            results,
+            layers_attempted: vec![RetrievalLayer::Hybrid, RetrievalLayer::BM25],
+            primary_layer: RetrievalLayer::BM25,
+            tier: CapabilityTier::Hybrid,
+            mode: ExecutionMode::Sequential,
+            fallback_occurred: true,
+            total_time_ms: 42,
+            layer_results: vec![],
+            layer_latencies: vec![
+                (RetrievalLayer::Hybrid, Duration::from_millis(30)),
+                (RetrievalLayer::BM25, Duration::from_millis(12)),
+            ],
+            explanation: "Hybrid returned nothing".to_string(),
+            reranked: false,
+            early_exit: None,
+        }
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let exec = execution(vec![
+            result(
+                "toc:day:2024-01-15",
+                "toc_node",
+                0.91,
+                "Fixed JWT\nvalidation",
+            ),
+            result("grip:123", "grip", 0.5, ""),
+        ]);
+        let report = RetrievalReport::from_execution(
+            "How did we fix the JWT bug?",
+            QueryIntent::Answer,
+            &exec,
+            &StopConditions::default(),
+        );
+        let md = report.to_markdown();
+
+        assert!(md.starts_with("# Retrieval Report\n"));
+        assert!(md.contains("**Query:** How did we fix the JWT bug?"));
+        assert!(md.contains("## Routing"));
+        assert!(md.contains("- **Intent:** answer"));
+        assert!(md.contains("- **Mode:** sequential"));
+        assert!(md.contains("- **Fallback:** Hybrid returned nothing"));
+        assert!(md.contains("- **Layers attempted:** hybrid -> bm25"));
+        assert!(md.contains("- **Layer latencies:** hybrid 30ms, bm25 12ms"));
+        assert!(md.contains("- **Time:** 42ms"));
+        assert!(md.contains("## Results (2)"));
+        assert!(md.contains(
+            "1. **toc:day:2024-01-15** (score: 0.9100, type: `toc_node`, layer: bm25)\n   > Fixed JWT validation\n"
+        ));
+        assert!(md.contains("2. **grip:123** (score: 0.5000, type: `grip`, layer: bm25)\n"));
+        assert!(!md.contains("2. **grip:123** (score: 0.5000, type: `grip`, layer: bm25)\n   >"));
+    }
+
+    #[test]
+    fn test_markdown_report_no_results() {
+        let report = RetrievalReport::from_execution(
+            "nothing here",
+            QueryIntent::Locate,
+            &execution(vec![]),
+            &StopConditions::default(),
+        );
+        let md = report.to_markdown();
+
+        assert!(md.contains("## Results (0)"));
+        assert!(md.contains("_No results found._"));
+    }
+
+    #[test]
+    fn test_preview_truncation() {
+        let long = "word ".repeat(100);
+        let p = preview(&long);
+        assert!(p.ends_with("..."));
+        assert!(p.chars().count() <= MAX_PREVIEW_CHARS + 3);
+    }
+}
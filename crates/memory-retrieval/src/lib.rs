@@ -57,7 +57,9 @@
 //! - [`classifier`]: Intent classification using keyword heuristics
 //! - [`tier`]: Tier detection from layer statuses
 //! - [`executor`]: Retrieval execution with fallbacks
+//! - [`rerank`]: Second-stage reranking of retrieval candidates
 //! - [`contracts`]: Skill contracts and explainability
+//! - [`export`]: Markdown reports of retrieval results
 //!
 //! ## References
 //!
@@ -66,7 +68,9 @@
 pub mod classifier;
 pub mod contracts;
 pub mod executor;
+pub mod export;
 pub mod ranking;
+pub mod rerank;
 pub mod stale_filter;
 pub mod tier;
 pub mod types;
@@ -75,15 +79,20 @@ pub mod types;
 pub use classifier::{ClassificationResult, ClassifierConfig, IntentClassifier, TimeConstraint};
 pub use contracts::{
     generate_skill_md_section, BoundAction, BoundHit, BoundType, ExplainabilityPayload,
-    IssueSeverity, SkillContract, SkillContractIssue, SkillContractValidation,
+    IssueSeverity, LayerLatency, SkillContract, SkillContractIssue, SkillContractValidation,
 };
 pub use executor::{
-    ExecutionResult, FallbackChain, LayerExecutor, LayerResults, MockLayerExecutor,
-    RetrievalExecutor, SearchResult,
+    CircuitBreaker, CircuitBreakerConfig, CircuitPermit, EarlyExit, ExecutionResult, FallbackChain,
+    FallbackChainBuilder, FallbackChainConfig, FallbackOverride, LayerExecutor, LayerResults,
+    MockLayerExecutor, RetrievalExecutor, SearchResult,
 };
+pub use export::RetrievalReport;
 pub use ranking::{apply_combined_ranking, RankingConfig};
+pub use rerank::{LexicalOverlapReranker, NoopReranker, Reranker};
 pub use stale_filter::StaleFilter;
-pub use tier::{LayerStatusProvider, MockLayerStatusProvider, TierDetectionResult, TierDetector};
+pub use tier::{
+    LayerStatusProvider, MockLayerStatusProvider, TierDetectionResult, TierDetector, TierWatcher,
+};
 pub use types::{
     CapabilityTier, CombinedStatus, ExecutionMode, LayerStatus, QueryIntent, RetrievalLayer,
     StopConditions,
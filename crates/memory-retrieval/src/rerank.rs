@@ -0,0 +1,150 @@
+//! Second-stage reranking of retrieval candidates.
+//!
+//! First-stage layers (BM25, vector, topics) optimize for recall. A `Reranker`
+//! reorders the top-N candidates before they leave the executor so precision@k
+//! is not bounded by first-stage ordering. A cross-encoder model is the
+//! intended production implementation; this module ships a no-op and a simple
+//! lexical-overlap reranker.
+
+use std::collections::HashSet;
+
+use crate::executor::SearchResult;
+
+/// Trait for second-stage result reranking.
+pub trait Reranker: Send + Sync {
+    /// Rerank candidates for a query, returning them in final order.
+    fn rerank(&self, query: &str, results: Vec<SearchResult>) -> Vec<SearchResult>;
+}
+
+/// Reranker that returns candidates unchanged.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopReranker;
+
+impl Reranker for NoopReranker {
+    fn rerank(&self, _query: &str, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        results
+    }
+}
+
+/// Reranker that blends the first-stage score with query term overlap.
+///
+/// The overlap is the fraction of distinct query terms found in the result's
+/// text preview. The final score is
+/// `(1 - overlap_weight) * score + overlap_weight * overlap`.
+#[derive(Debug, Clone)]
+pub struct LexicalOverlapReranker {
+    /// Weight of the overlap component (0.0-1.0)
+    pub overlap_weight: f32,
+}
+
+impl Default for LexicalOverlapReranker {
+    fn default() -> Self {
+        Self {
+            overlap_weight: 0.5,
+        }
+    }
+}
+
+impl LexicalOverlapReranker {
+    /// Create a reranker with the given overlap weight (clamped to 0.0-1.0).
+    pub fn new(overlap_weight: f32) -> Self {
+        Self {
+            overlap_weight: overlap_weight.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Fraction of query terms present in the text.
+    fn overlap(query_terms: &HashSet<String>, text: &str) -> f32 {
+        if query_terms.is_empty() {
+            return 0.0;
+        }
+        let text_terms = tokenize(text);
+        let matched = query_terms
+            .iter()
+            .filter(|t| text_terms.contains(*t))
+            .count();
+        matched as f32 / query_terms.len() as f32
+    }
+}
+
+impl Reranker for LexicalOverlapReranker {
+    fn rerank(&self, query: &str, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        let query_terms = tokenize(query);
+        let mut reranked: Vec<SearchResult> = results
+            .into_iter()
+            .map(|mut r| {
+                let overlap = Self::overlap(&query_terms, &r.text_preview);
+                r.score = (1.0 - self.overlap_weight) * r.score + self.overlap_weight * overlap;
+                r
+            })
+            .collect();
+
+        reranked.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        reranked
+    }
+}
+
+/// Lowercased alphanumeric terms of at least two characters.
+fn tokenize(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.len() >= 2)
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RetrievalLayer;
+
+    fn result(doc_id: &str, score: f32, text: &str) -> SearchResult {
+        SearchResult {
+            doc_id: doc_id.to_string(),
+            doc_type: "toc_node".to_string(),
+            score,
+            text_preview: text.to_string(),
+            source_layer: RetrievalLayer::BM25,
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_noop_reranker_preserves_order() {
+        let results = vec![result("a", 0.9, ""), result("b", 0.5, "")];
+        let reranked = NoopReranker.rerank("query", results);
+        let ids: Vec<_> = reranked.iter().map(|r| r.doc_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_lexical_overlap_promotes_matching_text() {
+        let results = vec![
+            result("unrelated", 0.6, "Discussed database migrations"),
+            result("match", 0.5, "Fixed the JWT token validation bug"),
+        ];
+        let reranked = LexicalOverlapReranker::default().rerank("JWT token bug", results);
+        assert_eq!(reranked[0].doc_id, "match");
+        assert!(reranked[0].score > reranked[1].score);
+    }
+
+    #[test]
+    fn test_lexical_overlap_zero_weight_keeps_scores() {
+        let results = vec![result("a", 0.8, "alpha"), result("b", 0.4, "beta")];
+        let reranked = LexicalOverlapReranker::new(0.0).rerank("beta", results);
+        assert_eq!(reranked[0].doc_id, "a");
+        assert_eq!(reranked[0].score, 0.8);
+    }
+
+    #[test]
+    fn test_tokenize_lowercases_and_drops_short_terms() {
+        let terms = tokenize("Fix a JWT-Bug!");
+        assert!(terms.contains("fix"));
+        assert!(terms.contains("jwt"));
+        assert!(terms.contains("bug"));
+        assert!(!terms.contains("a"));
+    }
+}
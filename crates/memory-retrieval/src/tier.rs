@@ -1,13 +1,16 @@
 //! Tier detection for retrieval capability assessment.
 //!
 //! This module implements the `TierDetector` which queries layer statuses
-//! and determines the available capability tier.
+//! and determines the available capability tier, and the `TierWatcher`
+//! which reports when that tier changes.
 //!
 //! Per PRD Section 5.2: Tier Detection Algorithm
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio::time::MissedTickBehavior;
 use tracing::{debug, info, warn};
 
 use crate::types::{CapabilityTier, CombinedStatus, LayerStatus, QueryIntent, RetrievalLayer};
@@ -282,6 +285,103 @@ impl<P: LayerStatusProvider> TierDetector<P> {
     }
 }
 
+/// Default interval between tier checks in a [`TierWatcher`].
+pub const DEFAULT_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default time a new tier must hold before a [`TierWatcher`] reports it.
+pub const DEFAULT_WATCH_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Watches for capability tier changes.
+///
+/// Re-runs tier detection every poll interval and reports a
+/// [`TierDetectionResult`] when the tier differs from the last one
+/// reported. A new tier must be seen for the whole debounce period before
+/// it is reported, so a layer flapping between states produces no events.
+pub struct TierWatcher<P: LayerStatusProvider> {
+    detector: TierDetector<P>,
+    poll_interval: Duration,
+    debounce: Duration,
+}
+
+impl<P: LayerStatusProvider + 'static> TierWatcher<P> {
+    /// Create a watcher with default poll interval and debounce.
+    pub fn new(provider: Arc<P>) -> Self {
+        Self {
+            // Every poll must see fresh status
+            detector: TierDetector::new(provider).with_cache_duration(Duration::ZERO),
+            poll_interval: DEFAULT_WATCH_POLL_INTERVAL,
+            debounce: DEFAULT_WATCH_DEBOUNCE,
+        }
+    }
+
+    /// Set the interval between tier checks.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Set how long a new tier must hold before it is reported.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Detect the current tier and start watching in a background task.
+    ///
+    /// Returns a receiver holding the latest reported detection, starting
+    /// with the baseline; it is marked changed whenever the tier changes.
+    /// Clone the receiver to share one watcher between subscribers. The
+    /// task stops once every receiver is dropped.
+    pub async fn start(self) -> watch::Receiver<TierDetectionResult> {
+        let baseline = self.detector.detect().await;
+        let reported = baseline.tier;
+        let (sender, receiver) = watch::channel(baseline);
+        tokio::spawn(self.watch(reported, sender));
+        receiver
+    }
+
+    async fn watch(self, mut reported: CapabilityTier, sender: watch::Sender<TierDetectionResult>) {
+        let mut pending: Option<(CapabilityTier, Instant)> = None;
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately; the baseline covers it
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = sender.closed() => break,
+            }
+
+            let result = self.detector.detect().await;
+            if result.tier == reported {
+                pending = None;
+                continue;
+            }
+
+            let since = match pending {
+                Some((tier, since)) if tier == result.tier => since,
+                _ => {
+                    let now = Instant::now();
+                    pending = Some((result.tier, now));
+                    now
+                }
+            };
+            if since.elapsed() < self.debounce {
+                continue;
+            }
+
+            info!(from = ?reported, to = ?result.tier, "Capability tier changed");
+            reported = result.tier;
+            pending = None;
+            if sender.send(result).is_err() {
+                break;
+            }
+        }
+        debug!("Tier watcher stopped");
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum StatusType {
     BM25,
@@ -403,6 +503,80 @@ impl LayerStatusProvider for MockLayerStatusProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// BM25 always ready; Vector switched on and off by the test.
+    #[derive(Default)]
+    struct ToggleVectorProvider {
+        vector_ready: AtomicBool,
+    }
+
+    #[async_trait]
+    impl LayerStatusProvider for ToggleVectorProvider {
+        async fn get_bm25_status(&self) -> Result<LayerStatus, String> {
+            Ok(LayerStatus::available(RetrievalLayer::BM25, 10))
+        }
+
+        async fn get_vector_status(&self) -> Result<LayerStatus, String> {
+            if self.vector_ready.load(Ordering::SeqCst) {
+                Ok(LayerStatus::available(RetrievalLayer::Vector, 10))
+            } else {
+                Ok(LayerStatus::disabled(RetrievalLayer::Vector))
+            }
+        }
+
+        async fn get_topics_status(&self) -> Result<LayerStatus, String> {
+            Ok(LayerStatus::disabled(RetrievalLayer::Topics))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reports_tier_change_once() {
+        let provider = Arc::new(ToggleVectorProvider::default());
+        let mut changes = TierWatcher::new(provider.clone())
+            .with_poll_interval(Duration::from_millis(10))
+            .with_debounce(Duration::from_millis(50))
+            .start()
+            .await;
+        assert_eq!(changes.borrow_and_update().tier, CapabilityTier::Keyword);
+        let mut other = changes.clone();
+
+        provider.vector_ready.store(true, Ordering::SeqCst);
+
+        tokio::time::timeout(Duration::from_secs(2), changes.changed())
+            .await
+            .expect("tier change reported")
+            .unwrap();
+        assert_eq!(changes.borrow_and_update().tier, CapabilityTier::Hybrid);
+        // Every subscriber of the shared watcher sees the change
+        assert!(other.has_changed().unwrap());
+        assert_eq!(other.borrow_and_update().tier, CapabilityTier::Hybrid);
+
+        // The tier holds, so nothing else is reported
+        let next = tokio::time::timeout(Duration::from_millis(200), changes.changed()).await;
+        assert!(next.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watcher_debounces_flaps() {
+        let provider = Arc::new(ToggleVectorProvider::default());
+        let mut changes = TierWatcher::new(provider.clone())
+            .with_poll_interval(Duration::from_millis(10))
+            .with_debounce(Duration::from_millis(300))
+            .start()
+            .await;
+        changes.borrow_and_update();
+
+        for _ in 0..5 {
+            provider.vector_ready.store(true, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            provider.vector_ready.store(false, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(30)).await;
+        }
+
+        let next = tokio::time::timeout(Duration::from_millis(400), changes.changed()).await;
+        assert!(next.is_err());
+    }
 
     #[tokio::test]
     async fn test_detect_full_tier() {
@@ -165,6 +165,10 @@ pub enum RetrievalLayer {
     /// Vector semantic search (Layer 4)
     Vector,
     /// BM25 keyword search (Layer 3)
+    ///
+    /// Serialized as `bm25`; `b_m25`, the name `rename_all` used to give it,
+    /// is still accepted.
+    #[serde(rename = "bm25", alias = "b_m25")]
     BM25,
     /// Agentic TOC navigation (Layer 2) - always available
     Agentic,
@@ -223,7 +227,9 @@ pub struct StopConditions {
     /// Beam width for parallel operations (default: 1, range: 1-5)
     pub beam_width: u8,
 
-    /// Minimum confidence score to accept results (default: 0.0)
+    /// Minimum confidence score to accept results (default: 0.0).
+    /// When set, sequential execution stops at the first layer whose top
+    /// result meets it.
     pub min_confidence: f32,
 
     /// Filter results to a specific agent (Phase 18).
@@ -684,6 +690,16 @@ mod tests {
         assert_eq!(format!("{}", RetrievalLayer::Hybrid), "hybrid");
     }
 
+    #[test]
+    fn test_retrieval_layer_serde_accepts_old_bm25_name() {
+        let old: RetrievalLayer = serde_json::from_str("\"b_m25\"").unwrap();
+        assert_eq!(old, RetrievalLayer::BM25);
+        assert_eq!(serde_json::to_string(&old).unwrap(), "\"bm25\"");
+
+        let chain: Vec<RetrievalLayer> = serde_json::from_str(r#"["hybrid", "b_m25"]"#).unwrap();
+        assert_eq!(chain, vec![RetrievalLayer::Hybrid, RetrievalLayer::BM25]);
+    }
+
     #[test]
     fn test_stop_conditions_agent_filter() {
         // Default has no agent filter
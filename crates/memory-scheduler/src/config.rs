@@ -20,6 +20,12 @@ pub struct SchedulerConfig {
     /// Defaults to 30 seconds.
     #[serde(default = "default_shutdown_timeout")]
     pub shutdown_timeout_secs: u64,
+
+    /// Maximum jobs running at once across the scheduler.
+    /// When several jobs are waiting, higher-priority jobs start first.
+    /// Defaults to 0 (no limit).
+    #[serde(default)]
+    pub max_concurrent_jobs: usize,
 }
 
 fn default_timezone() -> String {
@@ -35,6 +41,7 @@ impl Default for SchedulerConfig {
         Self {
             default_timezone: default_timezone(),
             shutdown_timeout_secs: default_shutdown_timeout(),
+            max_concurrent_jobs: 0,
         }
     }
 }
@@ -62,6 +69,7 @@ mod tests {
         let config = SchedulerConfig::default();
         assert_eq!(config.default_timezone, "UTC");
         assert_eq!(config.shutdown_timeout_secs, 30);
+        assert_eq!(config.max_concurrent_jobs, 0);
     }
 
     #[test]
@@ -100,10 +108,12 @@ mod tests {
         let config = SchedulerConfig {
             default_timezone: "Europe/London".to_string(),
             shutdown_timeout_secs: 60,
+            max_concurrent_jobs: 2,
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: SchedulerConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.default_timezone, "Europe/London");
         assert_eq!(parsed.shutdown_timeout_secs, 60);
+        assert_eq!(parsed.max_concurrent_jobs, 2);
     }
 }
@@ -370,6 +370,39 @@ mod tests {
         assert!(!stats.errors.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_job_uses_settings_retention() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        let mut settings = memory_types::Bm25LifecycleSettings {
+            enabled: true,
+            ..Default::default()
+        };
+        settings.retention.insert("grip".to_string(), 7);
+        settings.retention.insert("week".to_string(), 365);
+        let config = Bm25PruneJobConfig {
+            lifecycle: Bm25LifecycleConfig::from_settings(&settings).unwrap(),
+            ..Default::default()
+        };
+
+        let ages: Arc<Mutex<HashMap<String, u64>>> = Arc::default();
+        let ages_clone = ages.clone();
+        let prune_fn = move |age_days: u64, level: Option<String>, _dry_run: bool| {
+            ages_clone.lock().unwrap().insert(level.unwrap(), age_days);
+            async { Ok(Bm25PruneStats::new()) }
+        };
+        let job = Bm25PruneJob::with_prune_fn(config, prune_fn);
+        job.run(CancellationToken::new()).await.unwrap();
+
+        let ages = ages.lock().unwrap();
+        assert_eq!(ages.get("segment"), Some(&30));
+        assert_eq!(ages.get("grip"), Some(&7));
+        assert_eq!(ages.get("day"), Some(&180));
+        assert_eq!(ages.get("week"), Some(&365));
+        assert!(!ages.contains_key("year"));
+    }
+
     #[test]
     fn test_default_config() {
         let config = Bm25PruneJobConfig::default();
@@ -15,7 +15,9 @@ use tracing::info;
 
 use memory_storage::Storage;
 
-use crate::{JitterConfig, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig};
+use crate::{
+    JitterConfig, JobPriority, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig,
+};
 
 /// Configuration for the compaction job.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,7 +49,8 @@ impl Default for CompactionJobConfig {
 /// Register compaction job with the scheduler.
 ///
 /// Creates a job that triggers RocksDB compaction on all column families.
-/// Uses OverlapPolicy::Skip to prevent concurrent compaction runs.
+/// Uses OverlapPolicy::Skip to prevent concurrent compaction runs, and low
+/// priority so it yields to other jobs waiting for a concurrency slot.
 ///
 /// # Arguments
 ///
@@ -64,20 +67,28 @@ pub async fn create_compaction_job(
     config: CompactionJobConfig,
 ) -> Result<(), SchedulerError> {
     scheduler
-        .register_job(
+        .register_job_with_priority(
             "rocksdb_compaction",
             &config.cron,
             Some(&config.timezone),
             OverlapPolicy::Skip,
             JitterConfig::new(config.jitter_secs),
             TimeoutConfig::new(config.timeout_secs),
+            JobPriority::Low,
             move || {
                 let storage = storage.clone();
                 async move {
                     info!("Starting manual compaction");
                     storage
                         .compact()
-                        .map(|_| info!("Compaction complete"))
+                        .map(|report| {
+                            info!(
+                                bytes_before = report.bytes_before,
+                                bytes_after = report.bytes_after,
+                                reclaimed = report.reclaimed,
+                                "Compaction complete"
+                            )
+                        })
                         .map_err(|e| e.to_string())
                 }
             },
@@ -25,7 +25,9 @@ use tracing::{debug, info, warn};
 
 use memory_indexing::{IndexingPipeline, PipelineConfig};
 
-use crate::{JitterConfig, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig};
+use crate::{
+    JitterConfig, JobPriority, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig,
+};
 
 /// Configuration for the indexing job.
 ///
@@ -65,6 +67,13 @@ pub struct IndexingJobConfig {
     /// deleted to reclaim storage space.
     pub cleanup_after_processing: bool,
 
+    /// Processed entries kept behind the slowest index during cleanup (default: 1)
+    ///
+    /// Cleanup never deletes past the slowest index's checkpoint; the margin
+    /// keeps a few extra entries as a buffer for replay.
+    #[serde(default = "default_cleanup_safety_margin")]
+    pub cleanup_safety_margin: u64,
+
     /// Whether to continue processing on individual entry errors (default: true)
     ///
     /// When enabled, errors on individual entries are logged but don't
@@ -84,6 +93,10 @@ pub struct IndexingJobConfig {
     pub timeout_secs: u64,
 }
 
+fn default_cleanup_safety_margin() -> u64 {
+    1
+}
+
 impl Default for IndexingJobConfig {
     fn default() -> Self {
         Self {
@@ -93,6 +106,7 @@ impl Default for IndexingJobConfig {
             batch_size: 100,
             max_iterations: 10,
             cleanup_after_processing: true,
+            cleanup_safety_margin: default_cleanup_safety_margin(),
             continue_on_error: true,
             commit_after_batch: true,
             timeout_secs: 300, // 5 minutes
@@ -143,6 +157,7 @@ impl IndexingJobConfig {
             .with_batch_size(self.batch_size)
             .with_continue_on_error(self.continue_on_error)
             .with_commit_after_batch(self.commit_after_batch)
+            .with_cleanup_safety_margin(self.cleanup_safety_margin)
     }
 }
 
@@ -184,13 +199,15 @@ pub async fn create_indexing_job(
     let cleanup_after = config.cleanup_after_processing;
 
     scheduler
-        .register_job(
+        .register_job_with_priority(
             "outbox_indexing",
             &config.cron,
             Some(&config.timezone),
             OverlapPolicy::Skip,
             JitterConfig::new(config.jitter_secs),
             TimeoutConfig::new(config.timeout_secs),
+            // Keep search fresh ahead of maintenance when jobs queue for slots
+            JobPriority::High,
             move || {
                 let pipeline = pipeline.clone();
                 async move { run_indexing_job(pipeline, max_iterations, cleanup_after).await }
@@ -311,6 +328,7 @@ mod tests {
         assert_eq!(pipeline_config.batch_size, 50);
         assert!(pipeline_config.continue_on_error);
         assert!(pipeline_config.commit_after_batch);
+        assert_eq!(pipeline_config.cleanup_safety_margin, 1);
     }
 
     #[test]
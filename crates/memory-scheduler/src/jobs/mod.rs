@@ -7,6 +7,7 @@
 //!
 //! - **rollup**: TOC rollup jobs for day/week/month aggregation
 //! - **compaction**: RocksDB compaction for storage optimization
+//! - **toc_prune**: Old TOC node version pruning
 //! - **search**: Search index commit job for making documents searchable
 //! - **indexing**: Outbox indexing job for processing new entries into indexes
 //! - **vector_prune**: Vector index lifecycle pruning (FR-08)
@@ -14,6 +15,7 @@
 
 pub mod compaction;
 pub mod rollup;
+pub mod toc_prune;
 
 #[cfg(feature = "jobs")]
 pub mod bm25_prune;
@@ -28,6 +30,7 @@ pub mod vector_prune;
 
 pub use compaction::{create_compaction_job, CompactionJobConfig};
 pub use rollup::{create_rollup_jobs, RollupJobConfig};
+pub use toc_prune::{create_toc_version_prune_job, TocVersionPruneJobConfig};
 
 #[cfg(feature = "jobs")]
 pub use bm25_prune::{create_bm25_prune_job, Bm25PruneJob, Bm25PruneJobConfig};
@@ -20,8 +20,8 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use memory_storage::Storage;
-use memory_toc::rollup::RollupJob;
-use memory_toc::summarizer::Summarizer;
+use memory_toc::rollup::{RollupGrouping, RollupJob};
+use memory_toc::summarizer::GenericSummarizer;
 use memory_types::TocLevel;
 
 use crate::{JitterConfig, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig};
@@ -48,6 +48,10 @@ pub struct RollupJobConfig {
 
     /// Timeout in seconds for each rollup job (default: 1800 = 30 minutes)
     pub timeout_secs: u64,
+
+    /// How child nodes are grouped into parents (default: calendar)
+    #[serde(default)]
+    pub grouping: RollupGrouping,
 }
 
 impl Default for RollupJobConfig {
@@ -59,6 +63,7 @@ impl Default for RollupJobConfig {
             timezone: "UTC".to_string(),
             jitter_secs: 300,
             timeout_secs: 1800, // 30 minutes
+            grouping: RollupGrouping::Calendar,
         }
     }
 }
@@ -75,19 +80,21 @@ impl Default for RollupJobConfig {
 ///
 /// * `scheduler` - The scheduler service to register jobs with
 /// * `storage` - Storage instance for TOC operations
-/// * `summarizer` - Summarizer for generating rollup summaries
+/// * `summarizer` - Summarizer for generating rollup summaries; pass the
+///   concrete type when known to skip the boxed futures of `dyn Summarizer`
 /// * `config` - Configuration for job schedules
 ///
 /// # Errors
 ///
 /// Returns error if any job registration fails (invalid cron, invalid timezone).
-pub async fn create_rollup_jobs(
+pub async fn create_rollup_jobs<S: GenericSummarizer + ?Sized + 'static>(
     scheduler: &SchedulerService,
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
     config: RollupJobConfig,
 ) -> Result<(), SchedulerError> {
     let timeout = TimeoutConfig::new(config.timeout_secs);
+    let grouping = config.grouping;
 
     // Day rollup job
     let storage_day = storage.clone();
@@ -103,7 +110,7 @@ pub async fn create_rollup_jobs(
             move || {
                 let storage = storage_day.clone();
                 let summarizer = summarizer_day.clone();
-                async move { run_day_rollup(storage, summarizer).await }
+                async move { run_day_rollup(storage, summarizer, grouping).await }
             },
         )
         .await?;
@@ -122,7 +129,7 @@ pub async fn create_rollup_jobs(
             move || {
                 let storage = storage_week.clone();
                 let summarizer = summarizer_week.clone();
-                async move { run_week_rollup(storage, summarizer).await }
+                async move { run_week_rollup(storage, summarizer, grouping).await }
             },
         )
         .await?;
@@ -141,7 +148,7 @@ pub async fn create_rollup_jobs(
             move || {
                 let storage = storage_month.clone();
                 let summarizer = summarizer_month.clone();
-                async move { run_month_rollup(storage, summarizer).await }
+                async move { run_month_rollup(storage, summarizer, grouping).await }
             },
         )
         .await?;
@@ -154,11 +161,13 @@ pub async fn create_rollup_jobs(
 ///
 /// Aggregates segment nodes into day nodes. Uses 1 hour min_age
 /// to avoid rolling up incomplete hours.
-async fn run_day_rollup(
+async fn run_day_rollup<S: GenericSummarizer + ?Sized>(
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
+    grouping: RollupGrouping,
 ) -> Result<(), String> {
-    let job = RollupJob::new(storage, summarizer, TocLevel::Day, Duration::hours(1));
+    let job = RollupJob::new(storage, summarizer, TocLevel::Day, Duration::hours(1))
+        .with_grouping(grouping);
     job.run()
         .await
         .map(|count| info!(count, "Day rollup complete"))
@@ -169,11 +178,13 @@ async fn run_day_rollup(
 ///
 /// Aggregates day nodes into week nodes. Uses 24 hour min_age
 /// to avoid rolling up incomplete days.
-async fn run_week_rollup(
+async fn run_week_rollup<S: GenericSummarizer + ?Sized>(
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
+    grouping: RollupGrouping,
 ) -> Result<(), String> {
-    let job = RollupJob::new(storage, summarizer, TocLevel::Week, Duration::hours(24));
+    let job = RollupJob::new(storage, summarizer, TocLevel::Week, Duration::hours(24))
+        .with_grouping(grouping);
     job.run()
         .await
         .map(|count| info!(count, "Week rollup complete"))
@@ -184,11 +195,13 @@ async fn run_week_rollup(
 ///
 /// Aggregates week nodes into month nodes. Uses 24 hour min_age
 /// to avoid rolling up incomplete weeks.
-async fn run_month_rollup(
+async fn run_month_rollup<S: GenericSummarizer + ?Sized>(
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
+    grouping: RollupGrouping,
 ) -> Result<(), String> {
-    let job = RollupJob::new(storage, summarizer, TocLevel::Month, Duration::hours(24));
+    let job = RollupJob::new(storage, summarizer, TocLevel::Month, Duration::hours(24))
+        .with_grouping(grouping);
     job.run()
         .await
         .map(|count| info!(count, "Month rollup complete"))
@@ -209,6 +222,7 @@ mod tests {
         assert_eq!(config.timezone, "UTC");
         assert_eq!(config.jitter_secs, 300);
         assert_eq!(config.timeout_secs, 1800);
+        assert_eq!(config.grouping, RollupGrouping::Calendar);
     }
 
     #[test]
@@ -221,4 +235,23 @@ mod tests {
         assert_eq!(config.week_cron, decoded.week_cron);
         assert_eq!(config.month_cron, decoded.month_cron);
     }
+
+    #[test]
+    fn test_rollup_config_rolling_grouping() {
+        let json = r#"{"day_cron":"0 0 1 * * *","week_cron":"0 0 2 * * 0",
+            "month_cron":"0 0 3 1 * *","timezone":"UTC","jitter_secs":300,
+            "timeout_secs":1800,"grouping":{"rolling":{"window_ms":86400000}}}"#;
+        let config: RollupJobConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.grouping,
+            RollupGrouping::Rolling {
+                window_ms: 86_400_000
+            }
+        );
+
+        // Configs written before grouping existed stay on calendar buckets
+        let legacy = json.replace(r#","grouping":{"rolling":{"window_ms":86400000}}"#, "");
+        let config: RollupJobConfig = serde_json::from_str(&legacy).unwrap();
+        assert_eq!(config.grouping, RollupGrouping::Calendar);
+    }
 }
@@ -0,0 +1,118 @@
+//! TOC node version pruning job.
+//!
+//! `put_toc_node` appends a new version on every write, so rebuilds and
+//! rollups grow the TOC column family without bound. This job keeps the
+//! newest versions of each node and deletes the rest.
+//!
+//! By default runs weekly at 3:30 AM Sunday, ahead of compaction so the
+//! deleted space is reclaimed in the same maintenance window. Schedule and
+//! retention come from `[lifecycle.toc_versions]`.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use memory_storage::Storage;
+
+use crate::{JitterConfig, OverlapPolicy, SchedulerError, SchedulerService, TimeoutConfig};
+
+/// Configuration for the TOC version pruning job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TocVersionPruneJobConfig {
+    /// Cron expression (default: "0 30 3 * * 0" = 3:30 AM Sunday)
+    pub cron: String,
+
+    /// Timezone (default: "UTC")
+    pub timezone: String,
+
+    /// Max jitter in seconds (default: 300 = 5 min)
+    pub jitter_secs: u64,
+
+    /// Timeout in seconds (default: 1800 = 30 minutes)
+    pub timeout_secs: u64,
+
+    /// Versions to keep per node, including the latest (default: 5)
+    pub keep_last: usize,
+}
+
+impl Default for TocVersionPruneJobConfig {
+    fn default() -> Self {
+        Self {
+            cron: "0 30 3 * * 0".to_string(),
+            timezone: "UTC".to_string(),
+            jitter_secs: 300,
+            timeout_secs: 1800, // 30 minutes
+            keep_last: 5,
+        }
+    }
+}
+
+/// Register the TOC version pruning job with the scheduler.
+///
+/// Uses OverlapPolicy::Skip so a slow prune is never run twice at once.
+///
+/// # Arguments
+///
+/// * `scheduler` - The scheduler service to register the job with
+/// * `storage` - Storage instance to prune
+/// * `config` - Configuration for job schedule and retention
+///
+/// # Errors
+///
+/// Returns error if job registration fails (invalid cron, invalid timezone).
+pub async fn create_toc_version_prune_job(
+    scheduler: &SchedulerService,
+    storage: Arc<Storage>,
+    config: TocVersionPruneJobConfig,
+) -> Result<(), SchedulerError> {
+    let keep_last = config.keep_last;
+    scheduler
+        .register_job(
+            "toc_version_prune",
+            &config.cron,
+            Some(&config.timezone),
+            OverlapPolicy::Skip,
+            JitterConfig::new(config.jitter_secs),
+            TimeoutConfig::new(config.timeout_secs),
+            move || {
+                let storage = storage.clone();
+                async move {
+                    storage
+                        .prune_toc_versions(keep_last)
+                        .map(|removed| info!(removed, keep_last, "TOC version prune complete"))
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await?;
+
+    info!(keep_last, "Registered TOC version prune job");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toc_version_prune_config_default() {
+        let config = TocVersionPruneJobConfig::default();
+
+        assert_eq!(config.cron, "0 30 3 * * 0");
+        assert_eq!(config.timezone, "UTC");
+        assert_eq!(config.jitter_secs, 300);
+        assert_eq!(config.timeout_secs, 1800);
+        assert_eq!(config.keep_last, 5);
+    }
+
+    #[test]
+    fn test_toc_version_prune_config_serialization() {
+        let config = TocVersionPruneJobConfig::default();
+        let json = serde_json::to_string(&config).unwrap();
+        let decoded: TocVersionPruneJobConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(config.cron, decoded.cron);
+        assert_eq!(config.keep_last, decoded.keep_last);
+    }
+}
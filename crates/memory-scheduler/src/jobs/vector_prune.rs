@@ -14,9 +14,10 @@ use tokio_util::sync::CancellationToken;
 use tracing;
 
 /// Prune function type for vector pruning.
-/// Takes (age_days, level_filter) and returns count of pruned vectors.
+/// Takes (age_days, level_filter, dry_run) and returns count of pruned vectors
+/// (or, for a dry run, the count that would be pruned).
 pub type VectorPruneFn = Arc<
-    dyn Fn(u64, Option<String>) -> Pin<Box<dyn Future<Output = Result<usize, String>> + Send>>
+    dyn Fn(u64, Option<String>, bool) -> Pin<Box<dyn Future<Output = Result<usize, String>> + Send>>
         + Send
         + Sync,
 >;
@@ -33,10 +34,10 @@ pub struct VectorPruneJobConfig {
     pub cron_schedule: String,
     /// Lifecycle config.
     pub lifecycle: VectorLifecycleConfig,
-    /// Whether to run dry-run first.
+    /// Whether to log a dry-run estimate before pruning.
     pub dry_run_first: bool,
     /// Optional prune callback with level filter support.
-    /// The callback receives (age_days, level_filter) and returns count of pruned vectors.
+    /// The callback receives (age_days, level_filter, dry_run) and returns count of pruned vectors.
     pub prune_fn: Option<VectorPruneFn>,
 }
 
@@ -74,24 +75,47 @@ impl VectorPruneJob {
 
     /// Create a job with a prune callback that supports per-level filtering.
     ///
-    /// The callback should call `VectorIndexPipeline::prune_level(age_days, level)` and return
-    /// the count of pruned vectors.
+    /// The callback should call `VectorIndexPipeline::prune_level(age_days, level, dry_run)`
+    /// and return the count of pruned vectors.
     pub fn with_prune_fn<F, Fut>(mut config: VectorPruneJobConfig, prune_fn: F) -> Self
     where
-        F: Fn(u64, Option<String>) -> Fut + Send + Sync + 'static,
+        F: Fn(u64, Option<String>, bool) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<usize, String>> + Send + 'static,
     {
-        config.prune_fn = Some(Arc::new(move |age_days, level| {
-            Box::pin(prune_fn(age_days, level))
+        config.prune_fn = Some(Arc::new(move |age_days, level, dry_run| {
+            Box::pin(prune_fn(age_days, level, dry_run))
         }));
         Self { config }
     }
 
     /// Execute the prune job.
     ///
-    /// Prunes vectors per level according to retention config.
-    /// Uses the shortest retention period to prune all vectors older than that age.
+    /// Prunes vectors per level according to retention config. With
+    /// `dry_run_first`, the dry-run estimate is logged before pruning.
     pub async fn run(&self, cancel: CancellationToken) -> Result<PruneStats, String> {
+        if self.config.dry_run_first {
+            let estimate = self.dry_run(cancel.clone()).await?;
+            tracing::info!(
+                would_remove = estimate.total_would_remove(),
+                by_level = ?estimate.would_remove,
+                "Vector prune dry-run estimate"
+            );
+        }
+        self.prune_levels(cancel, false).await
+    }
+
+    /// Count the vectors each level would lose without removing any.
+    ///
+    /// The counts are returned in [`PruneStats::would_remove`].
+    pub async fn dry_run(&self, cancel: CancellationToken) -> Result<PruneStats, String> {
+        self.prune_levels(cancel, true).await
+    }
+
+    async fn prune_levels(
+        &self,
+        cancel: CancellationToken,
+        dry_run: bool,
+    ) -> Result<PruneStats, String> {
         if cancel.is_cancelled() {
             return Ok(PruneStats::new());
         }
@@ -101,7 +125,7 @@ impl VectorPruneJob {
             return Ok(PruneStats::new());
         }
 
-        tracing::info!("Starting vector prune job");
+        tracing::info!(dry_run, "Starting vector prune job");
 
         let mut total_stats = PruneStats::new();
 
@@ -128,7 +152,11 @@ impl VectorPruneJob {
 
             // Call prune callback if available
             if let Some(ref prune_fn) = self.config.prune_fn {
-                match prune_fn(retention_days as u64, Some(level.to_string())).await {
+                match prune_fn(retention_days as u64, Some(level.to_string()), dry_run).await {
+                    Ok(count) if dry_run => {
+                        total_stats.add_would_remove(level, count as u32);
+                        tracing::info!(level, count, "Dry run: would prune vectors for level");
+                    }
                     Ok(count) => {
                         total_stats.add(level, count as u32);
                         tracing::info!(level, count, "Pruned vectors for level");
@@ -151,6 +179,7 @@ impl VectorPruneJob {
 
         tracing::info!(
             total_pruned = total_stats.total(),
+            would_remove = total_stats.total_would_remove(),
             errors = total_stats.errors.len(),
             "Vector prune job completed"
         );
@@ -205,10 +234,10 @@ pub fn create_vector_prune_job(config: VectorPruneJobConfig) -> VectorPruneJob {
 /// let pipeline = Arc::new(VectorIndexPipeline::new(...));
 /// let job = VectorPruneJob::with_prune_fn(
 ///     VectorPruneJobConfig::default(),
-///     move |age_days, level| {
+///     move |age_days, level, dry_run| {
 ///         let p = Arc::clone(&pipeline);
 ///         async move {
-///             p.prune_level(age_days, level.as_deref())
+///             p.prune_level(age_days, level.as_deref(), dry_run)
 ///                 .map_err(|e| e.to_string())
 ///         }
 ///     },
@@ -319,7 +348,7 @@ mod tests {
         let call_count = Arc::new(AtomicU32::new(0));
         let call_count_clone = call_count.clone();
 
-        let prune_fn = move |_age_days: u64, _level: Option<String>| {
+        let prune_fn = move |_age_days: u64, _level: Option<String>, _dry_run: bool| {
             let count = call_count_clone.clone();
             async move {
                 count.fetch_add(1, Ordering::SeqCst);
@@ -345,8 +374,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_job_handles_prune_error() {
-        let prune_fn =
-            |_age_days: u64, _level: Option<String>| async { Err("test error".to_string()) };
+        let prune_fn = |_age_days: u64, _level: Option<String>, _dry_run: bool| async {
+            Err("test error".to_string())
+        };
 
         let config = VectorPruneJobConfig::default();
         let job = VectorPruneJob::with_prune_fn(config, prune_fn);
@@ -359,6 +389,88 @@ mod tests {
         assert!(!stats.errors.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_dry_run_counts_match_real_run() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        // Vectors eligible for pruning, per level
+        let eligible: Arc<Mutex<HashMap<String, usize>>> = Arc::new(Mutex::new(
+            [("segment", 3), ("grip", 2), ("day", 1), ("week", 0)]
+                .into_iter()
+                .map(|(level, count)| (level.to_string(), count))
+                .collect(),
+        ));
+        let eligible_clone = eligible.clone();
+        let prune_fn = move |_age_days: u64, level: Option<String>, dry_run: bool| {
+            let eligible = eligible_clone.clone();
+            async move {
+                let mut eligible = eligible.lock().unwrap();
+                let count = eligible.get_mut(&level.unwrap()).unwrap();
+                let pruned = *count;
+                if !dry_run {
+                    *count = 0;
+                }
+                Ok(pruned)
+            }
+        };
+        let job = VectorPruneJob::with_prune_fn(VectorPruneJobConfig::default(), prune_fn);
+
+        let estimate = job.dry_run(CancellationToken::new()).await.unwrap();
+        assert_eq!(estimate.total(), 0);
+        assert_eq!(estimate.total_would_remove(), 6);
+        assert_eq!(eligible.lock().unwrap().values().sum::<usize>(), 6);
+
+        let stats = job.run(CancellationToken::new()).await.unwrap();
+        assert!(stats.would_remove.is_empty());
+        assert_eq!(stats.total(), estimate.total_would_remove());
+        let pruned_by_level = [
+            ("segment", stats.segments_pruned),
+            ("grip", stats.grips_pruned),
+            ("day", stats.days_pruned),
+            ("week", stats.weeks_pruned),
+        ];
+        for (level, pruned) in pruned_by_level {
+            assert_eq!(
+                estimate.would_remove.get(level).copied().unwrap_or(0),
+                pruned,
+                "{level}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_job_uses_settings_retention() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        let mut settings = memory_types::VectorLifecycleSettings {
+            segment_retention_days: 14,
+            ..Default::default()
+        };
+        settings.retention.insert("day".to_string(), 90);
+        let config = VectorPruneJobConfig {
+            lifecycle: VectorLifecycleConfig::from_settings(&settings).unwrap(),
+            ..Default::default()
+        };
+
+        let ages: Arc<Mutex<HashMap<String, u64>>> = Arc::default();
+        let ages_clone = ages.clone();
+        let prune_fn = move |age_days: u64, level: Option<String>, _dry_run: bool| {
+            ages_clone.lock().unwrap().insert(level.unwrap(), age_days);
+            async { Ok(0usize) }
+        };
+        let job = VectorPruneJob::with_prune_fn(config, prune_fn);
+        job.run(CancellationToken::new()).await.unwrap();
+
+        let ages = ages.lock().unwrap();
+        assert_eq!(ages.get("segment"), Some(&14));
+        assert_eq!(ages.get("grip"), Some(&30));
+        assert_eq!(ages.get("day"), Some(&90));
+        assert_eq!(ages.get("week"), Some(&1825));
+        assert!(!ages.contains_key("month"));
+    }
+
     #[test]
     fn test_default_config() {
         let config = VectorPruneJobConfig::default();
@@ -12,11 +12,12 @@
 //! - **SCHED-05**: Job status observability via JobRegistry
 //! - **SCHED-06**: Overlap policy (skip/concurrent) for job execution
 //! - **SCHED-07**: Jitter support for distributed scheduling
+//! - **SCHED-08**: Job priority under a global concurrency limit
 //!
 //! # Example
 //!
 //! ```ignore
-//! use memory_scheduler::{SchedulerService, SchedulerConfig, OverlapPolicy, JitterConfig};
+//! use memory_scheduler::{JitterConfig, OverlapPolicy, SchedulerConfig, SchedulerService, TimeoutConfig};
 //!
 //! let config = SchedulerConfig::default();
 //! let scheduler = SchedulerService::new(config).await?;
@@ -28,6 +29,7 @@
 //!     None, // Use default timezone
 //!     OverlapPolicy::Skip,
 //!     JitterConfig::new(30), // Up to 30 seconds jitter
+//!     TimeoutConfig::none(),
 //!     || async { do_rollup().await },
 //! ).await?;
 //!
@@ -42,6 +44,7 @@ mod config;
 mod error;
 mod jitter;
 mod overlap;
+mod priority;
 mod registry;
 mod scheduler;
 
@@ -52,6 +55,7 @@ pub use config::SchedulerConfig;
 pub use error::SchedulerError;
 pub use jitter::{with_jitter, JitterConfig, TimeoutConfig};
 pub use overlap::{OverlapGuard, OverlapPolicy, RunGuard};
+pub use priority::JobPriority;
 pub use registry::{JobOutput, JobRegistry, JobResult, JobStatus};
 pub use scheduler::{validate_cron_expression, SchedulerService};
 
@@ -72,6 +76,8 @@ pub use jobs::rollup::{create_rollup_jobs, RollupJobConfig};
 #[cfg(feature = "jobs")]
 pub use jobs::search::{create_index_commit_job, IndexCommitJobConfig};
 #[cfg(feature = "jobs")]
+pub use jobs::toc_prune::{create_toc_version_prune_job, TocVersionPruneJobConfig};
+#[cfg(feature = "jobs")]
 pub use jobs::vector_prune::{
     create_vector_prune_job, register_vector_prune_job, VectorPruneJob, VectorPruneJobConfig,
 };
@@ -2,12 +2,13 @@
 //!
 //! When a job is scheduled to run but a previous instance is still running,
 //! the overlap policy determines whether to skip the new execution or allow
-//! concurrent runs.
+//! concurrent runs, optionally up to a limit.
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// Policy for handling overlapping job executions.
 ///
@@ -27,24 +28,49 @@ pub enum OverlapPolicy {
     /// Multiple instances of the same job can run simultaneously. Use with
     /// caution as this can lead to resource contention and race conditions.
     Concurrent,
+
+    /// Allow up to N concurrent executions, skipping any beyond that.
+    ///
+    /// Runs over the limit are recorded as skipped with reason
+    /// `"overlap_limit"`. A limit of 0 skips every run.
+    ConcurrentLimit(usize),
+}
+
+impl OverlapPolicy {
+    /// Reason recorded in the registry when this policy skips a run.
+    pub fn skip_reason(&self) -> &'static str {
+        match self {
+            OverlapPolicy::ConcurrentLimit(_) => "overlap_limit",
+            _ => "overlap",
+        }
+    }
 }
 
 /// Guard for tracking whether a job is currently running.
 ///
 /// The `OverlapGuard` uses an `AtomicBool` to track running state and provides
 /// lock-free acquisition of a `RunGuard` that automatically releases the lock
-/// when dropped.
+/// when dropped. `ConcurrentLimit` counts runs with a semaphore instead.
 pub struct OverlapGuard {
     is_running: Arc<AtomicBool>,
     policy: OverlapPolicy,
+    /// Run slots for `ConcurrentLimit`; `None` for other policies
+    slots: Option<Arc<Semaphore>>,
 }
 
 impl OverlapGuard {
     /// Create a new overlap guard with the given policy.
     pub fn new(policy: OverlapPolicy) -> Self {
+        let slots = match policy {
+            OverlapPolicy::ConcurrentLimit(limit) => {
+                Some(Arc::new(Semaphore::new(limit.min(Semaphore::MAX_PERMITS))))
+            }
+            _ => None,
+        };
         Self {
             is_running: Arc::new(AtomicBool::new(false)),
             policy,
+            slots,
         }
     }
 
@@ -59,6 +85,8 @@ impl OverlapGuard {
     ///   returns a `RunGuard` and marks the job as running.
     /// - `Concurrent`: Always returns a `RunGuard`, allowing multiple
     ///   concurrent executions.
+    /// - `ConcurrentLimit(n)`: Returns `None` if `n` runs are already
+    ///   active, otherwise returns a `RunGuard` holding one of the `n` slots.
     pub fn try_acquire(&self) -> Option<RunGuard> {
         match self.policy {
             OverlapPolicy::Skip => {
@@ -70,6 +98,7 @@ impl OverlapGuard {
                 {
                     Some(RunGuard {
                         flag: self.is_running.clone(),
+                        _permit: None,
                     })
                 } else {
                     // Already running, skip this execution
@@ -80,6 +109,14 @@ impl OverlapGuard {
                 // Always allow concurrent execution, use a dummy flag
                 Some(RunGuard {
                     flag: Arc::new(AtomicBool::new(true)),
+                    _permit: None,
+                })
+            }
+            OverlapPolicy::ConcurrentLimit(_) => {
+                let permit = self.slots.clone()?.try_acquire_owned().ok()?;
+                Some(RunGuard {
+                    flag: Arc::new(AtomicBool::new(true)),
+                    _permit: Some(permit),
                 })
             }
         }
@@ -89,8 +126,14 @@ impl OverlapGuard {
     ///
     /// For `Concurrent` policy, this only reflects the state of the shared
     /// flag, which may not accurately represent all running instances.
+    /// For `ConcurrentLimit`, this is true while any slot is held.
     pub fn is_running(&self) -> bool {
-        self.is_running.load(Ordering::SeqCst)
+        match (&self.slots, self.policy) {
+            (Some(slots), OverlapPolicy::ConcurrentLimit(limit)) => {
+                slots.available_permits() < limit.min(Semaphore::MAX_PERMITS)
+            }
+            _ => self.is_running.load(Ordering::SeqCst),
+        }
     }
 
     /// Get the overlap policy.
@@ -102,9 +145,11 @@ impl OverlapGuard {
 /// RAII guard that releases the running flag when dropped.
 ///
 /// This ensures that even if a job panics, the running flag is properly
-/// cleared to allow subsequent executions.
+/// cleared to allow subsequent executions. Under `ConcurrentLimit` the
+/// held semaphore slot is returned on drop as well.
 pub struct RunGuard {
     flag: Arc<AtomicBool>,
+    _permit: Option<OwnedSemaphorePermit>,
 }
 
 impl Drop for RunGuard {
@@ -191,6 +236,72 @@ mod tests {
         assert!(!guard.is_running());
     }
 
+    #[test]
+    fn test_overlap_concurrent_limit_skips_beyond_limit() {
+        let guard = OverlapGuard::new(OverlapPolicy::ConcurrentLimit(2));
+
+        // First two runs proceed
+        let run1 = guard.try_acquire();
+        let run2 = guard.try_acquire();
+        assert!(run1.is_some());
+        assert!(run2.is_some());
+        assert!(guard.is_running());
+
+        // Third is skipped while both are held
+        assert!(guard.try_acquire().is_none());
+        assert_eq!(guard.policy().skip_reason(), "overlap_limit");
+
+        // Finishing one run frees a slot
+        drop(run1);
+        let run3 = guard.try_acquire();
+        assert!(run3.is_some());
+        assert!(guard.try_acquire().is_none());
+
+        drop(run2);
+        drop(run3);
+        assert!(!guard.is_running());
+    }
+
+    #[test]
+    fn test_overlap_concurrent_limit_thread_safety() {
+        let guard = Arc::new(OverlapGuard::new(OverlapPolicy::ConcurrentLimit(2)));
+        let active = Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let max_active = Arc::new(std::sync::atomic::AtomicU32::new(0));
+
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let guard = guard.clone();
+                let active = active.clone();
+                let max_active = max_active.clone();
+                thread::spawn(move || {
+                    if let Some(_run_guard) = guard.try_acquire() {
+                        let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_active.fetch_max(now, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(10));
+                        active.fetch_sub(1, Ordering::SeqCst);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(max_active.load(Ordering::SeqCst) <= 2);
+        assert!(!guard.is_running());
+    }
+
+    #[test]
+    fn test_skip_reason() {
+        assert_eq!(OverlapPolicy::Skip.skip_reason(), "overlap");
+        assert_eq!(OverlapPolicy::Concurrent.skip_reason(), "overlap");
+        assert_eq!(
+            OverlapPolicy::ConcurrentLimit(3).skip_reason(),
+            "overlap_limit"
+        );
+    }
+
     #[test]
     fn test_run_guard_releases_on_drop() {
         let guard = OverlapGuard::new(OverlapPolicy::Skip);
@@ -229,5 +340,10 @@ mod tests {
 
         assert_eq!(skip, skip_back);
         assert_eq!(concurrent, concurrent_back);
+
+        let limited = OverlapPolicy::ConcurrentLimit(2);
+        let limited_json = serde_json::to_string(&limited).unwrap();
+        let limited_back: OverlapPolicy = serde_json::from_str(&limited_json).unwrap();
+        assert_eq!(limited, limited_back);
     }
 }
@@ -0,0 +1,272 @@
+//! Job priority and the global concurrency gate.
+//!
+//! When `max_concurrent_jobs` is set, every run must take a slot from a
+//! shared [`PriorityGate`] before executing. Runs that arrive together, as
+//! when several missed jobs fire at once after the daemon restarts, are
+//! admitted highest priority first rather than in whatever order their
+//! tasks happen to be polled.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+/// How long a new arrival waits for other runs due at the same moment
+/// before the gate admits anyone.
+const SETTLE_WINDOW: Duration = Duration::from_millis(100);
+
+/// Priority of a job when competing for global concurrency slots.
+///
+/// Only matters when `max_concurrent_jobs` is set; without a limit every
+/// job runs as soon as it fires.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
+pub enum JobPriority {
+    /// Maintenance that can wait (e.g., compaction)
+    Low,
+    /// Default for jobs that don't set a priority
+    #[default]
+    Normal,
+    /// Jobs that keep search and TOC data fresh (e.g., indexing)
+    High,
+}
+
+/// A run waiting for a slot.
+struct Waiter {
+    priority: JobPriority,
+    /// Arrival order, so equal priorities are admitted first come first served
+    seq: u64,
+    tx: oneshot::Sender<GatePermit>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    /// Max-heap order: higher priority first, then earlier arrival.
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+#[derive(Default)]
+struct GateState {
+    running: usize,
+    next_seq: u64,
+    waiters: BinaryHeap<Waiter>,
+}
+
+struct GateInner {
+    limit: usize,
+    state: Mutex<GateState>,
+}
+
+impl GateInner {
+    /// Hand free slots to the best waiters.
+    fn dispatch(self: &Arc<Self>) {
+        loop {
+            let waiter = {
+                let mut state = self.state.lock().unwrap();
+                if state.running >= self.limit {
+                    return;
+                }
+                match state.waiters.pop() {
+                    Some(waiter) => {
+                        state.running += 1;
+                        waiter
+                    }
+                    None => return,
+                }
+            };
+            // A waiter that gave up returns the permit, which frees the slot
+            // again; dropped outside the lock
+            let _ = waiter.tx.send(GatePermit {
+                inner: self.clone(),
+            });
+        }
+    }
+}
+
+/// Global limit on concurrently running jobs, admitted by priority.
+#[derive(Clone)]
+pub struct PriorityGate {
+    inner: Arc<GateInner>,
+}
+
+impl PriorityGate {
+    /// Create a gate allowing up to `limit` runs at once.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Arc::new(GateInner {
+                limit,
+                state: Mutex::new(GateState::default()),
+            }),
+        }
+    }
+
+    /// Wait for a slot.
+    ///
+    /// Waits at least [`SETTLE_WINDOW`] so runs that fire together are all
+    /// queued before any of them is admitted.
+    pub async fn acquire(&self, priority: JobPriority) -> GatePermit {
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut state = self.inner.state.lock().unwrap();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiters.push(Waiter { priority, seq, tx });
+        }
+
+        tokio::time::sleep(SETTLE_WINDOW).await;
+        self.inner.dispatch();
+
+        rx.await
+            .expect("gate holds the sender until a permit is sent")
+    }
+
+    /// Number of runs currently holding a slot.
+    #[cfg(test)]
+    fn running(&self) -> usize {
+        self.inner.state.lock().unwrap().running
+    }
+}
+
+/// A held slot in a [`PriorityGate`], released on drop.
+pub struct GatePermit {
+    inner: Arc<GateInner>,
+}
+
+impl Drop for GatePermit {
+    fn drop(&mut self) {
+        self.inner.state.lock().unwrap().running -= 1;
+        self.inner.dispatch();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_ordering() {
+        assert!(JobPriority::High > JobPriority::Normal);
+        assert!(JobPriority::Normal > JobPriority::Low);
+        assert_eq!(JobPriority::default(), JobPriority::Normal);
+    }
+
+    #[tokio::test]
+    async fn test_gate_admits_higher_priority_first() {
+        let gate = PriorityGate::new(1);
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let holder = gate.acquire(JobPriority::Normal).await;
+        let spawn = |name: &'static str, priority| {
+            let gate = gate.clone();
+            let order = order.clone();
+            tokio::spawn(async move {
+                let _permit = gate.acquire(priority).await;
+                order.lock().unwrap().push(name);
+            })
+        };
+        let low = spawn("low", JobPriority::Low);
+        let normal = spawn("normal", JobPriority::Normal);
+        let high = spawn("high", JobPriority::High);
+
+        // Let all three queue up behind the held slot
+        tokio::time::sleep(SETTLE_WINDOW * 2).await;
+        assert!(order.lock().unwrap().is_empty());
+        drop(holder);
+
+        for task in [low, normal, high] {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), vec!["high", "normal", "low"]);
+        assert_eq!(gate.running(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_gate_equal_priority_is_fifo() {
+        let gate = PriorityGate::new(1);
+        let holder = gate.acquire(JobPriority::Normal).await;
+
+        let mut tasks = Vec::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for i in 0..3 {
+            let gate = gate.clone();
+            let order = order.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = gate.acquire(JobPriority::Normal).await;
+                order.lock().unwrap().push(i);
+            }));
+            // Distinct arrival order
+            tokio::task::yield_now().await;
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        tokio::time::sleep(SETTLE_WINDOW * 2).await;
+        drop(holder);
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_gate_respects_limit() {
+        let gate = PriorityGate::new(2);
+        let first = gate.acquire(JobPriority::Low).await;
+        let second = gate.acquire(JobPriority::Low).await;
+        assert_eq!(gate.running(), 2);
+
+        let waiting = {
+            let gate = gate.clone();
+            tokio::spawn(async move { gate.acquire(JobPriority::High).await })
+        };
+        tokio::time::sleep(SETTLE_WINDOW * 2).await;
+        assert!(!waiting.is_finished());
+
+        drop(first);
+        let third = waiting.await.unwrap();
+        assert_eq!(gate.running(), 2);
+
+        drop(second);
+        drop(third);
+        assert_eq!(gate.running(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_gate_cancelled_waiter_frees_slot() {
+        let gate = PriorityGate::new(1);
+        let holder = gate.acquire(JobPriority::Normal).await;
+
+        let cancelled = {
+            let gate = gate.clone();
+            tokio::spawn(async move { gate.acquire(JobPriority::High).await })
+        };
+        tokio::time::sleep(SETTLE_WINDOW * 2).await;
+        cancelled.abort();
+        let _ = cancelled.await;
+
+        drop(holder);
+        assert_eq!(gate.running(), 0);
+        let _permit = gate.acquire(JobPriority::Low).await;
+        assert_eq!(gate.running(), 1);
+    }
+}
@@ -9,6 +9,8 @@ use std::sync::RwLock;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::priority::JobPriority;
+
 /// Result of a job execution.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum JobResult {
@@ -76,6 +78,9 @@ pub struct JobStatus {
     pub is_running: bool,
     /// Whether the job is paused
     pub is_paused: bool,
+    /// Priority when competing for global concurrency slots
+    #[serde(default)]
+    pub priority: JobPriority,
     /// Optional metadata from last run (e.g., prune count, items processed)
     /// Maps arbitrary keys to string values for extensibility.
     #[serde(default)]
@@ -96,6 +101,7 @@ impl JobStatus {
             error_count: 0,
             is_running: false,
             is_paused: false,
+            priority: JobPriority::default(),
             last_run_metadata: HashMap::new(),
         }
     }
@@ -174,7 +180,10 @@ impl JobRegistry {
     ) {
         let mut jobs = self.jobs.write().unwrap();
         if let Some(status) = jobs.get_mut(job_name) {
-            status.is_running = false;
+            // A skipped run never started, so another run may still be going
+            if !matches!(result, JobResult::Skipped(_)) {
+                status.is_running = false;
+            }
             status.last_run = Some(Utc::now());
             status.last_duration_ms = Some(duration_ms);
             status.run_count += 1;
@@ -202,6 +211,14 @@ impl JobRegistry {
         }
     }
 
+    /// Set the priority of a job.
+    pub fn set_priority(&self, job_name: &str, priority: JobPriority) {
+        let mut jobs = self.jobs.write().unwrap();
+        if let Some(status) = jobs.get_mut(job_name) {
+            status.priority = priority;
+        }
+    }
+
     /// Get the status of a specific job.
     ///
     /// Returns `None` if the job is not registered.
@@ -243,6 +260,18 @@ impl JobRegistry {
             .unwrap_or(false)
     }
 
+    /// Get the priority of a job.
+    ///
+    /// Returns the default priority if the job is not registered.
+    pub fn priority(&self, job_name: &str) -> JobPriority {
+        self.jobs
+            .read()
+            .unwrap()
+            .get(job_name)
+            .map(|s| s.priority)
+            .unwrap_or_default()
+    }
+
     /// Get the number of registered jobs.
     pub fn job_count(&self) -> usize {
         self.jobs.read().unwrap().len()
@@ -272,6 +301,21 @@ mod tests {
         assert!(!status.is_paused);
     }
 
+    #[test]
+    fn test_registry_priority() {
+        let registry = JobRegistry::new();
+        registry.register("indexing", "0 * * * * *");
+        assert_eq!(registry.priority("indexing"), JobPriority::Normal);
+
+        registry.set_priority("indexing", JobPriority::High);
+        assert_eq!(registry.priority("indexing"), JobPriority::High);
+        assert_eq!(
+            registry.get_status("indexing").unwrap().priority,
+            JobPriority::High
+        );
+        assert_eq!(registry.priority("missing"), JobPriority::Normal);
+    }
+
     #[test]
     fn test_registry_record_start() {
         let registry = JobRegistry::new();
@@ -320,10 +364,12 @@ mod tests {
     fn test_registry_record_complete_skipped() {
         let registry = JobRegistry::new();
         registry.register("test-job", "0 0 * * * *");
+        registry.record_start("test-job");
 
         registry.record_complete("test-job", JobResult::Skipped("overlap".into()), 0);
 
         let status = registry.get_status("test-job").unwrap();
+        assert!(status.is_running); // The run it overlapped is still going
         assert_eq!(status.run_count, 1);
         assert_eq!(status.error_count, 0); // Skipped doesn't count as error
         assert_eq!(
@@ -2,7 +2,8 @@
 //!
 //! Provides lifecycle management for background jobs with
 //! graceful shutdown support, job status tracking, overlap prevention,
-//! and jitter for distributed scheduling.
+//! jitter for distributed scheduling, and priority-ordered admission under
+//! a global concurrency limit.
 
 use std::future::Future;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -15,6 +16,7 @@ use tracing::{debug, info, warn};
 
 use crate::jitter::{JitterConfig, TimeoutConfig};
 use crate::overlap::{OverlapGuard, OverlapPolicy};
+use crate::priority::{JobPriority, PriorityGate};
 use crate::registry::{JobRegistry, JobResult};
 use crate::{SchedulerConfig, SchedulerError};
 
@@ -60,6 +62,8 @@ pub struct SchedulerService {
     shutdown_token: CancellationToken,
     is_running: AtomicBool,
     registry: Arc<JobRegistry>,
+    /// Global concurrency limit; `None` when `max_concurrent_jobs` is 0
+    gate: Option<PriorityGate>,
 }
 
 impl SchedulerService {
@@ -72,6 +76,8 @@ impl SchedulerService {
         let _ = config.parse_timezone()?;
 
         let scheduler = JobScheduler::new().await?;
+        let gate =
+            (config.max_concurrent_jobs > 0).then(|| PriorityGate::new(config.max_concurrent_jobs));
 
         Ok(Self {
             scheduler,
@@ -79,6 +85,7 @@ impl SchedulerService {
             shutdown_token: CancellationToken::new(),
             is_running: AtomicBool::new(false),
             registry: Arc::new(JobRegistry::new()),
+            gate,
         })
     }
 
@@ -110,8 +117,9 @@ impl SchedulerService {
 
     /// Shutdown the scheduler gracefully.
     ///
-    /// Signals all jobs to stop via the cancellation token, waits for
-    /// the configured timeout, then stops the scheduler.
+    /// Signals all jobs to stop via the cancellation token, waits up to
+    /// the configured timeout for running jobs to finish, then stops the
+    /// scheduler.
     ///
     /// # Errors
     ///
@@ -121,16 +129,34 @@ impl SchedulerService {
             return Err(SchedulerError::NotRunning);
         }
 
-        info!("Initiating scheduler shutdown");
+        let timeout = std::time::Duration::from_secs(self.config.shutdown_timeout_secs);
+        info!(
+            timeout_secs = self.config.shutdown_timeout_secs,
+            "Initiating scheduler shutdown"
+        );
 
         // Signal all jobs to stop
         self.shutdown_token.cancel();
 
-        // Give jobs time to finish
-        tokio::time::sleep(std::time::Duration::from_secs(
-            self.config.shutdown_timeout_secs.min(5), // Cap at 5s for tests
-        ))
-        .await;
+        // Give running jobs time to finish
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let running: Vec<String> = self
+                .registry
+                .get_all_status()
+                .into_iter()
+                .filter(|s| s.is_running)
+                .map(|s| s.job_name)
+                .collect();
+            if running.is_empty() {
+                break;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                warn!(jobs = ?running, "Shutdown timeout reached with jobs still running");
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
 
         // Stop the scheduler
         if let Err(e) = self.scheduler.shutdown().await {
@@ -265,6 +291,10 @@ impl SchedulerService {
     /// - Jitter for distributed scheduling
     /// - Timeout to prevent runaway jobs
     ///
+    /// The job runs at [`JobPriority::Normal`]; use
+    /// [`register_job_with_priority`](Self::register_job_with_priority) to
+    /// change its admission order under `max_concurrent_jobs`.
+    ///
     /// # Arguments
     ///
     /// * `name` - Unique name for the job (used for status tracking)
@@ -304,6 +334,57 @@ impl SchedulerService {
         timeout: TimeoutConfig,
         job_fn: F,
     ) -> Result<uuid::Uuid, SchedulerError>
+    where
+        F: Fn() -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send,
+    {
+        self.register_job_with_priority(
+            name,
+            cron_expr,
+            timezone,
+            overlap_policy,
+            jitter,
+            timeout,
+            JobPriority::Normal,
+            job_fn,
+        )
+        .await
+    }
+
+    /// Register a job like [`register_job`](Self::register_job), with a
+    /// priority.
+    ///
+    /// `priority` sets the admission order when runs queue for a global
+    /// concurrency slot. It only matters when `max_concurrent_jobs` is set.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use memory_scheduler::{JitterConfig, JobPriority, OverlapPolicy, TimeoutConfig};
+    ///
+    /// scheduler.register_job_with_priority(
+    ///     "outbox_indexing",
+    ///     "0 * * * * *",
+    ///     None,
+    ///     OverlapPolicy::Skip,
+    ///     JitterConfig::none(),
+    ///     TimeoutConfig::new(300),
+    ///     JobPriority::High, // Ahead of maintenance when jobs queue for slots
+    ///     || async { run_indexing().await },
+    /// ).await?;
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn register_job_with_priority<F, Fut>(
+        &self,
+        name: &str,
+        cron_expr: &str,
+        timezone: Option<&str>,
+        overlap_policy: OverlapPolicy,
+        jitter: JitterConfig,
+        timeout: TimeoutConfig,
+        priority: JobPriority,
+        job_fn: F,
+    ) -> Result<uuid::Uuid, SchedulerError>
     where
         F: Fn() -> Fut + Clone + Send + Sync + 'static,
         Fut: Future<Output = Result<(), String>> + Send,
@@ -321,10 +402,13 @@ impl SchedulerService {
 
         // Register in registry
         self.registry.register(name, cron_expr);
+        self.registry.set_priority(name, priority);
 
         let job_name = name.to_string();
         let registry = self.registry.clone();
         let overlap_guard = Arc::new(OverlapGuard::new(overlap_policy));
+        let gate = self.gate.clone();
+        let shutdown = self.shutdown_token.clone();
         let max_jitter_secs = jitter.max_jitter_secs;
         let timeout_duration = timeout.as_duration();
 
@@ -333,6 +417,8 @@ impl SchedulerService {
             let name = job_name.clone();
             let registry = registry.clone();
             let guard = overlap_guard.clone();
+            let gate = gate.clone();
+            let shutdown = shutdown.clone();
             let job_fn = job_fn.clone();
             let timeout_dur = timeout_duration;
 
@@ -348,17 +434,18 @@ impl SchedulerService {
                 let run_guard = match guard.try_acquire() {
                     Some(g) => g,
                     None => {
-                        debug!(job = %name, "Job already running, skipping due to overlap policy");
-                        registry.record_complete(&name, JobResult::Skipped("overlap".into()), 0);
+                        // The previous run may not have started yet
+                        let reason = if registry.is_running(&name) {
+                            overlap_policy.skip_reason()
+                        } else {
+                            "queued"
+                        };
+                        debug!(job = %name, reason, "Previous run not finished, skipping due to overlap policy");
+                        registry.record_complete(&name, JobResult::Skipped(reason.into()), 0);
                         return;
                     }
                 };
 
-                // Record start
-                registry.record_start(&name);
-                info!(job = %name, "Job started");
-                let start = std::time::Instant::now();
-
                 // Apply jitter
                 if max_jitter_secs > 0 {
                     let jitter_config = JitterConfig::new(max_jitter_secs);
@@ -369,6 +456,28 @@ impl SchedulerService {
                     }
                 }
 
+                // Wait for a global slot, if the scheduler has a limit. The
+                // run only counts as started once it has one.
+                let _slot = match &gate {
+                    Some(gate) => {
+                        debug!(job = %name, ?priority, "Waiting for concurrency slot");
+                        tokio::select! {
+                            permit = gate.acquire(priority) => Some(permit),
+                            _ = shutdown.cancelled() => {
+                                debug!(job = %name, "Scheduler shutting down, dropping queued run");
+                                registry.record_complete(&name, JobResult::Skipped("shutdown".into()), 0);
+                                return;
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                // Record start
+                registry.record_start(&name);
+                info!(job = %name, "Job started");
+                let start = std::time::Instant::now();
+
                 // Execute the job function with optional timeout
                 let result = match timeout_dur {
                     Some(duration) => {
@@ -431,6 +540,7 @@ impl SchedulerService {
     ///
     /// This is useful for jobs that need to report stats (e.g., prune count, items processed)
     /// that can be queried via the scheduler status API.
+    /// The job runs at [`JobPriority::Normal`].
     ///
     /// # Example
     ///
@@ -467,6 +577,8 @@ impl SchedulerService {
     {
         use std::collections::HashMap;
 
+        let priority = JobPriority::Normal;
+
         // Parse timezone
         let tz: Tz = match timezone {
             Some(tz_str) => tz_str
@@ -480,10 +592,13 @@ impl SchedulerService {
 
         // Register in registry
         self.registry.register(name, cron_expr);
+        self.registry.set_priority(name, priority);
 
         let job_name = name.to_string();
         let registry = self.registry.clone();
         let overlap_guard = Arc::new(OverlapGuard::new(overlap_policy));
+        let gate = self.gate.clone();
+        let shutdown = self.shutdown_token.clone();
         let max_jitter_secs = jitter.max_jitter_secs;
         let timeout_duration = timeout.as_duration();
 
@@ -492,6 +607,8 @@ impl SchedulerService {
             let name = job_name.clone();
             let registry = registry.clone();
             let guard = overlap_guard.clone();
+            let gate = gate.clone();
+            let shutdown = shutdown.clone();
             let job_fn = job_fn.clone();
             let timeout_dur = timeout_duration;
 
@@ -507,17 +624,18 @@ impl SchedulerService {
                 let run_guard = match guard.try_acquire() {
                     Some(g) => g,
                     None => {
-                        debug!(job = %name, "Job already running, skipping due to overlap policy");
-                        registry.record_complete(&name, JobResult::Skipped("overlap".into()), 0);
+                        // The previous run may not have started yet
+                        let reason = if registry.is_running(&name) {
+                            overlap_policy.skip_reason()
+                        } else {
+                            "queued"
+                        };
+                        debug!(job = %name, reason, "Previous run not finished, skipping due to overlap policy");
+                        registry.record_complete(&name, JobResult::Skipped(reason.into()), 0);
                         return;
                     }
                 };
 
-                // Record start
-                registry.record_start(&name);
-                info!(job = %name, "Job started");
-                let start = std::time::Instant::now();
-
                 // Apply jitter
                 if max_jitter_secs > 0 {
                     let jitter_config = JitterConfig::new(max_jitter_secs);
@@ -528,6 +646,28 @@ impl SchedulerService {
                     }
                 }
 
+                // Wait for a global slot, if the scheduler has a limit. The
+                // run only counts as started once it has one.
+                let _slot = match &gate {
+                    Some(gate) => {
+                        debug!(job = %name, ?priority, "Waiting for concurrency slot");
+                        tokio::select! {
+                            permit = gate.acquire(priority) => Some(permit),
+                            _ = shutdown.cancelled() => {
+                                debug!(job = %name, "Scheduler shutting down, dropping queued run");
+                                registry.record_complete(&name, JobResult::Skipped("shutdown".into()), 0);
+                                return;
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                // Record start
+                registry.record_start(&name);
+                info!(job = %name, "Job started");
+                let start = std::time::Instant::now();
+
                 // Execute the job function with optional timeout
                 let (result, metadata) = match timeout_dur {
                     Some(duration) => {
@@ -682,6 +822,40 @@ mod tests {
         assert!(token.is_cancelled());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_shutdown_returns_once_jobs_finish() {
+        let config = SchedulerConfig {
+            shutdown_timeout_secs: 60,
+            ..Default::default()
+        };
+        let mut scheduler = SchedulerService::new(config).await.unwrap();
+        scheduler.start().await.unwrap();
+
+        // Nothing is running, so the long grace period is not spent
+        let started = std::time::Instant::now();
+        scheduler.shutdown().await.unwrap();
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_shutdown_waits_for_running_job_until_timeout() {
+        let config = SchedulerConfig {
+            shutdown_timeout_secs: 1,
+            ..Default::default()
+        };
+        let mut scheduler = SchedulerService::new(config).await.unwrap();
+        scheduler.start().await.unwrap();
+
+        let registry = scheduler.registry();
+        registry.register("stuck-job", "0 0 * * * *");
+        registry.record_start("stuck-job");
+
+        let started = std::time::Instant::now();
+        scheduler.shutdown().await.unwrap();
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+        assert!(!scheduler.is_running());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_invalid_timezone_config() {
         let config = SchedulerConfig {
@@ -1140,4 +1314,136 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_priority_orders_jobs_under_concurrency_limit() {
+        use crate::{JitterConfig, OverlapPolicy, TimeoutConfig};
+        use std::sync::{Arc, Mutex};
+
+        let config = SchedulerConfig {
+            max_concurrent_jobs: 1,
+            ..Default::default()
+        };
+        let scheduler = SchedulerService::new(config).await.unwrap();
+
+        // Register the low-priority job first so registration order can't
+        // explain the result. The scheduler is never started; runs are
+        // admitted through its gate by hand below.
+        for (name, priority) in [
+            ("compaction", JobPriority::Low),
+            ("indexing", JobPriority::High),
+        ] {
+            scheduler
+                .register_job_with_priority(
+                    name,
+                    "0 0 0 1 1 *",
+                    None,
+                    OverlapPolicy::Skip,
+                    JitterConfig::none(),
+                    TimeoutConfig::none(),
+                    priority,
+                    || async { Ok(()) },
+                )
+                .await
+                .unwrap();
+        }
+        scheduler
+            .register_job(
+                "rollup",
+                "0 0 0 1 1 *",
+                None,
+                OverlapPolicy::Skip,
+                JitterConfig::none(),
+                TimeoutConfig::none(),
+                || async { Ok(()) },
+            )
+            .await
+            .unwrap();
+        assert_eq!(scheduler.registry().priority("rollup"), JobPriority::Normal);
+
+        let gate = scheduler.gate.clone().unwrap();
+        let holder = gate.acquire(JobPriority::Normal).await;
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let runs: Vec<_> = ["compaction", "rollup", "indexing"]
+            .into_iter()
+            .map(|name| {
+                let gate = gate.clone();
+                let order = order.clone();
+                let priority = scheduler.registry().priority(name);
+                tokio::spawn(async move {
+                    let _slot = gate.acquire(priority).await;
+                    order.lock().unwrap().push(name);
+                })
+            })
+            .collect();
+
+        // The clock is paused, so this only lets every run queue up
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        assert!(order.lock().unwrap().is_empty());
+        drop(holder);
+
+        for run in runs {
+            run.await.unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec!["indexing", "rollup", "compaction"]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_queued_run_starts_only_when_admitted() {
+        use crate::{JitterConfig, OverlapPolicy, TimeoutConfig};
+
+        let config = SchedulerConfig {
+            shutdown_timeout_secs: 1,
+            max_concurrent_jobs: 1,
+            ..Default::default()
+        };
+        let mut scheduler = SchedulerService::new(config).await.unwrap();
+
+        // The blocker holds the only slot for longer than the test runs
+        for (name, priority, hold_ms) in [
+            ("blocker", JobPriority::High, 10_000),
+            ("waiter", JobPriority::Low, 0),
+        ] {
+            scheduler
+                .register_job_with_priority(
+                    name,
+                    "* * * * * *",
+                    None,
+                    OverlapPolicy::Skip,
+                    JitterConfig::none(),
+                    TimeoutConfig::none(),
+                    priority,
+                    move || async move {
+                        tokio::time::sleep(std::time::Duration::from_millis(hold_ms)).await;
+                        Ok(())
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        scheduler.start().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+
+        // Waiting for a slot is not running, and later ticks say why they skipped
+        let registry = scheduler.registry();
+        assert!(registry.is_running("blocker"));
+        let waiter = registry.get_status("waiter").unwrap();
+        assert!(!waiter.is_running);
+        assert_eq!(
+            waiter.last_result,
+            Some(JobResult::Skipped("queued".into()))
+        );
+
+        // Shutdown releases the queued run instead of leaving it waiting
+        scheduler.shutdown().await.unwrap();
+        let waiter = registry.get_status("waiter").unwrap();
+        assert_eq!(
+            waiter.last_result,
+            Some(JobResult::Skipped("shutdown".into()))
+        );
+    }
 }
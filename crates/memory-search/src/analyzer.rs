@@ -0,0 +1,371 @@
+//! Configurable text analysis for the BM25 text fields.
+//!
+//! The analyzer is registered with Tantivy's tokenizer manager under a name
+//! derived from its settings. That name is stored in the index schema, so an
+//! index built with one analyzer is detected as stale when opened with another.
+
+use tantivy::tokenizer::{
+    LowerCaser, RemoveLongFilter, SimpleTokenizer, Stemmer, StopWordFilter, TextAnalyzer,
+};
+use tantivy::Index;
+
+pub use tantivy::tokenizer::Language;
+
+/// Tokenizer name used by the default analyzer (Tantivy's built-in).
+const DEFAULT_TOKENIZER: &str = "default";
+
+/// Tokens longer than this are dropped, matching Tantivy's default analyzer.
+const MAX_TOKEN_LEN: usize = 40;
+
+/// English stopwords (the Lucene EnglishAnalyzer list).
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// Languages with a dedicated text field and analyzer, as
+/// (ISO 639-1 code, language).
+///
+/// English is listed first and is the default for documents without a hint.
+pub const SUPPORTED_LANGUAGES: &[(&str, Language)] = &[
+    ("en", Language::English),
+    ("fr", Language::French),
+    ("de", Language::German),
+    ("es", Language::Spanish),
+    ("it", Language::Italian),
+    ("pt", Language::Portuguese),
+    ("nl", Language::Dutch),
+];
+
+/// Frequent function words used to guess a document's language.
+///
+/// Only words that are distinctive for their language are listed, so a
+/// handful of hits is a reliable signal. Non-English lists leave out words
+/// that are also common in English ("is", "as", "do", "die"), since mixed
+/// text is mostly English with a few foreign phrases.
+const DETECTION_WORDS: &[(Language, &[&str])] = &[
+    (
+        Language::English,
+        &[
+            "the", "and", "is", "are", "was", "with", "this", "that", "of", "to",
+        ],
+    ),
+    (
+        Language::French,
+        &[
+            "le", "les", "est", "et", "une", "des", "du", "avec", "pour", "dans", "nous", "sont",
+        ],
+    ),
+    (
+        Language::German,
+        &[
+            "der", "das", "und", "ist", "nicht", "mit", "ein", "eine", "wir", "sind", "auf", "ich",
+        ],
+    ),
+    (
+        Language::Spanish,
+        &[
+            "el", "los", "las", "y", "es", "una", "con", "para", "por", "del", "está", "que",
+        ],
+    ),
+    (
+        Language::Italian,
+        &[
+            "il", "gli", "della", "e", "è", "una", "con", "per", "sono", "che", "non", "nel",
+        ],
+    ),
+    (
+        Language::Portuguese,
+        &[
+            "o", "os", "e", "é", "uma", "com", "para", "não", "da", "são", "também", "mas",
+        ],
+    ),
+    (
+        Language::Dutch,
+        &[
+            "de", "het", "een", "en", "dat", "niet", "met", "van", "voor", "zijn", "wij", "ook",
+        ],
+    ),
+];
+
+/// Minimum detection-word hits before a non-English language is chosen.
+const MIN_DETECTION_HITS: usize = 2;
+
+/// ISO 639-1 code for a supported language.
+pub fn language_code(language: Language) -> Option<&'static str> {
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|(_, l)| *l == language)
+        .map(|(code, _)| *code)
+}
+
+/// Parse a language hint (ISO 639-1 code, case-insensitive).
+pub fn parse_language(code: &str) -> Option<Language> {
+    let code = code.trim().to_ascii_lowercase();
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, l)| *l)
+}
+
+/// Guess the language of `text` by counting distinctive function words.
+///
+/// Falls back to English unless another language has at least
+/// [`MIN_DETECTION_HITS`] hits and strictly more than English.
+pub fn detect_language(text: &str) -> Language {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let hits = |list: &[&str]| words.iter().filter(|w| list.contains(&w.as_str())).count();
+
+    let mut best = (Language::English, 0);
+    for (language, list) in DETECTION_WORDS {
+        let count = hits(list);
+        if count > best.1 {
+            best = (*language, count);
+        }
+    }
+
+    match best {
+        (language, count) if language != Language::English && count >= MIN_DETECTION_HITS => {
+            language
+        }
+        _ => Language::English,
+    }
+}
+
+/// Analyzer applied to the `text` and `keywords` fields at index and query time.
+///
+/// The default (lowercase only) is identical to Tantivy's built-in tokenizer,
+/// so existing indexes remain valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzerConfig {
+    /// Lowercase tokens
+    pub lowercase: bool,
+    /// Snowball stemmer language (None = no stemming)
+    pub stemmer: Option<Language>,
+    /// Words dropped from documents and queries (matched after lowercasing)
+    pub stopwords: Vec<String>,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            stemmer: None,
+            stopwords: Vec::new(),
+        }
+    }
+}
+
+impl AnalyzerConfig {
+    /// English analyzer: lowercasing, Porter/Snowball stemming and stopwords.
+    pub fn english() -> Self {
+        Self {
+            lowercase: true,
+            stemmer: Some(Language::English),
+            stopwords: ENGLISH_STOPWORDS.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    /// Analyzer for documents in `language`: lowercasing and that language's
+    /// stemmer. English also drops English stopwords (see [`Self::english`]).
+    pub fn for_language(language: Language) -> Self {
+        match language {
+            Language::English => Self::english(),
+            other => Self::default().with_stemmer(Some(other)),
+        }
+    }
+
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    pub fn with_stemmer(mut self, language: Option<Language>) -> Self {
+        self.stemmer = language;
+        self
+    }
+
+    pub fn with_stopwords(mut self, stopwords: Vec<String>) -> Self {
+        self.stopwords = stopwords;
+        self
+    }
+
+    /// Name the analyzer is registered under and recorded in the schema.
+    ///
+    /// Any change to the settings changes the name, which is what flags an
+    /// index built with a different analyzer as stale.
+    pub fn tokenizer_name(&self) -> String {
+        if *self == Self::default() {
+            DEFAULT_TOKENIZER.to_string()
+        } else {
+            format!("memory_{:016x}", self.fingerprint())
+        }
+    }
+
+    /// Stable hash of the settings (FNV-1a over a canonical description).
+    pub fn fingerprint(&self) -> u64 {
+        let mut stopwords: Vec<&str> = self.stopwords.iter().map(String::as_str).collect();
+        stopwords.sort_unstable();
+        stopwords.dedup();
+        let canonical = format!(
+            "lowercase={};stemmer={:?};stopwords={}",
+            self.lowercase,
+            self.stemmer,
+            stopwords.join(",")
+        );
+
+        canonical.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    /// Build the Tantivy analyzer.
+    ///
+    /// Stopwords are removed before stemming so the list can use plain words.
+    pub fn build(&self) -> TextAnalyzer {
+        let mut builder = TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(MAX_TOKEN_LEN))
+            .dynamic();
+        if self.lowercase {
+            builder = builder.filter_dynamic(LowerCaser);
+        }
+        if !self.stopwords.is_empty() {
+            builder = builder.filter_dynamic(StopWordFilter::remove(self.stopwords.clone()));
+        }
+        if let Some(language) = self.stemmer {
+            builder = builder.filter_dynamic(Stemmer::new(language));
+        }
+        builder.build()
+    }
+
+    /// Register the analyzer with an index's tokenizer manager.
+    pub fn register(&self, index: &Index) {
+        let name = self.tokenizer_name();
+        if name != DEFAULT_TOKENIZER {
+            index.tokenizers().register(&name, self.build());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(config: &AnalyzerConfig, text: &str) -> Vec<String> {
+        let mut analyzer = config.build();
+        let mut stream = analyzer.token_stream(text);
+        let mut out = Vec::new();
+        while let Some(token) = stream.next() {
+            out.push(token.text.clone());
+        }
+        out
+    }
+
+    #[test]
+    fn test_default_matches_builtin() {
+        let config = AnalyzerConfig::default();
+        assert_eq!(config.tokenizer_name(), "default");
+        assert_eq!(
+            tokens(&config, "The Running Dogs"),
+            ["the", "running", "dogs"]
+        );
+    }
+
+    #[test]
+    fn test_english_stems_and_drops_stopwords() {
+        let config = AnalyzerConfig::english();
+        assert_eq!(tokens(&config, "The Running Dogs"), ["run", "dog"]);
+    }
+
+    #[test]
+    fn test_tokenizer_name_tracks_settings() {
+        let english = AnalyzerConfig::english();
+        assert!(english.tokenizer_name().starts_with("memory_"));
+        assert_eq!(
+            english.tokenizer_name(),
+            AnalyzerConfig::english().tokenizer_name()
+        );
+
+        let no_stem = english.clone().with_stemmer(None);
+        assert_ne!(english.tokenizer_name(), no_stem.tokenizer_name());
+
+        // Stopword order and duplicates don't matter
+        let reordered = english.clone().with_stopwords(
+            english
+                .stopwords
+                .iter()
+                .rev()
+                .chain(english.stopwords.iter())
+                .cloned()
+                .collect(),
+        );
+        assert_eq!(english.fingerprint(), reordered.fingerprint());
+    }
+
+    #[test]
+    fn test_for_language() {
+        assert_eq!(
+            AnalyzerConfig::for_language(Language::English),
+            AnalyzerConfig::english()
+        );
+        let french = AnalyzerConfig::for_language(Language::French);
+        assert_eq!(tokens(&french, "Ils mangeaient"), ["il", "mang"]);
+        assert_ne!(
+            french.tokenizer_name(),
+            AnalyzerConfig::english().tokenizer_name()
+        );
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(language_code(Language::French), Some("fr"));
+        assert_eq!(parse_language("FR"), Some(Language::French));
+        assert_eq!(parse_language("xx"), None);
+        for (code, language) in SUPPORTED_LANGUAGES {
+            assert_eq!(parse_language(code), Some(*language));
+        }
+    }
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(
+            detect_language("The user is debugging the build with cargo"),
+            Language::English
+        );
+        assert_eq!(
+            detect_language("Nous avons discuté des tests et de la base de données pour le projet"),
+            Language::French
+        );
+        assert_eq!(
+            detect_language("Wir sind mit der Migration nicht fertig"),
+            Language::German
+        );
+        // Too little signal falls back to English
+        assert_eq!(detect_language("cargo build"), Language::English);
+        assert_eq!(detect_language(""), Language::English);
+    }
+
+    #[test]
+    fn test_detect_language_mixed_text() {
+        // English words shared with other languages do not count for them
+        assert_eq!(
+            detect_language("Status as of now: green as expected, as usual"),
+            Language::English
+        );
+        // A short foreign phrase inside English text stays English
+        assert_eq!(
+            detect_language("The deploy is done and the tests pass, but le build est cassé"),
+            Language::English
+        );
+        // Mostly French text with English terms is French
+        assert_eq!(
+            detect_language("Le build est cassé, nous avons des erreurs dans the linker"),
+            Language::French
+        );
+    }
+}
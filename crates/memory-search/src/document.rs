@@ -1,19 +1,33 @@
 //! Document mapping from domain types to Tantivy documents.
 //!
-//! Converts TocNode and Grip into indexable Tantivy documents.
+//! Converts TocNode, Grip and raw Event into indexable Tantivy documents.
+//!
+//! Each document's text is written to the text field for its language, so it
+//! is analyzed with that language's stemmer. The language comes from a hint
+//! when the caller has one, otherwise it is detected from the text.
 
 use tantivy::doc;
 use tantivy::TantivyDocument;
 
-use memory_types::{Grip, TocNode};
+use memory_types::{Event, Grip, TocNode};
 
+use crate::analyzer::{detect_language, language_code, Language};
 use crate::schema::{DocType, SearchSchema};
 
-/// Convert a TocNode to a Tantivy document.
+/// Convert a TocNode to a Tantivy document, detecting its language.
 ///
 /// Text field contains: title + all bullet texts
 /// Keywords field contains: joined keywords
 pub fn toc_node_to_doc(schema: &SearchSchema, node: &TocNode) -> TantivyDocument {
+    toc_node_to_doc_with_lang(schema, node, None)
+}
+
+/// Convert a TocNode to a Tantivy document in `lang` (None = detect).
+pub fn toc_node_to_doc_with_lang(
+    schema: &SearchSchema,
+    node: &TocNode,
+    lang: Option<Language>,
+) -> TantivyDocument {
     // Combine title and bullets for searchable text
     let mut text_parts = vec![node.title.clone()];
     for bullet in &node.bullets {
@@ -25,7 +39,7 @@ pub fn toc_node_to_doc(schema: &SearchSchema, node: &TocNode) -> TantivyDocument
     let keywords = node.keywords.join(" ");
 
     // Timestamp in milliseconds
-    let timestamp = node.start_time.timestamp_millis().to_string();
+    let timestamp_ms = node.start_time.timestamp_millis();
 
     // Use first contributing agent as the primary agent
     let agent = node
@@ -34,33 +48,96 @@ pub fn toc_node_to_doc(schema: &SearchSchema, node: &TocNode) -> TantivyDocument
         .cloned()
         .unwrap_or_default();
 
-    doc!(
+    let mut doc = doc!(
         schema.doc_type => DocType::TocNode.as_str(),
         schema.doc_id => node.node_id.clone(),
         schema.level => node.level.to_string(),
-        schema.text => text,
         schema.keywords => keywords,
-        schema.timestamp_ms => timestamp,
+        schema.timestamp_ms => timestamp_ms.to_string(),
         schema.agent => agent
-    )
+    );
+    add_time(schema, &mut doc, timestamp_ms);
+    add_text(schema, &mut doc, text, lang);
+    doc
 }
 
-/// Convert a Grip to a Tantivy document.
+/// Convert a Grip to a Tantivy document, detecting its language.
 ///
 /// Text field contains: excerpt
 /// Level field is empty (not applicable to grips)
 pub fn grip_to_doc(schema: &SearchSchema, grip: &Grip) -> TantivyDocument {
-    let timestamp = grip.timestamp.timestamp_millis().to_string();
+    grip_to_doc_with_lang(schema, grip, None)
+}
 
-    doc!(
+/// Convert a Grip to a Tantivy document in `lang` (None = detect).
+pub fn grip_to_doc_with_lang(
+    schema: &SearchSchema,
+    grip: &Grip,
+    lang: Option<Language>,
+) -> TantivyDocument {
+    let timestamp_ms = grip.timestamp.timestamp_millis();
+
+    let mut doc = doc!(
         schema.doc_type => DocType::Grip.as_str(),
         schema.doc_id => grip.grip_id.clone(),
         schema.level => "",  // Not applicable for grips
-        schema.text => grip.excerpt.clone(),
         schema.keywords => "",  // Grips don't have keywords
-        schema.timestamp_ms => timestamp,
+        schema.timestamp_ms => timestamp_ms.to_string(),
         schema.agent => ""  // Grips inherit agent from parent node
-    )
+    );
+    add_time(schema, &mut doc, timestamp_ms);
+    add_text(schema, &mut doc, grip.excerpt.clone(), lang);
+    doc
+}
+
+/// Convert a raw event to a Tantivy document, detecting its language.
+///
+/// Text field contains: event text
+/// Level and keywords are empty (not applicable to events)
+pub fn event_to_doc(schema: &SearchSchema, event: &Event) -> TantivyDocument {
+    let timestamp_ms = event.timestamp.timestamp_millis();
+
+    let mut doc = doc!(
+        schema.doc_type => DocType::Event.as_str(),
+        schema.doc_id => event.event_id.clone(),
+        schema.level => "",
+        schema.keywords => "",
+        schema.timestamp_ms => timestamp_ms.to_string(),
+        schema.agent => event.agent.clone().unwrap_or_default()
+    );
+    add_time(schema, &mut doc, timestamp_ms);
+    add_text(schema, &mut doc, event.text.clone(), None);
+    doc
+}
+
+/// Record the numeric timestamp, if the index has a field for it.
+fn add_time(schema: &SearchSchema, doc: &mut TantivyDocument, timestamp_ms: i64) {
+    if let Some(field) = schema.time_ms {
+        doc.add_i64(field, timestamp_ms);
+    }
+}
+
+/// Add `text` to the field for its language and record the language.
+///
+/// Languages without a field in this index are indexed as English.
+fn add_text(
+    schema: &SearchSchema,
+    doc: &mut TantivyDocument,
+    text: String,
+    lang: Option<Language>,
+) {
+    let language = lang.unwrap_or_else(|| detect_language(&text));
+    let field = schema.text_field_for(language);
+    let language = if field == schema.text {
+        Language::English
+    } else {
+        language
+    };
+
+    if let (Some(lang_field), Some(code)) = (schema.lang, language_code(language)) {
+        doc.add_text(lang_field, code);
+    }
+    doc.add_text(field, text);
 }
 
 /// Extract text content from a TocNode for indexing.
@@ -200,4 +277,36 @@ mod tests {
         let level = doc.get_first(schema.level).unwrap();
         assert_eq!(level.as_str(), Some(""));
     }
+
+    #[test]
+    fn test_doc_language_routing() {
+        let schema = build_teleport_schema();
+        let french_field = schema.text_field_for(Language::French);
+
+        // English is detected and stays in the main text field
+        let doc = toc_node_to_doc(&schema, &sample_toc_node());
+        assert_eq!(
+            doc.get_first(schema.lang.unwrap()).unwrap().as_str(),
+            Some("en")
+        );
+        assert!(doc.get_first(french_field).is_none());
+
+        // French is detected and routed to its own field
+        let mut grip = sample_grip();
+        grip.excerpt = "Nous avons corrigé les tests et la base de données".to_string();
+        let doc = grip_to_doc(&schema, &grip);
+        assert_eq!(
+            doc.get_first(schema.lang.unwrap()).unwrap().as_str(),
+            Some("fr")
+        );
+        assert!(doc.get_first(schema.text).is_none());
+        assert!(doc.get_first(french_field).is_some());
+
+        // An explicit hint overrides detection
+        let doc = grip_to_doc_with_lang(&schema, &sample_grip(), Some(Language::German));
+        assert_eq!(
+            doc.get_first(schema.lang.unwrap()).unwrap().as_str(),
+            Some("de")
+        );
+    }
 }
@@ -32,4 +32,8 @@ pub enum SearchError {
     /// Index is locked (another process has it open)
     #[error("Index is locked: {0}")]
     IndexLocked(String),
+
+    /// Index files failed an integrity check
+    #[error("Index corrupted: {0}")]
+    Corrupted(String),
 }
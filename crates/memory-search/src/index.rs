@@ -7,8 +7,9 @@ use std::path::{Path, PathBuf};
 use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy};
 use tracing::{debug, info};
 
+use crate::analyzer::AnalyzerConfig;
 use crate::error::SearchError;
-use crate::schema::{build_teleport_schema, SearchSchema};
+use crate::schema::{build_teleport_schema_with_analyzer, SearchSchema};
 
 /// Default memory budget for IndexWriter (50MB)
 const DEFAULT_WRITER_MEMORY_MB: usize = 50;
@@ -20,6 +21,8 @@ pub struct SearchIndexConfig {
     pub index_path: PathBuf,
     /// Memory budget for writer in MB
     pub writer_memory_mb: usize,
+    /// Analyzer for the text and keywords fields
+    pub analyzer: AnalyzerConfig,
 }
 
 impl Default for SearchIndexConfig {
@@ -27,6 +30,7 @@ impl Default for SearchIndexConfig {
         Self {
             index_path: PathBuf::from("./bm25-index"),
             writer_memory_mb: DEFAULT_WRITER_MEMORY_MB,
+            analyzer: AnalyzerConfig::default(),
         }
     }
 }
@@ -36,6 +40,7 @@ impl SearchIndexConfig {
         Self {
             index_path: index_path.into(),
             writer_memory_mb: DEFAULT_WRITER_MEMORY_MB,
+            analyzer: AnalyzerConfig::default(),
         }
     }
 
@@ -43,6 +48,11 @@ impl SearchIndexConfig {
         self.writer_memory_mb = mb;
         self
     }
+
+    pub fn with_analyzer(mut self, analyzer: AnalyzerConfig) -> Self {
+        self.analyzer = analyzer;
+        self
+    }
 }
 
 /// Wrapper for Tantivy index with schema access.
@@ -54,8 +64,11 @@ pub struct SearchIndex {
 
 impl SearchIndex {
     /// Open existing index or create new one.
+    ///
+    /// Fails with [`SearchError::SchemaMismatch`] if an existing index was
+    /// built with a different analyzer; it must be cleared and rebuilt.
     pub fn open_or_create(config: SearchIndexConfig) -> Result<Self, SearchError> {
-        let index = open_or_create_index(&config.index_path)?;
+        let index = open_or_create_index(&config.index_path, &config.analyzer)?;
         let schema = SearchSchema::from_schema(index.schema())?;
 
         info!(path = ?config.index_path, "Opened search index");
@@ -67,6 +80,28 @@ impl SearchIndex {
         })
     }
 
+    /// Open an existing index for inspection without creating one.
+    ///
+    /// Unlike [`SearchIndex::open_or_create`], this never writes to `path`
+    /// and accepts an index built with any analyzer. Fails if there is no
+    /// index at `path`.
+    pub fn open_existing(path: impl Into<PathBuf>) -> Result<Self, SearchError> {
+        let config = SearchIndexConfig::new(path);
+        let index = Index::open_in_dir(&config.index_path)?;
+        let schema = SearchSchema::from_schema(index.schema())?;
+        for (language, _) in &schema.language_text {
+            AnalyzerConfig::for_language(*language).register(&index);
+        }
+
+        info!(path = ?config.index_path, "Opened existing search index");
+
+        Ok(Self {
+            index,
+            schema,
+            config,
+        })
+    }
+
     /// Get the search schema
     pub fn schema(&self) -> &SearchSchema {
         &self.schema
@@ -112,19 +147,39 @@ impl SearchIndex {
 
 /// Open an existing index or create a new one.
 ///
-/// Uses MmapDirectory for persistence.
-pub fn open_or_create_index(path: &Path) -> Result<Index, SearchError> {
-    if path.join("meta.json").exists() {
+/// Uses MmapDirectory for persistence. The analyzer is registered on the
+/// returned index along with the per-language analyzers; an existing index whose analyzer fingerprint differs is
+/// rejected as stale.
+pub fn open_or_create_index(path: &Path, analyzer: &AnalyzerConfig) -> Result<Index, SearchError> {
+    let expected = analyzer.tokenizer_name();
+    let index = if path.join("meta.json").exists() {
         debug!(path = ?path, "Opening existing index");
         let index = Index::open_in_dir(path)?;
-        Ok(index)
+        let schema = SearchSchema::from_schema(index.schema())?;
+        match schema.analyzer_fingerprint() {
+            Some(found) if found == expected => {}
+            found => {
+                return Err(SearchError::SchemaMismatch(format!(
+                    "index analyzer '{}' does not match configured '{}'; clear and rebuild the index",
+                    found.unwrap_or("none"),
+                    expected
+                )));
+            }
+        }
+        index
     } else {
-        info!(path = ?path, "Creating new index");
+        info!(path = ?path, tokenizer = %expected, "Creating new index");
         std::fs::create_dir_all(path)?;
-        let schema = build_teleport_schema();
-        let index = Index::create_in_dir(path, schema.schema().clone())?;
-        Ok(index)
+        let schema = build_teleport_schema_with_analyzer(analyzer);
+        Index::create_in_dir(path, schema.schema().clone())?
+    };
+
+    analyzer.register(&index);
+    let schema = SearchSchema::from_schema(index.schema())?;
+    for (language, _) in &schema.language_text {
+        AnalyzerConfig::for_language(*language).register(&index);
     }
+    Ok(index)
 }
 
 #[cfg(test)]
@@ -171,6 +226,30 @@ mod tests {
         assert_eq!(config.writer_memory_mb, DEFAULT_WRITER_MEMORY_MB);
     }
 
+    #[test]
+    fn test_reopen_with_different_analyzer_is_stale() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SearchIndexConfig::new(temp_dir.path());
+        drop(SearchIndex::open_or_create(config.clone()).unwrap());
+
+        let english = config.with_analyzer(AnalyzerConfig::english());
+        let result = SearchIndex::open_or_create(english);
+        assert!(matches!(result, Err(SearchError::SchemaMismatch(_))));
+    }
+
+    #[test]
+    fn test_open_existing_does_not_create() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(SearchIndex::open_existing(temp_dir.path()).is_err());
+        assert!(!temp_dir.path().join("meta.json").exists());
+
+        let english =
+            SearchIndexConfig::new(temp_dir.path()).with_analyzer(AnalyzerConfig::english());
+        drop(SearchIndex::open_or_create(english).unwrap());
+        let index = SearchIndex::open_existing(temp_dir.path()).unwrap();
+        assert!(index.exists());
+    }
+
     #[test]
     fn test_config_with_memory() {
         let config = SearchIndexConfig::new("/tmp/test").with_memory_mb(100);
@@ -3,6 +3,7 @@
 //! The indexer wraps IndexWriter with shared access via `Arc<Mutex>`.
 //! Documents are not visible until commit() is called.
 
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
 use chrono::Utc;
@@ -12,13 +13,13 @@ use tantivy::schema::Value;
 use tantivy::{IndexReader, IndexWriter, ReloadPolicy, Term};
 use tracing::{debug, info, warn};
 
-use memory_types::{Grip, TocNode};
+use memory_types::{Event, Grip, TocNode};
 
-use crate::document::{grip_to_doc, toc_node_to_doc};
+use crate::document::{event_to_doc, grip_to_doc, toc_node_to_doc};
 use crate::error::SearchError;
 use crate::index::SearchIndex;
 use crate::lifecycle::Bm25PruneStats;
-use crate::schema::SearchSchema;
+use crate::schema::{DocType, SearchSchema};
 
 /// Manages document indexing operations.
 ///
@@ -106,6 +107,27 @@ impl SearchIndexer {
         Ok(())
     }
 
+    /// Index a raw event.
+    ///
+    /// If a document with the same event_id exists, it will be replaced.
+    pub fn index_event(&self, event: &Event) -> Result<(), SearchError> {
+        let doc = event_to_doc(&self.schema, event);
+
+        let writer = self
+            .writer
+            .lock()
+            .map_err(|e| SearchError::IndexLocked(e.to_string()))?;
+
+        // Delete existing document with same ID (for update)
+        let term = Term::from_field_text(self.schema.doc_id, &event.event_id);
+        writer.delete_term(term);
+
+        writer.add_document(doc)?;
+
+        debug!(event_id = %event.event_id, "Indexed event");
+        Ok(())
+    }
+
     /// Index multiple TOC nodes in batch.
     pub fn index_toc_nodes(&self, nodes: &[TocNode]) -> Result<usize, SearchError> {
         let writer = self
@@ -168,6 +190,34 @@ impl SearchIndexer {
         Ok(())
     }
 
+    /// Delete the documents of a purged session.
+    ///
+    /// The index does not record session IDs, so `doc_ids` must list the
+    /// session's event and grip IDs (see `PurgeReport::doc_ids` in
+    /// memory-storage). Deletions become visible after [`commit`](Self::commit).
+    /// Returns the number of delete operations queued.
+    pub fn delete_by_session(
+        &self,
+        session_id: &str,
+        doc_ids: &[String],
+    ) -> Result<usize, SearchError> {
+        let writer = self
+            .writer
+            .lock()
+            .map_err(|e| SearchError::IndexLocked(e.to_string()))?;
+
+        for doc_id in doc_ids {
+            writer.delete_term(Term::from_field_text(self.schema.doc_id, doc_id));
+        }
+
+        debug!(
+            session_id,
+            count = doc_ids.len(),
+            "Deleted session documents"
+        );
+        Ok(doc_ids.len())
+    }
+
     /// Commit pending changes to make them searchable.
     ///
     /// This is expensive - batch document adds and commit periodically.
@@ -211,6 +261,31 @@ impl SearchIndexer {
         Ok(())
     }
 
+    /// Collect the doc_id of every committed TOC node and grip document.
+    ///
+    /// Reloads the reader first so recent commits are included. Used by
+    /// consistency checks to compare the index against storage, so raw
+    /// event documents are left out.
+    pub fn doc_ids(&self) -> Result<HashSet<String>, SearchError> {
+        self.reader.reload()?;
+        let searcher = self.reader.searcher();
+        let all_docs = searcher.search(&AllQuery, &DocSetCollector)?;
+
+        let mut ids = HashSet::with_capacity(all_docs.len());
+        for doc_address in all_docs {
+            let doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+            let doc_type = doc.get_first(self.schema.doc_type).and_then(|v| v.as_str());
+            if doc_type == Some(DocType::Event.as_str()) {
+                continue;
+            }
+            if let Some(doc_id) = doc.get_first(self.schema.doc_id).and_then(|v| v.as_str()) {
+                ids.insert(doc_id.to_string());
+            }
+        }
+
+        Ok(ids)
+    }
+
     /// Prune documents older than the specified age.
     ///
     /// Scans all documents and deletes those with timestamp_ms older than
@@ -276,10 +351,9 @@ impl SearchIndexer {
                 .unwrap_or("");
 
             // Apply level filter if specified
-            let effective_level = if level.is_empty() && doc_type == "grip" {
-                "grip"
-            } else {
-                level
+            let effective_level = match doc_type {
+                "grip" | "event" if level.is_empty() => doc_type,
+                _ => level,
             };
 
             if let Some(filter) = level_filter {
@@ -489,6 +563,48 @@ mod tests {
         indexer.commit().unwrap();
     }
 
+    #[test]
+    fn test_doc_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SearchIndexConfig::new(temp_dir.path());
+        let index = SearchIndex::open_or_create(config).unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        indexer.index_toc_node(&sample_toc_node("node-1")).unwrap();
+        indexer.index_grip(&sample_grip("grip-1")).unwrap();
+        indexer.commit().unwrap();
+
+        let ids = indexer.doc_ids().unwrap();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains("node-1"));
+        assert!(ids.contains("grip-1"));
+
+        indexer.delete_document("node-1").unwrap();
+        indexer.commit().unwrap();
+        assert!(!indexer.doc_ids().unwrap().contains("node-1"));
+    }
+
+    #[test]
+    fn test_delete_by_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SearchIndexConfig::new(temp_dir.path());
+        let index = SearchIndex::open_or_create(config).unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        for id in ["grip-a1", "grip-a2", "grip-b1"] {
+            indexer.index_grip(&sample_grip(id)).unwrap();
+        }
+        indexer.commit().unwrap();
+
+        let purged = vec!["grip-a1".to_string(), "grip-a2".to_string()];
+        assert_eq!(indexer.delete_by_session("session-a", &purged).unwrap(), 2);
+        indexer.commit().unwrap();
+
+        let ids = indexer.doc_ids().unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(ids.contains("grip-b1"));
+    }
+
     #[test]
     fn test_update_existing_document() {
         let temp_dir = TempDir::new().unwrap();
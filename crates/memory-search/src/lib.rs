@@ -10,6 +10,7 @@
 //! - Schema for indexing TOC node summaries and grip excerpts
 //! - BM25 scoring for relevance ranking
 //! - Document type filtering (toc_node vs grip)
+//! - Per-document language analyzers with language filtering
 //!
 //! ## Requirements
 //! - TEL-01: Tantivy embedded index
@@ -17,6 +18,7 @@
 //! - TEL-03: Relevance scores for agent decision-making
 //! - TEL-04: Incremental index updates
 
+pub mod analyzer;
 pub mod document;
 pub mod error;
 pub mod index;
@@ -25,12 +27,20 @@ pub mod lifecycle;
 pub mod schema;
 pub mod searcher;
 
-pub use document::{extract_toc_text, grip_to_doc, toc_node_to_doc};
+pub use analyzer::{detect_language, parse_language, AnalyzerConfig, Language};
+pub use document::{
+    event_to_doc, extract_toc_text, grip_to_doc, grip_to_doc_with_lang, toc_node_to_doc,
+    toc_node_to_doc_with_lang,
+};
 pub use error::SearchError;
 pub use index::{open_or_create_index, SearchIndex, SearchIndexConfig};
 pub use indexer::SearchIndexer;
 pub use lifecycle::{
     is_protected_level, retention_map, Bm25LifecycleConfig, Bm25MaintenanceConfig, Bm25PruneStats,
 };
-pub use schema::{build_teleport_schema, DocType, SearchSchema};
-pub use searcher::{SearchOptions, TeleportResult, TeleportSearcher};
+pub use schema::{
+    build_teleport_schema, build_teleport_schema_with_analyzer, DocType, SearchSchema,
+};
+pub use searcher::{
+    IndexVerifyReport, NoResultsReason, SearchOptions, TeleportResult, TeleportSearcher,
+};
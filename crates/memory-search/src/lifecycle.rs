@@ -12,6 +12,7 @@
 //! Must be explicitly enabled via configuration.
 
 use chrono::{DateTime, Duration, Utc};
+use memory_types::Bm25LifecycleSettings;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -76,6 +77,37 @@ impl Bm25LifecycleConfig {
             ..Default::default()
         }
     }
+
+    /// Build from the `[lifecycle.bm25]` settings section.
+    ///
+    /// Entries in `retention` replace the matching `*_retention_days` field.
+    /// Protected levels can't be given a retention; unknown levels are rejected.
+    pub fn from_settings(settings: &Bm25LifecycleSettings) -> Result<Self, String> {
+        let mut config = Self {
+            enabled: settings.enabled,
+            segment_retention_days: settings.segment_retention_days,
+            grip_retention_days: settings.grip_retention_days,
+            day_retention_days: settings.day_retention_days,
+            week_retention_days: settings.week_retention_days,
+        };
+        for (level, &days) in &settings.retention {
+            let slot = match level.as_str() {
+                "segment" => &mut config.segment_retention_days,
+                "grip" => &mut config.grip_retention_days,
+                "day" => &mut config.day_retention_days,
+                "week" => &mut config.week_retention_days,
+                other if is_protected_level(other) => {
+                    return Err(format!(
+                        "level '{}' is protected and never pruned; remove its retention",
+                        other
+                    ))
+                }
+                other => return Err(format!("unknown level '{}' in BM25 retention", other)),
+            };
+            *slot = days;
+        }
+        Ok(config)
+    }
 }
 
 /// Statistics from a BM25 prune operation.
@@ -194,6 +226,29 @@ mod tests {
         assert_eq!(config.week_retention_days, 1825);
     }
 
+    #[test]
+    fn test_from_settings() {
+        let settings: Bm25LifecycleSettings = serde_json::from_str(
+            r#"{"enabled": true, "grip_retention_days": 7, "retention": {"segment": 14}}"#,
+        )
+        .unwrap();
+        let config = Bm25LifecycleConfig::from_settings(&settings).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.segment_retention_days, 14);
+        assert_eq!(config.grip_retention_days, 7);
+        assert_eq!(config.day_retention_days, 180);
+        assert_eq!(retention_map(&config).get("segment"), Some(&14));
+
+        let mut settings = Bm25LifecycleSettings::default();
+        settings.retention.insert("year".to_string(), 3650);
+        let err = Bm25LifecycleConfig::from_settings(&settings).unwrap_err();
+        assert!(err.contains("protected"));
+
+        let mut settings = Bm25LifecycleSettings::default();
+        settings.retention.insert("decade".to_string(), 1);
+        assert!(Bm25LifecycleConfig::from_settings(&settings).is_err());
+    }
+
     #[test]
     fn test_protected_levels() {
         assert!(is_protected_level("month"));
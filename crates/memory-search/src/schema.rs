@@ -1,18 +1,29 @@
 //! Tantivy schema definition for teleport search.
 //!
-//! Indexes two document types:
+//! Indexes three document types:
 //! - TOC nodes: title + bullets + keywords
 //! - Grips: excerpt text
+//! - Events: raw event text (only when event indexing is enabled)
+//!
+//! English (and unhinted) text goes in `text`, analyzed with the configured
+//! analyzer. Text in another supported language goes in its own
+//! `text_{code}` field so it is stemmed with that language's analyzer.
 
-use tantivy::schema::{Field, Schema, STORED, STRING, TEXT};
+use tantivy::schema::{
+    Field, FieldType, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, FAST, INDEXED,
+    STORED, STRING,
+};
 
+use crate::analyzer::{AnalyzerConfig, Language, SUPPORTED_LANGUAGES};
 use crate::SearchError;
 
 /// Document types stored in the index
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DocType {
     TocNode,
     Grip,
+    /// Raw event text; only returned when searched for explicitly
+    Event,
 }
 
 impl DocType {
@@ -20,6 +31,7 @@ impl DocType {
         match self {
             DocType::TocNode => "toc_node",
             DocType::Grip => "grip",
+            DocType::Event => "event",
         }
     }
 
@@ -28,6 +40,7 @@ impl DocType {
         match s {
             "toc_node" => Some(DocType::TocNode),
             "grip" => Some(DocType::Grip),
+            "event" => Some(DocType::Event),
             _ => None,
         }
     }
@@ -45,9 +58,9 @@ impl std::str::FromStr for DocType {
 #[derive(Debug, Clone)]
 pub struct SearchSchema {
     schema: Schema,
-    /// Document type: "toc_node" or "grip" (STRING | STORED)
+    /// Document type: "toc_node", "grip" or "event" (STRING | STORED)
     pub doc_type: Field,
-    /// Primary key: node_id or grip_id (STRING | STORED)
+    /// Primary key: node_id, grip_id or event_id (STRING | STORED)
     pub doc_id: Field,
     /// TOC level for toc_node: "year", "month", etc. (STRING)
     pub level: Field,
@@ -59,6 +72,14 @@ pub struct SearchSchema {
     pub timestamp_ms: Field,
     /// Agent attribution (STRING | STORED) - from TocNode.contributing_agents
     pub agent: Field,
+    /// Timestamp in milliseconds as an i64 (INDEXED | FAST) for time filters.
+    /// None for indexes created before time filtering.
+    pub time_ms: Option<Field>,
+    /// Document language code, e.g. "en" (STRING | STORED).
+    /// None for indexes created before per-document languages.
+    pub lang: Option<Field>,
+    /// Per-language text fields for non-English documents (TEXT)
+    pub language_text: Vec<(Language, Field)>,
 }
 
 impl SearchSchema {
@@ -67,6 +88,37 @@ impl SearchSchema {
         &self.schema
     }
 
+    /// Tokenizer the text fields were built with.
+    ///
+    /// Acts as the schema fingerprint for analysis: it changes whenever the
+    /// [`AnalyzerConfig`] does, so a mismatch means the index is stale.
+    pub fn analyzer_fingerprint(&self) -> Option<&str> {
+        match self.schema.get_field_entry(self.text).field_type() {
+            FieldType::Str(options) => options
+                .get_indexing_options()
+                .map(|indexing| indexing.tokenizer()),
+            _ => None,
+        }
+    }
+
+    /// Text field for documents in `language`.
+    ///
+    /// English, and languages without a field in this index, use `text`.
+    pub fn text_field_for(&self, language: Language) -> Field {
+        self.language_text
+            .iter()
+            .find(|(l, _)| *l == language)
+            .map(|(_, field)| *field)
+            .unwrap_or(self.text)
+    }
+
+    /// All fields holding searchable document text.
+    pub fn text_fields(&self) -> Vec<Field> {
+        std::iter::once(self.text)
+            .chain(self.language_text.iter().map(|(_, field)| *field))
+            .collect()
+    }
+
     /// Create a SearchSchema from an existing Tantivy Schema
     pub fn from_schema(schema: Schema) -> Result<Self, SearchError> {
         let doc_type = schema
@@ -91,6 +143,20 @@ impl SearchSchema {
             .get_field("agent")
             .map_err(|_| SearchError::SchemaMismatch("missing agent field".into()))?;
 
+        // Language and time fields are optional so older indexes still open
+        let lang = schema.get_field("lang").ok();
+        let time_ms = schema.get_field("time_ms").ok();
+        let language_text = SUPPORTED_LANGUAGES
+            .iter()
+            .filter(|(_, language)| *language != Language::English)
+            .filter_map(|(code, language)| {
+                schema
+                    .get_field(&language_text_field_name(code))
+                    .ok()
+                    .map(|field| (*language, field))
+            })
+            .collect();
+
         Ok(Self {
             schema,
             doc_type,
@@ -100,11 +166,18 @@ impl SearchSchema {
             keywords,
             timestamp_ms,
             agent,
+            time_ms,
+            lang,
+            language_text,
         })
     }
 }
 
-/// Build the teleport search schema.
+fn language_text_field_name(code: &str) -> String {
+    format!("text_{}", code)
+}
+
+/// Build the teleport search schema with the default analyzer.
 ///
 /// Schema fields:
 /// - doc_type: STRING | STORED - "toc_node" or "grip"
@@ -113,9 +186,29 @@ impl SearchSchema {
 /// - text: TEXT - searchable content
 /// - keywords: TEXT | STORED - keywords/tags
 /// - timestamp_ms: STRING | STORED - for recency info
+/// - agent: STRING | STORED - agent attribution
+/// - time_ms: i64 INDEXED | FAST - for time range filters
+/// - lang: STRING | STORED - document language code
+/// - text_{code}: TEXT - searchable content for non-English documents
 pub fn build_teleport_schema() -> SearchSchema {
+    build_teleport_schema_with_analyzer(&AnalyzerConfig::default())
+}
+
+/// Build the teleport search schema with `text` and `keywords` analyzed by
+/// the named tokenizer for `analyzer`.
+///
+/// The analyzer, and [`AnalyzerConfig::for_language`] for each language
+/// field, must be registered on the index (see [`AnalyzerConfig::register`])
+/// before documents are added or queried.
+pub fn build_teleport_schema_with_analyzer(analyzer: &AnalyzerConfig) -> SearchSchema {
     let mut schema_builder = Schema::builder();
 
+    let analyzed = TextOptions::default().set_indexing_options(
+        TextFieldIndexing::default()
+            .set_tokenizer(&analyzer.tokenizer_name())
+            .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+    );
+
     // Document type for filtering: "toc_node" or "grip"
     let doc_type = schema_builder.add_text_field("doc_type", STRING | STORED);
 
@@ -126,10 +219,10 @@ pub fn build_teleport_schema() -> SearchSchema {
     let level = schema_builder.add_text_field("level", STRING | STORED);
 
     // Searchable text content (title + bullets for TOC, excerpt for grip)
-    let text = schema_builder.add_text_field("text", TEXT);
+    let text = schema_builder.add_text_field("text", analyzed.clone());
 
     // Keywords (indexed and stored for retrieval)
-    let keywords = schema_builder.add_text_field("keywords", TEXT | STORED);
+    let keywords = schema_builder.add_text_field("keywords", analyzed | STORED);
 
     // Timestamp for recency (stored as string for simplicity)
     let timestamp_ms = schema_builder.add_text_field("timestamp_ms", STRING | STORED);
@@ -137,6 +230,27 @@ pub fn build_teleport_schema() -> SearchSchema {
     // Agent attribution (from TocNode.contributing_agents)
     let agent = schema_builder.add_text_field("agent", STRING | STORED);
 
+    // Timestamp as a number for range filtering
+    let time_ms = schema_builder.add_i64_field("time_ms", INDEXED | FAST);
+
+    // Document language (for filtering)
+    let lang = schema_builder.add_text_field("lang", STRING | STORED);
+
+    // Searchable text for non-English documents, one field per language
+    let language_text = SUPPORTED_LANGUAGES
+        .iter()
+        .filter(|(_, language)| *language != Language::English)
+        .map(|(code, language)| {
+            let options = TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(&AnalyzerConfig::for_language(*language).tokenizer_name())
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            );
+            let field = schema_builder.add_text_field(&language_text_field_name(code), options);
+            (*language, field)
+        })
+        .collect();
+
     let schema = schema_builder.build();
 
     SearchSchema {
@@ -148,6 +262,9 @@ pub fn build_teleport_schema() -> SearchSchema {
         keywords,
         timestamp_ms,
         agent,
+        time_ms: Some(time_ms),
+        lang: Some(lang),
+        language_text,
     }
 }
 
@@ -173,6 +290,53 @@ mod tests {
         assert!("invalid".parse::<DocType>().is_err());
     }
 
+    #[test]
+    fn test_legacy_schema_still_loads() {
+        use tantivy::schema::TEXT;
+
+        // Indexes created before the analyzer was configurable must still open
+        // with the default analyzer, just without language fields
+        let mut builder = Schema::builder();
+        builder.add_text_field("doc_type", STRING | STORED);
+        builder.add_text_field("doc_id", STRING | STORED);
+        builder.add_text_field("level", STRING | STORED);
+        builder.add_text_field("text", TEXT);
+        builder.add_text_field("keywords", TEXT | STORED);
+        builder.add_text_field("timestamp_ms", STRING | STORED);
+        builder.add_text_field("agent", STRING | STORED);
+        let legacy = builder.build();
+
+        let schema = SearchSchema::from_schema(legacy.clone()).unwrap();
+        assert_eq!(schema.analyzer_fingerprint(), Some("default"));
+        assert_eq!(
+            schema.analyzer_fingerprint(),
+            build_teleport_schema().analyzer_fingerprint()
+        );
+        assert!(schema.lang.is_none());
+        assert!(schema.language_text.is_empty());
+        assert_eq!(schema.text_field_for(Language::French), schema.text);
+
+        // The legacy fields are unchanged in the current schema
+        let current = build_teleport_schema();
+        for (field, entry) in legacy.fields() {
+            assert_eq!(current.schema().get_field_entry(field), entry);
+        }
+    }
+
+    #[test]
+    fn test_analyzer_changes_fingerprint() {
+        let english = AnalyzerConfig::english();
+        let schema = build_teleport_schema_with_analyzer(&english);
+        assert_eq!(
+            schema.analyzer_fingerprint(),
+            Some(english.tokenizer_name().as_str())
+        );
+        assert_ne!(
+            schema.analyzer_fingerprint(),
+            build_teleport_schema().analyzer_fingerprint()
+        );
+    }
+
     #[test]
     fn test_from_schema() {
         let original = build_teleport_schema();
@@ -180,5 +344,20 @@ mod tests {
         assert_eq!(rebuilt.doc_type, original.doc_type);
         assert_eq!(rebuilt.doc_id, original.doc_id);
         assert_eq!(rebuilt.text, original.text);
+        assert_eq!(rebuilt.lang, original.lang);
+        assert_eq!(rebuilt.language_text, original.language_text);
+    }
+
+    #[test]
+    fn test_language_text_fields() {
+        let schema = build_teleport_schema();
+        assert!(schema.lang.is_some());
+        assert_eq!(schema.language_text.len(), SUPPORTED_LANGUAGES.len() - 1);
+        assert_eq!(schema.text_field_for(Language::English), schema.text);
+
+        let french = schema.text_field_for(Language::French);
+        assert_ne!(french, schema.text);
+        assert_eq!(schema.schema().get_field_name(french), "text_fr");
+        assert_eq!(schema.text_fields().len(), SUPPORTED_LANGUAGES.len());
     }
 }
@@ -2,12 +2,18 @@
 //!
 //! Provides keyword search over TOC nodes and grips.
 
-use tantivy::collector::TopDocs;
-use tantivy::query::{BooleanQuery, Occur, QueryParser, TermQuery};
+use std::collections::HashMap;
+use std::ops::Bound;
+use std::sync::Mutex;
+
+use tantivy::collector::{Count, TopDocs};
+use tantivy::index::SegmentComponent;
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, RangeQuery, TermQuery};
 use tantivy::schema::{IndexRecordOption, Value};
-use tantivy::{IndexReader, Term};
+use tantivy::{Directory, IndexReader, ReloadPolicy, Term};
 use tracing::{debug, info};
 
+use crate::analyzer::{language_code, Language};
 use crate::error::SearchError;
 use crate::index::SearchIndex;
 use crate::schema::{DocType, SearchSchema};
@@ -29,13 +35,88 @@ pub struct TeleportResult {
     pub agent: Option<String>,
 }
 
+type BoxedQuery = Box<dyn Query>;
+
+/// Outcome of a successful [`TeleportSearcher::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexVerifyReport {
+    /// Number of searchable segments
+    pub segments: usize,
+    /// Number of segment files whose checksums were validated
+    pub files_checked: usize,
+    /// Live documents counted by the verification query
+    pub num_docs: u64,
+}
+
+/// Why a search returned no results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoResultsReason {
+    /// The index holds no documents
+    IndexEmpty,
+    /// Documents of the searched types matched, but the language or time
+    /// filters excluded all of them
+    AllFilteredOut,
+    /// Documents matched, but every score fell below `min_score`
+    BelowMinScore,
+    /// No document of the searched types matched the query terms
+    NoMatch,
+}
+
+impl NoResultsReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoResultsReason::IndexEmpty => "index_empty",
+            NoResultsReason::AllFilteredOut => "all_filtered_out",
+            NoResultsReason::BelowMinScore => "below_min_score",
+            NoResultsReason::NoMatch => "no_match",
+        }
+    }
+
+    /// One-line explanation for display.
+    pub fn description(&self) -> &'static str {
+        match self {
+            NoResultsReason::IndexEmpty => "the search index is empty",
+            NoResultsReason::AllFilteredOut => "matches exist, but the filters excluded them all",
+            NoResultsReason::BelowMinScore => {
+                "matches exist, but all scored below the minimum score"
+            }
+            NoResultsReason::NoMatch => "no indexed document contains the query terms",
+        }
+    }
+}
+
+impl std::fmt::Display for NoResultsReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Search options for filtering and limiting results.
 #[derive(Debug, Clone, Default)]
 pub struct SearchOptions {
-    /// Filter by document type (None = all types)
+    /// Filter by document type (None = TOC nodes and grips; raw events
+    /// are only searched when requested with [`DocType::Event`])
     pub doc_type: Option<DocType>,
     /// Maximum results to return
     pub limit: usize,
+    /// Filter by document language (None = all languages).
+    /// Ignored for indexes without a lang field.
+    pub lang: Option<Language>,
+    /// MMR lambda for result diversity (None = rank by relevance only).
+    /// Applied by the vector and hybrid paths, which have embeddings to
+    /// compare; BM25-only search ignores it. See
+    /// `memory_service::diversity`.
+    pub diversity: Option<f32>,
+    /// Only match documents at or after this time (ms since epoch)
+    pub start_ms: Option<i64>,
+    /// Only match documents before this time (ms since epoch)
+    pub end_ms: Option<i64>,
+    /// Drop results scoring below this (0.0 = keep all). Raw BM25 scores
+    /// are unbounded and depend on the query and corpus, typically 0-20;
+    /// set `normalize_scores` for a cutoff on a 0-1 scale.
+    pub min_score: f32,
+    /// Divide scores by the top result's score, so the best hit scores 1.0
+    pub normalize_scores: bool,
 }
 
 impl SearchOptions {
@@ -43,6 +124,12 @@ impl SearchOptions {
         Self {
             doc_type: None,
             limit: 10,
+            lang: None,
+            diversity: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         }
     }
 
@@ -56,6 +143,36 @@ impl SearchOptions {
         self
     }
 
+    pub fn with_lang(mut self, lang: Language) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Restrict to documents in `[start_ms, end_ms)`; either bound may be open.
+    pub fn with_time_range(mut self, start_ms: Option<i64>, end_ms: Option<i64>) -> Self {
+        self.start_ms = start_ms;
+        self.end_ms = end_ms;
+        self
+    }
+
+    /// Drop results scoring below `min_score`.
+    pub fn with_min_score(mut self, min_score: f32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Report scores relative to the top result, in [0.0, 1.0].
+    pub fn with_normalized_scores(mut self, normalize: bool) -> Self {
+        self.normalize_scores = normalize;
+        self
+    }
+
+    /// Rerank with MMR; `lambda` in [0.0, 1.0], lower is more diverse.
+    pub fn with_diversity(mut self, lambda: f32) -> Self {
+        self.diversity = Some(lambda.clamp(0.0, 1.0));
+        self
+    }
+
     pub fn toc_only() -> Self {
         Self::new().with_doc_type(DocType::TocNode)
     }
@@ -63,6 +180,10 @@ impl SearchOptions {
     pub fn grips_only() -> Self {
         Self::new().with_doc_type(DocType::Grip)
     }
+
+    pub fn events_only() -> Self {
+        Self::new().with_doc_type(DocType::Event)
+    }
 }
 
 /// Searcher for teleport queries using BM25 ranking.
@@ -70,6 +191,8 @@ pub struct TeleportSearcher {
     reader: IndexReader,
     schema: SearchSchema,
     query_parser: QueryParser,
+    /// Per-type counts and the reader generation they were taken at
+    doc_counts: Mutex<Option<(u64, HashMap<DocType, u64>)>>,
 }
 
 impl TeleportSearcher {
@@ -78,14 +201,17 @@ impl TeleportSearcher {
         let reader = index.reader()?;
         let schema = index.schema().clone();
 
-        // Create query parser targeting text and keywords fields
-        let query_parser =
-            QueryParser::for_index(index.index(), vec![schema.text, schema.keywords]);
+        // Create query parser targeting every text field and keywords; each
+        // field analyzes the query with its own language's analyzer
+        let mut fields = schema.text_fields();
+        fields.push(schema.keywords);
+        let query_parser = QueryParser::for_index(index.index(), fields);
 
         Ok(Self {
             reader,
             schema,
             query_parser,
+            doc_counts: Mutex::new(None),
         })
     }
 
@@ -96,9 +222,25 @@ impl TeleportSearcher {
         Ok(())
     }
 
+    /// Open a searcher and run an empty term lookup in every segment so the
+    /// first real query doesn't pay for faulting in the term dictionaries.
+    pub fn warm_up(&self) -> Result<(), SearchError> {
+        let searcher = self.reader.searcher();
+        let term = Term::from_field_text(self.schema.text, "");
+        let query = TermQuery::new(term, IndexRecordOption::Basic);
+        searcher.search(&query, &TopDocs::with_limit(1))?;
+        debug!(
+            segments = searcher.segment_readers().len(),
+            "Search index warmed up"
+        );
+        Ok(())
+    }
+
     /// Search with a query string.
     ///
-    /// Uses BM25 scoring over text and keywords fields.
+    /// Uses BM25 scoring over text and keywords fields. Indexes created
+    /// before time filtering apply `start_ms`/`end_ms` to the returned page
+    /// instead of the query, so they may return fewer than `limit` results.
     pub fn search(
         &self,
         query_str: &str,
@@ -109,29 +251,25 @@ impl TeleportSearcher {
         }
 
         let searcher = self.reader.searcher();
-
-        // Parse the text query
-        let text_query = self.query_parser.parse_query(query_str)?;
-
-        // Apply document type filter if specified
-        let final_query = if let Some(doc_type) = options.doc_type {
-            let type_term = Term::from_field_text(self.schema.doc_type, doc_type.as_str());
-            let type_query = TermQuery::new(type_term, IndexRecordOption::Basic);
-
-            Box::new(BooleanQuery::new(vec![
-                (Occur::Must, text_query),
-                (Occur::Must, Box::new(type_query)),
-            ]))
-        } else {
-            text_query
-        };
+        let (_, final_query) = self.build_query(query_str, &options)?;
+        let time_filtered = options.start_ms.is_some() || options.end_ms.is_some();
 
         // Execute search
         let top_docs = searcher.search(&final_query, &TopDocs::with_limit(options.limit))?;
 
-        // Map results
+        // Map results, best first, stopping at the score cutoff
+        let top_score = top_docs.first().map_or(0.0, |(score, _)| *score);
         let mut results = Vec::with_capacity(top_docs.len());
         for (score, doc_address) in top_docs {
+            let score = if options.normalize_scores && top_score > 0.0 {
+                score / top_score
+            } else {
+                score
+            };
+            if score < options.min_score {
+                break;
+            }
+
             let doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
 
             // Extract fields
@@ -165,6 +303,16 @@ impl TeleportSearcher {
 
             let doc_type = doc_type_str.parse::<DocType>().unwrap_or(DocType::TocNode);
 
+            if time_filtered && self.schema.time_ms.is_none() {
+                let in_range = timestamp_ms.is_some_and(|ms| {
+                    options.start_ms.is_none_or(|start| ms >= start)
+                        && options.end_ms.is_none_or(|end| ms < end)
+                });
+                if !in_range {
+                    continue;
+                }
+            }
+
             results.push(TeleportResult {
                 doc_id,
                 doc_type,
@@ -184,6 +332,116 @@ impl TeleportSearcher {
         Ok(results)
     }
 
+    /// Parse the query and wrap it in the option filters.
+    ///
+    /// Returns the text query with only the document type filter alongside
+    /// the fully filtered one.
+    fn build_query(
+        &self,
+        query_str: &str,
+        options: &SearchOptions,
+    ) -> Result<(BoxedQuery, BoxedQuery), SearchError> {
+        // Parse the text query
+        let text_query = self.query_parser.parse_query(query_str)?;
+
+        // The document type filter always applies: raw events are excluded
+        // unless requested
+        let mut filters: Vec<(Occur, BoxedQuery)> = Vec::new();
+        match options.doc_type {
+            Some(doc_type) => {
+                let type_term = Term::from_field_text(self.schema.doc_type, doc_type.as_str());
+                filters.push((
+                    Occur::Must,
+                    Box::new(TermQuery::new(type_term, IndexRecordOption::Basic)),
+                ));
+            }
+            None => {
+                let event_term =
+                    Term::from_field_text(self.schema.doc_type, DocType::Event.as_str());
+                filters.push((
+                    Occur::MustNot,
+                    Box::new(TermQuery::new(event_term, IndexRecordOption::Basic)),
+                ));
+            }
+        }
+        let mut typed = vec![(Occur::Must, text_query.box_clone())];
+        typed.extend(filters.iter().map(|(occur, q)| (*occur, q.box_clone())));
+        let typed_query: BoxedQuery = Box::new(BooleanQuery::new(typed));
+
+        // Then the language and time filters
+        if let (Some(lang), Some(lang_field)) = (options.lang, self.schema.lang) {
+            let code = language_code(lang).unwrap_or("en");
+            let lang_term = Term::from_field_text(lang_field, code);
+            filters.push((
+                Occur::Must,
+                Box::new(TermQuery::new(lang_term, IndexRecordOption::Basic)),
+            ));
+        }
+        let time_filtered = options.start_ms.is_some() || options.end_ms.is_some();
+        if let (true, Some(time_field)) = (time_filtered, self.schema.time_ms) {
+            let bound = |ms: Option<i64>, make: fn(Term) -> Bound<Term>| {
+                ms.map_or(Bound::Unbounded, |ms| {
+                    make(Term::from_field_i64(time_field, ms))
+                })
+            };
+            filters.push((
+                Occur::Must,
+                Box::new(RangeQuery::new(
+                    bound(options.start_ms, Bound::Included),
+                    bound(options.end_ms, Bound::Excluded),
+                )),
+            ));
+        }
+
+        filters.insert(0, (Occur::Must, text_query));
+        let final_query: BoxedQuery = Box::new(BooleanQuery::new(filters));
+        Ok((typed_query, final_query))
+    }
+
+    /// Explain why `search` with the same arguments returned nothing.
+    ///
+    /// Compares the index size, the documents of the searched types matching
+    /// the query text and those left after the language and time filters.
+    /// Matches of other types (including raw events, which are excluded
+    /// unless requested) count as no match. When filtered matches remain, the best score decides between
+    /// [`NoResultsReason::BelowMinScore`] and a time filter applied after
+    /// the query on older indexes ([`NoResultsReason::AllFilteredOut`]).
+    pub fn explain_no_results(
+        &self,
+        query_str: &str,
+        options: &SearchOptions,
+    ) -> Result<NoResultsReason, SearchError> {
+        if self.num_docs() == 0 {
+            return Ok(NoResultsReason::IndexEmpty);
+        }
+        if query_str.trim().is_empty() {
+            return Ok(NoResultsReason::NoMatch);
+        }
+
+        let searcher = self.reader.searcher();
+        let (typed_query, final_query) = self.build_query(query_str, options)?;
+
+        let candidates = searcher.search(&typed_query, &Count)?;
+        if candidates == 0 {
+            return Ok(NoResultsReason::NoMatch);
+        }
+        let top_docs = searcher.search(&final_query, &TopDocs::with_limit(1))?;
+        let Some((top_score, _)) = top_docs.first() else {
+            return Ok(NoResultsReason::AllFilteredOut);
+        };
+
+        let top_score = if options.normalize_scores {
+            1.0
+        } else {
+            *top_score
+        };
+        if top_score < options.min_score {
+            Ok(NoResultsReason::BelowMinScore)
+        } else {
+            Ok(NoResultsReason::AllFilteredOut)
+        }
+    }
+
     /// Search TOC nodes only.
     pub fn search_toc(
         &self,
@@ -202,6 +460,89 @@ impl TeleportSearcher {
         self.search(query_str, SearchOptions::grips_only().with_limit(limit))
     }
 
+    /// Search raw events only.
+    pub fn search_events(
+        &self,
+        query_str: &str,
+        limit: usize,
+    ) -> Result<Vec<TeleportResult>, SearchError> {
+        self.search(query_str, SearchOptions::events_only().with_limit(limit))
+    }
+
+    /// Check the on-disk index without modifying it.
+    ///
+    /// Validates that every file of every searchable segment exists and
+    /// matches its checksum, then opens a fresh reader and counts all
+    /// documents. Any failure is reported as [`SearchError::Corrupted`]
+    /// naming the offending file or step.
+    pub fn verify(&self) -> Result<IndexVerifyReport, SearchError> {
+        let searcher = self.reader.searcher();
+        let index = searcher.index();
+
+        let metas = index
+            .searchable_segment_metas()
+            .map_err(|e| SearchError::Corrupted(format!("unreadable segment metadata: {e}")))?;
+        let mut files_checked = 0;
+        for meta in &metas {
+            for component in SegmentComponent::iterator() {
+                let required = match component {
+                    SegmentComponent::TempStore => false,
+                    SegmentComponent::Delete => meta.delete_opstamp().is_some(),
+                    _ => true,
+                };
+                if !required {
+                    continue;
+                }
+                let path = meta.relative_path(*component);
+                let exists = index.directory().exists(&path).map_err(|e| {
+                    SearchError::Corrupted(format!("cannot stat {}: {e}", path.display()))
+                })?;
+                if !exists {
+                    return Err(SearchError::Corrupted(format!(
+                        "missing segment file {}",
+                        path.display()
+                    )));
+                }
+                let valid = index.directory().validate_checksum(&path).map_err(|e| {
+                    SearchError::Corrupted(format!(
+                        "unreadable segment file {}: {e}",
+                        path.display()
+                    ))
+                })?;
+                if !valid {
+                    return Err(SearchError::Corrupted(format!(
+                        "checksum mismatch in {}",
+                        path.display()
+                    )));
+                }
+                files_checked += 1;
+            }
+        }
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(|e: tantivy::TantivyError| {
+                SearchError::Corrupted(format!("cannot open segments: {e}"))
+            })?;
+        let num_docs = reader
+            .searcher()
+            .search(&AllQuery, &Count)
+            .map_err(|e| SearchError::Corrupted(format!("verification query failed: {e}")))?
+            as u64;
+
+        debug!(
+            segments = metas.len(),
+            files_checked, num_docs, "Search index verified"
+        );
+        Ok(IndexVerifyReport {
+            segments: metas.len(),
+            files_checked,
+            num_docs,
+        })
+    }
+
     /// Get the number of indexed documents.
     pub fn num_docs(&self) -> u64 {
         let searcher = self.reader.searcher();
@@ -212,6 +553,38 @@ impl TeleportSearcher {
             .sum()
     }
 
+    /// Generation of the reader's current searcher.
+    ///
+    /// Changes whenever the reader picks up a new commit, including commits
+    /// that replace documents without changing [`num_docs`](Self::num_docs).
+    pub fn generation(&self) -> u64 {
+        self.reader.searcher().generation().generation_id()
+    }
+
+    /// Count indexed documents per document type.
+    ///
+    /// Every type is present in the map, with 0 if none are indexed.
+    /// Counts are cached until the reader picks up a new commit.
+    pub fn doc_counts_by_type(&self) -> Result<HashMap<DocType, u64>, SearchError> {
+        let searcher = self.reader.searcher();
+        let generation = searcher.generation().generation_id();
+        if let Some((cached_at, counts)) = self.doc_counts.lock().unwrap().as_ref() {
+            if *cached_at == generation {
+                return Ok(counts.clone());
+            }
+        }
+
+        let mut counts = HashMap::new();
+        for doc_type in [DocType::TocNode, DocType::Grip, DocType::Event] {
+            let term = Term::from_field_text(self.schema.doc_type, doc_type.as_str());
+            let query = TermQuery::new(term, IndexRecordOption::Basic);
+            let count = searcher.search(&query, &Count)?;
+            counts.insert(doc_type, count as u64);
+        }
+        *self.doc_counts.lock().unwrap() = Some((generation, counts.clone()));
+        Ok(counts)
+    }
+
     /// Count documents older than cutoff timestamps, grouped by level.
     ///
     /// Returns a map of level name -> count of documents with timestamp_ms < cutoff.
@@ -352,6 +725,98 @@ mod tests {
         assert!(results[0].score > 0.0);
     }
 
+    #[test]
+    fn test_search_stemmed() {
+        use crate::analyzer::AnalyzerConfig;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config =
+            SearchIndexConfig::new(temp_dir.path()).with_analyzer(AnalyzerConfig::english());
+        let index = SearchIndex::open_or_create(config).unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        indexer
+            .index_grip(&sample_grip(
+                "grip-1",
+                "The user went running every morning",
+            ))
+            .unwrap();
+        indexer
+            .index_grip(&sample_grip("grip-2", "Discussed database performance"))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let results = searcher.search_grips("run", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, "grip-1");
+
+        // Stopwords are not indexed
+        assert!(searcher.search_grips("the", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_unstemmed_by_default() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_grip(&sample_grip(
+                "grip-1",
+                "The user went running every morning",
+            ))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        assert!(searcher.search_grips("run", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_per_language_analyzers() {
+        use crate::analyzer::AnalyzerConfig;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config =
+            SearchIndexConfig::new(temp_dir.path()).with_analyzer(AnalyzerConfig::english());
+        let index = SearchIndex::open_or_create(config).unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        indexer
+            .index_grip(&sample_grip(
+                "grip-en",
+                "The user went running with the team",
+            ))
+            .unwrap();
+        indexer
+            .index_grip(&sample_grip(
+                "grip-fr",
+                "Les enfants mangeaient des pommes avec nous dans le jardin",
+            ))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+
+        // English stemming: "run" matches "running"
+        let results = searcher.search_grips("run", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, "grip-en");
+
+        // French stemming: "manger" matches "mangeaient"
+        let results = searcher.search_grips("manger", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, "grip-fr");
+
+        // Language filter
+        let french = SearchOptions::grips_only().with_lang(Language::French);
+        let results = searcher.search("manger running", french).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, "grip-fr");
+
+        let english = SearchOptions::grips_only().with_lang(Language::English);
+        assert!(searcher.search("manger", english).unwrap().is_empty());
+    }
+
     #[test]
     fn test_search_grips() {
         let (_temp_dir, index) = setup_index();
@@ -392,6 +857,162 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    #[test]
+    fn test_search_min_score() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        // One strong match and several weak ones
+        indexer
+            .index_toc_node(&sample_toc_node(
+                "node-strong",
+                "Kafka consumer lag",
+                "Kafka partitions and kafka consumer groups",
+            ))
+            .unwrap();
+        for i in 0..4 {
+            indexer
+                .index_toc_node(&sample_toc_node(
+                    &format!("node-weak-{}", i),
+                    "Weekly planning notes",
+                    "Long discussion of roadmap, hiring, budget and one kafka mention",
+                ))
+                .unwrap();
+        }
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let all = searcher
+            .search("kafka consumer", SearchOptions::new().with_limit(10))
+            .unwrap();
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[0].doc_id, "node-strong");
+
+        // Raw cutoff between the strong and weak scores
+        let cutoff = (all[0].score + all[1].score) / 2.0;
+        let raw = searcher
+            .search(
+                "kafka consumer",
+                SearchOptions::new().with_limit(10).with_min_score(cutoff),
+            )
+            .unwrap();
+        assert_eq!(raw.len(), 1);
+        assert_eq!(raw[0].doc_id, "node-strong");
+
+        // Normalized: top result scores 1.0 and weak results fall below 0.9
+        let normalized = searcher
+            .search(
+                "kafka consumer",
+                SearchOptions::new()
+                    .with_limit(10)
+                    .with_normalized_scores(true)
+                    .with_min_score(0.9),
+            )
+            .unwrap();
+        assert_eq!(normalized.len(), 1);
+        assert_eq!(normalized[0].doc_id, "node-strong");
+        assert!((normalized[0].score - 1.0).abs() < f32::EPSILON);
+
+        // A cutoff above every score returns nothing
+        let none = searcher
+            .search(
+                "kafka consumer",
+                SearchOptions::new()
+                    .with_limit(10)
+                    .with_min_score(all[0].score + 1.0),
+            )
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_search_time_range() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        // One node per day across two weeks
+        let now = Utc::now();
+        for day in 0..14 {
+            let mut node =
+                sample_toc_node(&format!("node-{}", day), "Deploy review", "Rollout notes");
+            node.start_time = now - chrono::Duration::days(day);
+            indexer.index_toc_node(&node).unwrap();
+        }
+        let mut old_grip = sample_grip("grip-old", "Rollout rollback");
+        old_grip.timestamp = now - chrono::Duration::days(10);
+        indexer.index_grip(&old_grip).unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let since = (now - chrono::Duration::days(7)).timestamp_millis();
+
+        let results = searcher
+            .search(
+                "rollout",
+                SearchOptions::new()
+                    .with_limit(50)
+                    .with_time_range(Some(since), None),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 8, "days 0-7; the start is inclusive");
+        assert!(results
+            .iter()
+            .all(|r| r.timestamp_ms.unwrap() >= since && r.doc_type == DocType::TocNode));
+
+        // The end is exclusive
+        let results = searcher
+            .search(
+                "rollout",
+                SearchOptions::new()
+                    .with_limit(50)
+                    .with_time_range(None, Some(since)),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 7, "days 8-13 plus the old grip");
+        assert!(results.iter().all(|r| r.timestamp_ms.unwrap() < since));
+    }
+
+    #[test]
+    fn test_search_events_only_when_requested() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        let node = sample_toc_node("node-1", "Deploy review", "Talked about the rollout");
+        let event = memory_types::Event::new(
+            "01HN4QXKN6YWXVKZ3JMHP4BCDE".to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            memory_types::EventType::UserMessage,
+            memory_types::EventRole::User,
+            "The rollout failed with a kafka timeout".to_string(),
+        );
+        indexer.index_toc_node(&node).unwrap();
+        indexer.index_event(&event).unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+
+        // Unfiltered search leaves raw events out
+        let results = searcher
+            .search("rollout", SearchOptions::new().with_limit(10))
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_type, DocType::TocNode);
+
+        let results = searcher.search_events("kafka", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, event.event_id);
+        assert_eq!(results[0].doc_type, DocType::Event);
+        assert_eq!(
+            results[0].timestamp_ms,
+            Some(event.timestamp.timestamp_millis())
+        );
+
+        let counts = searcher.doc_counts_by_type().unwrap();
+        assert_eq!(counts[&DocType::Event], 1);
+        assert!(!indexer.doc_ids().unwrap().contains(&event.event_id));
+    }
+
     #[test]
     fn test_bm25_ranking() {
         let (_temp_dir, index) = setup_index();
@@ -454,6 +1075,129 @@ mod tests {
         assert_eq!(searcher.num_docs(), 2);
     }
 
+    #[test]
+    fn test_generation_changes_when_document_replaced() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Test", "Content"))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let before = searcher.generation();
+        assert_eq!(searcher.generation(), before);
+
+        // Same document ID with new text: the count stays the same
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Test", "Edited content"))
+            .unwrap();
+        indexer.commit().unwrap();
+        searcher.reload().unwrap();
+
+        assert_eq!(searcher.num_docs(), 1);
+        assert_ne!(searcher.generation(), before);
+    }
+
+    #[test]
+    fn test_verify_healthy_index() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Test", "Content"))
+            .unwrap();
+        indexer
+            .index_grip(&sample_grip("grip-1", "Excerpt"))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let report = searcher.verify().unwrap();
+        assert_eq!(report.segments, 1);
+        assert_eq!(report.num_docs, 2);
+        assert!(report.files_checked > 0);
+    }
+
+    #[test]
+    fn test_verify_detects_truncated_segment_file() {
+        let (temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Test", "Content"))
+            .unwrap();
+        indexer.commit().unwrap();
+        let searcher = TeleportSearcher::new(&index).unwrap();
+
+        let term_file = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().is_some_and(|ext| ext == "term"))
+            .unwrap();
+        let len = std::fs::metadata(&term_file).unwrap().len();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&term_file)
+            .unwrap()
+            .set_len(len / 2)
+            .unwrap();
+
+        let err = searcher.verify().unwrap_err();
+        assert!(matches!(err, SearchError::Corrupted(_)));
+        assert!(err.to_string().contains(".term"), "{err}");
+    }
+
+    #[test]
+    fn test_verify_detects_missing_segment_file() {
+        let (temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Test", "Content"))
+            .unwrap();
+        indexer.commit().unwrap();
+        let searcher = TeleportSearcher::new(&index).unwrap();
+
+        let store_file = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().is_some_and(|ext| ext == "store"))
+            .unwrap();
+        std::fs::remove_file(&store_file).unwrap();
+
+        let err = searcher.verify().unwrap_err();
+        assert!(err.to_string().contains("missing segment file"), "{err}");
+    }
+
+    #[test]
+    fn test_doc_counts_by_type() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        let counts = searcher.doc_counts_by_type().unwrap();
+        assert_eq!(counts[&DocType::TocNode], 0);
+        assert_eq!(counts[&DocType::Grip], 0);
+
+        for i in 0..3 {
+            indexer
+                .index_toc_node(&sample_toc_node(&format!("node-{}", i), "Title", "Bullet"))
+                .unwrap();
+        }
+        for i in 0..5 {
+            indexer
+                .index_grip(&sample_grip(&format!("grip-{}", i), "Excerpt"))
+                .unwrap();
+        }
+        // Deleted documents are not counted
+        indexer.delete_document("grip-0").unwrap();
+        indexer.commit().unwrap();
+        searcher.reload().unwrap();
+
+        let counts = searcher.doc_counts_by_type().unwrap();
+        assert_eq!(counts[&DocType::TocNode], 3);
+        assert_eq!(counts[&DocType::Grip], 4);
+        assert_eq!(counts.values().sum::<u64>(), searcher.num_docs());
+    }
+
     #[test]
     fn test_search_options_builder() {
         let options = SearchOptions::new()
@@ -512,6 +1256,26 @@ mod tests {
         searcher.reload().unwrap();
     }
 
+    #[test]
+    fn test_warm_up() {
+        let (_temp_dir, index) = setup_index();
+
+        // Empty index
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        searcher.warm_up().unwrap();
+
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Rust Discussion", "Ownership"))
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let searcher = TeleportSearcher::new(&index).unwrap();
+        searcher.warm_up().unwrap();
+        let results = searcher.search_toc("rust", 10).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
     #[test]
     fn test_no_results_for_nonexistent_term() {
         let (_temp_dir, index) = setup_index();
@@ -526,4 +1290,74 @@ mod tests {
         let results = searcher.search_toc("nonexistentterm12345", 10).unwrap();
         assert!(results.is_empty());
     }
+
+    #[test]
+    fn test_explain_no_results() {
+        let (_temp_dir, index) = setup_index();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        let searcher = TeleportSearcher::new(&index).unwrap();
+
+        let options = SearchOptions::new().with_limit(10);
+        assert_eq!(
+            searcher.explain_no_results("rust", &options).unwrap(),
+            NoResultsReason::IndexEmpty
+        );
+
+        let node = sample_toc_node("node-1", "Rust Discussion", "Talked about ownership");
+        indexer.index_toc_node(&node).unwrap();
+        indexer.commit().unwrap();
+        searcher.reload().unwrap();
+
+        assert_eq!(
+            searcher
+                .explain_no_results("nonexistentterm12345", &options)
+                .unwrap(),
+            NoResultsReason::NoMatch
+        );
+
+        // Only TOC nodes match, so a grip-only search has no match
+        let grips = SearchOptions::grips_only();
+        assert!(searcher.search("rust", grips.clone()).unwrap().is_empty());
+        assert_eq!(
+            searcher.explain_no_results("rust", &grips).unwrap(),
+            NoResultsReason::NoMatch
+        );
+
+        // Raw events are left out unless requested, so matching only an
+        // event is no match either
+        let event = memory_types::Event::new(
+            "01HN4QXKN6YWXVKZ3JMHP4BCDE".to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            memory_types::EventType::UserMessage,
+            memory_types::EventRole::User,
+            "The kafka consumer timed out".to_string(),
+        );
+        indexer.index_event(&event).unwrap();
+        indexer.commit().unwrap();
+        searcher.reload().unwrap();
+        assert!(searcher
+            .search("kafka", options.clone())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            searcher.explain_no_results("kafka", &options).unwrap(),
+            NoResultsReason::NoMatch
+        );
+
+        let future = Utc::now().timestamp_millis() + 3_600_000;
+        let later = options.clone().with_time_range(Some(future), None);
+        assert!(searcher.search("rust", later.clone()).unwrap().is_empty());
+        assert_eq!(
+            searcher.explain_no_results("rust", &later).unwrap(),
+            NoResultsReason::AllFilteredOut
+        );
+
+        let strict = options.clone().with_min_score(1000.0);
+        assert!(searcher.search("rust", strict.clone()).unwrap().is_empty());
+        assert_eq!(
+            searcher.explain_no_results("rust", &strict).unwrap(),
+            NoResultsReason::BelowMinScore
+        );
+    }
 }
@@ -3,23 +3,30 @@
 //! Implements the Phase 23 Agent Discovery RPCs:
 //! - ListAgents: List all contributing agents with summary statistics
 //! - GetAgentActivity: Get agent activity bucketed by time period
+//! - CompareAgents: Partition two agents' top topics into shared and unique sets
 //!
 //! Per R4.3.1, R4.3.2: Cross-agent discovery and activity timeline.
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
 use tonic::{Request, Response, Status};
 use tracing::{debug, info};
 
 use memory_storage::Storage;
-use memory_types::{Event, TocLevel, TocNode};
+use memory_topics::TopicStorage;
+use memory_types::{is_rolling_node_id, Event, TocLevel, TocNode};
 
 use crate::pb::{
-    ActivityBucket, AgentSummary, GetAgentActivityRequest, GetAgentActivityResponse,
-    ListAgentsRequest, ListAgentsResponse,
+    ActivityBucket, AgentSummary, CompareAgentsRequest, CompareAgentsResponse,
+    GetAgentActivityRequest, GetAgentActivityResponse, ListAgentsRequest, ListAgentsResponse,
+    Topic as ProtoTopic,
 };
+use crate::topics::topic_to_proto;
+
+/// Default size of each agent's top-topic set for CompareAgents.
+const DEFAULT_COMPARE_LIMIT: usize = 10;
 
 /// Handler for agent discovery RPCs.
 pub struct AgentDiscoveryHandler {
@@ -99,10 +106,7 @@ impl AgentDiscoveryHandler {
         let req = request.into_inner();
 
         // Validate bucket
-        let bucket = req.bucket.as_str();
-        if bucket != "day" && bucket != "week" {
-            return Err(Status::invalid_argument("bucket must be 'day' or 'week'"));
-        }
+        let bucket: ActivityBucketKind = req.bucket.parse()?;
 
         // Default from_ms to 30 days ago, to_ms to now
         let now_ms = Utc::now().timestamp_millis();
@@ -168,13 +172,88 @@ impl AgentDiscoveryHandler {
 
         debug!(
             bucket_count = buckets.len(),
-            bucket_type = bucket,
+            bucket_type = bucket.as_str(),
             "Agent activity bucketed"
         );
 
         Ok(Response::new(GetAgentActivityResponse { buckets }))
     }
 
+    /// Handle CompareAgents RPC.
+    ///
+    /// Fetches each agent's top-topic set (same ranking as agent-filtered
+    /// GetTopTopics) and partitions the union into shared, only-A and only-B.
+    /// Shared and only-A keep agent A's ranking; only-B keeps agent B's.
+    pub async fn compare_agents(
+        &self,
+        request: Request<CompareAgentsRequest>,
+    ) -> Result<Response<CompareAgentsResponse>, Status> {
+        let req = request.into_inner();
+
+        let agent_a = req.agent_a.trim();
+        let agent_b = req.agent_b.trim();
+        if agent_a.is_empty() || agent_b.is_empty() {
+            return Err(Status::invalid_argument(
+                "agent_a and agent_b must both be provided",
+            ));
+        }
+
+        let limit = if req.limit > 0 {
+            req.limit as usize
+        } else {
+            DEFAULT_COMPARE_LIMIT
+        };
+
+        let topics_a = self.top_topics_for_agent(agent_a, limit)?;
+        let topics_b = self.top_topics_for_agent(agent_b, limit)?;
+
+        let ids_a: HashSet<&str> = topics_a.iter().map(|t| t.id.as_str()).collect();
+        let ids_b: HashSet<&str> = topics_b.iter().map(|t| t.id.as_str()).collect();
+
+        let (shared, only_a): (Vec<ProtoTopic>, Vec<ProtoTopic>) = topics_a
+            .iter()
+            .cloned()
+            .partition(|t| ids_b.contains(t.id.as_str()));
+        let only_b: Vec<ProtoTopic> = topics_b
+            .iter()
+            .filter(|t| !ids_a.contains(t.id.as_str()))
+            .cloned()
+            .collect();
+
+        info!(
+            agent_a = agent_a,
+            agent_b = agent_b,
+            shared = shared.len(),
+            only_a = only_a.len(),
+            only_b = only_b.len(),
+            "CompareAgents complete"
+        );
+
+        Ok(Response::new(CompareAgentsResponse {
+            shared,
+            only_a,
+            only_b,
+        }))
+    }
+
+    /// Top topics an agent has contributed to, as proto topics.
+    #[allow(clippy::result_large_err)]
+    fn top_topics_for_agent(
+        &self,
+        agent_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ProtoTopic>, Status> {
+        let topic_storage = TopicStorage::new(self.storage.clone());
+        let topics = topic_storage
+            .get_topics_for_agent(&self.storage, agent_id, limit)
+            .map_err(|e| Status::internal(format!("Failed to get topics for agent: {}", e)))?;
+
+        Ok(topics
+            .into_iter()
+            .map(|(topic, _relevance)| topic_to_proto(topic))
+            .collect())
+    }
+
     /// Count distinct session_ids per agent from events (bounded to last 365 days).
     ///
     /// Returns a map of agent_id -> session count.
@@ -227,7 +306,12 @@ impl AgentDiscoveryHandler {
                 .storage
                 .get_toc_nodes_by_level(*level, None, None)
                 .map_err(|e| e.to_string())?;
-            all_nodes.extend(nodes);
+            // Rolling windows repeat calendar content and would double count
+            all_nodes.extend(
+                nodes
+                    .into_iter()
+                    .filter(|n| !is_rolling_node_id(&n.node_id)),
+            );
         }
         Ok(all_nodes)
     }
@@ -260,55 +344,100 @@ struct ActivityBucketBuilder {
     agent_id: String,
 }
 
+/// Time-bucket granularity for GetAgentActivity.
+///
+/// All buckets are computed in UTC and aligned to calendar boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityBucketKind {
+    /// Truncate to the start of the hour.
+    Hour,
+    /// Truncate to midnight.
+    Day,
+    /// Truncate to the ISO week start (Monday).
+    Week,
+    /// Truncate to the first day of the calendar month.
+    Month,
+}
+
+impl ActivityBucketKind {
+    /// Wire name of this bucket kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityBucketKind::Hour => "hour",
+            ActivityBucketKind::Day => "day",
+            ActivityBucketKind::Week => "week",
+            ActivityBucketKind::Month => "month",
+        }
+    }
+}
+
+impl std::str::FromStr for ActivityBucketKind {
+    type Err = Status;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hour" => Ok(ActivityBucketKind::Hour),
+            "day" => Ok(ActivityBucketKind::Day),
+            "week" => Ok(ActivityBucketKind::Week),
+            "month" => Ok(ActivityBucketKind::Month),
+            other => Err(Status::invalid_argument(format!(
+                "bucket must be one of 'hour', 'day', 'week', 'month' (got '{}')",
+                other
+            ))),
+        }
+    }
+}
+
 /// Compute the bucket start and end timestamps for a given event timestamp.
 ///
-/// For "day": truncates to date boundary (UTC).
-/// For "week": truncates to ISO week start (Monday, UTC).
-fn compute_bucket(timestamp_ms: i64, bucket: &str) -> (i64, i64) {
+/// The end is exclusive and equals the start of the next bucket, so months
+/// of differing lengths (and leap years) are handled by calendar arithmetic
+/// rather than a fixed duration.
+fn compute_bucket(timestamp_ms: i64, bucket: ActivityBucketKind) -> (i64, i64) {
     let dt = DateTime::<Utc>::from_timestamp_millis(timestamp_ms)
         .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
     let date = dt.date_naive();
 
-    match bucket {
-        "week" => {
+    let (start, end) = match bucket {
+        ActivityBucketKind::Hour => {
+            let start = date.and_hms_opt(dt.hour(), 0, 0).unwrap();
+            (start, start + chrono::Duration::hours(1))
+        }
+        ActivityBucketKind::Day => {
+            let start = date.and_hms_opt(0, 0, 0).unwrap();
+            (start, start + chrono::Duration::days(1))
+        }
+        ActivityBucketKind::Week => {
             // ISO week start = Monday
             let days_from_monday = date.weekday().num_days_from_monday();
             let monday = date - chrono::Duration::days(days_from_monday as i64);
-            let sunday = monday + chrono::Duration::days(7);
-            let start = monday
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc()
-                .timestamp_millis();
-            let end = sunday
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc()
-                .timestamp_millis();
-            (start, end)
+            let start = monday.and_hms_opt(0, 0, 0).unwrap();
+            (start, start + chrono::Duration::days(7))
         }
-        _ => {
-            // "day" bucket
-            let start = date
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc()
-                .timestamp_millis();
-            let next_day = date + chrono::Duration::days(1);
-            let end = next_day
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc()
-                .timestamp_millis();
-            (start, end)
+        ActivityBucketKind::Month => {
+            let first = NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap();
+            let next_first = if date.month() == 12 {
+                NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).unwrap()
+            } else {
+                NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1).unwrap()
+            };
+            (
+                first.and_hms_opt(0, 0, 0).unwrap(),
+                next_first.and_hms_opt(0, 0, 0).unwrap(),
+            )
         }
-    }
+    };
+
+    (
+        start.and_utc().timestamp_millis(),
+        end.and_utc().timestamp_millis(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{NaiveDate, TimeZone};
+    use chrono::TimeZone;
     use tempfile::TempDir;
 
     fn create_test_handler() -> (AgentDiscoveryHandler, Arc<Storage>, TempDir) {
@@ -622,7 +751,7 @@ mod tests {
                 agent_id: None,
                 from_ms: None,
                 to_ms: None,
-                bucket: "month".to_string(),
+                bucket: "fortnight".to_string(),
             }))
             .await;
 
@@ -633,7 +762,7 @@ mod tests {
     #[test]
     fn test_compute_bucket_day() {
         // 2024-02-08 12:00:00 UTC = 1707393600000
-        let (start, end) = compute_bucket(1707393600000, "day");
+        let (start, end) = compute_bucket(1707393600000, ActivityBucketKind::Day);
 
         // Should be midnight to midnight
         let expected_start = NaiveDate::from_ymd_opt(2024, 2, 8)
@@ -656,7 +785,7 @@ mod tests {
     #[test]
     fn test_compute_bucket_week() {
         // 2024-02-08 (Thursday) 12:00 UTC = 1707393600000
-        let (start, end) = compute_bucket(1707393600000, "week");
+        let (start, end) = compute_bucket(1707393600000, ActivityBucketKind::Week);
 
         // Week should start on Monday 2024-02-05
         let expected_start = NaiveDate::from_ymd_opt(2024, 2, 5)
@@ -677,6 +806,292 @@ mod tests {
         assert_eq!(end, expected_end);
     }
 
+    #[tokio::test]
+    async fn test_get_agent_activity_month_buckets_across_boundary() {
+        let (handler, storage, _temp) = create_test_handler();
+
+        let ms = |y, m, d, h| {
+            Utc.with_ymd_and_hms(y, m, d, h, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+
+        // Leap-year February (29 days) spilling into March
+        let events = vec![
+            create_test_event("sess-1", ms(2024, 2, 1, 0), Some("claude")),
+            create_test_event("sess-1", ms(2024, 2, 29, 23), Some("claude")),
+            create_test_event("sess-2", ms(2024, 3, 1, 0), Some("claude")),
+            create_test_event("sess-2", ms(2024, 3, 31, 12), Some("claude")),
+            create_test_event("sess-3", ms(2024, 3, 15, 8), Some("opencode")),
+        ];
+
+        for event in &events {
+            let bytes = event.to_bytes().unwrap();
+            let outbox =
+                memory_types::OutboxEntry::for_toc(event.event_id.clone(), event.timestamp_ms());
+            let outbox_bytes = outbox.to_bytes().unwrap();
+            storage
+                .put_event(&event.event_id, &bytes, &outbox_bytes)
+                .unwrap();
+        }
+
+        let response = handler
+            .get_agent_activity(Request::new(GetAgentActivityRequest {
+                agent_id: None,
+                from_ms: Some(ms(2024, 2, 1, 0)),
+                to_ms: Some(ms(2024, 4, 1, 0)),
+                bucket: "month".to_string(),
+            }))
+            .await
+            .unwrap();
+
+        let resp = response.into_inner();
+        assert_eq!(resp.buckets.len(), 3);
+
+        let feb = &resp.buckets[0];
+        assert_eq!(feb.agent_id, "claude");
+        assert_eq!(feb.event_count, 2);
+        assert_eq!(feb.start_ms, ms(2024, 2, 1, 0));
+        assert_eq!(feb.end_ms, ms(2024, 3, 1, 0));
+
+        // March buckets share a start and are ordered by agent_id
+        let mar_claude = &resp.buckets[1];
+        assert_eq!(mar_claude.agent_id, "claude");
+        assert_eq!(mar_claude.event_count, 2);
+        assert_eq!(mar_claude.start_ms, ms(2024, 3, 1, 0));
+        assert_eq!(mar_claude.end_ms, ms(2024, 4, 1, 0));
+
+        let mar_opencode = &resp.buckets[2];
+        assert_eq!(mar_opencode.agent_id, "opencode");
+        assert_eq!(mar_opencode.event_count, 1);
+        assert_eq!(mar_opencode.start_ms, ms(2024, 3, 1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_get_agent_activity_week_aligns_to_iso_week_across_month() {
+        let (handler, storage, _temp) = create_test_handler();
+
+        let ms = |y, m, d| {
+            Utc.with_ymd_and_hms(y, m, d, 12, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+
+        // 2024-01-29 (Mon) .. 2024-02-04 (Sun) is ISO week 5, straddling the month
+        let events = vec![
+            create_test_event("sess-1", ms(2024, 1, 31), Some("claude")), // Wed
+            create_test_event("sess-1", ms(2024, 2, 4), Some("claude")),  // Sun
+            create_test_event("sess-2", ms(2024, 2, 5), Some("claude")),  // Mon, week 6
+        ];
+
+        for event in &events {
+            let bytes = event.to_bytes().unwrap();
+            let outbox =
+                memory_types::OutboxEntry::for_toc(event.event_id.clone(), event.timestamp_ms());
+            let outbox_bytes = outbox.to_bytes().unwrap();
+            storage
+                .put_event(&event.event_id, &bytes, &outbox_bytes)
+                .unwrap();
+        }
+
+        let response = handler
+            .get_agent_activity(Request::new(GetAgentActivityRequest {
+                agent_id: None,
+                from_ms: Some(ms(2024, 1, 29)),
+                to_ms: Some(ms(2024, 2, 12)),
+                bucket: "week".to_string(),
+            }))
+            .await
+            .unwrap();
+
+        let resp = response.into_inner();
+        assert_eq!(resp.buckets.len(), 2);
+
+        for bucket in &resp.buckets {
+            let start = DateTime::<Utc>::from_timestamp_millis(bucket.start_ms).unwrap();
+            assert_eq!(start.weekday(), chrono::Weekday::Mon);
+            assert_eq!(bucket.end_ms - bucket.start_ms, 7 * 24 * 60 * 60 * 1000);
+        }
+
+        let week5 = &resp.buckets[0];
+        assert_eq!(week5.event_count, 2);
+        let week5_start = DateTime::<Utc>::from_timestamp_millis(week5.start_ms).unwrap();
+        assert_eq!(week5_start.iso_week().week(), 5);
+        assert_eq!(
+            week5_start.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 1, 29).unwrap()
+        );
+
+        let week6 = &resp.buckets[1];
+        assert_eq!(week6.event_count, 1);
+        let week6_start = DateTime::<Utc>::from_timestamp_millis(week6.start_ms).unwrap();
+        assert_eq!(week6_start.iso_week().week(), 6);
+    }
+
+    #[test]
+    fn test_bucket_kind_parse() {
+        for kind in [
+            ActivityBucketKind::Hour,
+            ActivityBucketKind::Day,
+            ActivityBucketKind::Week,
+            ActivityBucketKind::Month,
+        ] {
+            assert_eq!(kind.as_str().parse::<ActivityBucketKind>().unwrap(), kind);
+        }
+
+        let err = "Month".parse::<ActivityBucketKind>().unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!("".parse::<ActivityBucketKind>().is_err());
+    }
+
+    #[test]
+    fn test_compute_bucket_hour() {
+        // 2024-02-08 12:34:56 UTC
+        let ts = Utc
+            .with_ymd_and_hms(2024, 2, 8, 12, 34, 56)
+            .unwrap()
+            .timestamp_millis();
+        let (start, end) = compute_bucket(ts, ActivityBucketKind::Hour);
+
+        let expected_start = Utc
+            .with_ymd_and_hms(2024, 2, 8, 12, 0, 0)
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(start, expected_start);
+        assert_eq!(end, expected_start + 60 * 60 * 1000);
+    }
+
+    #[test]
+    fn test_compute_bucket_month_lengths() {
+        let month_start = |y, m| {
+            Utc.with_ymd_and_hms(y, m, 1, 0, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+
+        // Leap-year February
+        let ts = Utc
+            .with_ymd_and_hms(2024, 2, 29, 23, 59, 59)
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(
+            compute_bucket(ts, ActivityBucketKind::Month),
+            (month_start(2024, 2), month_start(2024, 3))
+        );
+
+        // 30-day month
+        let ts = Utc
+            .with_ymd_and_hms(2024, 4, 30, 0, 0, 0)
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(
+            compute_bucket(ts, ActivityBucketKind::Month),
+            (month_start(2024, 4), month_start(2024, 5))
+        );
+
+        // December rolls over into the next year
+        let ts = Utc
+            .with_ymd_and_hms(2024, 12, 31, 12, 0, 0)
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(
+            compute_bucket(ts, ActivityBucketKind::Month),
+            (month_start(2024, 12), month_start(2025, 1))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compare_agents_partitions_topics() {
+        let (handler, storage, _temp) = create_test_handler();
+        let topic_storage = TopicStorage::new(storage.clone());
+
+        // claude: rust, tokio, grpc; opencode: grpc, tokio, react
+        let topics = [
+            ("t-rust", "Rust", 0.9, &["claude"][..]),
+            ("t-tokio", "Tokio", 0.8, &["claude", "opencode"][..]),
+            ("t-grpc", "gRPC", 0.7, &["opencode", "claude"][..]),
+            ("t-react", "React", 0.6, &["opencode"][..]),
+        ];
+
+        for (id, label, importance, agents) in topics {
+            let now = Utc::now();
+            let topic = memory_topics::Topic {
+                topic_id: id.to_string(),
+                label: label.to_string(),
+                embedding: vec![0.1, 0.2],
+                importance_score: importance,
+                node_count: 1,
+                created_at: now,
+                last_mentioned_at: now,
+                status: memory_topics::TopicStatus::Active,
+                keywords: vec![],
+            };
+            topic_storage.save_topic(&topic).unwrap();
+
+            let node_id = format!("node-{}", id);
+            let mut node =
+                TocNode::new(node_id.clone(), TocLevel::Day, label.to_string(), now, now);
+            for agent in agents {
+                node = node.with_contributing_agent(*agent);
+            }
+            storage.put_toc_node(&node).unwrap();
+            topic_storage
+                .save_link(&memory_topics::TopicLink::new(id.to_string(), node_id, 1.0))
+                .unwrap();
+        }
+
+        let resp = handler
+            .compare_agents(Request::new(CompareAgentsRequest {
+                agent_a: "claude".to_string(),
+                agent_b: "opencode".to_string(),
+                limit: 10,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let ids = |topics: &[ProtoTopic]| -> Vec<String> {
+            topics.iter().map(|t| t.id.clone()).collect()
+        };
+        assert_eq!(ids(&resp.shared), vec!["t-tokio", "t-grpc"]);
+        assert_eq!(ids(&resp.only_a), vec!["t-rust"]);
+        assert_eq!(ids(&resp.only_b), vec!["t-react"]);
+    }
+
+    #[tokio::test]
+    async fn test_compare_agents_unknown_agent() {
+        let (handler, _, _temp) = create_test_handler();
+
+        let resp = handler
+            .compare_agents(Request::new(CompareAgentsRequest {
+                agent_a: "claude".to_string(),
+                agent_b: "nobody".to_string(),
+                limit: 0,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert!(resp.shared.is_empty());
+        assert!(resp.only_a.is_empty());
+        assert!(resp.only_b.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compare_agents_requires_both_ids() {
+        let (handler, _, _temp) = create_test_handler();
+
+        let result = handler
+            .compare_agents(Request::new(CompareAgentsRequest {
+                agent_a: "claude".to_string(),
+                agent_b: "  ".to_string(),
+                limit: 10,
+            }))
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
     /// Helper to create test events with known timestamps and agents.
     /// Uses ULID-based event IDs as required by storage layer.
     fn create_test_event(session_id: &str, timestamp_ms: i64, agent: Option<&str>) -> Event {
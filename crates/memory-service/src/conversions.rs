@@ -0,0 +1,558 @@
+//! Conversions between proto enums and domain enums.
+//!
+//! Proto enums travel as raw `i32` values and carry an `UNSPECIFIED` variant
+//! that has no domain counterpart. This module centralizes the mapping so
+//! handlers and CLI output don't each hand-roll their own `match` blocks:
+//!
+//! - `From<Domain> for Proto` is infallible.
+//! - `TryFrom<Proto> for Domain` fails on `Unspecified` with [`ConversionError`].
+//! - [`ProtoEnumName::as_str`] gives the canonical lowercase name of a proto
+//!   variant, and [`ProtoEnumName::name_of`] does the same for a raw `i32`.
+
+use thiserror::Error;
+use tonic::Status;
+
+use memory_retrieval::types::{
+    CapabilityTier as DomainTier, ExecutionMode as DomainExecMode, QueryIntent as DomainIntent,
+    RetrievalLayer as DomainLayer,
+};
+use memory_search::NoResultsReason as DomainNoResultsReason;
+use memory_toc::search::SearchField as DomainSearchField;
+use memory_types::TocLevel as DomainTocLevel;
+
+use crate::pb::{
+    CapabilityTier as ProtoTier, EventType as ProtoEventType, ExecutionMode as ProtoExecMode,
+    HybridMode as ProtoHybridMode, NoResultsReason as ProtoNoResultsReason,
+    QueryIntent as ProtoIntent, RetrievalLayer as ProtoLayer, SearchField as ProtoSearchField,
+    TocLevel as ProtoTocLevel,
+};
+
+/// Name returned for `i32` values that don't decode to a known variant.
+pub const UNKNOWN_NAME: &str = "unknown";
+
+/// Error converting a proto enum value to its domain counterpart.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{enum_name} has no domain value for {value}")]
+pub struct ConversionError {
+    /// Name of the proto enum
+    pub enum_name: &'static str,
+    /// Raw proto value that could not be converted
+    pub value: i32,
+}
+
+impl ConversionError {
+    fn new(enum_name: &'static str, value: i32) -> Self {
+        Self { enum_name, value }
+    }
+}
+
+impl From<ConversionError> for Status {
+    fn from(err: ConversionError) -> Self {
+        Status::invalid_argument(err.to_string())
+    }
+}
+
+/// Canonical names for proto enum variants.
+pub trait ProtoEnumName: Sized + TryFrom<i32> {
+    /// Canonical lowercase name of this variant.
+    fn as_str(&self) -> &'static str;
+
+    /// Name for a raw proto value, or [`UNKNOWN_NAME`] if it doesn't decode.
+    fn name_of(value: i32) -> &'static str {
+        Self::try_from(value)
+            .map(|v| v.as_str())
+            .unwrap_or(UNKNOWN_NAME)
+    }
+}
+
+/// Decode a raw proto value straight into its domain enum.
+pub fn domain_from_i32<P, D>(value: i32) -> Result<D, ConversionError>
+where
+    P: TryFrom<i32>,
+    D: TryFrom<P, Error = ConversionError>,
+{
+    let proto = P::try_from(value).map_err(|_| {
+        let enum_name = std::any::type_name::<P>();
+        ConversionError::new(enum_name.rsplit("::").next().unwrap_or(enum_name), value)
+    })?;
+    D::try_from(proto)
+}
+
+// ===== TocLevel =====
+
+impl From<DomainTocLevel> for ProtoTocLevel {
+    fn from(level: DomainTocLevel) -> Self {
+        match level {
+            DomainTocLevel::Year => ProtoTocLevel::Year,
+            DomainTocLevel::Month => ProtoTocLevel::Month,
+            DomainTocLevel::Week => ProtoTocLevel::Week,
+            DomainTocLevel::Day => ProtoTocLevel::Day,
+            DomainTocLevel::Segment => ProtoTocLevel::Segment,
+        }
+    }
+}
+
+impl TryFrom<ProtoTocLevel> for DomainTocLevel {
+    type Error = ConversionError;
+
+    fn try_from(level: ProtoTocLevel) -> Result<Self, Self::Error> {
+        match level {
+            ProtoTocLevel::Year => Ok(DomainTocLevel::Year),
+            ProtoTocLevel::Month => Ok(DomainTocLevel::Month),
+            ProtoTocLevel::Week => Ok(DomainTocLevel::Week),
+            ProtoTocLevel::Day => Ok(DomainTocLevel::Day),
+            ProtoTocLevel::Segment => Ok(DomainTocLevel::Segment),
+            ProtoTocLevel::Unspecified => Err(ConversionError::new("TocLevel", level as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoTocLevel {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProtoTocLevel::Unspecified => "unspecified",
+            ProtoTocLevel::Year => "year",
+            ProtoTocLevel::Month => "month",
+            ProtoTocLevel::Week => "week",
+            ProtoTocLevel::Day => "day",
+            ProtoTocLevel::Segment => "segment",
+        }
+    }
+}
+
+// ===== SearchField =====
+
+impl From<DomainSearchField> for ProtoSearchField {
+    fn from(field: DomainSearchField) -> Self {
+        match field {
+            DomainSearchField::Title => ProtoSearchField::Title,
+            DomainSearchField::Summary => ProtoSearchField::Summary,
+            DomainSearchField::Bullets => ProtoSearchField::Bullets,
+            DomainSearchField::Keywords => ProtoSearchField::Keywords,
+        }
+    }
+}
+
+impl TryFrom<ProtoSearchField> for DomainSearchField {
+    type Error = ConversionError;
+
+    fn try_from(field: ProtoSearchField) -> Result<Self, Self::Error> {
+        match field {
+            ProtoSearchField::Title => Ok(DomainSearchField::Title),
+            ProtoSearchField::Summary => Ok(DomainSearchField::Summary),
+            ProtoSearchField::Bullets => Ok(DomainSearchField::Bullets),
+            ProtoSearchField::Keywords => Ok(DomainSearchField::Keywords),
+            ProtoSearchField::Unspecified => Err(ConversionError::new("SearchField", field as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoSearchField {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProtoSearchField::Unspecified => "unspecified",
+            ProtoSearchField::Title => "title",
+            ProtoSearchField::Summary => "summary",
+            ProtoSearchField::Bullets => "bullets",
+            ProtoSearchField::Keywords => "keywords",
+        }
+    }
+}
+
+// ===== QueryIntent =====
+
+impl From<DomainIntent> for ProtoIntent {
+    fn from(intent: DomainIntent) -> Self {
+        match intent {
+            DomainIntent::Explore => ProtoIntent::Explore,
+            DomainIntent::Answer => ProtoIntent::Answer,
+            DomainIntent::Locate => ProtoIntent::Locate,
+            DomainIntent::TimeBoxed => ProtoIntent::TimeBoxed,
+        }
+    }
+}
+
+impl TryFrom<ProtoIntent> for DomainIntent {
+    type Error = ConversionError;
+
+    fn try_from(intent: ProtoIntent) -> Result<Self, Self::Error> {
+        match intent {
+            ProtoIntent::Explore => Ok(DomainIntent::Explore),
+            ProtoIntent::Answer => Ok(DomainIntent::Answer),
+            ProtoIntent::Locate => Ok(DomainIntent::Locate),
+            ProtoIntent::TimeBoxed => Ok(DomainIntent::TimeBoxed),
+            ProtoIntent::Unspecified => Err(ConversionError::new("QueryIntent", intent as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoIntent {
+    fn as_str(&self) -> &'static str {
+        match DomainIntent::try_from(*self) {
+            Ok(intent) => intent.as_str(),
+            Err(_) => "unspecified",
+        }
+    }
+}
+
+// ===== CapabilityTier =====
+
+impl From<DomainTier> for ProtoTier {
+    fn from(tier: DomainTier) -> Self {
+        match tier {
+            DomainTier::Full => ProtoTier::Full,
+            DomainTier::Hybrid => ProtoTier::Hybrid,
+            DomainTier::Semantic => ProtoTier::Semantic,
+            DomainTier::Keyword => ProtoTier::Keyword,
+            DomainTier::Agentic => ProtoTier::Agentic,
+        }
+    }
+}
+
+impl TryFrom<ProtoTier> for DomainTier {
+    type Error = ConversionError;
+
+    fn try_from(tier: ProtoTier) -> Result<Self, Self::Error> {
+        match tier {
+            ProtoTier::Full => Ok(DomainTier::Full),
+            ProtoTier::Hybrid => Ok(DomainTier::Hybrid),
+            ProtoTier::Semantic => Ok(DomainTier::Semantic),
+            ProtoTier::Keyword => Ok(DomainTier::Keyword),
+            ProtoTier::Agentic => Ok(DomainTier::Agentic),
+            ProtoTier::Unspecified => Err(ConversionError::new("CapabilityTier", tier as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoTier {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProtoTier::Unspecified => "unspecified",
+            ProtoTier::Full => "full",
+            ProtoTier::Hybrid => "hybrid",
+            ProtoTier::Semantic => "semantic",
+            ProtoTier::Keyword => "keyword",
+            ProtoTier::Agentic => "agentic",
+        }
+    }
+}
+
+// ===== ExecutionMode =====
+
+impl From<DomainExecMode> for ProtoExecMode {
+    fn from(mode: DomainExecMode) -> Self {
+        match mode {
+            DomainExecMode::Sequential => ProtoExecMode::Sequential,
+            DomainExecMode::Parallel => ProtoExecMode::Parallel,
+            DomainExecMode::Hybrid => ProtoExecMode::Hybrid,
+        }
+    }
+}
+
+impl TryFrom<ProtoExecMode> for DomainExecMode {
+    type Error = ConversionError;
+
+    fn try_from(mode: ProtoExecMode) -> Result<Self, Self::Error> {
+        match mode {
+            ProtoExecMode::Sequential => Ok(DomainExecMode::Sequential),
+            ProtoExecMode::Parallel => Ok(DomainExecMode::Parallel),
+            ProtoExecMode::Hybrid => Ok(DomainExecMode::Hybrid),
+            ProtoExecMode::Unspecified => Err(ConversionError::new("ExecutionMode", mode as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoExecMode {
+    fn as_str(&self) -> &'static str {
+        match DomainExecMode::try_from(*self) {
+            Ok(mode) => mode.as_str(),
+            Err(_) => "unspecified",
+        }
+    }
+}
+
+// ===== RetrievalLayer =====
+
+impl From<DomainLayer> for ProtoLayer {
+    fn from(layer: DomainLayer) -> Self {
+        match layer {
+            DomainLayer::Topics => ProtoLayer::Topics,
+            DomainLayer::Hybrid => ProtoLayer::Hybrid,
+            DomainLayer::Vector => ProtoLayer::Vector,
+            DomainLayer::BM25 => ProtoLayer::Bm25,
+            DomainLayer::Agentic => ProtoLayer::Agentic,
+        }
+    }
+}
+
+impl TryFrom<ProtoLayer> for DomainLayer {
+    type Error = ConversionError;
+
+    fn try_from(layer: ProtoLayer) -> Result<Self, Self::Error> {
+        match layer {
+            ProtoLayer::Topics => Ok(DomainLayer::Topics),
+            ProtoLayer::Hybrid => Ok(DomainLayer::Hybrid),
+            ProtoLayer::Vector => Ok(DomainLayer::Vector),
+            ProtoLayer::Bm25 => Ok(DomainLayer::BM25),
+            ProtoLayer::Agentic => Ok(DomainLayer::Agentic),
+            ProtoLayer::Unspecified => Err(ConversionError::new("RetrievalLayer", layer as i32)),
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoLayer {
+    fn as_str(&self) -> &'static str {
+        match DomainLayer::try_from(*self) {
+            Ok(layer) => layer.as_str(),
+            Err(_) => "unspecified",
+        }
+    }
+}
+
+// ===== NoResultsReason =====
+
+impl From<DomainNoResultsReason> for ProtoNoResultsReason {
+    fn from(reason: DomainNoResultsReason) -> Self {
+        match reason {
+            DomainNoResultsReason::IndexEmpty => ProtoNoResultsReason::IndexEmpty,
+            DomainNoResultsReason::AllFilteredOut => ProtoNoResultsReason::AllFilteredOut,
+            DomainNoResultsReason::BelowMinScore => ProtoNoResultsReason::BelowMinScore,
+            DomainNoResultsReason::NoMatch => ProtoNoResultsReason::NoMatch,
+        }
+    }
+}
+
+impl TryFrom<ProtoNoResultsReason> for DomainNoResultsReason {
+    type Error = ConversionError;
+
+    fn try_from(reason: ProtoNoResultsReason) -> Result<Self, Self::Error> {
+        match reason {
+            ProtoNoResultsReason::IndexEmpty => Ok(DomainNoResultsReason::IndexEmpty),
+            ProtoNoResultsReason::AllFilteredOut => Ok(DomainNoResultsReason::AllFilteredOut),
+            ProtoNoResultsReason::BelowMinScore => Ok(DomainNoResultsReason::BelowMinScore),
+            ProtoNoResultsReason::NoMatch => Ok(DomainNoResultsReason::NoMatch),
+            ProtoNoResultsReason::Unspecified => {
+                Err(ConversionError::new("NoResultsReason", reason as i32))
+            }
+        }
+    }
+}
+
+impl ProtoEnumName for ProtoNoResultsReason {
+    fn as_str(&self) -> &'static str {
+        match DomainNoResultsReason::try_from(*self) {
+            Ok(reason) => reason.as_str(),
+            Err(_) => "unspecified",
+        }
+    }
+}
+
+// ===== HybridMode =====
+//
+// HybridMode has no domain enum; the CLI and handlers work with the proto
+// type directly, so only names are provided.
+
+impl ProtoEnumName for ProtoHybridMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProtoHybridMode::Unspecified => "unspecified",
+            ProtoHybridMode::VectorOnly => "vector-only",
+            ProtoHybridMode::Bm25Only => "bm25-only",
+            ProtoHybridMode::Hybrid => "hybrid",
+        }
+    }
+}
+
+impl ProtoHybridMode {
+    /// Parse a CLI-style mode name ("vector-only", "bm25", "hybrid", ...).
+    ///
+    /// Unrecognized names map to `Unspecified`, which the server treats as hybrid.
+    pub fn from_name(name: &str) -> Self {
+        match name.to_lowercase().as_str() {
+            "vector-only" | "vector" => ProtoHybridMode::VectorOnly,
+            "bm25-only" | "bm25" => ProtoHybridMode::Bm25Only,
+            "hybrid" => ProtoHybridMode::Hybrid,
+            _ => ProtoHybridMode::Unspecified,
+        }
+    }
+}
+
+// ===== EventType =====
+//
+// Domain conversion lives with the event mapping in `query`; this section
+// covers names for CLI filters.
+
+impl ProtoEnumName for ProtoEventType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProtoEventType::Unspecified => "unspecified",
+            ProtoEventType::SessionStart => "session_start",
+            ProtoEventType::UserMessage => "user_message",
+            ProtoEventType::AssistantMessage => "assistant_message",
+            ProtoEventType::ToolResult => "tool_result",
+            ProtoEventType::AssistantStop => "assistant_stop",
+            ProtoEventType::SubagentStart => "subagent_start",
+            ProtoEventType::SubagentStop => "subagent_stop",
+            ProtoEventType::SessionEnd => "session_end",
+        }
+    }
+}
+
+impl ProtoEventType {
+    /// Parse a CLI-style type name ("user", "assistant", "tool_result", ...).
+    ///
+    /// Accepts the canonical names plus the short forms "user", "assistant"
+    /// and "tool", with `-` or `_` separators. Returns None for unknown names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "session_start" => Some(ProtoEventType::SessionStart),
+            "user" | "user_message" => Some(ProtoEventType::UserMessage),
+            "assistant" | "assistant_message" => Some(ProtoEventType::AssistantMessage),
+            "tool" | "tool_result" => Some(ProtoEventType::ToolResult),
+            "assistant_stop" => Some(ProtoEventType::AssistantStop),
+            "subagent_start" => Some(ProtoEventType::SubagentStart),
+            "subagent_stop" => Some(ProtoEventType::SubagentStop),
+            "session_end" => Some(ProtoEventType::SessionEnd),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every decodable value for a proto enum, found by probing a generous range.
+    fn all_values<P: TryFrom<i32>>() -> Vec<P> {
+        (0..32).filter_map(|v| P::try_from(v).ok()).collect()
+    }
+
+    /// Assert every non-unspecified proto variant round-trips through its domain enum.
+    fn assert_round_trip<P, D>(unspecified: P)
+    where
+        P: TryFrom<i32> + ProtoEnumName + Copy + PartialEq + std::fmt::Debug + From<D>,
+        D: TryFrom<P, Error = ConversionError> + Copy,
+    {
+        let values = all_values::<P>();
+        assert!(values.len() > 1);
+        for proto in values {
+            assert_ne!(proto.as_str(), UNKNOWN_NAME, "{proto:?} leaked unknown");
+            if proto == unspecified {
+                assert!(D::try_from(proto).is_err());
+                continue;
+            }
+            let domain = D::try_from(proto).expect("variant should map to domain");
+            assert_eq!(P::from(domain), proto);
+        }
+    }
+
+    #[test]
+    fn test_toc_level_round_trip() {
+        assert_round_trip::<ProtoTocLevel, DomainTocLevel>(ProtoTocLevel::Unspecified);
+    }
+
+    #[test]
+    fn test_search_field_round_trip() {
+        assert_round_trip::<ProtoSearchField, DomainSearchField>(ProtoSearchField::Unspecified);
+    }
+
+    #[test]
+    fn test_query_intent_round_trip() {
+        assert_round_trip::<ProtoIntent, DomainIntent>(ProtoIntent::Unspecified);
+    }
+
+    #[test]
+    fn test_capability_tier_round_trip() {
+        assert_round_trip::<ProtoTier, DomainTier>(ProtoTier::Unspecified);
+    }
+
+    #[test]
+    fn test_execution_mode_round_trip() {
+        assert_round_trip::<ProtoExecMode, DomainExecMode>(ProtoExecMode::Unspecified);
+    }
+
+    #[test]
+    fn test_retrieval_layer_round_trip() {
+        assert_round_trip::<ProtoLayer, DomainLayer>(ProtoLayer::Unspecified);
+    }
+
+    #[test]
+    fn test_no_results_reason_round_trip() {
+        assert_round_trip::<ProtoNoResultsReason, DomainNoResultsReason>(
+            ProtoNoResultsReason::Unspecified,
+        );
+    }
+
+    #[test]
+    fn test_domain_names_match_proto_names() {
+        for layer in all_values::<ProtoLayer>() {
+            if let Ok(domain) = DomainLayer::try_from(layer) {
+                assert_eq!(layer.as_str(), domain.as_str());
+            }
+        }
+        for level in all_values::<ProtoTocLevel>() {
+            if let Ok(domain) = DomainTocLevel::try_from(level) {
+                assert_eq!(level.as_str(), domain.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_hybrid_mode_names() {
+        for mode in all_values::<ProtoHybridMode>() {
+            assert_ne!(mode.as_str(), UNKNOWN_NAME);
+            assert_eq!(ProtoHybridMode::from_name(mode.as_str()), mode);
+        }
+        assert_eq!(
+            ProtoHybridMode::from_name("BM25"),
+            ProtoHybridMode::Bm25Only
+        );
+    }
+
+    #[test]
+    fn test_event_type_names() {
+        for event_type in all_values::<ProtoEventType>() {
+            if event_type == ProtoEventType::Unspecified {
+                assert_eq!(ProtoEventType::from_name(event_type.as_str()), None);
+                continue;
+            }
+            assert_eq!(
+                ProtoEventType::from_name(event_type.as_str()),
+                Some(event_type)
+            );
+        }
+        assert_eq!(
+            ProtoEventType::from_name("User"),
+            Some(ProtoEventType::UserMessage)
+        );
+        assert_eq!(
+            ProtoEventType::from_name("tool-result"),
+            Some(ProtoEventType::ToolResult)
+        );
+        assert_eq!(ProtoEventType::from_name("bogus"), None);
+    }
+
+    #[test]
+    fn test_name_of_raw_values() {
+        assert_eq!(ProtoLayer::name_of(ProtoLayer::Bm25 as i32), "bm25");
+        assert_eq!(ProtoTocLevel::name_of(ProtoTocLevel::Day as i32), "day");
+        assert_eq!(ProtoLayer::name_of(99), UNKNOWN_NAME);
+    }
+
+    #[test]
+    fn test_domain_from_i32() {
+        let layer: DomainLayer =
+            domain_from_i32::<ProtoLayer, _>(ProtoLayer::Vector as i32).expect("valid layer");
+        assert_eq!(layer, DomainLayer::Vector);
+
+        assert!(domain_from_i32::<ProtoLayer, DomainLayer>(0).is_err());
+        assert!(domain_from_i32::<ProtoLayer, DomainLayer>(99).is_err());
+    }
+
+    #[test]
+    fn test_conversion_error_to_status() {
+        let err = DomainIntent::try_from(ProtoIntent::Unspecified).unwrap_err();
+        let status: Status = err.into();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
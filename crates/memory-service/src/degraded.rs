@@ -0,0 +1,50 @@
+//! Degraded-mode tracking for optional features.
+//!
+//! Some features depend on resources that may be missing at startup, most
+//! notably the embedding model behind vector search. Rather than refusing to
+//! start, the daemon records which features are off and why, and capability
+//! reporting surfaces that reason to clients.
+
+/// Features disabled at startup, with the reason for each.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DegradedMode {
+    vector_disabled: Option<String>,
+}
+
+impl DegradedMode {
+    /// No features disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disable vector features (vector search, vector prune, dedup).
+    pub fn disable_vector(&mut self, reason: impl Into<String>) {
+        self.vector_disabled = Some(reason.into());
+    }
+
+    /// Why vector features are disabled, if they are.
+    pub fn vector_disabled_reason(&self) -> Option<&str> {
+        self.vector_disabled.as_deref()
+    }
+
+    /// True if any feature is disabled.
+    pub fn is_degraded(&self) -> bool {
+        self.vector_disabled.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degraded_mode() {
+        let mut mode = DegradedMode::new();
+        assert!(!mode.is_degraded());
+        assert_eq!(mode.vector_disabled_reason(), None);
+
+        mode.disable_vector("model not found");
+        assert!(mode.is_degraded());
+        assert_eq!(mode.vector_disabled_reason(), Some("model not found"));
+    }
+}
@@ -0,0 +1,155 @@
+//! Maximal Marginal Relevance (MMR) reranking.
+//!
+//! Search results often cluster around near-duplicates from the same
+//! conversation. MMR picks results one at a time, scoring each remaining
+//! candidate as
+//!
+//! `lambda * relevance - (1 - lambda) * max_similarity_to_selected`
+//!
+//! where similarity is the cosine similarity between embeddings. A lambda of
+//! 1.0 keeps the relevance order; lower values favour results unlike the
+//! ones already chosen.
+
+use memory_topics::cosine_similarity;
+
+/// A candidate for MMR reranking.
+#[derive(Debug, Clone, Copy)]
+pub struct MmrCandidate<'a> {
+    /// Relevance score from the original search (any scale)
+    pub relevance: f32,
+    /// Document embedding; candidates without one, or with a different
+    /// dimension, are never penalized
+    pub embedding: Option<&'a [f32]>,
+}
+
+/// Rerank `candidates` with MMR and return the indices of the top `k`.
+///
+/// Relevance scores are min-max normalized first so they are comparable
+/// with cosine similarity. `lambda` is clamped to [0.0, 1.0].
+pub fn mmr_rerank(candidates: &[MmrCandidate<'_>], lambda: f32, k: usize) -> Vec<usize> {
+    let lambda = lambda.clamp(0.0, 1.0);
+    let k = k.min(candidates.len());
+
+    let (min, max) = candidates
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), c| {
+            (lo.min(c.relevance), hi.max(c.relevance))
+        });
+    let range = max - min;
+    let relevance: Vec<f32> = candidates
+        .iter()
+        .map(|c| {
+            if range > 0.0 {
+                (c.relevance - min) / range
+            } else {
+                1.0
+            }
+        })
+        .collect();
+
+    let mut selected: Vec<usize> = Vec::with_capacity(k);
+    // Highest similarity of each candidate to anything selected so far
+    let mut max_sim = vec![0.0f32; candidates.len()];
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+
+    while selected.len() < k {
+        let (pos, &best) = remaining
+            .iter()
+            .enumerate()
+            .max_by(|(_, &a), (_, &b)| {
+                let score_a = lambda * relevance[a] - (1.0 - lambda) * max_sim[a];
+                let score_b = lambda * relevance[b] - (1.0 - lambda) * max_sim[b];
+                // Ties go to the earlier (more relevant) candidate
+                score_a
+                    .partial_cmp(&score_b)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(b.cmp(&a))
+            })
+            .expect("remaining is non-empty while selected < k");
+        remaining.swap_remove(pos);
+        selected.push(best);
+
+        if let Some(chosen) = candidates[best].embedding {
+            for &i in &remaining {
+                let other = candidates[i]
+                    .embedding
+                    .filter(|other| other.len() == chosen.len());
+                if let Some(other) = other {
+                    max_sim[i] = max_sim[i].max(cosine_similarity(chosen, other));
+                }
+            }
+        }
+    }
+
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates<'a>(embeddings: &'a [Vec<f32>], relevance: &[f32]) -> Vec<MmrCandidate<'a>> {
+        embeddings
+            .iter()
+            .zip(relevance)
+            .map(|(e, &relevance)| MmrCandidate {
+                relevance,
+                embedding: Some(e.as_slice()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_distinct_doc_promoted() {
+        // Three near-identical docs outrank one distinct doc
+        let embeddings = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.99, 0.01, 0.0],
+            vec![0.98, 0.02, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        let relevance = [0.95, 0.94, 0.93, 0.80];
+        let candidates = candidates(&embeddings, &relevance);
+
+        // Relevance only: distinct doc is last
+        assert_eq!(mmr_rerank(&candidates, 1.0, 4), vec![0, 1, 2, 3]);
+
+        // With diversity the distinct doc moves into the top two
+        let top = mmr_rerank(&candidates, 0.5, 2);
+        assert_eq!(top, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_missing_embeddings_keep_relevance_order() {
+        let candidates = vec![
+            MmrCandidate {
+                relevance: 3.0,
+                embedding: None,
+            },
+            MmrCandidate {
+                relevance: 2.0,
+                embedding: None,
+            },
+            MmrCandidate {
+                relevance: 1.0,
+                embedding: None,
+            },
+        ];
+        assert_eq!(mmr_rerank(&candidates, 0.3, 3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_mismatched_dimensions_not_penalized() {
+        let embeddings = vec![vec![1.0, 0.0], vec![1.0, 0.0, 0.0]];
+        let candidates = candidates(&embeddings, &[0.9, 0.8]);
+        assert_eq!(mmr_rerank(&candidates, 0.0, 2), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_k_larger_than_candidates() {
+        let embeddings = vec![vec![1.0, 0.0]];
+        let candidates = candidates(&embeddings, &[0.5]);
+        assert_eq!(mmr_rerank(&candidates, 0.5, 10), vec![0]);
+        assert!(mmr_rerank(&[], 0.5, 10).is_empty());
+    }
+}
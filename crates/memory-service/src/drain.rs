@@ -0,0 +1,336 @@
+//! Graceful drain of in-flight requests at shutdown.
+//!
+//! When the shutdown signal fires, the server stops accepting connections
+//! and waits up to a drain timeout for requests that are already running.
+//! [`InFlightRequests`] counts those requests; once the timeout expires the
+//! remaining ones are answered with `UNAVAILABLE` so their connections can
+//! close.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::Status;
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+/// Default time to wait for in-flight requests after a shutdown signal.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long aborted connections get to close before the server is dropped.
+const ABORT_GRACE: Duration = Duration::from_secs(1);
+
+/// Shared count of requests currently being handled.
+#[derive(Debug, Clone, Default)]
+pub struct InFlightRequests {
+    active: Arc<AtomicUsize>,
+    abort: CancellationToken,
+}
+
+impl InFlightRequests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of requests that have not yet produced a response.
+    pub fn active(&self) -> usize {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Answer every running request, and any that arrive later, with `UNAVAILABLE`.
+    pub fn abort(&self) {
+        self.abort.cancel();
+    }
+
+    /// Tower layer that counts requests passing through the server.
+    pub fn layer(&self) -> InFlightLayer {
+        InFlightLayer {
+            requests: self.clone(),
+        }
+    }
+}
+
+/// Layer produced by [`InFlightRequests::layer`].
+#[derive(Debug, Clone)]
+pub struct InFlightLayer {
+    requests: InFlightRequests,
+}
+
+impl<S> Layer<S> for InFlightLayer {
+    type Service = InFlightService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InFlightService {
+            inner,
+            requests: self.requests.clone(),
+        }
+    }
+}
+
+/// Service wrapper that keeps a request counted until its response is ready.
+#[derive(Debug, Clone)]
+pub struct InFlightService<S> {
+    inner: S,
+    requests: InFlightRequests,
+}
+
+impl<S, B> Service<http::Request<B>> for InFlightService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let guard = ActiveGuard::new(&self.requests.active);
+        let abort = self.requests.abort.clone();
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            let _guard = guard;
+            tokio::select! {
+                response = response => response,
+                _ = abort.cancelled() => {
+                    Ok(Status::unavailable("server shutting down").into_http())
+                }
+            }
+        })
+    }
+}
+
+/// Decrements the active count when dropped, including on cancellation.
+struct ActiveGuard(Arc<AtomicUsize>);
+
+impl ActiveGuard {
+    fn new(active: &Arc<AtomicUsize>) -> Self {
+        active.fetch_add(1, Ordering::SeqCst);
+        Self(active.clone())
+    }
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Drive a server until shutdown, then drain its in-flight requests.
+///
+/// `serve` must be a tonic server built with `requests.layer()` whose
+/// shutdown signal sends on the other end of `draining`. Once that happens
+/// the server is given `drain_timeout` to finish; requests still running
+/// after that are aborted.
+pub(crate) async fn serve_with_drain<S, E>(
+    serve: S,
+    draining: oneshot::Receiver<()>,
+    requests: &InFlightRequests,
+    drain_timeout: Duration,
+) -> Result<(), E>
+where
+    S: Future<Output = Result<(), E>>,
+{
+    tokio::pin!(serve);
+
+    tokio::select! {
+        result = &mut serve => return result,
+        _ = draining => {}
+    }
+
+    let pending = requests.active();
+    info!(
+        in_flight = pending,
+        timeout_ms = drain_timeout.as_millis() as u64,
+        "Shutdown signal received, draining in-flight requests"
+    );
+
+    if let Ok(result) = tokio::time::timeout(drain_timeout, &mut serve).await {
+        info!(drained = pending, aborted = 0, "In-flight requests drained");
+        return result;
+    }
+
+    let aborted = requests.active();
+    warn!(
+        drained = pending.saturating_sub(aborted),
+        aborted, "Drain timeout elapsed, aborting remaining requests"
+    );
+    requests.abort();
+    match tokio::time::timeout(ABORT_GRACE, &mut serve).await {
+        Ok(result) => result,
+        Err(_) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Channel, Server};
+    use tonic_health::pb::health_client::HealthClient;
+    use tonic_health::pb::HealthCheckRequest;
+
+    /// Layer that delays every request, standing in for a slow RPC.
+    #[derive(Clone)]
+    struct Delay(Duration);
+
+    impl<S> Layer<S> for Delay {
+        type Service = DelayService<S>;
+
+        fn layer(&self, inner: S) -> Self::Service {
+            DelayService {
+                inner,
+                delay: self.0,
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct DelayService<S> {
+        inner: S,
+        delay: Duration,
+    }
+
+    impl<S, B> Service<http::Request<B>> for DelayService<S>
+    where
+        S: Service<http::Request<B>>,
+        S::Future: Send + 'static,
+    {
+        type Response = S::Response;
+        type Error = S::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, request: http::Request<B>) -> Self::Future {
+            let delay = self.delay;
+            let response = self.inner.call(request);
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                response.await
+            })
+        }
+    }
+
+    /// Start a health-only server whose requests take `delay`, and return
+    /// its address, shutdown trigger, request counter and join handle.
+    async fn start_slow_server(
+        delay: Duration,
+        drain_timeout: Duration,
+    ) -> (
+        SocketAddr,
+        oneshot::Sender<()>,
+        InFlightRequests,
+        tokio::task::JoinHandle<Result<(), tonic::transport::Error>>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (_reporter, health_service) = tonic_health::server::health_reporter();
+
+        let requests = InFlightRequests::new();
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let (draining_tx, draining_rx) = oneshot::channel();
+        let serve = Server::builder()
+            .layer(requests.layer())
+            .layer(Delay(delay))
+            .add_service(health_service)
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async move {
+                shutdown_rx.await.ok();
+                let _ = draining_tx.send(());
+            });
+
+        let counter = requests.clone();
+        let handle = tokio::spawn(async move {
+            serve_with_drain(serve, draining_rx, &counter, drain_timeout).await
+        });
+        (addr, shutdown_tx, requests, handle)
+    }
+
+    async fn connect(addr: SocketAddr) -> HealthClient<Channel> {
+        let channel = Channel::from_shared(format!("http://{}", addr))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        HealthClient::new(channel)
+    }
+
+    async fn wait_for_in_flight(requests: &InFlightRequests) {
+        while requests.active() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_request_drains() {
+        let (addr, shutdown, requests, server) =
+            start_slow_server(Duration::from_millis(300), Duration::from_secs(5)).await;
+        let mut client = connect(addr).await;
+
+        let call = tokio::spawn(async move {
+            client
+                .check(HealthCheckRequest {
+                    service: String::new(),
+                })
+                .await
+        });
+        wait_for_in_flight(&requests).await;
+
+        shutdown.send(()).unwrap();
+
+        let response = tokio::time::timeout(Duration::from_secs(5), call)
+            .await
+            .expect("request outlived the drain window")
+            .unwrap();
+        assert!(response.is_ok(), "in-flight request failed: {:?}", response);
+        tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .expect("server did not stop after draining")
+            .unwrap()
+            .unwrap();
+        assert_eq!(requests.active(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_timeout_aborts_request() {
+        let (addr, shutdown, requests, server) =
+            start_slow_server(Duration::from_secs(60), Duration::from_millis(100)).await;
+        let mut client = connect(addr).await;
+
+        let call = tokio::spawn(async move {
+            client
+                .check(HealthCheckRequest {
+                    service: String::new(),
+                })
+                .await
+        });
+        wait_for_in_flight(&requests).await;
+
+        shutdown.send(()).unwrap();
+
+        let response = tokio::time::timeout(Duration::from_secs(5), call)
+            .await
+            .expect("request was not aborted")
+            .unwrap();
+        assert_eq!(response.unwrap_err().code(), tonic::Code::Unavailable);
+        tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .expect("server did not stop after aborting")
+            .unwrap()
+            .unwrap();
+    }
+}
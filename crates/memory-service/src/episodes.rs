@@ -24,6 +24,7 @@ use crate::pb::{
     EpisodeStatusProto, EpisodeSummary, GetSimilarEpisodesRequest, GetSimilarEpisodesResponse,
     RecordActionRequest, RecordActionResponse, StartEpisodeRequest, StartEpisodeResponse,
 };
+use crate::vector::MAX_SEARCH_TOP_K;
 
 /// Handler for episodic memory RPCs.
 pub struct EpisodeHandler {
@@ -234,6 +235,7 @@ impl EpisodeHandler {
         }
 
         let top_k = if req.top_k == 0 { 5 } else { req.top_k } as usize;
+        let top_k = top_k.min(MAX_SEARCH_TOP_K);
         let min_score = req.min_score;
 
         // Embed the query
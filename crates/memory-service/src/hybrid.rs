@@ -3,10 +3,21 @@
 //! Combines BM25 and vector search using Reciprocal Rank Fusion (RRF).
 //! RRF_score(doc) = sum(weight_i / (k + rank_i(doc)))
 //! where k=60 is the standard constant.
+//!
+//! When the request sets `diversity`, a wider pool of candidates is fetched
+//! and reranked with Maximal Marginal Relevance (MMR) using the vector index
+//! embeddings, so near-duplicates do not crowd out other results.
+//!
+//! An optional LRU result cache short-circuits repeated identical queries
+//! (common when an agent retries). Entries expire after a TTL and are treated
+//! as stale once either index has changed size since they were cached.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use lru::LruCache;
 use tonic::{Request, Response, Status};
 use tracing::{debug, info};
 
@@ -15,15 +26,49 @@ use memory_search::{SearchOptions, TeleportSearcher};
 use crate::pb::{
     HybridMode, HybridSearchRequest, HybridSearchResponse, VectorMatch, VectorTeleportRequest,
 };
-use crate::vector::VectorTeleportHandler;
+use crate::vector::{
+    rerank_by_diversity, VectorTeleportHandler, MAX_SEARCH_TOP_K, MMR_CANDIDATE_FACTOR,
+};
 
 /// Standard RRF constant (from original RRF paper)
 const RRF_K: f32 = 60.0;
 
+/// Default number of cached hybrid search results.
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+/// Default time-to-live for cached hybrid search results.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Configuration for the hybrid search result cache.
+#[derive(Debug, Clone)]
+pub struct HybridCacheConfig {
+    /// Maximum number of cached queries (0 disables caching)
+    pub capacity: usize,
+    /// How long a cached result stays valid
+    pub ttl: Duration,
+}
+
+impl Default for HybridCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CACHE_CAPACITY,
+            ttl: DEFAULT_CACHE_TTL,
+        }
+    }
+}
+
+impl HybridCacheConfig {
+    /// Create a cache configuration with the given capacity and TTL.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self { capacity, ttl }
+    }
+}
+
 /// Handler for hybrid search operations.
 pub struct HybridSearchHandler {
     vector_handler: Arc<VectorTeleportHandler>,
     searcher: Option<Arc<TeleportSearcher>>,
+    cache: Option<ResultCache>,
 }
 
 impl HybridSearchHandler {
@@ -35,6 +80,27 @@ impl HybridSearchHandler {
         Self {
             vector_handler,
             searcher,
+            cache: None,
+        }
+    }
+
+    /// Enable the query result cache.
+    ///
+    /// A capacity of zero leaves caching disabled.
+    pub fn with_cache(mut self, config: HybridCacheConfig) -> Self {
+        self.cache = ResultCache::new(&config);
+        self
+    }
+
+    /// Check if the result cache is enabled.
+    pub fn cache_enabled(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Drop all cached results (e.g. after an index rebuild).
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
         }
     }
 
@@ -55,8 +121,9 @@ impl HybridSearchHandler {
     ) -> Result<Response<HybridSearchResponse>, Status> {
         let req = request.into_inner();
         let query = &req.query;
+        // Default 10, max MAX_SEARCH_TOP_K
         let top_k = if req.top_k > 0 {
-            req.top_k as usize
+            (req.top_k as usize).min(MAX_SEARCH_TOP_K)
         } else {
             10
         };
@@ -72,44 +139,111 @@ impl HybridSearchHandler {
             0.5
         };
 
-        debug!(query = %query, mode = ?mode, "HybridSearch request");
+        let mut options = SearchOptions::new().with_limit(top_k);
+        if let Some(lambda) = req.diversity {
+            options = options.with_diversity(lambda);
+        }
+        // Reranking for diversity needs a wider candidate pool
+        let fetch_k = match options.diversity {
+            Some(_) => top_k * MMR_CANDIDATE_FACTOR,
+            None => top_k,
+        };
+
+        debug!(query = %query, mode = ?mode, diversity = ?options.diversity, "HybridSearch request");
+
+        let cache_key = CacheKey::new(&req, top_k, bm25_weight, vector_weight);
+        let fingerprint = self.index_fingerprint();
+        if let Some(cache) = &self.cache {
+            if let Some((matches, mode_used)) = cache.get(&cache_key, fingerprint) {
+                debug!(query = %query, results = matches.len(), "HybridSearch cache hit");
+                return Ok(Response::new(HybridSearchResponse {
+                    matches,
+                    mode_used,
+                    bm25_available: self.bm25_available(),
+                    vector_available: self.vector_available(),
+                    cache_hit: true,
+                }));
+            }
+        }
 
         // Determine actual mode based on availability
         let (actual_mode, matches) = match mode {
             HybridMode::VectorOnly => (
                 HybridMode::VectorOnly,
-                self.vector_search(query, top_k, &req).await?,
+                self.vector_search(query, fetch_k, &req).await?,
+            ),
+            HybridMode::Bm25Only => (
+                HybridMode::Bm25Only,
+                self.bm25_search(query, fetch_k, &req).await?,
             ),
-            HybridMode::Bm25Only => (HybridMode::Bm25Only, self.bm25_search(query, top_k).await?),
             HybridMode::Hybrid | HybridMode::Unspecified => {
                 if self.vector_available() && self.bm25_available() {
                     let fused = self
-                        .fuse_rrf(query, top_k, bm25_weight, vector_weight, &req)
+                        .fuse_rrf(query, fetch_k, bm25_weight, vector_weight, &req)
                         .await?;
                     (HybridMode::Hybrid, fused)
                 } else if self.vector_available() {
                     (
                         HybridMode::VectorOnly,
-                        self.vector_search(query, top_k, &req).await?,
+                        self.vector_search(query, fetch_k, &req).await?,
                     )
                 } else if self.bm25_available() {
-                    (HybridMode::Bm25Only, self.bm25_search(query, top_k).await?)
+                    (
+                        HybridMode::Bm25Only,
+                        self.bm25_search(query, fetch_k, &req).await?,
+                    )
                 } else {
                     (HybridMode::Unspecified, vec![])
                 }
             }
         };
+        let matches = self.diversify(matches, &options);
 
         info!(query = %query, mode = ?actual_mode, results = matches.len(), "HybridSearch complete");
 
+        if let Some(cache) = &self.cache {
+            cache.insert(cache_key, matches.clone(), actual_mode as i32, fingerprint);
+        }
+
         Ok(Response::new(HybridSearchResponse {
             matches,
             mode_used: actual_mode as i32,
             bm25_available: self.bm25_available(),
             vector_available: self.vector_available(),
+            cache_hit: false,
         }))
     }
 
+    /// Rerank candidates with MMR when `options.diversity` is set.
+    ///
+    /// Embeddings come from the vector index; candidates it does not hold
+    /// (e.g. BM25-only hits) are ranked by score alone.
+    fn diversify(&self, matches: Vec<VectorMatch>, options: &SearchOptions) -> Vec<VectorMatch> {
+        let Some(lambda) = options.diversity else {
+            return matches;
+        };
+        let doc_ids: Vec<String> = matches.iter().map(|m| m.doc_id.clone()).collect();
+        let embeddings = self.vector_handler.get_embeddings_for_doc_ids(&doc_ids);
+        rerank_by_diversity(matches, &embeddings, lambda, options.limit)
+    }
+
+    /// Snapshot of index state used to detect stale cache entries.
+    fn index_fingerprint(&self) -> IndexFingerprint {
+        let latest_vector = self
+            .vector_handler
+            .metadata()
+            .latest()
+            .ok()
+            .flatten()
+            .map(|entry| (entry.vector_id, entry.created_at));
+        IndexFingerprint {
+            vector_count: self.vector_handler.get_status().vector_count,
+            latest_vector,
+            bm25_docs: self.searcher.as_ref().map_or(0, |s| s.num_docs()),
+            bm25_generation: self.searcher.as_ref().map_or(0, |s| s.generation()),
+        }
+    }
+
     /// Perform vector-only search.
     async fn vector_search(
         &self,
@@ -124,6 +258,8 @@ impl HybridSearchHandler {
             time_filter: req.time_filter,
             target: req.target,
             agent_filter: req.agent_filter.clone(),
+            ef_search: None,
+            diversity: None,
         };
         let response = self
             .vector_handler
@@ -133,13 +269,22 @@ impl HybridSearchHandler {
     }
 
     /// Perform BM25-only search.
-    async fn bm25_search(&self, query: &str, top_k: usize) -> Result<Vec<VectorMatch>, Status> {
+    async fn bm25_search(
+        &self,
+        query: &str,
+        top_k: usize,
+        req: &HybridSearchRequest,
+    ) -> Result<Vec<VectorMatch>, Status> {
         let Some(searcher) = &self.searcher else {
             return Ok(vec![]);
         };
 
+        let mut options = SearchOptions::new().with_limit(top_k);
+        if let Some(tf) = req.time_filter {
+            options = options.with_time_range(Some(tf.start_ms), Some(tf.end_ms));
+        }
         let results = searcher
-            .search(query, SearchOptions::new().with_limit(top_k))
+            .search(query, options)
             .map_err(|e| Status::internal(format!("BM25 search error: {e}")))?;
 
         Ok(results
@@ -168,7 +313,7 @@ impl HybridSearchHandler {
         let fetch_k = top_k * 2;
 
         let vector_results = self.vector_search(query, fetch_k, req).await?;
-        let bm25_results = self.bm25_search(query, fetch_k).await?;
+        let bm25_results = self.bm25_search(query, fetch_k, req).await?;
 
         let mut rrf: HashMap<String, RrfEntry> = HashMap::new();
 
@@ -214,6 +359,119 @@ impl HybridSearchHandler {
     }
 }
 
+/// Cache key covering every request parameter that affects results.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    query: String,
+    mode: i32,
+    bm25_weight_bits: u32,
+    vector_weight_bits: u32,
+    target: i32,
+    top_k: usize,
+    time_filter: Option<(i64, i64)>,
+    agent_filter: Option<String>,
+    diversity_bits: Option<u32>,
+}
+
+impl CacheKey {
+    fn new(req: &HybridSearchRequest, top_k: usize, bm25_weight: f32, vector_weight: f32) -> Self {
+        Self {
+            query: req.query.clone(),
+            mode: req.mode,
+            bm25_weight_bits: bm25_weight.to_bits(),
+            vector_weight_bits: vector_weight.to_bits(),
+            target: req.target,
+            top_k,
+            time_filter: req.time_filter.map(|tf| (tf.start_ms, tf.end_ms)),
+            agent_filter: req.agent_filter.clone(),
+            diversity_bits: req.diversity.map(f32::to_bits),
+        }
+    }
+}
+
+/// Index state at the time a result was cached.
+///
+/// Counts alone miss in-place edits, so the fingerprint also carries the
+/// newest vector entry (ID and `created_at`) and the BM25 reader generation,
+/// both of which change when a document is re-indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IndexFingerprint {
+    vector_count: i64,
+    latest_vector: Option<(u64, i64)>,
+    bm25_docs: u64,
+    bm25_generation: u64,
+}
+
+/// A cached search result.
+struct CachedResult {
+    matches: Vec<VectorMatch>,
+    mode_used: i32,
+    fingerprint: IndexFingerprint,
+    inserted_at: Instant,
+}
+
+/// Bounded LRU cache of hybrid search results with TTL expiry.
+struct ResultCache {
+    entries: Mutex<LruCache<CacheKey, CachedResult>>,
+    ttl: Duration,
+}
+
+impl ResultCache {
+    /// Create a cache, or `None` when capacity is zero.
+    fn new(config: &HybridCacheConfig) -> Option<Self> {
+        let capacity = NonZeroUsize::new(config.capacity)?;
+        Some(Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl: config.ttl,
+        })
+    }
+
+    /// Look up a fresh entry, evicting it if expired or built from an older index.
+    fn get(
+        &self,
+        key: &CacheKey,
+        fingerprint: IndexFingerprint,
+    ) -> Option<(Vec<VectorMatch>, i32)> {
+        let mut entries = self.entries.lock().expect("cache mutex poisoned");
+        let fresh = match entries.get(key) {
+            Some(entry) => {
+                entry.inserted_at.elapsed() < self.ttl && entry.fingerprint == fingerprint
+            }
+            None => return None,
+        };
+        if !fresh {
+            entries.pop(key);
+            return None;
+        }
+        entries
+            .get(key)
+            .map(|entry| (entry.matches.clone(), entry.mode_used))
+    }
+
+    fn insert(
+        &self,
+        key: CacheKey,
+        matches: Vec<VectorMatch>,
+        mode_used: i32,
+        fingerprint: IndexFingerprint,
+    ) {
+        let mut entries = self.entries.lock().expect("cache mutex poisoned");
+        entries.put(
+            key,
+            CachedResult {
+                matches,
+                mode_used,
+                fingerprint,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    fn clear(&self) {
+        self.entries.lock().expect("cache mutex poisoned").clear();
+    }
+}
+
 /// Entry for RRF accumulation.
 struct RrfEntry {
     doc_id: String,
@@ -263,4 +521,125 @@ mod tests {
         assert_eq!(entry.doc_type, "toc_node");
         assert_eq!(entry.rrf_score, 0.0); // Should start at 0
     }
+
+    fn request(query: &str) -> HybridSearchRequest {
+        HybridSearchRequest {
+            query: query.to_string(),
+            top_k: 10,
+            mode: HybridMode::Hybrid as i32,
+            bm25_weight: 0.5,
+            vector_weight: 0.5,
+            time_filter: None,
+            target: 0,
+            agent_filter: None,
+            diversity: None,
+        }
+    }
+
+    fn key(query: &str) -> CacheKey {
+        CacheKey::new(&request(query), 10, 0.5, 0.5)
+    }
+
+    fn sample_match(doc_id: &str) -> VectorMatch {
+        VectorMatch {
+            doc_id: doc_id.to_string(),
+            doc_type: "toc_node".to_string(),
+            score: 0.5,
+            text_preview: String::new(),
+            timestamp_ms: 0,
+            agent: None,
+        }
+    }
+
+    const FINGERPRINT: IndexFingerprint = IndexFingerprint {
+        vector_count: 3,
+        latest_vector: Some((3, 1_706_540_400_000)),
+        bm25_docs: 5,
+        bm25_generation: 1,
+    };
+
+    #[test]
+    fn test_cache_hit_and_miss() {
+        let cache = ResultCache::new(&HybridCacheConfig::default()).unwrap();
+        cache.insert(
+            key("rust ownership"),
+            vec![sample_match("a")],
+            HybridMode::Hybrid as i32,
+            FINGERPRINT,
+        );
+
+        let (matches, mode) = cache.get(&key("rust ownership"), FINGERPRINT).unwrap();
+        assert_eq!(matches[0].doc_id, "a");
+        assert_eq!(mode, HybridMode::Hybrid as i32);
+        assert!(cache.get(&key("python flask"), FINGERPRINT).is_none());
+    }
+
+    #[test]
+    fn test_cache_key_includes_parameters() {
+        let mut other = request("rust");
+        other.agent_filter = Some("claude".to_string());
+        assert_ne!(key("rust"), CacheKey::new(&other, 10, 0.5, 0.5));
+        assert_ne!(key("rust"), CacheKey::new(&request("rust"), 5, 0.5, 0.5));
+        assert_ne!(key("rust"), CacheKey::new(&request("rust"), 10, 0.7, 0.3));
+
+        let mut diverse = request("rust");
+        diverse.diversity = Some(0.5);
+        assert_ne!(key("rust"), CacheKey::new(&diverse, 10, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_cache_entry_expires_after_ttl() {
+        let cache = ResultCache::new(&HybridCacheConfig::new(8, Duration::ZERO)).unwrap();
+        cache.insert(key("q"), vec![], 0, FINGERPRINT);
+        assert!(cache.get(&key("q"), FINGERPRINT).is_none());
+    }
+
+    #[test]
+    fn test_cache_entry_stale_after_index_change() {
+        let cache = ResultCache::new(&HybridCacheConfig::default()).unwrap();
+        cache.insert(key("q"), vec![], 0, FINGERPRINT);
+        let grown = IndexFingerprint {
+            vector_count: 4,
+            ..FINGERPRINT
+        };
+        assert!(cache.get(&key("q"), grown).is_none());
+        // Stale entry was evicted
+        assert!(cache.get(&key("q"), FINGERPRINT).is_none());
+    }
+
+    #[test]
+    fn test_cache_entry_stale_after_edit_with_same_counts() {
+        let cache = ResultCache::new(&HybridCacheConfig::default()).unwrap();
+
+        cache.insert(key("q"), vec![], 0, FINGERPRINT);
+        let reindexed = IndexFingerprint {
+            bm25_generation: 2,
+            ..FINGERPRINT
+        };
+        assert!(cache.get(&key("q"), reindexed).is_none());
+
+        cache.insert(key("q"), vec![], 0, FINGERPRINT);
+        let reembedded = IndexFingerprint {
+            latest_vector: Some((4, 1_706_540_500_000)),
+            ..FINGERPRINT
+        };
+        assert!(cache.get(&key("q"), reembedded).is_none());
+    }
+
+    #[test]
+    fn test_cache_lru_eviction_and_clear() {
+        let cache = ResultCache::new(&HybridCacheConfig::new(1, DEFAULT_CACHE_TTL)).unwrap();
+        cache.insert(key("first"), vec![], 0, FINGERPRINT);
+        cache.insert(key("second"), vec![], 0, FINGERPRINT);
+        assert!(cache.get(&key("first"), FINGERPRINT).is_none());
+        assert!(cache.get(&key("second"), FINGERPRINT).is_some());
+
+        cache.clear();
+        assert!(cache.get(&key("second"), FINGERPRINT).is_none());
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_cache() {
+        assert!(ResultCache::new(&HybridCacheConfig::new(0, DEFAULT_CACHE_TTL)).is_none());
+    }
 }
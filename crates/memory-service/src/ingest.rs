@@ -9,43 +9,56 @@ use std::sync::Arc;
 
 use chrono::{Duration, TimeZone, Utc};
 use tonic::{Request, Response, Status};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
+use memory_retrieval::FallbackChainConfig;
 use memory_scheduler::SchedulerService;
 use memory_search::TeleportSearcher;
 use memory_storage::Storage;
+use memory_toc::TokenCounter;
 use memory_types::{
     config::StalenessConfig, Event, EventRole, EventType, NoveltyConfig, OutboxEntry,
     SalienceConfig,
 };
 
 use crate::agents::AgentDiscoveryHandler;
+use crate::degraded::DegradedMode;
 use crate::episodes::EpisodeHandler;
-use crate::hybrid::HybridSearchHandler;
+use crate::hybrid::{HybridCacheConfig, HybridSearchHandler};
+use crate::metrics::{JobMetrics, Metrics, MetricsGauges};
 use crate::novelty::NoveltyChecker;
 use crate::pb::{
     memory_service_server::MemoryService, BrowseTocRequest, BrowseTocResponse,
-    ClassifyQueryIntentRequest, ClassifyQueryIntentResponse, CompleteEpisodeRequest,
-    CompleteEpisodeResponse, Event as ProtoEvent, EventRole as ProtoEventRole,
-    EventType as ProtoEventType, ExpandGripRequest, ExpandGripResponse, GetAgentActivityRequest,
+    ClassifyQueryIntentBatchRequest, ClassifyQueryIntentBatchResponse, ClassifyQueryIntentRequest,
+    ClassifyQueryIntentResponse, CompareAgentsRequest, CompareAgentsResponse,
+    CompleteEpisodeRequest, CompleteEpisodeResponse, Event as ProtoEvent,
+    EventRole as ProtoEventRole, EventType as ProtoEventType, ExpandGripRequest,
+    ExpandGripResponse, FindSimilarNodesRequest, FindSimilarNodesResponse, GetAgentActivityRequest,
     GetAgentActivityResponse, GetDedupStatusRequest, GetDedupStatusResponse, GetEventsRequest,
-    GetEventsResponse, GetNodeRequest, GetNodeResponse, GetRankingStatusRequest,
-    GetRankingStatusResponse, GetRelatedTopicsRequest, GetRelatedTopicsResponse,
-    GetRetrievalCapabilitiesRequest, GetRetrievalCapabilitiesResponse, GetSchedulerStatusRequest,
-    GetSchedulerStatusResponse, GetSimilarEpisodesRequest, GetSimilarEpisodesResponse,
-    GetTocRootRequest, GetTocRootResponse, GetTopTopicsRequest, GetTopTopicsResponse,
-    GetTopicGraphStatusRequest, GetTopicGraphStatusResponse, GetTopicsByQueryRequest,
+    GetEventsResponse, GetGripRequest, GetGripResponse, GetMetricsRequest, GetMetricsResponse,
+    GetNodeHistoryRequest, GetNodeHistoryResponse, GetNodeRequest, GetNodeResponse,
+    GetNodesRequest, GetNodesResponse, GetNoveltyMetricsRequest, GetNoveltyMetricsResponse,
+    GetRankingStatusRequest, GetRankingStatusResponse, GetRelatedTopicsRequest,
+    GetRelatedTopicsResponse, GetRetrievalCapabilitiesRequest, GetRetrievalCapabilitiesResponse,
+    GetSchedulerStatusRequest, GetSchedulerStatusResponse, GetSimilarEpisodesRequest,
+    GetSimilarEpisodesResponse, GetSubtreeRequest, GetSubtreeResponse, GetTocRootRequest,
+    GetTocRootResponse, GetTopTopicsRequest, GetTopTopicsResponse, GetTopicGraphStatusRequest,
+    GetTopicGraphStatusResponse, GetTopicNodesRequest, GetTopicNodesResponse,
+    GetTopicProjectionRequest, GetTopicProjectionResponse, GetTopicsByQueryRequest,
     GetTopicsByQueryResponse, GetVectorIndexStatusRequest, HybridSearchRequest,
     HybridSearchResponse, IngestEventRequest, IngestEventResponse, ListAgentsRequest,
     ListAgentsResponse, PauseJobRequest, PauseJobResponse, PruneBm25IndexRequest,
     PruneBm25IndexResponse, PruneVectorIndexRequest, PruneVectorIndexResponse, RecordActionRequest,
     RecordActionResponse, ResumeJobRequest, ResumeJobResponse, RouteQueryRequest,
-    RouteQueryResponse, SearchChildrenRequest, SearchChildrenResponse, SearchNodeRequest,
-    SearchNodeResponse, StartEpisodeRequest, StartEpisodeResponse, TeleportSearchRequest,
-    TeleportSearchResponse, VectorIndexStatus, VectorTeleportRequest, VectorTeleportResponse,
+    RouteQueryResponse, SearchChildrenRequest, SearchChildrenResponse, SearchEventsRequest,
+    SearchEventsResponse, SearchNodeRequest, SearchNodeResponse, StartEpisodeRequest,
+    StartEpisodeResponse, TeleportSearchRequest, TeleportSearchResponse, VectorIndexStatus,
+    VectorTeleportRequest, VectorTeleportResponse, WatchRetrievalCapabilitiesRequest,
 };
 use crate::query;
-use crate::retrieval::RetrievalHandler;
+use crate::rate_limit::IngestRateLimiter;
+use crate::redaction::Redactor;
+use crate::retrieval::{CapabilitiesStream, RetrievalHandler};
 use crate::scheduler_service::SchedulerGrpcService;
 use crate::search_service;
 use crate::teleport_service;
@@ -64,6 +77,10 @@ pub struct MemoryServiceImpl {
     agent_service: Arc<AgentDiscoveryHandler>,
     novelty_checker: Option<Arc<NoveltyChecker>>,
     episode_handler: Option<Arc<EpisodeHandler>>,
+    metrics: Arc<Metrics>,
+    rate_limiter: Option<Arc<IngestRateLimiter>>,
+    redactor: Option<Arc<Redactor>>,
+    token_counter: Arc<dyn TokenCounter>,
 }
 
 impl MemoryServiceImpl {
@@ -82,6 +99,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -104,7 +125,9 @@ impl MemoryServiceImpl {
         let agent_svc = Arc::new(AgentDiscoveryHandler::new(storage.clone()));
         Self {
             storage,
-            scheduler_service: Some(SchedulerGrpcService::new(scheduler)),
+            scheduler_service: Some(
+                SchedulerGrpcService::new(scheduler).with_outbox(storage.clone()),
+            ),
             teleport_searcher: None,
             vector_service: None,
             hybrid_service: None,
@@ -113,6 +136,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -135,7 +162,9 @@ impl MemoryServiceImpl {
         let agent_svc = Arc::new(AgentDiscoveryHandler::new(storage.clone()));
         Self {
             storage,
-            scheduler_service: Some(SchedulerGrpcService::new(scheduler)),
+            scheduler_service: Some(
+                SchedulerGrpcService::new(scheduler).with_outbox(storage.clone()),
+            ),
             teleport_searcher: Some(searcher),
             vector_service: None,
             hybrid_service: None,
@@ -144,6 +173,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -172,6 +205,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -203,6 +240,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -233,6 +274,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -258,7 +303,9 @@ impl MemoryServiceImpl {
         let agent_svc = Arc::new(AgentDiscoveryHandler::new(storage.clone()));
         Self {
             storage,
-            scheduler_service: Some(SchedulerGrpcService::new(scheduler)),
+            scheduler_service: Some(
+                SchedulerGrpcService::new(scheduler).with_outbox(storage.clone()),
+            ),
             teleport_searcher: Some(searcher),
             vector_service: Some(vector_handler),
             hybrid_service: Some(hybrid_handler),
@@ -267,6 +314,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -293,7 +344,9 @@ impl MemoryServiceImpl {
         let agent_svc = Arc::new(AgentDiscoveryHandler::new(storage.clone()));
         Self {
             storage,
-            scheduler_service: Some(SchedulerGrpcService::new(scheduler)),
+            scheduler_service: Some(
+                SchedulerGrpcService::new(scheduler).with_outbox(storage.clone()),
+            ),
             teleport_searcher: Some(searcher),
             vector_service: Some(vector_handler),
             hybrid_service: Some(hybrid_handler),
@@ -302,6 +355,10 @@ impl MemoryServiceImpl {
             agent_service: agent_svc,
             novelty_checker: None,
             episode_handler: None,
+            metrics: Arc::new(Metrics::new()),
+            rate_limiter: None,
+            redactor: None,
+            token_counter: search_service::budget_token_counter(),
         }
     }
 
@@ -313,6 +370,71 @@ impl MemoryServiceImpl {
         self.novelty_checker = Some(checker);
     }
 
+    /// Record features disabled at startup (e.g. vector search without a model).
+    ///
+    /// Called during daemon startup after construction. Takes effect even
+    /// while the retrieval handler is shared. GetRetrievalCapabilities
+    /// reports the reasons.
+    pub fn set_degraded_mode(&self, mode: DegradedMode) {
+        if let Some(retrieval) = &self.retrieval_service {
+            retrieval.set_degraded_mode(mode);
+        }
+    }
+
+    /// Enable the HybridSearch result cache.
+    ///
+    /// Called during daemon startup after construction. Has no effect when
+    /// vector search is not configured, since HybridSearch is then unavailable.
+    pub fn set_hybrid_cache(&mut self, config: HybridCacheConfig) {
+        if let (Some(_), Some(vector)) = (&self.hybrid_service, &self.vector_service) {
+            self.hybrid_service = Some(Arc::new(
+                HybridSearchHandler::new(vector.clone(), self.teleport_searcher.clone())
+                    .with_cache(config),
+            ));
+        }
+    }
+
+    /// Cap the number of results a single RouteQuery call may return.
+    pub fn set_max_route_limit(&self, max_route_limit: u32) {
+        if let Some(retrieval) = &self.retrieval_service {
+            retrieval.set_max_route_limit(max_route_limit);
+        }
+    }
+
+    /// Customize the retrieval layer sequence tried for each intent and tier.
+    pub fn set_fallback_config(&self, config: FallbackChainConfig) {
+        if let Some(retrieval) = &self.retrieval_service {
+            retrieval.set_fallback_config(config);
+        }
+    }
+
+    /// Set the outbox lag above which GetSchedulerStatus reports a warning.
+    pub fn set_outbox_lag_warn_threshold(&mut self, threshold: u64) {
+        if let Some(scheduler) = self.scheduler_service.as_mut() {
+            scheduler.set_outbox_lag_warn_threshold(threshold);
+        }
+    }
+
+    /// Limit IngestEvent calls per client (peer IP address and agent).
+    ///
+    /// Called during daemon startup after construction. Calls over the
+    /// limit fail with `RESOURCE_EXHAUSTED`.
+    pub fn set_ingest_rate_limiter(&mut self, limiter: Arc<IngestRateLimiter>) {
+        self.rate_limiter = Some(limiter);
+    }
+
+    /// Redact secrets from tool results before they are stored.
+    ///
+    /// Called during daemon startup after construction.
+    pub fn set_redactor(&mut self, redactor: Arc<Redactor>) {
+        self.redactor = Some(redactor);
+    }
+
+    /// Service counters, shared with anything else that reports them.
+    pub fn metrics(&self) -> Arc<Metrics> {
+        self.metrics.clone()
+    }
+
     /// Set the episode handler for episodic memory RPCs.
     ///
     /// Called during daemon startup after construction.
@@ -388,17 +510,20 @@ impl MemoryServiceImpl {
     /// Returns (avg_salience, high_salience_count, total_access_count, avg_usage_decay).
     /// Scans day-level nodes from the last 30 days for a bounded, representative sample.
     fn compute_ranking_metrics(&self) -> (f32, u32, u64, f32) {
-        use memory_types::{usage::usage_penalty, TocLevel, UsageConfig};
+        use memory_types::{is_rolling_node_id, usage::usage_penalty, TocLevel, UsageConfig};
 
         let now = chrono::Utc::now();
         let thirty_days_ago = now - chrono::Duration::days(30);
 
-        let nodes = match self.storage.get_toc_nodes_by_level(
+        let nodes: Vec<_> = match self.storage.get_toc_nodes_by_level(
             TocLevel::Day,
             Some(thirty_days_ago),
             Some(now),
         ) {
-            Ok(nodes) => nodes,
+            Ok(nodes) => nodes
+                .into_iter()
+                .filter(|n| !is_rolling_node_id(&n.node_id))
+                .collect(),
             Err(_) => return (0.0, 0, 0, 1.0),
         };
 
@@ -442,12 +567,26 @@ impl MemoryService for MemoryServiceImpl {
         &self,
         request: Request<IngestEventRequest>,
     ) -> Result<Response<IngestEventResponse>, Status> {
+        let peer = request.remote_addr().map(|addr| addr.ip().to_string());
         let req = request.into_inner();
 
         let proto_event = req
             .event
             .ok_or_else(|| Status::invalid_argument("Event is required"))?;
 
+        if let Some(ref limiter) = self.rate_limiter {
+            let client =
+                IngestRateLimiter::client_key(peer.as_deref(), proto_event.agent.as_deref());
+            if !limiter.try_acquire(&client) {
+                warn!(client = %client, "Ingest rate limit exceeded");
+                return Err(Status::resource_exhausted(format!(
+                    "Ingest rate limit of {} events/s exceeded for {}",
+                    limiter.rate_per_sec(),
+                    client
+                )));
+            }
+        }
+
         // Validate event_id
         if proto_event.event_id.is_empty() {
             return Err(Status::invalid_argument("event_id is required"));
@@ -461,8 +600,21 @@ impl MemoryService for MemoryServiceImpl {
         debug!("Ingesting event: {}", proto_event.event_id);
 
         // Convert proto to domain type
-        let event = Self::convert_event(proto_event)?;
+        let mut event = Self::convert_event(proto_event)?;
         let event_id = event.event_id.clone();
+
+        // Strip secrets from tool output before anything persists it
+        let mut redactions = 0;
+        if let Some(ref redactor) = self.redactor {
+            if event.event_type == EventType::ToolResult {
+                let (text, count) = redactor.redact(&event.text);
+                if count > 0 {
+                    info!(event_id = %event_id, count, "Redacted tool result");
+                    event.text = text.into_owned();
+                    redactions = count as u32;
+                }
+            }
+        }
         let timestamp_ms = event.timestamp_ms();
 
         // Serialize event for storage
@@ -517,6 +669,8 @@ impl MemoryService for MemoryServiceImpl {
             }
         }
 
+        self.metrics.record_ingest(created, deduplicated);
+
         if created {
             info!("Stored new event: {}", event_id);
         } else {
@@ -527,6 +681,7 @@ impl MemoryService for MemoryServiceImpl {
             event_id,
             created,
             deduplicated,
+            redactions,
         }))
     }
 
@@ -546,6 +701,30 @@ impl MemoryService for MemoryServiceImpl {
         query::get_node(self.storage.clone(), request).await
     }
 
+    /// Get several TOC nodes in one call.
+    async fn get_nodes(
+        &self,
+        request: Request<GetNodesRequest>,
+    ) -> Result<Response<GetNodesResponse>, Status> {
+        query::get_nodes(self.storage.clone(), request).await
+    }
+
+    /// Get a TOC node and its descendants down to a depth limit.
+    async fn get_subtree(
+        &self,
+        request: Request<GetSubtreeRequest>,
+    ) -> Result<Response<GetSubtreeResponse>, Status> {
+        query::get_subtree(self.storage.clone(), request).await
+    }
+
+    /// Get the version history of a TOC node.
+    async fn get_node_history(
+        &self,
+        request: Request<GetNodeHistoryRequest>,
+    ) -> Result<Response<GetNodeHistoryResponse>, Status> {
+        query::get_node_history(self.storage.clone(), request).await
+    }
+
     /// Browse children of a TOC node with pagination.
     async fn browse_toc(
         &self,
@@ -570,6 +749,14 @@ impl MemoryService for MemoryServiceImpl {
         query::expand_grip(self.storage.clone(), request).await
     }
 
+    /// Get a grip's stored record by ID.
+    async fn get_grip(
+        &self,
+        request: Request<GetGripRequest>,
+    ) -> Result<Response<GetGripResponse>, Status> {
+        query::get_grip(self.storage.clone(), request).await
+    }
+
     /// Get scheduler and job status.
     ///
     /// Per SCHED-05: Job status observable via gRPC.
@@ -582,6 +769,8 @@ impl MemoryService for MemoryServiceImpl {
             None => Ok(Response::new(GetSchedulerStatusResponse {
                 scheduler_running: false,
                 jobs: vec![],
+                outbox_lag: self.storage.outbox_lag().unwrap_or(0),
+                outbox_warning: None,
             })),
         }
     }
@@ -621,7 +810,12 @@ impl MemoryService for MemoryServiceImpl {
         &self,
         request: Request<SearchNodeRequest>,
     ) -> Result<Response<SearchNodeResponse>, Status> {
-        search_service::search_node(Arc::clone(&self.storage), request).await
+        search_service::search_node(
+            Arc::clone(&self.storage),
+            self.token_counter.as_ref(),
+            request,
+        )
+        .await
     }
 
     /// Search across children of a parent node.
@@ -631,7 +825,12 @@ impl MemoryService for MemoryServiceImpl {
         &self,
         request: Request<SearchChildrenRequest>,
     ) -> Result<Response<SearchChildrenResponse>, Status> {
-        search_service::search_children(Arc::clone(&self.storage), request).await
+        search_service::search_children(
+            Arc::clone(&self.storage),
+            self.token_counter.as_ref(),
+            request,
+        )
+        .await
     }
 
     /// Teleport search for TOC nodes or grips using BM25 ranking.
@@ -649,6 +848,26 @@ impl MemoryService for MemoryServiceImpl {
         }
     }
 
+    /// Full-text search over raw events using BM25 ranking.
+    ///
+    /// Only returns results when the daemon indexes raw events.
+    async fn search_events(
+        &self,
+        request: Request<SearchEventsRequest>,
+    ) -> Result<Response<SearchEventsResponse>, Status> {
+        match &self.teleport_searcher {
+            Some(searcher) => {
+                teleport_service::handle_search_events(
+                    searcher.clone(),
+                    self.storage.clone(),
+                    request,
+                )
+                .await
+            }
+            None => Err(Status::unavailable("Search index not configured")),
+        }
+    }
+
     /// Vector semantic search using HNSW index.
     ///
     /// Per VEC-01: Semantic similarity search over TOC nodes and grips.
@@ -695,6 +914,19 @@ impl MemoryService for MemoryServiceImpl {
         }
     }
 
+    /// Find TOC nodes similar to a given node.
+    ///
+    /// Seeds a vector search with the node's own embedding.
+    async fn find_similar_nodes(
+        &self,
+        request: Request<FindSimilarNodesRequest>,
+    ) -> Result<Response<FindSimilarNodesResponse>, Status> {
+        match &self.vector_service {
+            Some(svc) => svc.find_similar_nodes(&self.storage, request).await,
+            None => Err(Status::unavailable("Vector index not enabled")),
+        }
+    }
+
     /// Get topic graph status and statistics.
     ///
     /// Per TOPIC-08: Returns topic graph availability and stats.
@@ -752,6 +984,28 @@ impl MemoryService for MemoryServiceImpl {
         }
     }
 
+    /// Get a 2D projection of top topics for visualization.
+    async fn get_topic_projection(
+        &self,
+        request: Request<GetTopicProjectionRequest>,
+    ) -> Result<Response<GetTopicProjectionResponse>, Status> {
+        match &self.topic_service {
+            Some(svc) => svc.get_topic_projection(request).await,
+            None => Err(Status::unavailable("Topic graph not enabled")),
+        }
+    }
+
+    /// Get the TOC nodes that contributed to a topic.
+    async fn get_topic_nodes(
+        &self,
+        request: Request<GetTopicNodesRequest>,
+    ) -> Result<Response<GetTopicNodesResponse>, Status> {
+        match &self.topic_service {
+            Some(svc) => svc.get_topic_nodes(request).await,
+            None => Err(Status::unavailable("Topic graph not enabled")),
+        }
+    }
+
     /// Get retrieval capabilities.
     ///
     /// Per RETR-01: Combined status check pattern.
@@ -765,6 +1019,19 @@ impl MemoryService for MemoryServiceImpl {
         }
     }
 
+    type WatchRetrievalCapabilitiesStream = CapabilitiesStream;
+
+    /// Stream retrieval capabilities each time the tier changes.
+    async fn watch_retrieval_capabilities(
+        &self,
+        request: Request<WatchRetrievalCapabilitiesRequest>,
+    ) -> Result<Response<Self::WatchRetrievalCapabilitiesStream>, Status> {
+        match &self.retrieval_service {
+            Some(svc) => svc.clone().watch_retrieval_capabilities(request).await,
+            None => Err(Status::unavailable("Retrieval service not configured")),
+        }
+    }
+
     /// Classify query intent.
     ///
     /// Per RETR-04: Intent classification with keyword heuristics.
@@ -778,6 +1045,17 @@ impl MemoryService for MemoryServiceImpl {
         }
     }
 
+    /// Classify the intent of several queries at once.
+    async fn classify_query_intent_batch(
+        &self,
+        request: Request<ClassifyQueryIntentBatchRequest>,
+    ) -> Result<Response<ClassifyQueryIntentBatchResponse>, Status> {
+        match &self.retrieval_service {
+            Some(svc) => svc.classify_query_intent_batch(request).await,
+            None => Err(Status::unavailable("Retrieval service not configured")),
+        }
+    }
+
     /// Route a query through optimal layers.
     ///
     /// Per RETR-05: Fallback chains with explainability.
@@ -810,6 +1088,7 @@ impl MemoryService for MemoryServiceImpl {
                     days_pruned: 0,
                     weeks_pruned: 0,
                     message: "Vector index not configured".to_string(),
+                    would_remove: Default::default(),
                 }));
             }
         };
@@ -834,6 +1113,7 @@ impl MemoryService for MemoryServiceImpl {
                     days_pruned: 0,
                     weeks_pruned: 0,
                     message: format!("Level '{}' is protected and cannot be pruned", level),
+                    would_remove: Default::default(),
                 }));
             }
             vec![level]
@@ -885,37 +1165,42 @@ impl MemoryService for MemoryServiceImpl {
                 }
 
                 if entry.created_at < cutoff_ms {
-                    if !dry_run {
-                        if let Err(e) = metadata.delete(entry.vector_id) {
-                            stats.errors.push(format!(
-                                "Failed to delete vector {}: {}",
-                                entry.vector_id, e
-                            ));
-                            continue;
-                        }
+                    if dry_run {
+                        stats.add_would_remove(level, 1);
+                        continue;
+                    }
+                    if let Err(e) = metadata.delete(entry.vector_id) {
+                        stats.errors.push(format!(
+                            "Failed to delete vector {}: {}",
+                            entry.vector_id, e
+                        ));
+                        continue;
                     }
                     stats.add(level, 1);
                 }
             }
         }
 
-        let action = if dry_run {
-            "eligible for pruning"
-        } else {
-            "pruned"
-        };
-        let message = if stats.total() == 0 {
+        let message = if dry_run {
+            let by_level: Vec<String> = stats
+                .would_remove
+                .iter()
+                .map(|(level, count)| format!("{}={}", level, count))
+                .collect();
             format!(
-                "No vector metadata entries {} (retention policy applied). \
-                 Note: HNSW vectors remain until a full rebuild-index compacts them.",
-                action
+                "{} vector metadata entries eligible for pruning ({}). Nothing was removed.",
+                stats.total_would_remove(),
+                by_level.join(", ")
             )
+        } else if stats.total() == 0 {
+            "No vector metadata entries pruned (retention policy applied). \
+             Note: HNSW vectors remain until a full rebuild-index compacts them."
+                .to_string()
         } else {
             format!(
-                "{} {} vector metadata entries (segments={}, grips={}, days={}, weeks={}). \
+                "{} pruned vector metadata entries (segments={}, grips={}, days={}, weeks={}). \
                  Note: HNSW vectors remain until a full rebuild-index compacts them.",
                 stats.total(),
-                action,
                 stats.segments_pruned,
                 stats.grips_pruned,
                 stats.days_pruned,
@@ -930,6 +1215,7 @@ impl MemoryService for MemoryServiceImpl {
             days_pruned: stats.days_pruned,
             weeks_pruned: stats.weeks_pruned,
             message,
+            would_remove: stats.would_remove.into_iter().collect(),
         }))
     }
 
@@ -1118,6 +1404,16 @@ impl MemoryService for MemoryServiceImpl {
         self.agent_service.get_agent_activity(request).await
     }
 
+    /// Compare the top topics of two agents.
+    ///
+    /// Partitions topics into shared, only-A, and only-B sets.
+    async fn compare_agents(
+        &self,
+        request: Request<CompareAgentsRequest>,
+    ) -> Result<Response<CompareAgentsResponse>, Status> {
+        self.agent_service.compare_agents(request).await
+    }
+
     /// Get dedup gate status and metrics.
     ///
     /// Per DEDUP-03: Observability for dedup gate configuration and counters.
@@ -1152,6 +1448,86 @@ impl MemoryService for MemoryServiceImpl {
         Ok(Response::new(response))
     }
 
+    /// Get novelty suppression counters.
+    ///
+    /// All counts come from a single metrics snapshot, so `total_seen`
+    /// always equals `suppressed + stored_novel + skipped`.
+    async fn get_novelty_metrics(
+        &self,
+        _request: Request<GetNoveltyMetricsRequest>,
+    ) -> Result<Response<GetNoveltyMetricsResponse>, Status> {
+        let response = match self.novelty_checker {
+            Some(ref checker) => {
+                let snapshot = checker.metrics().snapshot();
+                GetNoveltyMetricsResponse {
+                    enabled: checker.config().enabled,
+                    total_seen: snapshot.total_seen(),
+                    suppressed: snapshot.total_rejected(),
+                    stored_novel: snapshot.stored_novel,
+                    skipped: snapshot.total_stored() - snapshot.stored_novel,
+                    suppression_rate: snapshot.suppression_rate(),
+                    window_size: checker.config().buffer_capacity as u32,
+                }
+            }
+            None => GetNoveltyMetricsResponse::default(),
+        };
+        Ok(Response::new(response))
+    }
+
+    /// Get service metrics in Prometheus text format.
+    ///
+    /// Counters come from the shared [`Metrics`]; outbox lag, job counts and
+    /// index sizes are read from their owners on each call.
+    async fn get_metrics(
+        &self,
+        _request: Request<GetMetricsRequest>,
+    ) -> Result<Response<GetMetricsResponse>, Status> {
+        let outbox_lag = self.storage.outbox_lag().map_err(|e| {
+            error!("Failed to read outbox lag: {}", e);
+            Status::internal(format!("Storage error: {}", e))
+        })?;
+
+        let mut jobs: Vec<JobMetrics> = self
+            .scheduler_service
+            .as_ref()
+            .map(|svc| {
+                svc.registry()
+                    .get_all_status()
+                    .into_iter()
+                    .map(|status| JobMetrics {
+                        name: status.job_name,
+                        runs: status.run_count,
+                        errors: status.error_count,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        jobs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut bm25_docs: Vec<(String, u64)> = match &self.teleport_searcher {
+            Some(searcher) => teleport_service::doc_counts_or_empty(searcher)
+                .into_iter()
+                .map(|(doc_type, count)| (doc_type.as_str().to_string(), count))
+                .collect(),
+            None => Vec::new(),
+        };
+        bm25_docs.sort();
+
+        let gauges = MetricsGauges {
+            outbox_lag,
+            jobs,
+            bm25_docs,
+            vector_count: self
+                .vector_service
+                .as_ref()
+                .map(|svc| svc.get_status().vector_count.max(0) as u64),
+        };
+
+        Ok(Response::new(GetMetricsResponse {
+            text: self.metrics.render(&gauges),
+        }))
+    }
+
     /// Start a new episode for tracking a task execution.
     ///
     /// Per Phase 44: Episodic memory lifecycle.
@@ -1284,6 +1660,136 @@ mod tests {
         assert!(!response2.into_inner().created); // Idempotent
     }
 
+    #[tokio::test]
+    async fn test_ingest_rate_limit() {
+        let (mut service, _temp) = create_test_service();
+        service.set_ingest_rate_limiter(Arc::new(IngestRateLimiter::new(3)));
+
+        let ingest = |agent: &str| {
+            Request::new(IngestEventRequest {
+                event: Some(ProtoEvent {
+                    event_id: ulid::Ulid::new().to_string(),
+                    session_id: "session-123".to_string(),
+                    timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                    event_type: ProtoEventType::UserMessage as i32,
+                    role: ProtoEventRole::User as i32,
+                    text: "Hello, world!".to_string(),
+                    metadata: HashMap::new(),
+                    agent: Some(agent.to_string()),
+                }),
+            })
+        };
+
+        let mut accepted = 0;
+        let mut rejected = 0;
+        for _ in 0..10 {
+            match service.ingest_event(ingest("claude")).await {
+                Ok(_) => accepted += 1,
+                Err(status) => {
+                    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+                    rejected += 1;
+                }
+            }
+        }
+        // The burst allowance, plus at most one token refilled meanwhile
+        assert!((3..=4).contains(&accepted), "accepted {accepted}");
+        assert!(rejected >= 6);
+
+        // Another agent on the same host has its own bucket
+        assert!(service.ingest_event(ingest("copilot")).await.is_ok());
+
+        // The bucket refills at the configured rate
+        tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+        assert!(service.ingest_event(ingest("claude")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_ingest_redacts_tool_result() {
+        let (mut service, _temp) = create_test_service();
+        service.set_redactor(Arc::new(Redactor::new(&[r"sk-[A-Za-z0-9]{20,}"]).unwrap()));
+
+        let ingest = |event_type: ProtoEventType, role: ProtoEventRole, text: &str| {
+            Request::new(IngestEventRequest {
+                event: Some(ProtoEvent {
+                    event_id: ulid::Ulid::new().to_string(),
+                    session_id: "session-123".to_string(),
+                    timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                    event_type: event_type as i32,
+                    role: role as i32,
+                    text: text.to_string(),
+                    metadata: HashMap::new(),
+                    agent: None,
+                }),
+            })
+        };
+        let stored_text = |event_id: &str| {
+            let bytes = service.storage.get_event(event_id).unwrap().unwrap();
+            Event::from_bytes(&bytes).unwrap().text
+        };
+
+        let resp = service
+            .ingest_event(ingest(
+                ProtoEventType::ToolResult,
+                ProtoEventRole::Tool,
+                "export OPENAI_API_KEY=sk-abcdefghijklmnopqrstuvwx",
+            ))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(resp.redactions, 1);
+        assert_eq!(
+            stored_text(&resp.event_id),
+            "export OPENAI_API_KEY=[REDACTED]"
+        );
+
+        let resp = service
+            .ingest_event(ingest(
+                ProtoEventType::ToolResult,
+                ProtoEventRole::Tool,
+                "test result: ok. 12 passed",
+            ))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(resp.redactions, 0);
+        assert_eq!(stored_text(&resp.event_id), "test result: ok. 12 passed");
+    }
+
+    #[tokio::test]
+    async fn test_get_metrics_counts_ingest() {
+        let (service, _temp) = create_test_service();
+
+        let event = ProtoEvent {
+            event_id: ulid::Ulid::new().to_string(),
+            session_id: "session-123".to_string(),
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            event_type: ProtoEventType::UserMessage as i32,
+            role: ProtoEventRole::User as i32,
+            text: "Hello, world!".to_string(),
+            metadata: HashMap::new(),
+            agent: None,
+        };
+        for _ in 0..2 {
+            service
+                .ingest_event(Request::new(IngestEventRequest {
+                    event: Some(event.clone()),
+                }))
+                .await
+                .unwrap();
+        }
+
+        let text = service
+            .get_metrics(Request::new(GetMetricsRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .text;
+        // The idempotent re-ingest is not counted
+        assert!(text.contains("agent_memory_events_ingested_total 1\n"));
+        assert!(text.contains("agent_memory_events_deduplicated_total 0\n"));
+        assert!(text.contains("agent_memory_outbox_lag 1\n"));
+    }
+
     #[tokio::test]
     async fn test_ingest_event_missing_event() {
         let (service, _temp) = create_test_service();
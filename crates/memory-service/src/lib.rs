@@ -11,12 +11,19 @@
 //! - Reflection endpoint for debugging (GRPC-04)
 
 pub mod agents;
+pub mod conversions;
+pub mod degraded;
+pub mod diversity;
+pub mod drain;
 pub mod episodes;
 pub mod federated;
 pub mod hybrid;
 pub mod ingest;
+pub mod metrics;
 pub mod novelty;
 pub mod query;
+pub mod rate_limit;
+pub mod redaction;
 pub mod retrieval;
 pub mod scheduler_service;
 pub mod search_service;
@@ -32,14 +39,19 @@ pub mod pb {
 }
 
 pub use agents::AgentDiscoveryHandler;
+pub use degraded::DegradedMode;
+pub use drain::{InFlightRequests, DEFAULT_DRAIN_TIMEOUT};
 pub use episodes::EpisodeHandler;
-pub use hybrid::HybridSearchHandler;
+pub use hybrid::{HybridCacheConfig, HybridSearchHandler};
 pub use ingest::MemoryServiceImpl;
+pub use metrics::{JobMetrics, Metrics, MetricsGauges};
 pub use novelty::{
     CandleEmbedderAdapter, DedupResult, NoveltyChecker, NoveltyMetrics, NoveltyMetricsSnapshot,
 };
+pub use rate_limit::IngestRateLimiter;
+pub use redaction::{RedactionError, Redactor};
 pub use retrieval::RetrievalHandler;
-pub use scheduler_service::SchedulerGrpcService;
+pub use scheduler_service::{SchedulerGrpcService, DEFAULT_OUTBOX_LAG_WARN_THRESHOLD};
 pub use server::{run_server, run_server_with_scheduler, run_server_with_shutdown};
 pub use topics::{TopicGraphHandler, TopicGraphStatus, TopicSearchResult};
 pub use vector::{VectorSearchResult, VectorTeleportHandler};
@@ -0,0 +1,274 @@
+//! Service metrics in Prometheus text exposition format.
+//!
+//! Counters updated on the request path live in [`Metrics`], which is shared
+//! via `Arc`. Values owned by other components (outbox lag, scheduler job
+//! counts, index sizes) are gathered into [`MetricsGauges`] when the metrics
+//! are rendered, so they are never stale.
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Prefix for all exported metric names.
+const PREFIX: &str = "agent_memory";
+
+/// Counters maintained by the service.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    events_ingested: AtomicU64,
+    events_deduplicated: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the outcome of one IngestEvent call.
+    ///
+    /// Idempotent re-ingests (`created == false`) are not counted.
+    pub fn record_ingest(&self, created: bool, deduplicated: bool) {
+        if !created {
+            return;
+        }
+        self.events_ingested.fetch_add(1, Ordering::Relaxed);
+        if deduplicated {
+            self.events_deduplicated.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// New events stored.
+    pub fn events_ingested(&self) -> u64 {
+        self.events_ingested.load(Ordering::Relaxed)
+    }
+
+    /// New events stored without indexing because the novelty gate flagged them.
+    pub fn events_deduplicated(&self) -> u64 {
+        self.events_deduplicated.load(Ordering::Relaxed)
+    }
+
+    /// Render the counters and `gauges` as Prometheus text.
+    pub fn render(&self, gauges: &MetricsGauges) -> String {
+        let mut out = String::new();
+
+        write_family(
+            &mut out,
+            "events_ingested_total",
+            "counter",
+            "New events stored by IngestEvent.",
+            &[(String::new(), self.events_ingested())],
+        );
+        write_family(
+            &mut out,
+            "events_deduplicated_total",
+            "counter",
+            "New events stored without indexing because the novelty gate flagged them as duplicates.",
+            &[(String::new(), self.events_deduplicated())],
+        );
+        write_family(
+            &mut out,
+            "outbox_lag",
+            "gauge",
+            "Outbox entries not yet removed by cleanup.",
+            &[(String::new(), gauges.outbox_lag)],
+        );
+
+        let job_samples = |value: fn(&JobMetrics) -> u64| -> Vec<(String, u64)> {
+            gauges
+                .jobs
+                .iter()
+                .map(|job| (labels(&[("job", &job.name)]), value(job)))
+                .collect()
+        };
+        write_family(
+            &mut out,
+            "job_runs_total",
+            "counter",
+            "Scheduler job executions.",
+            &job_samples(|job| job.runs),
+        );
+        write_family(
+            &mut out,
+            "job_errors_total",
+            "counter",
+            "Scheduler job executions that failed.",
+            &job_samples(|job| job.errors),
+        );
+
+        let bm25_samples: Vec<(String, u64)> = gauges
+            .bm25_docs
+            .iter()
+            .map(|(doc_type, count)| (labels(&[("doc_type", doc_type)]), *count))
+            .collect();
+        write_family(
+            &mut out,
+            "bm25_documents",
+            "gauge",
+            "Documents in the BM25 index by type.",
+            &bm25_samples,
+        );
+        if let Some(count) = gauges.vector_count {
+            write_family(
+                &mut out,
+                "vector_index_vectors",
+                "gauge",
+                "Vectors in the HNSW index.",
+                &[(String::new(), count)],
+            );
+        }
+
+        out
+    }
+}
+
+/// Values read from other components at render time.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsGauges {
+    /// Current outbox size
+    pub outbox_lag: u64,
+    /// Per-job scheduler counters
+    pub jobs: Vec<JobMetrics>,
+    /// BM25 document counts as (doc type, count)
+    pub bm25_docs: Vec<(String, u64)>,
+    /// Vector index size (None if vector search is not configured)
+    pub vector_count: Option<u64>,
+}
+
+/// Run and error counts for one scheduler job.
+#[derive(Debug, Clone)]
+pub struct JobMetrics {
+    pub name: String,
+    pub runs: u64,
+    pub errors: u64,
+}
+
+/// Write one metric family: HELP and TYPE lines followed by its samples.
+///
+/// Families without samples are still described, so scrapers see a stable
+/// set of names.
+fn write_family(out: &mut String, name: &str, kind: &str, help: &str, samples: &[(String, u64)]) {
+    let _ = writeln!(out, "# HELP {PREFIX}_{name} {help}");
+    let _ = writeln!(out, "# TYPE {PREFIX}_{name} {kind}");
+    for (labels, value) in samples {
+        let _ = writeln!(out, "{PREFIX}_{name}{labels} {value}");
+    }
+}
+
+/// Format a label set, escaping values per the exposition format.
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let body: Vec<String> = pairs
+        .iter()
+        .map(|(key, value)| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{key}=\"{escaped}\"")
+        })
+        .collect();
+    format!("{{{}}}", body.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Check `text` against the exposition format and return the sample names.
+    ///
+    /// Every sample must follow a HELP and TYPE line for its family.
+    fn parse_prometheus(text: &str) -> HashSet<String> {
+        let mut described = HashSet::new();
+        let mut typed = HashSet::new();
+        let mut samples = HashSet::new();
+
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("# HELP ") {
+                let (name, help) = rest.split_once(' ').expect("HELP without text");
+                assert!(!help.is_empty());
+                described.insert(name.to_string());
+            } else if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = rest.split_once(' ').expect("TYPE without kind");
+                assert!(["counter", "gauge"].contains(&kind), "bad type: {line}");
+                assert!(described.contains(name), "TYPE before HELP: {line}");
+                typed.insert(name.to_string());
+            } else {
+                let (series, value) = line.rsplit_once(' ').expect("sample without value");
+                value.parse::<f64>().expect("non-numeric value");
+                let name = match series.split_once('{') {
+                    Some((name, labels)) => {
+                        assert!(labels.ends_with('}'), "unterminated labels: {line}");
+                        name
+                    }
+                    None => series,
+                };
+                assert!(
+                    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+                    "bad metric name: {name}"
+                );
+                assert!(typed.contains(name), "sample before TYPE: {line}");
+                samples.insert(name.to_string());
+            }
+        }
+        samples
+    }
+
+    #[test]
+    fn test_record_ingest() {
+        let metrics = Metrics::new();
+        metrics.record_ingest(true, false);
+        metrics.record_ingest(true, true);
+        metrics.record_ingest(false, false);
+        assert_eq!(metrics.events_ingested(), 2);
+        assert_eq!(metrics.events_deduplicated(), 1);
+    }
+
+    #[test]
+    fn test_render_prometheus_text() {
+        let metrics = Metrics::new();
+        metrics.record_ingest(true, false);
+        metrics.record_ingest(true, true);
+
+        let gauges = MetricsGauges {
+            outbox_lag: 7,
+            jobs: vec![JobMetrics {
+                name: "rollup_day".to_string(),
+                runs: 3,
+                errors: 1,
+            }],
+            bm25_docs: vec![("toc_node".to_string(), 5), ("grip".to_string(), 2)],
+            vector_count: Some(4),
+        };
+        let text = metrics.render(&gauges);
+
+        let samples = parse_prometheus(&text);
+        for name in [
+            "agent_memory_events_ingested_total",
+            "agent_memory_events_deduplicated_total",
+            "agent_memory_outbox_lag",
+            "agent_memory_job_runs_total",
+            "agent_memory_job_errors_total",
+            "agent_memory_bm25_documents",
+            "agent_memory_vector_index_vectors",
+        ] {
+            assert!(text.contains(&format!("# HELP {name} ")), "{name} HELP");
+            assert!(samples.contains(name), "{name} sample");
+        }
+        assert!(text.contains("agent_memory_events_ingested_total 2\n"));
+        assert!(text.contains("agent_memory_events_deduplicated_total 1\n"));
+        assert!(text.contains("agent_memory_job_errors_total{job=\"rollup_day\"} 1\n"));
+        assert!(text.contains("agent_memory_bm25_documents{doc_type=\"grip\"} 2\n"));
+    }
+
+    #[test]
+    fn test_render_without_optional_sources() {
+        let text = Metrics::new().render(&MetricsGauges::default());
+        parse_prometheus(&text);
+        assert!(text.contains("# HELP agent_memory_job_runs_total "));
+        assert!(!text.contains("vector_index_vectors"));
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(labels(&[("job", "a\"b\\c")]), "{job=\"a\\\"b\\\\c\"}");
+    }
+}
@@ -6,10 +6,14 @@
 //! - Async check with configurable timeout
 //! - Full metrics for observability
 //! - NEVER a hard gate - always stores on any failure
+//!
+//! The in-flight buffer can be saved to and reloaded from storage so
+//! recently seen events are still recognized after a restart.
 
 use memory_embeddings::{CandleEmbedder, Embedding, EmbeddingModel};
+use memory_storage::{Storage, StorageError};
 use memory_types::config::DedupConfig;
-use memory_types::dedup::InFlightBuffer;
+use memory_types::dedup::{InFlightBuffer, InFlightBufferSnapshot};
 use memory_types::Event;
 use memory_vector::{HnswIndex, VectorIndex};
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -19,6 +23,10 @@ use tokio::time::timeout;
 use tracing;
 
 /// Metrics for novelty checking.
+///
+/// Each checked event bumps exactly one counter. Increments go through
+/// [`increment`](Self::increment) so [`snapshot`](Self::snapshot) never sees
+/// an event half-counted and the totals always add up.
 #[derive(Debug, Default)]
 pub struct NoveltyMetrics {
     pub skipped_disabled: AtomicU64,
@@ -30,6 +38,8 @@ pub struct NoveltyMetrics {
     pub skipped_short_text: AtomicU64,
     pub stored_novel: AtomicU64,
     pub rejected_duplicate: AtomicU64,
+    /// Held shared by increments and exclusively by snapshots
+    snapshot_lock: RwLock<()>,
 }
 
 impl NoveltyMetrics {
@@ -37,8 +47,23 @@ impl NoveltyMetrics {
         Self::default()
     }
 
+    /// Add one to `counter`, which must be a field of this struct.
+    pub fn increment(&self, counter: &AtomicU64) {
+        let _guard = self
+            .snapshot_lock
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Get all counts as a snapshot.
+    ///
+    /// Blocks increments while reading, so all counts are from the same instant.
     pub fn snapshot(&self) -> NoveltyMetricsSnapshot {
+        let _guard = self
+            .snapshot_lock
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
         NoveltyMetricsSnapshot {
             skipped_disabled: self.skipped_disabled.load(Ordering::Relaxed),
             skipped_no_embedder: self.skipped_no_embedder.load(Ordering::Relaxed),
@@ -89,6 +114,19 @@ impl NoveltyMetricsSnapshot {
     pub fn total_rejected(&self) -> u64 {
         self.rejected_duplicate
     }
+
+    /// Total events seen (stored + rejected).
+    pub fn total_seen(&self) -> u64 {
+        self.total_stored() + self.rejected_duplicate
+    }
+
+    /// Fraction of seen events rejected as duplicates (0.0 if none seen).
+    pub fn suppression_rate(&self) -> f64 {
+        match self.total_seen() {
+            0 => 0.0,
+            seen => self.rejected_duplicate as f64 / seen as f64,
+        }
+    }
 }
 
 /// Trait for embedder (to allow mocking).
@@ -119,6 +157,11 @@ impl CandleEmbedderAdapter {
             embedder: Arc::new(embedder),
         }
     }
+
+    /// Create an adapter over an embedder shared with other components.
+    pub fn from_shared(embedder: Arc<CandleEmbedder>) -> Self {
+        Self { embedder }
+    }
 }
 
 #[async_trait::async_trait]
@@ -285,8 +328,13 @@ pub struct NoveltyChecker {
     config: DedupConfig,
     metrics: Arc<NoveltyMetrics>,
     in_flight_buffer: Option<Arc<RwLock<InFlightBuffer>>>,
+    /// Buffer generation last written to (or loaded from) storage
+    saved_generation: AtomicU64,
 }
 
+/// `saved_generation` before anything was saved or loaded.
+const NEVER_SAVED: u64 = u64::MAX;
+
 impl NoveltyChecker {
     /// Create new novelty checker.
     pub fn new(
@@ -300,6 +348,7 @@ impl NoveltyChecker {
             config,
             metrics: Arc::new(NoveltyMetrics::new()),
             in_flight_buffer: None,
+            saved_generation: AtomicU64::new(NEVER_SAVED),
         }
     }
 
@@ -320,6 +369,7 @@ impl NoveltyChecker {
             config,
             metrics: Arc::new(NoveltyMetrics::new()),
             in_flight_buffer: Some(buffer),
+            saved_generation: AtomicU64::new(NEVER_SAVED),
         }
     }
 
@@ -344,6 +394,7 @@ impl NoveltyChecker {
             config,
             metrics: Arc::new(NoveltyMetrics::new()),
             in_flight_buffer: Some(buffer),
+            saved_generation: AtomicU64::new(NEVER_SAVED),
         }
     }
 
@@ -388,9 +439,7 @@ impl NoveltyChecker {
     pub async fn should_store_with_embedding(&self, event: &Event) -> DedupResult {
         // GATE 1: Feature must be explicitly enabled
         if !self.config.enabled {
-            self.metrics
-                .skipped_disabled
-                .fetch_add(1, Ordering::Relaxed);
+            self.metrics.increment(&self.metrics.skipped_disabled);
             return DedupResult {
                 should_store: true,
                 embedding: None,
@@ -399,9 +448,7 @@ impl NoveltyChecker {
 
         // GATE 2: Skip very short text
         if event.text.len() < self.config.min_text_length {
-            self.metrics
-                .skipped_short_text
-                .fetch_add(1, Ordering::Relaxed);
+            self.metrics.increment(&self.metrics.skipped_short_text);
             tracing::debug!(
                 text_len = event.text.len(),
                 min_len = self.config.min_text_length,
@@ -417,9 +464,7 @@ impl NoveltyChecker {
         let embedder = match &self.embedder {
             Some(e) => e,
             None => {
-                self.metrics
-                    .skipped_no_embedder
-                    .fetch_add(1, Ordering::Relaxed);
+                self.metrics.increment(&self.metrics.skipped_no_embedder);
                 tracing::debug!("Novelty check skipped: embedder unavailable");
                 return DedupResult {
                     should_store: true,
@@ -432,9 +477,7 @@ impl NoveltyChecker {
         let index = match &self.vector_index {
             Some(i) => i,
             None => {
-                self.metrics
-                    .skipped_no_index
-                    .fetch_add(1, Ordering::Relaxed);
+                self.metrics.increment(&self.metrics.skipped_no_index);
                 tracing::debug!("Novelty check skipped: vector index unavailable");
                 return DedupResult {
                     should_store: true,
@@ -445,8 +488,7 @@ impl NoveltyChecker {
 
         if !index.is_ready() {
             self.metrics
-                .skipped_index_not_ready
-                .fetch_add(1, Ordering::Relaxed);
+                .increment(&self.metrics.skipped_index_not_ready);
             tracing::debug!("Novelty check skipped: vector index not ready");
             return DedupResult {
                 should_store: true,
@@ -473,15 +515,13 @@ impl NoveltyChecker {
                 );
 
                 if is_novel {
-                    self.metrics.stored_novel.fetch_add(1, Ordering::Relaxed);
+                    self.metrics.increment(&self.metrics.stored_novel);
                     DedupResult {
                         should_store: true,
                         embedding: Some(embedding),
                     }
                 } else {
-                    self.metrics
-                        .rejected_duplicate
-                        .fetch_add(1, Ordering::Relaxed);
+                    self.metrics.increment(&self.metrics.rejected_duplicate);
                     tracing::info!(event_id = %event.event_id, "Novelty check rejected duplicate");
                     DedupResult {
                         should_store: false,
@@ -490,7 +530,7 @@ impl NoveltyChecker {
                 }
             }
             Ok(Err(e)) => {
-                self.metrics.skipped_error.fetch_add(1, Ordering::Relaxed);
+                self.metrics.increment(&self.metrics.skipped_error);
                 tracing::warn!(?e, "Novelty check failed, storing anyway");
                 DedupResult {
                     should_store: true,
@@ -498,7 +538,7 @@ impl NoveltyChecker {
                 }
             }
             Err(_) => {
-                self.metrics.skipped_timeout.fetch_add(1, Ordering::Relaxed);
+                self.metrics.increment(&self.metrics.skipped_timeout);
                 tracing::warn!(
                     timeout_ms = self.config.timeout_ms,
                     "Novelty check timed out, storing anyway"
@@ -550,6 +590,81 @@ impl NoveltyChecker {
             .map(|buf| buf.len())
             .unwrap_or(0)
     }
+
+    /// Snapshot the in-flight buffer for persistence.
+    ///
+    /// Returns None if no buffer is configured or the lock cannot be acquired.
+    pub fn snapshot_buffer(&self) -> Option<InFlightBufferSnapshot> {
+        self.in_flight_buffer
+            .as_ref()
+            .and_then(|buf| buf.read().ok())
+            .map(|buf| buf.snapshot())
+    }
+
+    /// Replace the in-flight buffer contents with a snapshot.
+    ///
+    /// Keeps at most `buffer_capacity` of the newest entries. Returns the
+    /// number of entries restored (0 if no buffer is configured).
+    pub fn restore_buffer(&self, snapshot: InFlightBufferSnapshot) -> usize {
+        let Some(ref buffer) = self.in_flight_buffer else {
+            return 0;
+        };
+        match buffer.write() {
+            Ok(mut guard) => guard.restore(snapshot),
+            Err(_) => {
+                tracing::warn!("InFlightBuffer lock poisoned, skipping restore");
+                0
+            }
+        }
+    }
+
+    /// Save the in-flight buffer to storage if it changed since the last
+    /// save or load.
+    ///
+    /// Returns the number of entries saved, or None if the buffer was
+    /// unchanged (or none is configured) and nothing was written.
+    pub fn save_state(&self, storage: &Storage) -> Result<Option<usize>, StorageError> {
+        let Some((snapshot, generation)) = self
+            .in_flight_buffer
+            .as_ref()
+            .and_then(|buf| buf.read().ok())
+            .map(|buf| (buf.snapshot(), buf.generation()))
+        else {
+            return Ok(None);
+        };
+        if self.saved_generation.load(Ordering::Relaxed) == generation {
+            return Ok(None);
+        }
+        let saved = snapshot.entries.len();
+        storage.put_novelty_state(&snapshot)?;
+        self.saved_generation.store(generation, Ordering::Relaxed);
+        Ok(Some(saved))
+    }
+
+    /// Reload the in-flight buffer from the last state saved to storage.
+    ///
+    /// Returns the number of entries restored.
+    pub fn load_state(&self, storage: &Storage) -> Result<usize, StorageError> {
+        let Some(snapshot) = storage.get_novelty_state()? else {
+            return Ok(0);
+        };
+        let Some(ref buffer) = self.in_flight_buffer else {
+            return Ok(0);
+        };
+        match buffer.write() {
+            Ok(mut guard) => {
+                let restored = guard.restore(snapshot);
+                // Storage now matches the buffer
+                self.saved_generation
+                    .store(guard.generation(), Ordering::Relaxed);
+                Ok(restored)
+            }
+            Err(_) => {
+                tracing::warn!("InFlightBuffer lock poisoned, skipping restore");
+                Ok(0)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -857,6 +972,53 @@ mod tests {
         assert_eq!(metrics.stored_novel, 1);
     }
 
+    #[tokio::test]
+    async fn test_buffer_state_survives_restart() {
+        let dim = 384;
+        let vec_v = uniform_normalized(dim);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let event = test_event("Deploy the service to staging after tests pass");
+
+        {
+            let storage = Storage::open(temp_dir.path()).unwrap();
+            let embedder: Arc<dyn EmbedderTrait> = Arc::new(MockEmbedder {
+                embedding: vec_v.clone(),
+            });
+            let checker = NoveltyChecker::with_in_flight_buffer(
+                Some(embedder),
+                Arc::new(RwLock::new(InFlightBuffer::new(256, dim))),
+                enabled_config(),
+            );
+
+            let result = checker.should_store_with_embedding(&event).await;
+            assert!(result.should_store);
+            checker.push_to_buffer("evt-1", &result.embedding.unwrap());
+            assert_eq!(checker.save_state(&storage).unwrap(), Some(1));
+            // Nothing changed since, so nothing is rewritten
+            assert_eq!(checker.save_state(&storage).unwrap(), None);
+        }
+
+        // Near-duplicate: same direction with a small perturbation
+        let mut near = vec_v;
+        near[0] += 0.05;
+        near[1] -= 0.05;
+
+        let storage = Storage::open(temp_dir.path()).unwrap();
+        let embedder: Arc<dyn EmbedderTrait> = Arc::new(MockEmbedder { embedding: near });
+        let checker = NoveltyChecker::with_in_flight_buffer(
+            Some(embedder),
+            Arc::new(RwLock::new(InFlightBuffer::new(256, dim))),
+            enabled_config(),
+        );
+        assert_eq!(checker.load_state(&storage).unwrap(), 1);
+        assert_eq!(checker.buffer_len(), 1);
+        assert_eq!(checker.save_state(&storage).unwrap(), None);
+
+        let event = test_event("Deploy the service to staging once tests pass");
+        assert!(!checker.should_store(&event).await);
+        assert_eq!(checker.metrics().snapshot().rejected_duplicate, 1);
+    }
+
     // --- CompositeVectorIndex and HnswIndexAdapter tests ---
 
     struct FailingVectorIndex;
@@ -9,15 +9,19 @@ use tracing::{debug, warn};
 
 use memory_storage::Storage;
 use memory_types::{
-    Event, EventRole, EventType, TocLevel as DomainTocLevel, TocNode as DomainTocNode,
+    is_rolling_node_id, Event, EventRole, EventType, Grip as DomainGrip,
+    MemoryKind as DomainMemoryKind, TocLevel as DomainTocLevel, TocNode as DomainTocNode,
 };
 
 use crate::pb::{
     BrowseTocRequest, BrowseTocResponse, Event as ProtoEvent, EventRole as ProtoEventRole,
     EventType as ProtoEventType, ExpandGripRequest, ExpandGripResponse, GetEventsRequest,
-    GetEventsResponse, GetNodeRequest, GetNodeResponse, GetTocRootRequest, GetTocRootResponse,
-    Grip as ProtoGrip, MemoryKind as ProtoMemoryKind, TocBullet as ProtoTocBullet,
-    TocLevel as ProtoTocLevel, TocNode as ProtoTocNode,
+    GetEventsResponse, GetGripRequest, GetGripResponse, GetNodeHistoryRequest,
+    GetNodeHistoryResponse, GetNodeRequest, GetNodeResponse, GetNodesRequest, GetNodesResponse,
+    GetSubtreeRequest, GetSubtreeResponse, GetTocRootRequest, GetTocRootResponse,
+    Grip as ProtoGrip, GripProvenance as ProtoGripProvenance, MemoryKind as ProtoMemoryKind,
+    SubtreeNode as ProtoSubtreeNode, TocBullet as ProtoTocBullet, TocLevel as ProtoTocLevel,
+    TocNode as ProtoTocNode, TocNodeChange,
 };
 
 /// Get root TOC nodes (year level).
@@ -33,8 +37,13 @@ pub async fn get_toc_root(
         .get_toc_nodes_by_level(DomainTocLevel::Year, None, None)
         .map_err(|e| Status::internal(format!("Storage error: {}", e)))?;
 
+    // Rolling windows have no place in the calendar hierarchy.
     // Sort by time descending (most recent first)
-    let mut nodes: Vec<ProtoTocNode> = year_nodes.into_iter().map(domain_to_proto_node).collect();
+    let mut nodes: Vec<ProtoTocNode> = year_nodes
+        .into_iter()
+        .filter(|n| !is_rolling_node_id(&n.node_id))
+        .map(domain_to_proto_node)
+        .collect();
     nodes.reverse();
 
     Ok(Response::new(GetTocRootResponse { nodes }))
@@ -63,6 +72,152 @@ pub async fn get_node(
     Ok(Response::new(GetNodeResponse { node: proto_node }))
 }
 
+/// Maximum node IDs accepted by a single GetNodes call.
+pub const MAX_GET_NODES: usize = 1000;
+
+/// Get several TOC nodes by ID in one batched read.
+///
+/// Found nodes and missing IDs are returned in separate lists, each in
+/// request order.
+pub async fn get_nodes(
+    storage: Arc<Storage>,
+    request: Request<GetNodesRequest>,
+) -> Result<Response<GetNodesResponse>, Status> {
+    let req = request.into_inner();
+    debug!("GetNodes request: {} ids", req.node_ids.len());
+
+    if req.node_ids.len() > MAX_GET_NODES {
+        return Err(Status::invalid_argument(format!(
+            "At most {} node_ids per request",
+            MAX_GET_NODES
+        )));
+    }
+    if req.node_ids.iter().any(|id| id.is_empty()) {
+        return Err(Status::invalid_argument("node_ids must not be empty"));
+    }
+
+    let found = storage
+        .get_toc_nodes(&req.node_ids)
+        .map_err(|e| Status::internal(format!("Storage error: {}", e)))?;
+
+    let mut nodes = Vec::new();
+    let mut not_found = Vec::new();
+    for (node_id, node) in req.node_ids.into_iter().zip(found) {
+        match node {
+            Some(node) => nodes.push(domain_to_proto_node(node)),
+            None => not_found.push(node_id),
+        }
+    }
+
+    Ok(Response::new(GetNodesResponse { nodes, not_found }))
+}
+
+/// Maximum nodes returned by a single GetSubtree call.
+pub const MAX_SUBTREE_NODES: usize = 1000;
+
+/// Get a TOC node and its descendants down to `max_depth`.
+///
+/// The response is capped at [`MAX_SUBTREE_NODES`] (or the smaller
+/// `max_nodes` from the request) and flags when the cap was hit.
+pub async fn get_subtree(
+    storage: Arc<Storage>,
+    request: Request<GetSubtreeRequest>,
+) -> Result<Response<GetSubtreeResponse>, Status> {
+    let req = request.into_inner();
+    debug!(
+        "GetSubtree request: root={}, max_depth={}",
+        req.root_id, req.max_depth
+    );
+
+    if req.root_id.is_empty() {
+        return Err(Status::invalid_argument("root_id is required"));
+    }
+
+    let max_nodes = match req.max_nodes as usize {
+        0 => MAX_SUBTREE_NODES,
+        n => n.min(MAX_SUBTREE_NODES),
+    };
+    let subtree = storage
+        .get_subtree(&req.root_id, req.max_depth, max_nodes)
+        .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+        .ok_or_else(|| Status::not_found(format!("Node not found: {}", req.root_id)))?;
+
+    let nodes = subtree
+        .nodes
+        .into_iter()
+        .map(|n| ProtoSubtreeNode {
+            node: Some(domain_to_proto_node(n.node)),
+            parent_id: n.parent_id,
+            depth: n.depth,
+        })
+        .collect();
+
+    Ok(Response::new(GetSubtreeResponse {
+        nodes,
+        truncated: subtree.truncated,
+    }))
+}
+
+/// Get the version history of a TOC node.
+///
+/// Each version is diffed against the one before it; the first version is
+/// diffed against an empty node so its contents show up as additions.
+pub async fn get_node_history(
+    storage: Arc<Storage>,
+    request: Request<GetNodeHistoryRequest>,
+) -> Result<Response<GetNodeHistoryResponse>, Status> {
+    let req = request.into_inner();
+    debug!("GetNodeHistory request: {}", req.node_id);
+
+    if req.node_id.is_empty() {
+        return Err(Status::invalid_argument("node_id is required"));
+    }
+
+    let latest = storage
+        .get_toc_node(&req.node_id)
+        .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+        .ok_or_else(|| Status::not_found(format!("Node not found: {}", req.node_id)))?;
+
+    let mut changes = Vec::new();
+    let mut previous: Option<DomainTocNode> = None;
+    for version in 1..=latest.version {
+        let Some(node) = storage
+            .get_toc_node_version(&req.node_id, version)
+            .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+        else {
+            continue;
+        };
+
+        let base = previous.clone().unwrap_or_else(|| {
+            DomainTocNode::new(
+                node.node_id.clone(),
+                node.level,
+                node.title.clone(),
+                node.start_time,
+                node.end_time,
+            )
+        });
+        let diff = base.diff(&node);
+        changes.push(TocNodeChange {
+            version,
+            created_at_ms: node
+                .version_written_ms
+                .unwrap_or_else(|| node.created_at.timestamp_millis()),
+            title: node.title.clone(),
+            previous_title: diff.title.map(|(old, _)| old),
+            bullets_added: diff.bullets_added,
+            bullets_removed: diff.bullets_removed,
+            keywords_added: diff.keywords_added,
+            keywords_removed: diff.keywords_removed,
+            children_added: diff.children_added,
+            children_removed: diff.children_removed,
+        });
+        previous = Some(node);
+    }
+
+    Ok(Response::new(GetNodeHistoryResponse { changes }))
+}
+
 /// Browse children of a TOC node with pagination.
 ///
 /// Per QRY-03: BrowseToc supports pagination of children.
@@ -129,8 +284,8 @@ pub async fn get_events(
 ) -> Result<Response<GetEventsResponse>, Status> {
     let req = request.into_inner();
     debug!(
-        "GetEvents request: from={} to={} limit={}",
-        req.from_timestamp_ms, req.to_timestamp_ms, req.limit
+        "GetEvents request: from={} to={} limit={} types={:?}",
+        req.from_timestamp_ms, req.to_timestamp_ms, req.limit, req.event_types
     );
 
     let limit = if req.limit <= 0 {
@@ -143,22 +298,54 @@ pub async fn get_events(
         .get_events_in_range(req.from_timestamp_ms, req.to_timestamp_ms)
         .map_err(|e| Status::internal(format!("Storage error: {}", e)))?;
 
-    let has_more = raw_events.len() > limit;
-
+    // Apply the type filter while scanning so the limit counts matches only
     let mut events = Vec::new();
-    for (_key, bytes) in raw_events.into_iter().take(limit) {
-        match Event::from_bytes(&bytes) {
-            Ok(event) => events.push(domain_to_proto_event(event)),
+    let mut has_more = false;
+    for (_key, bytes) in raw_events {
+        let event = match Event::from_bytes(&bytes) {
+            Ok(event) => domain_to_proto_event(event),
             Err(e) => {
                 warn!("Failed to deserialize event: {}", e);
                 continue;
             }
+        };
+        if !req.event_types.is_empty() && !req.event_types.contains(&event.event_type) {
+            continue;
         }
+        if events.len() == limit {
+            has_more = true;
+            break;
+        }
+        events.push(event);
     }
 
     Ok(Response::new(GetEventsResponse { events, has_more }))
 }
 
+/// Get a grip's stored record by ID.
+///
+/// Unlike ExpandGrip this reads only the grip itself; `grip` is None for
+/// unknown IDs.
+pub async fn get_grip(
+    storage: Arc<Storage>,
+    request: Request<GetGripRequest>,
+) -> Result<Response<GetGripResponse>, Status> {
+    let req = request.into_inner();
+    debug!("GetGrip request: {}", req.grip_id);
+
+    if req.grip_id.is_empty() {
+        return Err(Status::invalid_argument("grip_id is required"));
+    }
+
+    let grip = storage
+        .get_grip(&req.grip_id)
+        .map_err(|e| Status::internal(format!("Storage error: {}", e)))?;
+
+    Ok(Response::new(GetGripResponse {
+        grip: grip.map(domain_to_proto_grip),
+    }))
+}
+
 /// Expand a grip to show context events.
 ///
 /// Per QRY-05: ExpandGrip retrieves context around grip excerpt.
@@ -256,21 +443,8 @@ pub async fn expand_grip(
         .map(domain_to_proto_event)
         .collect();
 
-    let proto_grip = ProtoGrip {
-        grip_id: grip.grip_id,
-        excerpt: grip.excerpt,
-        event_id_start: grip.event_id_start,
-        event_id_end: grip.event_id_end,
-        timestamp_ms: grip.timestamp.timestamp_millis(),
-        source: grip.source,
-        // Phase 16 fields - defaults for now
-        salience_score: 0.5,
-        memory_kind: ProtoMemoryKind::Observation as i32,
-        is_pinned: false,
-    };
-
     Ok(Response::new(ExpandGripResponse {
-        grip: Some(proto_grip),
+        grip: Some(domain_to_proto_grip(grip)),
         events_before,
         excerpt_events,
         events_after,
@@ -279,14 +453,8 @@ pub async fn expand_grip(
 
 // ===== Type Conversion Functions =====
 
-fn domain_to_proto_node(node: DomainTocNode) -> ProtoTocNode {
-    let level = match node.level {
-        DomainTocLevel::Year => ProtoTocLevel::Year,
-        DomainTocLevel::Month => ProtoTocLevel::Month,
-        DomainTocLevel::Week => ProtoTocLevel::Week,
-        DomainTocLevel::Day => ProtoTocLevel::Day,
-        DomainTocLevel::Segment => ProtoTocLevel::Segment,
-    };
+pub(crate) fn domain_to_proto_node(node: DomainTocNode) -> ProtoTocNode {
+    let level = ProtoTocLevel::from(node.level);
 
     let bullets: Vec<ProtoTocBullet> = node
         .bullets
@@ -331,7 +499,36 @@ fn domain_to_proto_node(node: DomainTocNode) -> ProtoTocNode {
     }
 }
 
-fn domain_to_proto_event(event: Event) -> ProtoEvent {
+pub(crate) fn domain_to_proto_grip(grip: DomainGrip) -> ProtoGrip {
+    let memory_kind = match grip.memory_kind {
+        DomainMemoryKind::Observation => ProtoMemoryKind::Observation,
+        DomainMemoryKind::Preference => ProtoMemoryKind::Preference,
+        DomainMemoryKind::Procedure => ProtoMemoryKind::Procedure,
+        DomainMemoryKind::Constraint => ProtoMemoryKind::Constraint,
+        DomainMemoryKind::Definition => ProtoMemoryKind::Definition,
+    };
+
+    ProtoGrip {
+        grip_id: grip.grip_id,
+        excerpt: grip.excerpt,
+        event_id_start: grip.event_id_start,
+        event_id_end: grip.event_id_end,
+        timestamp_ms: grip.timestamp.timestamp_millis(),
+        source: grip.source,
+        toc_node_id: grip.toc_node_id,
+        salience_score: grip.salience_score,
+        memory_kind: memory_kind as i32,
+        is_pinned: grip.is_pinned,
+        provenance: grip.provenance.is_known().then(|| ProtoGripProvenance {
+            summarizer_id: grip.provenance.summarizer_id,
+            summarizer_version: grip.provenance.summarizer_version,
+            created_at_ms: grip.provenance.created_at.map(|t| t.timestamp_millis()),
+            rebuild_id: grip.provenance.rebuild_id,
+        }),
+    }
+}
+
+pub(crate) fn domain_to_proto_event(event: Event) -> ProtoEvent {
     let event_type = match event.event_type {
         EventType::SessionStart => ProtoEventType::SessionStart,
         EventType::UserMessage => ProtoEventType::UserMessage,
@@ -382,6 +579,33 @@ mod tests {
         assert!(response.into_inner().nodes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_get_toc_root_skips_rolling_windows() {
+        let (storage, _temp) = create_test_storage();
+        for id in [
+            "toc:year:2024",
+            "toc:year:rolling:31536000000:1704067200000",
+        ] {
+            let node = DomainTocNode::new(
+                id.to_string(),
+                DomainTocLevel::Year,
+                id.to_string(),
+                Utc::now(),
+                Utc::now(),
+            );
+            storage.put_toc_node(&node).unwrap();
+        }
+
+        let request = Request::new(GetTocRootRequest {});
+        let nodes = get_toc_root(storage, request)
+            .await
+            .unwrap()
+            .into_inner()
+            .nodes;
+        let ids: Vec<&str> = nodes.iter().map(|n| n.node_id.as_str()).collect();
+        assert_eq!(ids, vec!["toc:year:2024"]);
+    }
+
     #[tokio::test]
     async fn test_get_node_not_found() {
         let (storage, _temp) = create_test_storage();
@@ -402,6 +626,175 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_get_nodes_partitions_found_and_missing() {
+        let (storage, _temp) = create_test_storage();
+        for id in [
+            "toc:day:2024-01-15",
+            "toc:day:2024-01-16",
+            "toc:day:2024-01-17",
+        ] {
+            let node = DomainTocNode::new(
+                id.to_string(),
+                DomainTocLevel::Day,
+                id.to_string(),
+                Utc::now(),
+                Utc::now(),
+            );
+            storage.put_toc_node(&node).unwrap();
+        }
+
+        let request = Request::new(GetNodesRequest {
+            node_ids: [
+                "toc:day:2024-01-17",
+                "toc:missing:a",
+                "toc:day:2024-01-15",
+                "toc:missing:b",
+                "toc:day:2024-01-16",
+            ]
+            .map(String::from)
+            .to_vec(),
+        });
+        let resp = get_nodes(storage, request).await.unwrap().into_inner();
+
+        let found: Vec<&str> = resp.nodes.iter().map(|n| n.node_id.as_str()).collect();
+        assert_eq!(
+            found,
+            [
+                "toc:day:2024-01-17",
+                "toc:day:2024-01-15",
+                "toc:day:2024-01-16"
+            ]
+        );
+        assert_eq!(resp.not_found, ["toc:missing:a", "toc:missing:b"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_nodes_rejects_oversized_request() {
+        let (storage, _temp) = create_test_storage();
+        let request = Request::new(GetNodesRequest {
+            node_ids: vec!["toc:day:2024-01-15".to_string(); MAX_GET_NODES + 1],
+        });
+        let status = get_nodes(storage, request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_node_history_diffs_versions() {
+        let (storage, _temp) = create_test_storage();
+        let now = Utc::now();
+        let mut node = DomainTocNode::new(
+            "toc:day:2024-01-15".to_string(),
+            DomainTocLevel::Day,
+            "Monday".to_string(),
+            now,
+            now,
+        );
+        node.child_node_ids = vec!["toc:segment:2024-01-15:a".to_string()];
+        storage.put_toc_node(&node).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        node.title = "Monday: auth work".to_string();
+        node.child_node_ids
+            .push("toc:segment:2024-01-15:b".to_string());
+        storage.put_toc_node(&node).unwrap();
+
+        let request = Request::new(GetNodeHistoryRequest {
+            node_id: "toc:day:2024-01-15".to_string(),
+        });
+        let changes = get_node_history(storage.clone(), request)
+            .await
+            .unwrap()
+            .into_inner()
+            .changes;
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].version, 1);
+        assert_eq!(changes[0].previous_title, None);
+        assert_eq!(changes[0].children_added, ["toc:segment:2024-01-15:a"]);
+        assert_eq!(changes[1].title, "Monday: auth work");
+        assert_eq!(changes[1].previous_title.as_deref(), Some("Monday"));
+        assert_eq!(changes[1].children_added, ["toc:segment:2024-01-15:b"]);
+        // Each version reports its own write time, not the node's created_at
+        assert!(changes[1].created_at_ms > changes[0].created_at_ms);
+
+        let missing = Request::new(GetNodeHistoryRequest {
+            node_id: "toc:day:1999-01-01".to_string(),
+        });
+        let status = get_node_history(storage, missing).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_get_subtree_stops_at_max_depth() {
+        let (storage, _temp) = create_test_storage();
+        let now = Utc::now();
+        let hierarchy = [
+            (
+                "toc:year:2024",
+                DomainTocLevel::Year,
+                vec!["toc:month:2024-01"],
+            ),
+            (
+                "toc:month:2024-01",
+                DomainTocLevel::Month,
+                vec!["toc:week:2024-W01", "toc:week:2024-W02"],
+            ),
+            (
+                "toc:week:2024-W01",
+                DomainTocLevel::Week,
+                vec!["toc:day:2024-01-01"],
+            ),
+            ("toc:week:2024-W02", DomainTocLevel::Week, vec![]),
+            ("toc:day:2024-01-01", DomainTocLevel::Day, vec![]),
+        ];
+        for (id, level, children) in hierarchy {
+            let mut node = DomainTocNode::new(id.to_string(), level, id.to_string(), now, now);
+            node.child_node_ids = children.into_iter().map(String::from).collect();
+            storage.put_toc_node(&node).unwrap();
+        }
+
+        let request = Request::new(GetSubtreeRequest {
+            root_id: "toc:year:2024".to_string(),
+            max_depth: 2,
+            max_nodes: 0,
+        });
+        let resp = get_subtree(storage.clone(), request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        let nodes: Vec<(&str, Option<&str>, u32)> = resp
+            .nodes
+            .iter()
+            .map(|n| {
+                (
+                    n.node.as_ref().unwrap().node_id.as_str(),
+                    n.parent_id.as_deref(),
+                    n.depth,
+                )
+            })
+            .collect();
+        assert_eq!(
+            nodes,
+            [
+                ("toc:year:2024", None, 0),
+                ("toc:month:2024-01", Some("toc:year:2024"), 1),
+                ("toc:week:2024-W01", Some("toc:month:2024-01"), 2),
+                ("toc:week:2024-W02", Some("toc:month:2024-01"), 2),
+            ]
+        );
+        assert!(!resp.truncated);
+
+        let request = Request::new(GetSubtreeRequest {
+            root_id: "toc:missing".to_string(),
+            max_depth: 2,
+            max_nodes: 0,
+        });
+        let status = get_subtree(storage, request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
     #[tokio::test]
     async fn test_browse_toc_empty() {
         let (storage, _temp) = create_test_storage();
@@ -424,11 +817,85 @@ mod tests {
             from_timestamp_ms: now - 3600000,
             to_timestamp_ms: now,
             limit: 50,
+            event_types: vec![],
         });
         let response = get_events(storage, request).await.unwrap();
         assert!(response.into_inner().events.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_get_events_filters_by_type() {
+        let (storage, _temp) = create_test_storage();
+        let base_ms = Utc::now().timestamp_millis() - 60_000;
+        let kinds = [
+            (EventType::SessionStart, EventRole::System),
+            (EventType::UserMessage, EventRole::User),
+            (EventType::ToolResult, EventRole::Tool),
+            (EventType::AssistantMessage, EventRole::Assistant),
+            (EventType::ToolResult, EventRole::Tool),
+            (EventType::UserMessage, EventRole::User),
+        ];
+        for (i, (event_type, role)) in kinds.into_iter().enumerate() {
+            let timestamp_ms = base_ms + i as i64 * 1000;
+            let event_id = ulid::Ulid::from_parts(timestamp_ms as u64, rand::random()).to_string();
+            let event = Event::new(
+                event_id.clone(),
+                "session-1".to_string(),
+                Utc.timestamp_millis_opt(timestamp_ms).unwrap(),
+                event_type,
+                role,
+                format!("event {}", i),
+            );
+            storage
+                .put_event_only(&event_id, &event.to_bytes().unwrap())
+                .unwrap();
+        }
+
+        let request = |event_types: Vec<i32>, limit: i32| {
+            Request::new(GetEventsRequest {
+                from_timestamp_ms: base_ms,
+                to_timestamp_ms: base_ms + 10_000,
+                limit,
+                event_types,
+            })
+        };
+        let conversation = vec![
+            ProtoEventType::UserMessage as i32,
+            ProtoEventType::AssistantMessage as i32,
+        ];
+
+        let resp = get_events(storage.clone(), request(conversation.clone(), 50))
+            .await
+            .unwrap()
+            .into_inner();
+        let texts: Vec<&str> = resp.events.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["event 1", "event 3", "event 5"]);
+        assert!(resp
+            .events
+            .iter()
+            .all(|e| conversation.contains(&e.event_type)));
+        assert!(resp
+            .events
+            .windows(2)
+            .all(|w| w[0].timestamp_ms < w[1].timestamp_ms));
+        assert!(!resp.has_more);
+
+        // The limit applies to matching events only
+        let resp = get_events(storage.clone(), request(conversation, 2))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(resp.events.len(), 2);
+        assert!(resp.has_more);
+
+        // An empty filter returns every type
+        let resp = get_events(storage, request(vec![], 50))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(resp.events.len(), 6);
+    }
+
     #[tokio::test]
     async fn test_expand_grip_not_found() {
         let (storage, _temp) = create_test_storage();
@@ -442,6 +909,75 @@ mod tests {
         assert!(resp.grip.is_none());
     }
 
+    #[tokio::test]
+    async fn test_get_grip_round_trips_provenance() {
+        let (storage, _temp) = create_test_storage();
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 4, 10, 30, 0).unwrap();
+        let grip = DomainGrip::new(
+            "grip:1772620200000:abc".to_string(),
+            "Always run migrations before deploy".to_string(),
+            "01HQ0000000000000000000001".to_string(),
+            "01HQ0000000000000000000004".to_string(),
+            timestamp,
+            "segment_summarizer".to_string(),
+        )
+        .with_toc_node("toc:segment:2026-03-04:xyz".to_string())
+        .with_salience(0.9, DomainMemoryKind::Constraint, true)
+        .with_provenance(
+            memory_types::GripProvenance::new("api:gpt-4o-mini", "2.7.0").with_rebuild_id("rb-1"),
+        );
+        storage.put_grip(&grip).unwrap();
+
+        let request = Request::new(GetGripRequest {
+            grip_id: grip.grip_id.clone(),
+        });
+        let proto = get_grip(storage, request)
+            .await
+            .unwrap()
+            .into_inner()
+            .grip
+            .unwrap();
+
+        assert_eq!(proto.grip_id, grip.grip_id);
+        assert_eq!(proto.excerpt, grip.excerpt);
+        assert_eq!(proto.event_id_start, grip.event_id_start);
+        assert_eq!(proto.event_id_end, grip.event_id_end);
+        assert_eq!(proto.timestamp_ms, timestamp.timestamp_millis());
+        assert_eq!(proto.source, "segment_summarizer");
+        assert_eq!(
+            proto.toc_node_id.as_deref(),
+            Some("toc:segment:2026-03-04:xyz")
+        );
+        assert!((proto.salience_score - 0.9).abs() < f32::EPSILON);
+        assert_eq!(proto.memory_kind, ProtoMemoryKind::Constraint as i32);
+        assert!(proto.is_pinned);
+
+        let provenance = proto.provenance.unwrap();
+        assert_eq!(provenance.summarizer_id, "api:gpt-4o-mini");
+        assert_eq!(provenance.summarizer_version, "2.7.0");
+        assert_eq!(provenance.rebuild_id.as_deref(), Some("rb-1"));
+        assert_eq!(
+            provenance.created_at_ms,
+            grip.provenance.created_at.map(|t| t.timestamp_millis())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_grip_not_found() {
+        let (storage, _temp) = create_test_storage();
+        let request = Request::new(GetGripRequest {
+            grip_id: "grip:missing".to_string(),
+        });
+        let response = get_grip(storage.clone(), request).await.unwrap();
+        assert!(response.into_inner().grip.is_none());
+
+        let request = Request::new(GetGripRequest {
+            grip_id: String::new(),
+        });
+        let status = get_grip(storage, request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
     #[test]
     fn test_domain_to_proto_node() {
         let node = DomainTocNode::new(
@@ -0,0 +1,166 @@
+//! Per-client rate limiting for IngestEvent.
+//!
+//! Each client (the peer IP address of the connection, plus the event's
+//! agent when set) gets a token bucket that holds up to one second of
+//! requests and refills at the configured rate. A request that finds its
+//! bucket empty is rejected with `RESOURCE_EXHAUSTED`, so one runaway hook
+//! cannot flood storage, and agents sharing a host don't starve each other.
+//!
+//! At most [`MAX_BUCKETS`] are kept; the least recently used is evicted, so
+//! rotating agent names cannot grow the limiter without bound.
+
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use lru::LruCache;
+
+/// Buckets kept before the least recently used one is evicted.
+pub const MAX_BUCKETS: usize = 1024;
+
+/// Token-bucket limiter shared by all ingest calls.
+#[derive(Debug)]
+pub struct IngestRateLimiter {
+    rate_per_sec: u32,
+    buckets: Mutex<LruCache<String, Bucket>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl IngestRateLimiter {
+    /// Allow `rate_per_sec` requests per second per client, with bursts of
+    /// the same size. A rate of 0 is treated as 1.
+    pub fn new(rate_per_sec: u32) -> Self {
+        let capacity = NonZeroUsize::new(MAX_BUCKETS).expect("MAX_BUCKETS is non-zero");
+        Self {
+            rate_per_sec: rate_per_sec.max(1),
+            buckets: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Requests per second allowed for each client.
+    pub fn rate_per_sec(&self) -> u32 {
+        self.rate_per_sec
+    }
+
+    /// Bucket key for a request from `peer` on behalf of `agent`.
+    pub fn client_key(peer: Option<&str>, agent: Option<&str>) -> String {
+        let peer = peer.unwrap_or("unknown");
+        match agent.filter(|agent| !agent.is_empty()) {
+            Some(agent) => format!("{}/{}", peer, agent),
+            None => peer.to_string(),
+        }
+    }
+
+    /// Take a token for `client`, returning false if its bucket is empty.
+    pub fn try_acquire(&self, client: &str) -> bool {
+        self.try_acquire_at(client, Instant::now())
+    }
+
+    fn try_acquire_at(&self, client: &str, now: Instant) -> bool {
+        let capacity = f64::from(self.rate_per_sec);
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        let bucket = buckets.get_or_insert_mut(client.to_string(), || Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        bucket.tokens = bucket.refilled(now, capacity);
+        bucket.updated = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Bucket {
+    /// Tokens available at `now`, capped at `capacity`.
+    fn refilled(&self, now: Instant, capacity: f64) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        (self.tokens + elapsed * capacity).min(capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_burst_above_rate_is_rejected() {
+        let limiter = IngestRateLimiter::new(5);
+        let now = Instant::now();
+
+        let accepted = (0..20)
+            .filter(|_| limiter.try_acquire_at("agent-a", now))
+            .count();
+        assert_eq!(accepted, 5);
+
+        // Other clients have their own bucket
+        assert!(limiter.try_acquire_at("agent-b", now));
+    }
+
+    #[test]
+    fn test_client_key_includes_agent() {
+        let key = IngestRateLimiter::client_key;
+        assert_eq!(key(Some("10.0.0.1"), Some("claude")), "10.0.0.1/claude");
+        assert_eq!(key(Some("10.0.0.1"), None), "10.0.0.1");
+        assert_eq!(key(Some("10.0.0.1"), Some("")), "10.0.0.1");
+        assert_eq!(key(None, Some("claude")), "unknown/claude");
+    }
+
+    #[test]
+    fn test_rate_respected_over_time() {
+        let limiter = IngestRateLimiter::new(10);
+        let start = Instant::now();
+
+        // 100 requests per second for three seconds
+        let mut accepted = 0;
+        for i in 0..300u64 {
+            let at = start + Duration::from_millis(i * 10);
+            if limiter.try_acquire_at("agent", at) {
+                accepted += 1;
+            }
+        }
+
+        // Initial burst of 10 plus 10/s refill over ~3 seconds
+        assert!(accepted <= 10 + 30, "accepted {accepted}");
+        assert!(accepted >= 35, "accepted {accepted}");
+    }
+
+    #[test]
+    fn test_bucket_count_is_bounded() {
+        let limiter = IngestRateLimiter::new(1);
+        let now = Instant::now();
+        assert!(limiter.try_acquire_at("10.0.0.1", now));
+        assert!(!limiter.try_acquire_at("10.0.0.1", now));
+
+        for i in 0..MAX_BUCKETS {
+            assert!(limiter.try_acquire_at(&format!("peer-{i}"), now));
+        }
+        assert_eq!(limiter.buckets.lock().unwrap().len(), MAX_BUCKETS);
+
+        // The least recently used bucket was evicted
+        assert!(limiter.try_acquire_at("10.0.0.1", now));
+    }
+
+    #[test]
+    fn test_recently_used_bucket_survives_eviction() {
+        let limiter = IngestRateLimiter::new(1);
+        let now = Instant::now();
+        assert!(limiter.try_acquire_at("10.0.0.1", now));
+
+        for i in 0..MAX_BUCKETS * 2 {
+            // The busy client keeps its (empty) bucket while others churn
+            assert!(!limiter.try_acquire_at("10.0.0.1", now));
+            assert!(limiter.try_acquire_at(&format!("peer-{i}"), now));
+        }
+    }
+}
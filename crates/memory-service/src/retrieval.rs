@@ -2,24 +2,35 @@
 //!
 //! Implements the Phase 17 Agent Retrieval Policy RPCs:
 //! - GetRetrievalCapabilities: Combined status check for all retrieval layers
+//! - WatchRetrievalCapabilities: Stream capabilities when the tier changes
 //! - ClassifyQueryIntent: Classify query intent and extract time constraints
+//! - ClassifyQueryIntentBatch: Classify many queries in one call
 //! - RouteQuery: Route query through optimal layers with explainability
 //!
 //! Per PRD: Agent Retrieval Policy - intent routing, tier detection, fallbacks.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use tokio::sync::{mpsc, watch};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
-use tracing::{debug, info};
+use tracing::{debug, info, info_span, Instrument};
 
 use memory_retrieval::{
     classifier::IntentClassifier,
-    executor::{FallbackChain, LayerExecutor, RetrievalExecutor, SearchResult},
+    executor::{
+        CircuitBreaker, FallbackChainConfig, LayerExecutor, RetrievalExecutor, SearchResult,
+    },
     ranking::{apply_combined_ranking, RankingConfig},
     stale_filter::StaleFilter,
+    tier::{
+        LayerStatusProvider, TierDetectionResult, TierWatcher, DEFAULT_WATCH_DEBOUNCE,
+        DEFAULT_WATCH_POLL_INTERVAL,
+    },
     types::{
         CapabilityTier as CrateTier, CombinedStatus, ExecutionMode as CrateExecMode,
         LayerStatus as CrateLayerStatus, QueryIntent as CrateIntent, RetrievalLayer as CrateLayer,
@@ -30,19 +41,37 @@ use memory_search::TeleportSearcher;
 use memory_storage::Storage;
 use memory_types::config::StalenessConfig;
 
+use crate::conversions::domain_from_i32;
+use crate::degraded::DegradedMode;
 use crate::federated::federated_query;
 
 use crate::pb::{
-    CapabilityTier as ProtoTier, ClassifyQueryIntentRequest, ClassifyQueryIntentResponse,
-    ExecutionMode as ProtoExecMode, ExplainabilityPayload as ProtoExplainability,
-    GetRetrievalCapabilitiesRequest, GetRetrievalCapabilitiesResponse,
+    CapabilityTier as ProtoTier, ClassifyQueryIntentBatchRequest, ClassifyQueryIntentBatchResponse,
+    ClassifyQueryIntentRequest, ClassifyQueryIntentResponse, ExecutionMode as ProtoExecMode,
+    ExplainabilityPayload as ProtoExplainability, GetRetrievalCapabilitiesRequest,
+    GetRetrievalCapabilitiesResponse, LayerLatency as ProtoLayerLatency,
     LayerStatus as ProtoLayerStatus, QueryIntent as ProtoIntent, RetrievalLayer as ProtoLayer,
     RetrievalResult as ProtoResult, RouteQueryRequest, RouteQueryResponse,
-    StopConditions as ProtoStopConditions,
+    StopConditions as ProtoStopConditions, WatchRetrievalCapabilitiesRequest,
 };
 use crate::topics::TopicGraphHandler;
 use crate::vector::VectorTeleportHandler;
 
+/// Most queries accepted by one ClassifyQueryIntentBatch call.
+pub const MAX_CLASSIFY_BATCH: usize = 1000;
+
+/// Shortest poll interval the shared capabilities watcher may use.
+pub const MIN_WATCH_POLL_MS: u64 = 50;
+
+/// Default cap on the number of results one RouteQuery call may return.
+pub const DEFAULT_MAX_ROUTE_LIMIT: u32 = 100;
+
+/// Limit used when a RouteQuery request leaves `limit` unset.
+const DEFAULT_ROUTE_LIMIT: u32 = 10;
+
+/// Stream returned by WatchRetrievalCapabilities.
+pub type CapabilitiesStream = ReceiverStream<Result<GetRetrievalCapabilitiesResponse, Status>>;
+
 /// Handler for retrieval policy RPCs.
 pub struct RetrievalHandler {
     /// Storage for direct access
@@ -69,6 +98,29 @@ pub struct RetrievalHandler {
 
     /// Path of the primary store (used for result attribution).
     primary_db_path: String,
+
+    /// Per-layer circuit breaker, shared across queries
+    circuit_breaker: Arc<CircuitBreaker>,
+
+    /// Features disabled at startup, reported in capabilities.
+    /// Settable through a shared handle, like the two fields below.
+    degraded_mode: RwLock<DegradedMode>,
+
+    /// Per-intent, per-tier fallback chain customization
+    fallback_config: RwLock<FallbackChainConfig>,
+
+    /// Largest limit a RouteQuery request may ask for
+    max_route_limit: AtomicU32,
+
+    /// Tier watcher shared by all WatchRetrievalCapabilities streams.
+    /// Held weakly so the watcher stops when the last stream ends.
+    tier_watch: Mutex<Weak<watch::Receiver<TierDetectionResult>>>,
+
+    /// Poll interval of the shared tier watcher
+    watch_poll_interval: Duration,
+
+    /// Debounce of the shared tier watcher
+    watch_debounce: Duration,
 }
 
 impl RetrievalHandler {
@@ -83,6 +135,13 @@ impl RetrievalHandler {
             staleness_config: StalenessConfig::default(),
             registered_projects: Vec::new(),
             primary_db_path: String::new(),
+            circuit_breaker: Arc::new(CircuitBreaker::default()),
+            degraded_mode: RwLock::new(DegradedMode::default()),
+            fallback_config: RwLock::new(FallbackChainConfig::default()),
+            max_route_limit: AtomicU32::new(DEFAULT_MAX_ROUTE_LIMIT),
+            tier_watch: Mutex::new(Weak::new()),
+            watch_poll_interval: DEFAULT_WATCH_POLL_INTERVAL,
+            watch_debounce: DEFAULT_WATCH_DEBOUNCE,
         }
     }
 
@@ -103,9 +162,21 @@ impl RetrievalHandler {
             staleness_config,
             registered_projects: Vec::new(),
             primary_db_path: String::new(),
+            circuit_breaker: Arc::new(CircuitBreaker::default()),
+            degraded_mode: RwLock::new(DegradedMode::default()),
+            fallback_config: RwLock::new(FallbackChainConfig::default()),
+            max_route_limit: AtomicU32::new(DEFAULT_MAX_ROUTE_LIMIT),
+            tier_watch: Mutex::new(Weak::new()),
+            watch_poll_interval: DEFAULT_WATCH_POLL_INTERVAL,
+            watch_debounce: DEFAULT_WATCH_DEBOUNCE,
         }
     }
 
+    /// Record features disabled at startup so capabilities can explain them.
+    pub fn set_degraded_mode(&self, mode: DegradedMode) {
+        *self.degraded_mode.write().unwrap() = mode;
+    }
+
     /// Set registered project paths for cross-project federation (v3.0).
     pub fn with_registered_projects(
         mut self,
@@ -117,6 +188,39 @@ impl RetrievalHandler {
         self
     }
 
+    /// Builder form of [`Self::set_fallback_config`].
+    pub fn with_fallback_config(self, config: FallbackChainConfig) -> Self {
+        self.set_fallback_config(config);
+        self
+    }
+
+    /// Customize the layer sequence tried for each intent and tier.
+    pub fn set_fallback_config(&self, config: FallbackChainConfig) {
+        *self.fallback_config.write().unwrap() = config;
+    }
+
+    /// Builder form of [`Self::set_max_route_limit`].
+    pub fn with_max_route_limit(self, max_route_limit: u32) -> Self {
+        self.set_max_route_limit(max_route_limit);
+        self
+    }
+
+    /// Cap the number of results a single RouteQuery call may return.
+    /// Requests above the cap are clamped and the clamp is noted in the
+    /// explanation.
+    pub fn set_max_route_limit(&self, max_route_limit: u32) {
+        self.max_route_limit
+            .store(max_route_limit.max(1), Ordering::Relaxed);
+    }
+
+    /// Set the poll interval and debounce of the shared tier watcher
+    /// behind WatchRetrievalCapabilities.
+    pub fn with_watch_timing(mut self, poll_interval: Duration, debounce: Duration) -> Self {
+        self.watch_poll_interval = poll_interval.max(Duration::from_millis(MIN_WATCH_POLL_MS));
+        self.watch_debounce = debounce;
+        self
+    }
+
     /// Handle GetRetrievalCapabilities RPC.
     ///
     /// Per PRD Section 5.2: Combined status check pattern.
@@ -136,7 +240,12 @@ impl RetrievalHandler {
         // Check Vector status
         let vector_status = self.check_vector_status().await;
         if !vector_status.enabled {
-            warnings.push("Vector index not configured".to_string());
+            warnings.push(
+                vector_status
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| "Vector index not configured".to_string()),
+            );
         }
 
         // Check Topics status
@@ -174,7 +283,7 @@ impl RetrievalHandler {
         );
 
         Ok(Response::new(GetRetrievalCapabilitiesResponse {
-            tier: tier_to_proto(tier) as i32,
+            tier: ProtoTier::from(tier) as i32,
             bm25_status: Some(bm25_status),
             vector_status: Some(vector_status),
             topics_status: Some(topics_status),
@@ -184,6 +293,73 @@ impl RetrievalHandler {
         }))
     }
 
+    /// Handle WatchRetrievalCapabilities RPC.
+    ///
+    /// All streams share one [`TierWatcher`], started by the first
+    /// subscriber and stopped when the last one goes away. Each stream gets
+    /// a full capabilities snapshot whenever the tier changes. The current
+    /// tier is not sent; callers get it from GetRetrievalCapabilities.
+    /// Timing is the handler's (see [`Self::with_watch_timing`]), not the
+    /// caller's.
+    pub async fn watch_retrieval_capabilities(
+        self: Arc<Self>,
+        _request: Request<WatchRetrievalCapabilitiesRequest>,
+    ) -> Result<Response<CapabilitiesStream>, Status> {
+        let shared = self.shared_tier_watch().await;
+        let mut changes = (*shared).clone();
+        debug!(tier = ?changes.borrow_and_update().tier, "Watching retrieval capabilities");
+
+        let (sender, receiver) = mpsc::channel(4);
+        tokio::spawn(async move {
+            // Keeps the shared watcher alive while this stream is open
+            let _shared = shared;
+            loop {
+                tokio::select! {
+                    change = changes.changed() => {
+                        if change.is_err() {
+                            break;
+                        }
+                        changes.borrow_and_update();
+                    }
+                    _ = sender.closed() => break,
+                }
+                let snapshot = self
+                    .get_retrieval_capabilities(Request::new(GetRetrievalCapabilitiesRequest {}))
+                    .await
+                    .map(Response::into_inner);
+                if sender.send(snapshot).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    /// Get the running shared tier watcher, starting one if none is live.
+    async fn shared_tier_watch(self: &Arc<Self>) -> Arc<watch::Receiver<TierDetectionResult>> {
+        if let Some(shared) = self.tier_watch.lock().unwrap().upgrade() {
+            return shared;
+        }
+
+        let provider = Arc::new(HandlerStatusProvider(self.clone()));
+        let started = TierWatcher::new(provider)
+            .with_poll_interval(self.watch_poll_interval)
+            .with_debounce(self.watch_debounce)
+            .start()
+            .await;
+
+        // Another subscriber may have started one while we detected the
+        // baseline; keep theirs so only one watcher polls.
+        let mut slot = self.tier_watch.lock().unwrap();
+        if let Some(shared) = slot.upgrade() {
+            return shared;
+        }
+        let shared = Arc::new(started);
+        *slot = Arc::downgrade(&shared);
+        shared
+    }
+
     /// Handle ClassifyQueryIntent RPC.
     ///
     /// Per PRD Section 4: Intent classification with keyword heuristics.
@@ -197,18 +373,57 @@ impl RetrievalHandler {
             return Err(Status::invalid_argument("Query is required"));
         }
 
+        Ok(Response::new(self.classify_one(&req.query, req.timeout_ms)))
+    }
+
+    /// Handle ClassifyQueryIntentBatch RPC.
+    ///
+    /// Each query is classified exactly as ClassifyQueryIntent would, with
+    /// `timeout_ms` applying to each query on its own. Results keep the
+    /// request order.
+    pub async fn classify_query_intent_batch(
+        &self,
+        request: Request<ClassifyQueryIntentBatchRequest>,
+    ) -> Result<Response<ClassifyQueryIntentBatchResponse>, Status> {
+        let req = request.into_inner();
+
+        if req.queries.len() > MAX_CLASSIFY_BATCH {
+            return Err(Status::invalid_argument(format!(
+                "At most {} queries per batch, got {}",
+                MAX_CLASSIFY_BATCH,
+                req.queries.len()
+            )));
+        }
+        if let Some(index) = req.queries.iter().position(|q| q.is_empty()) {
+            return Err(Status::invalid_argument(format!(
+                "Query {} is empty",
+                index
+            )));
+        }
+
+        let results = req
+            .queries
+            .iter()
+            .map(|query| self.classify_one(query, req.timeout_ms))
+            .collect();
+
+        Ok(Response::new(ClassifyQueryIntentBatchResponse { results }))
+    }
+
+    /// Classify one non-empty query.
+    fn classify_one(&self, query: &str, timeout_ms: Option<u64>) -> ClassifyQueryIntentResponse {
         // Build stop conditions for classification
-        let _stop_conditions = if let Some(timeout_ms) = req.timeout_ms {
+        let _stop_conditions = if let Some(timeout_ms) = timeout_ms {
             CrateStopConditions::with_timeout(Duration::from_millis(timeout_ms))
         } else {
             CrateStopConditions::default()
         };
 
         // Classify the query
-        let classification = self.classifier.classify(&req.query);
+        let classification = self.classifier.classify(query);
 
         debug!(
-            query = %req.query,
+            query = %query,
             intent = ?classification.intent,
             confidence = classification.confidence,
             "Query classified"
@@ -221,24 +436,37 @@ impl RetrievalHandler {
             .and_then(|tc| tc.lookback.map(|d| d.as_millis() as u64))
             .unwrap_or(0);
 
-        Ok(Response::new(ClassifyQueryIntentResponse {
-            intent: intent_to_proto(classification.intent) as i32,
+        ClassifyQueryIntentResponse {
+            intent: ProtoIntent::from(classification.intent) as i32,
             confidence: classification.confidence,
             reason: classification.reason,
             matched_keywords: classification.matched_keywords,
             lookback_ms: Some(lookback_ms),
-        }))
+        }
     }
 
     /// Handle RouteQuery RPC.
     ///
     /// Per PRD Section 5.4: Route through optimal layers with fallbacks.
+    /// Each call gets a `request_id` that tags the `route_query` span and the
+    /// executor and layer spans beneath it, and is returned in the response.
     pub async fn route_query(
         &self,
         request: Request<RouteQueryRequest>,
     ) -> Result<Response<RouteQueryResponse>, Status> {
-        let req = request.into_inner();
+        let request_id = ulid::Ulid::new().to_string();
+        let span = info_span!("route_query", request_id = %request_id);
+        self.route_query_traced(request.into_inner(), request_id)
+            .instrument(span)
+            .await
+    }
 
+    /// Body of `route_query`, run inside its tracing span.
+    async fn route_query_traced(
+        &self,
+        req: RouteQueryRequest,
+        request_id: String,
+    ) -> Result<Response<RouteQueryResponse>, Status> {
         if req.query.is_empty() {
             return Err(Status::invalid_argument("Query is required"));
         }
@@ -251,7 +479,7 @@ impl RetrievalHandler {
 
         // Classify intent or use override
         let intent = if let Some(override_intent) = req.intent_override {
-            proto_to_intent(ProtoIntent::try_from(override_intent).unwrap_or(ProtoIntent::Answer))
+            domain_from_i32::<ProtoIntent, CrateIntent>(override_intent).unwrap_or_default()
         } else {
             self.classifier.classify(&req.query).intent
         };
@@ -261,9 +489,7 @@ impl RetrievalHandler {
 
         // Get execution mode
         let mode = if let Some(override_mode) = req.mode_override {
-            proto_to_exec_mode(
-                ProtoExecMode::try_from(override_mode).unwrap_or(ProtoExecMode::Sequential),
-            )
+            domain_from_i32::<ProtoExecMode, CrateExecMode>(override_mode).unwrap_or_default()
         } else {
             // Default: Sequential for most, Parallel for complex
             match intent {
@@ -274,15 +500,24 @@ impl RetrievalHandler {
             }
         };
 
-        let limit = if req.limit > 0 {
-            req.limit as usize
+        let mut notes = Vec::new();
+        let requested_limit = if req.limit > 0 {
+            req.limit as u32
         } else {
-            10
+            DEFAULT_ROUTE_LIMIT
         };
+        let limit = requested_limit.clamp(1, self.max_route_limit.load(Ordering::Relaxed));
+        if limit != requested_limit {
+            notes.push(format!(
+                "limit {} clamped to {} (max_route_limit)",
+                requested_limit, limit
+            ));
+        }
+        let limit = limit as usize;
 
         // Execute the retrieval
         let start = Instant::now();
-        let chain = FallbackChain::for_intent(intent, tier);
+        let chain = self.fallback_config.read().unwrap().chain_for(intent, tier);
 
         // Create a simple executor that delegates to our services
         let executor = Arc::new(SimpleLayerExecutor::new(
@@ -292,7 +527,9 @@ impl RetrievalHandler {
             self.topic_handler.clone(),
         ));
 
-        let retrieval_executor = RetrievalExecutor::new(executor);
+        let retrieval_executor = RetrievalExecutor::new(executor)
+            .with_circuit_breaker(self.circuit_breaker.clone())
+            .with_request_id(request_id.clone());
         let result = retrieval_executor
             .execute(&req.query, chain, &stop_conditions, mode, tier)
             .await;
@@ -348,7 +585,7 @@ impl RetrievalHandler {
                 doc_type: r.doc_type.clone(),
                 score: r.score,
                 text_preview: r.text_preview.clone(),
-                source_layer: layer_to_proto(r.source_layer) as i32,
+                source_layer: ProtoLayer::from(r.source_layer) as i32,
                 metadata: r.metadata.clone(),
                 agent: r.metadata.get("agent").cloned(),
                 project: r.metadata.get("project").cloned(),
@@ -357,15 +594,15 @@ impl RetrievalHandler {
 
         // Build explainability payload
         let explanation = ProtoExplainability {
-            intent: intent_to_proto(intent) as i32,
-            tier: tier_to_proto(tier) as i32,
-            mode: exec_mode_to_proto(mode) as i32,
+            intent: ProtoIntent::from(intent) as i32,
+            tier: ProtoTier::from(tier) as i32,
+            mode: ProtoExecMode::from(mode) as i32,
             candidates_considered: result
                 .layers_attempted
                 .iter()
-                .map(|l| layer_to_proto(*l) as i32)
+                .map(|l| ProtoLayer::from(*l) as i32)
                 .collect(),
-            winner: layer_to_proto(result.primary_layer) as i32,
+            winner: ProtoLayer::from(result.primary_layer) as i32,
             why_winner: result.explanation.clone(),
             fallback_occurred: result.fallback_occurred,
             fallback_reason: if result.fallback_occurred {
@@ -374,11 +611,20 @@ impl RetrievalHandler {
                 None
             },
             total_time_ms,
+            layer_latencies: result
+                .layer_latencies
+                .iter()
+                .map(|(layer, latency)| ProtoLayerLatency {
+                    layer: ProtoLayer::from(*layer) as i32,
+                    latency_ms: latency.as_millis() as u64,
+                })
+                .collect(),
             grip_ids: results
                 .iter()
                 .filter(|r| r.doc_type == "grip")
                 .map(|r| r.doc_id.clone())
                 .collect(),
+            notes,
         };
 
         let has_results = !results.is_empty();
@@ -400,8 +646,9 @@ impl RetrievalHandler {
             layers_attempted: result
                 .layers_attempted
                 .iter()
-                .map(|l| layer_to_proto(*l) as i32)
+                .map(|l| ProtoLayer::from(*l) as i32)
                 .collect(),
+            request_id,
         }))
     }
 
@@ -454,7 +701,12 @@ impl RetrievalHandler {
                 enabled: false,
                 healthy: false,
                 doc_count: 0,
-                message: Some("Vector index not configured".to_string()),
+                message: Some(
+                    match self.degraded_mode.read().unwrap().vector_disabled_reason() {
+                        Some(reason) => format!("Vector search disabled: {}", reason),
+                        None => "Vector index not configured".to_string(),
+                    },
+                ),
             },
         }
     }
@@ -501,6 +753,24 @@ impl RetrievalHandler {
     }
 }
 
+/// Layer status of a shared [`RetrievalHandler`], for tier watching.
+struct HandlerStatusProvider(Arc<RetrievalHandler>);
+
+#[async_trait]
+impl LayerStatusProvider for HandlerStatusProvider {
+    async fn get_bm25_status(&self) -> Result<CrateLayerStatus, String> {
+        Ok(layer_status_from_proto(&self.0.check_bm25_status().await))
+    }
+
+    async fn get_vector_status(&self) -> Result<CrateLayerStatus, String> {
+        Ok(layer_status_from_proto(&self.0.check_vector_status().await))
+    }
+
+    async fn get_topics_status(&self) -> Result<CrateLayerStatus, String> {
+        Ok(layer_status_from_proto(&self.0.check_topics_status().await))
+    }
+}
+
 /// Simple layer executor that delegates to available services.
 struct SimpleLayerExecutor {
     _storage: Arc<Storage>,
@@ -718,13 +988,8 @@ fn build_metadata(
 
 /// Convert proto LayerStatus to crate LayerStatus.
 fn layer_status_from_proto(proto: &ProtoLayerStatus) -> CrateLayerStatus {
-    let layer = match ProtoLayer::try_from(proto.layer) {
-        Ok(ProtoLayer::Bm25) => CrateLayer::BM25,
-        Ok(ProtoLayer::Vector) => CrateLayer::Vector,
-        Ok(ProtoLayer::Topics) => CrateLayer::Topics,
-        Ok(ProtoLayer::Hybrid) => CrateLayer::Hybrid,
-        Ok(ProtoLayer::Agentic) | Ok(ProtoLayer::Unspecified) | Err(_) => CrateLayer::Agentic,
-    };
+    let layer =
+        domain_from_i32::<ProtoLayer, CrateLayer>(proto.layer).unwrap_or(CrateLayer::Agentic);
 
     if !proto.enabled {
         CrateLayerStatus::disabled(layer)
@@ -735,62 +1000,6 @@ fn layer_status_from_proto(proto: &ProtoLayerStatus) -> CrateLayerStatus {
     }
 }
 
-fn tier_to_proto(tier: CrateTier) -> ProtoTier {
-    match tier {
-        CrateTier::Full => ProtoTier::Full,
-        CrateTier::Hybrid => ProtoTier::Hybrid,
-        CrateTier::Semantic => ProtoTier::Semantic,
-        CrateTier::Keyword => ProtoTier::Keyword,
-        CrateTier::Agentic => ProtoTier::Agentic,
-    }
-}
-
-fn intent_to_proto(intent: CrateIntent) -> ProtoIntent {
-    match intent {
-        CrateIntent::Explore => ProtoIntent::Explore,
-        CrateIntent::Answer => ProtoIntent::Answer,
-        CrateIntent::Locate => ProtoIntent::Locate,
-        CrateIntent::TimeBoxed => ProtoIntent::TimeBoxed,
-    }
-}
-
-fn proto_to_intent(proto: ProtoIntent) -> CrateIntent {
-    match proto {
-        ProtoIntent::Explore => CrateIntent::Explore,
-        ProtoIntent::Answer => CrateIntent::Answer,
-        ProtoIntent::Locate => CrateIntent::Locate,
-        ProtoIntent::TimeBoxed => CrateIntent::TimeBoxed,
-        ProtoIntent::Unspecified => CrateIntent::Answer, // Default
-    }
-}
-
-fn layer_to_proto(layer: CrateLayer) -> ProtoLayer {
-    match layer {
-        CrateLayer::Topics => ProtoLayer::Topics,
-        CrateLayer::Hybrid => ProtoLayer::Hybrid,
-        CrateLayer::Vector => ProtoLayer::Vector,
-        CrateLayer::BM25 => ProtoLayer::Bm25,
-        CrateLayer::Agentic => ProtoLayer::Agentic,
-    }
-}
-
-fn exec_mode_to_proto(mode: CrateExecMode) -> ProtoExecMode {
-    match mode {
-        CrateExecMode::Sequential => ProtoExecMode::Sequential,
-        CrateExecMode::Parallel => ProtoExecMode::Parallel,
-        CrateExecMode::Hybrid => ProtoExecMode::Hybrid,
-    }
-}
-
-fn proto_to_exec_mode(proto: ProtoExecMode) -> CrateExecMode {
-    match proto {
-        ProtoExecMode::Sequential => CrateExecMode::Sequential,
-        ProtoExecMode::Parallel => CrateExecMode::Parallel,
-        ProtoExecMode::Hybrid => CrateExecMode::Hybrid,
-        ProtoExecMode::Unspecified => CrateExecMode::Sequential, // Default
-    }
-}
-
 fn proto_to_stop_conditions(proto: &ProtoStopConditions) -> CrateStopConditions {
     let mut conditions = CrateStopConditions::default();
 
@@ -854,6 +1063,137 @@ mod tests {
         assert!(!resp.topics_status.unwrap().enabled);
     }
 
+    #[tokio::test]
+    async fn test_get_retrieval_capabilities_degraded_vector() {
+        use chrono::Utc;
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+        use memory_types::{TocLevel, TocNode};
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("db")).unwrap());
+        let index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("search")))
+                .unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&TocNode::new(
+                "toc:day:2024-01-15".to_string(),
+                TocLevel::Day,
+                "Monday".to_string(),
+                Utc::now(),
+                Utc::now(),
+            ))
+            .unwrap();
+        indexer.commit().unwrap();
+        let searcher = Arc::new(TeleportSearcher::new(&index).unwrap());
+
+        let handler = Arc::new(RetrievalHandler::with_services(
+            storage,
+            Some(searcher),
+            None,
+            None,
+            StalenessConfig::default(),
+        ));
+        // Set through one handle while another is held elsewhere
+        let shared = handler.clone();
+        let mut degraded = DegradedMode::new();
+        degraded.disable_vector("embedding model not found");
+        handler.set_degraded_mode(degraded);
+
+        let resp = shared
+            .get_retrieval_capabilities(Request::new(GetRetrievalCapabilitiesRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        // BM25 keeps working, so the tier degrades to Keyword rather than failing
+        assert_eq!(resp.tier, ProtoTier::Keyword as i32);
+        assert!(resp.bm25_status.unwrap().healthy);
+
+        let vector = resp.vector_status.unwrap();
+        assert!(!vector.enabled);
+        assert_eq!(
+            vector.message.as_deref(),
+            Some("Vector search disabled: embedding model not found")
+        );
+        assert!(resp
+            .warnings
+            .iter()
+            .any(|w| w.contains("embedding model not found")));
+    }
+
+    #[tokio::test]
+    async fn test_watch_retrieval_capabilities_reports_bm25_online() {
+        use chrono::Utc;
+        use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
+        use memory_types::{TocLevel, TocNode};
+        use tokio_stream::StreamExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("db")).unwrap());
+        let index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("search")))
+                .unwrap();
+        let searcher = Arc::new(TeleportSearcher::new(&index).unwrap());
+        let handler = Arc::new(
+            RetrievalHandler::with_services(
+                storage,
+                Some(searcher.clone()),
+                None,
+                None,
+                StalenessConfig::default(),
+            )
+            .with_watch_timing(
+                Duration::from_millis(MIN_WATCH_POLL_MS),
+                Duration::from_millis(100),
+            ),
+        );
+
+        let subscribe = || {
+            handler
+                .clone()
+                .watch_retrieval_capabilities(Request::new(WatchRetrievalCapabilitiesRequest {}))
+        };
+        let mut stream = subscribe().await.unwrap().into_inner();
+        let mut other = subscribe().await.unwrap().into_inner();
+
+        // Both streams hold the same watcher
+        let shared = handler.tier_watch.lock().unwrap().clone();
+        assert_eq!(shared.strong_count(), 2);
+
+        // Empty BM25 index: Agentic tier until a document lands
+        let indexer = SearchIndexer::new(&index).unwrap();
+        indexer
+            .index_toc_node(&TocNode::new(
+                "toc:day:2024-01-15".to_string(),
+                TocLevel::Day,
+                "Monday".to_string(),
+                Utc::now(),
+                Utc::now(),
+            ))
+            .unwrap();
+        indexer.commit().unwrap();
+        searcher.reload().unwrap();
+
+        let update = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("tier change streamed")
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.tier, ProtoTier::Keyword as i32);
+        assert!(update.bm25_status.unwrap().healthy);
+
+        let update = tokio::time::timeout(Duration::from_secs(5), other.next())
+            .await
+            .expect("tier change streamed to second subscriber")
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.tier, ProtoTier::Keyword as i32);
+
+        let next = tokio::time::timeout(Duration::from_millis(500), stream.next()).await;
+        assert!(next.is_err(), "only one tier change expected");
+    }
+
     #[tokio::test]
     async fn test_classify_query_intent_explore() {
         let (handler, _temp) = create_test_handler();
@@ -888,6 +1228,56 @@ mod tests {
         assert_eq!(resp.intent, ProtoIntent::Locate as i32);
     }
 
+    #[tokio::test]
+    async fn test_classify_query_intent_batch_matches_single() {
+        let (handler, _temp) = create_test_handler();
+        let queries = vec![
+            "what topics did we discuss about rust?".to_string(),
+            "find the exact error message about auth".to_string(),
+            "what did we decide yesterday about the schema?".to_string(),
+        ];
+
+        let batch = handler
+            .classify_query_intent_batch(Request::new(ClassifyQueryIntentBatchRequest {
+                queries: queries.clone(),
+                timeout_ms: Some(500),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(batch.results.len(), queries.len());
+        assert_eq!(batch.results[0].intent, ProtoIntent::Explore as i32);
+        assert_eq!(batch.results[1].intent, ProtoIntent::Locate as i32);
+        for (query, result) in queries.into_iter().zip(&batch.results) {
+            let single = handler
+                .classify_query_intent(Request::new(ClassifyQueryIntentRequest {
+                    query,
+                    timeout_ms: Some(500),
+                }))
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(&single, result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_classify_query_intent_batch_rejects_empty_query() {
+        let (handler, _temp) = create_test_handler();
+
+        let result = handler
+            .classify_query_intent_batch(Request::new(ClassifyQueryIntentBatchRequest {
+                queries: vec!["what is rust?".to_string(), String::new()],
+                timeout_ms: None,
+            }))
+            .await;
+
+        let status = result.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("Query 1"));
+    }
+
     #[tokio::test]
     async fn test_classify_query_empty_query() {
         let (handler, _temp) = create_test_handler();
@@ -927,6 +1317,9 @@ mod tests {
 
         // Should have attempted at least agentic layer
         assert!(!resp.layers_attempted.is_empty());
+
+        // Should carry a ULID correlation ID
+        assert!(ulid::Ulid::from_string(&resp.request_id).is_ok());
     }
 
     #[tokio::test]
@@ -949,25 +1342,59 @@ mod tests {
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
 
+    #[tokio::test]
+    async fn test_route_query_clamps_absurd_limit() {
+        let (handler, _temp) = create_test_handler();
+        let handler = handler.with_max_route_limit(5);
+
+        let response = handler
+            .route_query(Request::new(RouteQueryRequest {
+                query: "what is rust?".to_string(),
+                intent_override: None,
+                stop_conditions: None,
+                mode_override: None,
+                limit: 1_000_000,
+                agent_filter: None,
+                all_projects: false,
+            }))
+            .await
+            .unwrap();
+
+        let resp = response.into_inner();
+        assert!(resp.results.len() <= 5);
+
+        let explanation = resp.explanation.unwrap();
+        assert!(explanation
+            .notes
+            .iter()
+            .any(|n| n == "limit 1000000 clamped to 5 (max_route_limit)"));
+    }
+
     #[test]
     fn test_tier_conversion() {
-        assert_eq!(tier_to_proto(CrateTier::Full), ProtoTier::Full);
-        assert_eq!(tier_to_proto(CrateTier::Hybrid), ProtoTier::Hybrid);
-        assert_eq!(tier_to_proto(CrateTier::Semantic), ProtoTier::Semantic);
-        assert_eq!(tier_to_proto(CrateTier::Keyword), ProtoTier::Keyword);
-        assert_eq!(tier_to_proto(CrateTier::Agentic), ProtoTier::Agentic);
+        assert_eq!(ProtoTier::from(CrateTier::Full), ProtoTier::Full);
+        assert_eq!(ProtoTier::from(CrateTier::Hybrid), ProtoTier::Hybrid);
+        assert_eq!(ProtoTier::from(CrateTier::Semantic), ProtoTier::Semantic);
+        assert_eq!(ProtoTier::from(CrateTier::Keyword), ProtoTier::Keyword);
+        assert_eq!(ProtoTier::from(CrateTier::Agentic), ProtoTier::Agentic);
     }
 
     #[test]
     fn test_intent_conversion() {
-        assert_eq!(intent_to_proto(CrateIntent::Explore), ProtoIntent::Explore);
-        assert_eq!(intent_to_proto(CrateIntent::Answer), ProtoIntent::Answer);
-        assert_eq!(intent_to_proto(CrateIntent::Locate), ProtoIntent::Locate);
         assert_eq!(
-            intent_to_proto(CrateIntent::TimeBoxed),
+            ProtoIntent::from(CrateIntent::Explore),
+            ProtoIntent::Explore
+        );
+        assert_eq!(ProtoIntent::from(CrateIntent::Answer), ProtoIntent::Answer);
+        assert_eq!(ProtoIntent::from(CrateIntent::Locate), ProtoIntent::Locate);
+        assert_eq!(
+            ProtoIntent::from(CrateIntent::TimeBoxed),
             ProtoIntent::TimeBoxed
         );
 
+        let proto_to_intent = |p: ProtoIntent| {
+            domain_from_i32::<ProtoIntent, CrateIntent>(p as i32).unwrap_or_default()
+        };
         assert_eq!(proto_to_intent(ProtoIntent::Explore), CrateIntent::Explore);
         assert_eq!(proto_to_intent(ProtoIntent::Answer), CrateIntent::Answer);
         assert_eq!(
@@ -997,7 +1424,7 @@ mod tests {
             doc_type: result.doc_type.clone(),
             score: result.score,
             text_preview: result.text_preview.clone(),
-            source_layer: layer_to_proto(result.source_layer) as i32,
+            source_layer: ProtoLayer::from(result.source_layer) as i32,
             metadata: result.metadata.clone(),
             agent: result.metadata.get("agent").cloned(),
             project: result.metadata.get("project").cloned(),
@@ -1018,7 +1445,7 @@ mod tests {
             doc_type: result_no_agent.doc_type.clone(),
             score: result_no_agent.score,
             text_preview: result_no_agent.text_preview.clone(),
-            source_layer: layer_to_proto(result_no_agent.source_layer) as i32,
+            source_layer: ProtoLayer::from(result_no_agent.source_layer) as i32,
             metadata: result_no_agent.metadata.clone(),
             agent: result_no_agent.metadata.get("agent").cloned(),
             project: result_no_agent.metadata.get("project").cloned(),
@@ -3,21 +3,27 @@
 //! Per SCHED-05: Job status observable via gRPC.
 //!
 //! This module provides gRPC handlers for scheduler status and control:
-//! - GetSchedulerStatus: Returns scheduler running state and all job statuses
+//! - GetSchedulerStatus: Returns scheduler running state, all job statuses
+//!   and the outbox lag (with a warning when indexing falls behind)
 //! - PauseJob: Pauses a scheduled job
 //! - ResumeJob: Resumes a paused job
 
 use std::sync::Arc;
 
 use tonic::{Request, Response, Status};
+use tracing::warn;
 
 use memory_scheduler::{JobRegistry, JobResult, SchedulerService};
+use memory_storage::Storage;
 
 use crate::pb::{
     GetSchedulerStatusRequest, GetSchedulerStatusResponse, JobResultStatus, JobStatusProto,
     PauseJobRequest, PauseJobResponse, ResumeJobRequest, ResumeJobResponse,
 };
 
+/// Default outbox lag above which scheduler status warns.
+pub const DEFAULT_OUTBOX_LAG_WARN_THRESHOLD: u64 = 10_000;
+
 /// Convert domain JobResult to proto enum and error message.
 fn job_result_to_proto(result: &JobResult) -> (JobResultStatus, Option<String>) {
     match result {
@@ -46,12 +52,30 @@ fn job_result_to_proto(result: &JobResult) -> (JobResultStatus, Option<String>)
 /// ```
 pub struct SchedulerGrpcService {
     scheduler: Arc<SchedulerService>,
+    /// Storage whose outbox lag is reported (None = not reported)
+    outbox_storage: Option<Arc<Storage>>,
+    outbox_lag_warn_threshold: u64,
 }
 
 impl SchedulerGrpcService {
     /// Create a new SchedulerGrpcService with the given scheduler.
     pub fn new(scheduler: Arc<SchedulerService>) -> Self {
-        Self { scheduler }
+        Self {
+            scheduler,
+            outbox_storage: None,
+            outbox_lag_warn_threshold: DEFAULT_OUTBOX_LAG_WARN_THRESHOLD,
+        }
+    }
+
+    /// Report the outbox lag of `storage` in scheduler status.
+    pub fn with_outbox(mut self, storage: Arc<Storage>) -> Self {
+        self.outbox_storage = Some(storage);
+        self
+    }
+
+    /// Set the outbox lag above which scheduler status warns.
+    pub fn set_outbox_lag_warn_threshold(&mut self, threshold: u64) {
+        self.outbox_lag_warn_threshold = threshold;
     }
 
     /// Get the job registry.
@@ -95,9 +119,30 @@ impl SchedulerGrpcService {
             })
             .collect();
 
+        let outbox_lag = match &self.outbox_storage {
+            Some(storage) => storage
+                .outbox_lag()
+                .map_err(|e| Status::internal(format!("Failed to read outbox lag: {}", e)))?,
+            None => 0,
+        };
+        let outbox_warning = (outbox_lag > self.outbox_lag_warn_threshold).then(|| {
+            let message = format!(
+                "Outbox lag {} exceeds threshold {}; indexing is falling behind ingestion",
+                outbox_lag, self.outbox_lag_warn_threshold
+            );
+            warn!(
+                outbox_lag,
+                threshold = self.outbox_lag_warn_threshold,
+                "Indexing is falling behind ingestion"
+            );
+            message
+        });
+
         Ok(Response::new(GetSchedulerStatusResponse {
             scheduler_running: self.scheduler.is_running(),
             jobs,
+            outbox_lag,
+            outbox_warning,
         }))
     }
 
@@ -167,6 +212,35 @@ mod tests {
         assert!(resp.jobs.is_empty());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_scheduler_status_outbox_lag_warning() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(temp_dir.path()).unwrap());
+        for i in 0..3 {
+            let event_id = ulid::Ulid::new().to_string();
+            let entry = memory_types::OutboxEntry::for_index(event_id.clone(), i * 1000);
+            storage
+                .put_event(&event_id, b"test", &entry.to_bytes().unwrap())
+                .unwrap();
+        }
+
+        let scheduler = create_test_scheduler().await;
+        let mut service = SchedulerGrpcService::new(scheduler).with_outbox(storage);
+
+        let request = Request::new(GetSchedulerStatusRequest {});
+        let resp = service.get_scheduler_status(request).await.unwrap();
+        let resp = resp.into_inner();
+        assert_eq!(resp.outbox_lag, 3);
+        assert!(resp.outbox_warning.is_none());
+
+        service.set_outbox_lag_warn_threshold(2);
+        let request = Request::new(GetSchedulerStatusRequest {});
+        let resp = service.get_scheduler_status(request).await.unwrap();
+        let resp = resp.into_inner();
+        assert_eq!(resp.outbox_lag, 3);
+        assert!(resp.outbox_warning.unwrap().contains("exceeds threshold 2"));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_get_scheduler_status_with_jobs() {
         let scheduler = create_test_scheduler().await;
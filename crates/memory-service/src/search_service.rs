@@ -1,6 +1,10 @@
 //! Search RPC implementations.
 //!
 //! Per SEARCH-01, SEARCH-02: TOC node search via term matching.
+//!
+//! A positive `token_budget` caps the estimated tokens of match text in a
+//! response. Matches are kept most relevant first; the first match that does
+//! not fit is truncated and everything after it is dropped.
 
 use std::cmp::Ordering;
 use std::sync::Arc;
@@ -13,8 +17,10 @@ use memory_toc::search::{
     search_node as core_search_node, SearchField as DomainSearchField,
     SearchMatch as DomainSearchMatch,
 };
-use memory_types::TocLevel as DomainTocLevel;
+use memory_toc::{default_token_counter, TokenCounter};
+use memory_types::{is_rolling_node_id, TocLevel as DomainTocLevel};
 
+use crate::conversions::domain_from_i32;
 use crate::pb::{
     SearchChildrenRequest, SearchChildrenResponse, SearchField as ProtoSearchField,
     SearchMatch as ProtoSearchMatch, SearchNodeRequest, SearchNodeResponse,
@@ -23,23 +29,13 @@ use crate::pb::{
 
 /// Convert proto SearchField to domain SearchField.
 fn proto_to_domain_field(proto: i32) -> Option<DomainSearchField> {
-    match ProtoSearchField::try_from(proto) {
-        Ok(ProtoSearchField::Title) => Some(DomainSearchField::Title),
-        Ok(ProtoSearchField::Summary) => Some(DomainSearchField::Summary),
-        Ok(ProtoSearchField::Bullets) => Some(DomainSearchField::Bullets),
-        Ok(ProtoSearchField::Keywords) => Some(DomainSearchField::Keywords),
-        _ => None, // Unspecified or invalid
-    }
+    // Unspecified or invalid values have no domain field
+    domain_from_i32::<ProtoSearchField, _>(proto).ok()
 }
 
 /// Convert domain SearchField to proto SearchField.
 fn domain_to_proto_field(domain: DomainSearchField) -> i32 {
-    match domain {
-        DomainSearchField::Title => ProtoSearchField::Title as i32,
-        DomainSearchField::Summary => ProtoSearchField::Summary as i32,
-        DomainSearchField::Bullets => ProtoSearchField::Bullets as i32,
-        DomainSearchField::Keywords => ProtoSearchField::Keywords as i32,
-    }
+    ProtoSearchField::from(domain) as i32
 }
 
 /// Convert domain SearchMatch to proto SearchMatch.
@@ -52,15 +48,75 @@ fn domain_to_proto_match(m: DomainSearchMatch) -> ProtoSearchMatch {
     }
 }
 
+/// Tool-result truncation limit for the token counter (unused for plain text).
+const COUNTER_MAX_TOOL_RESULT_CHARS: usize = 1000;
+
+/// Token counter for search `token_budget`s.
+///
+/// Loading the BPE tables is not cheap, so the service builds this once
+/// and passes it to every search call.
+pub fn budget_token_counter() -> Arc<dyn TokenCounter> {
+    default_token_counter(COUNTER_MAX_TOOL_RESULT_CHARS)
+}
+
+/// Trim matches (ordered most relevant first) to fit `budget` tokens of text.
+///
+/// Returns the kept matches and the unused budget. The first match that does
+/// not fit is truncated to the remaining budget; later matches are dropped.
+fn apply_token_budget(
+    matches: Vec<ProtoSearchMatch>,
+    budget: usize,
+    counter: &dyn TokenCounter,
+) -> (Vec<ProtoSearchMatch>, usize) {
+    let mut remaining = budget;
+    let mut kept = Vec::with_capacity(matches.len());
+
+    for mut m in matches {
+        let tokens = counter.count_text(&m.text);
+        if tokens <= remaining {
+            remaining -= tokens;
+            kept.push(m);
+            continue;
+        }
+        if let Some(text) = truncate_to_tokens(&m.text, remaining, counter) {
+            remaining -= counter.count_text(&text);
+            m.text = text;
+            kept.push(m);
+        }
+        break;
+    }
+
+    (kept, remaining)
+}
+
+/// Longest prefix of `text` (at a char boundary) within `max_tokens`.
+///
+/// Returns None if not even a single character fits.
+fn truncate_to_tokens(text: &str, max_tokens: usize, counter: &dyn TokenCounter) -> Option<String> {
+    let boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .skip(1)
+        .chain(std::iter::once(text.len()))
+        .collect();
+
+    // Binary search for the longest prefix that fits
+    let (mut lo, mut hi) = (0, boundaries.len());
+    while lo < hi {
+        let mid = (lo + hi).div_ceil(2);
+        if counter.count_text(&text[..boundaries[mid - 1]]) <= max_tokens {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    (lo > 0).then(|| text[..boundaries[lo - 1]].trim_end().to_string())
+}
+
 /// Convert domain TocLevel to proto TocLevel.
 fn domain_to_proto_level(level: DomainTocLevel) -> i32 {
-    match level {
-        DomainTocLevel::Year => ProtoTocLevel::Year as i32,
-        DomainTocLevel::Month => ProtoTocLevel::Month as i32,
-        DomainTocLevel::Week => ProtoTocLevel::Week as i32,
-        DomainTocLevel::Day => ProtoTocLevel::Day as i32,
-        DomainTocLevel::Segment => ProtoTocLevel::Segment as i32,
-    }
+    ProtoTocLevel::from(level) as i32
 }
 
 /// Search within a single TOC node.
@@ -68,6 +124,7 @@ fn domain_to_proto_level(level: DomainTocLevel) -> i32 {
 /// Per SEARCH-01: SearchNode searches node's fields for query terms.
 pub async fn search_node(
     storage: Arc<Storage>,
+    counter: &dyn TokenCounter,
     request: Request<SearchNodeRequest>,
 ) -> Result<Response<SearchNodeResponse>, Status> {
     let req = request.into_inner();
@@ -106,12 +163,16 @@ pub async fn search_node(
     } else {
         10
     };
-    let matches: Vec<ProtoSearchMatch> = matches
+    let mut matches: Vec<ProtoSearchMatch> = matches
         .into_iter()
         .take(limit)
         .map(domain_to_proto_match)
         .collect();
 
+    if req.token_budget > 0 {
+        (matches, _) = apply_token_budget(matches, req.token_budget as usize, counter);
+    }
+
     Ok(Response::new(SearchNodeResponse {
         matched: !matches.is_empty(),
         matches,
@@ -125,6 +186,7 @@ pub async fn search_node(
 /// Per SEARCH-02: SearchChildren searches all children of parent.
 pub async fn search_children(
     storage: Arc<Storage>,
+    counter: &dyn TokenCounter,
     request: Request<SearchChildrenRequest>,
 ) -> Result<Response<SearchChildrenResponse>, Status> {
     let req = request.into_inner();
@@ -142,6 +204,9 @@ pub async fn search_children(
         storage
             .get_toc_nodes_by_level(DomainTocLevel::Year, None, None)
             .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+            .into_iter()
+            .filter(|n| !is_rolling_node_id(&n.node_id))
+            .collect()
     } else {
         storage
             .get_child_nodes(&req.parent_id)
@@ -186,8 +251,25 @@ pub async fn search_children(
     } else {
         10
     };
-    let has_more = results.len() > limit;
-    let results: Vec<ProtoSearchNodeResult> = results.into_iter().take(limit).collect();
+    let mut has_more = results.len() > limit;
+    let mut results: Vec<ProtoSearchNodeResult> = results.into_iter().take(limit).collect();
+
+    if req.token_budget > 0 {
+        let mut remaining = req.token_budget as usize;
+        let mut kept = Vec::with_capacity(results.len());
+        for mut result in results {
+            let (matches, left) =
+                apply_token_budget(std::mem::take(&mut result.matches), remaining, counter);
+            remaining = left;
+            if matches.is_empty() {
+                has_more = true;
+                break;
+            }
+            result.matches = matches;
+            kept.push(result);
+        }
+        results = kept;
+    }
 
     Ok(Response::new(SearchChildrenResponse { results, has_more }))
 }
@@ -195,6 +277,9 @@ pub async fn search_children(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
+    use memory_toc::HeuristicTokenCounter;
+    use memory_types::{TocBullet, TocNode};
     use tempfile::TempDir;
 
     fn create_test_storage() -> (Arc<Storage>, TempDir) {
@@ -213,7 +298,7 @@ mod tests {
             limit: 10,
             token_budget: 0,
         });
-        let result = search_node(storage, request).await;
+        let result = search_node(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::NotFound);
     }
@@ -228,7 +313,7 @@ mod tests {
             limit: 10,
             token_budget: 0,
         });
-        let result = search_node(storage, request).await;
+        let result = search_node(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
@@ -243,7 +328,7 @@ mod tests {
             limit: 10,
             token_budget: 0,
         });
-        let result = search_node(storage, request).await;
+        let result = search_node(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
@@ -258,7 +343,7 @@ mod tests {
             limit: 10,
             token_budget: 0,
         });
-        let result = search_node(storage, request).await;
+        let result = search_node(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
@@ -274,7 +359,7 @@ mod tests {
             limit: 10,
             token_budget: 0,
         });
-        let result = search_children(storage, request).await;
+        let result = search_children(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
@@ -291,13 +376,110 @@ mod tests {
             token_budget: 0,
         });
         // Should succeed with empty results (no nodes in storage)
-        let result = search_children(storage, request).await;
+        let result = search_children(storage, budget_token_counter().as_ref(), request).await;
         assert!(result.is_ok());
         let response = result.unwrap().into_inner();
         assert!(response.results.is_empty());
         assert!(!response.has_more);
     }
 
+    fn text_match(text: &str, score: f32) -> ProtoSearchMatch {
+        ProtoSearchMatch {
+            field: ProtoSearchField::Bullets as i32,
+            text: text.to_string(),
+            grip_ids: vec![],
+            score,
+        }
+    }
+
+    #[test]
+    fn test_apply_token_budget() {
+        // Heuristic counter: 4 chars per token
+        let counter = HeuristicTokenCounter::new(1000);
+        let matches = vec![
+            text_match(&"a".repeat(40), 0.9),
+            text_match(&"b".repeat(40), 0.5),
+            text_match(&"c".repeat(40), 0.1),
+        ];
+
+        let (kept, remaining) = apply_token_budget(matches.clone(), 100, &counter);
+        assert_eq!(kept.len(), 3);
+        assert_eq!(remaining, 70);
+
+        // Second match is truncated, third dropped
+        let (kept, remaining) = apply_token_budget(matches.clone(), 15, &counter);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].text, "a".repeat(40));
+        assert_eq!(kept[1].text, "b".repeat(23));
+        assert_eq!(remaining, 0);
+
+        let (kept, _) = apply_token_budget(matches, 0, &counter);
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn test_truncate_to_tokens_char_boundary() {
+        let counter = HeuristicTokenCounter::new(1000);
+        let text = "héllo wörld ünïcode";
+        let truncated = truncate_to_tokens(text, 2, &counter).unwrap();
+        assert!(text.starts_with(&truncated));
+        assert!(counter.count_text(&truncated) <= 2);
+        assert_eq!(truncate_to_tokens(text, 0, &counter), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_node_token_budget() {
+        let (storage, _temp) = create_test_storage();
+        let mut node = TocNode::new(
+            "toc:day:2026-01-15".to_string(),
+            DomainTocLevel::Day,
+            "Rust work".to_string(),
+            Utc::now(),
+            Utc::now(),
+        );
+        let filler = "and then we reviewed the details at great length ".repeat(10);
+        node.bullets = vec![
+            TocBullet::new(format!("rust borrow checker lifetimes {}", filler)),
+            TocBullet::new(format!("rust borrow {}", filler)),
+            TocBullet::new(format!("rust {}", filler)),
+        ];
+        storage.put_toc_node(&node).unwrap();
+
+        let request = |token_budget| {
+            Request::new(SearchNodeRequest {
+                node_id: node.node_id.clone(),
+                query: "rust borrow checker lifetimes".to_string(),
+                fields: vec![ProtoSearchField::Bullets as i32],
+                limit: 10,
+                token_budget,
+            })
+        };
+
+        let counter = budget_token_counter();
+        let unbounded = search_node(storage.clone(), counter.as_ref(), request(0))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(unbounded.matches.len(), 3);
+
+        let budget = 150;
+        let bounded = search_node(storage, counter.as_ref(), request(budget))
+            .await
+            .unwrap()
+            .into_inner();
+        let used: usize = bounded
+            .matches
+            .iter()
+            .map(|m| counter.count_text(&m.text))
+            .sum();
+        assert!(used <= budget as usize);
+        assert!(bounded.matches.len() < unbounded.matches.len());
+
+        // The highest-scoring match is kept intact
+        assert_eq!(bounded.matches[0].text, unbounded.matches[0].text);
+        assert!(bounded.matches[0].score >= unbounded.matches[1].score);
+    }
+
     #[test]
     fn test_proto_to_domain_field_title() {
         let result = proto_to_domain_field(ProtoSearchField::Title as i32);
@@ -6,19 +6,27 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tonic::transport::Server;
 use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use tracing::info;
+use tracing::{info, warn};
 
+use memory_retrieval::FallbackChainConfig;
 use memory_scheduler::SchedulerService;
+use memory_search::TeleportSearcher;
 use memory_storage::Storage;
 use memory_types::config::StalenessConfig;
 
+use crate::degraded::DegradedMode;
+use crate::drain::{serve_with_drain, InFlightRequests};
+use crate::hybrid::HybridCacheConfig;
 use crate::ingest::MemoryServiceImpl;
 use crate::novelty::NoveltyChecker;
 use crate::pb::{memory_service_server::MemoryServiceServer, FILE_DESCRIPTOR_SET};
+use crate::rate_limit::IngestRateLimiter;
+use crate::redaction::Redactor;
 
 /// Run the gRPC server with health check and reflection.
 ///
@@ -64,10 +72,13 @@ pub async fn run_server(
 /// Run the gRPC server with graceful shutdown support.
 ///
 /// Accepts a shutdown signal future that, when resolved, triggers graceful shutdown.
+/// The server then stops accepting connections and waits up to `drain_timeout`
+/// for in-flight requests before aborting them.
 pub async fn run_server_with_shutdown<F>(
     addr: SocketAddr,
     storage: Arc<Storage>,
     shutdown_signal: F,
+    drain_timeout: Duration,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
 where
     F: std::future::Future<Output = ()> + Send + 'static,
@@ -92,12 +103,18 @@ where
 
     info!("gRPC server ready on {}", addr);
 
-    Server::builder()
+    let in_flight = InFlightRequests::new();
+    let (draining_tx, draining_rx) = tokio::sync::oneshot::channel();
+    let serve = Server::builder()
+        .layer(in_flight.layer())
         .add_service(health_service)
         .add_service(reflection_service)
         .add_service(MemoryServiceServer::new(memory_service))
-        .serve_with_shutdown(addr, shutdown_signal)
-        .await?;
+        .serve_with_shutdown(addr, async move {
+            shutdown_signal.await;
+            let _ = draining_tx.send(());
+        });
+    serve_with_drain(serve, draining_rx, &in_flight, drain_timeout).await?;
 
     info!("gRPC server shutdown complete");
     Ok(())
@@ -113,13 +130,35 @@ where
 ///
 /// The scheduler service is injected into MemoryServiceImpl to handle
 /// scheduler-related RPCs (GetSchedulerStatus, PauseJob, ResumeJob).
+/// `teleport_searcher`, if set, serves BM25 search and retrieval; it is
+/// shared with the caller, which may have warmed it up.
+/// `degraded_mode` lists features that failed to initialize; the server
+/// still starts and reports them via GetRetrievalCapabilities.
+/// `outbox_lag_warn_threshold` sets when GetSchedulerStatus warns that
+/// indexing is falling behind. `ingest_rate_limit_per_sec`, if set, caps
+/// IngestEvent calls per client. `max_route_limit` caps the results one
+/// RouteQuery call may return. `fallback_config` customizes the retrieval
+/// layer sequence. `redactor`, if set, strips secrets from
+/// tool results before storage. `hybrid_cache` sizes the HybridSearch
+/// result cache. In-flight requests are drained for up to
+/// `drain_timeout` after the shutdown signal.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_server_with_scheduler<F>(
     addr: SocketAddr,
     storage: Arc<Storage>,
     scheduler: SchedulerService,
     shutdown_signal: F,
     novelty_checker: Option<Arc<NoveltyChecker>>,
+    teleport_searcher: Option<Arc<TeleportSearcher>>,
     staleness_config: StalenessConfig,
+    degraded_mode: DegradedMode,
+    outbox_lag_warn_threshold: u64,
+    ingest_rate_limit_per_sec: Option<u32>,
+    max_route_limit: u32,
+    fallback_config: FallbackChainConfig,
+    redactor: Option<Arc<Redactor>>,
+    hybrid_cache: HybridCacheConfig,
+    drain_timeout: Duration,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
 where
     F: std::future::Future<Output = ()> + Send + 'static,
@@ -148,32 +187,68 @@ where
         .build_v1()?;
 
     // Main service implementation with scheduler
-    let mut memory_service =
-        MemoryServiceImpl::with_scheduler(storage, scheduler.clone(), staleness_config);
+    let mut memory_service = match teleport_searcher {
+        Some(searcher) => MemoryServiceImpl::with_scheduler_and_search(
+            storage,
+            scheduler.clone(),
+            searcher,
+            staleness_config,
+        ),
+        None => MemoryServiceImpl::with_scheduler(storage, scheduler.clone(), staleness_config),
+    };
     if let Some(checker) = novelty_checker {
         memory_service.set_novelty_checker(checker);
     }
+    if degraded_mode.is_degraded() {
+        info!(
+            vector = ?degraded_mode.vector_disabled_reason(),
+            "Starting in degraded mode"
+        );
+    }
+    memory_service.set_degraded_mode(degraded_mode);
+    memory_service.set_outbox_lag_warn_threshold(outbox_lag_warn_threshold);
+    if let Some(rate) = ingest_rate_limit_per_sec {
+        info!(rate, "Ingest rate limit enabled (events/s per client)");
+        memory_service.set_ingest_rate_limiter(Arc::new(IngestRateLimiter::new(rate)));
+    }
+    memory_service.set_max_route_limit(max_route_limit);
+    memory_service.set_fallback_config(fallback_config);
+    memory_service.set_hybrid_cache(hybrid_cache);
+    if let Some(redactor) = redactor.filter(|r| !r.is_empty()) {
+        memory_service.set_redactor(redactor);
+    }
 
     info!("gRPC server ready on {}", addr);
 
-    // Run server until shutdown signal
-    Server::builder()
+    // Run server until shutdown signal, then drain in-flight requests
+    let in_flight = InFlightRequests::new();
+    let (draining_tx, draining_rx) = tokio::sync::oneshot::channel();
+    let serve = Server::builder()
+        .layer(in_flight.layer())
         .add_service(health_service)
         .add_service(reflection_service)
         .add_service(MemoryServiceServer::new(memory_service))
-        .serve_with_shutdown(addr, shutdown_signal)
-        .await?;
+        .serve_with_shutdown(addr, async move {
+            shutdown_signal.await;
+            let _ = draining_tx.send(());
+        });
+    serve_with_drain(serve, draining_rx, &in_flight, drain_timeout).await?;
 
     info!("gRPC server shutdown, stopping scheduler...");
 
-    // Shutdown scheduler - need to get mutable access
-    // Arc::get_mut won't work here since we have multiple references,
-    // so we use try_unwrap after dropping other references
-    drop(scheduler); // Drop our reference, MemoryServiceImpl's reference is already gone since server stopped
-
-    // Note: In a production system, you might want a different approach
-    // such as using a separate shutdown channel. For now, the scheduler
-    // will be dropped when this function returns, triggering implicit cleanup.
+    // The service's reference went away with the server, so this is
+    // normally the last one and running jobs get the configured grace
+    match Arc::try_unwrap(scheduler) {
+        Ok(mut scheduler) => {
+            if let Err(e) = scheduler.shutdown().await {
+                warn!("Scheduler shutdown failed: {}", e);
+            }
+        }
+        Err(scheduler) => {
+            warn!("Scheduler still shared at shutdown; cancelling jobs without waiting");
+            scheduler.shutdown_token().cancel();
+        }
+    }
 
     info!("Server shutdown complete");
     Ok(())
@@ -182,6 +257,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scheduler_service::DEFAULT_OUTBOX_LAG_WARN_THRESHOLD;
     use std::time::Duration;
     use tempfile::TempDir;
     use tokio::time::timeout;
@@ -197,9 +273,14 @@ mod tests {
         let (tx, rx) = tokio::sync::oneshot::channel::<()>();
 
         let server_handle = tokio::spawn(async move {
-            run_server_with_shutdown(addr, storage, async {
-                rx.await.ok();
-            })
+            run_server_with_shutdown(
+                addr,
+                storage,
+                async {
+                    rx.await.ok();
+                },
+                crate::drain::DEFAULT_DRAIN_TIMEOUT,
+            )
             .await
         });
 
@@ -213,4 +294,52 @@ mod tests {
         let result = timeout(Duration::from_secs(5), server_handle).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_server_starts_in_degraded_mode() {
+        use memory_scheduler::SchedulerConfig;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(temp_dir.path()).unwrap());
+        let scheduler = SchedulerService::new(SchedulerConfig::default())
+            .await
+            .unwrap();
+
+        // Simulate the embedder failing to load at startup
+        let mut degraded = DegradedMode::new();
+        degraded.disable_vector("model not downloaded");
+
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+        let server_handle = tokio::spawn(async move {
+            run_server_with_scheduler(
+                addr,
+                storage,
+                scheduler,
+                async {
+                    rx.await.ok();
+                },
+                None,
+                None,
+                StalenessConfig::default(),
+                degraded,
+                DEFAULT_OUTBOX_LAG_WARN_THRESHOLD,
+                None,
+                crate::retrieval::DEFAULT_MAX_ROUTE_LIMIT,
+                FallbackChainConfig::default(),
+                None,
+                HybridCacheConfig::default(),
+                crate::drain::DEFAULT_DRAIN_TIMEOUT,
+            )
+            .await
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!server_handle.is_finished(), "server exited during startup");
+
+        tx.send(()).ok();
+        let result = timeout(Duration::from_secs(5), server_handle).await;
+        assert!(result.unwrap().unwrap().is_ok());
+    }
 }
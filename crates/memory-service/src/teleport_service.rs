@@ -1,16 +1,34 @@
 //! Teleport search handler.
 //!
-//! Provides BM25 keyword search over TOC nodes and grips.
+//! Provides BM25 keyword search over TOC nodes and grips, and over raw
+//! events when event indexing is enabled.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use memory_search::{DocType, SearchOptions, TeleportSearcher};
+use memory_storage::Storage;
+use memory_types::Event;
 use tonic::{Request, Response, Status};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::pb::{
-    TeleportDocType, TeleportSearchRequest, TeleportSearchResponse, TeleportSearchResult,
+    EventSearchResult, NoResultsReason as ProtoNoResultsReason, SearchEventsRequest,
+    SearchEventsResponse, TeleportDocType, TeleportSearchRequest, TeleportSearchResponse,
+    TeleportSearchResult,
 };
+use crate::query::domain_to_proto_event;
+
+/// Per-type document counts, or none if they cannot be read.
+///
+/// Counts only annotate a response, so a failure is logged rather than
+/// failing a search that already has results.
+pub(crate) fn doc_counts_or_empty(searcher: &TeleportSearcher) -> HashMap<DocType, u64> {
+    searcher.doc_counts_by_type().unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to count indexed documents");
+        HashMap::new()
+    })
+}
 
 /// Handle TeleportSearch RPC.
 pub async fn handle_teleport_search(
@@ -30,7 +48,11 @@ pub async fn handle_teleport_search(
     } else {
         10
     };
-    options = options.with_limit(limit);
+    options = options
+        .with_limit(limit)
+        .with_time_range(req.start_ms, req.end_ms)
+        .with_min_score(req.min_score)
+        .with_normalized_scores(req.normalize_scores);
 
     // Set doc type filter
     if req.doc_type == TeleportDocType::TocNode as i32 {
@@ -40,15 +62,25 @@ pub async fn handle_teleport_search(
     }
 
     // Execute search (blocking operation, use spawn_blocking)
+    // and explain an empty result while the options are at hand
     let query = req.query.clone();
     let searcher_clone = searcher.clone();
-    let results = tokio::task::spawn_blocking(move || searcher_clone.search(&query, options))
-        .await
-        .map_err(|e| Status::internal(format!("Search task failed: {}", e)))?
-        .map_err(|e| Status::internal(format!("Search failed: {}", e)))?;
-
-    // Get total docs
+    let (results, no_results_reason) = tokio::task::spawn_blocking(move || {
+        let results = searcher_clone.search(&query, options.clone())?;
+        let reason = if results.is_empty() {
+            Some(searcher_clone.explain_no_results(&query, &options)?)
+        } else {
+            None
+        };
+        Ok::<_, memory_search::SearchError>((results, reason))
+    })
+    .await
+    .map_err(|e| Status::internal(format!("Search task failed: {}", e)))?
+    .map_err(|e| Status::internal(format!("Search failed: {}", e)))?;
+
+    // Get total docs and the per-type breakdown
     let total_docs = searcher.num_docs();
+    let counts = doc_counts_or_empty(&searcher);
 
     // Map to proto results
     let proto_results: Vec<TeleportSearchResult> = results
@@ -58,6 +90,7 @@ pub async fn handle_teleport_search(
             doc_type: match r.doc_type {
                 DocType::TocNode => TeleportDocType::TocNode as i32,
                 DocType::Grip => TeleportDocType::Grip as i32,
+                DocType::Event => TeleportDocType::Unspecified as i32,
             },
             score: r.score,
             keywords: r.keywords,
@@ -69,6 +102,61 @@ pub async fn handle_teleport_search(
     Ok(Response::new(TeleportSearchResponse {
         results: proto_results,
         total_docs,
+        toc_node_docs: counts.get(&DocType::TocNode).copied().unwrap_or(0),
+        grip_docs: counts.get(&DocType::Grip).copied().unwrap_or(0),
+        no_results_reason: no_results_reason.map(|r| ProtoNoResultsReason::from(r) as i32),
+    }))
+}
+
+/// Handle SearchEvents RPC.
+///
+/// Searches raw event text and loads each hit from storage. Hits whose
+/// event is no longer in storage are skipped.
+pub async fn handle_search_events(
+    searcher: Arc<TeleportSearcher>,
+    storage: Arc<Storage>,
+    request: Request<SearchEventsRequest>,
+) -> Result<Response<SearchEventsResponse>, Status> {
+    let req = request.into_inner();
+
+    debug!(query = %req.query, "Processing event search");
+
+    // Set limit (default 10, max 100)
+    let limit = if req.limit > 0 {
+        (req.limit as usize).min(100)
+    } else {
+        10
+    };
+
+    let query = req.query.clone();
+    let searcher_clone = searcher.clone();
+    let hits = tokio::task::spawn_blocking(move || searcher_clone.search_events(&query, limit))
+        .await
+        .map_err(|e| Status::internal(format!("Search task failed: {}", e)))?
+        .map_err(|e| Status::internal(format!("Search failed: {}", e)))?;
+
+    let mut results = Vec::with_capacity(hits.len());
+    for hit in hits {
+        let bytes = storage
+            .get_event(&hit.doc_id)
+            .map_err(|e| Status::internal(format!("Storage error: {}", e)))?;
+        let Some(bytes) = bytes else {
+            warn!(event_id = %hit.doc_id, "Indexed event missing from storage");
+            continue;
+        };
+        let event = Event::from_bytes(&bytes)
+            .map_err(|e| Status::internal(format!("Failed to decode event: {}", e)))?;
+        results.push(EventSearchResult {
+            event: Some(domain_to_proto_event(event)),
+            score: hit.score,
+        });
+    }
+
+    let counts = doc_counts_or_empty(&searcher);
+
+    Ok(Response::new(SearchEventsResponse {
+        results,
+        event_docs: counts.get(&DocType::Event).copied().unwrap_or(0),
     }))
 }
 
@@ -77,7 +165,7 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use memory_search::{SearchIndex, SearchIndexConfig, SearchIndexer};
-    use memory_types::{Grip, TocBullet, TocLevel, TocNode};
+    use memory_types::{EventRole, EventType, Grip, TocBullet, TocLevel, TocNode};
     use tempfile::TempDir;
 
     fn sample_toc_node(id: &str, title: &str, bullet: &str) -> TocNode {
@@ -136,6 +224,10 @@ mod tests {
             doc_type: TeleportDocType::Unspecified as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -144,6 +236,8 @@ mod tests {
         // Should find both node and grip
         assert_eq!(resp.results.len(), 2);
         assert!(resp.total_docs >= 2);
+        assert_eq!(resp.toc_node_docs, 1);
+        assert_eq!(resp.grip_docs, 1);
     }
 
     #[tokio::test]
@@ -155,6 +249,10 @@ mod tests {
             doc_type: TeleportDocType::TocNode as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -174,6 +272,10 @@ mod tests {
             doc_type: TeleportDocType::Grip as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -193,6 +295,10 @@ mod tests {
             doc_type: TeleportDocType::Unspecified as i32,
             limit: 1,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -211,6 +317,10 @@ mod tests {
             doc_type: TeleportDocType::Unspecified as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -229,12 +339,20 @@ mod tests {
             doc_type: TeleportDocType::Unspecified as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
         let resp = response.into_inner();
 
         assert!(resp.results.is_empty());
+        assert_eq!(
+            resp.no_results_reason,
+            Some(ProtoNoResultsReason::NoMatch as i32)
+        );
     }
 
     #[tokio::test]
@@ -246,6 +364,10 @@ mod tests {
             doc_type: TeleportDocType::Unspecified as i32,
             limit: 0, // Should default to 10
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -284,6 +406,10 @@ mod tests {
             doc_type: TeleportDocType::TocNode as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -304,6 +430,10 @@ mod tests {
             doc_type: TeleportDocType::TocNode as i32,
             limit: 10,
             agent_filter: None,
+            start_ms: None,
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
         });
 
         let response = handle_teleport_search(searcher, request).await.unwrap();
@@ -313,4 +443,133 @@ mod tests {
         // No contributing_agents on sample node -> agent should be None
         assert_eq!(resp.results[0].agent, None);
     }
+
+    #[tokio::test]
+    async fn test_handle_search_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp_dir.path().join("db")).unwrap());
+        let index =
+            SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path().join("search")))
+                .unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        let mut stored = Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            EventType::UserMessage,
+            EventRole::User,
+            "Deploy failed with a kafka timeout".to_string(),
+        );
+        stored.agent = Some("claude".to_string());
+        storage
+            .put_event_only(&stored.event_id, &stored.to_bytes().unwrap())
+            .unwrap();
+        indexer.index_event(&stored).unwrap();
+
+        // Indexed but no longer in storage
+        let missing = Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-1".to_string(),
+            Utc::now(),
+            EventType::UserMessage,
+            EventRole::User,
+            "Another kafka timeout".to_string(),
+        );
+        indexer.index_event(&missing).unwrap();
+        indexer
+            .index_toc_node(&sample_toc_node("node-1", "Kafka review", "kafka"))
+            .unwrap();
+        indexer.commit().unwrap();
+        let searcher = Arc::new(TeleportSearcher::new(&index).unwrap());
+
+        let request = Request::new(SearchEventsRequest {
+            query: "kafka".to_string(),
+            limit: 10,
+        });
+        let resp = handle_search_events(searcher, storage, request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(resp.event_docs, 2);
+        assert_eq!(resp.results.len(), 1);
+        let event = resp.results[0].event.as_ref().unwrap();
+        assert_eq!(event.event_id, stored.event_id);
+        assert_eq!(event.session_id, "session-1");
+        assert_eq!(event.agent.as_deref(), Some("claude"));
+        assert!(resp.results[0].score > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_handle_teleport_search_time_range() {
+        let temp_dir = TempDir::new().unwrap();
+        let index = SearchIndex::open_or_create(SearchIndexConfig::new(temp_dir.path())).unwrap();
+        let indexer = SearchIndexer::new(&index).unwrap();
+
+        let now = Utc::now();
+        let mut recent = sample_toc_node("node-recent", "Memory review", "This week");
+        recent.start_time = now - chrono::Duration::days(2);
+        let mut old = sample_toc_node("node-old", "Memory review", "Last week");
+        old.start_time = now - chrono::Duration::days(10);
+        indexer.index_toc_node(&recent).unwrap();
+        indexer.index_toc_node(&old).unwrap();
+        indexer.commit().unwrap();
+        let searcher = Arc::new(TeleportSearcher::new(&index).unwrap());
+
+        let request = Request::new(TeleportSearchRequest {
+            query: "memory".to_string(),
+            doc_type: TeleportDocType::Unspecified as i32,
+            limit: 10,
+            agent_filter: None,
+            start_ms: Some((now - chrono::Duration::days(7)).timestamp_millis()),
+            end_ms: None,
+            min_score: 0.0,
+            normalize_scores: false,
+        });
+        let resp = handle_teleport_search(searcher, request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(resp.results.len(), 1);
+        assert_eq!(resp.results[0].doc_id, "node-recent");
+    }
+
+    #[tokio::test]
+    async fn test_handle_teleport_search_min_score() {
+        let (_temp_dir, searcher) = setup_searcher();
+
+        let request = |min_score: f32| {
+            Request::new(TeleportSearchRequest {
+                query: "memory".to_string(),
+                doc_type: TeleportDocType::Unspecified as i32,
+                limit: 10,
+                agent_filter: None,
+                start_ms: None,
+                end_ms: None,
+                min_score,
+                normalize_scores: true,
+            })
+        };
+
+        // Normalized: the top result always scores 1.0 and is kept
+        let resp = handle_teleport_search(searcher.clone(), request(1.0))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!resp.results.is_empty());
+        assert!((resp.results[0].score - 1.0).abs() < f32::EPSILON);
+        assert_eq!(resp.no_results_reason, None);
+
+        let resp = handle_teleport_search(searcher, request(1.5))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(resp.results.is_empty());
+        assert_eq!(
+            resp.no_results_reason,
+            Some(ProtoNoResultsReason::BelowMinScore as i32)
+        );
+    }
 }
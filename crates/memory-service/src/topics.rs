@@ -2,9 +2,11 @@
 //!
 //! Provides gRPC handlers for topic navigation:
 //! - GetTopicGraphStatus: Check if topic graph is available
-//! - GetTopicsByQuery: Search topics by keywords
+//! - GetTopicsByQuery: Search topics by keywords, ranked by relevance and importance
 //! - GetRelatedTopics: Get topics related to a given topic
 //! - GetTopTopics: Get top topics by importance score
+//! - GetTopicProjection: Get 2D coordinates of top topics for visualization
+//! - GetTopicNodes: Get the TOC nodes that contributed to a topic
 
 use std::sync::Arc;
 
@@ -13,13 +15,22 @@ use tonic::{Request, Response, Status};
 use tracing::{debug, info};
 
 use memory_storage::Storage;
-use memory_topics::{RelationshipType, TopicStorage};
+use memory_topics::{
+    rank_topics, ProjectionMethod, RelationshipType, TopicGraphBuilder, TopicStorage,
+    DEFAULT_RELEVANCE_WEIGHT,
+};
 
 use crate::pb::{
     GetRelatedTopicsRequest, GetRelatedTopicsResponse, GetTopTopicsRequest, GetTopTopicsResponse,
-    GetTopicGraphStatusRequest, GetTopicGraphStatusResponse, GetTopicsByQueryRequest,
-    GetTopicsByQueryResponse, Topic as ProtoTopic, TopicRelationship as ProtoTopicRelationship,
+    GetTopicGraphStatusRequest, GetTopicGraphStatusResponse, GetTopicNodesRequest,
+    GetTopicNodesResponse, GetTopicProjectionRequest, GetTopicProjectionResponse,
+    GetTopicsByQueryRequest, GetTopicsByQueryResponse, Topic as ProtoTopic, TopicPoint,
+    TopicRelationship as ProtoTopicRelationship, TopicScore,
 };
+use crate::query::domain_to_proto_node;
+
+/// Page size cap for GetTopicNodes.
+const MAX_TOPIC_NODES_LIMIT: usize = 100;
 
 /// Handler for topic graph operations.
 pub struct TopicGraphHandler {
@@ -86,46 +97,23 @@ impl TopicGraphHandler {
         query: &str,
         limit: u32,
     ) -> Result<Vec<TopicSearchResult>, String> {
-        let query_lower = query.to_lowercase();
-        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
-
         let all_topics = self
             .storage
             .list_topics()
             .map_err(|e| format!("Failed to list topics: {}", e))?;
 
-        // Filter topics by query matching label or keywords
-        let mut matching_topics: Vec<_> = all_topics
-            .into_iter()
-            .filter(|topic| {
-                let label_lower = topic.label.to_lowercase();
-                let keywords_lower: Vec<String> =
-                    topic.keywords.iter().map(|k| k.to_lowercase()).collect();
-
-                query_terms.iter().any(|term| {
-                    label_lower.contains(term) || keywords_lower.iter().any(|k| k.contains(term))
-                })
-            })
-            .collect();
-
-        // Sort by importance score descending
-        matching_topics.sort_by(|a, b| {
-            b.importance_score
-                .partial_cmp(&a.importance_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
         // Limit results and convert to search results
-        let results: Vec<TopicSearchResult> = matching_topics
-            .into_iter()
-            .take(limit as usize)
-            .map(|t| TopicSearchResult {
-                id: t.topic_id,
-                label: t.label,
-                importance_score: t.importance_score as f32,
-                keywords: t.keywords,
-            })
-            .collect();
+        let results: Vec<TopicSearchResult> =
+            rank_topics(all_topics, query, DEFAULT_RELEVANCE_WEIGHT)
+                .into_iter()
+                .take(limit as usize)
+                .map(|scored| TopicSearchResult {
+                    id: scored.topic.topic_id,
+                    label: scored.topic.label,
+                    importance_score: scored.topic.importance_score as f32,
+                    keywords: scored.topic.keywords,
+                })
+                .collect();
 
         Ok(results)
     }
@@ -170,46 +158,43 @@ impl TopicGraphHandler {
         } else {
             10
         };
+        let relevance_weight = req.relevance_weight.unwrap_or(DEFAULT_RELEVANCE_WEIGHT);
+        if !(0.0..=1.0).contains(&relevance_weight) {
+            return Err(Status::invalid_argument(format!(
+                "relevance_weight must be between 0.0 and 1.0, got {}",
+                relevance_weight
+            )));
+        }
 
-        debug!(query = %query, limit = limit, "GetTopicsByQuery request");
+        debug!(query = %query, limit = limit, relevance_weight, "GetTopicsByQuery request");
 
         let all_topics = self.storage.list_topics().map_err(|e| {
             tracing::error!("Failed to list topics: {}", e);
             Status::internal(format!("Failed to list topics: {}", e))
         })?;
 
-        // Filter topics by query matching label or keywords
-        let query_terms: Vec<&str> = query.split_whitespace().collect();
-        let mut matching_topics: Vec<_> = all_topics
-            .into_iter()
-            .filter(|topic| {
-                let label_lower = topic.label.to_lowercase();
-                let keywords_lower: Vec<String> =
-                    topic.keywords.iter().map(|k| k.to_lowercase()).collect();
+        let mut ranked = rank_topics(all_topics, &query, relevance_weight);
+        ranked.truncate(limit);
 
-                query_terms.iter().any(|term| {
-                    label_lower.contains(term) || keywords_lower.iter().any(|k| k.contains(term))
-                })
+        let scores: Vec<TopicScore> = ranked
+            .iter()
+            .map(|scored| TopicScore {
+                topic_id: scored.topic.topic_id.clone(),
+                relevance: scored.relevance,
+                importance: scored.importance,
+                score: scored.score,
             })
             .collect();
-
-        // Sort by importance score descending
-        matching_topics.sort_by(|a, b| {
-            b.importance_score
-                .partial_cmp(&a.importance_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // Limit results
-        matching_topics.truncate(limit);
-
-        let proto_topics: Vec<ProtoTopic> =
-            matching_topics.into_iter().map(topic_to_proto).collect();
+        let proto_topics: Vec<ProtoTopic> = ranked
+            .into_iter()
+            .map(|scored| topic_to_proto(scored.topic))
+            .collect();
 
         info!(query = %query, results = proto_topics.len(), "GetTopicsByQuery complete");
 
         Ok(Response::new(GetTopicsByQueryResponse {
             topics: proto_topics,
+            scores,
         }))
     }
 
@@ -226,6 +211,13 @@ impl TopicGraphHandler {
             10
         };
 
+        if !(0.0..=1.0).contains(&req.min_strength) {
+            return Err(Status::invalid_argument(format!(
+                "min_strength must be between 0.0 and 1.0, got {}",
+                req.min_strength
+            )));
+        }
+
         // Parse optional relationship type filter
         let rel_type_filter = if req.relationship_type.is_empty() {
             None
@@ -237,6 +229,7 @@ impl TopicGraphHandler {
             topic_id = %topic_id,
             relationship_type = ?rel_type_filter,
             limit = limit,
+            min_strength = req.min_strength,
             "GetRelatedTopics request"
         );
 
@@ -259,8 +252,13 @@ impl TopicGraphHandler {
                 Status::internal(format!("Failed to get relationships: {}", e))
             })?;
 
-        // Limit relationships
-        let limited_rels: Vec<_> = relationships.into_iter().take(limit).collect();
+        // Drop weak relationships before limiting, so the limit counts only
+        // relationships that will be returned
+        let limited_rels: Vec<_> = relationships
+            .into_iter()
+            .filter(|rel| rel.strength >= req.min_strength)
+            .take(limit)
+            .collect();
 
         // Fetch related topics
         let mut related_topics = Vec::new();
@@ -351,10 +349,159 @@ impl TopicGraphHandler {
             topics: proto_topics,
         }))
     }
+
+    /// Handle GetTopicProjection RPC request.
+    ///
+    /// Projects the centroids of the most important topics into 2D.
+    pub async fn get_topic_projection(
+        &self,
+        request: Request<GetTopicProjectionRequest>,
+    ) -> Result<Response<GetTopicProjectionResponse>, Status> {
+        let req = request.into_inner();
+        let limit = if req.limit > 0 {
+            req.limit as usize
+        } else {
+            100
+        };
+        let method = if req.method.is_empty() {
+            ProjectionMethod::default()
+        } else {
+            req.method
+                .parse::<ProjectionMethod>()
+                .map_err(|e| Status::invalid_argument(e.to_string()))?
+        };
+
+        debug!(limit = limit, method = %method, "GetTopicProjection request");
+
+        let topics = self.storage.get_top_topics(limit).map_err(|e| {
+            tracing::error!("Failed to get top topics: {}", e);
+            Status::internal(format!("Failed to get top topics: {}", e))
+        })?;
+
+        let coords: std::collections::HashMap<_, _> = TopicGraphBuilder::new()
+            .project_2d(&topics, method)
+            .into_iter()
+            .collect();
+
+        let points: Vec<TopicPoint> = topics
+            .into_iter()
+            .filter_map(|t| {
+                let [x, y] = *coords.get(&t.topic_id)?;
+                Some(TopicPoint {
+                    topic_id: t.topic_id,
+                    label: t.label,
+                    x,
+                    y,
+                    importance_score: t.importance_score as f32,
+                })
+            })
+            .collect();
+
+        info!(
+            limit = limit,
+            results = points.len(),
+            "GetTopicProjection complete"
+        );
+
+        Ok(Response::new(GetTopicProjectionResponse {
+            points,
+            method: method.to_string(),
+        }))
+    }
+
+    /// Handle GetTopicNodes RPC request.
+    ///
+    /// Pages through the topic's node links, most relevant first, and
+    /// resolves each node against main storage. IDs whose node has since
+    /// been deleted are reported in `missing_node_ids` rather than failing
+    /// the page.
+    pub async fn get_topic_nodes(
+        &self,
+        request: Request<GetTopicNodesRequest>,
+    ) -> Result<Response<GetTopicNodesResponse>, Status> {
+        let req = request.into_inner();
+        let limit = if req.limit > 0 {
+            (req.limit as usize).min(MAX_TOPIC_NODES_LIMIT)
+        } else {
+            20
+        };
+        let offset: usize = match req.continuation_token.as_deref() {
+            Some(token) => token.parse().map_err(|_| {
+                Status::invalid_argument(format!("Invalid continuation token: {}", token))
+            })?,
+            None => 0,
+        };
+
+        debug!(
+            topic_id = %req.topic_id,
+            limit = limit,
+            offset = offset,
+            "GetTopicNodes request"
+        );
+
+        self.storage
+            .get_topic(&req.topic_id)
+            .map_err(|e| {
+                tracing::error!("Failed to get topic: {}", e);
+                Status::internal(format!("Failed to get topic: {}", e))
+            })?
+            .ok_or_else(|| Status::not_found(format!("Topic not found: {}", req.topic_id)))?;
+
+        let links = self
+            .storage
+            .get_links_for_topic(&req.topic_id)
+            .map_err(|e| {
+                tracing::error!("Failed to get topic links: {}", e);
+                Status::internal(format!("Failed to get topic links: {}", e))
+            })?;
+        let total = links.len();
+        let page: Vec<String> = links
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|link| link.node_id)
+            .collect();
+
+        let mut nodes = Vec::with_capacity(page.len());
+        let mut missing_node_ids = Vec::new();
+        for node_id in &page {
+            match self
+                .main_storage
+                .get_toc_node(node_id)
+                .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+            {
+                Some(node) => nodes.push(domain_to_proto_node(node)),
+                None => missing_node_ids.push(node_id.clone()),
+            }
+        }
+
+        let next_offset = offset + page.len();
+        let has_more = next_offset < total;
+        let continuation_token = if has_more {
+            Some(next_offset.to_string())
+        } else {
+            None
+        };
+
+        info!(
+            topic_id = %req.topic_id,
+            results = nodes.len(),
+            missing = missing_node_ids.len(),
+            "GetTopicNodes complete"
+        );
+
+        Ok(Response::new(GetTopicNodesResponse {
+            nodes,
+            missing_node_ids,
+            total_nodes: total as u32,
+            continuation_token,
+            has_more,
+        }))
+    }
 }
 
 /// Convert a domain Topic to a proto Topic.
-fn topic_to_proto(topic: memory_topics::Topic) -> ProtoTopic {
+pub(crate) fn topic_to_proto(topic: memory_topics::Topic) -> ProtoTopic {
     ProtoTopic {
         id: topic.topic_id,
         label: topic.label,
@@ -604,4 +751,255 @@ mod tests {
 
         assert_eq!(topics.len(), 1, "Empty filter should return all topics");
     }
+
+    #[tokio::test]
+    async fn test_get_topic_projection() {
+        let (_dir, handler) = create_test_handler();
+
+        let mut t1 = make_topic("t1", "Rust", 0.9);
+        t1.embedding = vec![1.0, 0.0, 0.0];
+        let mut t2 = make_topic("t2", "Python", 0.6);
+        t2.embedding = vec![0.0, 1.0, 0.0];
+        let mut t3 = make_topic("t3", "Go", 0.3);
+        t3.embedding = vec![0.0, 0.0, 1.0];
+        for t in [&t1, &t2, &t3] {
+            handler.storage.save_topic(t).unwrap();
+        }
+
+        let request = tonic::Request::new(GetTopicProjectionRequest {
+            limit: 0,
+            method: String::new(),
+        });
+        let response = handler.get_topic_projection(request).await.unwrap();
+        let response = response.into_inner();
+
+        assert_eq!(response.method, "pca");
+        let ids: Vec<&str> = response
+            .points
+            .iter()
+            .map(|p| p.topic_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["t1", "t2", "t3"]);
+        assert_eq!(response.points[0].label, "Rust");
+        assert!((response.points[0].importance_score - 0.9).abs() < 1e-6);
+
+        // Equidistant centroids stay equidistant in 2D
+        let dist =
+            |a: &TopicPoint, b: &TopicPoint| ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+        let d01 = dist(&response.points[0], &response.points[1]);
+        let d12 = dist(&response.points[1], &response.points[2]);
+        assert!((d01 - 2.0f32.sqrt()).abs() < 1e-3);
+        assert!((d01 - d12).abs() < 1e-3);
+    }
+
+    #[tokio::test]
+    async fn test_get_topic_projection_rejects_unknown_method() {
+        let (_dir, handler) = create_test_handler();
+
+        let request = tonic::Request::new(GetTopicProjectionRequest {
+            limit: 10,
+            method: "tsne".to_string(),
+        });
+        let status = handler.get_topic_projection(request).await.unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_topics_by_query_weighting() {
+        let (_dir, handler) = create_test_handler();
+
+        // Matches both query terms but is rarely mentioned
+        let relevant = make_topic("relevant", "Rust Memory", 0.1);
+        // Matches one term but is very important
+        let important = make_topic("important", "Memory", 2.0);
+        handler.storage.save_topic(&relevant).unwrap();
+        handler.storage.save_topic(&important).unwrap();
+
+        let query = |relevance_weight| {
+            tonic::Request::new(GetTopicsByQueryRequest {
+                query: "rust memory".to_string(),
+                limit: 10,
+                relevance_weight,
+            })
+        };
+
+        let response = handler
+            .get_topics_by_query(query(None))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.topics[0].id, "relevant");
+        assert_eq!(response.scores.len(), 2);
+        assert_eq!(response.scores[0].topic_id, "relevant");
+        assert!((response.scores[0].relevance - 1.0).abs() < f32::EPSILON);
+        assert!((response.scores[1].importance - 1.0).abs() < f32::EPSILON);
+
+        let response = handler
+            .get_topics_by_query(query(Some(0.2)))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.topics[0].id, "important");
+        assert!(response.scores[0].score > response.scores[1].score);
+
+        let status = handler
+            .get_topics_by_query(query(Some(1.5)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_related_topics_min_strength() {
+        let (_dir, handler) = create_test_handler();
+        for (id, label) in [
+            ("main", "Main"),
+            ("strong", "Strong"),
+            ("medium", "Medium"),
+            ("weak", "Weak"),
+        ] {
+            handler
+                .storage
+                .save_topic(&make_topic(id, label, 0.5))
+                .unwrap();
+        }
+        for (target, strength) in [("strong", 0.9), ("medium", 0.5), ("weak", 0.1)] {
+            let rel = TopicRelationship::new(
+                "main".to_string(),
+                target.to_string(),
+                RelationshipType::Semantic,
+                strength,
+            );
+            handler.storage.save_relationship(&rel).unwrap();
+        }
+
+        let request = |min_strength| {
+            tonic::Request::new(GetRelatedTopicsRequest {
+                topic_id: "main".to_string(),
+                relationship_type: String::new(),
+                limit: 10,
+                min_strength,
+            })
+        };
+
+        let all = handler
+            .get_related_topics(request(0.0))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(all.related_topics.len(), 3);
+
+        let filtered = handler
+            .get_related_topics(request(0.4))
+            .await
+            .unwrap()
+            .into_inner();
+        let topic_ids: Vec<_> = filtered
+            .related_topics
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        let target_ids: Vec<_> = filtered
+            .relationships
+            .iter()
+            .map(|r| r.target_id.as_str())
+            .collect();
+        assert_eq!(topic_ids, ["strong", "medium"]);
+        assert_eq!(target_ids, topic_ids);
+        assert!(filtered.relationships.iter().all(|r| r.strength >= 0.4));
+
+        let status = handler.get_related_topics(request(1.5)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_topic_nodes_returns_contributing_nodes() {
+        let (_dir, handler) = create_test_handler();
+        let node_ids = ["toc:day:a", "toc:day:b", "toc:day:c"];
+        for id in node_ids {
+            store_node(&handler.main_storage, id, &[]);
+        }
+        store_node(&handler.main_storage, "toc:day:other", &[]);
+
+        let topic_id = "t1".to_string();
+        handler
+            .storage
+            .save_topic(&make_topic(&topic_id, "Rust", 0.9))
+            .unwrap();
+        for (id, relevance) in node_ids.iter().zip([0.9, 0.8, 0.7]) {
+            handler
+                .storage
+                .save_link(&TopicLink::new(topic_id.clone(), id.to_string(), relevance))
+                .unwrap();
+        }
+
+        let request = |limit, continuation_token| {
+            tonic::Request::new(GetTopicNodesRequest {
+                topic_id: topic_id.clone(),
+                limit,
+                continuation_token,
+            })
+        };
+
+        let all = handler
+            .get_topic_nodes(request(0, None))
+            .await
+            .unwrap()
+            .into_inner();
+        let ids: Vec<_> = all.nodes.iter().map(|n| n.node_id.as_str()).collect();
+        assert_eq!(ids, node_ids);
+        assert_eq!(all.total_nodes, 3);
+        assert!(all.missing_node_ids.is_empty());
+        assert!(!all.has_more);
+
+        let first = handler
+            .get_topic_nodes(request(2, None))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(first.nodes.len(), 2);
+        assert!(first.has_more);
+        let second = handler
+            .get_topic_nodes(request(2, first.continuation_token))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(second.nodes.len(), 1);
+        assert_eq!(second.nodes[0].node_id, "toc:day:c");
+        assert!(!second.has_more);
+        assert!(second.continuation_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_topic_nodes_invalid_token() {
+        let (_dir, handler) = create_test_handler();
+        handler
+            .storage
+            .save_topic(&make_topic("t1", "Rust", 0.9))
+            .unwrap();
+        let status = handler
+            .get_topic_nodes(tonic::Request::new(GetTopicNodesRequest {
+                topic_id: "t1".to_string(),
+                limit: 10,
+                continuation_token: Some("not-a-number".to_string()),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_topic_nodes_unknown_topic() {
+        let (_dir, handler) = create_test_handler();
+        let status = handler
+            .get_topic_nodes(tonic::Request::new(GetTopicNodesRequest {
+                topic_id: "missing".to_string(),
+                limit: 10,
+                continuation_token: None,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
 }
@@ -1,7 +1,8 @@
 //! VectorTeleport RPC implementation.
 //!
 //! Provides semantic similarity search over TOC nodes and grips
-//! using HNSW vector index.
+//! using HNSW vector index, and "more like this" search seeded by a
+//! stored TOC node.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -9,17 +10,26 @@ use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{debug, info};
 
-use memory_embeddings::{CandleEmbedder, EmbeddingModel};
-use memory_vector::{DocType, HnswIndex, VectorIndex, VectorMetadata};
+use memory_embeddings::{Embedding, EmbeddingModel};
+use memory_storage::Storage;
+use memory_vector::{DocType, HnswIndex, IndexableItem, VectorError, VectorIndex, VectorMetadata};
 
+use crate::diversity::{mmr_rerank, MmrCandidate};
 use crate::pb::{
-    GetVectorIndexStatusRequest, VectorIndexStatus, VectorMatch, VectorTargetType,
-    VectorTeleportRequest, VectorTeleportResponse,
+    FindSimilarNodesRequest, FindSimilarNodesResponse, GetVectorIndexStatusRequest,
+    VectorIndexStatus, VectorMatch, VectorTargetType, VectorTeleportRequest,
+    VectorTeleportResponse,
 };
 
+/// Candidates fetched per requested result when reranking for diversity.
+pub const MMR_CANDIDATE_FACTOR: usize = 4;
+
+/// Largest `top_k` the search RPCs honor; larger requests are clamped.
+pub const MAX_SEARCH_TOP_K: usize = 100;
+
 /// Handler for vector search operations.
 pub struct VectorTeleportHandler {
-    embedder: Arc<CandleEmbedder>,
+    embedder: Arc<dyn EmbeddingModel>,
     index: Arc<std::sync::RwLock<HnswIndex>>,
     metadata: Arc<VectorMetadata>,
 }
@@ -27,7 +37,7 @@ pub struct VectorTeleportHandler {
 impl VectorTeleportHandler {
     /// Create a new vector teleport handler.
     pub fn new(
-        embedder: Arc<CandleEmbedder>,
+        embedder: Arc<dyn EmbeddingModel>,
         index: Arc<std::sync::RwLock<HnswIndex>>,
         metadata: Arc<VectorMetadata>,
     ) -> Self {
@@ -70,14 +80,31 @@ impl VectorTeleportHandler {
     ) -> Result<Response<VectorTeleportResponse>, Status> {
         let req = request.into_inner();
         let query = &req.query;
+        // Default 10, max MAX_SEARCH_TOP_K
         let top_k = if req.top_k > 0 {
-            req.top_k as usize
+            (req.top_k as usize).min(MAX_SEARCH_TOP_K)
         } else {
             10
         };
         let min_score = req.min_score;
+        let ef_search = req.ef_search.map(|ef| ef as usize);
+        if let Some(ef) = ef_search {
+            if ef < top_k {
+                return Err(Status::invalid_argument(format!(
+                    "ef_search ({}) must be >= top_k ({})",
+                    ef, top_k
+                )));
+            }
+        }
 
-        debug!(query = %query, top_k = top_k, "VectorTeleport request");
+        // Reranking for diversity needs a wider candidate pool
+        let fetch_k = match req.diversity {
+            Some(_) => top_k * MMR_CANDIDATE_FACTOR,
+            None => top_k,
+        };
+        let ef_search = ef_search.map(|ef| ef.max(fetch_k));
+
+        debug!(query = %query, top_k = top_k, ef_search = ?ef_search, "VectorTeleport request");
 
         let status = self.get_status();
         if !status.available {
@@ -99,12 +126,13 @@ impl VectorTeleportHandler {
         let results = {
             let index = self.index.read().unwrap();
             index
-                .search(&embedding, top_k)
+                .search_with_ef(&embedding, fetch_k, ef_search)
                 .map_err(|e| Status::internal(format!("Search failed: {}", e)))?
         };
 
         // Convert to matches with metadata lookup
         let mut matches = Vec::new();
+        let mut embeddings = HashMap::new();
         for result in results {
             if result.score < min_score {
                 continue;
@@ -123,6 +151,13 @@ impl VectorTeleportHandler {
                     }
                 }
 
+                if req.diversity.is_some() {
+                    let index = self.index.read().unwrap();
+                    if let Ok(Some(vector)) = index.get_vector(result.vector_id) {
+                        embeddings.insert(entry.doc_id.clone(), vector);
+                    }
+                }
+
                 matches.push(VectorMatch {
                     doc_id: entry.doc_id,
                     doc_type: entry.doc_type.as_str().to_string(),
@@ -134,6 +169,11 @@ impl VectorTeleportHandler {
             }
         }
 
+        let matches = match req.diversity {
+            Some(lambda) => rerank_by_diversity(matches, &embeddings, lambda, top_k),
+            None => matches,
+        };
+
         info!(query = %query, results = matches.len(), "VectorTeleport complete");
 
         Ok(Response::new(VectorTeleportResponse {
@@ -142,6 +182,88 @@ impl VectorTeleportHandler {
         }))
     }
 
+    /// Handle FindSimilarNodes RPC request.
+    ///
+    /// Uses the node's stored vector when it has one; otherwise loads the
+    /// node from `storage` and embeds its text the way the indexing
+    /// pipeline would.
+    pub async fn find_similar_nodes(
+        &self,
+        storage: &Storage,
+        request: Request<FindSimilarNodesRequest>,
+    ) -> Result<Response<FindSimilarNodesResponse>, Status> {
+        let req = request.into_inner();
+        if req.node_id.is_empty() {
+            return Err(Status::invalid_argument("node_id is required"));
+        }
+        // Default 10, max MAX_SEARCH_TOP_K
+        let top_k = if req.top_k > 0 {
+            (req.top_k as usize).min(MAX_SEARCH_TOP_K)
+        } else {
+            10
+        };
+
+        debug!(node_id = %req.node_id, top_k = top_k, "FindSimilarNodes request");
+
+        let status = self.get_status();
+        if !status.available {
+            return Ok(Response::new(FindSimilarNodesResponse {
+                matches: vec![],
+                index_status: Some(status),
+                embedded_on_the_fly: false,
+            }));
+        }
+
+        let stored = self.get_embeddings_for_doc_ids(std::slice::from_ref(&req.node_id));
+        let (embedding, embedded_on_the_fly) = match stored.into_values().next() {
+            Some(vector) => (Embedding::from_normalized(vector), false),
+            None => {
+                let node = storage
+                    .get_toc_node(&req.node_id)
+                    .map_err(|e| Status::internal(format!("Storage error: {}", e)))?
+                    .ok_or_else(|| Status::not_found(format!("Node not found: {}", req.node_id)))?;
+                let text = IndexableItem::TocNode {
+                    node_id: req.node_id.clone(),
+                    node,
+                }
+                .text();
+
+                let embedder = self.embedder.clone();
+                let vector = tokio::task::spawn_blocking(move || embedder.embed(&text))
+                    .await
+                    .map_err(|e| Status::internal(format!("Task error: {}", e)))?
+                    .map_err(|e| Status::internal(format!("Embedding failed: {}", e)))?;
+                (vector, true)
+            }
+        };
+
+        let matches = {
+            let index = self.index.read().unwrap();
+            similar_nodes(
+                &*index,
+                &self.metadata,
+                &req.node_id,
+                &embedding,
+                top_k,
+                req.min_score,
+            )
+            .map_err(|e| Status::internal(format!("Search failed: {}", e)))?
+        };
+
+        info!(
+            node_id = %req.node_id,
+            results = matches.len(),
+            embedded_on_the_fly = embedded_on_the_fly,
+            "FindSimilarNodes complete"
+        );
+
+        Ok(Response::new(FindSimilarNodesResponse {
+            matches,
+            index_status: Some(status),
+            embedded_on_the_fly,
+        }))
+    }
+
     /// Handle GetVectorIndexStatus RPC request.
     pub async fn get_vector_index_status(
         &self,
@@ -228,6 +350,71 @@ impl VectorTeleportHandler {
     }
 }
 
+/// Rerank `matches` with MMR and keep the best `top_k`.
+///
+/// `embeddings` maps doc_id to its vector; matches without one are ranked
+/// by score alone. Scores are left unchanged, so the returned order may no
+/// longer be sorted by score.
+pub(crate) fn rerank_by_diversity(
+    matches: Vec<VectorMatch>,
+    embeddings: &HashMap<String, Vec<f32>>,
+    lambda: f32,
+    top_k: usize,
+) -> Vec<VectorMatch> {
+    let candidates: Vec<MmrCandidate<'_>> = matches
+        .iter()
+        .map(|m| MmrCandidate {
+            relevance: m.score,
+            embedding: embeddings.get(&m.doc_id).map(Vec::as_slice),
+        })
+        .collect();
+    let order = mmr_rerank(&candidates, lambda, top_k);
+
+    let mut slots: Vec<Option<VectorMatch>> = matches.into_iter().map(Some).collect();
+    order.into_iter().filter_map(|i| slots[i].take()).collect()
+}
+
+/// TOC nodes nearest to `embedding`, excluding `node_id` itself.
+///
+/// Grips are skipped, so more candidates than `top_k` are fetched to leave
+/// room for them and for the node's own vector.
+pub(crate) fn similar_nodes(
+    index: &dyn VectorIndex,
+    metadata: &VectorMetadata,
+    node_id: &str,
+    embedding: &Embedding,
+    top_k: usize,
+    min_score: f32,
+) -> Result<Vec<VectorMatch>, VectorError> {
+    let fetch_k = (top_k + 1) * MMR_CANDIDATE_FACTOR;
+    let results = index.search(embedding, fetch_k)?;
+
+    let mut matches = Vec::new();
+    for result in results {
+        if result.score < min_score {
+            continue;
+        }
+        let Some(entry) = metadata.get(result.vector_id)? else {
+            continue;
+        };
+        if entry.doc_type != DocType::TocNode || entry.doc_id == node_id {
+            continue;
+        }
+        matches.push(VectorMatch {
+            doc_id: entry.doc_id,
+            doc_type: entry.doc_type.as_str().to_string(),
+            score: result.score,
+            text_preview: entry.text_preview,
+            timestamp_ms: entry.created_at,
+            agent: entry.agent,
+        });
+        if matches.len() == top_k {
+            break;
+        }
+    }
+    Ok(matches)
+}
+
 /// Simplified search result for retrieval handler.
 pub struct VectorSearchResult {
     pub doc_id: String,
@@ -243,4 +430,106 @@ pub struct VectorSearchResult {
 mod tests {
     // Integration tests require embedding model download
     // Run with: cargo test -p memory-service --features integration -- --ignored
+
+    use super::*;
+
+    fn vector_match(doc_id: &str, score: f32) -> VectorMatch {
+        VectorMatch {
+            doc_id: doc_id.to_string(),
+            doc_type: "toc_node".to_string(),
+            score,
+            text_preview: String::new(),
+            timestamp_ms: 0,
+            agent: None,
+        }
+    }
+
+    #[test]
+    fn test_rerank_by_diversity_promotes_distinct_doc() {
+        let matches = vec![
+            vector_match("dup-1", 0.95),
+            vector_match("dup-2", 0.94),
+            vector_match("dup-3", 0.93),
+            vector_match("distinct", 0.80),
+        ];
+        let embeddings: HashMap<String, Vec<f32>> = [
+            ("dup-1", vec![1.0, 0.0, 0.0]),
+            ("dup-2", vec![0.99, 0.01, 0.0]),
+            ("dup-3", vec![0.98, 0.02, 0.0]),
+            ("distinct", vec![0.0, 0.0, 1.0]),
+        ]
+        .into_iter()
+        .map(|(id, v)| (id.to_string(), v))
+        .collect();
+
+        let plain = rerank_by_diversity(matches.clone(), &embeddings, 1.0, 2);
+        let ids: Vec<_> = plain.iter().map(|m| m.doc_id.as_str()).collect();
+        assert_eq!(ids, vec!["dup-1", "dup-2"]);
+
+        let diverse = rerank_by_diversity(matches, &embeddings, 0.5, 2);
+        let ids: Vec<_> = diverse.iter().map(|m| m.doc_id.as_str()).collect();
+        assert_eq!(ids, vec!["dup-1", "distinct"]);
+    }
+
+    #[test]
+    fn test_similar_nodes_ranks_topical_neighbour_first() {
+        use memory_embeddings::HashEmbedder;
+        use memory_vector::{HnswConfig, VectorEntry};
+        use tempfile::TempDir;
+
+        let embedder = HashEmbedder::new(64);
+        let docs = [
+            (
+                "toc:day:2026-01-10",
+                DocType::TocNode,
+                "Debugged the rust borrow checker lifetime errors in the parser",
+            ),
+            (
+                "toc:day:2026-01-11",
+                DocType::TocNode,
+                "Fixed rust borrow checker lifetime errors in the lexer",
+            ),
+            (
+                "toc:day:2026-01-12",
+                DocType::TocNode,
+                "Baked sourdough bread with a long cold proof",
+            ),
+            (
+                "toc:day:2026-01-13",
+                DocType::TocNode,
+                "Planted tomatoes and watered the vegetable garden",
+            ),
+            (
+                "grip:1768003200000:abc",
+                DocType::Grip,
+                "rust borrow checker lifetime errors in the parser",
+            ),
+        ];
+
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(64, temp.path().join("hnsw")).with_capacity(100);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+        let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+        for (id, (doc_id, doc_type, text)) in docs.iter().enumerate() {
+            index
+                .add(id as u64, &embedder.embed(text).unwrap())
+                .unwrap();
+            metadata
+                .put(&VectorEntry::new(id as u64, *doc_type, *doc_id, 0, text))
+                .unwrap();
+        }
+
+        let seed = Embedding::from_normalized(index.get_vector(0).unwrap().unwrap());
+        let matches =
+            similar_nodes(&index, &metadata, "toc:day:2026-01-10", &seed, 3, 0.0).unwrap();
+
+        let ids: Vec<_> = matches.iter().map(|m| m.doc_id.as_str()).collect();
+        assert_eq!(ids.len(), 3);
+        // The topically close node ranks first; the node itself and grips are excluded
+        assert_eq!(ids[0], "toc:day:2026-01-11");
+        assert!(!ids.contains(&"toc:day:2026-01-10"));
+        assert!(!ids.contains(&"grip:1768003200000:abc"));
+        assert!(matches[0].score > matches[1].score);
+        assert!(matches.iter().all(|m| m.doc_type == "toc_node"));
+    }
 }
@@ -7,8 +7,9 @@
 //! - grips: Excerpt-to-event links (default compaction)
 //! - outbox: Queue for async index updates (FIFO compaction)
 //! - checkpoints: Crash recovery checkpoints (default compaction)
+//! - novelty_state: Persisted dedup buffer (default compaction)
 
-use rocksdb::{ColumnFamilyDescriptor, Options};
+use rocksdb::{BlockBasedOptions, Cache, ColumnFamilyDescriptor, Options};
 
 /// Column family name for conversation events
 pub const CF_EVENTS: &str = "events";
@@ -45,6 +46,10 @@ pub const CF_USAGE_COUNTERS: &str = "usage_counters";
 /// Stores complete task execution episodes with actions, outcomes, and lessons.
 pub const CF_EPISODES: &str = "episodes";
 
+/// Column family for persisted novelty (dedup) state.
+/// Holds the in-flight buffer so dedup survives daemon restarts.
+pub const CF_NOVELTY_STATE: &str = "novelty_state";
+
 /// All column family names
 pub const ALL_CF_NAMES: &[&str] = &[
     CF_EVENTS,
@@ -58,6 +63,7 @@ pub const ALL_CF_NAMES: &[&str] = &[
     CF_TOPIC_RELS,
     CF_USAGE_COUNTERS,
     CF_EPISODES,
+    CF_NOVELTY_STATE,
 ];
 
 /// Create column family options for events (append-only, compressed)
@@ -78,19 +84,44 @@ fn outbox_options() -> Options {
     opts
 }
 
+/// Small, hot column families whose index and filter blocks stay pinned
+/// in the block cache (latest-version pointers, job checkpoints)
+pub const PINNED_CF_NAMES: &[&str] = &[CF_TOC_LATEST, CF_CHECKPOINTS];
+
+/// Point a column family's table reader at the shared block cache.
+fn with_block_cache(mut opts: Options, name: &str, cache: &Cache) -> Options {
+    let mut table = BlockBasedOptions::default();
+    table.set_block_cache(cache);
+    if PINNED_CF_NAMES.contains(&name) {
+        table.set_cache_index_and_filter_blocks(true);
+        table.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        table.set_pin_top_level_index_and_filter(true);
+    }
+    opts.set_block_based_table_factory(&table);
+    opts
+}
+
 /// Build all column family descriptors
 pub fn build_cf_descriptors() -> Vec<ColumnFamilyDescriptor> {
-    vec![
-        ColumnFamilyDescriptor::new(CF_EVENTS, events_options()),
-        ColumnFamilyDescriptor::new(CF_TOC_NODES, Options::default()),
-        ColumnFamilyDescriptor::new(CF_TOC_LATEST, Options::default()),
-        ColumnFamilyDescriptor::new(CF_GRIPS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_OUTBOX, outbox_options()),
-        ColumnFamilyDescriptor::new(CF_CHECKPOINTS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_TOPICS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_TOPIC_LINKS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_TOPIC_RELS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_USAGE_COUNTERS, Options::default()),
-        ColumnFamilyDescriptor::new(CF_EPISODES, Options::default()),
-    ]
+    build_cf_descriptors_with_cache(None)
+}
+
+/// Build all column family descriptors, sharing `block_cache` across them
+/// if given. Without one each CF gets RocksDB's default cache.
+pub fn build_cf_descriptors_with_cache(block_cache: Option<&Cache>) -> Vec<ColumnFamilyDescriptor> {
+    ALL_CF_NAMES
+        .iter()
+        .map(|&name| {
+            let opts = match name {
+                CF_EVENTS => events_options(),
+                CF_OUTBOX => outbox_options(),
+                _ => Options::default(),
+            };
+            let opts = match block_cache {
+                Some(cache) => with_block_cache(opts, name, cache),
+                None => opts,
+            };
+            ColumnFamilyDescriptor::new(name, opts)
+        })
+        .collect()
 }
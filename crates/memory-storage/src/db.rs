@@ -6,13 +6,15 @@
 //! - Single-key and range reads
 //! - Idempotent writes (ING-03)
 
-use rocksdb::{Direction, IteratorMode, Options, WriteBatch, DB};
+use rocksdb::{Cache, Direction, IteratorMode, Options, WriteBatch, WriteOptions, DB};
+use std::collections::HashSet;
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use tracing::{debug, info};
 
 use crate::column_families::{
-    build_cf_descriptors, ALL_CF_NAMES, CF_CHECKPOINTS, CF_EVENTS, CF_GRIPS, CF_OUTBOX,
+    build_cf_descriptors_with_cache, ALL_CF_NAMES, CF_CHECKPOINTS, CF_EVENTS, CF_GRIPS, CF_OUTBOX,
     CF_TOC_LATEST, CF_TOC_NODES,
 };
 use crate::error::StorageError;
@@ -22,19 +24,100 @@ use memory_types::OutboxEntry;
 // Re-export TocLevel for use in this crate
 pub use memory_types::TocLevel;
 
+/// Default size of the block cache shared by all column families (64MB)
+pub const DEFAULT_BLOCK_CACHE_MB: usize = 64;
+
+/// Deletes written per batch when pruning, so a large prune never builds
+/// one unbounded `WriteBatch` in memory.
+const PRUNE_BATCH_SIZE: usize = 1000;
+
+/// Storage tuning options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageConfig {
+    /// Sync the RocksDB WAL to disk on every event write.
+    ///
+    /// Off by default: writes survive a process crash but the last few may
+    /// be lost on power failure. Turn on when durability matters more than
+    /// ingestion throughput.
+    pub sync_writes: bool,
+
+    /// Size in MB of the LRU block cache shared by all column families.
+    ///
+    /// Index and filter blocks of the small hot CFs (`toc_latest`,
+    /// `checkpoints`) are pinned in it.
+    pub block_cache_mb: usize,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            sync_writes: false,
+            block_cache_mb: DEFAULT_BLOCK_CACHE_MB,
+        }
+    }
+}
+
+impl StorageConfig {
+    /// Set whether event writes sync the WAL.
+    pub fn with_sync_writes(mut self, sync_writes: bool) -> Self {
+        self.sync_writes = sync_writes;
+        self
+    }
+
+    /// Set the shared block cache size in MB.
+    pub fn with_block_cache_mb(mut self, block_cache_mb: usize) -> Self {
+        self.block_cache_mb = block_cache_mb;
+        self
+    }
+}
+
+/// Outcome of [`Storage::put_events_batch`].
+#[derive(Debug, Clone, Default)]
+pub struct BatchResult {
+    /// (event_key, created) per input event, in input order.
+    ///
+    /// created=false for events that already existed or repeat an earlier
+    /// event in the same batch.
+    pub results: Vec<(EventKey, bool)>,
+}
+
+impl BatchResult {
+    /// Number of events written.
+    pub fn created(&self) -> usize {
+        self.results.iter().filter(|(_, created)| *created).count()
+    }
+
+    /// Number of events skipped as duplicates.
+    pub fn skipped(&self) -> usize {
+        self.results.len() - self.created()
+    }
+}
+
 /// Main storage interface for agent-memory
 pub struct Storage {
     pub(crate) db: DB,
     /// Outbox sequence counter for monotonic ordering
     outbox_sequence: AtomicU64,
+    config: StorageConfig,
+    /// Options the DB was opened with; holds the statistics counters
+    db_opts: Options,
+    /// Opened with `open_read_only`; write methods return `StorageError::ReadOnly`
+    read_only: bool,
+    /// Segment nodes created through this handle
+    segments_created: AtomicU64,
+    /// IDs of segment nodes created since the last drain, once tracking is on
+    new_segment_ids: Mutex<Option<Vec<String>>>,
 }
 
 impl Storage {
     /// Open storage in read-only mode at the given path.
     ///
     /// Used by FederatedQueryHandler (v3.0) to open remote project stores
-    /// without acquiring write locks. Does not create the database if missing.
-    /// Returns an error if the path does not exist or lacks the expected CFs.
+    /// and by admin inspection commands. No lock is taken, so this works while
+    /// a daemon holds the database open; reads see the state at open time.
+    /// Write methods return [`StorageError::ReadOnly`]. Does not create the
+    /// database if missing. Returns an error if the path does not exist or
+    /// lacks the expected CFs.
     pub fn open_read_only(path: &Path) -> Result<Self, StorageError> {
         info!("Opening read-only storage at {:?}", path);
 
@@ -47,6 +130,11 @@ impl Storage {
         Ok(Self {
             db,
             outbox_sequence: std::sync::atomic::AtomicU64::new(0),
+            config: StorageConfig::default(),
+            db_opts,
+            read_only: true,
+            segments_created: AtomicU64::new(0),
+            new_segment_ids: Mutex::new(None),
         })
     }
 
@@ -55,7 +143,15 @@ impl Storage {
     /// Per STOR-04: Each project gets its own RocksDB instance.
     /// Per STOR-05: Uses Universal compaction for append-only workload.
     pub fn open(path: &Path) -> Result<Self, StorageError> {
-        info!("Opening storage at {:?}", path);
+        Self::open_with_config(path, StorageConfig::default())
+    }
+
+    /// Open storage at the given path with tuning options.
+    pub fn open_with_config(path: &Path, config: StorageConfig) -> Result<Self, StorageError> {
+        info!(
+            "Opening storage at {:?} (sync_writes={}, block_cache={}MB)",
+            path, config.sync_writes, config.block_cache_mb
+        );
 
         let mut db_opts = Options::default();
         db_opts.create_if_missing(true);
@@ -64,8 +160,12 @@ impl Storage {
         db_opts.set_compaction_style(rocksdb::DBCompactionStyle::Universal);
         // Limit memory usage during compaction
         db_opts.set_max_background_jobs(4);
+        // Ticker counters only, for cache hit reporting
+        db_opts.enable_statistics();
+        db_opts.set_statistics_level(rocksdb::statistics::StatsLevel::ExceptTimers);
 
-        let cf_descriptors = build_cf_descriptors();
+        let block_cache = Cache::new_lru_cache(config.block_cache_mb * 1024 * 1024);
+        let cf_descriptors = build_cf_descriptors_with_cache(Some(&block_cache));
         let db = DB::open_cf_descriptors(&db_opts, path, cf_descriptors)?;
 
         // Initialize outbox sequence from highest existing key
@@ -74,9 +174,47 @@ impl Storage {
         Ok(Self {
             db,
             outbox_sequence: AtomicU64::new(outbox_sequence),
+            config,
+            db_opts,
+            read_only: false,
+            segments_created: AtomicU64::new(0),
+            new_segment_ids: Mutex::new(None),
         })
     }
 
+    /// True if opened with [`open_read_only`](Self::open_read_only).
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Fail with [`StorageError::ReadOnly`] on a read-only handle.
+    pub(crate) fn check_writable(&self) -> Result<(), StorageError> {
+        if self.read_only {
+            return Err(StorageError::ReadOnly);
+        }
+        Ok(())
+    }
+
+    /// Storage tuning options this instance was opened with.
+    pub fn config(&self) -> StorageConfig {
+        self.config
+    }
+
+    /// Block cache hits since open, across all column families.
+    ///
+    /// Always 0 for read-only handles, which don't collect statistics.
+    pub fn block_cache_hits(&self) -> u64 {
+        self.db_opts
+            .get_ticker_count(rocksdb::statistics::Ticker::BlockCacheHit)
+    }
+
+    /// Write options for event writes, honoring `sync_writes`.
+    fn event_write_options(&self) -> WriteOptions {
+        let mut opts = WriteOptions::default();
+        opts.set_sync(self.config.sync_writes);
+        opts
+    }
+
     /// Load the highest outbox sequence number from storage
     fn load_outbox_sequence(db: &DB) -> Result<u64, StorageError> {
         let cf = db
@@ -107,6 +245,7 @@ impl Storage {
         event_bytes: &[u8],
         outbox_bytes: &[u8],
     ) -> Result<(EventKey, bool), StorageError> {
+        self.check_writable()?;
         let events_cf = self
             .db
             .cf_handle(CF_EVENTS)
@@ -132,7 +271,7 @@ impl Storage {
         batch.put_cf(&events_cf, event_key.to_bytes(), event_bytes);
         batch.put_cf(&outbox_cf, outbox_key.to_bytes(), outbox_bytes);
 
-        self.db.write(batch)?;
+        self.db.write_opt(batch, &self.event_write_options())?;
         debug!(
             "Stored event {} with outbox seq {}",
             event_id, outbox_key.sequence
@@ -153,6 +292,7 @@ impl Storage {
         event_id: &str,
         event_bytes: &[u8],
     ) -> Result<(EventKey, bool), StorageError> {
+        self.check_writable()?;
         let events_cf = self
             .db
             .cf_handle(CF_EVENTS)
@@ -168,13 +308,79 @@ impl Storage {
         }
 
         // Store event only — no outbox entry
-        self.db
-            .put_cf(&events_cf, event_key.to_bytes(), event_bytes)?;
+        self.db.put_cf_opt(
+            &events_cf,
+            event_key.to_bytes(),
+            event_bytes,
+            &self.event_write_options(),
+        )?;
         debug!("Stored event {} without outbox (deduplicated)", event_id);
 
         Ok((event_key, true))
     }
 
+    /// Store many events with their outbox entries in one WriteBatch.
+    ///
+    /// Each element is (event_id, event_bytes, outbox_bytes) as for
+    /// [`put_event`](Self::put_event). Events that already exist, or repeat
+    /// an earlier event_id in the batch, are skipped (ING-03) and get no
+    /// outbox entry. The batch is written atomically.
+    pub fn put_events_batch(
+        &self,
+        events: &[(&str, &[u8], &[u8])],
+    ) -> Result<BatchResult, StorageError> {
+        self.check_writable()?;
+        let events_cf = self
+            .db
+            .cf_handle(CF_EVENTS)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_EVENTS.to_string()))?;
+        let outbox_cf = self
+            .db
+            .cf_handle(CF_OUTBOX)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_OUTBOX.to_string()))?;
+
+        // Parse every key up front so a bad event_id rejects the whole batch
+        let keys = events
+            .iter()
+            .map(|(event_id, _, _)| EventKey::from_event_id(event_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let key_bytes: Vec<Vec<u8>> = keys.iter().map(EventKey::to_bytes).collect();
+        let existing = self
+            .db
+            .multi_get_cf(key_bytes.iter().map(|k| (&events_cf, k.as_slice())));
+
+        let mut batch = WriteBatch::default();
+        let mut seen = HashSet::with_capacity(events.len());
+        let mut result = BatchResult {
+            results: Vec::with_capacity(events.len()),
+        };
+
+        for (((_, event_bytes, outbox_bytes), key), (bytes, found)) in
+            events.iter().zip(keys).zip(key_bytes.iter().zip(existing))
+        {
+            let created = found?.is_none() && seen.insert(bytes.as_slice());
+            if created {
+                let outbox_key = OutboxKey::new(self.next_outbox_sequence());
+                batch.put_cf(&events_cf, bytes, event_bytes);
+                batch.put_cf(&outbox_cf, outbox_key.to_bytes(), outbox_bytes);
+            }
+            result.results.push((key, created));
+        }
+
+        let created = result.created();
+        if created > 0 {
+            self.db.write_opt(batch, &self.event_write_options())?;
+        }
+        debug!(
+            "Stored batch of {} events ({} skipped)",
+            created,
+            result.skipped()
+        );
+
+        Ok(result)
+    }
+
     /// Get an event by its event_id
     pub fn get_event(&self, event_id: &str) -> Result<Option<Vec<u8>>, StorageError> {
         let events_cf = self
@@ -228,6 +434,7 @@ impl Storage {
         job_name: &str,
         checkpoint_bytes: &[u8],
     ) -> Result<(), StorageError> {
+        self.check_writable()?;
         let cf = self
             .db
             .cf_handle(CF_CHECKPOINTS)
@@ -289,6 +496,7 @@ impl Storage {
     /// Used to clean up processed outbox entries after all indexes
     /// have been updated. Returns count of deleted entries.
     pub fn delete_outbox_entries(&self, up_to_sequence: u64) -> Result<usize, StorageError> {
+        self.check_writable()?;
         let cf = self
             .db
             .cf_handle(CF_OUTBOX)
@@ -322,8 +530,48 @@ impl Storage {
         Ok(count)
     }
 
+    /// Number of outbox entries waiting in the outbox.
+    ///
+    /// Processed entries are removed by outbox cleanup, so this is the
+    /// indexing backlog. A steadily growing value means indexing is not
+    /// keeping up with ingestion.
+    pub fn outbox_lag(&self) -> Result<u64, StorageError> {
+        let cf = self
+            .db
+            .cf_handle(CF_OUTBOX)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_OUTBOX.to_string()))?;
+
+        let mut count = 0;
+        for item in self.db.iterator_cf(&cf, IteratorMode::Start) {
+            item?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// First and last sequence numbers still in the outbox.
+    ///
+    /// Returns `None` when the outbox is empty.
+    pub fn outbox_sequence_range(&self) -> Result<Option<(u64, u64)>, StorageError> {
+        let cf = self
+            .db
+            .cf_handle(CF_OUTBOX)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_OUTBOX.to_string()))?;
+
+        let first = match self.db.iterator_cf(&cf, IteratorMode::Start).next() {
+            Some(item) => OutboxKey::from_bytes(&item?.0)?.sequence,
+            None => return Ok(None),
+        };
+        let last = match self.db.iterator_cf(&cf, IteratorMode::End).next() {
+            Some(item) => OutboxKey::from_bytes(&item?.0)?.sequence,
+            None => first,
+        };
+        Ok(Some((first, last)))
+    }
+
     /// Flush all column families to disk
     pub fn flush(&self) -> Result<(), StorageError> {
+        self.check_writable()?;
         for cf_name in ALL_CF_NAMES {
             if let Some(cf) = self.db.cf_handle(cf_name) {
                 self.db.flush_cf(&cf)?;
@@ -339,6 +587,7 @@ impl Storage {
     /// Appends a new version rather than mutating.
     /// Updates toc_latest to point to new version.
     pub fn put_toc_node(&self, node: &memory_types::TocNode) -> Result<(), StorageError> {
+        self.check_writable()?;
         let nodes_cf = self
             .db
             .cf_handle(CF_TOC_NODES)
@@ -368,6 +617,7 @@ impl Storage {
         // Update node version
         let mut versioned_node = node.clone();
         versioned_node.version = new_version;
+        versioned_node.version_written_ms = Some(chrono::Utc::now().timestamp_millis());
 
         let node_bytes = versioned_node
             .to_bytes()
@@ -380,19 +630,53 @@ impl Storage {
 
         self.db.write(batch)?;
 
+        if current_version == 0 && node.level == TocLevel::Segment {
+            self.segments_created.fetch_add(1, Ordering::SeqCst);
+            if let Some(ids) = self.new_segment_ids.lock().unwrap().as_mut() {
+                ids.push(node.node_id.clone());
+            }
+        }
+
         debug!(node_id = %node.node_id, version = new_version, "Stored TOC node");
         Ok(())
     }
 
+    /// Number of segment nodes (summaries) created through this handle.
+    ///
+    /// Counts first versions only; rewriting an existing segment is not a
+    /// new summary.
+    pub fn segments_created(&self) -> u64 {
+        self.segments_created.load(Ordering::SeqCst)
+    }
+
+    /// Start recording the IDs of newly created segment nodes for
+    /// [`take_new_segment_ids`](Self::take_new_segment_ids).
+    ///
+    /// Off by default so the list doesn't grow without a consumer.
+    pub fn track_new_segments(&self) {
+        self.new_segment_ids
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Vec::new);
+    }
+
+    /// Drain the IDs of segment nodes created since the last call.
+    ///
+    /// Empty unless [`track_new_segments`](Self::track_new_segments) was called.
+    pub fn take_new_segment_ids(&self) -> Vec<String> {
+        self.new_segment_ids
+            .lock()
+            .unwrap()
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
     /// Get the latest version of a TOC node.
     pub fn get_toc_node(
         &self,
         node_id: &str,
     ) -> Result<Option<memory_types::TocNode>, StorageError> {
-        let nodes_cf = self
-            .db
-            .cf_handle(CF_TOC_NODES)
-            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_NODES.to_string()))?;
         let latest_cf = self
             .db
             .cf_handle(CF_TOC_LATEST)
@@ -405,7 +689,22 @@ impl Storage {
             _ => return Ok(None),
         };
 
-        // Get versioned node
+        self.get_toc_node_version(node_id, version)
+    }
+
+    /// Get a specific version of a TOC node.
+    ///
+    /// Versions start at 1 and increase with every `put_toc_node`.
+    pub fn get_toc_node_version(
+        &self,
+        node_id: &str,
+        version: u32,
+    ) -> Result<Option<memory_types::TocNode>, StorageError> {
+        let nodes_cf = self
+            .db
+            .cf_handle(CF_TOC_NODES)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_NODES.to_string()))?;
+
         let versioned_key = format!("toc:{}:v{:06}", node_id, version);
         match self.db.get_cf(&nodes_cf, versioned_key.as_bytes())? {
             Some(bytes) => {
@@ -417,6 +716,119 @@ impl Storage {
         }
     }
 
+    /// Delete old TOC node versions, keeping the newest `keep_last` per node.
+    ///
+    /// Versions are counted back from the one the `latest` pointer names,
+    /// so the latest version always survives (`keep_last` of 0 is treated
+    /// as 1) and the pointers themselves are never modified. Returns the
+    /// number of versions deleted.
+    pub fn prune_toc_versions(&self, keep_last: usize) -> Result<usize, StorageError> {
+        self.check_writable()?;
+        let nodes_cf = self
+            .db
+            .cf_handle(CF_TOC_NODES)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_NODES.to_string()))?;
+        let latest_cf = self
+            .db
+            .cf_handle(CF_TOC_LATEST)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_LATEST.to_string()))?;
+        let keep_last = keep_last.max(1) as u64;
+
+        // Latest version per node, keyed as in CF_TOC_NODES ("toc:{node_id}")
+        let mut latest_versions = std::collections::HashMap::new();
+        for item in self.db.iterator_cf(&latest_cf, IteratorMode::Start) {
+            let (key, value) = item?;
+            let key_str = String::from_utf8_lossy(&key);
+            if let (Some(node_id), Some(bytes)) = (
+                key_str.strip_prefix("latest:"),
+                value.get(..4).and_then(|b| <[u8; 4]>::try_from(b).ok()),
+            ) {
+                latest_versions.insert(format!("toc:{}", node_id), u32::from_be_bytes(bytes));
+            }
+        }
+
+        let mut batch = WriteBatch::default();
+        let mut removed = 0;
+        for item in self.db.iterator_cf(&nodes_cf, IteratorMode::Start) {
+            let (key, _) = item?;
+            let key_str = String::from_utf8_lossy(&key);
+            let Some((node_key, version)) = key_str
+                .rsplit_once(":v")
+                .and_then(|(node_key, v)| Some((node_key, v.parse::<u32>().ok()?)))
+            else {
+                continue;
+            };
+            let Some(&latest) = latest_versions.get(node_key) else {
+                continue;
+            };
+            if u64::from(version) + keep_last <= u64::from(latest) {
+                batch.delete_cf(&nodes_cf, &key);
+                removed += 1;
+                if batch.len() >= PRUNE_BATCH_SIZE {
+                    self.db.write(std::mem::take(&mut batch))?;
+                }
+            }
+        }
+
+        self.db.write(batch)?;
+        info!(removed, keep_last, "Pruned old TOC node versions");
+        Ok(removed)
+    }
+
+    /// Get the latest version of several TOC nodes.
+    ///
+    /// Reads are batched into two multi-gets (latest pointers, then node
+    /// versions). Returns one entry per ID in input order, `None` where the
+    /// node does not exist.
+    pub fn get_toc_nodes(
+        &self,
+        node_ids: &[String],
+    ) -> Result<Vec<Option<memory_types::TocNode>>, StorageError> {
+        let nodes_cf = self
+            .db
+            .cf_handle(CF_TOC_NODES)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_NODES.to_string()))?;
+        let latest_cf = self
+            .db
+            .cf_handle(CF_TOC_LATEST)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_TOC_LATEST.to_string()))?;
+
+        let latest_keys: Vec<String> = node_ids
+            .iter()
+            .map(|node_id| format!("latest:{}", node_id))
+            .collect();
+        let versions = self
+            .db
+            .multi_get_cf(latest_keys.iter().map(|key| (latest_cf, key.as_bytes())));
+
+        // Versioned keys for the IDs that have a latest pointer
+        let mut versioned_keys = Vec::new();
+        let mut positions = Vec::new();
+        for (i, (node_id, version)) in node_ids.iter().zip(versions).enumerate() {
+            if let Some(b) = version? {
+                if b.len() >= 4 {
+                    let version = u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+                    versioned_keys.push(format!("toc:{}:v{:06}", node_id, version));
+                    positions.push(i);
+                }
+            }
+        }
+
+        let mut nodes = vec![None; node_ids.len()];
+        let values = self
+            .db
+            .multi_get_cf(versioned_keys.iter().map(|key| (nodes_cf, key.as_bytes())));
+        for (i, value) in positions.into_iter().zip(values) {
+            if let Some(bytes) = value? {
+                let node = memory_types::TocNode::from_bytes(&bytes)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                nodes[i] = Some(node);
+            }
+        }
+
+        Ok(nodes)
+    }
+
     /// Get TOC nodes by level, optionally filtered by time range.
     pub fn get_toc_nodes_by_level(
         &self,
@@ -505,10 +917,83 @@ impl Storage {
         }
     }
 
+    /// Get a node and its descendants down to `max_depth` levels below it.
+    ///
+    /// Nodes are returned breadth-first (the root at depth 0), with siblings
+    /// ordered by start time. At most `max_nodes` nodes are returned; if the
+    /// limit cuts the traversal short, `truncated` is set. Returns `None` if
+    /// the root does not exist.
+    pub fn get_subtree(
+        &self,
+        root_id: &str,
+        max_depth: u32,
+        max_nodes: usize,
+    ) -> Result<Option<Subtree>, StorageError> {
+        let Some(root) = self.get_toc_node(root_id)? else {
+            return Ok(None);
+        };
+
+        let mut subtree = Subtree {
+            nodes: Vec::new(),
+            truncated: false,
+        };
+        let mut level = vec![(root, None)];
+        let mut depth = 0;
+        while !level.is_empty() {
+            // Room left for the next level once this one is added. Children
+            // past it are not fetched; a parent's children are taken whole
+            // so they can be ordered by start time.
+            let budget = max_nodes.saturating_sub(subtree.nodes.len() + level.len());
+
+            // (parent position in this level, parent ID) for each child ID
+            let mut child_ids = Vec::new();
+            let mut child_parents = Vec::new();
+            for (position, (node, parent_id)) in level.into_iter().enumerate() {
+                if subtree.nodes.len() >= max_nodes {
+                    subtree.truncated = true;
+                    return Ok(Some(subtree));
+                }
+                if depth < max_depth && !node.child_node_ids.is_empty() {
+                    if child_ids.len() < budget {
+                        for child_id in &node.child_node_ids {
+                            child_ids.push(child_id.clone());
+                            child_parents.push((position, node.node_id.clone()));
+                        }
+                    } else {
+                        subtree.truncated = true;
+                    }
+                }
+                subtree.nodes.push(SubtreeNode {
+                    node,
+                    parent_id,
+                    depth,
+                });
+            }
+
+            // Fetch the next level in one batch; siblings stay grouped
+            // under their parent and ordered by start time
+            let mut next: Vec<_> = self
+                .get_toc_nodes(&child_ids)?
+                .into_iter()
+                .zip(child_parents)
+                .filter_map(|(child, parent)| child.map(|c| (parent, c)))
+                .collect();
+            next.sort_by_key(|((position, _), child)| (*position, child.start_time));
+            level = next
+                .into_iter()
+                .map(|((_, parent_id), child)| (child, Some(parent_id)))
+                .collect();
+            depth += 1;
+        }
+
+        Ok(Some(subtree))
+    }
+
     // ==================== Grip Methods ====================
 
     /// Store a grip.
     pub fn put_grip(&self, grip: &memory_types::Grip) -> Result<(), StorageError> {
+        self.check_writable()?;
         let grips_cf = self
             .db
             .cf_handle(CF_GRIPS)
@@ -549,6 +1034,8 @@ impl Storage {
     }
 
     /// Get all grips linked to a TOC node.
+    ///
+    /// Grips are ordered by timestamp (source event start), then grip ID.
     pub fn get_grips_for_node(
         &self,
         node_id: &str,
@@ -582,11 +1069,39 @@ impl Storage {
             }
         }
 
+        // Key order is lexicographic on the ID; return source order instead
+        grips.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.grip_id.cmp(&b.grip_id))
+        });
+        Ok(grips)
+    }
+
+    /// Get all grips, skipping node index entries.
+    pub fn get_all_grips(&self) -> Result<Vec<memory_types::Grip>, StorageError> {
+        let grips_cf = self
+            .db
+            .cf_handle(CF_GRIPS)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_GRIPS.to_string()))?;
+
+        let mut grips = Vec::new();
+        for item in self.db.iterator_cf(&grips_cf, IteratorMode::Start) {
+            let (key, value) = item?;
+            if key.starts_with(b"node:") {
+                continue;
+            }
+            let grip = memory_types::Grip::from_bytes(&value)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            grips.push(grip);
+        }
+
         Ok(grips)
     }
 
     /// Delete a grip and its index entry.
     pub fn delete_grip(&self, grip_id: &str) -> Result<(), StorageError> {
+        self.check_writable()?;
         let grips_cf = self
             .db
             .cf_handle(CF_GRIPS)
@@ -615,6 +1130,7 @@ impl Storage {
     /// This is a low-level method for use by other crates that manage their own
     /// column families (e.g., memory-topics).
     pub fn put(&self, cf_name: &str, key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+        self.check_writable()?;
         let cf = self
             .db
             .cf_handle(cf_name)
@@ -641,6 +1157,7 @@ impl Storage {
     /// This is a low-level method for use by other crates that manage their own
     /// column families (e.g., memory-topics).
     pub fn delete(&self, cf_name: &str, key: &[u8]) -> Result<(), StorageError> {
+        self.check_writable()?;
         let cf = self
             .db
             .cf_handle(cf_name)
@@ -687,8 +1204,12 @@ impl Storage {
     /// Trigger manual compaction on all column families.
     ///
     /// Per CLI-03: Admin commands include compact.
-    pub fn compact(&self) -> Result<(), StorageError> {
+    ///
+    /// Sizes in the report are the database directory's total disk usage.
+    pub fn compact(&self) -> Result<CompactionReport, StorageError> {
+        self.check_writable()?;
         info!("Starting full compaction...");
+        let bytes_before = self.get_disk_usage()?;
         self.db.compact_range::<&[u8], &[u8]>(None, None);
 
         for cf_name in &[
@@ -703,20 +1224,36 @@ impl Storage {
                 self.db.compact_range_cf::<&[u8], &[u8]>(&cf, None, None);
             }
         }
-        info!("Compaction complete");
-        Ok(())
+        let report = CompactionReport::new(None, bytes_before, self.get_disk_usage()?);
+        info!(
+            bytes_before = report.bytes_before,
+            bytes_after = report.bytes_after,
+            "Compaction complete"
+        );
+        Ok(report)
     }
 
     /// Trigger compaction on a specific column family.
-    pub fn compact_cf(&self, cf_name: &str) -> Result<(), StorageError> {
+    ///
+    /// Sizes in the report are the column family's total SST file size.
+    pub fn compact_cf(&self, cf_name: &str) -> Result<CompactionReport, StorageError> {
+        self.check_writable()?;
         let cf = self
             .db
             .cf_handle(cf_name)
             .ok_or_else(|| StorageError::ColumnFamilyNotFound(cf_name.to_string()))?;
         info!(cf = %cf_name, "Starting compaction...");
+        let bytes_before = self.cf_size(cf_name, cf)?.sst_bytes;
         self.db.compact_range_cf::<&[u8], &[u8]>(&cf, None, None);
-        info!(cf = %cf_name, "Compaction complete");
-        Ok(())
+        let bytes_after = self.cf_size(cf_name, cf)?.sst_bytes;
+        let report = CompactionReport::new(Some(cf_name), bytes_before, bytes_after);
+        info!(
+            cf = %cf_name,
+            bytes_before = report.bytes_before,
+            bytes_after = report.bytes_after,
+            "Compaction complete"
+        );
+        Ok(report)
     }
 
     /// Get database statistics.
@@ -748,9 +1285,33 @@ impl Storage {
         // Get disk usage
         stats.disk_usage_bytes = self.get_disk_usage()?;
 
+        for cf_name in ALL_CF_NAMES {
+            if let Some(cf) = self.db.cf_handle(cf_name) {
+                stats.cf_sizes.push(self.cf_size(cf_name, cf)?);
+            }
+        }
+
         Ok(stats)
     }
 
+    /// Size estimates for one column family from RocksDB properties.
+    fn cf_size(&self, name: &str, cf: &rocksdb::ColumnFamily) -> Result<CfSize, StorageError> {
+        use rocksdb::properties::{
+            PropName, CUR_SIZE_ALL_MEM_TABLES, ESTIMATE_LIVE_DATA_SIZE, TOTAL_SST_FILES_SIZE,
+        };
+
+        let property = |prop: &PropName| -> Result<u64, StorageError> {
+            Ok(self.db.property_int_value_cf(cf, prop)?.unwrap_or(0))
+        };
+
+        Ok(CfSize {
+            name: name.to_string(),
+            live_data_bytes: property(ESTIMATE_LIVE_DATA_SIZE)?,
+            sst_bytes: property(TOTAL_SST_FILES_SIZE)?,
+            memtable_bytes: property(CUR_SIZE_ALL_MEM_TABLES)?,
+        })
+    }
+
     fn count_cf_entries(&self, cf: &rocksdb::ColumnFamily) -> Result<u64, StorageError> {
         let mut count = 0u64;
         let iter = self.db.iterator_cf(cf, IteratorMode::Start);
@@ -777,6 +1338,25 @@ impl Storage {
     }
 }
 
+/// A TOC node and its descendants, from [`Storage::get_subtree`].
+#[derive(Debug)]
+pub struct Subtree {
+    /// Nodes in breadth-first order, root first
+    pub nodes: Vec<SubtreeNode>,
+    /// True if the node limit stopped the traversal early
+    pub truncated: bool,
+}
+
+/// One node of a [`Subtree`].
+#[derive(Debug)]
+pub struct SubtreeNode {
+    pub node: memory_types::TocNode,
+    /// Parent within the subtree (None for the root)
+    pub parent_id: Option<String>,
+    /// Levels below the root
+    pub depth: u32,
+}
+
 /// Statistics about the storage.
 #[derive(Debug, Default)]
 pub struct StorageStats {
@@ -790,11 +1370,62 @@ pub struct StorageStats {
     pub outbox_count: u64,
     /// Total disk usage in bytes
     pub disk_usage_bytes: u64,
+    /// Per column family size estimates, in `ALL_CF_NAMES` order
+    pub cf_sizes: Vec<CfSize>,
+}
+
+/// Disk usage before and after a compaction, from [`Storage::compact`] or
+/// [`Storage::compact_cf`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Column family compacted, or None for a full compaction
+    pub cf_name: Option<String>,
+    /// Bytes on disk before compacting
+    pub bytes_before: u64,
+    /// Bytes on disk after compacting
+    pub bytes_after: u64,
+    /// Bytes freed; 0 if compaction grew the data (e.g. flushed memtables)
+    pub reclaimed: u64,
+}
+
+impl CompactionReport {
+    fn new(cf_name: Option<&str>, bytes_before: u64, bytes_after: u64) -> Self {
+        Self {
+            cf_name: cf_name.map(str::to_string),
+            bytes_before,
+            bytes_after,
+            reclaimed: bytes_before.saturating_sub(bytes_after),
+        }
+    }
+}
+
+/// Size estimates for one column family.
+///
+/// Data not yet flushed lives in memtables, so recently written column
+/// families may report SST sizes of zero.
+#[derive(Debug, Clone, Default)]
+pub struct CfSize {
+    /// Column family name
+    pub name: String,
+    /// Estimated live data in SST files (`rocksdb.estimate-live-data-size`)
+    pub live_data_bytes: u64,
+    /// Total size of SST files, including obsolete data awaiting compaction
+    pub sst_bytes: u64,
+    /// Memory used by memtables (`rocksdb.cur-size-all-mem-tables`)
+    pub memtable_bytes: u64,
+}
+
+impl CfSize {
+    /// Best estimate of the column family's size: live SST data plus memtables.
+    pub fn estimated_bytes(&self) -> u64 {
+        self.live_data_bytes + self.memtable_bytes
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::column_families::CF_TOPICS;
     use tempfile::TempDir;
 
     fn create_test_storage() -> (Storage, TempDir) {
@@ -853,6 +1484,211 @@ mod tests {
         assert!(!created2); // Second write should be idempotent
     }
 
+    #[test]
+    fn test_read_only_alongside_writer() {
+        let (storage, temp) = create_test_storage();
+        let event_id = ulid::Ulid::new().to_string();
+        storage.put_event(&event_id, b"event", b"outbox").unwrap();
+        storage.flush().unwrap();
+
+        // Writer stays open while the read-only handle is used
+        let reader = Storage::open_read_only(temp.path()).unwrap();
+        assert!(reader.is_read_only());
+        assert_eq!(
+            reader.get_event(&event_id).unwrap(),
+            Some(b"event".to_vec())
+        );
+        assert!(reader.get_stats().unwrap().event_count >= 1);
+
+        let other_id = ulid::Ulid::new().to_string();
+        assert!(matches!(
+            reader.put_event(&other_id, b"event", b"outbox"),
+            Err(StorageError::ReadOnly)
+        ));
+        assert!(matches!(
+            reader.put(CF_CHECKPOINTS, b"key", b"value"),
+            Err(StorageError::ReadOnly)
+        ));
+        assert!(matches!(
+            reader.purge_session("session"),
+            Err(StorageError::ReadOnly)
+        ));
+
+        // The writer is unaffected
+        assert!(!storage.is_read_only());
+        storage.put_event(&other_id, b"event", b"outbox").unwrap();
+    }
+
+    #[test]
+    fn test_cf_sizes_in_stats() {
+        let (storage, _temp) = create_test_storage();
+
+        let payload = vec![b'x'; 1024];
+        for _ in 0..200 {
+            let event_id = ulid::Ulid::new().to_string();
+            storage.put_event(&event_id, &payload, b"outbox").unwrap();
+        }
+        for i in 0..20 {
+            let grip = memory_types::Grip::new(
+                format!("grip:{i}"),
+                "User asked about authentication".to_string(),
+                "event-001".to_string(),
+                "event-003".to_string(),
+                chrono::Utc::now(),
+                "segment_summarizer".to_string(),
+            );
+            storage.put_grip(&grip).unwrap();
+        }
+        storage.put_checkpoint("job", b"checkpoint").unwrap();
+        storage.flush().unwrap();
+
+        let stats = storage.get_stats().unwrap();
+        assert_eq!(stats.cf_sizes.len(), ALL_CF_NAMES.len());
+        let size = |name: &str| {
+            stats
+                .cf_sizes
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .clone()
+        };
+
+        let events = size(CF_EVENTS);
+        let grips = size(CF_GRIPS);
+        let checkpoints = size(CF_CHECKPOINTS);
+        assert!(events.sst_bytes > 0);
+        assert!(events.estimated_bytes() > 0);
+        assert!(grips.estimated_bytes() > 0);
+        assert!(events.estimated_bytes() > checkpoints.estimated_bytes());
+    }
+
+    #[test]
+    fn test_put_events_batch() {
+        let (storage, _temp) = create_test_storage();
+
+        let events: Vec<(String, Vec<u8>, Vec<u8>)> = (0..1000)
+            .map(|i| {
+                (
+                    ulid::Ulid::new().to_string(),
+                    format!("event {i}").into_bytes(),
+                    format!("outbox {i}").into_bytes(),
+                )
+            })
+            .collect();
+
+        // One event already stored, one repeated within the batch
+        storage
+            .put_event(&events[0].0, &events[0].1, &events[0].2)
+            .unwrap();
+        let mut batch: Vec<(&str, &[u8], &[u8])> = events
+            .iter()
+            .map(|(id, event, outbox)| (id.as_str(), event.as_slice(), outbox.as_slice()))
+            .collect();
+        batch.push(batch[500]);
+
+        let result = storage.put_events_batch(&batch).unwrap();
+        assert_eq!(result.results.len(), 1001);
+        assert_eq!(result.created(), 999);
+        assert_eq!(result.skipped(), 2);
+        assert!(!result.results[0].1);
+        assert!(result.results[500].1);
+        assert!(!result.results[1000].1);
+
+        for (id, event, _) in &events {
+            assert_eq!(storage.get_event(id).unwrap().as_deref(), Some(&event[..]));
+        }
+        // One outbox entry per stored event
+        let stats = storage.get_stats().unwrap();
+        assert_eq!(stats.event_count, 1000);
+        assert_eq!(stats.outbox_count, 1000);
+    }
+
+    #[test]
+    fn test_sync_writes_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = StorageConfig::default().with_sync_writes(true);
+        let storage = Storage::open_with_config(temp_dir.path(), config).unwrap();
+        assert!(storage.config().sync_writes);
+
+        let event_id = ulid::Ulid::new().to_string();
+        let (_, created) = storage.put_event(&event_id, b"event", b"outbox").unwrap();
+        assert!(created);
+        assert!(storage.get_event(&event_id).unwrap().is_some());
+    }
+
+    /// Write then delete `count` 1KB values, flushing after each pass so SST
+    /// files hold both the shadowed data and the tombstones.
+    fn write_and_delete(storage: &Storage, count: u32) {
+        let value: Vec<u8> = (0..1024u32).map(|i| (i * 31 % 251) as u8).collect();
+        for i in 0..count {
+            storage
+                .put(CF_TOPICS, format!("topic-{i:05}").as_bytes(), &value)
+                .unwrap();
+        }
+        storage.flush().unwrap();
+        for i in 0..count {
+            storage
+                .delete(CF_TOPICS, format!("topic-{i:05}").as_bytes())
+                .unwrap();
+        }
+        storage.flush().unwrap();
+    }
+
+    #[test]
+    fn test_compact_cf_reports_reclaimed_space() {
+        let (storage, _temp) = create_test_storage();
+        write_and_delete(&storage, 500);
+
+        let report = storage.compact_cf(CF_TOPICS).unwrap();
+        assert_eq!(report.cf_name.as_deref(), Some(CF_TOPICS));
+        assert!(report.bytes_before > 0);
+        assert!(report.bytes_after < report.bytes_before, "{report:?}");
+        assert_eq!(report.reclaimed, report.bytes_before - report.bytes_after);
+    }
+
+    #[test]
+    fn test_compact_reports_disk_usage() {
+        let (storage, _temp) = create_test_storage();
+        write_and_delete(&storage, 500);
+
+        let report = storage.compact().unwrap();
+        assert_eq!(report.cf_name, None);
+        assert!(report.bytes_before > 0);
+        assert!(report.bytes_after > 0);
+        assert_eq!(
+            report.reclaimed,
+            report.bytes_before.saturating_sub(report.bytes_after)
+        );
+    }
+
+    #[test]
+    fn test_block_cache_serves_repeated_reads() {
+        fn hits_for_repeated_reads(block_cache_mb: usize) -> u64 {
+            let temp_dir = TempDir::new().unwrap();
+            let config = StorageConfig::default().with_block_cache_mb(block_cache_mb);
+            let storage = Storage::open_with_config(temp_dir.path(), config).unwrap();
+            assert_eq!(storage.config().block_cache_mb, block_cache_mb);
+
+            let event_id = ulid::Ulid::new().to_string();
+            storage.put_event(&event_id, b"event", b"outbox").unwrap();
+            // Push the event out of the memtable so reads go through SST blocks
+            storage.flush().unwrap();
+
+            let before = storage.block_cache_hits();
+            for _ in 0..100 {
+                assert!(storage.get_event(&event_id).unwrap().is_some());
+            }
+            storage.block_cache_hits() - before
+        }
+
+        let cached = hits_for_repeated_reads(16);
+        let uncached = hits_for_repeated_reads(0);
+        assert!(
+            cached > uncached,
+            "cached hits {cached} should exceed uncached hits {uncached}"
+        );
+    }
+
     #[test]
     fn test_get_events_in_range() {
         let (storage, _temp) = create_test_storage();
@@ -942,6 +1778,292 @@ mod tests {
         assert_eq!(retrieved.version, 2);
     }
 
+    #[test]
+    fn test_new_segments_tracked() {
+        let (storage, _temp) = create_test_storage();
+        let segment = |id: &str| {
+            memory_types::TocNode::new(
+                id.to_string(),
+                memory_types::TocLevel::Segment,
+                "Segment".to_string(),
+                chrono::Utc::now(),
+                chrono::Utc::now(),
+            )
+        };
+
+        // Counted, but IDs are only kept once tracking is on
+        storage
+            .put_toc_node(&segment("toc:segment:2024-01-15:a"))
+            .unwrap();
+        assert_eq!(storage.segments_created(), 1);
+        assert!(storage.take_new_segment_ids().is_empty());
+
+        storage.track_new_segments();
+        storage
+            .put_toc_node(&segment("toc:segment:2024-01-15:b"))
+            .unwrap();
+        // Rewrites and other levels are not new summaries
+        storage
+            .put_toc_node(&segment("toc:segment:2024-01-15:a"))
+            .unwrap();
+        let day = memory_types::TocNode::new(
+            "toc:day:2024-01-15".to_string(),
+            memory_types::TocLevel::Day,
+            "Monday".to_string(),
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        );
+        storage.put_toc_node(&day).unwrap();
+
+        assert_eq!(storage.segments_created(), 2);
+        assert_eq!(
+            storage.take_new_segment_ids(),
+            vec!["toc:segment:2024-01-15:b".to_string()]
+        );
+        assert!(storage.take_new_segment_ids().is_empty());
+    }
+
+    #[test]
+    fn test_prune_toc_versions_spans_batches() {
+        let (storage, _temp) = create_test_storage();
+        let node_id = "toc:day:2024-01-20";
+        let node = memory_types::TocNode::new(
+            node_id.to_string(),
+            memory_types::TocLevel::Day,
+            "Saturday".to_string(),
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        );
+        let versions = PRUNE_BATCH_SIZE as u32 + 3;
+        for _ in 0..versions {
+            storage.put_toc_node(&node).unwrap();
+        }
+
+        assert_eq!(
+            storage.prune_toc_versions(1).unwrap(),
+            versions as usize - 1
+        );
+        for version in [1, PRUNE_BATCH_SIZE as u32, versions - 1] {
+            assert!(storage
+                .get_toc_node_version(node_id, version)
+                .unwrap()
+                .is_none());
+        }
+        assert_eq!(
+            storage.get_toc_node(node_id).unwrap().unwrap().version,
+            versions
+        );
+    }
+
+    #[test]
+    fn test_prune_toc_versions() {
+        let (storage, _temp) = create_test_storage();
+        let node_id = "toc:day:2024-01-18";
+
+        let mut node = memory_types::TocNode::new(
+            node_id.to_string(),
+            memory_types::TocLevel::Day,
+            "Thursday".to_string(),
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        );
+        for i in 1..=5 {
+            node.title = format!("Thursday v{}", i);
+            storage.put_toc_node(&node).unwrap();
+        }
+        // A node with a single version is left alone
+        let other = memory_types::TocNode::new(
+            "toc:day:2024-01-19".to_string(),
+            memory_types::TocLevel::Day,
+            "Friday".to_string(),
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        );
+        storage.put_toc_node(&other).unwrap();
+
+        assert_eq!(storage.prune_toc_versions(2).unwrap(), 3);
+
+        for version in 1..=3 {
+            assert!(storage
+                .get_toc_node_version(node_id, version)
+                .unwrap()
+                .is_none());
+        }
+        assert!(storage.get_toc_node_version(node_id, 4).unwrap().is_some());
+        let latest = storage.get_toc_node(node_id).unwrap().unwrap();
+        assert_eq!(latest.version, 5);
+        assert_eq!(latest.title, "Thursday v5");
+        assert!(storage
+            .get_toc_node("toc:day:2024-01-19")
+            .unwrap()
+            .is_some());
+
+        // Nothing left to prune
+        assert_eq!(storage.prune_toc_versions(2).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_toc_node_version_diff() {
+        let (storage, _temp) = create_test_storage();
+        let node_id = "toc:day:2024-01-17";
+
+        let mut node = memory_types::TocNode::new(
+            node_id.to_string(),
+            memory_types::TocLevel::Day,
+            "Wednesday".to_string(),
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        );
+        node.bullets = vec![memory_types::TocBullet::new("Summary pending...")];
+        node.child_node_ids = vec!["toc:segment:2024-01-17:a".to_string()];
+        storage.put_toc_node(&node).unwrap();
+
+        node.title = "Wednesday: release prep".to_string();
+        node.bullets = vec![memory_types::TocBullet::new("Tagged v1.2")];
+        node.keywords = vec!["release".to_string()];
+        storage.put_toc_node(&node).unwrap();
+
+        let v1 = storage.get_toc_node_version(node_id, 1).unwrap().unwrap();
+        let v2 = storage.get_toc_node_version(node_id, 2).unwrap().unwrap();
+        assert_eq!(v1.version, 1);
+        assert_eq!(v1.title, "Wednesday");
+        assert!(storage.get_toc_node_version(node_id, 3).unwrap().is_none());
+
+        let diff = v1.diff(&v2);
+        assert_eq!(
+            diff.title,
+            Some((
+                "Wednesday".to_string(),
+                "Wednesday: release prep".to_string()
+            ))
+        );
+        assert_eq!(diff.bullets_added, vec!["Tagged v1.2"]);
+        assert_eq!(diff.bullets_removed, vec!["Summary pending..."]);
+        assert_eq!(diff.keywords_added, vec!["release"]);
+        assert!(diff.children_added.is_empty() && diff.children_removed.is_empty());
+    }
+
+    #[test]
+    fn test_get_toc_nodes_batch() {
+        let (storage, _temp) = create_test_storage();
+
+        for (id, title) in [
+            ("toc:day:2024-01-15", "Monday"),
+            ("toc:day:2024-01-16", "Tuesday"),
+        ] {
+            let node = memory_types::TocNode::new(
+                id.to_string(),
+                memory_types::TocLevel::Day,
+                title.to_string(),
+                chrono::Utc::now(),
+                chrono::Utc::now(),
+            );
+            storage.put_toc_node(&node).unwrap();
+        }
+        // A second version must be the one returned
+        let mut updated = storage.get_toc_node("toc:day:2024-01-16").unwrap().unwrap();
+        updated.title = "Tuesday (updated)".to_string();
+        storage.put_toc_node(&updated).unwrap();
+
+        let ids = [
+            "toc:day:2024-01-16",
+            "toc:nonexistent",
+            "toc:day:2024-01-15",
+        ]
+        .map(String::from);
+        let nodes = storage.get_toc_nodes(&ids).unwrap();
+
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0].as_ref().unwrap().title, "Tuesday (updated)");
+        assert!(nodes[1].is_none());
+        assert_eq!(nodes[2].as_ref().unwrap().title, "Monday");
+        assert!(storage.get_toc_nodes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_subtree_depth_and_node_limits() {
+        let (storage, _temp) = create_test_storage();
+        let base = chrono::Utc::now();
+
+        // year -> 2 months -> 2 weeks each
+        let node = |id: &str, level, offset: i64, children: &[&str]| {
+            let start = base + chrono::Duration::days(offset);
+            let mut node =
+                memory_types::TocNode::new(id.to_string(), level, id.to_string(), start, start);
+            node.child_node_ids = children.iter().map(|c| c.to_string()).collect();
+            node
+        };
+        use memory_types::TocLevel::{Month, Week, Year};
+        for n in [
+            // Children listed out of time order
+            node(
+                "toc:year:2024",
+                Year,
+                0,
+                &["toc:month:2024-02", "toc:month:2024-01"],
+            ),
+            node(
+                "toc:month:2024-01",
+                Month,
+                0,
+                &["toc:week:2024-W01", "toc:week:2024-W02"],
+            ),
+            node("toc:month:2024-02", Month, 31, &["toc:week:2024-W05"]),
+            node("toc:week:2024-W01", Week, 0, &[]),
+            node("toc:week:2024-W02", Week, 7, &[]),
+            node("toc:week:2024-W05", Week, 31, &[]),
+        ] {
+            storage.put_toc_node(&n).unwrap();
+        }
+
+        let ids = |subtree: &Subtree| -> Vec<String> {
+            subtree
+                .nodes
+                .iter()
+                .map(|n| n.node.node_id.clone())
+                .collect()
+        };
+
+        let subtree = storage
+            .get_subtree("toc:year:2024", 1, 100)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            ids(&subtree),
+            ["toc:year:2024", "toc:month:2024-01", "toc:month:2024-02"]
+        );
+        assert!(!subtree.truncated);
+
+        let subtree = storage
+            .get_subtree("toc:year:2024", 2, 100)
+            .unwrap()
+            .unwrap();
+        assert_eq!(subtree.nodes.len(), 6);
+        assert_eq!(subtree.nodes[3].node.node_id, "toc:week:2024-W01");
+        assert_eq!(
+            subtree.nodes[5].parent_id.as_deref(),
+            Some("toc:month:2024-02")
+        );
+        assert_eq!(subtree.nodes[5].depth, 2);
+
+        let subtree = storage.get_subtree("toc:year:2024", 2, 4).unwrap().unwrap();
+        assert_eq!(subtree.nodes.len(), 4);
+        assert!(subtree.truncated);
+
+        // The limit is reached with the months; the weeks are never read
+        let subtree = storage.get_subtree("toc:year:2024", 2, 3).unwrap().unwrap();
+        assert_eq!(
+            ids(&subtree),
+            ["toc:year:2024", "toc:month:2024-01", "toc:month:2024-02"]
+        );
+        assert!(subtree.truncated);
+
+        assert!(storage
+            .get_subtree("toc:missing", 2, 100)
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_toc_node_not_found() {
         let (storage, _temp) = create_test_storage();
@@ -1033,6 +2155,67 @@ mod tests {
         assert_eq!(grips[0].grip_id, "grip:1706540400000:test456");
     }
 
+    #[test]
+    fn test_grips_for_node_ordered_by_timestamp() {
+        let (storage, _temp) = create_test_storage();
+        let node_id = "toc:segment:2024-01-29:abc";
+        let base = chrono::DateTime::from_timestamp_millis(1706540400000).unwrap();
+
+        // IDs sort opposite to timestamps, and two grips share a timestamp
+        for (id, offset_ms) in [("grip:a", 2000), ("grip:c", 0), ("grip:b", 0)] {
+            let grip = memory_types::Grip::new(
+                id.to_string(),
+                "excerpt".to_string(),
+                "event-start".to_string(),
+                "event-end".to_string(),
+                base + chrono::Duration::milliseconds(offset_ms),
+                "test".to_string(),
+            )
+            .with_toc_node(node_id.to_string());
+            storage.put_grip(&grip).unwrap();
+        }
+
+        let ids: Vec<String> = storage
+            .get_grips_for_node(node_id)
+            .unwrap()
+            .into_iter()
+            .map(|g| g.grip_id)
+            .collect();
+        assert_eq!(ids, vec!["grip:b", "grip:c", "grip:a"]);
+    }
+
+    #[test]
+    fn test_get_all_grips_skips_node_index() {
+        let (storage, _temp) = create_test_storage();
+
+        let indexed = memory_types::Grip::new(
+            "grip:1706540400000:all1".to_string(),
+            "Indexed".to_string(),
+            "event-001".to_string(),
+            "event-002".to_string(),
+            chrono::Utc::now(),
+            "test".to_string(),
+        )
+        .with_toc_node("toc:day:2024-01-29".to_string());
+        let plain = memory_types::Grip::new(
+            "grip:1706540500000:all2".to_string(),
+            "Plain".to_string(),
+            "event-003".to_string(),
+            "event-004".to_string(),
+            chrono::Utc::now(),
+            "test".to_string(),
+        );
+        storage.put_grip(&indexed).unwrap();
+        storage.put_grip(&plain).unwrap();
+
+        let grips = storage.get_all_grips().unwrap();
+        let ids: Vec<&str> = grips.iter().map(|g| g.grip_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["grip:1706540400000:all1", "grip:1706540500000:all2"]
+        );
+    }
+
     #[test]
     fn test_grip_not_found() {
         let (storage, _temp) = create_test_storage();
@@ -1102,6 +2285,42 @@ mod tests {
         assert_eq!(entries[0].1.event_id, event_id);
     }
 
+    #[test]
+    fn test_outbox_lag() {
+        let (storage, _temp) = create_test_storage();
+        assert_eq!(storage.outbox_lag().unwrap(), 0);
+
+        for i in 0..4 {
+            let event_id = ulid::Ulid::new().to_string();
+            let outbox_entry = memory_types::OutboxEntry::for_index(event_id.clone(), i * 1000);
+            storage
+                .put_event(&event_id, b"test", &outbox_entry.to_bytes().unwrap())
+                .unwrap();
+        }
+        assert_eq!(storage.outbox_lag().unwrap(), 4);
+
+        storage.delete_outbox_entries(1).unwrap();
+        assert_eq!(storage.outbox_lag().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_outbox_sequence_range() {
+        let (storage, _temp) = create_test_storage();
+        assert_eq!(storage.outbox_sequence_range().unwrap(), None);
+
+        for i in 0..4 {
+            let event_id = ulid::Ulid::new().to_string();
+            let outbox_entry = memory_types::OutboxEntry::for_index(event_id.clone(), i * 1000);
+            storage
+                .put_event(&event_id, b"test", &outbox_entry.to_bytes().unwrap())
+                .unwrap();
+        }
+        assert_eq!(storage.outbox_sequence_range().unwrap(), Some((0, 3)));
+
+        storage.delete_outbox_entries(1).unwrap();
+        assert_eq!(storage.outbox_sequence_range().unwrap(), Some((2, 3)));
+    }
+
     #[test]
     fn test_get_outbox_entries_with_limit() {
         let (storage, _temp) = create_test_storage();
@@ -24,6 +24,10 @@ pub enum StorageError {
     /// Event not found
     #[error("Event not found: {0}")]
     NotFound(String),
+
+    /// Write attempted on storage opened with `Storage::open_read_only`
+    #[error("Storage is read-only")]
+    ReadOnly,
 }
 
 impl From<serde_json::Error> for StorageError {
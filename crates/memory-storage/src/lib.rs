@@ -7,19 +7,26 @@
 //! - Idempotent event writes (ING-03)
 //! - Checkpoint-based crash recovery (STOR-03)
 //! - Usage tracking with cache-first reads (Phase 16)
+//! - Per-session purge of events and derived grips
 
 pub mod column_families;
 pub mod db;
 pub mod episodes;
 pub mod error;
 pub mod keys;
+pub mod novelty;
+pub mod purge;
 pub mod usage;
 
 pub use column_families::{
-    CF_CHECKPOINTS, CF_EPISODES, CF_EVENTS, CF_GRIPS, CF_OUTBOX, CF_TOC_LATEST, CF_TOC_NODES,
-    CF_TOPICS, CF_TOPIC_LINKS, CF_TOPIC_RELS, CF_USAGE_COUNTERS,
+    CF_CHECKPOINTS, CF_EPISODES, CF_EVENTS, CF_GRIPS, CF_NOVELTY_STATE, CF_OUTBOX, CF_TOC_LATEST,
+    CF_TOC_NODES, CF_TOPICS, CF_TOPIC_LINKS, CF_TOPIC_RELS, CF_USAGE_COUNTERS,
+};
+pub use db::{
+    BatchResult, CfSize, CompactionReport, Storage, StorageConfig, StorageStats, Subtree,
+    SubtreeNode,
 };
-pub use db::{Storage, StorageStats};
 pub use error::StorageError;
 pub use keys::{CheckpointKey, EventKey, OutboxKey};
+pub use purge::PurgeReport;
 pub use usage::UsageTracker;
@@ -0,0 +1,70 @@
+//! Novelty state storage operations.
+//!
+//! Persists the dedup gate's in-flight buffer in the CF_NOVELTY_STATE column
+//! family so recently seen events are still recognized after a restart.
+//! The snapshot is stored as JSON under a single key.
+
+use crate::column_families::CF_NOVELTY_STATE;
+use crate::error::StorageError;
+use crate::Storage;
+use memory_types::InFlightBufferSnapshot;
+use tracing::debug;
+
+/// Key of the in-flight buffer snapshot.
+pub(crate) const IN_FLIGHT_BUFFER_KEY: &[u8] = b"in_flight_buffer";
+
+impl Storage {
+    /// Store the in-flight dedup buffer, replacing any previous snapshot.
+    pub fn put_novelty_state(&self, snapshot: &InFlightBufferSnapshot) -> Result<(), StorageError> {
+        let bytes =
+            serde_json::to_vec(snapshot).map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        self.put(CF_NOVELTY_STATE, IN_FLIGHT_BUFFER_KEY, &bytes)?;
+        debug!(entries = snapshot.entries.len(), "Stored novelty state");
+        Ok(())
+    }
+
+    /// Get the last stored in-flight dedup buffer, if any.
+    pub fn get_novelty_state(&self) -> Result<Option<InFlightBufferSnapshot>, StorageError> {
+        match self.get(CF_NOVELTY_STATE, IN_FLIGHT_BUFFER_KEY)? {
+            Some(bytes) => {
+                let snapshot = serde_json::from_slice(&bytes)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                Ok(Some(snapshot))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use memory_types::InFlightBuffer;
+    use tempfile::TempDir;
+
+    use crate::Storage;
+
+    #[test]
+    fn test_novelty_state_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::open(temp_dir.path()).unwrap();
+        assert!(storage.get_novelty_state().unwrap().is_none());
+
+        let mut buffer = InFlightBuffer::new(4, 2);
+        buffer.push("evt-1".to_string(), vec![1.0, 0.0]);
+        buffer.push("evt-2".to_string(), vec![0.0, 1.0]);
+        storage.put_novelty_state(&buffer.snapshot()).unwrap();
+
+        // Reopen to make sure the state survives a restart
+        drop(storage);
+        let storage = Storage::open(temp_dir.path()).unwrap();
+        let snapshot = storage.get_novelty_state().unwrap().unwrap();
+        assert_eq!(snapshot.dimension, 2);
+        let ids: Vec<&str> = snapshot
+            .entries
+            .iter()
+            .map(|e| e.event_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["evt-1", "evt-2"]);
+    }
+}
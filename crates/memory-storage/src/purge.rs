@@ -0,0 +1,347 @@
+//! Session purge operations.
+//!
+//! Removes everything stored for one session: its events, the grips whose
+//! source events belong to it, any outbox entries still waiting to index
+//! those events, and their entries in the persisted dedup buffer. All
+//! deletions are applied in a single WriteBatch.
+//!
+//! Search indexes live outside storage, so the returned [`PurgeReport`]
+//! carries the deleted IDs for the caller to remove from BM25 and vector
+//! indexes.
+//!
+//! TOC summaries are not rewritten: a segment summary blends every session
+//! active in its time range, and regenerating it needs a summarizer. The
+//! report lists the segment nodes overlapping the purged events so the
+//! caller can surface them.
+//!
+//! Events are not indexed by session, so finding them is a scan of the
+//! event keys. Grips are scanned over the grip keys only, skipping the
+//! per-node index entries that share their column family.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use rocksdb::{Direction, IteratorMode, WriteBatch};
+use tracing::{debug, info};
+
+use crate::column_families::{CF_EVENTS, CF_GRIPS, CF_NOVELTY_STATE, CF_OUTBOX};
+use crate::error::StorageError;
+use crate::novelty::IN_FLIGHT_BUFFER_KEY;
+use crate::Storage;
+use memory_types::{Event, Grip, OutboxEntry, TocLevel};
+
+/// Key prefix of event records in CF_EVENTS.
+const EVENT_KEY_PREFIX: &[u8] = b"evt:";
+
+/// Key prefix of grip records in CF_GRIPS (index entries use `node:`).
+const GRIP_KEY_PREFIX: &[u8] = b"grip:";
+
+/// What [`Storage::purge_session`] deleted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PurgeReport {
+    /// Session that was purged
+    pub session_id: String,
+    /// Number of events deleted
+    pub events_deleted: usize,
+    /// Number of grips deleted
+    pub grips_deleted: usize,
+    /// Number of pending outbox entries deleted
+    pub outbox_entries_deleted: usize,
+    /// Number of entries removed from the persisted dedup buffer
+    pub novelty_entries_deleted: usize,
+    /// IDs of the deleted events
+    pub event_ids: Vec<String>,
+    /// IDs of the deleted grips
+    pub grip_ids: Vec<String>,
+    /// Segment TOC nodes overlapping the purged events, whose summaries may
+    /// still reflect the session
+    pub toc_node_ids: Vec<String>,
+}
+
+impl PurgeReport {
+    /// True if nothing was stored for the session.
+    pub fn is_empty(&self) -> bool {
+        self.events_deleted == 0 && self.grips_deleted == 0
+    }
+
+    /// Search index document IDs to remove (event and grip IDs).
+    pub fn doc_ids(&self) -> Vec<String> {
+        self.event_ids
+            .iter()
+            .chain(&self.grip_ids)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Storage {
+    /// Delete all events of a session and the data derived from them.
+    ///
+    /// A grip is deleted if its start or end event belongs to the session.
+    /// Pending outbox entries for the session's events are dropped so they
+    /// are not indexed afterwards, and their embeddings are removed from the
+    /// persisted dedup buffer. TOC nodes are left in place and reported in
+    /// [`PurgeReport::toc_node_ids`]: their summaries cover time ranges
+    /// shared with other sessions.
+    ///
+    /// Values that do not parse are skipped rather than failing the purge.
+    pub fn purge_session(&self, session_id: &str) -> Result<PurgeReport, StorageError> {
+        self.check_writable()?;
+        let events_cf = self
+            .db
+            .cf_handle(CF_EVENTS)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_EVENTS.to_string()))?;
+        let grips_cf = self
+            .db
+            .cf_handle(CF_GRIPS)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_GRIPS.to_string()))?;
+        let outbox_cf = self
+            .db
+            .cf_handle(CF_OUTBOX)
+            .ok_or_else(|| StorageError::ColumnFamilyNotFound(CF_OUTBOX.to_string()))?;
+
+        let mut report = PurgeReport {
+            session_id: session_id.to_string(),
+            ..Default::default()
+        };
+        let mut batch = WriteBatch::default();
+
+        // Events
+        let mut time_range: Option<(i64, i64)> = None;
+        for item in self.db.iterator_cf(
+            &events_cf,
+            IteratorMode::From(EVENT_KEY_PREFIX, Direction::Forward),
+        ) {
+            let (key, value) = item?;
+            if !key.starts_with(EVENT_KEY_PREFIX) {
+                break;
+            }
+            let Ok(event) = Event::from_bytes(&value) else {
+                continue;
+            };
+            if event.session_id == session_id {
+                let ts = event.timestamp_ms();
+                time_range = Some(match time_range {
+                    Some((start, end)) => (start.min(ts), end.max(ts)),
+                    None => (ts, ts),
+                });
+                batch.delete_cf(&events_cf, &key);
+                report.event_ids.push(event.event_id);
+            }
+        }
+        let purged: HashSet<&str> = report.event_ids.iter().map(String::as_str).collect();
+
+        // Grips and their node index entries
+        for item in self.db.iterator_cf(
+            &grips_cf,
+            IteratorMode::From(GRIP_KEY_PREFIX, Direction::Forward),
+        ) {
+            let (key, value) = item?;
+            if !key.starts_with(GRIP_KEY_PREFIX) {
+                break;
+            }
+            let Ok(grip) = Grip::from_bytes(&value) else {
+                continue;
+            };
+            if purged.contains(grip.event_id_start.as_str())
+                || purged.contains(grip.event_id_end.as_str())
+            {
+                if let Some(ref node_id) = grip.toc_node_id {
+                    let index_key = format!("node:{}:{}", node_id, grip.grip_id);
+                    batch.delete_cf(&grips_cf, index_key.as_bytes());
+                }
+                batch.delete_cf(&grips_cf, &key);
+                report.grip_ids.push(grip.grip_id);
+            }
+        }
+
+        // Outbox entries not yet indexed
+        for item in self.db.iterator_cf(&outbox_cf, IteratorMode::Start) {
+            let (key, value) = item?;
+            let Ok(entry) = OutboxEntry::from_bytes(&value) else {
+                continue;
+            };
+            if purged.contains(entry.event_id.as_str()) {
+                batch.delete_cf(&outbox_cf, &key);
+                report.outbox_entries_deleted += 1;
+            }
+        }
+
+        // Dedup buffer snapshot
+        if let Some(mut snapshot) = self.get_novelty_state()? {
+            let before = snapshot.entries.len();
+            snapshot
+                .entries
+                .retain(|entry| !purged.contains(entry.event_id.as_str()));
+            report.novelty_entries_deleted = before - snapshot.entries.len();
+            if report.novelty_entries_deleted > 0 {
+                let novelty_cf = self.db.cf_handle(CF_NOVELTY_STATE).ok_or_else(|| {
+                    StorageError::ColumnFamilyNotFound(CF_NOVELTY_STATE.to_string())
+                })?;
+                let bytes = serde_json::to_vec(&snapshot)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                batch.put_cf(&novelty_cf, IN_FLIGHT_BUFFER_KEY, bytes);
+            }
+        }
+
+        // Segments whose summaries may mention the session
+        if let Some((start, end)) = time_range {
+            let to_time = |ms| DateTime::<Utc>::from_timestamp_millis(ms);
+            report.toc_node_ids = self
+                .get_toc_nodes_by_level(TocLevel::Segment, to_time(start), to_time(end))?
+                .into_iter()
+                .map(|node| node.node_id)
+                .collect();
+        }
+
+        report.events_deleted = report.event_ids.len();
+        report.grips_deleted = report.grip_ids.len();
+
+        if report.is_empty() {
+            debug!(session_id = %session_id, "No data to purge for session");
+            return Ok(report);
+        }
+
+        self.db.write(batch)?;
+        info!(
+            session_id = %session_id,
+            events = report.events_deleted,
+            grips = report.grips_deleted,
+            outbox = report.outbox_entries_deleted,
+            novelty = report.novelty_entries_deleted,
+            stale_toc_nodes = report.toc_node_ids.len(),
+            "Purged session"
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use memory_types::{EventRole, EventType, InFlightBuffer, TocNode};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Store `count` events for a session plus one grip spanning them.
+    fn ingest_session(storage: &Storage, session_id: &str, count: usize) -> (Vec<String>, String) {
+        let mut event_ids = Vec::new();
+        for i in 0..count {
+            let event_id = ulid::Ulid::new().to_string();
+            let event = Event::new(
+                event_id.clone(),
+                session_id.to_string(),
+                Utc::now(),
+                EventType::UserMessage,
+                EventRole::User,
+                format!("{session_id} message {i}"),
+            );
+            let outbox = OutboxEntry::for_index(event_id.clone(), event.timestamp_ms());
+            storage
+                .put_event(
+                    &event_id,
+                    &event.to_bytes().unwrap(),
+                    &outbox.to_bytes().unwrap(),
+                )
+                .unwrap();
+            event_ids.push(event_id);
+        }
+
+        let grip_id = format!("grip:{session_id}");
+        let mut grip = Grip::new(
+            grip_id.clone(),
+            format!("{session_id} excerpt"),
+            event_ids[0].clone(),
+            event_ids[count - 1].clone(),
+            Utc::now(),
+            "segment_summarizer".to_string(),
+        );
+        grip.toc_node_id = Some("toc:segment:2024-01-15:abc".to_string());
+        storage.put_grip(&grip).unwrap();
+
+        (event_ids, grip_id)
+    }
+
+    #[test]
+    fn test_purge_session_leaves_other_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::open(temp_dir.path()).unwrap();
+
+        let (purged_events, purged_grip) = ingest_session(&storage, "session-a", 3);
+        let (kept_events, kept_grip) = ingest_session(&storage, "session-b", 2);
+
+        let report = storage.purge_session("session-a").unwrap();
+        assert_eq!(report.session_id, "session-a");
+        assert_eq!(report.events_deleted, 3);
+        assert_eq!(report.grips_deleted, 1);
+        assert_eq!(report.outbox_entries_deleted, 3);
+        assert_eq!(report.grip_ids, vec![purged_grip.clone()]);
+        assert_eq!(report.doc_ids().len(), 4);
+
+        for event_id in &purged_events {
+            assert!(storage.get_event(event_id).unwrap().is_none());
+        }
+        assert!(storage.get_grip(&purged_grip).unwrap().is_none());
+        let node_grips = storage
+            .get_grips_for_node("toc:segment:2024-01-15:abc")
+            .unwrap();
+        assert_eq!(node_grips.len(), 1);
+        assert_eq!(node_grips[0].grip_id, kept_grip);
+
+        for event_id in &kept_events {
+            assert!(storage.get_event(event_id).unwrap().is_some());
+        }
+        let outbox = storage.get_outbox_entries(0, 100).unwrap();
+        let outbox_ids: Vec<&str> = outbox.iter().map(|(_, e)| e.event_id.as_str()).collect();
+        assert_eq!(outbox_ids, kept_events);
+
+        // Purging again finds nothing
+        assert!(storage.purge_session("session-a").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_purge_session_clears_novelty_and_reports_segments() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::open(temp_dir.path()).unwrap();
+
+        let (purged_events, _) = ingest_session(&storage, "session-a", 2);
+        let (kept_events, _) = ingest_session(&storage, "session-b", 1);
+
+        let mut buffer = InFlightBuffer::new(8, 2);
+        buffer.push(purged_events[0].clone(), vec![1.0, 0.0]);
+        buffer.push(kept_events[0].clone(), vec![0.0, 1.0]);
+        buffer.push(purged_events[1].clone(), vec![1.0, 0.0]);
+        storage.put_novelty_state(&buffer.snapshot()).unwrap();
+
+        let now = Utc::now();
+        let covering = TocNode::new(
+            "toc:segment:now:abc".to_string(),
+            TocLevel::Segment,
+            "Current segment".to_string(),
+            now - chrono::Duration::hours(1),
+            now + chrono::Duration::hours(1),
+        );
+        let earlier = TocNode::new(
+            "toc:segment:earlier:def".to_string(),
+            TocLevel::Segment,
+            "Earlier segment".to_string(),
+            now - chrono::Duration::days(2),
+            now - chrono::Duration::days(1),
+        );
+        storage.put_toc_node(&covering).unwrap();
+        storage.put_toc_node(&earlier).unwrap();
+
+        let report = storage.purge_session("session-a").unwrap();
+        assert_eq!(report.novelty_entries_deleted, 2);
+        assert_eq!(report.toc_node_ids, vec![covering.node_id]);
+
+        let snapshot = storage.get_novelty_state().unwrap().unwrap();
+        let buffered: Vec<&str> = snapshot
+            .entries
+            .iter()
+            .map(|e| e.event_id.as_str())
+            .collect();
+        assert_eq!(buffered, vec![kept_events[0].as_str()]);
+    }
+}
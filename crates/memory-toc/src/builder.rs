@@ -7,10 +7,14 @@ use std::sync::Arc;
 use tracing::{debug, info};
 
 use memory_storage::Storage;
-use memory_types::{Segment, TocBullet, TocLevel, TocNode};
+use memory_types::{GripProvenance, Segment, TocBullet, TocLevel, TocNode};
 
-use crate::node_id::{generate_node_id, generate_title, get_parent_node_id, get_time_boundaries};
-use crate::summarizer::{extract_grips, Summarizer, SummarizerError, Summary};
+use crate::config::TocConfig;
+use crate::node_id::{
+    generate_node_id, generate_segment_node_id_from_events, get_parent_node_id,
+    get_time_boundaries, EnglishDateTitleFormatter, TitleFormatter,
+};
+use crate::summarizer::{GripExtractor, Summarizer, SummarizerError, Summary};
 
 /// Error type for TOC building.
 #[derive(Debug, thiserror::Error)]
@@ -31,6 +35,9 @@ pub enum BuilderError {
 pub struct TocBuilder {
     storage: Arc<Storage>,
     summarizer: Arc<dyn Summarizer>,
+    title_formatter: Arc<dyn TitleFormatter>,
+    /// Recorded in grip provenance when building as part of a rebuild
+    rebuild_id: Option<String>,
 }
 
 impl TocBuilder {
@@ -39,15 +46,44 @@ impl TocBuilder {
         Self {
             storage,
             summarizer,
+            title_formatter: Arc::new(EnglishDateTitleFormatter),
+            rebuild_id: None,
         }
     }
 
+    /// Tag grips created by this builder as part of the given TOC rebuild.
+    pub fn with_rebuild_id(mut self, rebuild_id: impl Into<String>) -> Self {
+        self.rebuild_id = Some(rebuild_id.into());
+        self
+    }
+
+    /// Provenance recorded on grips extracted now.
+    fn grip_provenance(&self) -> GripProvenance {
+        let provenance = GripProvenance::new(
+            self.summarizer.summarizer_id(),
+            self.summarizer.summarizer_version(),
+        );
+        match self.rebuild_id {
+            Some(ref rebuild_id) => provenance.with_rebuild_id(rebuild_id.clone()),
+            None => provenance,
+        }
+    }
+
+    /// Apply settings from a TOC configuration.
+    pub fn with_config(mut self, config: &TocConfig) -> Self {
+        self.title_formatter = config.title_formatter();
+        self
+    }
+
     /// Process a segment and create/update TOC nodes.
     ///
     /// Creates:
     /// 1. Segment-level node from the segment
     /// 2. Ensures parent nodes exist up to Year level
     /// 3. Extracts grips from events based on bullets (SUMM-03)
+    ///
+    /// Segment node IDs are derived from the events, so processing the same
+    /// segment again overwrites its node and replaces its grips.
     pub async fn process_segment(&self, segment: &Segment) -> Result<TocNode, BuilderError> {
         if segment.events.is_empty() {
             return Err(BuilderError::InvalidSegment(
@@ -61,15 +97,25 @@ impl TocBuilder {
             "Processing segment"
         );
 
-        // Summarize the segment
-        let all_events: Vec<_> = segment.all_events().into_iter().cloned().collect();
-        let summary = self.summarizer.summarize_events(&all_events).await?;
+        // Summarize the segment, with the previous segment's tail as context
+        let summary = self
+            .summarizer
+            .summarize_events_with_context(&segment.context_prefix, &segment.events)
+            .await?;
 
         // Create segment node
         let mut segment_node = self.create_segment_node(segment, &summary)?;
 
         // Extract grips from events based on bullets (SUMM-03)
-        let extracted_grips = extract_grips(&all_events, &summary.bullets, &segment_node.node_id);
+        // Context events belong to the previous segment, so they are skipped
+        let extracted_grips = GripExtractor::new()
+            .with_provenance(self.grip_provenance())
+            .extract_grips(&segment.events, &summary.bullets, &segment_node.node_id);
+
+        // Drop grips from an earlier run over this segment
+        for stale in self.storage.get_grips_for_node(&segment_node.node_id)? {
+            self.storage.delete_grip(&stale.grip_id)?;
+        }
 
         // Store grips and link to segment node
         for extracted in &extracted_grips {
@@ -109,11 +155,7 @@ impl TocBuilder {
         segment: &Segment,
         summary: &Summary,
     ) -> Result<TocNode, BuilderError> {
-        let node_id = format!(
-            "toc:segment:{}:{}",
-            segment.start_time.format("%Y-%m-%d"),
-            segment.segment_id.trim_start_matches("seg:")
-        );
+        let node_id = generate_segment_node_id_from_events(segment.start_time, &segment.events);
 
         let bullets: Vec<TocBullet> = summary.bullets.iter().map(TocBullet::new).collect();
 
@@ -182,7 +224,9 @@ impl TocBuilder {
         child_id: &str,
     ) -> Result<TocNode, BuilderError> {
         let (start_time, end_time) = get_time_boundaries(level, child.start_time);
-        let title = generate_title(level, child.start_time);
+        let title = self
+            .title_formatter
+            .format_title(level, start_time, end_time);
 
         let mut node = TocNode::new(parent_id.to_string(), level, title, start_time, end_time);
         node.child_node_ids.push(child_id.to_string());
@@ -252,6 +296,32 @@ mod tests {
         assert!(!node.bullets.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_process_segment_excludes_context_prefix() {
+        let (storage, _temp) = create_test_storage();
+        let summarizer = Arc::new(MockSummarizer::new());
+        let builder = TocBuilder::new(storage.clone(), summarizer);
+
+        let context = vec![create_test_event("Earlier", 1706540300000)];
+        let events = vec![
+            create_test_event("Hello", 1706540400000),
+            create_test_event("World", 1706540500000),
+        ];
+        let segment = Segment::new(
+            "seg:test789".to_string(),
+            events.clone(),
+            events[0].timestamp,
+            events[1].timestamp,
+            100,
+        )
+        .with_context_prefix(context);
+
+        let node = builder.process_segment(&segment).await.unwrap();
+
+        // Only the segment's own events are summarized
+        assert_eq!(node.title, "Summary of 2 events");
+    }
+
     #[tokio::test]
     async fn test_process_segment_creates_parents() {
         let (storage, _temp) = create_test_storage();
@@ -278,6 +348,50 @@ mod tests {
         assert!(year_node.is_some());
     }
 
+    #[derive(Debug)]
+    struct IsoTitleFormatter;
+
+    impl TitleFormatter for IsoTitleFormatter {
+        fn format_title(
+            &self,
+            level: TocLevel,
+            start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> String {
+            match level {
+                TocLevel::Year => start.format("%Y").to_string(),
+                TocLevel::Month => start.format("%Y-%m").to_string(),
+                TocLevel::Week => start.format("%G-W%V").to_string(),
+                TocLevel::Day | TocLevel::Segment => start.format("%Y-%m-%d").to_string(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_segment_uses_title_formatter() {
+        let (storage, _temp) = create_test_storage();
+        let summarizer = Arc::new(MockSummarizer::new());
+        let config = TocConfig::default().with_title_formatter(Arc::new(IsoTitleFormatter));
+        let builder = TocBuilder::new(storage.clone(), summarizer).with_config(&config);
+
+        let events = vec![create_test_event("Test", 1705320000000)]; // 2024-01-15
+        let segment = Segment::new(
+            "seg:test-iso".to_string(),
+            events.clone(),
+            events[0].timestamp,
+            events[0].timestamp,
+            50,
+        );
+
+        builder.process_segment(&segment).await.unwrap();
+
+        let title = |id: &str| storage.get_toc_node(id).unwrap().unwrap().title;
+        assert_eq!(title("toc:day:2024-01-15"), "2024-01-15");
+        assert_eq!(title("toc:week:2024:W03"), "2024-W03");
+        assert_eq!(title("toc:month:2024:01"), "2024-01");
+        assert_eq!(title("toc:year:2024"), "2024");
+    }
+
     #[tokio::test]
     async fn test_process_segment_extracts_grips() {
         let (storage, _temp) = create_test_storage();
@@ -315,4 +429,94 @@ mod tests {
             assert!(grip.toc_node_id.as_ref() == Some(&node.node_id));
         }
     }
+
+    /// Summarizer returning fixed bullets, for controlling grip extraction.
+    struct FixedBulletsSummarizer(Vec<String>);
+
+    #[async_trait::async_trait]
+    impl Summarizer for FixedBulletsSummarizer {
+        async fn summarize_events(&self, _events: &[Event]) -> Result<Summary, SummarizerError> {
+            Ok(Summary::new(
+                "Fixed".to_string(),
+                self.0.clone(),
+                Vec::new(),
+            ))
+        }
+
+        async fn summarize_children(
+            &self,
+            summaries: &[Summary],
+        ) -> Result<Summary, SummarizerError> {
+            MockSummarizer::new().summarize_children(summaries).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_grips_stored_in_source_order() {
+        let events = vec![
+            create_test_event("Started with the database schema migration", 1706540400000),
+            create_test_event("Then wrote the API endpoint handlers", 1706540500000),
+            create_test_event("Finally deployed everything to staging", 1706540600000),
+        ];
+        // Bullets in reverse chronological order
+        let summarizer = Arc::new(FixedBulletsSummarizer(vec![
+            "Deployed everything to staging".to_string(),
+            "Wrote endpoint handlers".to_string(),
+            "Database schema migration".to_string(),
+        ]));
+        let segment = Segment::new(
+            "seg:order".to_string(),
+            events.clone(),
+            events[0].timestamp,
+            events[2].timestamp,
+            150,
+        );
+
+        for _ in 0..3 {
+            let (storage, _temp) = create_test_storage();
+            let builder = TocBuilder::new(storage.clone(), summarizer.clone());
+            let node = builder.process_segment(&segment).await.unwrap();
+
+            let grips = storage.get_grips_for_node(&node.node_id).unwrap();
+            let starts: Vec<&str> = grips.iter().map(|g| g.event_id_start.as_str()).collect();
+            let expected: Vec<&str> = events.iter().map(|e| e.event_id.as_str()).collect();
+            assert_eq!(starts, expected);
+
+            // Each bullet still points at the grip for its own event
+            for (bullet, grip) in node.bullets.iter().rev().zip(&grips) {
+                assert_eq!(bullet.grip_ids, vec![grip.grip_id.clone()]);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_grips_record_provenance() {
+        let (storage, _temp) = create_test_storage();
+        let events = vec![create_test_event(
+            "Started with the database schema migration",
+            1706540400000,
+        )];
+        let summarizer = Arc::new(FixedBulletsSummarizer(vec![
+            "Database schema migration".to_string()
+        ]));
+        let builder = TocBuilder::new(storage.clone(), summarizer).with_rebuild_id("rebuild-42");
+        let segment = Segment::new(
+            "seg:provenance".to_string(),
+            events.clone(),
+            events[0].timestamp,
+            events[0].timestamp,
+            50,
+        );
+
+        let node = builder.process_segment(&segment).await.unwrap();
+        let grips = storage.get_grips_for_node(&node.node_id).unwrap();
+        assert_eq!(grips.len(), 1);
+
+        let provenance = &grips[0].provenance;
+        assert!(!provenance.is_known());
+        assert_eq!(provenance.run_label(), "unknown rebuild=rebuild-42");
+        assert_eq!(provenance.summarizer_version, env!("CARGO_PKG_VERSION"));
+        assert!(provenance.created_at.is_some());
+        assert_eq!(provenance.rebuild_id.as_deref(), Some("rebuild-42"));
+    }
 }
@@ -1,7 +1,14 @@
 //! Configuration for TOC building.
 
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
+use memory_types::{EventRole, EventType};
+
+use crate::node_id::{EnglishDateTitleFormatter, TitleFormatter};
+use crate::tokens::{default_token_counter_with_tool_tokens, TokenCounter};
+
 /// Configuration for event segmentation.
 ///
 /// Per TOC-03: Segment on time threshold (30 min) or token threshold (4K).
@@ -26,6 +33,107 @@ pub struct SegmentationConfig {
 
     /// Maximum text length to count for tool results (to avoid explosion)
     pub max_tool_result_chars: usize,
+
+    /// Truncate tool results to this many tokens instead of
+    /// `max_tool_result_chars`
+    #[serde(default)]
+    pub max_tool_result_tokens: Option<usize>,
+
+    /// Hard cap on tokens in a single segment
+    /// Events larger than this are split into continuation chunks
+    #[serde(default = "default_max_segment_tokens")]
+    pub max_segment_tokens: usize,
+
+    /// Weights applied to event tokens when checking `token_threshold`
+    #[serde(default)]
+    pub salience_weights: SalienceWeights,
+
+    /// Token counter to use instead of the build default.
+    ///
+    /// An injected counter applies its own tool result limit.
+    #[serde(skip)]
+    pub token_counter: Option<Arc<dyn TokenCounter>>,
+}
+
+impl SegmentationConfig {
+    /// Use a specific token counter, e.g. one matching the summarizer's tokenizer.
+    pub fn with_token_counter(mut self, counter: Arc<dyn TokenCounter>) -> Self {
+        self.token_counter = Some(counter);
+        self
+    }
+
+    /// Get the configured token counter, or the build default.
+    pub fn token_counter(&self) -> Arc<dyn TokenCounter> {
+        self.token_counter.clone().unwrap_or_else(|| {
+            default_token_counter_with_tool_tokens(
+                self.max_tool_result_chars,
+                self.max_tool_result_tokens,
+            )
+        })
+    }
+
+    /// Use specific salience weights for token threshold checks.
+    pub fn with_salience_weights(mut self, weights: SalienceWeights) -> Self {
+        self.salience_weights = weights;
+        self
+    }
+
+    /// Weight of an event's tokens toward the segment token threshold.
+    ///
+    /// Tool results (or anything from the tool role) use `tool`, user and
+    /// assistant messages use `message`, and everything else uses `other`.
+    /// Negative weights are treated as 0.
+    pub fn salience_weight(&self, role: &EventRole, event_type: &EventType) -> f32 {
+        let weights = &self.salience_weights;
+        let weight = match (role, event_type) {
+            (EventRole::Tool, _) | (_, EventType::ToolResult) => weights.tool,
+            (_, EventType::UserMessage | EventType::AssistantMessage) => weights.message,
+            _ => weights.other,
+        };
+        weight.max(0.0)
+    }
+
+    /// Token count of an event after applying its salience weight.
+    pub fn weighted_tokens(
+        &self,
+        role: &EventRole,
+        event_type: &EventType,
+        tokens: usize,
+    ) -> usize {
+        let weight = self.salience_weight(role, event_type) as f64;
+        (tokens as f64 * weight).ceil() as usize
+    }
+}
+
+/// Per-kind multipliers for event token counts during segmentation.
+///
+/// Verbose, low-signal events such as tool output can be down-weighted so
+/// they fill segments more slowly than conversation. Weights only affect
+/// where token boundaries fall; the hard `max_segment_tokens` cap and the
+/// segment's reported token count always use raw tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SalienceWeights {
+    /// User and assistant messages
+    pub message: f32,
+    /// Tool results and tool role events
+    pub tool: f32,
+    /// Session, subagent and system events
+    pub other: f32,
+}
+
+impl Default for SalienceWeights {
+    fn default() -> Self {
+        Self {
+            message: 1.0,
+            tool: 1.0,
+            other: 1.0,
+        }
+    }
+}
+
+fn default_max_segment_tokens() -> usize {
+    8000
 }
 
 impl Default for SegmentationConfig {
@@ -36,6 +144,10 @@ impl Default for SegmentationConfig {
             overlap_time_ms: 5 * 60 * 1000, // 5 minutes
             overlap_tokens: 500,
             max_tool_result_chars: 1000,
+            max_tool_result_tokens: None,
+            max_segment_tokens: default_max_segment_tokens(),
+            salience_weights: SalienceWeights::default(),
+            token_counter: None,
         }
     }
 }
@@ -48,6 +160,25 @@ pub struct TocConfig {
 
     /// Minimum events to create a segment
     pub min_events_per_segment: usize,
+
+    /// Title formatter to use instead of English date titles.
+    #[serde(skip)]
+    pub title_formatter: Option<Arc<dyn TitleFormatter>>,
+}
+
+impl TocConfig {
+    /// Use a specific title formatter, e.g. a localized one.
+    pub fn with_title_formatter(mut self, formatter: Arc<dyn TitleFormatter>) -> Self {
+        self.title_formatter = Some(formatter);
+        self
+    }
+
+    /// Get the configured title formatter, or English date titles.
+    pub fn title_formatter(&self) -> Arc<dyn TitleFormatter> {
+        self.title_formatter
+            .clone()
+            .unwrap_or_else(|| Arc::new(EnglishDateTitleFormatter))
+    }
 }
 
 impl Default for TocConfig {
@@ -55,6 +186,7 @@ impl Default for TocConfig {
         Self {
             segmentation: SegmentationConfig::default(),
             min_events_per_segment: 2,
+            title_formatter: None,
         }
     }
 }
@@ -70,6 +202,33 @@ mod tests {
         assert_eq!(config.token_threshold, 4000);
         assert_eq!(config.overlap_time_ms, 5 * 60 * 1000);
         assert_eq!(config.overlap_tokens, 500);
+        assert_eq!(config.max_segment_tokens, 8000);
+        assert_eq!(config.salience_weights, SalienceWeights::default());
+    }
+
+    #[test]
+    fn test_salience_weight_by_kind() {
+        let config = SegmentationConfig::default().with_salience_weights(SalienceWeights {
+            message: 1.0,
+            tool: 0.25,
+            other: 0.5,
+        });
+        assert_eq!(
+            config.salience_weight(&EventRole::Tool, &EventType::ToolResult),
+            0.25
+        );
+        assert_eq!(
+            config.salience_weight(&EventRole::User, &EventType::UserMessage),
+            1.0
+        );
+        assert_eq!(
+            config.salience_weight(&EventRole::System, &EventType::SessionStart),
+            0.5
+        );
+        assert_eq!(
+            config.weighted_tokens(&EventRole::Tool, &EventType::ToolResult, 101),
+            26
+        );
     }
 
     #[test]
@@ -3,32 +3,54 @@
 //! Per GRIP-04: ExpandGrip returns context events around excerpt.
 
 use chrono::Duration;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tracing::debug;
 
 use memory_storage::Storage;
 use memory_types::{Event, Grip};
 
+use crate::segmenter::parent_event_id;
+use crate::tokens::default_token_counter;
+
+/// How far to expand context around a grip's excerpt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpandWindow {
+    /// Number of events to include (before, after) the excerpt range
+    Events(usize, usize),
+    /// Token budget for context events, filled nearest-first from both sides.
+    /// Excerpt events do not count against the budget.
+    Tokens(usize),
+    /// Wall-clock window in seconds on each side of the excerpt range
+    Duration(i64),
+}
+
+impl Default for ExpandWindow {
+    fn default() -> Self {
+        ExpandWindow::Events(3, 3)
+    }
+}
+
 /// Configuration for grip expansion.
 #[derive(Debug, Clone)]
 pub struct ExpandConfig {
-    /// Number of events to include before the excerpt range
-    pub events_before: usize,
-    /// Number of events to include after the excerpt range
-    pub events_after: usize,
-    /// Maximum time window before excerpt (in minutes)
+    /// Expansion window (event counts, token budget, or time)
+    pub window: ExpandWindow,
+    /// Maximum time window before excerpt (in minutes) for event/token windows
     pub max_time_before_mins: i64,
-    /// Maximum time window after excerpt (in minutes)
+    /// Maximum time window after excerpt (in minutes) for event/token windows
     pub max_time_after_mins: i64,
+    /// Maximum chars of a tool result counted toward the token budget
+    pub max_tool_result_chars: usize,
 }
 
 impl Default for ExpandConfig {
     fn default() -> Self {
         Self {
-            events_before: 3,
-            events_after: 3,
+            window: ExpandWindow::default(),
             max_time_before_mins: 30,
             max_time_after_mins: 30,
+            max_tool_result_chars: 1000,
         }
     }
 }
@@ -44,6 +66,9 @@ pub struct ExpandedGrip {
     pub excerpt_events: Vec<Event>,
     /// Events after the excerpt range
     pub events_after: Vec<Event>,
+    /// Effective window used, in the configured unit: event counts returned,
+    /// context tokens consumed, or the time window applied
+    pub window: ExpandWindow,
 }
 
 impl ExpandedGrip {
@@ -62,6 +87,38 @@ impl ExpandedGrip {
     }
 }
 
+/// A grip whose source events no longer resolve in storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingGrip {
+    /// The grip ID
+    pub grip_id: String,
+    /// Start/end event IDs that could not be found
+    pub missing_event_ids: Vec<String>,
+}
+
+/// Result of a grip provenance audit.
+#[derive(Debug, Clone, Default)]
+pub struct GripAuditReport {
+    /// Number of grips checked
+    pub total_grips: usize,
+    /// Grips with missing source events
+    pub dangling: Vec<DanglingGrip>,
+    /// Grip count per producing run, keyed by `GripProvenance::run_label`
+    pub by_provenance: BTreeMap<String, usize>,
+}
+
+impl GripAuditReport {
+    /// Number of grips whose source events all resolve.
+    pub fn valid_grips(&self) -> usize {
+        self.total_grips - self.dangling.len()
+    }
+
+    /// Whether every grip resolves.
+    pub fn is_clean(&self) -> bool {
+        self.dangling.is_empty()
+    }
+}
+
 /// Error type for grip expansion.
 #[derive(Debug, thiserror::Error)]
 pub enum ExpandError {
@@ -125,13 +182,23 @@ impl GripExpander {
             .ok_or_else(|| ExpandError::EventNotFound(grip.event_id_end.clone()))?;
 
         // Calculate time range for context
-        let context_start = start_ts - Duration::minutes(self.config.max_time_before_mins);
-        let context_end = end_ts + Duration::minutes(self.config.max_time_after_mins);
+        let (context_start, context_end) = match self.config.window {
+            ExpandWindow::Duration(secs) => (
+                start_ts - Duration::seconds(secs),
+                end_ts + Duration::seconds(secs),
+            ),
+            ExpandWindow::Events(..) | ExpandWindow::Tokens(_) => (
+                start_ts - Duration::minutes(self.config.max_time_before_mins),
+                end_ts + Duration::minutes(self.config.max_time_after_mins),
+            ),
+        };
 
-        // Get all events in the extended range
+        // Get all events in the extended range. Storage ranges are end
+        // exclusive, so add 1ms to keep events at `context_end` itself,
+        // including the grip's last event when the window is zero.
         let all_events = self.storage.get_events_in_range(
             context_start.timestamp_millis(),
-            context_end.timestamp_millis(),
+            context_end.timestamp_millis() + 1,
         )?;
 
         // Deserialize and partition events
@@ -153,19 +220,20 @@ impl GripExpander {
         }
 
         // Limit context events
-        let events_before: Vec<_> = events_before
-            .into_iter()
-            .rev()
-            .take(self.config.events_before)
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .collect();
-
-        let events_after: Vec<_> = events_after
-            .into_iter()
-            .take(self.config.events_after)
-            .collect();
+        let (events_before, events_after, window) = match self.config.window {
+            ExpandWindow::Events(before, after) => {
+                let events_before = take_nearest_before(events_before, before);
+                events_after.truncate(after);
+                let window = ExpandWindow::Events(events_before.len(), events_after.len());
+                (events_before, events_after, window)
+            }
+            ExpandWindow::Tokens(budget) => {
+                self.limit_by_tokens(events_before, events_after, budget)
+            }
+            ExpandWindow::Duration(secs) => {
+                (events_before, events_after, ExpandWindow::Duration(secs))
+            }
+        };
 
         debug!(
             grip_id = %grip.grip_id,
@@ -180,16 +248,121 @@ impl GripExpander {
             events_before,
             excerpt_events,
             events_after,
+            window,
         })
     }
+
+    /// Fill a token budget with context events, alternating nearest-first
+    /// between the before and after sides. A side stops at its first event
+    /// that no longer fits.
+    fn limit_by_tokens(
+        &self,
+        mut before: Vec<Event>,
+        after: Vec<Event>,
+        budget: usize,
+    ) -> (Vec<Event>, Vec<Event>, ExpandWindow) {
+        let counter = default_token_counter(self.config.max_tool_result_chars);
+        let mut before_iter = std::iter::from_fn(move || before.pop()).peekable();
+        let mut after_iter = after.into_iter().peekable();
+        let mut events_before = Vec::new();
+        let mut events_after = Vec::new();
+        let mut used = 0;
+        let (mut before_open, mut after_open) = (true, true);
+
+        while before_open || after_open {
+            if before_open {
+                match before_iter.peek().map(|e| counter.count_event(e)) {
+                    Some(tokens) if used + tokens <= budget => {
+                        used += tokens;
+                        events_before.extend(before_iter.next());
+                    }
+                    _ => before_open = false,
+                }
+            }
+            if after_open {
+                match after_iter.peek().map(|e| counter.count_event(e)) {
+                    Some(tokens) if used + tokens <= budget => {
+                        used += tokens;
+                        events_after.extend(after_iter.next());
+                    }
+                    _ => after_open = false,
+                }
+            }
+        }
+
+        events_before.reverse();
+        (events_before, events_after, ExpandWindow::Tokens(used))
+    }
+
+    /// Check that every grip's start/end source events still exist.
+    ///
+    /// Event IDs that are not valid ULIDs cannot be resolved and are
+    /// reported as missing.
+    pub fn audit(&self) -> Result<GripAuditReport, ExpandError> {
+        let grips = self.storage.get_all_grips()?;
+        let mut report = GripAuditReport {
+            total_grips: grips.len(),
+            ..Default::default()
+        };
+
+        for grip in &grips {
+            *report
+                .by_provenance
+                .entry(grip.provenance.run_label())
+                .or_default() += 1;
+
+            let mut missing_event_ids = Vec::new();
+            for event_id in [&grip.event_id_start, &grip.event_id_end] {
+                if missing_event_ids.contains(event_id) {
+                    continue;
+                }
+                if !self.event_exists(event_id)? {
+                    missing_event_ids.push(event_id.clone());
+                }
+            }
+
+            if !missing_event_ids.is_empty() {
+                debug!(grip_id = %grip.grip_id, missing = ?missing_event_ids, "Dangling grip");
+                report.dangling.push(DanglingGrip {
+                    grip_id: grip.grip_id.clone(),
+                    missing_event_ids,
+                });
+            }
+        }
+
+        debug!(
+            total = report.total_grips,
+            dangling = report.dangling.len(),
+            "Grip audit complete"
+        );
+
+        Ok(report)
+    }
+
+    fn event_exists(&self, event_id: &str) -> Result<bool, ExpandError> {
+        let event_id = parent_event_id(event_id);
+        if ulid::Ulid::from_string(event_id).is_err() {
+            return Ok(false);
+        }
+        Ok(self.storage.get_event(event_id)?.is_some())
+    }
+}
+
+/// Keep the `count` events closest to the excerpt, in chronological order.
+fn take_nearest_before(mut events: Vec<Event>, count: usize) -> Vec<Event> {
+    let skip = events.len().saturating_sub(count);
+    events.drain(..skip);
+    events
 }
 
-/// Parse timestamp from ULID event ID.
+/// Parse timestamp from ULID event ID, or the parent ID of a continuation chunk.
 fn parse_ulid_timestamp(event_id: &str) -> Option<chrono::DateTime<chrono::Utc>> {
-    ulid::Ulid::from_string(event_id).ok().and_then(|u| {
-        let ms = u.timestamp_ms();
-        chrono::DateTime::from_timestamp_millis(ms as i64)
-    })
+    ulid::Ulid::from_string(parent_event_id(event_id))
+        .ok()
+        .and_then(|u| {
+            let ms = u.timestamp_ms();
+            chrono::DateTime::from_timestamp_millis(ms as i64)
+        })
 }
 
 /// Convenience function to expand a grip.
@@ -200,7 +373,7 @@ pub fn expand_grip(storage: Arc<Storage>, grip_id: &str) -> Result<ExpandedGrip,
 #[cfg(test)]
 mod tests {
     use super::*;
-    use memory_types::{EventRole, EventType};
+    use memory_types::{EventRole, EventType, GripProvenance};
     use tempfile::TempDir;
 
     fn create_test_storage() -> (Arc<Storage>, TempDir) {
@@ -294,6 +467,258 @@ mod tests {
         assert!(!all.is_empty()); // At least the excerpt event
     }
 
+    #[test]
+    fn test_audit_flags_dangling_grip() {
+        let (storage, _temp) = create_test_storage();
+
+        let start = create_and_store_event(&storage, "Start", 1706540400000);
+        let end = create_and_store_event(&storage, "End", 1706540500000);
+        let valid = Grip::new(
+            "grip:1706540400000:valid".to_string(),
+            "Start".to_string(),
+            start.event_id.clone(),
+            end.event_id.clone(),
+            start.timestamp,
+            "test".to_string(),
+        );
+        storage.put_grip(&valid).unwrap();
+
+        // End event was never stored (e.g. expired)
+        let missing = ulid::Ulid::from_parts(1706540600000, rand::random()).to_string();
+        let dangling = Grip::new(
+            "grip:1706540400000:dangling".to_string(),
+            "Start".to_string(),
+            start.event_id.clone(),
+            missing.clone(),
+            start.timestamp,
+            "test".to_string(),
+        )
+        .with_toc_node("toc:day:2024-01-29".to_string());
+        storage.put_grip(&dangling).unwrap();
+
+        let report = GripExpander::new(storage).audit().unwrap();
+
+        assert_eq!(report.total_grips, 2);
+        assert_eq!(report.valid_grips(), 1);
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.dangling,
+            vec![DanglingGrip {
+                grip_id: dangling.grip_id,
+                missing_event_ids: vec![missing],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_audit_groups_by_provenance() {
+        let (storage, _temp) = create_test_storage();
+        let event = create_and_store_event(&storage, "Start", 1706540400000);
+        let grip = |id: &str| {
+            Grip::new(
+                format!("grip:1706540400000:{id}"),
+                "Start".to_string(),
+                event.event_id.clone(),
+                event.event_id.clone(),
+                event.timestamp,
+                "test".to_string(),
+            )
+        };
+
+        let live = GripProvenance::new("mock", "1.0");
+        let rebuilt = GripProvenance::new("mock", "1.0").with_rebuild_id("rb-1");
+        storage
+            .put_grip(&grip("a").with_provenance(live.clone()))
+            .unwrap();
+        storage.put_grip(&grip("b").with_provenance(live)).unwrap();
+        storage
+            .put_grip(&grip("c").with_provenance(rebuilt))
+            .unwrap();
+        storage.put_grip(&grip("d")).unwrap();
+
+        let report = GripExpander::new(storage).audit().unwrap();
+
+        let groups: Vec<(&str, usize)> = report
+            .by_provenance
+            .iter()
+            .map(|(label, count)| (label.as_str(), *count))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("mock@1.0", 2),
+                ("mock@1.0 rebuild=rb-1", 1),
+                ("unknown", 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_non_ulid_event_ids_are_missing() {
+        let (storage, _temp) = create_test_storage();
+
+        let grip = Grip::new(
+            "grip:test".to_string(),
+            "Excerpt".to_string(),
+            "event-001".to_string(),
+            "event-001".to_string(),
+            chrono::Utc::now(),
+            "test".to_string(),
+        );
+        storage.put_grip(&grip).unwrap();
+
+        let report = GripExpander::new(storage).audit().unwrap();
+
+        assert_eq!(report.dangling.len(), 1);
+        assert_eq!(report.dangling[0].missing_event_ids, vec!["event-001"]);
+    }
+
+    #[test]
+    fn test_audit_resolves_continuation_chunk_ids() {
+        let (storage, _temp) = create_test_storage();
+        let event = create_and_store_event(&storage, "Oversized message", 1706540400000);
+
+        let grip = Grip::new(
+            "grip:chunk".to_string(),
+            "Excerpt".to_string(),
+            format!("{}#1", event.event_id),
+            format!("{}#3", event.event_id),
+            chrono::Utc::now(),
+            "test".to_string(),
+        );
+        storage.put_grip(&grip).unwrap();
+
+        let report = GripExpander::new(storage).audit().unwrap();
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_audit_empty_storage_is_clean() {
+        let (storage, _temp) = create_test_storage();
+
+        let report = GripExpander::new(storage).audit().unwrap();
+
+        assert_eq!(report.total_grips, 0);
+        assert!(report.is_clean());
+    }
+
+    /// Store events at -300s, -200s, -100s, excerpt, +100s, +200s, +300s
+    /// and a grip over the excerpt event.
+    fn create_window_fixture(storage: &Storage) -> Grip {
+        let base = 1706540400000;
+        for (i, offset) in [-300, -200, -100].into_iter().enumerate() {
+            create_and_store_event(storage, &format!("before {}", i), base + offset * 1000);
+        }
+        let excerpt = create_and_store_event(storage, "the excerpt", base);
+        for (i, offset) in [100, 200, 300].into_iter().enumerate() {
+            create_and_store_event(storage, &format!("after {}", i), base + offset * 1000);
+        }
+
+        let grip = Grip::new(
+            "grip:1706540400000:window".to_string(),
+            "the excerpt".to_string(),
+            excerpt.event_id.clone(),
+            excerpt.event_id.clone(),
+            excerpt.timestamp,
+            "test".to_string(),
+        );
+        storage.put_grip(&grip).unwrap();
+        grip
+    }
+
+    fn texts(events: &[Event]) -> Vec<&str> {
+        events.iter().map(|e| e.text.as_str()).collect()
+    }
+
+    fn expand_with(storage: Arc<Storage>, grip_id: &str, window: ExpandWindow) -> ExpandedGrip {
+        let config = ExpandConfig {
+            window,
+            ..Default::default()
+        };
+        GripExpander::with_config(storage, config)
+            .expand(grip_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_expand_window_events() {
+        let (storage, _temp) = create_test_storage();
+        let grip = create_window_fixture(&storage);
+
+        let expanded = expand_with(storage, &grip.grip_id, ExpandWindow::Events(2, 5));
+
+        assert_eq!(texts(&expanded.events_before), vec!["before 1", "before 2"]);
+        assert_eq!(texts(&expanded.excerpt_events), vec!["the excerpt"]);
+        assert_eq!(
+            texts(&expanded.events_after),
+            vec!["after 0", "after 1", "after 2"]
+        );
+        // Only three events exist after the excerpt
+        assert_eq!(expanded.window, ExpandWindow::Events(2, 3));
+    }
+
+    #[test]
+    fn test_expand_window_tokens() {
+        let (storage, _temp) = create_test_storage();
+        let grip = create_window_fixture(&storage);
+
+        // Measure the nearest context event on each side
+        let probe = expand_with(storage.clone(), &grip.grip_id, ExpandWindow::Events(1, 1));
+        let counter = default_token_counter(ExpandConfig::default().max_tool_result_chars);
+        let nearest = counter.count_event(&probe.events_before[0])
+            + counter.count_event(&probe.events_after[0]);
+
+        let expanded = expand_with(
+            storage.clone(),
+            &grip.grip_id,
+            ExpandWindow::Tokens(nearest),
+        );
+        assert_eq!(texts(&expanded.events_before), vec!["before 2"]);
+        assert_eq!(texts(&expanded.events_after), vec!["after 0"]);
+        assert_eq!(expanded.window, ExpandWindow::Tokens(nearest));
+
+        // A budget too small for any context event still returns the excerpt
+        let expanded = expand_with(storage, &grip.grip_id, ExpandWindow::Tokens(0));
+        assert!(expanded.events_before.is_empty());
+        assert!(expanded.events_after.is_empty());
+        assert_eq!(expanded.excerpt_events.len(), 1);
+        assert_eq!(expanded.window, ExpandWindow::Tokens(0));
+    }
+
+    #[test]
+    fn test_expand_window_duration() {
+        let (storage, _temp) = create_test_storage();
+        let grip = create_window_fixture(&storage);
+
+        let expanded = expand_with(storage.clone(), &grip.grip_id, ExpandWindow::Duration(200));
+        assert_eq!(texts(&expanded.events_before), vec!["before 1", "before 2"]);
+        assert_eq!(texts(&expanded.events_after), vec!["after 0", "after 1"]);
+        assert_eq!(expanded.window, ExpandWindow::Duration(200));
+
+        // Time windows are not capped by the event/token fetch limits
+        create_and_store_event(&storage, "long before", 1706540400000 - 45 * 60 * 1000);
+        let expanded = expand_with(storage, &grip.grip_id, ExpandWindow::Duration(3600));
+        assert_eq!(expanded.events_before.len(), 4);
+        assert_eq!(expanded.events_before[0].text, "long before");
+    }
+
+    #[test]
+    fn test_expand_window_end_is_inclusive() {
+        let (storage, _temp) = create_test_storage();
+        let grip = create_window_fixture(&storage);
+
+        // A zero window still returns the excerpt, which ends at context_end
+        let expanded = expand_with(storage.clone(), &grip.grip_id, ExpandWindow::Duration(0));
+        assert_eq!(texts(&expanded.excerpt_events), vec!["the excerpt"]);
+        assert!(expanded.events_before.is_empty());
+        assert!(expanded.events_after.is_empty());
+
+        // Events exactly on either edge of the window are included
+        let expanded = expand_with(storage, &grip.grip_id, ExpandWindow::Duration(100));
+        assert_eq!(texts(&expanded.events_before), vec!["before 2"]);
+        assert_eq!(texts(&expanded.events_after), vec!["after 0"]);
+    }
+
     #[test]
     fn test_expand_config_limits() {
         let (storage, _temp) = create_test_storage();
@@ -320,8 +745,7 @@ mod tests {
         storage.put_grip(&grip).unwrap();
 
         let config = ExpandConfig {
-            events_before: 2,
-            events_after: 2,
+            window: ExpandWindow::Events(2, 2),
             ..Default::default()
         };
 
@@ -2,6 +2,7 @@
 //!
 //! Provides:
 //! - Event segmentation (TOC-03, TOC-04)
+//! - Pluggable token counting (heuristic or tiktoken)
 //! - Summarization trait (SUMM-01, SUMM-02, SUMM-04)
 //! - TOC hierarchy building (TOC-01, TOC-02, TOC-05)
 //! - Node ID generation
@@ -18,15 +19,33 @@ pub mod rollup;
 pub mod search;
 pub mod segmenter;
 pub mod summarizer;
+pub mod tokens;
 
 pub use builder::{BuilderError, TocBuilder};
-pub use config::{SegmentationConfig, TocConfig};
-pub use expand::{expand_grip, ExpandConfig, ExpandError, ExpandedGrip, GripExpander};
+pub use config::{SalienceWeights, SegmentationConfig, TocConfig};
+pub use expand::{
+    expand_grip, DanglingGrip, ExpandConfig, ExpandError, ExpandWindow, ExpandedGrip,
+    GripAuditReport, GripExpander,
+};
 pub use grip_id::{generate_grip_id, is_valid_grip_id, parse_grip_timestamp};
-pub use node_id::{generate_node_id, generate_title, get_parent_node_id, parse_level};
-pub use rollup::{run_all_rollups, RollupCheckpoint, RollupError, RollupJob};
+pub use node_id::{
+    generate_node_id, generate_rolling_node_id, generate_segment_node_id_from_events,
+    generate_title, get_parent_node_id, is_rolling_node_id, parse_level, EnglishDateTitleFormatter,
+    TitleFormatter,
+};
+pub use rollup::{
+    run_all_rollups, run_all_rollups_grouped, RollupCheckpoint, RollupError, RollupGrouping,
+    RollupJob,
+};
 pub use search::{search_node, term_overlap_score, SearchField, SearchMatch};
-pub use segmenter::{segment_events, SegmentBuilder, TokenCounter};
+pub use segmenter::{parent_event_id, segment_events, SegmentBuilder, StreamingSegmenter};
 pub use summarizer::{
-    ApiSummarizer, ApiSummarizerConfig, MockSummarizer, Summarizer, SummarizerError, Summary,
+    ApiSummarizer, ApiSummarizerConfig, GenericSummarizer, MockSummarizer, Summarizer,
+    SummarizerError, Summary,
+};
+#[cfg(feature = "tiktoken")]
+pub use tokens::TiktokenCounter;
+pub use tokens::{
+    default_token_counter, default_token_counter_with_tool_tokens, HeuristicTokenCounter,
+    TokenCounter,
 };
@@ -3,8 +3,12 @@
 //! Node IDs encode the level and time period for hierarchical organization.
 //! Format: "toc:{level}:{time_identifier}"
 
+use std::fmt;
+
 use chrono::{DateTime, Datelike, Utc, Weekday};
-use memory_types::TocLevel;
+use memory_types::{Event, TocLevel};
+
+pub use memory_types::is_rolling_node_id;
 
 /// Generate a node ID for the given level and time.
 ///
@@ -36,6 +40,60 @@ pub fn generate_segment_node_id(time: DateTime<Utc>, segment_ulid: &str) -> Stri
     format!("toc:segment:{}:{}", time.format("%Y-%m-%d"), segment_ulid)
 }
 
+/// Generate a deterministic node ID for a segment from its events.
+///
+/// Format: "toc:segment:{date}:{first_event_id}-{fingerprint}". The
+/// fingerprint covers every event ID and text, so segments that start on
+/// the same event (e.g. chunks of one split event) still get distinct IDs,
+/// while re-processing the same events yields the same ID.
+pub fn generate_segment_node_id_from_events(time: DateTime<Utc>, events: &[Event]) -> String {
+    let first_event_id = events.first().map_or("", |e| e.event_id.as_str());
+    format!(
+        "toc:segment:{}:{}-{}",
+        time.format("%Y-%m-%d"),
+        first_event_id,
+        segment_fingerprint(events)
+    )
+}
+
+/// Stable 64-bit FNV-1a hash of the segment's event IDs and texts.
+///
+/// Unlike `DefaultHasher`, the output does not change between Rust releases,
+/// which matters because node IDs are persisted.
+fn segment_fingerprint(events: &[Event]) -> String {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    for event in events {
+        let bytes = event
+            .event_id
+            .bytes()
+            .chain([0])
+            .chain(event.text.bytes())
+            .chain([0]);
+        for byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Generate a node ID for a rolling rollup window.
+///
+/// Format: "toc:{level}:rolling:{window_ms}:{start_ms}". Encoding both the
+/// window length and its start keeps IDs stable across reruns and distinct
+/// from calendar nodes at the same level.
+pub fn generate_rolling_node_id(level: TocLevel, window_ms: u64, start: DateTime<Utc>) -> String {
+    format!(
+        "toc:{}:rolling:{}:{}",
+        level,
+        window_ms,
+        start.timestamp_millis()
+    )
+}
+
 /// Get the parent node ID for a given node ID.
 ///
 /// Returns None for year-level nodes (no parent).
@@ -129,6 +187,24 @@ pub fn generate_title(level: TocLevel, time: DateTime<Utc>) -> String {
     }
 }
 
+/// Formats titles for TOC nodes.
+///
+/// Inject via `TocConfig::title_formatter` for localized or custom titles.
+pub trait TitleFormatter: fmt::Debug + Send + Sync {
+    /// Format the title for a node at `level` covering `start..=end`.
+    fn format_title(&self, level: TocLevel, start: DateTime<Utc>, end: DateTime<Utc>) -> String;
+}
+
+/// Default formatter producing English titles like "Monday, January 15, 2024".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishDateTitleFormatter;
+
+impl TitleFormatter for EnglishDateTitleFormatter {
+    fn format_title(&self, level: TocLevel, start: DateTime<Utc>, _end: DateTime<Utc>) -> String {
+        generate_title(level, start)
+    }
+}
+
 /// Get the time boundaries for a level at a given time.
 pub fn get_time_boundaries(level: TocLevel, time: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
     use chrono::{Duration, NaiveTime, TimeZone};
@@ -218,6 +294,80 @@ mod tests {
         assert!(id.starts_with("toc:segment:2024-01-15:"));
     }
 
+    fn create_event_at(text: &str, timestamp_ms: i64) -> Event {
+        let ulid = ulid::Ulid::from_parts(timestamp_ms as u64, 0);
+        Event::new(
+            ulid.to_string(),
+            "session-123".to_string(),
+            Utc.timestamp_millis_opt(timestamp_ms).unwrap(),
+            memory_types::EventType::UserMessage,
+            memory_types::EventRole::User,
+            text.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_segment_node_id_from_events_is_deterministic() {
+        let events = vec![
+            create_event_at("Hello", 1000),
+            create_event_at("World", 2000),
+        ];
+        let time = events[0].timestamp;
+
+        let id = generate_segment_node_id_from_events(time, &events);
+        assert_eq!(id, generate_segment_node_id_from_events(time, &events));
+        assert!(id.starts_with(&format!("toc:segment:1970-01-01:{}-", events[0].event_id)));
+
+        // Same first event, different range
+        let shorter = generate_segment_node_id_from_events(time, &events[..1]);
+        assert_ne!(id, shorter);
+    }
+
+    #[test]
+    fn test_segment_node_ids_unique_within_day() {
+        let day_start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let base_ms = day_start.timestamp_millis();
+        let mut ids = std::collections::HashSet::new();
+
+        // Many sub-second segments, several starting on the same event
+        for i in 0..1000i64 {
+            let start_ms = base_ms + i * 50;
+            let first = create_event_at("shared start", start_ms);
+            for len in 1..=3i64 {
+                let mut events = vec![first.clone()];
+                events.extend((1..len).map(|j| create_event_at("next", start_ms + j)));
+
+                let id = generate_segment_node_id_from_events(first.timestamp, &events);
+                assert!(ids.insert(id.clone()), "duplicate node ID {}", id);
+                assert_eq!(parse_level(&id), Some(TocLevel::Segment));
+                assert_eq!(
+                    get_parent_node_id(&id),
+                    Some("toc:day:2024-01-15".to_string())
+                );
+            }
+        }
+
+        // Chunks of one split event share an ID but not their text
+        let part1 = create_event_at("[part 1/2] aaa", base_ms);
+        let mut part2 = part1.clone();
+        part2.text = "[part 2/2] bbb".to_string();
+        assert_ne!(
+            generate_segment_node_id_from_events(day_start, &[part1]),
+            generate_segment_node_id_from_events(day_start, &[part2])
+        );
+        assert_eq!(ids.len(), 3000);
+    }
+
+    #[test]
+    fn test_rolling_node_id() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 23, 0, 0).unwrap();
+        let id = generate_rolling_node_id(TocLevel::Day, 86_400_000, start);
+        assert_eq!(id, "toc:day:rolling:86400000:1705359600000");
+        assert!(is_rolling_node_id(&id));
+        assert_eq!(parse_level(&id), Some(TocLevel::Day));
+        assert!(!is_rolling_node_id("toc:day:2024-01-15"));
+    }
+
     #[test]
     fn test_get_parent_node_id() {
         assert_eq!(
@@ -246,6 +396,28 @@ mod tests {
         assert_eq!(generate_title(TocLevel::Month, time), "January 2024");
     }
 
+    #[test]
+    fn test_english_title_formatter_matches_generate_title() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let formatter = EnglishDateTitleFormatter;
+        for level in [
+            TocLevel::Year,
+            TocLevel::Month,
+            TocLevel::Week,
+            TocLevel::Day,
+        ] {
+            let (start, end) = get_time_boundaries(level, time);
+            assert_eq!(
+                formatter.format_title(level, start, end),
+                generate_title(level, time)
+            );
+        }
+        assert_eq!(
+            formatter.format_title(TocLevel::Day, time, time),
+            "Monday, January 15, 2024"
+        );
+    }
+
     #[test]
     fn test_get_time_boundaries_day() {
         let time = Utc.with_ymd_and_hms(2024, 1, 15, 12, 30, 0).unwrap();
@@ -11,7 +11,41 @@ use tracing::{debug, info};
 use memory_storage::Storage;
 use memory_types::{TocBullet, TocLevel, TocNode};
 
-use crate::summarizer::{Summarizer, SummarizerError, Summary};
+use crate::node_id::{generate_rolling_node_id, is_rolling_node_id};
+use crate::summarizer::{GenericSummarizer, Summarizer, SummarizerError, Summary};
+
+/// How child nodes are grouped into parent nodes during rollup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollupGrouping {
+    /// Calendar day/week/month buckets (the nodes built by `TocBuilder`)
+    #[default]
+    Calendar,
+    /// Windows of fixed length starting at the first activity after the
+    /// previous window.
+    ///
+    /// `window_ms` is the day-level window; week, month and year windows
+    /// are 7, 30 and 365 times longer.
+    Rolling { window_ms: u64 },
+}
+
+impl RollupGrouping {
+    /// Window length for `level`, or None for calendar grouping and segments.
+    pub fn window_for(&self, level: TocLevel) -> Option<Duration> {
+        let RollupGrouping::Rolling { window_ms } = *self else {
+            return None;
+        };
+        let multiplier = match level {
+            TocLevel::Day => 1,
+            TocLevel::Week => 7,
+            TocLevel::Month => 30,
+            TocLevel::Year => 365,
+            TocLevel::Segment => return None,
+        };
+        let ms = i64::try_from(window_ms.max(1).saturating_mul(multiplier)).unwrap_or(i64::MAX);
+        Some(Duration::try_milliseconds(ms).unwrap_or(Duration::MAX))
+    }
+}
 
 /// Checkpoint for rollup job crash recovery.
 ///
@@ -28,7 +62,8 @@ pub struct RollupCheckpoint {
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub last_processed_time: DateTime<Utc>,
 
-    /// Number of nodes processed in current run
+    /// Number of parent nodes built since the checkpoint was first created,
+    /// including those from earlier, interrupted runs
     pub processed_count: usize,
 
     /// When this checkpoint was created
@@ -73,22 +108,29 @@ pub enum RollupError {
 }
 
 /// Rollup job for aggregating child nodes into parent summaries.
-pub struct RollupJob {
+///
+/// Generic over the summarizer so a concrete type is called without the
+/// boxed futures of the [`Summarizer`] trait object. Defaults to
+/// `dyn Summarizer` for pluggable summarizers.
+pub struct RollupJob<S: ?Sized = dyn Summarizer> {
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
     level: TocLevel,
     /// Minimum age of period before rollup (avoids rolling up incomplete periods)
     min_age: Duration,
+    grouping: RollupGrouping,
+    /// Stop after building this many parent nodes in one run
+    max_nodes: Option<usize>,
 }
 
-impl RollupJob {
+impl<S: GenericSummarizer + ?Sized> RollupJob<S> {
     /// Create a new rollup job for the specified level.
     ///
     /// min_age: Minimum age of a period before it can be rolled up.
     /// This prevents rolling up periods that are still receiving events.
     pub fn new(
         storage: Arc<Storage>,
-        summarizer: Arc<dyn Summarizer>,
+        summarizer: Arc<S>,
         level: TocLevel,
         min_age: Duration,
     ) -> Self {
@@ -97,11 +139,29 @@ impl RollupJob {
             summarizer,
             level,
             min_age,
+            grouping: RollupGrouping::default(),
+            max_nodes: None,
         }
     }
 
+    /// Set how child nodes are grouped into parents.
+    pub fn with_grouping(mut self, grouping: RollupGrouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Limit how many parent nodes one run builds.
+    ///
+    /// The checkpoint is saved after every parent, so the next run picks up
+    /// where this one stopped. Useful for spreading a large backfill over
+    /// several scheduled runs.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
     /// Create rollup jobs for all levels.
-    pub fn create_all(storage: Arc<Storage>, summarizer: Arc<dyn Summarizer>) -> Vec<Self> {
+    pub fn create_all(storage: Arc<Storage>, summarizer: Arc<S>) -> Vec<Self> {
         vec![
             Self::new(
                 storage.clone(),
@@ -132,16 +192,32 @@ impl RollupJob {
 
     /// Run the rollup job.
     ///
-    /// Processes nodes that need rollup since the last checkpoint.
+    /// Processes nodes that need rollup since the last checkpoint. The
+    /// checkpoint is saved after each completed parent, so a run that is
+    /// interrupted resumes after the last parent it finished. Returns the
+    /// number of parents built in this run.
+    ///
+    /// The calendar hierarchy is always rolled up. With rolling grouping,
+    /// rolling windows are built as well, alongside it; `max_nodes` applies
+    /// to each separately.
     pub async fn run(&self) -> Result<usize, RollupError> {
+        let mut processed = self.run_calendar().await?;
+        if let Some(window) = self.grouping.window_for(self.level) {
+            processed += self.run_rolling(window).await?;
+        }
+        Ok(processed)
+    }
+
+    /// Roll up the calendar node at this level for each closed period.
+    async fn run_calendar(&self) -> Result<usize, RollupError> {
         let job_name = format!("rollup_{}", self.level);
         info!(job = %job_name, level = %self.level, "Starting rollup job");
 
         // Load checkpoint
-        let checkpoint = self.load_checkpoint(&job_name)?;
-        let start_time = checkpoint
-            .map(|c| c.last_processed_time)
-            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let mut checkpoint = self
+            .load_checkpoint(&job_name)?
+            .unwrap_or_else(|| RollupCheckpoint::new(job_name.clone(), self.level));
+        let start_time = checkpoint.last_processed_time;
 
         // Get nodes at this level that need rollup
         let cutoff_time = Utc::now() - self.min_age;
@@ -152,6 +228,21 @@ impl RollupJob {
         let mut processed = 0;
 
         for node in nodes {
+            if self.max_nodes.is_some_and(|max| processed >= max) {
+                debug!(job = %job_name, processed, "Stopping at node limit");
+                break;
+            }
+
+            // Rolling windows are maintained by rolling-mode jobs
+            if is_rolling_node_id(&node.node_id) {
+                continue;
+            }
+
+            // Already built by an earlier run
+            if node.end_time <= start_time {
+                continue;
+            }
+
             // Skip if period is too recent
             if node.end_time > cutoff_time {
                 debug!(
@@ -168,38 +259,13 @@ impl RollupJob {
                 continue;
             }
 
-            // Convert children to summaries
-            let summaries: Vec<Summary> = children
-                .iter()
-                .map(|c| {
-                    Summary::new(
-                        c.title.clone(),
-                        c.bullets.iter().map(|b| b.text.clone()).collect(),
-                        c.keywords.clone(),
-                    )
-                })
-                .collect();
-
-            // Generate rollup summary
-            let rollup_summary = self.summarizer.summarize_children(&summaries).await?;
-
-            // Update node with rollup summary
             let mut updated_node = node.clone();
-            updated_node.title = rollup_summary.title;
-            updated_node.bullets = rollup_summary
-                .bullets
-                .into_iter()
-                .map(TocBullet::new)
-                .collect();
-            updated_node.keywords = rollup_summary.keywords;
-
-            // Ensure child IDs are up to date
-            updated_node.child_node_ids = children.iter().map(|c| c.node_id.clone()).collect();
-
-            self.storage.put_toc_node(&updated_node)?;
+            self.apply_rollup_summary(&mut updated_node, &children)
+                .await?;
+            self.store_if_changed(&updated_node, Some(&node))?;
 
             // Save checkpoint after each node
-            self.save_checkpoint(&job_name, &updated_node)?;
+            self.save_checkpoint(&mut checkpoint, &updated_node)?;
 
             processed += 1;
             debug!(
@@ -218,6 +284,137 @@ impl RollupJob {
         Ok(processed)
     }
 
+    /// Run the rollup with rolling windows of length `window`.
+    ///
+    /// Child nodes are walked in time order. Each window starts at the first
+    /// child not covered by the previous one, so windows follow activity
+    /// rather than calendar boundaries. Window node IDs encode the window
+    /// length and start, so rerunning over the same children rewrites the
+    /// same nodes.
+    async fn run_rolling(&self, window: Duration) -> Result<usize, RollupError> {
+        let Some(child_level) = self.level.child() else {
+            return Err(RollupError::NoChildLevel(self.level));
+        };
+        let window_ms = window.num_milliseconds() as u64;
+        let job_name = format!("rollup_{}_rolling_{}", self.level, window_ms);
+        info!(job = %job_name, level = %self.level, window_ms, "Starting rolling rollup job");
+
+        let mut checkpoint = self
+            .load_checkpoint(&job_name)?
+            .unwrap_or_else(|| RollupCheckpoint::new(job_name.clone(), self.level));
+        let start_time = checkpoint.last_processed_time;
+        let cutoff_time = Utc::now() - self.min_age;
+
+        // Above day level, roll up the matching rolling windows of the level below
+        let child_prefix = self
+            .grouping
+            .window_for(child_level)
+            .map(|w| format!("toc:{}:rolling:{}:", child_level, w.num_milliseconds()));
+        let children: Vec<TocNode> = self
+            .storage
+            .get_toc_nodes_by_level(child_level, Some(start_time), Some(cutoff_time))?
+            .into_iter()
+            .filter(|c| c.start_time > start_time)
+            .filter(|c| {
+                child_prefix
+                    .as_ref()
+                    .is_none_or(|prefix| c.node_id.starts_with(prefix))
+            })
+            .collect();
+
+        let mut processed = 0;
+        let mut remaining = children.as_slice();
+
+        while let Some(first) = remaining.first() {
+            if self.max_nodes.is_some_and(|max| processed >= max) {
+                debug!(job = %job_name, processed, "Stopping at node limit");
+                break;
+            }
+
+            let window_start = first.start_time;
+            let window_end = window_start
+                .checked_add_signed(window - Duration::milliseconds(1))
+                .unwrap_or(DateTime::<Utc>::MAX_UTC);
+
+            // Only roll up windows that have closed
+            if window_end > cutoff_time {
+                debug!(start = %window_start, "Skipping window - not yet closed");
+                break;
+            }
+
+            let len = remaining
+                .iter()
+                .take_while(|c| c.start_time <= window_end)
+                .count();
+            let (members, rest) = remaining.split_at(len);
+            remaining = rest;
+
+            let node_id = generate_rolling_node_id(self.level, window_ms, window_start);
+            let title = format!(
+                "{} - {}",
+                window_start.format("%B %d, %Y %H:%M"),
+                window_end.format("%B %d, %Y %H:%M")
+            );
+            let existing = self.storage.get_toc_node(&node_id)?;
+            let mut node = TocNode::new(node_id, self.level, title, window_start, window_end);
+            self.apply_rollup_summary(&mut node, members).await?;
+            self.store_if_changed(&node, existing.as_ref())?;
+            self.save_checkpoint(&mut checkpoint, &node)?;
+
+            processed += 1;
+            debug!(
+                node_id = %node.node_id,
+                children = members.len(),
+                "Rolled up window"
+            );
+        }
+
+        info!(
+            job = %job_name,
+            processed = processed,
+            "Rolling rollup job complete"
+        );
+
+        Ok(processed)
+    }
+
+    /// Summarize `children` into `node` and record them as its children.
+    async fn apply_rollup_summary(
+        &self,
+        node: &mut TocNode,
+        children: &[TocNode],
+    ) -> Result<(), RollupError> {
+        // Convert children to summaries
+        let summaries: Vec<Summary> = children
+            .iter()
+            .map(|c| {
+                Summary::new(
+                    c.title.clone(),
+                    c.bullets.iter().map(|b| b.text.clone()).collect(),
+                    c.keywords.clone(),
+                )
+            })
+            .collect();
+
+        // Generate rollup summary
+        let rollup_summary = self
+            .summarizer
+            .summarize_children_generic(&summaries)
+            .await?;
+
+        node.title = rollup_summary.title;
+        node.bullets = rollup_summary
+            .bullets
+            .into_iter()
+            .map(TocBullet::new)
+            .collect();
+        node.keywords = rollup_summary.keywords;
+
+        // Ensure child IDs are up to date
+        node.child_node_ids = children.iter().map(|c| c.node_id.clone()).collect();
+        Ok(())
+    }
+
     /// Load checkpoint from storage.
     fn load_checkpoint(&self, job_name: &str) -> Result<Option<RollupCheckpoint>, RollupError> {
         match self.storage.get_checkpoint(job_name)? {
@@ -230,35 +427,73 @@ impl RollupJob {
         }
     }
 
-    /// Save checkpoint to storage.
-    fn save_checkpoint(&self, job_name: &str, node: &TocNode) -> Result<(), RollupError> {
-        let checkpoint = RollupCheckpoint {
-            job_name: job_name.to_string(),
-            level: self.level,
-            last_processed_time: node.end_time,
-            processed_count: 1,
-            created_at: Utc::now(),
-        };
+    /// Advance the checkpoint past `node` and save it to storage.
+    fn save_checkpoint(
+        &self,
+        checkpoint: &mut RollupCheckpoint,
+        node: &TocNode,
+    ) -> Result<(), RollupError> {
+        checkpoint.last_processed_time = node.end_time;
+        checkpoint.processed_count += 1;
+        checkpoint.created_at = Utc::now();
 
         let bytes = checkpoint
             .to_bytes()
             .map_err(|e| RollupError::Checkpoint(e.to_string()))?;
 
-        self.storage.put_checkpoint(job_name, &bytes)?;
+        self.storage.put_checkpoint(&checkpoint.job_name, &bytes)?;
+        Ok(())
+    }
+
+    /// Store `node` unless `existing` already holds the same rollup.
+    ///
+    /// Skipping identical writes keeps reruns from piling up node versions.
+    fn store_if_changed(
+        &self,
+        node: &TocNode,
+        existing: Option<&TocNode>,
+    ) -> Result<(), RollupError> {
+        let unchanged = existing.is_some_and(|e| {
+            e.title == node.title
+                && e.keywords == node.keywords
+                && e.child_node_ids == node.child_node_ids
+                && e.bullets.len() == node.bullets.len()
+                && e.bullets
+                    .iter()
+                    .zip(&node.bullets)
+                    .all(|(a, b)| a.text == b.text)
+        });
+        if unchanged {
+            debug!(node_id = %node.node_id, "Rollup unchanged - not rewriting");
+            return Ok(());
+        }
+        self.storage.put_toc_node(node)?;
         Ok(())
     }
 }
 
 /// Run all rollup jobs in sequence.
-pub async fn run_all_rollups(
+///
+/// Pass the concrete summarizer type when it is known to skip the boxed
+/// futures of the trait object; `Arc<dyn Summarizer>` works as well.
+pub async fn run_all_rollups<S: GenericSummarizer + ?Sized>(
+    storage: Arc<Storage>,
+    summarizer: Arc<S>,
+) -> Result<usize, RollupError> {
+    run_all_rollups_grouped(storage, summarizer, RollupGrouping::Calendar).await
+}
+
+/// Run all rollup jobs in sequence with the given grouping.
+pub async fn run_all_rollups_grouped<S: GenericSummarizer + ?Sized>(
     storage: Arc<Storage>,
-    summarizer: Arc<dyn Summarizer>,
+    summarizer: Arc<S>,
+    grouping: RollupGrouping,
 ) -> Result<usize, RollupError> {
     let jobs = RollupJob::create_all(storage, summarizer);
     let mut total = 0;
 
     for job in jobs {
-        total += job.run().await?;
+        total += job.with_grouping(grouping).run().await?;
     }
 
     Ok(total)
@@ -279,7 +514,6 @@ mod tests {
         (storage, temp_dir)
     }
 
-    #[allow(dead_code)]
     fn create_test_event(text: &str, timestamp_ms: i64) -> Event {
         let ulid = ulid::Ulid::from_parts(timestamp_ms as u64, rand::random());
         Event::new(
@@ -360,4 +594,156 @@ mod tests {
         // result is a count of nodes processed
         let _ = result;
     }
+
+    /// Build one segment at each of `times` through `TocBuilder`.
+    async fn build_segments(storage: &Arc<Storage>, times: &[DateTime<Utc>]) {
+        let builder = TocBuilder::new(storage.clone(), Arc::new(MockSummarizer::new()));
+        for (i, time) in times.iter().enumerate() {
+            let event = create_test_event(&format!("Event {i}"), time.timestamp_millis());
+            let segment = Segment::new(format!("seg:{i}"), vec![event], *time, *time, 50);
+            builder.process_segment(&segment).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_calendar_splits_day_boundary_rolling_does_not() {
+        let (storage, _temp) = create_test_storage();
+        let late = Utc.with_ymd_and_hms(2024, 1, 15, 23, 0, 0).unwrap();
+        let early = Utc.with_ymd_and_hms(2024, 1, 16, 1, 0, 0).unwrap();
+        build_segments(&storage, &[late, early]).await;
+        let summarizer = Arc::new(MockSummarizer::new());
+
+        // Calendar: one day node per side of midnight
+        let calendar = RollupJob::new(
+            storage.clone(),
+            summarizer.clone(),
+            TocLevel::Day,
+            Duration::hours(1),
+        );
+        assert_eq!(calendar.run().await.unwrap(), 2);
+        for day in ["toc:day:2024-01-15", "toc:day:2024-01-16"] {
+            assert_eq!(storage.get_child_nodes(day).unwrap().len(), 1);
+        }
+
+        // Rolling 24h: one window starting at the first segment holds both
+        let grouping = RollupGrouping::Rolling {
+            window_ms: 86_400_000,
+        };
+        let rolling = RollupJob::new(
+            storage.clone(),
+            summarizer.clone(),
+            TocLevel::Day,
+            Duration::hours(1),
+        )
+        .with_grouping(grouping);
+        assert_eq!(rolling.run().await.unwrap(), 1);
+
+        let window_id = generate_rolling_node_id(TocLevel::Day, 86_400_000, late);
+        let children = storage.get_child_nodes(&window_id).unwrap();
+        assert_eq!(children.len(), 2);
+
+        // A fresh rolling run over the same segments produces the same window,
+        // and still rolls up the calendar days alongside it
+        let (rerun_storage, _rerun_temp) = create_test_storage();
+        build_segments(&rerun_storage, &[late, early]).await;
+        let built = RollupJob::new(
+            rerun_storage.clone(),
+            summarizer,
+            TocLevel::Day,
+            Duration::hours(1),
+        )
+        .with_grouping(grouping)
+        .run()
+        .await
+        .unwrap();
+        assert_eq!(built, 3);
+        assert!(rerun_storage.get_toc_node(&window_id).unwrap().is_some());
+        for day in ["toc:day:2024-01-15", "toc:day:2024-01-16"] {
+            let node = rerun_storage.get_toc_node(day).unwrap().unwrap();
+            assert!(node.title.contains("child summaries"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rollup_resumes_after_interruption() {
+        let (storage, _temp) = create_test_storage();
+        let times: Vec<DateTime<Utc>> = (10..15)
+            .map(|day| Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap())
+            .collect();
+        build_segments(&storage, &times).await;
+        let summarizer = Arc::new(MockSummarizer::new());
+        let day_ids: Vec<String> = (10..15)
+            .map(|day| format!("toc:day:2024-01-{day}"))
+            .collect();
+        let version = |id: &str| storage.get_toc_node(id).unwrap().unwrap().version;
+        let job = || {
+            RollupJob::new(
+                storage.clone(),
+                summarizer.clone(),
+                TocLevel::Day,
+                Duration::hours(1),
+            )
+        };
+
+        // Interrupted after K = 2 parents
+        assert_eq!(job().with_max_nodes(2).run().await.unwrap(), 2);
+        let checkpoint =
+            RollupCheckpoint::from_bytes(&storage.get_checkpoint("rollup_day").unwrap().unwrap())
+                .unwrap();
+        assert_eq!(checkpoint.processed_count, 2);
+        let built: Vec<u32> = day_ids[..2].iter().map(|id| version(id)).collect();
+
+        // Resume builds only the remaining parents
+        assert_eq!(job().run().await.unwrap(), 3);
+        let resumed: Vec<u32> = day_ids[..2].iter().map(|id| version(id)).collect();
+        assert_eq!(built, resumed);
+        for id in &day_ids {
+            let node = storage.get_toc_node(id).unwrap().unwrap();
+            assert_eq!(node.child_node_ids.len(), 1);
+            assert!(node.title.contains("child summaries"));
+        }
+        assert_eq!(job().run().await.unwrap(), 0);
+
+        // Rebuilding from scratch reproduces the hierarchy without new versions
+        let versions: Vec<u32> = day_ids.iter().map(|id| version(id)).collect();
+        let fresh = RollupCheckpoint::new("rollup_day".to_string(), TocLevel::Day);
+        storage
+            .put_checkpoint("rollup_day", &fresh.to_bytes().unwrap())
+            .unwrap();
+        assert_eq!(job().run().await.unwrap(), 5);
+        let rebuilt: Vec<u32> = day_ids.iter().map(|id| version(id)).collect();
+        assert_eq!(versions, rebuilt);
+    }
+
+    #[tokio::test]
+    async fn test_generic_rollups_match_trait_object() {
+        let times: Vec<DateTime<Utc>> = (10..13)
+            .map(|day| Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap())
+            .collect();
+        let (generic_storage, _generic_temp) = create_test_storage();
+        let (dynamic_storage, _dynamic_temp) = create_test_storage();
+        build_segments(&generic_storage, &times).await;
+        build_segments(&dynamic_storage, &times).await;
+
+        let concrete = Arc::new(MockSummarizer::new());
+        let dynamic: Arc<dyn Summarizer> = Arc::new(MockSummarizer::new());
+        let generic_count = run_all_rollups(generic_storage.clone(), concrete)
+            .await
+            .unwrap();
+        let dynamic_count = run_all_rollups(dynamic_storage.clone(), dynamic)
+            .await
+            .unwrap();
+        assert_eq!(generic_count, dynamic_count);
+
+        for day in 10..13 {
+            let id = format!("toc:day:2024-01-{day}");
+            let generic = generic_storage.get_toc_node(&id).unwrap().unwrap();
+            let dynamic = dynamic_storage.get_toc_node(&id).unwrap().unwrap();
+            assert_eq!(generic.title, dynamic.title);
+            assert_eq!(generic.keywords, dynamic.keywords);
+            let bullets =
+                |n: &TocNode| n.bullets.iter().map(|b| b.text.clone()).collect::<Vec<_>>();
+            assert_eq!(bullets(&generic), bullets(&dynamic));
+        }
+    }
 }
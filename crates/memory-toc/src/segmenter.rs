@@ -2,6 +2,11 @@
 //!
 //! Per TOC-03: Creates segments on time threshold (30 min) or token threshold (4K).
 //! Per TOC-04: Includes overlap for context continuity.
+//! Events over `max_segment_tokens` are split into continuation chunks.
+//! The token threshold counts each event's tokens times its salience weight,
+//! so verbose tool output can fill a segment more slowly than conversation.
+
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use tracing::{debug, trace};
@@ -9,65 +14,45 @@ use tracing::{debug, trace};
 use memory_types::{Event, EventType, Segment};
 
 use crate::config::SegmentationConfig;
+use crate::tokens::{truncate_chars, TokenCounter};
 
-/// Token counter for events.
-pub struct TokenCounter {
-    /// Maximum chars for tool results
-    max_tool_result_chars: usize,
-}
-
-impl TokenCounter {
-    pub fn new(max_tool_result_chars: usize) -> Self {
-        Self {
-            max_tool_result_chars,
-        }
-    }
-
-    /// Count tokens in event text.
-    ///
-    /// Uses tiktoken for accurate OpenAI token counting.
-    /// Truncates tool results to avoid token explosion.
-    pub fn count_event(&self, event: &Event) -> usize {
-        let text = if event.event_type == EventType::ToolResult {
-            // Truncate tool results to avoid token explosion
-            let len = event.text.len().min(self.max_tool_result_chars);
-            &event.text[..len]
-        } else {
-            &event.text
-        };
+/// Room left in each chunk for its continuation marker, in tokens.
+const CONTINUATION_RESERVE: usize = 32;
 
-        // Use tiktoken for accurate counting
-        // Fall back to estimate if tiktoken unavailable
-        match tiktoken_rs::cl100k_base() {
-            Ok(bpe) => bpe.encode_with_special_tokens(text).len(),
-            Err(_) => {
-                // Rough estimate: ~4 chars per token
-                (text.len() / 4).max(1)
-            }
-        }
-    }
+/// Separates a continuation chunk's part number from its parent event ID.
+const PART_SEPARATOR: char = '#';
 
-    /// Count tokens in multiple events.
-    pub fn count_events(&self, events: &[Event]) -> usize {
-        events.iter().map(|e| self.count_event(e)).sum()
-    }
+/// The stored event a segment event came from.
+///
+/// Continuation chunks carry derived IDs (`{event_id}#{n}`); any other ID is
+/// returned unchanged.
+pub fn parent_event_id(event_id: &str) -> &str {
+    event_id
+        .split_once(PART_SEPARATOR)
+        .map_or(event_id, |(parent, _)| parent)
 }
 
 /// Builder for creating segments from a stream of events.
 ///
 /// Detects segment boundaries based on:
 /// - Time gaps (TOC-03: 30 min default)
-/// - Token thresholds (TOC-03: 4K default)
+/// - Token thresholds (TOC-03: 4K default), on salience-weighted tokens
+/// - Hard segment cap (`max_segment_tokens`) on full event text, splitting
+///   oversized events
 ///
-/// Includes overlap from previous segment (TOC-04).
+/// Attaches the previous segment's tail as a context prefix (TOC-04).
 pub struct SegmentBuilder {
     config: SegmentationConfig,
-    token_counter: TokenCounter,
+    token_counter: Arc<dyn TokenCounter>,
 
     /// Events in current segment being built
     current_events: Vec<Event>,
     /// Token count of current segment
     current_tokens: usize,
+    /// Salience-weighted token count of current segment
+    current_weighted_tokens: usize,
+    /// Token count of current segment's full text, tool results uncut
+    current_text_tokens: usize,
     /// Time of last event
     last_event_time: Option<DateTime<Utc>>,
 
@@ -80,12 +65,14 @@ pub struct SegmentBuilder {
 impl SegmentBuilder {
     /// Create a new segment builder with the given configuration.
     pub fn new(config: SegmentationConfig) -> Self {
-        let token_counter = TokenCounter::new(config.max_tool_result_chars);
+        let token_counter = config.token_counter();
         Self {
             config,
             token_counter,
             current_events: Vec::new(),
             current_tokens: 0,
+            current_weighted_tokens: 0,
+            current_text_tokens: 0,
             last_event_time: None,
             overlap_buffer: Vec::new(),
             overlap_tokens: 0,
@@ -94,13 +81,39 @@ impl SegmentBuilder {
 
     /// Add an event to the builder.
     ///
+    /// Returns the segments completed by this event. This is at most one,
+    /// unless the event is over `max_segment_tokens` and gets split into
+    /// continuation chunks spanning several segments.
+    pub fn add_event(&mut self, event: Event) -> Vec<Segment> {
+        match self.split_oversized(&event) {
+            Some(chunks) => chunks
+                .into_iter()
+                .filter_map(|chunk| self.add_single(chunk))
+                .collect(),
+            None => self.add_single(event).into_iter().collect(),
+        }
+    }
+
+    /// Add one event that fits within `max_segment_tokens`.
+    ///
     /// Returns Some(Segment) if a boundary was detected and segment completed.
-    pub fn add_event(&mut self, event: Event) -> Option<Segment> {
+    fn add_single(&mut self, event: Event) -> Option<Segment> {
         let event_tokens = self.token_counter.count_event(&event);
+        // count_event only counts a tool result's prefix; the cap holds the
+        // full text so chunks of one tool result never share a segment
+        let text_tokens = if event.event_type == EventType::ToolResult {
+            self.token_counter.count_text(&event.text)
+        } else {
+            event_tokens
+        };
+        let weighted_tokens =
+            self.config
+                .weighted_tokens(&event.role, &event.event_type, event_tokens);
 
         trace!(
             event_id = %event.event_id,
             tokens = event_tokens,
+            weighted_tokens = weighted_tokens,
             "Processing event"
         );
 
@@ -114,36 +127,97 @@ impl SegmentBuilder {
                     "Time gap boundary detected"
                 );
                 let segment = self.flush_segment();
-                self.add_event_internal(event, event_tokens);
+                self.add_event_internal(event, event_tokens, weighted_tokens, text_tokens);
                 return Some(segment);
             }
         }
 
-        // Check for token threshold boundary
-        if self.current_tokens + event_tokens > self.config.token_threshold
-            && !self.current_events.is_empty()
-        {
+        // Check for token threshold boundary (weighted) and hard cap (raw)
+        let over_threshold =
+            self.current_weighted_tokens + weighted_tokens > self.config.token_threshold;
+        let over_cap = self.current_text_tokens + text_tokens > self.config.max_segment_tokens;
+        if (over_threshold || over_cap) && !self.current_events.is_empty() {
             debug!(
                 current_tokens = self.current_tokens,
+                current_weighted_tokens = self.current_weighted_tokens,
                 event_tokens = event_tokens,
+                weighted_tokens = weighted_tokens,
                 threshold = self.config.token_threshold,
+                max_segment_tokens = self.config.max_segment_tokens,
                 "Token threshold boundary detected"
             );
             let segment = self.flush_segment();
-            self.add_event_internal(event, event_tokens);
+            self.add_event_internal(event, event_tokens, weighted_tokens, text_tokens);
             return Some(segment);
         }
 
         // No boundary, add to current segment
-        self.add_event_internal(event, event_tokens);
+        self.add_event_internal(event, event_tokens, weighted_tokens, text_tokens);
         None
     }
 
+    /// Split an event over `max_segment_tokens` into continuation chunks.
+    ///
+    /// Each chunk is a copy of the event with a derived ID (`{event_id}#{i}`,
+    /// see [`parent_event_id`]) whose text is prefixed with a `[part i/n]`
+    /// marker.
+    ///
+    /// Returns None if the event fits as-is.
+    fn split_oversized(&self, event: &Event) -> Option<Vec<Event>> {
+        let max_tokens = self.config.max_segment_tokens;
+
+        // Every token covers at least one byte, so short text always fits
+        if event.text.len() <= max_tokens {
+            return None;
+        }
+        let text_tokens = self.token_counter.count_text(&event.text);
+        if text_tokens <= max_tokens {
+            return None;
+        }
+
+        let chunks = chunk_text(
+            self.token_counter.as_ref(),
+            &event.text,
+            text_tokens,
+            max_tokens.saturating_sub(CONTINUATION_RESERVE).max(1),
+        );
+
+        debug!(
+            event_id = %event.event_id,
+            tokens = text_tokens,
+            max_segment_tokens = max_tokens,
+            chunks = chunks.len(),
+            "Splitting oversized event"
+        );
+
+        let total = chunks.len();
+        Some(
+            chunks
+                .into_iter()
+                .enumerate()
+                .map(|(i, chunk)| {
+                    let mut part = event.clone();
+                    part.event_id = format!("{}{}{}", event.event_id, PART_SEPARATOR, i + 1);
+                    part.text = format!("[part {}/{}] {}", i + 1, total, chunk);
+                    part
+                })
+                .collect(),
+        )
+    }
+
     /// Internal method to add event to current segment.
-    fn add_event_internal(&mut self, event: Event, event_tokens: usize) {
+    fn add_event_internal(
+        &mut self,
+        event: Event,
+        event_tokens: usize,
+        weighted_tokens: usize,
+        text_tokens: usize,
+    ) {
         self.last_event_time = Some(event.timestamp);
         self.current_events.push(event);
         self.current_tokens += event_tokens;
+        self.current_weighted_tokens += weighted_tokens;
+        self.current_text_tokens += text_tokens;
     }
 
     /// Flush current events as a completed segment.
@@ -151,11 +225,13 @@ impl SegmentBuilder {
         let events = std::mem::take(&mut self.current_events);
         let tokens = self.current_tokens;
         self.current_tokens = 0;
+        self.current_weighted_tokens = 0;
+        self.current_text_tokens = 0;
 
         let start_time = events.first().map(|e| e.timestamp).unwrap_or_else(Utc::now);
         let end_time = events.last().map(|e| e.timestamp).unwrap_or_else(Utc::now);
 
-        // Create segment with overlap from previous
+        // Previous segment's overlap becomes this segment's context prefix
         let overlap = std::mem::take(&mut self.overlap_buffer);
         let segment_id = format!("seg:{}", ulid::Ulid::new());
 
@@ -170,7 +246,7 @@ impl SegmentBuilder {
         // Build overlap buffer for next segment
         self.build_overlap_buffer(&events);
 
-        Segment::new(segment_id, events, start_time, end_time, tokens).with_overlap(overlap)
+        Segment::new(segment_id, events, start_time, end_time, tokens).with_context_prefix(overlap)
     }
 
     /// Build overlap buffer for next segment from current events.
@@ -239,15 +315,90 @@ impl SegmentBuilder {
     }
 }
 
+/// Split text into chunks of at most `max_tokens` tokens.
+///
+/// `text_tokens` is the token count of the whole text, used to guess the
+/// chunk size before measuring. Chunks never split a UTF-8 character.
+fn chunk_text<'a>(
+    counter: &dyn TokenCounter,
+    text: &'a str,
+    text_tokens: usize,
+    max_tokens: usize,
+) -> Vec<&'a str> {
+    // Assume tokens are spread evenly over the text
+    let guess = (text.len() / text_tokens.max(1))
+        .max(1)
+        .saturating_mul(max_tokens);
+
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = truncate_chars(rest, guess).len();
+        loop {
+            if end == 0 {
+                // A single character is the smallest possible chunk
+                end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+                break;
+            }
+            let tokens = counter.count_text(&rest[..end]);
+            if tokens <= max_tokens {
+                break;
+            }
+            // Shrink in proportion to the overshoot
+            end = truncate_chars(rest, (end * max_tokens / tokens).min(end - 1)).len();
+        }
+
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+/// Incremental segmentation for live ingestion.
+///
+/// Events are pushed as they arrive and segments are returned as soon as a
+/// boundary is crossed. All boundary logic lives in the [`SegmentBuilder`]
+/// this wraps, the same one [`segment_events`] runs, so a sequence pushed
+/// one event at a time yields the same segments as the batch call.
+pub struct StreamingSegmenter {
+    builder: SegmentBuilder,
+}
+
+impl StreamingSegmenter {
+    /// Create a streaming segmenter with the given configuration.
+    pub fn new(config: SegmentationConfig) -> Self {
+        Self {
+            builder: SegmentBuilder::new(config),
+        }
+    }
+
+    /// Add the next event, returning the segments it completed.
+    pub fn push(&mut self, event: Event) -> Vec<Segment> {
+        self.builder.add_event(event)
+    }
+
+    /// Close the trailing partial segment, if any.
+    ///
+    /// The segmenter can keep receiving events afterwards; the next segment
+    /// gets the flushed segment's tail as its context prefix.
+    pub fn flush(&mut self) -> Option<Segment> {
+        self.builder.flush()
+    }
+
+    /// Check if events are waiting for a boundary.
+    pub fn has_pending(&self) -> bool {
+        self.builder.has_pending()
+    }
+}
+
 /// Process a batch of events into segments.
 pub fn segment_events(events: Vec<Event>, config: SegmentationConfig) -> Vec<Segment> {
     let mut builder = SegmentBuilder::new(config);
     let mut segments = Vec::new();
 
     for event in events {
-        if let Some(segment) = builder.add_event(event) {
-            segments.push(segment);
-        }
+        segments.extend(builder.add_event(event));
     }
 
     // Flush any remaining events
@@ -261,6 +412,7 @@ pub fn segment_events(events: Vec<Event>, config: SegmentationConfig) -> Vec<Seg
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::SalienceWeights;
     use chrono::TimeZone;
     use memory_types::{EventRole, EventType};
 
@@ -278,7 +430,7 @@ mod tests {
 
     #[test]
     fn test_token_counter_basic() {
-        let counter = TokenCounter::new(1000);
+        let counter = crate::tokens::default_token_counter(1000);
         let event = create_event_at("Hello, world!", 1000);
         let tokens = counter.count_event(&event);
         assert!(tokens > 0);
@@ -287,7 +439,7 @@ mod tests {
 
     #[test]
     fn test_token_counter_truncates_tool_results() {
-        let counter = TokenCounter::new(100);
+        let counter = crate::tokens::default_token_counter(100);
         let mut event = create_event_at(&"x".repeat(1000), 1000);
         event.event_type = EventType::ToolResult;
 
@@ -296,6 +448,24 @@ mod tests {
         assert!(tokens < 50);
     }
 
+    #[test]
+    fn test_tool_results_truncated_by_tokens() {
+        let config = SegmentationConfig {
+            max_tool_result_tokens: Some(10),
+            ..Default::default()
+        };
+        let counter = config.token_counter();
+        assert_eq!(counter.max_tool_result_tokens(), Some(10));
+
+        let mut event = create_event_at(&"lorem ipsum dolor ".repeat(100), 1000);
+        event.event_type = EventType::ToolResult;
+        assert!(counter.count_event(&event) <= 10);
+
+        let truncated = counter.truncate_tool_result(&event.text);
+        assert!(truncated.ends_with(" chars]"));
+        assert!(counter.count_text(truncated.split(" [truncated ").next().unwrap()) <= 10);
+    }
+
     #[test]
     fn test_segment_builder_time_boundary() {
         let config = SegmentationConfig {
@@ -304,20 +474,21 @@ mod tests {
             overlap_time_ms: 500,
             overlap_tokens: 100,
             max_tool_result_chars: 1000,
+            ..Default::default()
         };
 
         let mut builder = SegmentBuilder::new(config);
 
         // Events within 1 second - no boundary
-        assert!(builder.add_event(create_event_at("First", 1000)).is_none());
-        assert!(builder.add_event(create_event_at("Second", 1500)).is_none());
+        assert!(builder.add_event(create_event_at("First", 1000)).is_empty());
+        assert!(builder
+            .add_event(create_event_at("Second", 1500))
+            .is_empty());
 
         // Event after 2 second gap - boundary
-        let segment = builder.add_event(create_event_at("After gap", 4000));
-        assert!(segment.is_some());
-
-        let seg = segment.unwrap();
-        assert_eq!(seg.events.len(), 2);
+        let segments = builder.add_event(create_event_at("After gap", 4000));
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].events.len(), 2);
     }
 
     #[test]
@@ -328,19 +499,60 @@ mod tests {
             overlap_time_ms: 500,
             overlap_tokens: 5,
             max_tool_result_chars: 1000,
+            ..Default::default()
         };
 
         let mut builder = SegmentBuilder::new(config);
 
         // First event
-        assert!(builder.add_event(create_event_at("Short", 1000)).is_none());
+        assert!(builder.add_event(create_event_at("Short", 1000)).is_empty());
 
         // Long event should trigger boundary
-        let segment = builder.add_event(create_event_at(
+        let segments = builder.add_event(create_event_at(
             "This is a much longer message that should exceed the token threshold",
             2000,
         ));
-        assert!(segment.is_some());
+        assert_eq!(segments.len(), 1);
+    }
+
+    #[test]
+    fn test_down_weighted_tool_result_does_not_split() {
+        let config = SegmentationConfig {
+            time_threshold_ms: 1000000,
+            token_threshold: 30,
+            max_tool_result_chars: 1000,
+            ..Default::default()
+        };
+
+        let mut tool_result = create_event_at(&"cargo test output line ok ".repeat(10), 2000);
+        tool_result.event_type = EventType::ToolResult;
+        tool_result.role = EventRole::Tool;
+        let events = vec![
+            create_event_at("Run the tests", 1000),
+            tool_result,
+            create_event_at("All tests pass", 3000),
+        ];
+
+        // Unweighted, the verbose tool result forces a split
+        let baseline = segment_events(events.clone(), config.clone());
+        assert!(baseline.len() > 1);
+        assert_eq!(baseline[0].events.len(), 1);
+
+        // Down-weighted, the exchange stays in one segment
+        let weighted = config.with_salience_weights(SalienceWeights {
+            tool: 0.1,
+            ..Default::default()
+        });
+        let segments = segment_events(events, weighted);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].events.len(), 3);
+
+        // The reported token count stays unweighted
+        let counter = crate::tokens::default_token_counter(1000);
+        assert_eq!(
+            segments[0].token_count,
+            counter.count_events(&segments[0].events)
+        );
     }
 
     #[test]
@@ -351,6 +563,7 @@ mod tests {
             overlap_time_ms: 500,
             overlap_tokens: 1000,
             max_tool_result_chars: 1000,
+            ..Default::default()
         };
 
         let mut builder = SegmentBuilder::new(config);
@@ -363,6 +576,7 @@ mod tests {
         // Trigger boundary
         let segment1 = builder
             .add_event(create_event_at("After gap", 5000))
+            .pop()
             .unwrap();
         assert_eq!(segment1.events.len(), 3);
 
@@ -371,7 +585,7 @@ mod tests {
         let segment2 = builder.flush().unwrap();
 
         // Second segment should have overlap from first
-        assert!(!segment2.overlap_events.is_empty());
+        assert!(!segment2.context_prefix.is_empty());
     }
 
     #[test]
@@ -382,6 +596,7 @@ mod tests {
             overlap_time_ms: 100,
             overlap_tokens: 50,
             max_tool_result_chars: 1000,
+            ..Default::default()
         };
 
         let events = vec![
@@ -395,6 +610,196 @@ mod tests {
         assert_eq!(segments.len(), 2);
     }
 
+    #[test]
+    fn test_context_prefix_holds_previous_tail() {
+        let config = SegmentationConfig {
+            time_threshold_ms: 1000,
+            token_threshold: 10000,
+            overlap_time_ms: 300,
+            overlap_tokens: 1000,
+            max_tool_result_chars: 1000,
+            ..Default::default()
+        };
+
+        let events = vec![
+            create_event_at("Early", 1000),
+            create_event_at("Middle", 1200),
+            create_event_at("Late", 1400),
+            create_event_at("After gap", 5000),
+            create_event_at("New event", 5500),
+        ];
+
+        let segments = segment_events(events, config);
+        assert_eq!(segments.len(), 2);
+        assert!(segments[0].context_prefix.is_empty());
+
+        // Only the tail within the overlap window is carried over
+        let prefix: Vec<&str> = segments[1]
+            .context_prefix
+            .iter()
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(prefix, vec!["Middle", "Late"]);
+        assert_eq!(
+            segments[1].context_prefix[1].event_id,
+            segments[0].events[2].event_id
+        );
+
+        // Context is kept apart from the segment's own events and tokens
+        let texts: Vec<&str> = segments[1].events.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["After gap", "New event"]);
+        let counter = crate::tokens::default_token_counter(1000);
+        assert_eq!(
+            segments[1].token_count,
+            counter.count_events(&segments[1].events)
+        );
+    }
+
+    #[test]
+    fn test_oversized_tool_result_is_split() {
+        let config = SegmentationConfig {
+            max_segment_tokens: 2000,
+            ..Default::default()
+        };
+        let counter = config.token_counter();
+
+        let mut event = create_event_at(&"lorem ipsum dolor sit amet ".repeat(7500), 1000);
+        event.event_type = EventType::ToolResult;
+        assert!(event.text.len() >= 200_000);
+
+        let segments = segment_events(vec![event.clone()], config);
+        assert!(segments.len() > 1);
+
+        let mut rebuilt = String::new();
+        for segment in &segments {
+            assert!(segment.token_count <= 2000);
+            let tokens: usize = segment
+                .events
+                .iter()
+                .map(|e| counter.count_text(&e.text))
+                .sum();
+            assert!(tokens <= 2000, "segment has {} tokens", tokens);
+            for part in &segment.events {
+                assert_eq!(parent_event_id(&part.event_id), event.event_id);
+                assert!(part.text.starts_with("[part "));
+                rebuilt.push_str(part.text.split_once("] ").unwrap().1);
+            }
+        }
+        // No text is lost when chunking
+        assert_eq!(rebuilt, event.text);
+    }
+
+    #[test]
+    fn test_oversized_message_is_split() {
+        let config = SegmentationConfig {
+            max_segment_tokens: 100,
+            ..Default::default()
+        };
+        let counter = config.token_counter();
+        let event = create_event_at(&"word ".repeat(1000), 1000);
+        let event_id = event.event_id.clone();
+
+        let mut builder = SegmentBuilder::new(config);
+        let mut segments = builder.add_event(event);
+        segments.extend(builder.flush());
+
+        assert!(segments.len() > 1);
+        let mut ids = std::collections::HashSet::new();
+        for (i, segment) in segments.iter().enumerate() {
+            assert_eq!(segment.events.len(), 1);
+            let part = &segment.events[0];
+            assert!(counter.count_text(&part.text) <= 100);
+            assert_eq!(part.event_id, format!("{}#{}", event_id, i + 1));
+            assert_eq!(parent_event_id(&part.event_id), event_id);
+            assert!(ids.insert(part.event_id.clone()), "duplicate chunk ID");
+        }
+        assert!(segments[0].events[0].text.starts_with("[part 1/"));
+    }
+
+    #[test]
+    fn test_small_event_not_split() {
+        let config = SegmentationConfig {
+            max_segment_tokens: 100,
+            ..Default::default()
+        };
+        let segments = segment_events(vec![create_event_at("Hello", 1000)], config);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].events[0].text, "Hello");
+    }
+
+    /// Segment contents without the random segment ID.
+    type SegmentShape = (Vec<String>, Vec<String>, i64, i64, usize);
+
+    fn shape(segment: &Segment) -> SegmentShape {
+        let describe = |events: &[Event]| -> Vec<String> {
+            events
+                .iter()
+                .map(|e| format!("{}:{}", e.event_id, e.text))
+                .collect()
+        };
+        (
+            describe(&segment.events),
+            describe(&segment.context_prefix),
+            segment.start_time.timestamp_millis(),
+            segment.end_time.timestamp_millis(),
+            segment.token_count,
+        )
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let config = SegmentationConfig {
+            time_threshold_ms: 60_000,
+            token_threshold: 40,
+            max_segment_tokens: 200,
+            overlap_time_ms: 30_000,
+            overlap_tokens: 20,
+            ..Default::default()
+        };
+
+        // Token boundaries, a time gap, and an oversized event split in parts
+        let mut events: Vec<Event> = (0..12)
+            .map(|i| {
+                create_event_at(
+                    &format!("message {} about the schema migration", i),
+                    i * 1000,
+                )
+            })
+            .collect();
+        events.push(create_event_at("after a long pause", 500_000));
+        events.push(create_event_at(
+            &"very long tool output ".repeat(200),
+            501_000,
+        ));
+        events.push(create_event_at("trailing message", 502_000));
+
+        let batch: Vec<SegmentShape> = segment_events(events.clone(), config.clone())
+            .iter()
+            .map(shape)
+            .collect();
+
+        let mut segmenter = StreamingSegmenter::new(config);
+        let mut streamed = Vec::new();
+        for event in events {
+            let is_after_gap = event.text == "after a long pause";
+            let completed = segmenter.push(event);
+            if is_after_gap {
+                // The time gap closes the open segment as soon as it is seen
+                let last = completed.last().expect("gap completes a segment");
+                let last_event = last.events.last().unwrap();
+                assert_eq!(last_event.timestamp.timestamp_millis(), 11_000);
+            }
+            streamed.extend(completed.iter().map(shape));
+        }
+        assert!(segmenter.has_pending());
+        streamed.extend(segmenter.flush().iter().map(shape));
+        assert!(!segmenter.has_pending());
+        assert!(segmenter.flush().is_none());
+
+        assert!(batch.len() > 3);
+        assert_eq!(streamed, batch);
+    }
+
     #[test]
     fn test_flush_empty_builder() {
         let mut builder = SegmentBuilder::new(SegmentationConfig::default());
@@ -5,12 +5,28 @@ use backoff::{backoff::Backoff, ExponentialBackoff};
 use reqwest::Client;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, error, warn};
 
 use memory_types::Event;
 
-use super::{Summarizer, SummarizerError, Summary};
+use super::output::{parse_summary_output, summary_json_schema};
+use super::{GenericSummarizer, Summarizer, SummarizerError, Summary};
+use crate::tokens::{default_token_counter, TokenCounter};
+
+/// Default prompt budget for event summarization (tokens).
+const DEFAULT_MAX_INPUT_TOKENS: usize = 16_000;
+
+/// Placeholder for the conversation text in `prompt_template` (required).
+pub const EVENTS_PLACEHOLDER: &str = "{events}";
+
+/// Placeholder for prior-segment context in `prompt_template` (optional).
+pub const CONTEXT_PLACEHOLDER: &str = "{context}";
+
+/// Placeholder for child summaries in `rollup_prompt_template` (required).
+pub const CHILD_SUMMARIES_PLACEHOLDER: &str = "{child_summaries}";
 
 /// Configuration for API-based summarizer.
 #[derive(Debug, Clone)]
@@ -29,6 +45,18 @@ pub struct ApiSummarizerConfig {
 
     /// Maximum retries on failure
     pub max_retries: u32,
+
+    /// Maximum prompt tokens for event summarization.
+    /// Events past the budget are dropped from the prompt.
+    pub max_input_tokens: usize,
+
+    /// Custom prompt for event summarization, replacing the built-in one.
+    /// Must contain `{events}`; `{context}` is optional.
+    pub prompt_template: Option<String>,
+
+    /// Custom prompt for rollup summarization, replacing the built-in one.
+    /// Must contain `{child_summaries}`.
+    pub rollup_prompt_template: Option<String>,
 }
 
 impl ApiSummarizerConfig {
@@ -40,6 +68,9 @@ impl ApiSummarizerConfig {
             api_key: SecretString::from(api_key.into()),
             timeout: Duration::from_secs(60),
             max_retries: 3,
+            max_input_tokens: DEFAULT_MAX_INPUT_TOKENS,
+            prompt_template: None,
+            rollup_prompt_template: None,
         }
     }
 
@@ -51,7 +82,44 @@ impl ApiSummarizerConfig {
             api_key: SecretString::from(api_key.into()),
             timeout: Duration::from_secs(60),
             max_retries: 3,
+            max_input_tokens: DEFAULT_MAX_INPUT_TOKENS,
+            prompt_template: None,
+            rollup_prompt_template: None,
+        }
+    }
+
+    /// Use a custom event summarization prompt.
+    pub fn with_prompt_template(mut self, template: impl Into<String>) -> Self {
+        self.prompt_template = Some(template.into());
+        self
+    }
+
+    /// Use a custom rollup summarization prompt.
+    pub fn with_rollup_prompt_template(mut self, template: impl Into<String>) -> Self {
+        self.rollup_prompt_template = Some(template.into());
+        self
+    }
+
+    /// Check that custom templates contain their required placeholders.
+    pub fn validate(&self) -> Result<(), SummarizerError> {
+        let templates = [
+            ("prompt_template", &self.prompt_template, EVENTS_PLACEHOLDER),
+            (
+                "rollup_prompt_template",
+                &self.rollup_prompt_template,
+                CHILD_SUMMARIES_PLACEHOLDER,
+            ),
+        ];
+        for (name, template, required) in templates {
+            if let Some(template) = template {
+                if !template.contains(required) {
+                    return Err(SummarizerError::ConfigError(format!(
+                        "{name} is missing the {required} placeholder"
+                    )));
+                }
+            }
         }
+        Ok(())
     }
 }
 
@@ -59,34 +127,127 @@ impl ApiSummarizerConfig {
 pub struct ApiSummarizer {
     client: Client,
     config: ApiSummarizerConfig,
+    token_counter: Arc<dyn TokenCounter>,
+    /// Set once the endpoint has rejected a strict `json_schema` response
+    /// format, so later requests go straight to `json_object`.
+    json_schema_rejected: AtomicBool,
 }
 
 impl ApiSummarizer {
     /// Create a new API summarizer.
     pub fn new(config: ApiSummarizerConfig) -> Result<Self, SummarizerError> {
+        config.validate()?;
         let client = Client::builder()
             .timeout(config.timeout)
             .build()
             .map_err(|e| SummarizerError::ConfigError(e.to_string()))?;
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            config,
+            token_counter: default_token_counter(1000),
+            json_schema_rejected: AtomicBool::new(false),
+        })
+    }
+
+    /// Use a specific token counter for prompt budgeting.
+    ///
+    /// Share the counter passed to `SegmentationConfig` so segments and
+    /// prompts are measured the same way.
+    pub fn with_token_counter(mut self, counter: Arc<dyn TokenCounter>) -> Self {
+        self.token_counter = counter;
+        self
+    }
+
+    /// Keep the leading events whose prompt fits `max_input_tokens`.
+    ///
+    /// Always keeps at least one event.
+    fn fit_events_to_budget<'a>(&self, events: &'a [Event]) -> &'a [Event] {
+        let budget = self.config.max_input_tokens;
+        let mut used = self
+            .token_counter
+            .count_text(&self.build_events_prompt(&[], &[]));
+        let mut kept = 0;
+
+        for event in events {
+            used += self.token_counter.count_text(&format_event(event));
+            if used > budget {
+                break;
+            }
+            kept += 1;
+        }
+
+        let kept = kept.max(1);
+        if kept < events.len() {
+            warn!(
+                kept = kept,
+                dropped = events.len() - kept,
+                budget = budget,
+                "Summarizer prompt over token budget, dropping trailing events"
+            );
+        }
+        &events[..kept]
+    }
+
+    /// Keep the latest context events that fit the budget left by `events`.
+    ///
+    /// Context is dropped before any segment event.
+    fn fit_context_to_budget<'a>(&self, context: &'a [Event], events: &[Event]) -> &'a [Event] {
+        let budget = self.config.max_input_tokens;
+        let mut used = self
+            .token_counter
+            .count_text(&self.build_events_prompt(&[], events));
+        let mut kept = 0;
+
+        for event in context.iter().rev() {
+            used += self.token_counter.count_text(&format_event(event));
+            if used > budget {
+                break;
+            }
+            kept += 1;
+        }
+
+        &context[context.len() - kept..]
     }
 
     /// Build prompt for event summarization.
-    fn build_events_prompt(&self, events: &[Event]) -> String {
+    ///
+    /// `context` events are shown before the conversation, marked as
+    /// background that should not be summarized.
+    fn build_events_prompt(&self, context: &[Event], events: &[Event]) -> String {
         let events_text: String = events
             .iter()
-            .map(|e| {
-                let timestamp = e.timestamp.format("%Y-%m-%d %H:%M:%S");
-                format!("[{}] {}: {}", timestamp, e.role, e.text)
-            })
+            .map(format_event)
             .collect::<Vec<_>>()
             .join("\n\n");
 
+        let context_text = if context.is_empty() {
+            String::new()
+        } else {
+            let lines = context
+                .iter()
+                .map(format_event)
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            format!(
+                "PRIOR CONTEXT (end of the previous segment, for continuity only; do not summarize):\n{lines}\n\n"
+            )
+        };
+
+        if let Some(template) = &self.config.prompt_template {
+            return render_template(
+                template,
+                &[
+                    (EVENTS_PLACEHOLDER, events_text.as_str()),
+                    (CONTEXT_PLACEHOLDER, context_text.as_str()),
+                ],
+            );
+        }
+
         format!(
             r#"Summarize this conversation segment for a Table of Contents entry.
 
-CONVERSATION:
+{context_text}CONVERSATION:
 {events_text}
 
 Provide your response in JSON format:
@@ -122,6 +283,13 @@ Guidelines:
             .collect::<Vec<_>>()
             .join("\n\n");
 
+        if let Some(template) = &self.config.rollup_prompt_template {
+            return render_template(
+                template,
+                &[(CHILD_SUMMARIES_PLACEHOLDER, summaries_text.as_str())],
+            );
+        }
+
         format!(
             r#"Create a higher-level summary by aggregating these child summaries.
 
@@ -198,6 +366,11 @@ Guidelines:
     }
 
     /// Make OpenAI-compatible API request.
+    ///
+    /// Asks for a strict `json_schema` response first. Not every
+    /// OpenAI-compatible server supports it, so a 400 reply is retried
+    /// with plain `json_object`, and once that works the schema is no
+    /// longer sent.
     async fn make_openai_request(&self, prompt: &str) -> Result<String, SummarizerError> {
         #[derive(Serialize)]
         struct OpenAIRequest {
@@ -216,6 +389,15 @@ Guidelines:
         struct OpenAIResponseFormat {
             #[serde(rename = "type")]
             format_type: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            json_schema: Option<OpenAIJsonSchema>,
+        }
+
+        #[derive(Serialize)]
+        struct OpenAIJsonSchema {
+            name: String,
+            strict: bool,
+            schema: serde_json::Value,
         }
 
         #[derive(Deserialize)]
@@ -233,31 +415,64 @@ Guidelines:
             content: String,
         }
 
-        let request = OpenAIRequest {
-            model: self.config.model.clone(),
-            messages: vec![OpenAIMessage {
-                role: "user".to_string(),
-                content: prompt.to_string(),
-            }],
-            response_format: OpenAIResponseFormat {
-                format_type: "json_object".to_string(),
-            },
+        let response_format = |strict_schema: bool| {
+            if strict_schema {
+                OpenAIResponseFormat {
+                    format_type: "json_schema".to_string(),
+                    json_schema: Some(OpenAIJsonSchema {
+                        name: "summary".to_string(),
+                        strict: true,
+                        schema: summary_json_schema(),
+                    }),
+                }
+            } else {
+                OpenAIResponseFormat {
+                    format_type: "json_object".to_string(),
+                    json_schema: None,
+                }
+            }
         };
 
         let url = format!("{}/chat/completions", self.config.base_url);
-
-        let response = self
-            .client
-            .post(&url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.config.api_key.expose_secret()),
-            )
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| SummarizerError::ApiError(e.to_string()))?;
+        let mut strict_schema = !self.json_schema_rejected.load(Ordering::Relaxed);
+
+        let response = loop {
+            let request = OpenAIRequest {
+                model: self.config.model.clone(),
+                messages: vec![OpenAIMessage {
+                    role: "user".to_string(),
+                    content: prompt.to_string(),
+                }],
+                response_format: response_format(strict_schema),
+            };
+
+            let response = self
+                .client
+                .post(&url)
+                .header(
+                    "Authorization",
+                    format!("Bearer {}", self.config.api_key.expose_secret()),
+                )
+                .header("Content-Type", "application/json")
+                .json(&request)
+                .send()
+                .await
+                .map_err(|e| SummarizerError::ApiError(e.to_string()))?;
+
+            if strict_schema && response.status() == 400 {
+                let body = response.text().await.unwrap_or_default();
+                warn!(
+                    body = %body,
+                    "json_schema response format rejected, retrying with json_object"
+                );
+                strict_schema = false;
+                continue;
+            }
+            if !strict_schema && response.status().is_success() {
+                self.json_schema_rejected.store(true, Ordering::Relaxed);
+            }
+            break response;
+        };
 
         if response.status() == 429 {
             return Err(SummarizerError::RateLimitExceeded);
@@ -290,6 +505,7 @@ Guidelines:
         struct AnthropicRequest {
             model: String,
             max_tokens: u32,
+            system: String,
             messages: Vec<AnthropicMessage>,
         }
 
@@ -312,6 +528,10 @@ Guidelines:
         let request = AnthropicRequest {
             model: self.config.model.clone(),
             max_tokens: 1024,
+            system: format!(
+                "Respond with a single JSON object and nothing else. It must match this JSON schema:\n{}",
+                summary_json_schema()
+            ),
             messages: vec![AnthropicMessage {
                 role: "user".to_string(),
                 content: prompt.to_string(),
@@ -356,91 +576,117 @@ Guidelines:
             .ok_or_else(|| SummarizerError::ParseError("No content in response".to_string()))
     }
 
-    /// Parse JSON response into Summary.
-    fn parse_summary(&self, response: &str) -> Result<Summary, SummarizerError> {
-        // Try to extract JSON from response (in case there's extra text)
-        let json_str = extract_json(response);
+    /// Summarize `events`, with `context` from the previous segment.
+    async fn summarize_with_context(
+        &self,
+        context: &[Event],
+        events: &[Event],
+    ) -> Result<Summary, SummarizerError> {
+        if events.is_empty() {
+            return Err(SummarizerError::NoEvents);
+        }
 
-        serde_json::from_str(&json_str).map_err(|e| {
-            SummarizerError::ParseError(format!("Failed to parse summary JSON: {}", e))
-        })
+        let events = self.fit_events_to_budget(events);
+        let context = self.fit_context_to_budget(context, events);
+        let prompt = self.build_events_prompt(context, events);
+        let response = self.call_api(&prompt).await?;
+        self.parse_summary(&response)
+    }
+
+    /// Parse the model's reply into a Summary.
+    fn parse_summary(&self, response: &str) -> Result<Summary, SummarizerError> {
+        parse_summary_output(response)
     }
 }
 
-/// Extract JSON object from text (handles markdown code blocks).
-fn extract_json(text: &str) -> String {
-    // Check for markdown code block
-    if let Some(start) = text.find("```json") {
-        if let Some(end) = text[start + 7..].find("```") {
-            return text[start + 7..start + 7 + end].trim().to_string();
-        }
+impl GenericSummarizer for ApiSummarizer {
+    async fn summarize_events_generic(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
+        self.summarize_with_context(&[], events).await
     }
 
-    // Check for plain code block
-    if let Some(start) = text.find("```") {
-        if let Some(end) = text[start + 3..].find("```") {
-            return text[start + 3..start + 3 + end].trim().to_string();
+    async fn summarize_children_generic(
+        &self,
+        summaries: &[Summary],
+    ) -> Result<Summary, SummarizerError> {
+        if summaries.is_empty() {
+            return Err(SummarizerError::NoEvents);
         }
-    }
 
-    // Find first { and last }
-    if let (Some(start), Some(end)) = (text.find('{'), text.rfind('}')) {
-        return text[start..=end].to_string();
+        let prompt = self.build_rollup_prompt(summaries);
+        let response = self.call_api(&prompt).await?;
+        self.parse_summary(&response)
     }
-
-    text.to_string()
 }
 
 #[async_trait]
 impl Summarizer for ApiSummarizer {
     async fn summarize_events(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
-        if events.is_empty() {
-            return Err(SummarizerError::NoEvents);
-        }
+        self.summarize_events_generic(events).await
+    }
 
-        let prompt = self.build_events_prompt(events);
-        let response = self.call_api(&prompt).await?;
-        self.parse_summary(&response)
+    async fn summarize_events_with_context(
+        &self,
+        context: &[Event],
+        events: &[Event],
+    ) -> Result<Summary, SummarizerError> {
+        self.summarize_with_context(context, events).await
     }
 
     async fn summarize_children(&self, summaries: &[Summary]) -> Result<Summary, SummarizerError> {
-        if summaries.is_empty() {
-            return Err(SummarizerError::NoEvents);
-        }
+        self.summarize_children_generic(summaries).await
+    }
 
-        let prompt = self.build_rollup_prompt(summaries);
-        let response = self.call_api(&prompt).await?;
-        self.parse_summary(&response)
+    fn summarizer_id(&self) -> String {
+        format!("api:{}", self.config.model)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_extract_json_plain() {
-        let text = r#"{"title": "Test", "bullets": [], "keywords": []}"#;
-        let json = extract_json(text);
-        assert_eq!(json, text);
+/// Substitute `placeholders` into `template` in a single pass.
+///
+/// Substituted text is not scanned again, so event text that happens to
+/// contain a placeholder is left as written.
+fn render_template(template: &str, placeholders: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find('{') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        match placeholders.iter().find(|(key, _)| rest.starts_with(key)) {
+            Some((key, value)) => {
+                out.push_str(value);
+                rest = &rest[key.len()..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
     }
+    out.push_str(rest);
+    out
+}
 
-    #[test]
-    fn test_extract_json_code_block() {
-        let text = r#"Here's the summary:
-```json
-{"title": "Test", "bullets": [], "keywords": []}
-```"#;
-        let json = extract_json(text);
-        assert!(json.contains("Test"));
-    }
+/// Format an event as a prompt line.
+fn format_event(event: &Event) -> String {
+    let timestamp = event.timestamp.format("%Y-%m-%d %H:%M:%S");
+    format!("[{}] {}: {}", timestamp, event.role, event.text)
+}
 
-    #[test]
-    fn test_extract_json_with_prefix() {
-        let text = r#"Sure! Here's your summary: {"title": "Test", "bullets": [], "keywords": []}"#;
-        let json = extract_json(text);
-        assert!(json.starts_with('{'));
-        assert!(json.ends_with('}'));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::HeuristicTokenCounter;
+    use memory_types::{EventRole, EventType};
+
+    fn event(text: &str) -> Event {
+        Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-123".to_string(),
+            chrono::Utc::now(),
+            EventType::UserMessage,
+            EventRole::User,
+            text.to_string(),
+        )
     }
 
     #[test]
@@ -456,4 +702,190 @@ mod tests {
         assert!(config.base_url.contains("anthropic"));
         assert_eq!(config.model, "claude-3-haiku-20240307");
     }
+
+    #[test]
+    fn test_fit_events_to_budget() {
+        let counter = Arc::new(HeuristicTokenCounter::new(1000));
+        let events: Vec<Event> = (0..10).map(|_| event(&"x".repeat(400))).collect();
+
+        let mut config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini");
+        let overhead = counter.count_text(
+            &ApiSummarizer::new(config.clone())
+                .unwrap()
+                .build_events_prompt(&[], &[]),
+        );
+        let per_event = counter.count_text(&format_event(&events[0]));
+
+        // Room for exactly three events
+        config.max_input_tokens = overhead + per_event * 3;
+        let summarizer = ApiSummarizer::new(config)
+            .unwrap()
+            .with_token_counter(counter);
+
+        assert_eq!(summarizer.fit_events_to_budget(&events).len(), 3);
+        assert_eq!(summarizer.fit_events_to_budget(&events[..2]).len(), 2);
+    }
+
+    #[test]
+    fn test_fit_events_keeps_at_least_one() {
+        let mut config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini");
+        config.max_input_tokens = 1;
+        let summarizer = ApiSummarizer::new(config)
+            .unwrap()
+            .with_token_counter(Arc::new(HeuristicTokenCounter::new(1000)));
+
+        let events = vec![event("first"), event("second")];
+        let kept = summarizer.fit_events_to_budget(&events);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].text, "first");
+    }
+
+    #[test]
+    fn test_events_prompt_marks_context() {
+        let summarizer =
+            ApiSummarizer::new(ApiSummarizerConfig::openai("test-key", "gpt-4o-mini")).unwrap();
+
+        let prompt =
+            summarizer.build_events_prompt(&[event("earlier talk")], &[event("main talk")]);
+        let context_at = prompt.find("PRIOR CONTEXT").unwrap();
+        let conversation_at = prompt.find("CONVERSATION:").unwrap();
+        assert!(context_at < prompt.find("earlier talk").unwrap());
+        assert!(prompt.find("earlier talk").unwrap() < conversation_at);
+        assert!(conversation_at < prompt.find("main talk").unwrap());
+
+        let plain = summarizer.build_events_prompt(&[], &[event("main talk")]);
+        assert!(!plain.contains("PRIOR CONTEXT"));
+    }
+
+    #[test]
+    fn test_fit_context_keeps_latest() {
+        let counter = Arc::new(HeuristicTokenCounter::new(1000));
+        let events = vec![event("main")];
+        let context: Vec<Event> = (0..5)
+            .map(|i| event(&format!("{}{}", i, "x".repeat(400))))
+            .collect();
+
+        let mut config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini");
+        let base = counter.count_text(
+            &ApiSummarizer::new(config.clone())
+                .unwrap()
+                .build_events_prompt(&[], &events),
+        );
+        let per_event = counter.count_text(&format_event(&context[0]));
+
+        // Room for two context events on top of the segment itself
+        config.max_input_tokens = base + per_event * 2;
+        let summarizer = ApiSummarizer::new(config)
+            .unwrap()
+            .with_token_counter(counter);
+
+        let kept = summarizer.fit_context_to_budget(&context, &events);
+        assert_eq!(kept.len(), 2);
+        assert!(kept[0].text.starts_with('3'));
+        assert!(kept[1].text.starts_with('4'));
+    }
+
+    #[test]
+    fn test_custom_prompt_template_substitutes_events() {
+        let config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini").with_prompt_template(
+            "Focus on decisions and rationale.\n{context}Conversation:\n{events}\nReply in JSON.",
+        );
+        let summarizer = ApiSummarizer::new(config).unwrap();
+
+        let prompt = summarizer.build_events_prompt(&[], &[event("we chose {context} tokens")]);
+        assert!(prompt.starts_with("Focus on decisions and rationale.\nConversation:\n"));
+        assert!(prompt.contains("we chose {context} tokens"));
+        assert!(!prompt.contains("{events}"));
+        assert!(!prompt.contains("Table of Contents"));
+
+        let with_context =
+            summarizer.build_events_prompt(&[event("earlier talk")], &[event("main talk")]);
+        assert!(
+            with_context.find("earlier talk").unwrap() < with_context.find("main talk").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_custom_rollup_template_substitutes_children() {
+        let config = ApiSummarizerConfig::claude("test-key", "claude-3-haiku-20240307")
+            .with_rollup_prompt_template("Merge these:\n{child_summaries}");
+        let summarizer = ApiSummarizer::new(config).unwrap();
+
+        let prompt = summarizer.build_rollup_prompt(&[Summary::new(
+            "Auth work".to_string(),
+            vec!["Added OAuth".to_string()],
+            vec!["auth".to_string()],
+        )]);
+        assert!(prompt.starts_with("Merge these:\n### Summary 1\nTitle: Auth work"));
+        assert!(!prompt.contains("{child_summaries}"));
+    }
+
+    #[tokio::test]
+    async fn test_generic_path_rejects_empty_input() {
+        let summarizer =
+            ApiSummarizer::new(ApiSummarizerConfig::openai("test-key", "gpt-4o-mini")).unwrap();
+        assert!(matches!(
+            summarizer.summarize_events_generic(&[]).await,
+            Err(SummarizerError::NoEvents)
+        ));
+        assert!(matches!(
+            summarizer.summarize_children_generic(&[]).await,
+            Err(SummarizerError::NoEvents)
+        ));
+    }
+
+    #[test]
+    fn test_template_missing_placeholder_rejected() {
+        let config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini")
+            .with_prompt_template("Summarize the conversation.");
+        assert!(matches!(
+            ApiSummarizer::new(config),
+            Err(SummarizerError::ConfigError(_))
+        ));
+
+        let config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini")
+            .with_rollup_prompt_template("Merge {events}");
+        assert!(matches!(
+            config.validate(),
+            Err(SummarizerError::ConfigError(msg)) if msg.contains("{child_summaries}")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_openai_falls_back_to_json_object_on_400() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(
+                serde_json::json!({"response_format": {"type": "json_schema"}}),
+            ))
+            .respond_with(ResponseTemplate::new(400).set_body_string("json_schema not supported"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(
+                serde_json::json!({"response_format": {"type": "json_object"}}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{"message": {"content": "{\"title\": \"Fixed\"}"}}]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let mut config = ApiSummarizerConfig::openai("test-key", "gpt-4o-mini");
+        config.base_url = server.uri();
+        let summarizer = ApiSummarizer::new(config).unwrap();
+
+        // The first call falls back; the second skips the schema entirely
+        for _ in 0..2 {
+            let content = summarizer.make_openai_request("Return JSON").await.unwrap();
+            assert_eq!(content, "{\"title\": \"Fixed\"}");
+        }
+    }
 }
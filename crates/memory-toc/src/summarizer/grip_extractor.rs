@@ -2,7 +2,8 @@
 //!
 //! Per SUMM-03: Extracts key excerpts and creates grips during summarization.
 
-use memory_types::{Event, Grip};
+use memory_types::{Event, Grip, GripProvenance};
+use tracing::debug;
 
 use crate::grip_id::generate_grip_id;
 
@@ -13,6 +14,8 @@ pub struct GripExtractorConfig {
     pub max_excerpt_length: usize,
     /// Minimum text length to consider for extraction
     pub min_text_length: usize,
+    /// Grips scoring below this confidence (0.0-1.0) are dropped
+    pub min_confidence: f32,
 }
 
 impl Default for GripExtractorConfig {
@@ -20,6 +23,7 @@ impl Default for GripExtractorConfig {
         Self {
             max_excerpt_length: 200,
             min_text_length: 20,
+            min_confidence: 0.25,
         }
     }
 }
@@ -31,29 +35,41 @@ pub struct ExtractedGrip {
     pub grip: Grip,
     /// Index of the bullet this grip supports (if known)
     pub bullet_index: Option<usize>,
+    /// How well the excerpt supports the bullet (0.0-1.0)
+    pub confidence: f32,
 }
 
 /// Extracts grips from events based on bullet points.
 pub struct GripExtractor {
     config: GripExtractorConfig,
+    provenance: GripProvenance,
 }
 
 impl GripExtractor {
     /// Create a new grip extractor with default config.
     pub fn new() -> Self {
-        Self {
-            config: GripExtractorConfig::default(),
-        }
+        Self::with_config(GripExtractorConfig::default())
     }
 
     /// Create with custom config.
     pub fn with_config(config: GripExtractorConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            provenance: GripProvenance::default(),
+        }
+    }
+
+    /// Record `provenance` on every extracted grip.
+    pub fn with_provenance(mut self, provenance: GripProvenance) -> Self {
+        self.provenance = provenance;
+        self
     }
 
     /// Extract grips from events based on bullet points.
     ///
     /// For each bullet, finds events that best support it and creates a grip.
+    /// Grips are returned ordered by source event start time, then grip ID,
+    /// regardless of bullet order.
     pub fn extract_grips(
         &self,
         events: &[Event],
@@ -67,19 +83,35 @@ impl GripExtractor {
         let mut grips = Vec::new();
 
         for (bullet_idx, bullet) in bullets.iter().enumerate() {
-            if let Some(grip) = self.find_best_match(events, bullet, source) {
+            if let Some((grip, confidence)) = self.find_best_match(events, bullet, source) {
+                if confidence < self.config.min_confidence {
+                    debug!(
+                        bullet_index = bullet_idx,
+                        confidence, "Dropping low-confidence grip"
+                    );
+                    continue;
+                }
                 grips.push(ExtractedGrip {
                     grip,
                     bullet_index: Some(bullet_idx),
+                    confidence,
                 });
             }
         }
 
+        grips.sort_by(|a, b| {
+            a.grip
+                .timestamp
+                .cmp(&b.grip.timestamp)
+                .then_with(|| a.grip.grip_id.cmp(&b.grip.grip_id))
+        });
         grips
     }
 
     /// Find the best matching events for a bullet point.
-    fn find_best_match(&self, events: &[Event], bullet: &str, source: &str) -> Option<Grip> {
+    ///
+    /// Returns the grip with its confidence score.
+    fn find_best_match(&self, events: &[Event], bullet: &str, source: &str) -> Option<(Grip, f32)> {
         // Extract key terms from bullet
         let key_terms: Vec<&str> = bullet.split_whitespace().filter(|w| w.len() > 3).collect();
 
@@ -89,20 +121,17 @@ impl GripExtractor {
 
         // Score each event based on term overlap
         let mut best_match: Option<(usize, usize, f32)> = None; // (start_idx, end_idx, score)
+        let mut matching = vec![false; events.len()];
 
         for (idx, event) in events.iter().enumerate() {
             if event.text.len() < self.config.min_text_length {
                 continue;
             }
 
-            let text_lower = event.text.to_lowercase();
-            let score: f32 = key_terms
-                .iter()
-                .filter(|term| text_lower.contains(&term.to_lowercase()))
-                .count() as f32
-                / key_terms.len() as f32;
+            let score = term_overlap(&key_terms, &event.text);
 
             if score > 0.3 {
+                matching[idx] = true;
                 // At least 30% term match
                 match &best_match {
                     Some((start, _, best_score)) if score > *best_score => {
@@ -121,13 +150,14 @@ impl GripExtractor {
         }
 
         best_match.map(|(start_idx, end_idx, _)| {
+            let span = &events[start_idx..=end_idx];
             let start_event = &events[start_idx];
             let end_event = &events[end_idx];
 
             // Create excerpt from the matching event(s)
-            let excerpt = self.create_excerpt(&events[start_idx..=end_idx]);
+            let excerpt = self.create_excerpt(span);
 
-            Grip::new(
+            let grip = Grip::new(
                 generate_grip_id(start_event.timestamp),
                 excerpt,
                 start_event.event_id.clone(),
@@ -135,6 +165,9 @@ impl GripExtractor {
                 start_event.timestamp,
                 source.to_string(),
             )
+            .with_provenance(self.provenance.clone());
+            let confidence = grip_confidence(&key_terms, span, &matching[start_idx..=end_idx]);
+            (grip, confidence)
         })
     }
 
@@ -154,6 +187,34 @@ impl GripExtractor {
     }
 }
 
+/// Fraction of `key_terms` found in `text`, ignoring case.
+fn term_overlap(key_terms: &[&str], text: &str) -> f32 {
+    let text_lower = text.to_lowercase();
+    key_terms
+        .iter()
+        .filter(|term| text_lower.contains(&term.to_lowercase()))
+        .count() as f32
+        / key_terms.len() as f32
+}
+
+/// Confidence that a span of events supports a bullet, in [0.0, 1.0].
+///
+/// Overlap is the fraction of bullet terms found anywhere in the span.
+/// Coherence is the fraction of events in the span that matched the bullet
+/// on their own; a span stretched across unrelated events scores lower.
+/// Overlap dominates: a fully coherent span is worth its overlap, a span of
+/// mostly filler half of it.
+fn grip_confidence(key_terms: &[&str], span: &[Event], matching: &[bool]) -> f32 {
+    let text: String = span
+        .iter()
+        .map(|e| e.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let overlap = term_overlap(key_terms, &text);
+    let coherence = matching.iter().filter(|m| **m).count() as f32 / span.len() as f32;
+    (overlap * (0.5 + 0.5 * coherence)).clamp(0.0, 1.0)
+}
+
 impl Default for GripExtractor {
     fn default() -> Self {
         Self::new()
@@ -221,6 +282,7 @@ mod tests {
         let extractor = GripExtractor::with_config(GripExtractorConfig {
             max_excerpt_length: 50,
             min_text_length: 10,
+            ..Default::default()
         });
 
         let events = vec![
@@ -234,4 +296,68 @@ mod tests {
         assert!(grips[0].grip.excerpt.len() <= 50);
         assert!(grips[0].grip.excerpt.ends_with("..."));
     }
+
+    #[test]
+    fn test_low_confidence_grip_filtered() {
+        let events = vec![
+            create_test_event(
+                "We configured the Redis cache with an LRU eviction policy",
+                1706540400000,
+            ),
+            create_test_event(
+                "Lunch plans for tomorrow are still open for discussion",
+                1706540500000,
+            ),
+            create_test_event("Later the cache metrics looked fine overall", 1706540600000),
+        ];
+        let bullets = vec![
+            "Configured Redis cache eviction policy".to_string(),
+            "Reviewed deployment pipeline cache metrics".to_string(),
+        ];
+
+        let all = GripExtractor::with_config(GripExtractorConfig {
+            min_confidence: 0.0,
+            ..Default::default()
+        })
+        .extract_grips(&events, &bullets, "test");
+        assert_eq!(all.len(), 2);
+        for grip in &all {
+            assert!((0.0..=1.0).contains(&grip.confidence));
+        }
+        assert!(all[0].confidence > all[1].confidence);
+
+        let filtered = GripExtractor::with_config(GripExtractorConfig {
+            min_confidence: 0.5,
+            ..Default::default()
+        })
+        .extract_grips(&events, &bullets, "test");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].bullet_index, Some(0));
+        assert!(filtered[0].grip.excerpt.contains("Redis"));
+    }
+
+    #[test]
+    fn test_grips_ordered_by_source_time() {
+        let events = vec![
+            create_test_event("Started with the database schema migration", 1706540400000),
+            create_test_event("Then wrote the API endpoint handlers", 1706540500000),
+            create_test_event("Finally deployed everything to staging", 1706540600000),
+        ];
+        // Bullets in reverse chronological order
+        let bullets = vec![
+            "Deployed everything to staging".to_string(),
+            "Wrote endpoint handlers".to_string(),
+            "Database schema migration".to_string(),
+        ];
+
+        let grips = extract_grips(&events, &bullets, "test");
+        let starts: Vec<&str> = grips
+            .iter()
+            .map(|g| g.grip.event_id_start.as_str())
+            .collect();
+        let expected: Vec<&str> = events.iter().map(|e| e.event_id.as_str()).collect();
+        assert_eq!(starts, expected);
+        let bullet_order: Vec<Option<usize>> = grips.iter().map(|g| g.bullet_index).collect();
+        assert_eq!(bullet_order, vec![Some(2), Some(1), Some(0)]);
+    }
 }
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 
 use memory_types::Event;
 
-use super::{Summarizer, SummarizerError, Summary};
+use super::{GenericSummarizer, Summarizer, SummarizerError, Summary};
 
 /// Mock summarizer that generates deterministic summaries.
 ///
@@ -36,9 +36,8 @@ impl Default for MockSummarizer {
     }
 }
 
-#[async_trait]
-impl Summarizer for MockSummarizer {
-    async fn summarize_events(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
+impl GenericSummarizer for MockSummarizer {
+    async fn summarize_events_generic(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
         if events.is_empty() {
             return Err(SummarizerError::NoEvents);
         }
@@ -61,7 +60,10 @@ impl Summarizer for MockSummarizer {
         Ok(Summary::new(title, bullets, keywords))
     }
 
-    async fn summarize_children(&self, summaries: &[Summary]) -> Result<Summary, SummarizerError> {
+    async fn summarize_children_generic(
+        &self,
+        summaries: &[Summary],
+    ) -> Result<Summary, SummarizerError> {
         if summaries.is_empty() {
             return Err(SummarizerError::NoEvents);
         }
@@ -86,6 +88,21 @@ impl Summarizer for MockSummarizer {
     }
 }
 
+#[async_trait]
+impl Summarizer for MockSummarizer {
+    async fn summarize_events(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
+        self.summarize_events_generic(events).await
+    }
+
+    async fn summarize_children(&self, summaries: &[Summary]) -> Result<Summary, SummarizerError> {
+        self.summarize_children_generic(summaries).await
+    }
+
+    fn summarizer_id(&self) -> String {
+        "mock".to_string()
+    }
+}
+
 /// Truncate text to max length, adding "..." if truncated.
 fn truncate(text: &str, max_len: usize) -> String {
     if text.len() <= max_len {
@@ -8,10 +8,14 @@
 mod api;
 mod grip_extractor;
 mod mock;
+mod output;
 
 pub use api::{ApiSummarizer, ApiSummarizerConfig};
 pub use grip_extractor::{extract_grips, ExtractedGrip, GripExtractor, GripExtractorConfig};
 pub use mock::MockSummarizer;
+pub use output::{parse_summary_output, summary_json_schema, MAX_BULLETS, MAX_KEYWORDS};
+
+use std::future::Future;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -86,10 +90,70 @@ pub trait Summarizer: Send + Sync {
     /// Per SUMM-02: Generates title, bullets, keywords.
     async fn summarize_events(&self, events: &[Event]) -> Result<Summary, SummarizerError>;
 
+    /// Generate a summary of `events`, primed with earlier `context` events.
+    ///
+    /// Per TOC-04: `context` is the previous segment's tail. It helps with
+    /// continuity but is not part of the content being summarized.
+    /// The default implementation ignores it.
+    async fn summarize_events_with_context(
+        &self,
+        context: &[Event],
+        events: &[Event],
+    ) -> Result<Summary, SummarizerError> {
+        let _ = context;
+        self.summarize_events(events).await
+    }
+
     /// Generate a rollup summary from child summaries.
     ///
     /// Per SUMM-04: Aggregates child node summaries for parent TOC nodes.
     async fn summarize_children(&self, summaries: &[Summary]) -> Result<Summary, SummarizerError>;
+
+    /// Identifier recorded in the provenance of grips this summarizer produces.
+    ///
+    /// Empty by default, which records the grips' producer as unknown.
+    fn summarizer_id(&self) -> String {
+        String::new()
+    }
+
+    /// Version recorded in grip provenance alongside [`summarizer_id`](Self::summarizer_id).
+    fn summarizer_version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+}
+
+/// Summarizer whose futures are returned without boxing.
+///
+/// `#[async_trait]` boxes every future returned through [`Summarizer`], which
+/// adds an allocation per call in tight rollup loops. Code that knows the
+/// concrete summarizer type can call these methods instead. `dyn Summarizer`
+/// implements this trait by forwarding to the boxed methods, so generic code
+/// still accepts trait objects.
+pub trait GenericSummarizer: Send + Sync {
+    /// Unboxed form of [`Summarizer::summarize_events`].
+    fn summarize_events_generic(
+        &self,
+        events: &[Event],
+    ) -> impl Future<Output = Result<Summary, SummarizerError>> + Send;
+
+    /// Unboxed form of [`Summarizer::summarize_children`].
+    fn summarize_children_generic(
+        &self,
+        summaries: &[Summary],
+    ) -> impl Future<Output = Result<Summary, SummarizerError>> + Send;
+}
+
+impl GenericSummarizer for dyn Summarizer {
+    async fn summarize_events_generic(&self, events: &[Event]) -> Result<Summary, SummarizerError> {
+        self.summarize_events(events).await
+    }
+
+    async fn summarize_children_generic(
+        &self,
+        summaries: &[Summary],
+    ) -> Result<Summary, SummarizerError> {
+        self.summarize_children(summaries).await
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +197,43 @@ mod tests {
 
         assert_eq!(summary.title, decoded.title);
     }
+
+    #[tokio::test]
+    async fn test_generic_path_matches_trait_object() {
+        use chrono::Utc;
+        use memory_types::{EventRole, EventType};
+        use std::sync::Arc;
+
+        let events: Vec<Event> = ["Set up the rollup scheduler", "Checkpoint after each node"]
+            .iter()
+            .map(|text| {
+                Event::new(
+                    ulid::Ulid::new().to_string(),
+                    "session-123".to_string(),
+                    Utc::now(),
+                    EventType::UserMessage,
+                    EventRole::User,
+                    text.to_string(),
+                )
+            })
+            .collect();
+        let concrete = MockSummarizer::new();
+        let dynamic: Arc<dyn Summarizer> = Arc::new(MockSummarizer::new());
+
+        let generic = concrete.summarize_events_generic(&events).await.unwrap();
+        let boxed = dynamic.summarize_events(&events).await.unwrap();
+        assert_eq!(generic.title, boxed.title);
+        assert_eq!(generic.bullets, boxed.bullets);
+        assert_eq!(generic.keywords, boxed.keywords);
+
+        let children = vec![generic, boxed];
+        let generic = concrete
+            .summarize_children_generic(&children)
+            .await
+            .unwrap();
+        let boxed = dynamic.summarize_children(&children).await.unwrap();
+        assert_eq!(generic.title, boxed.title);
+        assert_eq!(generic.bullets, boxed.bullets);
+        assert_eq!(generic.keywords, boxed.keywords);
+    }
 }
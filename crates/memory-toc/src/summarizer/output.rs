@@ -0,0 +1,339 @@
+//! Parsing of LLM summary output.
+//!
+//! Per SUMM-02: summaries carry a title, 3-5 bullets and 3-7 keywords.
+//! Models are asked for strict JSON matching [`summary_json_schema`], but
+//! free-form replies still happen: prose around the object, markdown
+//! fences, trailing commas, or no JSON at all. [`parse_summary_output`]
+//! recovers from these in increasing order of guesswork and only errors
+//! when nothing usable is left.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{SummarizerError, Summary};
+
+/// Most bullets kept from a summary.
+pub const MAX_BULLETS: usize = 5;
+
+/// Most keywords kept from a summary.
+pub const MAX_KEYWORDS: usize = 7;
+
+/// JSON schema of the summary object requested from the model.
+///
+/// Item counts are left out because strict structured-output modes reject
+/// `minItems`/`maxItems`; the prompt asks for them and the parser caps them.
+pub fn summary_json_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "title": { "type": "string" },
+            "bullets": { "type": "array", "items": { "type": "string" } },
+            "keywords": { "type": "array", "items": { "type": "string" } }
+        },
+        "required": ["title", "bullets", "keywords"],
+        "additionalProperties": false
+    })
+}
+
+/// Summary fields as the model may send them; missing arrays are tolerated.
+#[derive(Deserialize)]
+struct RawSummary {
+    title: String,
+    #[serde(default)]
+    bullets: Vec<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+}
+
+/// Parse a model reply into a [`Summary`].
+///
+/// Tries, in order: the JSON object found in the reply (inside a markdown
+/// fence or surrounded by prose), the same object with trailing commas
+/// removed, and a line-based extraction of title, bullets and keywords.
+/// Bullets and keywords are trimmed, emptied entries dropped, and capped at
+/// [`MAX_BULLETS`] and [`MAX_KEYWORDS`].
+pub fn parse_summary_output(text: &str) -> Result<Summary, SummarizerError> {
+    let json = extract_json(text);
+
+    let raw = serde_json::from_str::<RawSummary>(&json)
+        .or_else(|_| serde_json::from_str::<RawSummary>(&strip_trailing_commas(&json)))
+        .ok()
+        .or_else(|| best_effort(text));
+
+    match raw {
+        Some(raw) if !raw.title.trim().is_empty() => Ok(normalize(raw)),
+        _ => Err(SummarizerError::ParseError(format!(
+            "No summary found in response: {}",
+            text.chars().take(200).collect::<String>()
+        ))),
+    }
+}
+
+/// Extract the JSON object from text (handles markdown code blocks).
+///
+/// Returns the first balanced `{...}` object, skipping braces inside
+/// strings, so prose after the object is ignored.
+pub(crate) fn extract_json(text: &str) -> String {
+    // Prefer the contents of a markdown code block
+    let body = fenced_block(text).unwrap_or(text);
+
+    let Some(start) = body.find('{') else {
+        return body.trim().to_string();
+    };
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in body[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return body[start..=start + i].to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Unbalanced: hand back everything from the first brace
+    body[start..].trim().to_string()
+}
+
+/// Contents of the first markdown code block, if any.
+fn fenced_block(text: &str) -> Option<&str> {
+    let start = text.find("```")?;
+    let after = &text[start + 3..];
+    let after = after.strip_prefix("json").unwrap_or(after);
+    let end = after.find("```")?;
+    Some(after[..end].trim())
+}
+
+/// Remove commas directly before a closing `}` or `]`, outside strings.
+fn strip_trailing_commas(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let chars: Vec<char> = json.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Line-based extraction for replies that are not JSON at all.
+///
+/// Recognizes `title:` / `keywords:` lines (with or without quotes) and
+/// list items starting with `-`, `*`, `•` or `1.`. Without a title line,
+/// the first non-list line is used.
+fn best_effort(text: &str) -> Option<RawSummary> {
+    let mut title = None;
+    let mut bullets = Vec::new();
+    let mut keywords = Vec::new();
+    let mut first_line = None;
+
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(value) = field_value(line, "title") {
+            title.get_or_insert(value);
+        } else if let Some(value) = field_value(line, "keywords") {
+            keywords.extend(value.split(',').map(|k| unquote(k).to_string()));
+        } else if let Some(item) = list_item(line) {
+            bullets.push(unquote(item).to_string());
+        } else if field_value(line, "bullets").is_none() {
+            first_line.get_or_insert_with(|| line.to_string());
+        }
+    }
+
+    let title = title.or(first_line)?;
+    if bullets.is_empty() && keywords.is_empty() {
+        return None;
+    }
+    Some(RawSummary {
+        title,
+        bullets,
+        keywords,
+    })
+}
+
+/// Value of a `name: value` line, ignoring case, quotes and brackets.
+fn field_value(line: &str, name: &str) -> Option<String> {
+    let line = line.trim_start_matches(['#', '*', ' ', '"']);
+    let (key, value) = line.split_once(':')?;
+    if !key.trim_end_matches(['"', '*']).eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let value = value
+        .trim_start_matches(['*', ' ', '['])
+        .trim_end_matches(',')
+        .trim_end_matches(']');
+    Some(unquote(value).to_string())
+}
+
+/// Text of a markdown list item, if `line` is one.
+fn list_item(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "• "] {
+        if let Some(item) = line.strip_prefix(marker) {
+            return Some(item);
+        }
+    }
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0 {
+        return line[digits..].strip_prefix(". ");
+    }
+    None
+}
+
+fn unquote(s: &str) -> &str {
+    s.trim().trim_end_matches(',').trim_matches('"').trim()
+}
+
+/// Trim entries, drop empty ones and apply the bullet/keyword caps.
+fn normalize(raw: RawSummary) -> Summary {
+    let clean = |items: Vec<String>, max: usize| -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for item in items.into_iter().map(|s| s.trim().to_string()) {
+            if !item.is_empty() && !out.contains(&item) {
+                out.push(item);
+            }
+        }
+        out.truncate(max);
+        out
+    };
+
+    Summary::new(
+        raw.title.trim().to_string(),
+        clean(raw.bullets, MAX_BULLETS),
+        clean(raw.keywords, MAX_KEYWORDS),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_json_plain() {
+        let text = r#"{"title": "Test", "bullets": [], "keywords": []}"#;
+        let json = extract_json(text);
+        assert_eq!(json, text);
+    }
+
+    #[test]
+    fn test_extract_json_code_block() {
+        let text = r#"Here's the summary:
+```json
+{"title": "Test", "bullets": [], "keywords": []}
+```"#;
+        let json = extract_json(text);
+        assert!(json.contains("Test"));
+    }
+
+    #[test]
+    fn test_extract_json_with_prefix() {
+        let text = r#"Sure! Here's your summary: {"title": "Test", "bullets": [], "keywords": []}"#;
+        let json = extract_json(text);
+        assert!(json.starts_with('{'));
+        assert!(json.ends_with('}'));
+    }
+
+    #[test]
+    fn test_extract_json_stops_at_balanced_close() {
+        let text = r#"{"title": "Uses {braces} inside", "bullets": []} Hope that helps {:}"#;
+        assert_eq!(
+            extract_json(text),
+            r#"{"title": "Uses {braces} inside", "bullets": []}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_clean_json() {
+        let text = r#"{"title": "Auth refactor", "bullets": ["Moved to JWT", "Dropped sessions", "Added tests"], "keywords": ["auth", "jwt", "tests"]}"#;
+        let summary = parse_summary_output(text).unwrap();
+        assert_eq!(summary.title, "Auth refactor");
+        assert_eq!(summary.bullets.len(), 3);
+        assert_eq!(summary.keywords, vec!["auth", "jwt", "tests"]);
+    }
+
+    #[test]
+    fn test_parse_fenced_json() {
+        let text = "Here you go:\n```json\n{\"title\": \"Deploy fix\", \"bullets\": [\"Pinned version\"], \"keywords\": [\"deploy\"]}\n```\nLet me know!";
+        let summary = parse_summary_output(text).unwrap();
+        assert_eq!(summary.title, "Deploy fix");
+        assert_eq!(summary.bullets, vec!["Pinned version"]);
+    }
+
+    #[test]
+    fn test_parse_repairs_trailing_commas() {
+        let text = r#"Summary: {
+  "title": "Cache tuning",
+  "bullets": ["Raised TTL", "Added metrics", "Fixed, finally",],
+  "keywords": ["cache", "ttl", "metrics",],
+}"#;
+        let summary = parse_summary_output(text).unwrap();
+        assert_eq!(summary.title, "Cache tuning");
+        assert_eq!(summary.bullets[2], "Fixed, finally");
+        assert_eq!(summary.keywords.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_best_effort_from_prose() {
+        let text = "Title: Database migration\n\nKey points:\n- Added index on events\n- Backfilled rows\n2. Verified counts\n\nKeywords: database, migration, index";
+        let summary = parse_summary_output(text).unwrap();
+        assert_eq!(summary.title, "Database migration");
+        assert_eq!(
+            summary.bullets,
+            vec![
+                "Added index on events",
+                "Backfilled rows",
+                "Verified counts"
+            ]
+        );
+        assert_eq!(summary.keywords, vec!["database", "migration", "index"]);
+    }
+
+    #[test]
+    fn test_parse_caps_bullets_and_keywords() {
+        let bullets: Vec<String> = (0..8).map(|i| format!("point {i}")).collect();
+        let keywords: Vec<String> = (0..10).map(|i| format!("kw{i}")).collect();
+        let text = json!({ "title": "Long", "bullets": bullets, "keywords": keywords }).to_string();
+        let summary = parse_summary_output(&text).unwrap();
+        assert_eq!(summary.bullets.len(), MAX_BULLETS);
+        assert_eq!(summary.keywords.len(), MAX_KEYWORDS);
+    }
+
+    #[test]
+    fn test_parse_unrecoverable_errors() {
+        assert!(matches!(
+            parse_summary_output("I'm sorry, I can't help with that."),
+            Err(SummarizerError::ParseError(_))
+        ));
+        assert!(parse_summary_output("").is_err());
+    }
+}
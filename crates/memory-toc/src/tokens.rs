@@ -0,0 +1,361 @@
+//! Token counting for segmentation, grip expansion and summarizer budgets.
+//!
+//! All token budgets in this crate go through the [`TokenCounter`] trait so
+//! that segment boundaries, expansion windows and summarizer prompts agree
+//! with each other. With the `tiktoken` feature (default) the counter matches
+//! the cl100k BPE tokenizer; otherwise a character heuristic is used.
+//!
+//! Tool results are cut down before counting, by bytes or optionally by
+//! tokens. Truncation never splits a UTF-8 character.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use memory_types::{Event, EventType};
+
+/// Counts tokens in text and events.
+pub trait TokenCounter: fmt::Debug + Send + Sync {
+    /// Count tokens in raw text.
+    fn count_text(&self, text: &str) -> usize;
+
+    /// Maximum chars of a tool result that are counted.
+    fn max_tool_result_chars(&self) -> usize;
+
+    /// Maximum tokens of a tool result that are counted.
+    ///
+    /// When set, this replaces `max_tool_result_chars`.
+    fn max_tool_result_tokens(&self) -> Option<usize> {
+        None
+    }
+
+    /// Part of a tool result that is kept, cut at a character boundary.
+    fn tool_result_prefix<'a>(&self, text: &'a str) -> &'a str {
+        match self.max_tool_result_tokens() {
+            Some(max_tokens) => truncate_tokens(self, text, max_tokens),
+            None => truncate_chars(text, self.max_tool_result_chars()),
+        }
+    }
+
+    /// Truncate a tool result to the configured limit.
+    ///
+    /// Text that fits is returned unchanged; otherwise the kept prefix is
+    /// followed by a `[truncated N chars]` marker.
+    fn truncate_tool_result<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let prefix = self.tool_result_prefix(text);
+        if prefix.len() == text.len() {
+            return Cow::Borrowed(text);
+        }
+        let dropped = text[prefix.len()..].chars().count();
+        Cow::Owned(format!("{} [truncated {} chars]", prefix, dropped))
+    }
+
+    /// Count tokens in event text.
+    ///
+    /// Truncates tool results to avoid token explosion.
+    fn count_event(&self, event: &Event) -> usize {
+        if event.event_type == EventType::ToolResult {
+            self.count_text(self.tool_result_prefix(&event.text))
+        } else {
+            self.count_text(&event.text)
+        }
+    }
+
+    /// Count tokens in multiple events.
+    fn count_events(&self, events: &[Event]) -> usize {
+        events.iter().map(|e| self.count_event(e)).sum()
+    }
+}
+
+/// Rough token estimate of ~4 chars per token.
+#[derive(Debug, Clone)]
+pub struct HeuristicTokenCounter {
+    /// Maximum chars for tool results
+    max_tool_result_chars: usize,
+    /// Maximum tokens for tool results, replacing the char limit
+    max_tool_result_tokens: Option<usize>,
+}
+
+impl HeuristicTokenCounter {
+    pub fn new(max_tool_result_chars: usize) -> Self {
+        Self {
+            max_tool_result_chars,
+            max_tool_result_tokens: None,
+        }
+    }
+
+    /// Truncate tool results by tokens instead of chars.
+    pub fn with_max_tool_result_tokens(mut self, max_tokens: Option<usize>) -> Self {
+        self.max_tool_result_tokens = max_tokens;
+        self
+    }
+}
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn count_text(&self, text: &str) -> usize {
+        (text.len() / 4).max(1)
+    }
+
+    fn max_tool_result_chars(&self) -> usize {
+        self.max_tool_result_chars
+    }
+
+    fn max_tool_result_tokens(&self) -> Option<usize> {
+        self.max_tool_result_tokens
+    }
+}
+
+/// Token counter matching the cl100k BPE tokenizer (GPT-4 family).
+///
+/// Falls back to the heuristic if the tokenizer cannot be loaded.
+#[cfg(feature = "tiktoken")]
+#[derive(Clone)]
+pub struct TiktokenCounter {
+    bpe: Option<Arc<tiktoken_rs::CoreBPE>>,
+    fallback: HeuristicTokenCounter,
+}
+
+#[cfg(feature = "tiktoken")]
+impl TiktokenCounter {
+    pub fn new(max_tool_result_chars: usize) -> Self {
+        let bpe = match tiktoken_rs::cl100k_base() {
+            Ok(bpe) => Some(Arc::new(bpe)),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load cl100k tokenizer, using heuristic");
+                None
+            }
+        };
+        Self {
+            bpe,
+            fallback: HeuristicTokenCounter::new(max_tool_result_chars),
+        }
+    }
+
+    /// Truncate tool results by tokens instead of chars.
+    pub fn with_max_tool_result_tokens(mut self, max_tokens: Option<usize>) -> Self {
+        self.fallback = self.fallback.with_max_tool_result_tokens(max_tokens);
+        self
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl fmt::Debug for TiktokenCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TiktokenCounter")
+            .field("loaded", &self.bpe.is_some())
+            .field(
+                "max_tool_result_chars",
+                &self.fallback.max_tool_result_chars,
+            )
+            .field(
+                "max_tool_result_tokens",
+                &self.fallback.max_tool_result_tokens,
+            )
+            .finish()
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl TokenCounter for TiktokenCounter {
+    fn count_text(&self, text: &str) -> usize {
+        match &self.bpe {
+            Some(bpe) => bpe.encode_with_special_tokens(text).len(),
+            None => self.fallback.count_text(text),
+        }
+    }
+
+    fn max_tool_result_chars(&self) -> usize {
+        self.fallback.max_tool_result_chars
+    }
+
+    fn max_tool_result_tokens(&self) -> Option<usize> {
+        self.fallback.max_tool_result_tokens
+    }
+}
+
+/// Create the default token counter for this build.
+///
+/// Uses [`TiktokenCounter`] with the `tiktoken` feature, otherwise
+/// [`HeuristicTokenCounter`].
+pub fn default_token_counter(max_tool_result_chars: usize) -> Arc<dyn TokenCounter> {
+    default_token_counter_with_tool_tokens(max_tool_result_chars, None)
+}
+
+/// Create the default token counter, truncating tool results by tokens
+/// when `max_tool_result_tokens` is set.
+pub fn default_token_counter_with_tool_tokens(
+    max_tool_result_chars: usize,
+    max_tool_result_tokens: Option<usize>,
+) -> Arc<dyn TokenCounter> {
+    #[cfg(feature = "tiktoken")]
+    {
+        Arc::new(
+            TiktokenCounter::new(max_tool_result_chars)
+                .with_max_tool_result_tokens(max_tool_result_tokens),
+        )
+    }
+    #[cfg(not(feature = "tiktoken"))]
+    {
+        Arc::new(
+            HeuristicTokenCounter::new(max_tool_result_chars)
+                .with_max_tool_result_tokens(max_tool_result_tokens),
+        )
+    }
+}
+
+/// Truncate to at most `max` bytes without splitting a UTF-8 character.
+pub(crate) fn truncate_chars(text: &str, max: usize) -> &str {
+    if text.len() <= max {
+        return text;
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// Longest prefix of `text` within `max_tokens`, ending on a character boundary.
+fn truncate_tokens<'a, C: TokenCounter + ?Sized>(
+    counter: &C,
+    text: &'a str,
+    max_tokens: usize,
+) -> &'a str {
+    if counter.count_text(text) <= max_tokens {
+        return text;
+    }
+    let boundaries: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+
+    // Binary search for the last boundary whose prefix fits; the whole
+    // text is known not to fit
+    let (mut lo, mut hi) = (0, boundaries.len());
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if counter.count_text(&text[..boundaries[mid]]) <= max_tokens {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    &text[..boundaries[lo]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use memory_types::EventRole;
+
+    fn event(text: &str, event_type: EventType) -> Event {
+        Event::new(
+            ulid::Ulid::new().to_string(),
+            "session-123".to_string(),
+            Utc::now(),
+            event_type,
+            EventRole::User,
+            text.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_heuristic_counts() {
+        let counter = HeuristicTokenCounter::new(1000);
+        assert_eq!(counter.count_text("hello world"), 2);
+        assert_eq!(
+            counter.count_text("The quick brown fox jumps over the lazy dog."),
+            11
+        );
+        // Never zero, even for empty text
+        assert_eq!(counter.count_text(""), 1);
+    }
+
+    #[test]
+    fn test_tool_result_truncation() {
+        let counter = HeuristicTokenCounter::new(100);
+        let result = event(&"x".repeat(1000), EventType::ToolResult);
+        let message = event(&"x".repeat(1000), EventType::UserMessage);
+
+        assert_eq!(counter.count_event(&result), 25);
+        assert_eq!(counter.count_event(&message), 250);
+        assert_eq!(counter.count_events(&[result, message]), 275);
+    }
+
+    #[test]
+    fn test_truncation_respects_char_boundaries() {
+        let counter = HeuristicTokenCounter::new(5);
+        // Each 'é' is two bytes; byte 5 falls inside the third one
+        let result = event("ééééé", EventType::ToolResult);
+        assert_eq!(counter.count_event(&result), 1);
+        assert_eq!(truncate_chars("ééééé", 5), "éé");
+    }
+
+    #[test]
+    fn test_truncate_tool_result_marks_dropped_chars() {
+        let counter = HeuristicTokenCounter::new(8);
+        // Each 'é' is two bytes; byte 8 falls inside the fourth one
+        let text = "aéééééé";
+        let truncated = counter.truncate_tool_result(text);
+
+        assert!(std::str::from_utf8(truncated.as_bytes()).is_ok());
+        assert_eq!(truncated, "aééé [truncated 3 chars]");
+
+        // Text within the limit is returned untouched
+        assert!(matches!(
+            counter.truncate_tool_result("short"),
+            Cow::Borrowed("short")
+        ));
+    }
+
+    #[test]
+    fn test_truncate_tool_result_by_tokens() {
+        let counter = HeuristicTokenCounter::new(1000).with_max_tool_result_tokens(Some(2));
+        assert_eq!(counter.max_tool_result_tokens(), Some(2));
+
+        // 2 tokens cover 8-11 bytes under the heuristic; "日" is 3 bytes
+        let text = "日本語のテキストです";
+        let truncated = counter.truncate_tool_result(text);
+        assert!(std::str::from_utf8(truncated.as_bytes()).is_ok());
+        assert_eq!(truncated, "日本語 [truncated 7 chars]");
+
+        // The token limit replaces the char limit when counting
+        let result = event(&"x".repeat(1000), EventType::ToolResult);
+        assert_eq!(counter.count_event(&result), 2);
+    }
+
+    #[cfg(feature = "tiktoken")]
+    #[test]
+    fn test_tiktoken_known_counts() {
+        let counter = TiktokenCounter::new(1000);
+        assert_eq!(counter.count_text("hello world"), 2);
+        assert_eq!(counter.count_text("Hello, world!"), 4);
+        assert_eq!(counter.count_text("tiktoken is great!"), 6);
+        assert_eq!(
+            counter.count_text("The quick brown fox jumps over the lazy dog."),
+            10
+        );
+    }
+
+    #[cfg(feature = "tiktoken")]
+    #[test]
+    fn test_heuristic_vs_tiktoken() {
+        let heuristic = HeuristicTokenCounter::new(1000);
+        let tiktoken = TiktokenCounter::new(1000);
+
+        // English prose: heuristic is close to the real count
+        let prose = "The quick brown fox jumps over the lazy dog.";
+        assert_eq!(heuristic.count_text(prose), 11);
+        assert_eq!(tiktoken.count_text(prose), 10);
+
+        // Repetitive text compresses well under BPE, so the heuristic overshoots
+        let repeated = "x".repeat(1000);
+        assert_eq!(heuristic.count_text(&repeated), 250);
+        assert!(tiktoken.count_text(&repeated) < 250);
+    }
+
+    #[test]
+    fn test_default_token_counter() {
+        let counter = default_token_counter(100);
+        assert_eq!(counter.max_tool_result_chars(), 100);
+        assert!(counter.count_text("hello world") > 0);
+    }
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::TopicsError;
+
 /// Master configuration for topic functionality.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopicsConfig {
@@ -30,6 +32,13 @@ pub struct TopicsConfig {
     pub lifecycle: LifecycleConfig,
 }
 
+impl TopicsConfig {
+    /// Validate the configuration.
+    pub fn validate(&self) -> Result<(), TopicsError> {
+        self.extraction.validate()
+    }
+}
+
 #[allow(clippy::derivable_impls)]
 impl Default for TopicsConfig {
     fn default() -> Self {
@@ -47,10 +56,20 @@ impl Default for TopicsConfig {
 /// Topic extraction configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractionConfig {
-    /// Minimum cluster size for HDBSCAN
+    /// Minimum cluster size for HDBSCAN (must be at least 2)
     #[serde(default = "default_min_cluster_size")]
     pub min_cluster_size: usize,
 
+    /// Neighbourhood size HDBSCAN uses to estimate density.
+    /// Defaults to `min_cluster_size` when unset; larger values mark more
+    /// points as noise.
+    #[serde(default)]
+    pub min_samples: Option<usize>,
+
+    /// What to do with points HDBSCAN labels as noise
+    #[serde(default)]
+    pub noise_policy: NoisePolicy,
+
     /// Minimum similarity threshold for cluster membership
     #[serde(default = "default_similarity_threshold")]
     pub similarity_threshold: f32,
@@ -68,6 +87,8 @@ impl Default for ExtractionConfig {
     fn default() -> Self {
         Self {
             min_cluster_size: default_min_cluster_size(),
+            min_samples: None,
+            noise_policy: NoisePolicy::default(),
             similarity_threshold: default_similarity_threshold(),
             schedule: default_extraction_schedule(),
             batch_size: default_batch_size(),
@@ -75,6 +96,37 @@ impl Default for ExtractionConfig {
     }
 }
 
+impl ExtractionConfig {
+    /// Validate the extraction settings.
+    pub fn validate(&self) -> Result<(), TopicsError> {
+        if self.min_cluster_size < 2 {
+            return Err(TopicsError::InvalidConfig(format!(
+                "min_cluster_size must be at least 2, got {}",
+                self.min_cluster_size
+            )));
+        }
+        if self.min_samples == Some(0) {
+            return Err(TopicsError::InvalidConfig(
+                "min_samples must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Handling of points HDBSCAN cannot place in any cluster.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoisePolicy {
+    /// Leave noise points out of every topic
+    #[default]
+    Drop,
+    /// Add each noise point to the topic whose centroid it is most similar to
+    NearestTopic,
+    /// Give each noise point a single-node topic of its own
+    OwnTopic,
+}
+
 fn default_min_cluster_size() -> usize {
     3
 }
@@ -237,9 +289,43 @@ mod tests {
     fn test_extraction_defaults() {
         let config = ExtractionConfig::default();
         assert_eq!(config.min_cluster_size, 3);
+        assert_eq!(config.min_samples, None);
+        assert_eq!(config.noise_policy, NoisePolicy::Drop);
         assert!((config.similarity_threshold - 0.75).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_extraction_validate() {
+        assert!(TopicsConfig::default().validate().is_ok());
+
+        let config = ExtractionConfig {
+            min_cluster_size: 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(TopicsError::InvalidConfig(_))
+        ));
+
+        let config = ExtractionConfig {
+            min_samples: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_noise_policy_serde() {
+        let config: ExtractionConfig =
+            serde_json::from_str(r#"{"min_samples": 2, "noise_policy": "nearest_topic"}"#).unwrap();
+        assert_eq!(config.min_samples, Some(2));
+        assert_eq!(config.noise_policy, NoisePolicy::NearestTopic);
+        assert_eq!(
+            serde_json::to_string(&NoisePolicy::OwnTopic).unwrap(),
+            r#""own_topic""#
+        );
+    }
+
     #[test]
     fn test_labeling_defaults() {
         let config = LabelingConfig::default();
@@ -33,6 +33,10 @@ pub enum TopicsError {
     #[error("Embedding error: {0}")]
     Embedding(String),
 
+    /// LLM request error (network, HTTP status, rate limit, bad reply)
+    #[error("LLM error: {0}")]
+    Llm(String),
+
     /// Invalid input
     #[error("Invalid input: {0}")]
     InvalidInput(String),
@@ -4,11 +4,11 @@ use hdbscan::{Hdbscan, HdbscanHyperParams};
 use tracing::{debug, info};
 use ulid::Ulid;
 
-use crate::config::ExtractionConfig;
+use crate::config::{ExtractionConfig, NoisePolicy};
 use crate::error::TopicsError;
 use crate::labeling::{ClusterDocument, TopicLabel, TopicLabeler};
-use crate::similarity::calculate_centroid;
-use crate::types::Topic;
+use crate::similarity::{calculate_centroid, cosine_similarity};
+use crate::types::{Topic, TopicLink};
 
 /// Input for topic extraction: node ID with its embedding.
 #[derive(Debug, Clone)]
@@ -47,8 +47,11 @@ impl TopicExtractor {
 
     /// Cluster embeddings using HDBSCAN.
     ///
-    /// Returns clusters grouped by label. Label -1 indicates noise points.
+    /// Returns clusters grouped by label. Noise points are handled according
+    /// to the configured [`NoisePolicy`]; any left as noise carry label -1.
     pub fn cluster(&self, nodes: &[NodeEmbedding]) -> Result<Vec<ClusterResult>, TopicsError> {
+        self.config.validate()?;
+
         if nodes.len() < self.config.min_cluster_size {
             debug!(
                 count = nodes.len(),
@@ -67,9 +70,12 @@ impl TopicExtractor {
             .collect();
 
         // Create clusterer with custom params
-        let params = HdbscanHyperParams::builder()
-            .min_cluster_size(self.config.min_cluster_size)
-            .build();
+        let mut builder =
+            HdbscanHyperParams::builder().min_cluster_size(self.config.min_cluster_size);
+        if let Some(min_samples) = self.config.min_samples {
+            builder = builder.min_samples(min_samples);
+        }
+        let params = builder.build();
 
         let clusterer = Hdbscan::new(&data, params);
 
@@ -78,12 +84,17 @@ impl TopicExtractor {
             .cluster()
             .map_err(|e| TopicsError::Clustering(e.to_string()))?;
 
+        let noise = labels.iter().filter(|&&l| l < 0).count();
         info!(
             labels = labels.len(),
             unique = count_unique_clusters(&labels),
+            noise,
+            policy = ?self.config.noise_policy,
             "Clustering complete"
         );
 
+        let labels = resolve_noise(nodes, labels, self.config.noise_policy);
+
         // Group nodes by cluster label
         let clusters = group_by_cluster(nodes, &labels);
 
@@ -118,6 +129,36 @@ impl TopicExtractor {
             .collect()
     }
 
+    /// Link new nodes to the existing topics they belong to.
+    ///
+    /// An incremental pass that runs between full clustering cycles: each
+    /// node joins the active topic whose centroid it is most similar to, if
+    /// that similarity reaches `similarity_threshold`. Nodes matching no
+    /// topic are left for the next full extraction.
+    pub fn assign_to_existing(&self, nodes: &[NodeEmbedding], topics: &[Topic]) -> Vec<TopicLink> {
+        let candidates: Vec<&Topic> = topics.iter().filter(|t| t.is_active()).collect();
+
+        nodes
+            .iter()
+            .filter_map(|node| {
+                let (topic, similarity) = candidates
+                    .iter()
+                    .filter(|t| t.embedding.len() == node.embedding.len())
+                    .map(|t| (t, cosine_similarity(&node.embedding, &t.embedding)))
+                    .max_by(|a, b| a.1.total_cmp(&b.1))?;
+                if similarity < self.config.similarity_threshold {
+                    debug!(node_id = %node.node_id, similarity, "No existing topic close enough");
+                    return None;
+                }
+                Some(TopicLink::new(
+                    topic.topic_id.clone(),
+                    node.node_id.clone(),
+                    similarity,
+                ))
+            })
+            .collect()
+    }
+
     /// Get configuration.
     pub fn config(&self) -> &ExtractionConfig {
         &self.config
@@ -176,6 +217,43 @@ fn count_unique_clusters(labels: &[i32]) -> usize {
     unique.len()
 }
 
+/// Relabel noise points (-1) according to `policy`.
+fn resolve_noise(nodes: &[NodeEmbedding], mut labels: Vec<i32>, policy: NoisePolicy) -> Vec<i32> {
+    match policy {
+        NoisePolicy::Drop => {}
+        NoisePolicy::OwnTopic => {
+            let first = labels.iter().copied().max().unwrap_or(-1) + 1;
+            for (next, label) in (first..).zip(labels.iter_mut().filter(|l| **l < 0)) {
+                *label = next;
+            }
+        }
+        NoisePolicy::NearestTopic => {
+            let centroids: Vec<(i32, Vec<f32>)> = group_by_cluster(nodes, &labels)
+                .into_iter()
+                .filter(|c| c.label >= 0)
+                .map(|c| {
+                    let refs: Vec<&[f32]> = c.embeddings.iter().map(|e| e.as_slice()).collect();
+                    (c.label, calculate_centroid(&refs))
+                })
+                .collect();
+
+            for (node, label) in nodes.iter().zip(labels.iter_mut()) {
+                if *label >= 0 {
+                    continue;
+                }
+                let nearest = centroids
+                    .iter()
+                    .map(|(l, centroid)| (*l, cosine_similarity(&node.embedding, centroid)))
+                    .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+                if let Some((nearest, _)) = nearest {
+                    *label = nearest;
+                }
+            }
+        }
+    }
+    labels
+}
+
 /// Group nodes by cluster label.
 fn group_by_cluster(nodes: &[NodeEmbedding], labels: &[i32]) -> Vec<ClusterResult> {
     use std::collections::HashMap;
@@ -226,6 +304,135 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    /// Two tight clusters of five plus three scattered noise points.
+    ///
+    /// Noise points lean slightly towards a cluster so `NearestTopic` has a
+    /// clear answer: noise-0 and noise-2 towards "a", noise-1 towards "b".
+    fn clusters_with_noise() -> Vec<NodeEmbedding> {
+        let mut nodes = Vec::new();
+        for i in 0..5 {
+            let d = i as f32 * 0.01;
+            nodes.push(make_node(&format!("a{i}"), vec![1.0, d, 0.0, 0.0]));
+            nodes.push(make_node(&format!("b{i}"), vec![d, 1.0, 0.0, 0.0]));
+        }
+        nodes.push(make_node("noise-0", vec![0.5, 0.0, 5.0, 0.0]));
+        nodes.push(make_node("noise-1", vec![0.0, 0.5, 0.0, 5.0]));
+        nodes.push(make_node("noise-2", vec![0.5, 0.0, -5.0, 0.0]));
+        nodes
+    }
+
+    fn cluster_with_policy(policy: NoisePolicy) -> Vec<ClusterResult> {
+        let config = ExtractionConfig {
+            min_cluster_size: 3,
+            noise_policy: policy,
+            ..Default::default()
+        };
+        TopicExtractor::new(config)
+            .cluster(&clusters_with_noise())
+            .unwrap()
+    }
+
+    fn cluster_containing<'a>(clusters: &'a [ClusterResult], node_id: &str) -> &'a ClusterResult {
+        clusters
+            .iter()
+            .find(|c| c.node_ids.iter().any(|id| id == node_id))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_noise_policy_drop() {
+        let extractor = TopicExtractor::new(ExtractionConfig::default());
+        let clusters = cluster_with_policy(NoisePolicy::Drop);
+
+        let noise = clusters.iter().find(|c| c.label == -1).unwrap();
+        assert_eq!(noise.node_ids.len(), 3);
+
+        let topics = extractor.create_topics(&clusters);
+        assert_eq!(topics.len(), 2);
+        assert!(topics.iter().all(|t| t.node_count == 5));
+    }
+
+    #[test]
+    fn test_noise_policy_nearest_topic() {
+        let extractor = TopicExtractor::new(ExtractionConfig::default());
+        let clusters = cluster_with_policy(NoisePolicy::NearestTopic);
+
+        assert!(clusters.iter().all(|c| c.label >= 0));
+        assert_eq!(extractor.create_topics(&clusters).len(), 2);
+
+        let a = cluster_containing(&clusters, "a0");
+        assert_eq!(a.node_ids.len(), 7);
+        assert!(a.node_ids.iter().any(|id| id == "noise-0"));
+        assert!(a.node_ids.iter().any(|id| id == "noise-2"));
+
+        let b = cluster_containing(&clusters, "b0");
+        assert_eq!(b.node_ids.len(), 6);
+        assert!(b.node_ids.iter().any(|id| id == "noise-1"));
+    }
+
+    #[test]
+    fn test_noise_policy_own_topic() {
+        let extractor = TopicExtractor::new(ExtractionConfig::default());
+        let clusters = cluster_with_policy(NoisePolicy::OwnTopic);
+
+        assert!(clusters.iter().all(|c| c.label >= 0));
+        let topics = extractor.create_topics(&clusters);
+        assert_eq!(topics.len(), 5);
+        assert_eq!(topics.iter().filter(|t| t.node_count == 1).count(), 3);
+
+        for id in ["noise-0", "noise-1", "noise-2"] {
+            assert_eq!(cluster_containing(&clusters, id).node_ids, vec![id]);
+        }
+    }
+
+    #[test]
+    fn test_resolve_noise_without_clusters() {
+        let nodes = vec![make_node("n1", vec![1.0]), make_node("n2", vec![2.0])];
+        let labels = vec![-1, -1];
+
+        assert_eq!(
+            resolve_noise(&nodes, labels.clone(), NoisePolicy::NearestTopic),
+            vec![-1, -1]
+        );
+        assert_eq!(
+            resolve_noise(&nodes, labels, NoisePolicy::OwnTopic),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_cluster_rejects_small_min_cluster_size() {
+        let config = ExtractionConfig {
+            min_cluster_size: 1,
+            ..Default::default()
+        };
+        let extractor = TopicExtractor::new(config);
+
+        let result = extractor.cluster(&clusters_with_noise());
+        assert!(matches!(result, Err(TopicsError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_assign_to_existing() {
+        let extractor = TopicExtractor::new(ExtractionConfig::default());
+        let rust = Topic::new("rust".to_string(), "Rust".to_string(), vec![1.0, 0.0, 0.0]);
+        let mut pruned = Topic::new("old".to_string(), "Old".to_string(), vec![0.0, 1.0, 0.0]);
+        pruned.status = crate::types::TopicStatus::Pruned;
+        let topics = vec![rust, pruned];
+
+        let nodes = vec![
+            make_node("near-rust", vec![0.9, 0.1, 0.0]),
+            make_node("near-pruned", vec![0.0, 1.0, 0.0]),
+            make_node("unrelated", vec![0.0, 0.0, 1.0]),
+        ];
+
+        let links = extractor.assign_to_existing(&nodes, &topics);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].topic_id, "rust");
+        assert_eq!(links[0].node_id, "near-rust");
+        assert!(links[0].relevance >= 0.75);
+    }
+
     #[test]
     fn test_count_unique_clusters() {
         let labels = vec![0, 0, 1, 1, -1, 2, -1];
@@ -10,8 +10,11 @@
 //! - HDBSCAN clustering for automatic topic detection
 //! - TF-IDF keyword extraction for topic labeling
 //! - Optional LLM-enhanced labeling with keyword fallback
+//! - OpenAI-compatible LLM client for labeling
 //! - Time-decayed importance scoring
+//! - Query ranking that blends match relevance with importance
 //! - Topic relationships (similar, parent, child)
+//! - PCA projection of topic centroids for 2D visualization
 //! - Optional feature - disabled by default
 //!
 //! ## Requirements
@@ -27,23 +30,30 @@ pub mod importance;
 pub mod labeling;
 pub mod lifecycle;
 pub mod llm_labeler;
+pub mod openai;
+pub mod projection;
 pub mod relationships;
+pub mod search;
 pub mod similarity;
 pub mod storage;
 pub mod tfidf;
 pub mod types;
 
-pub use config::{ImportanceConfig, LabelingConfig, TopicsConfig};
+pub use config::{ImportanceConfig, LabelingConfig, NoisePolicy, TopicsConfig};
 pub use error::TopicsError;
 pub use extraction::TopicExtractor;
 pub use importance::ImportanceScorer;
 pub use labeling::{ClusterDocument, KeywordLabeler, TopicLabel, TopicLabeler};
 pub use lifecycle::{LifecycleStats, TopicLifecycleManager};
 pub use llm_labeler::{LlmClient, LlmLabeler, NoOpLlmClient};
+pub use openai::{OpenAiLlmClient, OpenAiLlmConfig};
+pub use projection::ProjectionMethod;
 pub use relationships::{RelationshipBuilder, TopicGraphBuilder};
+pub use search::{rank_topics, ScoredTopic, DEFAULT_RELEVANCE_WEIGHT};
 pub use similarity::{calculate_centroid, cosine_similarity};
 pub use storage::TopicStorage;
 pub use tfidf::TfIdf;
 pub use types::{
-    Embedding, RelationshipType, Topic, TopicId, TopicLink, TopicRelationship, TopicStatus,
+    Embedding, RelationshipType, Topic, TopicGraphDump, TopicId, TopicLink, TopicRelationship,
+    TopicStatus, TOPIC_DUMP_VERSION,
 };
@@ -55,8 +55,8 @@ impl<L: LlmClient> LlmLabeler<L> {
         Self::new(None, config)
     }
 
-    /// Generate a prompt for the LLM.
-    fn generate_prompt(&self, documents: &[ClusterDocument]) -> String {
+    /// Generate a prompt for the LLM from sample documents and keywords.
+    fn generate_prompt(&self, documents: &[ClusterDocument], keywords: &[String]) -> String {
         let samples: Vec<&str> = documents
             .iter()
             .take(5) // Limit context size
@@ -69,18 +69,26 @@ impl<L: LlmClient> LlmLabeler<L> {
             r#"Generate a concise topic label (2-5 words) for the following cluster of related documents.
 The label should capture the main theme or concept.
 
+Keywords: {}
+
 Documents:
 {}
 
 Respond with ONLY the topic label, nothing else."#,
+            keywords.join(", "),
             sample_text
         )
     }
 
     /// Parse LLM response into a label.
     fn parse_response(&self, response: &str) -> String {
-        // Clean up response: trim, remove quotes, limit length
-        let cleaned = response.trim().trim_matches('"').trim_matches('\'').trim();
+        // Clean up response: first non-empty line, remove quotes, limit length
+        let line = response
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("");
+        let cleaned = line.trim_matches('"').trim_matches('\'').trim();
 
         // Truncate if needed
         if cleaned.len() > self.config.max_label_length {
@@ -99,12 +107,15 @@ Respond with ONLY the topic label, nothing else."#,
         llm: &L,
         documents: &[ClusterDocument],
     ) -> Result<TopicLabel, TopicsError> {
-        let prompt = self.generate_prompt(documents);
+        // Keywords steer the prompt and are kept as label metadata
+        let keyword_result = self.keyword_fallback.label_cluster(documents)?;
+
+        let prompt = self.generate_prompt(documents, &keyword_result.keywords);
         let response = llm.complete(&prompt)?;
         let label = self.parse_response(&response);
-
-        // Get keywords from fallback for metadata
-        let keyword_result = self.keyword_fallback.label_cluster(documents)?;
+        if label.is_empty() {
+            return Err(TopicsError::Llm("empty label".to_string()));
+        }
 
         Ok(TopicLabel::new(
             label,
@@ -279,7 +290,9 @@ mod tests {
             make_doc("d2", "Second document about programming"),
         ];
 
-        let prompt = labeler.generate_prompt(&docs);
+        let keywords = vec!["rust".to_string(), "programming".to_string()];
+        let prompt = labeler.generate_prompt(&docs, &keywords);
+        assert!(prompt.contains("Keywords: rust, programming"));
         assert!(prompt.contains("First document"));
         assert!(prompt.contains("Second document"));
         assert!(prompt.contains("2-5 words"));
@@ -299,6 +312,10 @@ mod tests {
             "Rust Programming"
         );
         assert_eq!(labeler.parse_response("\"Quoted Label\""), "Quoted Label");
+        assert_eq!(
+            labeler.parse_response("\nFirst Line\nexplanation"),
+            "First Line"
+        );
     }
 
     #[test]
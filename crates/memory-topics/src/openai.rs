@@ -0,0 +1,303 @@
+//! OpenAI-compatible LLM client for topic labeling.
+//!
+//! [`OpenAiLlmClient`] sends labeling prompts to a `/chat/completions`
+//! endpoint. Pointing `base_url` at another OpenAI-compatible server
+//! (Ollama, vLLM, a gateway) works the same way; the API key is optional for
+//! servers that don't need one.
+//!
+//! Requests are blocking to match the synchronous [`LlmClient`] trait. From
+//! async code, create, use and drop the client inside
+//! `tokio::task::spawn_blocking`. Rate limits and HTTP errors are returned
+//! as [`TopicsError::Llm`], which lets [`LlmLabeler`](crate::LlmLabeler)
+//! fall back to keyword labels.
+
+use std::time::Duration;
+
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+
+use crate::error::TopicsError;
+use crate::llm_labeler::LlmClient;
+
+/// Configuration for [`OpenAiLlmClient`].
+#[derive(Debug, Clone)]
+pub struct OpenAiLlmConfig {
+    /// API base URL (e.g., `https://api.openai.com/v1`)
+    pub base_url: String,
+
+    /// API key, sent as a bearer token when set
+    pub api_key: Option<SecretString>,
+
+    /// Model to use (e.g., "gpt-4o-mini")
+    pub model: String,
+
+    /// Sampling temperature; low values keep labels stable
+    pub temperature: f32,
+
+    /// Request timeout
+    pub timeout: Duration,
+
+    /// Maximum tokens in the reply
+    pub max_tokens: u32,
+}
+
+impl OpenAiLlmConfig {
+    /// Create config for the OpenAI API.
+    pub fn openai(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self::compatible("https://api.openai.com/v1", model).with_api_key(api_key)
+    }
+
+    /// Create config for an OpenAI-compatible endpoint without an API key.
+    pub fn compatible(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: None,
+            model: model.into(),
+            temperature: 0.2,
+            timeout: Duration::from_secs(30),
+            max_tokens: 16,
+        }
+    }
+
+    /// Send an API key as a bearer token.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(SecretString::from(api_key.into()));
+        self
+    }
+
+    /// Set the sampling temperature.
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    /// Set the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the maximum reply length in tokens.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+}
+
+/// [`LlmClient`] for OpenAI-compatible chat completion endpoints.
+pub struct OpenAiLlmClient {
+    client: reqwest::blocking::Client,
+    config: OpenAiLlmConfig,
+}
+
+impl OpenAiLlmClient {
+    /// Create a client with the given configuration.
+    pub fn new(config: OpenAiLlmConfig) -> Result<Self, TopicsError> {
+        if !(0.0..=2.0).contains(&config.temperature) {
+            return Err(TopicsError::InvalidConfig(format!(
+                "temperature must be between 0.0 and 2.0, got {}",
+                config.temperature
+            )));
+        }
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| TopicsError::Llm(e.to_string()))?;
+
+        Ok(Self { client, config })
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &OpenAiLlmConfig {
+        &self.config
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    temperature: f32,
+    max_tokens: u32,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatMessageResponse,
+}
+
+#[derive(Deserialize)]
+struct ChatMessageResponse {
+    content: Option<String>,
+}
+
+impl LlmClient for OpenAiLlmClient {
+    fn complete(&self, prompt: &str) -> Result<String, TopicsError> {
+        let request = ChatRequest {
+            model: &self.config.model,
+            messages: vec![ChatMessage {
+                role: "user",
+                content: prompt,
+            }],
+            temperature: self.config.temperature,
+            max_tokens: self.config.max_tokens,
+        };
+
+        let url = format!(
+            "{}/chat/completions",
+            self.config.base_url.trim_end_matches('/')
+        );
+        let mut builder = self.client.post(&url).json(&request);
+        if let Some(ref key) = self.config.api_key {
+            builder = builder.bearer_auth(key.expose_secret());
+        }
+
+        let response = builder
+            .send()
+            .map_err(|e| TopicsError::Llm(e.to_string()))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("unknown")
+                .to_string();
+            return Err(TopicsError::Llm(format!(
+                "rate limited (retry after: {})",
+                retry_after
+            )));
+        }
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(TopicsError::Llm(format!("HTTP {}: {}", status, body)));
+        }
+
+        let body: ChatResponse = response
+            .json()
+            .map_err(|e| TopicsError::Llm(format!("invalid response: {}", e)))?;
+
+        body.choices
+            .into_iter()
+            .next()
+            .and_then(|c| c.message.content)
+            .filter(|content| !content.trim().is_empty())
+            .ok_or_else(|| TopicsError::Llm("empty completion".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LabelingConfig;
+    use crate::labeling::{ClusterDocument, TopicLabel, TopicLabeler};
+    use crate::llm_labeler::LlmLabeler;
+    use serde_json::{json, Value};
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn docs() -> Vec<ClusterDocument> {
+        vec![
+            ClusterDocument::new(
+                "d1".to_string(),
+                "rust borrow checker memory safety".to_string(),
+            ),
+            ClusterDocument::new(
+                "d2".to_string(),
+                "rust ownership and memory safety rules".to_string(),
+            ),
+        ]
+    }
+
+    /// Label `docs()` through a labeler talking to `base_url`.
+    ///
+    /// The blocking client must not be created or dropped on an async
+    /// worker thread, so everything runs in `spawn_blocking`.
+    async fn label_via(base_url: String) -> Result<TopicLabel, TopicsError> {
+        tokio::task::spawn_blocking(move || {
+            let config = OpenAiLlmConfig::compatible(base_url, "test-model")
+                .with_api_key("test-key")
+                .with_temperature(0.0);
+            let client = OpenAiLlmClient::new(config)?;
+            LlmLabeler::with_llm(client, LabelingConfig::default()).label_cluster(&docs())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_label_from_mock_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("authorization", "Bearer test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "choices": [{ "message": { "content": "\"Rust Memory Safety\"\n" } }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let label = label_via(server.uri()).await.unwrap();
+        assert_eq!(label.label, "Rust Memory Safety");
+
+        let requests = server.received_requests().await.unwrap();
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["model"], "test-model");
+        assert_eq!(body["temperature"], 0.0);
+        let prompt = body["messages"][0]["content"].as_str().unwrap();
+        assert!(!label.keywords.is_empty());
+        for keyword in &label.keywords {
+            assert!(prompt.contains(keyword.as_str()), "missing {keyword}");
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_rate_limit_falls_back_to_keywords() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "20"))
+            .mount(&server)
+            .await;
+
+        let label = label_via(server.uri()).await.unwrap();
+        let keyword_label = crate::labeling::KeywordLabeler::new(LabelingConfig::default())
+            .label_cluster(&docs())
+            .unwrap();
+        assert_eq!(label.label, keyword_label.label);
+
+        let uri = server.uri();
+        let error = tokio::task::spawn_blocking(move || {
+            OpenAiLlmClient::new(OpenAiLlmConfig::compatible(uri, "test-model"))
+                .unwrap()
+                .complete("prompt")
+        })
+        .await
+        .unwrap()
+        .unwrap_err();
+        assert!(error.to_string().contains("rate limited (retry after: 20)"));
+    }
+
+    #[test]
+    fn test_invalid_temperature_rejected() {
+        let config = OpenAiLlmConfig::openai("key", "gpt-4o-mini").with_temperature(3.0);
+        assert!(matches!(
+            OpenAiLlmClient::new(config),
+            Err(TopicsError::InvalidConfig(_))
+        ));
+    }
+}
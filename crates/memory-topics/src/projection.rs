@@ -0,0 +1,294 @@
+//! Dimension reduction of topic centroids for 2D visualization.
+//!
+//! Pure Rust PCA via power iteration; no linear algebra dependency needed for
+//! the handful of components we extract.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::TopicsError;
+use crate::types::{Topic, TopicId};
+
+/// Maximum power iterations per principal component.
+const MAX_ITERATIONS: usize = 200;
+
+/// Convergence tolerance on the component direction.
+const TOLERANCE: f64 = 1e-9;
+
+/// Method used to project topic centroids into 2D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectionMethod {
+    /// Principal component analysis (top two components).
+    #[default]
+    Pca,
+}
+
+impl ProjectionMethod {
+    /// Get the method name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectionMethod::Pca => "pca",
+        }
+    }
+}
+
+impl fmt::Display for ProjectionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ProjectionMethod {
+    type Err = TopicsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pca" => Ok(ProjectionMethod::Pca),
+            other => Err(TopicsError::InvalidInput(format!(
+                "Unknown projection method: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Project topic centroids into 2D.
+///
+/// Topics with an empty embedding, or whose dimension differs from the first
+/// non-empty embedding, are skipped. Output order follows the input order.
+pub fn project_2d(topics: &[Topic], method: ProjectionMethod) -> Vec<(TopicId, [f32; 2])> {
+    let dim = match topics.iter().find(|t| !t.embedding.is_empty()) {
+        Some(t) => t.embedding.len(),
+        None => return Vec::new(),
+    };
+    let usable: Vec<&Topic> = topics.iter().filter(|t| t.embedding.len() == dim).collect();
+
+    let coords = match method {
+        ProjectionMethod::Pca => pca_2d(&usable, dim),
+    };
+
+    usable
+        .into_iter()
+        .zip(coords)
+        .map(|(t, c)| (t.topic_id.clone(), c))
+        .collect()
+}
+
+/// Project rows onto their top two principal components.
+fn pca_2d(topics: &[&Topic], dim: usize) -> Vec<[f32; 2]> {
+    let n = topics.len();
+
+    // Center the data
+    let mut mean = vec![0.0f64; dim];
+    for t in topics {
+        for (m, &v) in mean.iter_mut().zip(&t.embedding) {
+            *m += v as f64;
+        }
+    }
+    for m in mean.iter_mut() {
+        *m /= n as f64;
+    }
+    let rows: Vec<Vec<f64>> = topics
+        .iter()
+        .map(|t| {
+            t.embedding
+                .iter()
+                .zip(&mean)
+                .map(|(&v, m)| v as f64 - m)
+                .collect()
+        })
+        .collect();
+
+    let first = principal_component(&rows, &[]);
+    let second = first
+        .as_ref()
+        .and_then(|pc| principal_component(&rows, std::slice::from_ref(pc)));
+
+    rows.iter()
+        .map(|row| {
+            let x = first.as_ref().map_or(0.0, |pc| dot(row, pc));
+            let y = second.as_ref().map_or(0.0, |pc| dot(row, pc));
+            [x as f32, y as f32]
+        })
+        .collect()
+}
+
+/// Find the dominant principal component orthogonal to `previous`.
+///
+/// Returns `None` when no variance remains.
+fn principal_component(rows: &[Vec<f64>], previous: &[Vec<f64>]) -> Option<Vec<f64>> {
+    // Start from the row with the largest residual: it lies in the data span,
+    // so it cannot be orthogonal to the component we are looking for.
+    let mut v = rows
+        .iter()
+        .map(|row| {
+            let mut r = row.clone();
+            orthogonalize(&mut r, previous);
+            r
+        })
+        .max_by(|a, b| dot(a, a).total_cmp(&dot(b, b)))?;
+    if !normalize(&mut v) {
+        return None;
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        // v' = X^T (X v), without forming the covariance matrix
+        let mut next = vec![0.0f64; v.len()];
+        for row in rows {
+            let proj = dot(row, &v);
+            for (n, &r) in next.iter_mut().zip(row) {
+                *n += proj * r;
+            }
+        }
+        orthogonalize(&mut next, previous);
+        if !normalize(&mut next) {
+            return None;
+        }
+
+        let delta: f64 = next.iter().zip(&v).map(|(a, b)| (a - b).abs()).sum();
+        v = next;
+        if delta < TOLERANCE {
+            break;
+        }
+    }
+
+    // Deterministic sign: largest-magnitude coordinate is positive
+    if let Some(max) = v.iter().copied().max_by(|a, b| a.abs().total_cmp(&b.abs())) {
+        if max < 0.0 {
+            v.iter_mut().for_each(|x| *x = -*x);
+        }
+    }
+    Some(v)
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn orthogonalize(v: &mut [f64], basis: &[Vec<f64>]) {
+    for b in basis {
+        let p = dot(v, b);
+        for (x, &y) in v.iter_mut().zip(b) {
+            *x -= p * y;
+        }
+    }
+}
+
+fn normalize(v: &mut [f64]) -> bool {
+    let norm = dot(v, v).sqrt();
+    if norm < 1e-12 {
+        return false;
+    }
+    v.iter_mut().for_each(|x| *x /= norm);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(id: &str, embedding: Vec<f32>) -> Topic {
+        Topic::new(id.to_string(), id.to_string(), embedding)
+    }
+
+    fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_pca_recovers_plane_layout() {
+        // Orthonormal basis of a plane in 5D, offset from the origin
+        let s = std::f32::consts::FRAC_1_SQRT_2;
+        let u = [s, s, 0.0, 0.0, 0.0];
+        let w = [0.0, 0.0, s, 0.0, -s];
+        let origin = [1.0, -2.0, 0.5, 3.0, 0.25];
+        let plane: [[f32; 2]; 5] = [[0.0, 0.0], [4.0, 0.0], [0.0, 2.0], [4.0, 2.0], [1.0, 3.0]];
+
+        let topics: Vec<Topic> = plane
+            .iter()
+            .enumerate()
+            .map(|(i, [a, b])| {
+                let emb = (0..5).map(|d| origin[d] + a * u[d] + b * w[d]).collect();
+                topic(&format!("t{}", i), emb)
+            })
+            .collect();
+
+        let projected = project_2d(&topics, ProjectionMethod::Pca);
+        assert_eq!(projected.len(), 5);
+        for (i, (id, _)) in projected.iter().enumerate() {
+            assert_eq!(id, &format!("t{}", i));
+        }
+
+        // Relative pairwise distances are preserved up to rotation/scale
+        let scale = distance(projected[0].1, projected[1].1) / distance(plane[0], plane[1]);
+        assert!(scale > 0.0);
+        for i in 0..plane.len() {
+            for j in (i + 1)..plane.len() {
+                let expected = distance(plane[i], plane[j]) * scale;
+                let actual = distance(projected[i].1, projected[j].1);
+                assert!(
+                    (expected - actual).abs() < 1e-3,
+                    "pair ({}, {}): expected {}, got {}",
+                    i,
+                    j,
+                    expected,
+                    actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pca_collinear_points_have_zero_second_axis() {
+        let topics = vec![
+            topic("a", vec![0.0, 0.0, 0.0]),
+            topic("b", vec![1.0, 1.0, 1.0]),
+            topic("c", vec![2.0, 2.0, 2.0]),
+        ];
+
+        let projected = project_2d(&topics, ProjectionMethod::Pca);
+        for (_, [_, y]) in &projected {
+            assert!(y.abs() < 1e-5);
+        }
+        let spread = distance(projected[0].1, projected[2].1);
+        assert!((spread - 2.0 * 3.0f32.sqrt()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_project_skips_mismatched_embeddings() {
+        let topics = vec![
+            topic("a", vec![1.0, 0.0]),
+            topic("empty", vec![]),
+            topic("b", vec![0.0, 1.0]),
+            topic("wrong-dim", vec![1.0, 2.0, 3.0]),
+        ];
+
+        let ids: Vec<TopicId> = project_2d(&topics, ProjectionMethod::Pca)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_project_edge_cases() {
+        assert!(project_2d(&[], ProjectionMethod::Pca).is_empty());
+
+        let single = project_2d(&[topic("a", vec![0.3, 0.4])], ProjectionMethod::Pca);
+        assert_eq!(single, vec![("a".to_string(), [0.0, 0.0])]);
+    }
+
+    #[test]
+    fn test_projection_method_parse() {
+        assert_eq!(
+            "pca".parse::<ProjectionMethod>().unwrap(),
+            ProjectionMethod::Pca
+        );
+        assert_eq!(
+            "PCA".parse::<ProjectionMethod>().unwrap(),
+            ProjectionMethod::Pca
+        );
+        assert!("umap".parse::<ProjectionMethod>().is_err());
+        assert_eq!(ProjectionMethod::default().to_string(), "pca");
+    }
+}
@@ -36,8 +36,9 @@ use chrono::{DateTime, Utc};
 use tracing::{debug, instrument};
 
 use crate::error::TopicsError;
+use crate::projection::{self, ProjectionMethod};
 use crate::similarity::cosine_similarity;
-use crate::types::{Embedding, RelationshipType, TopicId, TopicRelationship};
+use crate::types::{Embedding, RelationshipType, Topic, TopicId, TopicRelationship};
 
 /// Default strength increase per co-occurrence evidence.
 const CO_OCCURRENCE_STRENGTH_DELTA: f32 = 0.1;
@@ -556,6 +557,26 @@ impl TopicGraphBuilder {
     pub fn clear(&mut self) {
         self.relationships.clear();
     }
+
+    /// Project topic centroids into 2D for visualization.
+    ///
+    /// Topics whose embedding is empty or has a different dimension from the
+    /// rest are skipped. Coordinates are only meaningful relative to each other.
+    #[instrument(skip(self, topics))]
+    pub fn project_2d(
+        &self,
+        topics: &[Topic],
+        method: ProjectionMethod,
+    ) -> Vec<(TopicId, [f32; 2])> {
+        let points = projection::project_2d(topics, method);
+        debug!(
+            topics = topics.len(),
+            projected = points.len(),
+            method = %method,
+            "Projected topic centroids"
+        );
+        points
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,137 @@
+//! Topic search ranking.
+//!
+//! Ranks topics for a text query by blending how well the query matches a
+//! topic's label and keywords with the topic's importance, so a relevant
+//! but stale topic can still outrank a popular one that barely matches.
+
+use crate::types::Topic;
+
+/// Default weight of query relevance against importance.
+pub const DEFAULT_RELEVANCE_WEIGHT: f32 = 0.7;
+
+/// A topic matched by a query, with the components of its score.
+#[derive(Debug, Clone)]
+pub struct ScoredTopic {
+    /// The matched topic
+    pub topic: Topic,
+    /// Fraction of query terms found in the label or keywords (0.0 - 1.0)
+    pub relevance: f32,
+    /// Importance divided by the highest importance among the matches (0.0 - 1.0)
+    pub importance: f32,
+    /// `weight * relevance + (1 - weight) * importance`
+    pub score: f32,
+}
+
+/// Fraction of `query_terms` (lowercase) that appear in the topic's label or keywords.
+pub fn query_relevance(topic: &Topic, query_terms: &[&str]) -> f32 {
+    if query_terms.is_empty() {
+        return 0.0;
+    }
+    let label = topic.label.to_lowercase();
+    let keywords: Vec<String> = topic.keywords.iter().map(|k| k.to_lowercase()).collect();
+    let matched = query_terms
+        .iter()
+        .filter(|term| label.contains(*term) || keywords.iter().any(|k| k.contains(*term)))
+        .count();
+    matched as f32 / query_terms.len() as f32
+}
+
+/// Rank topics matching `query`, best first.
+///
+/// Topics sharing no term with the query are dropped. `relevance_weight` is
+/// clamped to [0.0, 1.0]; 1.0 ranks by relevance alone and 0.0 by
+/// importance alone.
+pub fn rank_topics(topics: Vec<Topic>, query: &str, relevance_weight: f32) -> Vec<ScoredTopic> {
+    let weight = relevance_weight.clamp(0.0, 1.0);
+    let query = query.to_lowercase();
+    let query_terms: Vec<&str> = query.split_whitespace().collect();
+
+    let matches: Vec<(Topic, f32)> = topics
+        .into_iter()
+        .map(|topic| {
+            let relevance = query_relevance(&topic, &query_terms);
+            (topic, relevance)
+        })
+        .filter(|(_, relevance)| *relevance > 0.0)
+        .collect();
+
+    let max_importance = matches
+        .iter()
+        .map(|(t, _)| t.importance_score)
+        .fold(0.0f64, f64::max);
+
+    let mut scored: Vec<ScoredTopic> = matches
+        .into_iter()
+        .map(|(topic, relevance)| {
+            let importance = if max_importance > 0.0 {
+                (topic.importance_score / max_importance) as f32
+            } else {
+                0.0
+            };
+            ScoredTopic {
+                score: weight * relevance + (1.0 - weight) * importance,
+                topic,
+                relevance,
+                importance,
+            }
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(id: &str, label: &str, keywords: &[&str], importance: f64) -> Topic {
+        let mut topic = Topic::new(id.to_string(), label.to_string(), vec![0.0; 3]);
+        topic.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        topic.importance_score = importance;
+        topic
+    }
+
+    /// One topic matching the whole query but rarely mentioned, one matching
+    /// half of it but very important.
+    fn topics() -> Vec<Topic> {
+        vec![
+            topic("relevant", "Rust memory allocator", &["arena"], 0.1),
+            topic("important", "Memory", &["recall"], 2.0),
+            topic("unrelated", "Deployment", &["ci"], 5.0),
+        ]
+    }
+
+    #[test]
+    fn test_relevance_weight_favours_match() {
+        let ranked = rank_topics(topics(), "rust memory", 0.7);
+        let ids: Vec<&str> = ranked.iter().map(|s| s.topic.topic_id.as_str()).collect();
+        assert_eq!(ids, vec!["relevant", "important"]);
+
+        assert!((ranked[0].relevance - 1.0).abs() < f32::EPSILON);
+        assert!((ranked[0].importance - 0.05).abs() < 1e-6);
+        assert!((ranked[1].relevance - 0.5).abs() < f32::EPSILON);
+        assert!((ranked[1].importance - 1.0).abs() < f32::EPSILON);
+        assert!((ranked[0].score - (0.7 + 0.3 * 0.05)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_importance_weight_favours_popular() {
+        let ranked = rank_topics(topics(), "rust memory", 0.3);
+        let ids: Vec<&str> = ranked.iter().map(|s| s.topic.topic_id.as_str()).collect();
+        assert_eq!(ids, vec!["important", "relevant"]);
+    }
+
+    #[test]
+    fn test_weight_is_clamped() {
+        let ranked = rank_topics(topics(), "rust memory", 5.0);
+        assert_eq!(ranked[0].topic.topic_id, "relevant");
+        assert!((ranked[0].score - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_no_terms_match_nothing() {
+        assert!(rank_topics(topics(), "   ", 0.7).is_empty());
+        assert!(rank_topics(topics(), "kubernetes", 0.7).is_empty());
+    }
+}
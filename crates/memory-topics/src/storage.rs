@@ -10,8 +10,10 @@ use tracing::{debug, info, instrument};
 
 use crate::error::TopicsError;
 use crate::importance::ImportanceScorer;
+use crate::similarity::normalize;
 use crate::types::{
-    RelationshipType, Topic, TopicLink, TopicRelationship, TopicStats, TopicStatus,
+    RelationshipType, Topic, TopicGraphDump, TopicLink, TopicRelationship, TopicStats, TopicStatus,
+    TOPIC_DUMP_VERSION,
 };
 
 /// Column family names (must match memory-storage)
@@ -156,11 +158,12 @@ impl TopicStorage {
             links.push(link);
         }
 
-        // Sort by relevance descending
+        // Sort by relevance descending, ties by node ID so pages are stable
         links.sort_by(|a, b| {
             b.relevance
                 .partial_cmp(&a.relevance)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.node_id.cmp(&b.node_id))
         });
 
         Ok(links)
@@ -554,6 +557,138 @@ impl TopicStorage {
         );
         Ok(updated)
     }
+
+    // --- Export / Import ---
+
+    /// Export every topic, link, and relationship, including pruned topics.
+    pub fn export_all(&self) -> Result<TopicGraphDump, TopicsError> {
+        let mut topics = Vec::new();
+        for (_, value) in self.storage.prefix_iterator(CF_TOPICS, b"topic:")? {
+            topics.push(serde_json::from_slice::<Topic>(&value)?);
+        }
+
+        // Primary link keys only; the node index holds the same links
+        let mut links = Vec::new();
+        for (_, value) in self.storage.prefix_iterator(CF_TOPIC_LINKS, b"link:")? {
+            links.push(serde_json::from_slice::<TopicLink>(&value)?);
+        }
+
+        let mut relationships = Vec::new();
+        for (_, value) in self.storage.prefix_iterator(CF_TOPIC_RELS, b"rel:")? {
+            relationships.push(serde_json::from_slice::<TopicRelationship>(&value)?);
+        }
+
+        info!(
+            topics = topics.len(),
+            links = links.len(),
+            relationships = relationships.len(),
+            "Exported topic graph"
+        );
+        Ok(TopicGraphDump {
+            version: TOPIC_DUMP_VERSION,
+            exported_at: Utc::now(),
+            topics,
+            links,
+            relationships,
+        })
+    }
+
+    /// Import a dump produced by [`export_all`](Self::export_all).
+    ///
+    /// Without `merge`, records in the dump overwrite existing ones with the
+    /// same ID. With `merge`, they are combined: centroids are averaged by
+    /// node count, keywords are unioned, and counts, scores, and timestamps
+    /// take the larger (or earliest creation) value, so re-importing a dump
+    /// does not inflate counts.
+    #[instrument(skip(self, dump), fields(version = dump.version))]
+    pub fn import_all(&self, dump: &TopicGraphDump, merge: bool) -> Result<(), TopicsError> {
+        if dump.version != TOPIC_DUMP_VERSION {
+            return Err(TopicsError::InvalidInput(format!(
+                "Unsupported topic dump version {} (expected {})",
+                dump.version, TOPIC_DUMP_VERSION
+            )));
+        }
+
+        for topic in &dump.topics {
+            let topic = match self.get_topic(&topic.topic_id)? {
+                Some(existing) if merge => merge_topic(existing, topic),
+                _ => topic.clone(),
+            };
+            self.save_topic(&topic)?;
+        }
+
+        for link in &dump.links {
+            let key = topic_link_key(&link.topic_id, &link.node_id);
+            let link = match self.storage.get(CF_TOPIC_LINKS, key.as_bytes())? {
+                Some(bytes) if merge => merge_link(serde_json::from_slice(&bytes)?, link),
+                _ => link.clone(),
+            };
+            self.save_link(&link)?;
+        }
+
+        for rel in &dump.relationships {
+            let existing =
+                self.get_relationship(&rel.source_id, &rel.target_id, rel.relationship_type)?;
+            let rel = match existing {
+                Some(existing) if merge => merge_relationship(existing, rel),
+                _ => rel.clone(),
+            };
+            self.save_relationship(&rel)?;
+        }
+
+        info!(
+            topics = dump.topics.len(),
+            links = dump.links.len(),
+            relationships = dump.relationships.len(),
+            merge,
+            "Imported topic graph"
+        );
+        Ok(())
+    }
+}
+
+/// Combine a stored topic with the same topic from a dump.
+fn merge_topic(mut existing: Topic, incoming: &Topic) -> Topic {
+    if existing.embedding.len() == incoming.embedding.len() {
+        let a = existing.node_count.max(1) as f32;
+        let b = incoming.node_count.max(1) as f32;
+        for (x, y) in existing.embedding.iter_mut().zip(&incoming.embedding) {
+            *x = (*x * a + y * b) / (a + b);
+        }
+        normalize(&mut existing.embedding);
+    }
+    for keyword in &incoming.keywords {
+        if !existing.keywords.contains(keyword) {
+            existing.keywords.push(keyword.clone());
+        }
+    }
+    existing.node_count = existing.node_count.max(incoming.node_count);
+    existing.importance_score = existing.importance_score.max(incoming.importance_score);
+    existing.created_at = existing.created_at.min(incoming.created_at);
+    existing.last_mentioned_at = existing.last_mentioned_at.max(incoming.last_mentioned_at);
+    if incoming.is_active() {
+        existing.status = TopicStatus::Active;
+    }
+    existing
+}
+
+/// Combine a stored link with the same link from a dump.
+fn merge_link(mut existing: TopicLink, incoming: &TopicLink) -> TopicLink {
+    existing.relevance = existing.relevance.max(incoming.relevance);
+    existing.created_at = existing.created_at.min(incoming.created_at);
+    existing
+}
+
+/// Combine a stored relationship with the same relationship from a dump.
+fn merge_relationship(
+    mut existing: TopicRelationship,
+    incoming: &TopicRelationship,
+) -> TopicRelationship {
+    existing.strength = existing.strength.max(incoming.strength);
+    existing.evidence_count = existing.evidence_count.max(incoming.evidence_count);
+    existing.created_at = existing.created_at.min(incoming.created_at);
+    existing.updated_at = existing.updated_at.max(incoming.updated_at);
+    existing
 }
 
 #[cfg(test)]
@@ -636,6 +771,111 @@ mod tests {
         storage.put_toc_node(&node).unwrap();
     }
 
+    /// Helper: store two topics (one pruned), two links, and a relationship.
+    fn populate_graph(topic_storage: &TopicStorage) {
+        let mut t1 = Topic::new("t1".to_string(), "Rust".to_string(), vec![0.6, 0.8, 0.0]);
+        t1.node_count = 2;
+        t1.keywords = vec!["rust".to_string()];
+        let mut t2 = Topic::new("t2".to_string(), "Old".to_string(), vec![0.0, 0.0, 1.0]);
+        t2.status = TopicStatus::Pruned;
+        topic_storage.save_topic(&t1).unwrap();
+        topic_storage.save_topic(&t2).unwrap();
+
+        topic_storage
+            .save_link(&TopicLink::new("t1".to_string(), "node-1".to_string(), 0.9))
+            .unwrap();
+        topic_storage
+            .save_link(&TopicLink::new("t1".to_string(), "node-2".to_string(), 0.7))
+            .unwrap();
+        topic_storage
+            .save_relationship(&TopicRelationship::new(
+                "t1".to_string(),
+                "t2".to_string(),
+                RelationshipType::Semantic,
+                0.6,
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let (_src_dir, src) = create_test_storage();
+        let source = TopicStorage::new(src);
+        populate_graph(&source);
+
+        let dump = source.export_all().unwrap();
+        assert_eq!(dump.version, TOPIC_DUMP_VERSION);
+        let json = dump.to_json().unwrap();
+
+        let (_dst_dir, dst) = create_test_storage();
+        let target = TopicStorage::new(dst);
+        target
+            .import_all(&TopicGraphDump::from_json(&json).unwrap(), false)
+            .unwrap();
+
+        let exported = target.export_all().unwrap();
+        assert_eq!(exported.topics.len(), 2);
+        assert_eq!(exported.links.len(), 2);
+        assert_eq!(exported.relationships.len(), 1);
+        for topic in &dump.topics {
+            let imported = target.get_topic(&topic.topic_id).unwrap().unwrap();
+            assert_eq!(imported.embedding, topic.embedding);
+            assert_eq!(imported.status, topic.status);
+        }
+        assert_eq!(target.get_topics_for_node("node-1").unwrap().len(), 1);
+        let stats = target.get_stats().unwrap();
+        assert_eq!(stats.link_count, 2);
+        assert_eq!(stats.relationship_count, 1);
+    }
+
+    #[test]
+    fn test_import_merge_combines_topics() {
+        let (_src_dir, src) = create_test_storage();
+        let source = TopicStorage::new(src);
+        populate_graph(&source);
+        let dump = source.export_all().unwrap();
+
+        let (_dst_dir, dst) = create_test_storage();
+        let target = TopicStorage::new(dst);
+        let mut local = Topic::new("t1".to_string(), "Rust".to_string(), vec![0.0, 1.0, 0.0]);
+        local.node_count = 2;
+        local.keywords = vec!["cargo".to_string()];
+        target.save_topic(&local).unwrap();
+
+        target.import_all(&dump, true).unwrap();
+        let merged = target.get_topic("t1").unwrap().unwrap();
+        assert_eq!(merged.keywords, vec!["cargo", "rust"]);
+        assert!(merged.embedding[0] > 0.0 && merged.embedding[1] > 0.8);
+
+        // Importing the same dump again does not inflate counts
+        target.import_all(&dump, true).unwrap();
+        let again = target.get_topic("t1").unwrap().unwrap();
+        assert_eq!(again.keywords, merged.keywords);
+        let rel = target
+            .get_relationship("t1", "t2", RelationshipType::Semantic)
+            .unwrap()
+            .unwrap();
+        assert_eq!(rel.evidence_count, 1);
+
+        // Without merge the dump overwrites the local topic
+        target.import_all(&dump, false).unwrap();
+        let replaced = target.get_topic("t1").unwrap().unwrap();
+        assert_eq!(replaced.embedding, vec![0.6, 0.8, 0.0]);
+        assert_eq!(replaced.keywords, vec!["rust"]);
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_version() {
+        let (_dir, storage) = create_test_storage();
+        let topic_storage = TopicStorage::new(storage);
+        let mut dump = topic_storage.export_all().unwrap();
+        dump.version = TOPIC_DUMP_VERSION + 1;
+        assert!(matches!(
+            topic_storage.import_all(&dump, false),
+            Err(TopicsError::InvalidInput(_))
+        ));
+    }
+
     #[test]
     fn test_get_topics_for_agent_returns_matching_topics() {
         let (_dir, storage) = create_test_storage();
@@ -265,6 +265,36 @@ pub struct TopicStats {
     pub similarity_threshold: f32,
 }
 
+/// Format version written by [`TopicGraphDump`] exports.
+pub const TOPIC_DUMP_VERSION: u32 = 1;
+
+/// Complete topic graph, for backup or moving topics between databases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicGraphDump {
+    /// Dump format version
+    pub version: u32,
+    /// When the dump was taken
+    pub exported_at: DateTime<Utc>,
+    /// All topics, including pruned ones
+    pub topics: Vec<Topic>,
+    /// Topic-node links
+    pub links: Vec<TopicLink>,
+    /// Topic relationships
+    pub relationships: Vec<TopicRelationship>,
+}
+
+impl TopicGraphDump {
+    /// Serialize to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parse a dump from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
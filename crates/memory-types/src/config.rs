@@ -7,6 +7,7 @@
 use config::{Config, Environment, File};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use crate::error::MemoryError;
@@ -41,6 +42,13 @@ pub struct DedupConfig {
     /// Capacity of the in-flight ring buffer for recent embeddings.
     #[serde(default = "default_buffer_capacity")]
     pub buffer_capacity: usize,
+
+    /// How often the in-flight buffer is saved to storage, in seconds.
+    /// The buffer is reloaded on startup so dedup survives restarts; saves
+    /// are skipped while it is unchanged.
+    /// 0 disables persistence.
+    #[serde(default = "default_persist_interval_secs")]
+    pub persist_interval_secs: u64,
 }
 
 /// Backward-compatible type alias for code that still references `NoveltyConfig`.
@@ -62,6 +70,10 @@ fn default_buffer_capacity() -> usize {
     256
 }
 
+fn default_persist_interval_secs() -> u64 {
+    60
+}
+
 impl Default for DedupConfig {
     fn default() -> Self {
         Self {
@@ -70,6 +82,7 @@ impl Default for DedupConfig {
             timeout_ms: default_dedup_timeout(),
             min_text_length: default_min_text_length(),
             buffer_capacity: default_buffer_capacity(),
+            persist_interval_secs: default_persist_interval_secs(),
         }
     }
 }
@@ -209,6 +222,16 @@ pub struct SummarizerSettings {
     /// "ANTHROPIC_API_KEY" for anthropic.
     #[serde(default)]
     pub api_key_env: Option<String>,
+
+    /// Custom event summarization prompt. Must contain `{events}`;
+    /// `{context}` is optional. Unset uses the built-in prompt.
+    #[serde(default)]
+    pub prompt_template: Option<String>,
+
+    /// Custom rollup summarization prompt. Must contain `{child_summaries}`.
+    /// Unset uses the built-in prompt.
+    #[serde(default)]
+    pub rollup_prompt_template: Option<String>,
 }
 
 fn default_summarizer_provider() -> String {
@@ -227,6 +250,8 @@ impl Default for SummarizerSettings {
             api_key: None,
             api_base_url: None,
             api_key_env: None,
+            prompt_template: None,
+            rollup_prompt_template: None,
         }
     }
 }
@@ -313,6 +338,41 @@ pub enum MultiAgentMode {
     Unified,
 }
 
+/// Log output format for the daemon.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// Human-readable text (default)
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log aggregators
+    Json,
+}
+
+impl LogFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogFormat::Pretty => "pretty",
+            LogFormat::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "unknown log format '{}' (expected pretty or json)",
+                other
+            )),
+        }
+    }
+}
+
 /// Cross-project federation configuration (v3.0).
 ///
 /// Controls whether queries can span multiple registered project stores.
@@ -363,6 +423,10 @@ pub struct Settings {
     #[serde(default = "default_log_level")]
     pub log_level: String,
 
+    /// Log output format (pretty, json)
+    #[serde(default)]
+    pub log_format: LogFormat,
+
     /// Path to BM25 search index directory
     #[serde(default = "default_search_index_path")]
     pub search_index_path: String,
@@ -400,6 +464,210 @@ pub struct Settings {
     /// Lists additional project stores to include in federated queries.
     #[serde(default)]
     pub projects: CrossProjectConfig,
+
+    /// Outbox lag (unprocessed entries) above which scheduler status warns
+    /// that indexing is falling behind ingestion.
+    #[serde(default = "default_outbox_lag_warn_threshold")]
+    pub outbox_lag_warn_threshold: u64,
+
+    /// Build missing or empty search indexes from storage at startup.
+    /// Indexes that already hold documents are never rebuilt.
+    #[serde(default = "default_auto_rebuild_on_start")]
+    pub auto_rebuild_on_start: bool,
+
+    /// Seconds to wait after a shutdown signal for in-flight requests and
+    /// running scheduler jobs before aborting them. Also accepted as
+    /// `drain_timeout_secs`.
+    #[serde(
+        default = "default_shutdown_timeout_secs",
+        alias = "drain_timeout_secs"
+    )]
+    pub shutdown_timeout_secs: u64,
+
+    /// Maximum IngestEvent calls per second per client peer address and agent.
+    /// Unset disables rate limiting.
+    #[serde(default)]
+    pub ingest_rate_limit_per_sec: Option<u32>,
+
+    /// Add the text of every ingested event to the BM25 index so it can be
+    /// searched with SearchEvents. Off by default because of index size.
+    /// Events ingested before this was enabled are indexed by
+    /// `rebuild-indexes --index bm25`.
+    #[serde(default)]
+    pub index_raw_events: bool,
+
+    /// New segment summaries written before they are linked to existing
+    /// topics, between scheduled extractions. 0 disables the incremental pass.
+    #[serde(default = "default_topic_refresh_threshold")]
+    pub topic_refresh_threshold: usize,
+
+    /// Regex patterns whose matches in tool results are replaced with
+    /// `[REDACTED]` before storage (e.g., API tokens).
+    #[serde(default)]
+    pub redaction_patterns: Vec<String>,
+
+    /// Largest result limit a RouteQuery request may ask for. Larger
+    /// requests are clamped and the explanation notes the clamp.
+    #[serde(default = "default_max_route_limit")]
+    pub max_route_limit: u32,
+
+    /// Size in MB of the RocksDB block cache shared by all column families.
+    #[serde(default = "default_block_cache_mb")]
+    pub block_cache_mb: usize,
+
+    /// Background scheduler settings.
+    #[serde(default)]
+    pub scheduler: SchedulerSettings,
+
+    /// HNSW vector index build and search parameters.
+    #[serde(default)]
+    pub vector: VectorSettings,
+
+    /// HybridSearch result cache settings.
+    #[serde(default)]
+    pub hybrid_cache: HybridCacheSettings,
+}
+
+/// Background scheduler settings.
+///
+/// Maps to `[scheduler]` section in config.toml.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchedulerSettings {
+    /// IANA timezone for jobs that don't set their own (default: "UTC").
+    #[serde(default = "default_scheduler_timezone")]
+    pub default_timezone: String,
+
+    /// Maximum jobs running at once; waiting jobs start in priority order
+    /// (default: 0, no limit).
+    #[serde(default)]
+    pub max_concurrent_jobs: usize,
+}
+
+impl Default for SchedulerSettings {
+    fn default() -> Self {
+        Self {
+            default_timezone: default_scheduler_timezone(),
+            max_concurrent_jobs: 0,
+        }
+    }
+}
+
+fn default_scheduler_timezone() -> String {
+    "UTC".to_string()
+}
+
+/// HybridSearch result cache settings.
+///
+/// Maps to `[hybrid_cache]` section in config.toml. Cached results are
+/// also dropped as soon as either index changes size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HybridCacheSettings {
+    /// Maximum number of cached queries; 0 disables the cache (default: 256).
+    #[serde(default = "default_hybrid_cache_capacity")]
+    pub capacity: usize,
+
+    /// Seconds a cached result stays valid (default: 60).
+    #[serde(default = "default_hybrid_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+impl Default for HybridCacheSettings {
+    fn default() -> Self {
+        Self {
+            capacity: default_hybrid_cache_capacity(),
+            ttl_secs: default_hybrid_cache_ttl_secs(),
+        }
+    }
+}
+
+fn default_hybrid_cache_capacity() -> usize {
+    256
+}
+
+fn default_hybrid_cache_ttl_secs() -> u64 {
+    60
+}
+
+/// HNSW vector index parameters.
+///
+/// Maps to `[vector]` section in config.toml. `m` and `ef_construction`
+/// shape the graph when vectors are inserted, so changing them only takes
+/// full effect after the vector index is rebuilt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorSettings {
+    /// Connections per node per layer (default: 16).
+    #[serde(default = "default_hnsw_m")]
+    pub m: usize,
+
+    /// Candidate list size while inserting (default: 200).
+    #[serde(default = "default_hnsw_ef_construction")]
+    pub ef_construction: usize,
+
+    /// Candidate list size while searching (default: 100).
+    #[serde(default = "default_hnsw_ef_search")]
+    pub ef_search: usize,
+
+    /// Worker threads embedding items while the vector pipeline inserts
+    /// earlier ones (default: 1, embed and insert serially).
+    #[serde(default = "default_embed_concurrency")]
+    pub embed_concurrency: usize,
+}
+
+/// Allowed range for [`VectorSettings::m`].
+const HNSW_M_RANGE: std::ops::RangeInclusive<usize> = 2..=128;
+
+impl Default for VectorSettings {
+    fn default() -> Self {
+        Self {
+            m: default_hnsw_m(),
+            ef_construction: default_hnsw_ef_construction(),
+            ef_search: default_hnsw_ef_search(),
+            embed_concurrency: default_embed_concurrency(),
+        }
+    }
+}
+
+impl VectorSettings {
+    /// Validate HNSW parameters.
+    pub fn validate(&self) -> Result<(), String> {
+        if !HNSW_M_RANGE.contains(&self.m) {
+            return Err(format!(
+                "m must be {}-{}, got {}",
+                HNSW_M_RANGE.start(),
+                HNSW_M_RANGE.end(),
+                self.m
+            ));
+        }
+        if self.ef_construction < self.m {
+            return Err(format!(
+                "ef_construction must be >= m ({}), got {}",
+                self.m, self.ef_construction
+            ));
+        }
+        if self.ef_search == 0 {
+            return Err("ef_search must be > 0".to_string());
+        }
+        if self.embed_concurrency == 0 {
+            return Err("embed_concurrency must be > 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_hnsw_m() -> usize {
+    16
+}
+
+fn default_hnsw_ef_construction() -> usize {
+    200
+}
+
+fn default_hnsw_ef_search() -> usize {
+    100
+}
+
+fn default_embed_concurrency() -> usize {
+    1
 }
 
 /// Lifecycle automation configuration for index pruning and rebuilding.
@@ -412,6 +680,10 @@ pub struct LifecycleConfig {
     /// BM25 index lifecycle settings.
     #[serde(default)]
     pub bm25: Bm25LifecycleSettings,
+
+    /// TOC node version retention settings.
+    #[serde(default)]
+    pub toc_versions: TocVersionLifecycleSettings,
 }
 
 /// Vector index lifecycle settings.
@@ -443,6 +715,12 @@ pub struct VectorLifecycleSettings {
     /// Cron schedule for prune job (default: "0 3 * * *" = daily 3 AM).
     #[serde(default = "default_vector_prune_schedule")]
     pub prune_schedule: String,
+
+    /// Per-level retention overrides in days, keyed by TOC level
+    /// (`[lifecycle.vector.retention]`). Entries replace the matching
+    /// `*_retention_days` field; protected levels (month, year) are rejected.
+    #[serde(default)]
+    pub retention: BTreeMap<String, u32>,
 }
 
 fn default_vector_enabled() -> bool {
@@ -475,6 +753,7 @@ impl Default for VectorLifecycleSettings {
             day_retention_days: default_day_retention(),
             week_retention_days: default_week_retention(),
             prune_schedule: default_vector_prune_schedule(),
+            retention: BTreeMap::new(),
         }
     }
 }
@@ -513,6 +792,11 @@ pub struct Bm25LifecycleSettings {
     /// Retention days for week-level docs (default: 1825 = 5 years).
     #[serde(default = "default_week_retention")]
     pub week_retention_days: u32,
+
+    /// Per-level retention overrides in days (`[lifecycle.bm25.retention]`).
+    /// Same rules as [`VectorLifecycleSettings::retention`].
+    #[serde(default)]
+    pub retention: BTreeMap<String, u32>,
 }
 
 fn default_min_level() -> String {
@@ -537,6 +821,48 @@ impl Default for Bm25LifecycleSettings {
             grip_retention_days: default_grip_retention(),
             day_retention_days: default_bm25_day_retention(),
             week_retention_days: default_week_retention(),
+            retention: BTreeMap::new(),
+        }
+    }
+}
+
+/// TOC node version retention.
+///
+/// Maps to `[lifecycle.toc_versions]` section in config.toml. Every TOC
+/// write appends a version, so old versions are pruned by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TocVersionLifecycleSettings {
+    /// Enable the TOC version prune job (default: true).
+    #[serde(default = "default_toc_versions_enabled")]
+    pub enabled: bool,
+
+    /// Versions kept per node, including the latest (default: 5).
+    #[serde(default = "default_toc_versions_keep_last")]
+    pub keep_last: usize,
+
+    /// Cron schedule for the prune job (default: "0 30 3 * * 0" = Sunday 3:30 AM).
+    #[serde(default = "default_toc_versions_prune_schedule")]
+    pub prune_schedule: String,
+}
+
+fn default_toc_versions_enabled() -> bool {
+    true
+}
+
+fn default_toc_versions_keep_last() -> usize {
+    5
+}
+
+fn default_toc_versions_prune_schedule() -> String {
+    "0 30 3 * * 0".to_string()
+}
+
+impl Default for TocVersionLifecycleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_toc_versions_enabled(),
+            keep_last: default_toc_versions_keep_last(),
+            prune_schedule: default_toc_versions_prune_schedule(),
         }
     }
 }
@@ -569,6 +895,30 @@ fn default_search_index_path() -> String {
         .to_string()
 }
 
+fn default_outbox_lag_warn_threshold() -> u64 {
+    10_000
+}
+
+fn default_auto_rebuild_on_start() -> bool {
+    true
+}
+
+fn default_shutdown_timeout_secs() -> u64 {
+    30
+}
+
+fn default_topic_refresh_threshold() -> usize {
+    50
+}
+
+fn default_max_route_limit() -> u32 {
+    100
+}
+
+fn default_block_cache_mb() -> usize {
+    64
+}
+
 fn default_vector_index_path() -> String {
     ProjectDirs::from("", "", "agent-memory")
         .map(|p| p.data_local_dir().join("vector-index"))
@@ -587,6 +937,7 @@ impl Default for Settings {
             agent_id: None,
             summarizer: SummarizerSettings::default(),
             log_level: default_log_level(),
+            log_format: LogFormat::default(),
             search_index_path: default_search_index_path(),
             vector_index_path: default_vector_index_path(),
             dedup: DedupConfig::default(),
@@ -596,6 +947,18 @@ impl Default for Settings {
             lifecycle: LifecycleConfig::default(),
             episodic: EpisodicConfig::default(),
             projects: CrossProjectConfig::default(),
+            outbox_lag_warn_threshold: default_outbox_lag_warn_threshold(),
+            auto_rebuild_on_start: default_auto_rebuild_on_start(),
+            shutdown_timeout_secs: default_shutdown_timeout_secs(),
+            ingest_rate_limit_per_sec: None,
+            index_raw_events: false,
+            topic_refresh_threshold: default_topic_refresh_threshold(),
+            redaction_patterns: Vec::new(),
+            max_route_limit: default_max_route_limit(),
+            block_cache_mb: default_block_cache_mb(),
+            scheduler: SchedulerSettings::default(),
+            vector: VectorSettings::default(),
+            hybrid_cache: HybridCacheSettings::default(),
         }
     }
 }
@@ -609,6 +972,28 @@ impl Settings {
     ///
     /// CLI flags should be applied by the caller after this returns.
     pub fn load(cli_config_path: Option<&str>) -> Result<Self, MemoryError> {
+        Self::layered_config(cli_config_path)?
+            .try_deserialize()
+            .map_err(|e| MemoryError::Config(e.to_string()))
+    }
+
+    /// Load a config section owned by another crate (e.g. `[topics]`) from
+    /// the same layered sources as [`load`](Self::load).
+    ///
+    /// Returns None if the section is not set anywhere.
+    pub fn load_section<T: serde::de::DeserializeOwned>(
+        cli_config_path: Option<&str>,
+        key: &str,
+    ) -> Result<Option<T>, MemoryError> {
+        match Self::layered_config(cli_config_path)?.get::<T>(key) {
+            Ok(section) => Ok(Some(section)),
+            Err(config::ConfigError::NotFound(_)) => Ok(None),
+            Err(e) => Err(MemoryError::Config(format!("[{}]: {}", key, e))),
+        }
+    }
+
+    /// Defaults, config files and environment, merged in precedence order.
+    fn layered_config(cli_config_path: Option<&str>) -> Result<Config, MemoryError> {
         // Get default config file location (CFG-03)
         let config_dir = ProjectDirs::from("", "", "agent-memory")
             .map(|p| p.config_dir().to_path_buf())
@@ -626,6 +1011,8 @@ impl Settings {
             .map_err(|e| MemoryError::Config(e.to_string()))?
             .set_default("log_level", default_log_level())
             .map_err(|e| MemoryError::Config(e.to_string()))?
+            .set_default("log_format", LogFormat::default().as_str())
+            .map_err(|e| MemoryError::Config(e.to_string()))?
             .set_default("summarizer.provider", default_summarizer_provider())
             .map_err(|e| MemoryError::Config(e.to_string()))?
             .set_default("summarizer.model", default_summarizer_model())
@@ -650,12 +1037,8 @@ impl Settings {
                 .try_parsing(true),
         );
 
-        let config = builder
+        builder
             .build()
-            .map_err(|e| MemoryError::Config(e.to_string()))?;
-
-        config
-            .try_deserialize()
             .map_err(|e| MemoryError::Config(e.to_string()))
     }
 
@@ -699,6 +1082,40 @@ mod tests {
         assert_eq!(settings.grpc_port, 50051);
         assert_eq!(settings.grpc_host, "0.0.0.0");
         assert_eq!(settings.summarizer.provider, "openai");
+        assert!(settings.summarizer.prompt_template.is_none());
+        assert!(settings.summarizer.rollup_prompt_template.is_none());
+        assert!(settings.auto_rebuild_on_start);
+        assert_eq!(settings.shutdown_timeout_secs, 30);
+        assert_eq!(settings.ingest_rate_limit_per_sec, None);
+        assert!(!settings.index_raw_events);
+        assert_eq!(settings.topic_refresh_threshold, 50);
+        assert_eq!(settings.max_route_limit, 100);
+        assert_eq!(settings.block_cache_mb, 64);
+        assert_eq!(settings.scheduler.default_timezone, "UTC");
+        assert_eq!(settings.scheduler.max_concurrent_jobs, 0);
+        assert_eq!(settings.vector, VectorSettings::default());
+        assert_eq!(settings.hybrid_cache.capacity, 256);
+        assert_eq!(settings.hybrid_cache.ttl_secs, 60);
+    }
+
+    #[test]
+    fn test_shutdown_timeout_drain_alias() {
+        let settings: Settings = serde_json::from_str(r#"{"shutdown_timeout_secs": 120}"#).unwrap();
+        assert_eq!(settings.shutdown_timeout_secs, 120);
+
+        // Configs written before the scheduler shared this timeout
+        let settings: Settings = serde_json::from_str(r#"{"drain_timeout_secs": 45}"#).unwrap();
+        assert_eq!(settings.shutdown_timeout_secs, 45);
+    }
+
+    #[test]
+    fn test_log_format() {
+        assert_eq!(Settings::default().log_format, LogFormat::Pretty);
+        assert_eq!("JSON".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert!("xml".parse::<LogFormat>().is_err());
+
+        let settings: Settings = serde_json::from_str(r#"{"log_format": "json"}"#).unwrap();
+        assert_eq!(settings.log_format, LogFormat::Json);
     }
 
     #[test]
@@ -728,6 +1145,7 @@ mod tests {
         assert_eq!(config.timeout_ms, 50);
         assert_eq!(config.min_text_length, 50);
         assert_eq!(config.buffer_capacity, 256);
+        assert_eq!(config.persist_interval_secs, 60);
     }
 
     #[test]
@@ -879,6 +1297,11 @@ mod tests {
         assert_eq!(config.bm25.grip_retention_days, 30);
         assert_eq!(config.bm25.day_retention_days, 180);
         assert_eq!(config.bm25.week_retention_days, 1825);
+
+        // TOC versions: pruned weekly, newest 5 kept
+        assert!(config.toc_versions.enabled);
+        assert_eq!(config.toc_versions.keep_last, 5);
+        assert_eq!(config.toc_versions.prune_schedule, "0 30 3 * * 0");
     }
 
     #[test]
@@ -892,6 +1315,116 @@ mod tests {
         assert_eq!(decoded.vector.prune_schedule, "0 3 * * *");
     }
 
+    #[test]
+    fn test_load_lifecycle_retention_overrides() {
+        let path = std::env::temp_dir().join(format!(
+            "agent-memory-lifecycle-{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "[lifecycle.vector]\n\
+             segment_retention_days = 14\n\
+             [lifecycle.vector.retention]\n\
+             day = 90\n\
+             [lifecycle.bm25]\n\
+             enabled = true\n\
+             [lifecycle.bm25.retention]\n\
+             week = 365\n\
+             [lifecycle.toc_versions]\n\
+             keep_last = 10\n",
+        )
+        .unwrap();
+        let settings = Settings::load(Some(path.to_str().unwrap()));
+        std::fs::remove_file(&path).unwrap();
+        let settings = settings.unwrap();
+
+        assert_eq!(settings.lifecycle.vector.segment_retention_days, 14);
+        assert_eq!(settings.lifecycle.vector.retention.get("day"), Some(&90));
+        assert!(settings.lifecycle.bm25.enabled);
+        assert_eq!(settings.lifecycle.bm25.retention.get("week"), Some(&365));
+        assert_eq!(settings.lifecycle.bm25.day_retention_days, 180);
+        assert_eq!(settings.lifecycle.toc_versions.keep_last, 10);
+        assert!(settings.lifecycle.toc_versions.enabled);
+    }
+
+    #[test]
+    fn test_load_vector_settings() {
+        let path =
+            std::env::temp_dir().join(format!("agent-memory-vector-{}.toml", std::process::id()));
+        std::fs::write(&path, "[vector]\nm = 32\nef_construction = 400\n").unwrap();
+        let settings = Settings::load(Some(path.to_str().unwrap()));
+        std::fs::remove_file(&path).unwrap();
+        let settings = settings.unwrap();
+
+        assert_eq!(settings.vector.m, 32);
+        assert_eq!(settings.vector.ef_construction, 400);
+        assert_eq!(settings.vector.ef_search, 100);
+        assert!(settings.vector.validate().is_ok());
+    }
+
+    #[test]
+    fn test_load_section() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Extraction {
+            similarity_threshold: f32,
+        }
+
+        let path =
+            std::env::temp_dir().join(format!("agent-memory-section-{}.toml", std::process::id()));
+        std::fs::write(&path, "[topics.extraction]\nsimilarity_threshold = 0.9\n").unwrap();
+        let path_str = path.to_str().unwrap();
+        let present = Settings::load_section::<Extraction>(Some(path_str), "topics.extraction");
+        let missing = Settings::load_section::<Extraction>(Some(path_str), "topics.labeling");
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            present.unwrap(),
+            Some(Extraction {
+                similarity_threshold: 0.9
+            })
+        );
+        assert_eq!(missing.unwrap(), None);
+    }
+
+    #[test]
+    fn test_vector_settings_validation() {
+        assert!(VectorSettings::default().validate().is_ok());
+
+        for m in [0, 1, 129] {
+            let settings = VectorSettings {
+                m,
+                ..Default::default()
+            };
+            assert!(settings.validate().unwrap_err().contains("m must be"));
+        }
+
+        let settings = VectorSettings {
+            m: 48,
+            ef_construction: 32,
+            ..Default::default()
+        };
+        assert!(settings
+            .validate()
+            .unwrap_err()
+            .contains("ef_construction must be >= m"));
+
+        let settings = VectorSettings {
+            ef_search: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = VectorSettings {
+            embed_concurrency: 0,
+            ..Default::default()
+        };
+        assert!(settings
+            .validate()
+            .unwrap_err()
+            .contains("embed_concurrency"));
+    }
+
     #[test]
     fn test_settings_lifecycle_default() {
         let settings = Settings::default();
@@ -3,9 +3,14 @@
 //! Provides a fixed-capacity ring buffer that stores recent event embeddings
 //! and supports brute-force cosine similarity search. Used by the dedup gate
 //! to detect near-duplicate events before they reach the HNSW index.
+//!
+//! The buffer can be captured as an [`InFlightBufferSnapshot`] and restored
+//! later, so recently seen events are still recognized after a restart.
+
+use serde::{Deserialize, Serialize};
 
 /// A single entry in the in-flight dedup buffer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BufferEntry {
     /// The event ID associated with this embedding.
     pub event_id: String,
@@ -13,6 +18,15 @@ pub struct BufferEntry {
     pub embedding: Vec<f32>,
 }
 
+/// Serializable contents of an [`InFlightBuffer`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InFlightBufferSnapshot {
+    /// Embedding dimension of the buffer the entries came from.
+    pub dimension: usize,
+    /// Buffered entries, oldest first.
+    pub entries: Vec<BufferEntry>,
+}
+
 /// Fixed-capacity ring buffer for recent event embeddings.
 ///
 /// Stores up to `capacity` embeddings and finds the most similar entry
@@ -25,6 +39,9 @@ pub struct InFlightBuffer {
     dimension: usize,
     head: usize,
     count: usize,
+    /// Bumped on every change, so callers can tell whether the contents
+    /// moved since they last looked
+    generation: u64,
 }
 
 impl InFlightBuffer {
@@ -36,6 +53,7 @@ impl InFlightBuffer {
             dimension,
             head: 0,
             count: 0,
+            generation: 0,
         }
     }
 
@@ -60,6 +78,7 @@ impl InFlightBuffer {
         if self.count < self.capacity {
             self.count += 1;
         }
+        self.generation += 1;
     }
 
     /// Find the most similar entry above the given threshold.
@@ -93,6 +112,11 @@ impl InFlightBuffer {
         self.count == 0
     }
 
+    /// Counter that changes whenever the contents change.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Returns the maximum capacity of the buffer.
     pub fn capacity(&self) -> usize {
         self.capacity
@@ -105,6 +129,38 @@ impl InFlightBuffer {
         }
         self.head = 0;
         self.count = 0;
+        self.generation += 1;
+    }
+
+    /// Copy the buffered entries, oldest first.
+    pub fn snapshot(&self) -> InFlightBufferSnapshot {
+        let entries = (0..self.capacity)
+            .filter_map(|i| self.entries[(self.head + i) % self.capacity].clone())
+            .collect();
+        InFlightBufferSnapshot {
+            dimension: self.dimension,
+            entries,
+        }
+    }
+
+    /// Replace the buffer contents with a snapshot.
+    ///
+    /// Only the newest `capacity` entries are kept, so a snapshot taken with
+    /// a larger buffer is trimmed to this buffer's window. A snapshot of a
+    /// different embedding dimension (e.g. after a model change) is ignored.
+    /// Returns the number of entries restored.
+    pub fn restore(&mut self, snapshot: InFlightBufferSnapshot) -> usize {
+        if snapshot.dimension != self.dimension {
+            return 0;
+        }
+        self.clear();
+        let skip = snapshot.entries.len().saturating_sub(self.capacity);
+        for entry in snapshot.entries.into_iter().skip(skip) {
+            if entry.embedding.len() == self.dimension {
+                self.push(entry.event_id, entry.embedding);
+            }
+        }
+        self.count
     }
 }
 
@@ -199,10 +255,61 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_snapshot_restore_keeps_newest_window() {
+        let mut buf = InFlightBuffer::new(3, 4);
+        for i in 0..4 {
+            buf.push(format!("evt-{i}"), unit_vector(4, i));
+        }
+
+        let snapshot = buf.snapshot();
+        let ids: Vec<&str> = snapshot
+            .entries
+            .iter()
+            .map(|e| e.event_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["evt-1", "evt-2", "evt-3"]);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let decoded: InFlightBufferSnapshot = serde_json::from_str(&json).unwrap();
+
+        // A smaller window keeps only the newest entries
+        let mut restored = InFlightBuffer::new(2, 4);
+        assert_eq!(restored.restore(decoded.clone()), 2);
+        assert!(restored.find_similar(&unit_vector(4, 1), 0.9).is_none());
+        assert_eq!(
+            restored.find_similar(&unit_vector(4, 3), 0.9).unwrap().0,
+            "evt-3"
+        );
+
+        // A different embedding dimension is not restored
+        let mut other = InFlightBuffer::new(3, 8);
+        assert_eq!(other.restore(decoded), 0);
+        assert!(other.is_empty());
+    }
+
     #[test]
     #[should_panic(expected = "embedding dimension mismatch")]
     fn test_dimension_mismatch_panics() {
         let mut buf = InFlightBuffer::new(16, 4);
         buf.push("evt-1".to_string(), vec![0.5, 0.5, 0.5]); // wrong dimension
     }
+
+    #[test]
+    fn test_generation_tracks_changes() {
+        let mut buffer = InFlightBuffer::new(2, 2);
+        let start = buffer.generation();
+
+        buffer.push("a".to_string(), unit_vector(2, 0));
+        let after_push = buffer.generation();
+        assert_ne!(after_push, start);
+
+        // Reads leave it alone
+        buffer.find_similar(&unit_vector(2, 0), 0.5);
+        let _ = buffer.snapshot();
+        assert_eq!(buffer.generation(), after_push);
+
+        buffer.clear();
+        assert_ne!(buffer.generation(), after_push);
+    }
 }
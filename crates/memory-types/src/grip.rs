@@ -9,12 +9,75 @@
 //! - `salience_score`: Importance score calculated at write time
 //! - `memory_kind`: Classification (observation, preference, procedure, etc.)
 //! - `is_pinned`: Whether the grip is pinned for boosted importance
+//!
+//! Grips also record a [`GripProvenance`] naming the summarizer run that
+//! created them, so grips from different runs can be told apart.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::salience::{default_salience, MemoryKind};
 
+/// Which summarizer run produced a grip.
+///
+/// All fields default when missing, so grips written before provenance was
+/// recorded deserialize with an empty `summarizer_id`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GripProvenance {
+    /// Summarizer that produced the grip's summary (e.g. `api:gpt-4o-mini`)
+    pub summarizer_id: String,
+
+    /// Version of that summarizer
+    pub summarizer_version: String,
+
+    /// When the grip was created
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// TOC rebuild that created the grip, if it was not live ingestion
+    pub rebuild_id: Option<String>,
+}
+
+impl GripProvenance {
+    /// Provenance for a grip created now by the given summarizer.
+    pub fn new(summarizer_id: impl Into<String>, summarizer_version: impl Into<String>) -> Self {
+        Self {
+            summarizer_id: summarizer_id.into(),
+            summarizer_version: summarizer_version.into(),
+            created_at: Some(Utc::now()),
+            rebuild_id: None,
+        }
+    }
+
+    /// Mark the grip as created by a TOC rebuild.
+    pub fn with_rebuild_id(mut self, rebuild_id: impl Into<String>) -> Self {
+        self.rebuild_id = Some(rebuild_id.into());
+        self
+    }
+
+    /// Whether provenance was recorded (false for grips predating it).
+    pub fn is_known(&self) -> bool {
+        !self.summarizer_id.is_empty()
+    }
+
+    /// Label identifying the run, for grouping grips.
+    ///
+    /// `summarizer_id@summarizer_version`, followed by ` rebuild=<id>` for
+    /// rebuilds; `unknown` replaces the summarizer part when it was not recorded.
+    pub fn run_label(&self) -> String {
+        let mut label = if self.is_known() {
+            format!("{}@{}", self.summarizer_id, self.summarizer_version)
+        } else {
+            "unknown".to_string()
+        };
+        if let Some(ref rebuild_id) = self.rebuild_id {
+            label.push_str(&format!(" rebuild={}", rebuild_id));
+        }
+        label
+    }
+}
+
 /// A grip anchors a summary excerpt to source events.
 ///
 /// Per GRIP-01: Contains excerpt, event_id_start, event_id_end, timestamp, source.
@@ -70,6 +133,11 @@ pub struct Grip {
     /// Default: false for existing v2.0.0 data.
     #[serde(default)]
     pub is_pinned: bool,
+
+    /// Summarizer run that produced this grip.
+    /// Default: unknown provenance for grips written before it was recorded.
+    #[serde(default)]
+    pub provenance: GripProvenance,
 }
 
 impl Grip {
@@ -94,6 +162,7 @@ impl Grip {
             salience_score: default_salience(),
             memory_kind: MemoryKind::default(),
             is_pinned: false,
+            provenance: GripProvenance::default(),
         }
     }
 
@@ -131,6 +200,12 @@ impl Grip {
         self
     }
 
+    /// Record which summarizer run produced this grip.
+    pub fn with_provenance(mut self, provenance: GripProvenance) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
     /// Serialize to JSON bytes
     pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
         serde_json::to_vec(self)
@@ -262,5 +337,58 @@ mod tests {
         // Verify other fields loaded correctly
         assert_eq!(grip.grip_id, "grip-001");
         assert_eq!(grip.excerpt, "User discussed Rust patterns");
+
+        // No provenance was recorded
+        assert_eq!(grip.provenance, GripProvenance::default());
+        assert_eq!(grip.provenance.run_label(), "unknown");
+    }
+
+    #[test]
+    fn test_grip_provenance_roundtrip() {
+        let provenance = GripProvenance::new("api:gpt-4o-mini", "0.4.0").with_rebuild_id("rb-1");
+        let grip = Grip::new(
+            "grip-123".to_string(),
+            "Test excerpt".to_string(),
+            "event-001".to_string(),
+            "event-003".to_string(),
+            Utc::now(),
+            "test".to_string(),
+        )
+        .with_provenance(provenance.clone());
+
+        let decoded = Grip::from_bytes(&grip.to_bytes().unwrap()).unwrap();
+
+        assert!(decoded.provenance.is_known());
+        assert_eq!(decoded.provenance.summarizer_id, "api:gpt-4o-mini");
+        assert_eq!(decoded.provenance.summarizer_version, "0.4.0");
+        assert_eq!(decoded.provenance.rebuild_id.as_deref(), Some("rb-1"));
+        assert_eq!(
+            decoded.provenance.created_at.map(|t| t.timestamp_millis()),
+            provenance.created_at.map(|t| t.timestamp_millis())
+        );
+        assert_eq!(
+            decoded.provenance.run_label(),
+            "api:gpt-4o-mini@0.4.0 rebuild=rb-1"
+        );
+    }
+
+    #[test]
+    fn test_grip_partial_provenance_defaults() {
+        let json = r#"{
+            "grip_id": "grip-002",
+            "excerpt": "Partial",
+            "event_id_start": "event-001",
+            "event_id_end": "event-002",
+            "timestamp": 1735689600000,
+            "source": "segment_summarizer",
+            "provenance": { "summarizer_id": "mock" }
+        }"#;
+
+        let grip: Grip = serde_json::from_str(json).unwrap();
+
+        assert_eq!(grip.provenance.summarizer_id, "mock");
+        assert!(grip.provenance.summarizer_version.is_empty());
+        assert!(grip.provenance.created_at.is_none());
+        assert!(grip.provenance.rebuild_id.is_none());
     }
 }
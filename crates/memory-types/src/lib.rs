@@ -26,6 +26,7 @@ pub mod episode;
 pub mod error;
 pub mod event;
 pub mod grip;
+pub mod index_checkpoint;
 pub mod outbox;
 pub mod salience;
 pub mod segment;
@@ -34,20 +35,22 @@ pub mod usage;
 
 // Re-export main types at crate root
 pub use config::{
-    Bm25LifecycleSettings, CrossProjectConfig, DedupConfig, EpisodicConfig, LifecycleConfig,
-    MultiAgentMode, NoveltyConfig, Settings, StalenessConfig, SummarizerSettings,
-    VectorLifecycleSettings,
+    Bm25LifecycleSettings, CrossProjectConfig, DedupConfig, EpisodicConfig, HybridCacheSettings,
+    LifecycleConfig, LogFormat, MultiAgentMode, NoveltyConfig, SchedulerSettings, Settings,
+    StalenessConfig, SummarizerSettings, TocVersionLifecycleSettings, VectorLifecycleSettings,
+    VectorSettings,
 };
-pub use dedup::{BufferEntry, InFlightBuffer};
+pub use dedup::{BufferEntry, InFlightBuffer, InFlightBufferSnapshot};
 pub use episode::{Action, ActionResult, Episode, EpisodeStatus};
 pub use error::MemoryError;
 pub use event::{Event, EventRole, EventType};
-pub use grip::Grip;
+pub use grip::{Grip, GripProvenance};
+pub use index_checkpoint::{IndexCheckpoint, IndexType};
 pub use outbox::{OutboxAction, OutboxEntry};
 pub use salience::{
     calculate_salience, classify_memory_kind, default_salience, MemoryKind, SalienceConfig,
     SalienceScorer,
 };
 pub use segment::Segment;
-pub use toc::{TocBullet, TocLevel, TocNode};
+pub use toc::{is_rolling_node_id, TocBullet, TocLevel, TocNode, TocNodeDiff};
 pub use usage::{usage_penalty, UsageConfig, UsageStats};
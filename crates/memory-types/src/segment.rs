@@ -18,15 +18,15 @@ pub struct Segment {
     /// Unique segment identifier
     pub segment_id: String,
 
-    /// Events in the overlap window (from previous segment for context)
-    /// Per TOC-04: Provides context continuity
-    #[serde(default)]
-    pub overlap_events: Vec<Event>,
+    /// Tail events of the previous segment, for context only
+    /// Per TOC-04: Provides context continuity without counting as content
+    #[serde(default, alias = "overlap_events")]
+    pub context_prefix: Vec<Event>,
 
-    /// Events in this segment (excluding overlap)
+    /// Events in this segment (excluding context prefix)
     pub events: Vec<Event>,
 
-    /// Start time of the segment (first event, excluding overlap)
+    /// Start time of the segment (first event, excluding context prefix)
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub start_time: DateTime<Utc>,
 
@@ -34,7 +34,7 @@ pub struct Segment {
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub end_time: DateTime<Utc>,
 
-    /// Token count of events (excluding overlap)
+    /// Token count of events (excluding context prefix)
     pub token_count: usize,
 }
 
@@ -49,7 +49,7 @@ impl Segment {
     ) -> Self {
         Self {
             segment_id,
-            overlap_events: Vec::new(),
+            context_prefix: Vec::new(),
             events,
             start_time,
             end_time,
@@ -57,15 +57,15 @@ impl Segment {
         }
     }
 
-    /// Add overlap events from previous segment
-    pub fn with_overlap(mut self, overlap_events: Vec<Event>) -> Self {
-        self.overlap_events = overlap_events;
+    /// Add context events from the previous segment
+    pub fn with_context_prefix(mut self, context_prefix: Vec<Event>) -> Self {
+        self.context_prefix = context_prefix;
         self
     }
 
-    /// Get all events (overlap + main) for summarization
+    /// Get all events (context prefix + main) in order
     pub fn all_events(&self) -> Vec<&Event> {
-        self.overlap_events
+        self.context_prefix
             .iter()
             .chain(self.events.iter())
             .collect()
@@ -111,19 +111,36 @@ mod tests {
     }
 
     #[test]
-    fn test_segment_with_overlap() {
-        let overlap = vec![create_test_event("Context")];
+    fn test_segment_with_context_prefix() {
+        let context = vec![create_test_event("Context")];
         let events = vec![create_test_event("Main")];
         let start = events[0].timestamp;
         let end = events[0].timestamp;
 
-        let segment =
-            Segment::new("seg-123".to_string(), events, start, end, 50).with_overlap(overlap);
+        let segment = Segment::new("seg-123".to_string(), events, start, end, 50)
+            .with_context_prefix(context);
 
-        assert_eq!(segment.overlap_events.len(), 1);
+        assert_eq!(segment.context_prefix.len(), 1);
         assert_eq!(segment.all_events().len(), 2);
     }
 
+    #[test]
+    fn test_segment_reads_legacy_overlap_events() {
+        let context = vec![create_test_event("Context")];
+        let events = vec![create_test_event("Main")];
+        let start = events[0].timestamp;
+
+        let segment = Segment::new("seg-123".to_string(), events, start, start, 50)
+            .with_context_prefix(context);
+        let json = String::from_utf8(segment.to_bytes().unwrap())
+            .unwrap()
+            .replace("context_prefix", "overlap_events");
+
+        let decoded = Segment::from_bytes(json.as_bytes()).unwrap();
+        assert_eq!(decoded.context_prefix.len(), 1);
+        assert_eq!(decoded.context_prefix[0].text, "Context");
+    }
+
     #[test]
     fn test_segment_serialization() {
         let events = vec![create_test_event("Test")];
@@ -177,6 +177,22 @@ pub struct TocNode {
     /// Default: None for backward compatibility.
     #[serde(default)]
     pub last_accessed_ms: Option<i64>,
+
+    /// When storage wrote this version, in milliseconds.
+    ///
+    /// Set on each `put_toc_node`; `created_at` is carried over between
+    /// versions. Default: None for versions written before this field.
+    #[serde(default)]
+    pub version_written_ms: Option<i64>,
+}
+
+/// Whether a node ID names a rolling rollup window
+/// (`toc:{level}:rolling:{window_ms}:{start_ms}`).
+///
+/// Rolling windows sit outside the calendar hierarchy: they have no parent,
+/// so TOC listings and search indexes leave them out.
+pub fn is_rolling_node_id(node_id: &str) -> bool {
+    node_id.split(':').nth(2) == Some("rolling")
 }
 
 impl TocNode {
@@ -208,6 +224,7 @@ impl TocNode {
             // Phase 40: Usage tracking
             access_count: 0,
             last_accessed_ms: None,
+            version_written_ms: None,
         }
     }
 
@@ -266,12 +283,114 @@ impl TocNode {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, serde_json::Error> {
         serde_json::from_slice(bytes)
     }
+
+    /// Describe what changed going from this node to `other`.
+    ///
+    /// Bullets are compared by text; bullets, keywords and children are
+    /// reported as additions and removals in the order they appear.
+    pub fn diff(&self, other: &TocNode) -> TocNodeDiff {
+        let bullet_texts = |node: &TocNode| -> Vec<String> {
+            node.bullets.iter().map(|b| b.text.clone()).collect()
+        };
+        let (old_bullets, new_bullets) = (bullet_texts(self), bullet_texts(other));
+
+        TocNodeDiff {
+            title: (self.title != other.title).then(|| (self.title.clone(), other.title.clone())),
+            bullets_added: missing_from(&new_bullets, &old_bullets),
+            bullets_removed: missing_from(&old_bullets, &new_bullets),
+            keywords_added: missing_from(&other.keywords, &self.keywords),
+            keywords_removed: missing_from(&self.keywords, &other.keywords),
+            children_added: missing_from(&other.child_node_ids, &self.child_node_ids),
+            children_removed: missing_from(&self.child_node_ids, &other.child_node_ids),
+        }
+    }
+}
+
+/// Items of `items` not present in `other`, in order.
+fn missing_from(items: &[String], other: &[String]) -> Vec<String> {
+    items
+        .iter()
+        .filter(|item| !other.contains(item))
+        .cloned()
+        .collect()
+}
+
+/// Changes between two versions of a TOC node, from [`TocNode::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TocNodeDiff {
+    /// Old and new title, if the title changed
+    pub title: Option<(String, String)>,
+
+    /// Bullet texts only in the newer version
+    pub bullets_added: Vec<String>,
+
+    /// Bullet texts only in the older version
+    pub bullets_removed: Vec<String>,
+
+    /// Keywords only in the newer version
+    pub keywords_added: Vec<String>,
+
+    /// Keywords only in the older version
+    pub keywords_removed: Vec<String>,
+
+    /// Child node IDs only in the newer version
+    pub children_added: Vec<String>,
+
+    /// Child node IDs only in the older version
+    pub children_removed: Vec<String>,
+}
+
+impl TocNodeDiff {
+    /// True if none of the compared fields changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_toc_node_diff() {
+        let mut old = TocNode::new(
+            "toc:day:2024-01-15".to_string(),
+            TocLevel::Day,
+            "Auth work".to_string(),
+            Utc::now(),
+            Utc::now(),
+        );
+        old.bullets = vec![TocBullet::new("Added JWT"), TocBullet::new("Fixed login")];
+        old.keywords = vec!["auth".to_string(), "jwt".to_string()];
+        old.child_node_ids = vec!["seg-1".to_string()];
+
+        let mut new = old.clone();
+        new.version = 2;
+        new.title = "Auth and sessions".to_string();
+        new.bullets = vec![
+            TocBullet::new("Added JWT"),
+            TocBullet::new("Added sessions"),
+        ];
+        new.keywords = vec!["auth".to_string(), "sessions".to_string()];
+        new.child_node_ids = vec!["seg-1".to_string(), "seg-2".to_string()];
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.title,
+            Some(("Auth work".to_string(), "Auth and sessions".to_string()))
+        );
+        assert_eq!(diff.bullets_added, vec!["Added sessions"]);
+        assert_eq!(diff.bullets_removed, vec!["Fixed login"]);
+        assert_eq!(diff.keywords_added, vec!["sessions"]);
+        assert_eq!(diff.keywords_removed, vec!["jwt"]);
+        assert_eq!(diff.children_added, vec!["seg-2"]);
+        assert!(diff.children_removed.is_empty());
+        assert!(!diff.is_empty());
+
+        // Version and timestamps are not content
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+
     #[test]
     fn test_toc_level_hierarchy() {
         assert_eq!(TocLevel::Segment.parent(), Some(TocLevel::Day));
@@ -13,6 +13,10 @@ pub enum VectorError {
     #[error("Dimension mismatch: expected {expected}, got {actual}")]
     DimensionMismatch { expected: usize, actual: usize },
 
+    /// Per-query search breadth is smaller than the number of results asked for
+    #[error("ef_search ({ef_search}) must be >= top_k ({k})")]
+    InvalidEfSearch { ef_search: usize, k: usize },
+
     /// Vector not found
     #[error("Vector not found: {0}")]
     NotFound(u64),
@@ -33,10 +37,18 @@ pub enum VectorError {
     #[error("Index capacity reached: {0}")]
     CapacityReached(usize),
 
+    /// Index file failed an integrity check
+    #[error("Index corrupted: {0}")]
+    Corrupted(String),
+
     /// Index not initialized
     #[error("Index not initialized")]
     NotInitialized,
 
+    /// Checkpoint storage error
+    #[error("Storage error: {0}")]
+    Storage(#[from] memory_storage::StorageError),
+
     /// Embedding error
     #[error("Embedding error: {0}")]
     Embedding(#[from] memory_embeddings::EmbeddingError),
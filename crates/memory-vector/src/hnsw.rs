@@ -9,11 +9,13 @@ use std::path::PathBuf;
 use std::sync::RwLock;
 
 use memory_embeddings::Embedding;
-use tracing::{debug, info};
+use memory_types::VectorSettings;
+use tracing::{debug, info, warn};
 use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
 
 use crate::error::VectorError;
 use crate::index::{IndexStats, SearchResult, VectorIndex};
+use crate::metadata::VectorMetadata;
 
 /// HNSW index configuration
 #[derive(Debug, Clone)]
@@ -69,6 +71,33 @@ impl HnswConfig {
         self.capacity = capacity;
         self
     }
+
+    /// Build from the `[vector]` settings section.
+    pub fn from_settings(
+        dimension: usize,
+        index_path: impl Into<PathBuf>,
+        settings: &VectorSettings,
+    ) -> Result<Self, String> {
+        settings.validate()?;
+        Ok(Self::new(dimension, index_path)
+            .with_connectivity(settings.m)
+            .with_expansion(settings.ef_construction, settings.ef_search))
+    }
+}
+
+/// Outcome of a successful [`HnswIndex::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HnswVerifyReport {
+    /// Whether an index file has been saved yet
+    pub file_present: bool,
+    /// Size of the index file in bytes
+    pub file_size: u64,
+    /// Vector dimension recorded in the file
+    pub dimension: usize,
+    /// Live vectors recorded in the file
+    pub vectors_present: usize,
+    /// Deleted slots still held by the graph
+    pub vectors_deleted: usize,
 }
 
 /// HNSW index wrapper around usearch.
@@ -81,16 +110,7 @@ impl HnswIndex {
     /// Create a new HNSW index or open existing one.
     pub fn open_or_create(config: HnswConfig) -> Result<Self, VectorError> {
         let index_file = config.index_path.join("hnsw.usearch");
-
-        let options = IndexOptions {
-            dimensions: config.dimension,
-            metric: MetricKind::Cos, // Cosine similarity
-            quantization: ScalarKind::F32,
-            connectivity: config.connectivity,
-            expansion_add: config.expansion_add,
-            expansion_search: config.expansion_search,
-            multi: false, // Single vector per key
-        };
+        let options = index_options(&config);
 
         let index = if index_file.exists() {
             info!(path = ?index_file, "Opening existing vector index");
@@ -101,6 +121,15 @@ impl HnswIndex {
                     .ok_or_else(|| VectorError::Index("Invalid path encoding".to_string()))?,
             )
             .map_err(|e| VectorError::Index(format!("Failed to load: {}", e)))?;
+            // The graph keeps the M it was built with
+            if idx.connectivity() != config.connectivity {
+                warn!(
+                    path = ?index_file,
+                    index_m = idx.connectivity(),
+                    configured_m = config.connectivity,
+                    "Vector index was built with a different M; rebuild it to apply the new value"
+                );
+            }
             idx
         } else {
             info!(path = ?index_file, dim = config.dimension, "Creating new vector index");
@@ -122,6 +151,101 @@ impl HnswIndex {
         self.config.index_path.join("hnsw.usearch")
     }
 
+    /// M the graph was built with.
+    ///
+    /// A loaded index keeps its saved M even if the config asked for another.
+    pub fn connectivity(&self) -> usize {
+        self.index.read().unwrap().connectivity()
+    }
+
+    /// Check the saved index file without loading or modifying it.
+    ///
+    /// See [`HnswIndex::verify_file`].
+    pub fn verify(&self, metadata: &VectorMetadata) -> Result<HnswVerifyReport, VectorError> {
+        Self::verify_file(&self.config, metadata)
+    }
+
+    /// Check the index file at `config.index_path` without loading it.
+    ///
+    /// The file is memory-mapped read-only through usearch's `view`, which
+    /// rejects a bad header or a graph that doesn't fit in the file. The
+    /// dimension is then checked against the config and the live vector
+    /// count against `metadata`. Failures are reported as
+    /// [`VectorError::Corrupted`].
+    pub fn verify_file(
+        config: &HnswConfig,
+        metadata: &VectorMetadata,
+    ) -> Result<HnswVerifyReport, VectorError> {
+        let expected = metadata.count()?;
+        let index_file = config.index_path.join("hnsw.usearch");
+        let corrupted =
+            |msg: String| VectorError::Corrupted(format!("{}: {}", index_file.display(), msg));
+        if !index_file.exists() {
+            if expected > 0 {
+                return Err(corrupted(format!(
+                    "missing but metadata has {} entries",
+                    expected
+                )));
+            }
+            return Ok(HnswVerifyReport {
+                file_present: false,
+                file_size: 0,
+                dimension: config.dimension,
+                vectors_present: 0,
+                vectors_deleted: 0,
+            });
+        }
+
+        let file_size = std::fs::metadata(&index_file)?.len();
+        let view =
+            Index::new(&index_options(config)).map_err(|e| VectorError::Index(e.to_string()))?;
+        view.view(
+            index_file
+                .to_str()
+                .ok_or_else(|| VectorError::Index("Invalid path encoding".to_string()))?,
+        )
+        .map_err(|e| corrupted(e.to_string()))?;
+
+        let report = HnswVerifyReport {
+            file_present: true,
+            file_size,
+            dimension: view.dimensions(),
+            vectors_present: view.size(),
+            // A viewed index has one slot per saved node, live or deleted
+            vectors_deleted: view.capacity().saturating_sub(view.size()),
+        };
+        if report.dimension != config.dimension {
+            return Err(corrupted(format!(
+                "file dimension {} does not match configured dimension {}",
+                report.dimension, config.dimension
+            )));
+        }
+        if report.vectors_present != expected {
+            return Err(corrupted(format!(
+                "file holds {} vectors but metadata has {} entries",
+                report.vectors_present, expected
+            )));
+        }
+        debug!(path = ?index_file, vectors = report.vectors_present, "Vector index verified");
+        Ok(report)
+    }
+
+    /// Touch the index with a dummy search so the first real query
+    /// doesn't pay for faulting in the loaded graph.
+    pub fn warm_up(&self) -> Result<(), VectorError> {
+        let index = self.index.read().unwrap();
+        if index.size() == 0 {
+            return Ok(());
+        }
+        let mut probe = vec![0.0f32; self.config.dimension];
+        probe[0] = 1.0;
+        index
+            .search(&probe, 1)
+            .map_err(|e| VectorError::Index(e.to_string()))?;
+        debug!(vectors = index.size(), "Vector index warmed up");
+        Ok(())
+    }
+
     /// Retrieve a stored vector by its internal ID.
     ///
     /// Returns `None` if the ID is not present in the index.
@@ -140,6 +264,19 @@ impl HnswIndex {
     }
 }
 
+/// usearch options for an index built from `config`.
+fn index_options(config: &HnswConfig) -> IndexOptions {
+    IndexOptions {
+        dimensions: config.dimension,
+        metric: MetricKind::Cos, // Cosine similarity
+        quantization: ScalarKind::F32,
+        connectivity: config.connectivity,
+        expansion_add: config.expansion_add,
+        expansion_search: config.expansion_search,
+        multi: false, // Single vector per key
+    }
+}
+
 impl VectorIndex for HnswIndex {
     fn dimension(&self) -> usize {
         self.config.dimension
@@ -167,7 +304,13 @@ impl VectorIndex for HnswIndex {
         Ok(())
     }
 
-    fn search(&self, query: &Embedding, k: usize) -> Result<Vec<SearchResult>, VectorError> {
+    #[allow(clippy::readonly_write_lock)] // usearch::Index uses interior mutability
+    fn search_with_ef(
+        &self,
+        query: &Embedding,
+        k: usize,
+        ef_search: Option<usize>,
+    ) -> Result<Vec<SearchResult>, VectorError> {
         if query.dimension() != self.config.dimension {
             return Err(VectorError::DimensionMismatch {
                 expected: self.config.dimension,
@@ -175,10 +318,22 @@ impl VectorIndex for HnswIndex {
             });
         }
 
-        let index = self.index.read().unwrap();
-        let results = index
-            .search(&query.values, k)
-            .map_err(|e| VectorError::Index(e.to_string()))?;
+        let results = match ef_search {
+            Some(ef) if ef < k => {
+                return Err(VectorError::InvalidEfSearch { ef_search: ef, k });
+            }
+            Some(ef) if ef != self.config.expansion_search => {
+                // ef_search lives on the usearch index, so hold the write lock
+                // while it's overridden and restore the default before release.
+                let index = self.index.write().unwrap();
+                index.change_expansion_search(ef);
+                let results = index.search(&query.values, k);
+                index.change_expansion_search(self.config.expansion_search);
+                results
+            }
+            _ => self.index.read().unwrap().search(&query.values, k),
+        }
+        .map_err(|e| VectorError::Index(e.to_string()))?;
 
         let search_results: Vec<SearchResult> = results
             .keys
@@ -308,6 +463,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_search_with_hash_embedder() {
+        use memory_embeddings::{EmbeddingModel, HashEmbedder};
+
+        let embedder = HashEmbedder::new(64);
+        let docs = [
+            "Configure the RocksDB block cache size for the event store",
+            "Bake the sourdough bread at high heat with steam",
+            "Deploy the gRPC service behind a load balancer",
+        ];
+
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(64, temp.path()).with_capacity(100);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+        for (id, doc) in docs.iter().enumerate() {
+            index.add(id as u64, &embedder.embed(doc).unwrap()).unwrap();
+        }
+
+        // Identical text finds its own document with a perfect score
+        let results = index.search(&embedder.embed(docs[1]).unwrap(), 3).unwrap();
+        assert_eq!(results[0].vector_id, 1);
+        assert!((results[0].score - 1.0).abs() < 1e-4);
+
+        // Overlapping words find the matching document first
+        let query = embedder.embed("grpc load balancer").unwrap();
+        let results = index.search(&query, 3).unwrap();
+        assert_eq!(results[0].vector_id, 2);
+        assert!(results[0].score > results[1].score);
+    }
+
     #[test]
     fn test_save_and_load() {
         let temp = TempDir::new().unwrap();
@@ -327,6 +512,130 @@ mod tests {
         assert_eq!(index.len(), 5);
     }
 
+    #[test]
+    fn test_config_from_settings() {
+        let settings = VectorSettings {
+            m: 32,
+            ef_construction: 400,
+            ef_search: 64,
+        };
+        let config = HnswConfig::from_settings(384, "/tmp/hnsw", &settings).unwrap();
+        assert_eq!(config.dimension, 384);
+        assert_eq!(config.connectivity, 32);
+        assert_eq!(config.expansion_add, 400);
+        assert_eq!(config.expansion_search, 64);
+
+        let settings = VectorSettings {
+            m: 32,
+            ef_construction: 16,
+            ..Default::default()
+        };
+        assert!(HnswConfig::from_settings(384, "/tmp/hnsw", &settings).is_err());
+    }
+
+    #[test]
+    fn test_reopen_keeps_built_connectivity() {
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(16, temp.path())
+            .with_connectivity(16)
+            .with_capacity(10);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+        assert_eq!(index.connectivity(), 16);
+        index.add(1, &random_embedding(16)).unwrap();
+        index.save().unwrap();
+        drop(index);
+
+        let config = HnswConfig::new(16, temp.path()).with_connectivity(32);
+        let index = HnswIndex::open_or_create(config).unwrap();
+        assert_eq!(index.connectivity(), 16);
+        assert_eq!(index.len(), 1);
+    }
+
+    fn saved_index_with_metadata(count: u64) -> (TempDir, HnswIndex, VectorMetadata) {
+        use crate::metadata::{DocType, VectorEntry};
+
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(64, temp.path().join("hnsw")).with_capacity(100);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+        let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+        for i in 0..count {
+            index.add(i, &random_embedding(64)).unwrap();
+            metadata
+                .put(&VectorEntry::new(
+                    i,
+                    DocType::TocNode,
+                    format!("node-{i}"),
+                    0,
+                    "text",
+                ))
+                .unwrap();
+        }
+        index.save().unwrap();
+        (temp, index, metadata)
+    }
+
+    #[test]
+    fn test_verify_healthy_index() {
+        let (_temp, index, metadata) = saved_index_with_metadata(5);
+        let report = index.verify(&metadata).unwrap();
+        assert!(report.file_present);
+        assert_eq!(report.dimension, 64);
+        assert_eq!(report.vectors_present, 5);
+        assert_eq!(report.vectors_deleted, 0);
+        assert_eq!(
+            report.file_size,
+            std::fs::metadata(index.index_file()).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_truncated_file() {
+        let (_temp, index, metadata) = saved_index_with_metadata(5);
+        let file = index.index_file();
+        let len = std::fs::metadata(&file).unwrap().len();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_len(len - 16)
+            .unwrap();
+
+        // Verifying doesn't need the file to load
+        let config = HnswConfig::new(64, file.parent().unwrap());
+        assert!(HnswIndex::open_or_create(config.clone()).is_err());
+        let err = HnswIndex::verify_file(&config, &metadata).unwrap_err();
+        assert!(matches!(err, VectorError::Corrupted(_)));
+        assert!(err.to_string().contains("corrupted"), "{err}");
+    }
+
+    #[test]
+    fn test_verify_detects_corrupted_header() {
+        let (_temp, index, metadata) = saved_index_with_metadata(5);
+        let file = index.index_file();
+        let mut bytes = std::fs::read(&file).unwrap();
+        // Header magic sits right after the 8-byte matrix shape and vectors
+        let head = 8 + 5 * 64 * 4;
+        bytes[head..head + 7].copy_from_slice(b"garbage");
+        std::fs::write(&file, bytes).unwrap();
+
+        let err = index.verify(&metadata).unwrap_err();
+        assert!(matches!(err, VectorError::Corrupted(_)));
+        assert!(err.to_string().to_lowercase().contains("magic"), "{err}");
+    }
+
+    #[test]
+    fn test_verify_detects_metadata_count_mismatch() {
+        use crate::metadata::{DocType, VectorEntry};
+
+        let (_temp, index, metadata) = saved_index_with_metadata(5);
+        metadata
+            .put(&VectorEntry::new(99, DocType::Grip, "grip-99", 0, "text"))
+            .unwrap();
+
+        let err = index.verify(&metadata).unwrap_err();
+        assert!(err.to_string().contains("metadata has 6 entries"), "{err}");
+    }
+
     #[test]
     fn test_dimension_mismatch() {
         let temp = TempDir::new().unwrap();
@@ -369,6 +678,109 @@ mod tests {
         assert!(missing.is_none(), "Missing vector should return None");
     }
 
+    #[test]
+    fn test_warm_up() {
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(64, temp.path()).with_capacity(100);
+        let mut index = HnswIndex::open_or_create(config.clone()).unwrap();
+
+        // Empty index
+        index.warm_up().unwrap();
+
+        for i in 0..5 {
+            index.add(i, &random_embedding(64)).unwrap();
+        }
+        index.save().unwrap();
+
+        // Reopened from disk
+        let index = HnswIndex::open_or_create(config).unwrap();
+        index.warm_up().unwrap();
+        let results = index.search(&random_embedding(64), 3).unwrap();
+        assert_eq!(results.len(), 3);
+    }
+
+    /// Deterministic unit vectors so recall comparisons are reproducible.
+    fn seeded_embeddings(count: usize, dim: usize, seed: u64) -> Vec<Embedding> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                let values = (0..dim)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        (state % 10_000) as f32 / 10_000.0 - 0.5
+                    })
+                    .collect();
+                Embedding::new(values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search_with_ef_override() {
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(32, temp.path())
+            .with_connectivity(4)
+            .with_expansion(16, 64)
+            .with_capacity(2000);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+
+        let vectors = seeded_embeddings(2000, 32, 0x9E37_79B9_7F4A_7C15);
+        for (i, emb) in vectors.iter().enumerate() {
+            index.add(i as u64, emb).unwrap();
+        }
+
+        let k = 10;
+        let queries = seeded_embeddings(20, 32, 42);
+        let (mut low_hits, mut high_hits) = (0, 0);
+        for query in &queries {
+            // Exact neighbors by brute force
+            let mut exact: Vec<(u64, f32)> = vectors
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i as u64, query.cosine_similarity(v)))
+                .collect();
+            exact.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let truth: std::collections::HashSet<u64> =
+                exact.iter().take(k).map(|(id, _)| *id).collect();
+
+            let low = index.search_with_ef(query, k, Some(k)).unwrap();
+            let high = index.search_with_ef(query, k, Some(500)).unwrap();
+            assert!(low.len() <= k);
+            assert!(high.len() <= k);
+
+            low_hits += low.iter().filter(|r| truth.contains(&r.vector_id)).count();
+            high_hits += high.iter().filter(|r| truth.contains(&r.vector_id)).count();
+        }
+        assert!(
+            high_hits >= low_hits,
+            "high ef recall {} < low ef recall {}",
+            high_hits,
+            low_hits
+        );
+
+        // The index default is restored after an override
+        assert_eq!(index.index.read().unwrap().expansion_search(), 64);
+    }
+
+    #[test]
+    fn test_search_with_ef_below_k() {
+        let temp = TempDir::new().unwrap();
+        let config = HnswConfig::new(64, temp.path()).with_capacity(100);
+        let mut index = HnswIndex::open_or_create(config).unwrap();
+        index.add(1, &random_embedding(64)).unwrap();
+
+        let result = index.search_with_ef(&random_embedding(64), 10, Some(5));
+        assert!(matches!(
+            result,
+            Err(VectorError::InvalidEfSearch {
+                ef_search: 5,
+                k: 10
+            })
+        ));
+    }
+
     #[test]
     fn test_remove() {
         let temp = TempDir::new().unwrap();
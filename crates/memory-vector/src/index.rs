@@ -62,7 +62,19 @@ pub trait VectorIndex: Send + Sync {
 
     /// Search for k nearest neighbors.
     /// Returns results sorted by similarity (best first).
-    fn search(&self, query: &Embedding, k: usize) -> Result<Vec<SearchResult>, VectorError>;
+    fn search(&self, query: &Embedding, k: usize) -> Result<Vec<SearchResult>, VectorError> {
+        self.search_with_ef(query, k, None)
+    }
+
+    /// Search for k nearest neighbors with an optional search breadth
+    /// (`ef_search`) for this call only. Higher values trade latency for
+    /// recall; `None` uses the index default. Must be >= `k`.
+    fn search_with_ef(
+        &self,
+        query: &Embedding,
+        k: usize,
+        ef_search: Option<usize>,
+    ) -> Result<Vec<SearchResult>, VectorError>;
 
     /// Remove a vector by ID.
     fn remove(&mut self, id: u64) -> Result<bool, VectorError>;
@@ -24,7 +24,7 @@ pub mod metadata;
 pub mod pipeline;
 
 pub use error::VectorError;
-pub use hnsw::{HnswConfig, HnswIndex};
+pub use hnsw::{HnswConfig, HnswIndex, HnswVerifyReport};
 pub use index::{IndexStats, SearchResult, VectorIndex};
 pub use lifecycle::{is_protected_level, PruneStats, VectorLifecycleConfig};
 pub use metadata::{DocType, VectorEntry, VectorMetadata, CF_VECTOR_META};
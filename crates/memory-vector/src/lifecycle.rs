@@ -9,8 +9,9 @@
 //! - Year: NEVER pruned (stable anchor)
 
 use chrono::{DateTime, Duration, Utc};
+use memory_types::VectorLifecycleSettings;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Configuration for vector lifecycle per FR-08.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +78,37 @@ impl VectorLifecycleConfig {
             ..Default::default()
         }
     }
+
+    /// Build from the `[lifecycle.vector]` settings section.
+    ///
+    /// Entries in `retention` replace the matching `*_retention_days` field.
+    /// Protected levels can't be given a retention; unknown levels are rejected.
+    pub fn from_settings(settings: &VectorLifecycleSettings) -> Result<Self, String> {
+        let mut config = Self {
+            enabled: settings.enabled,
+            segment_retention_days: settings.segment_retention_days,
+            grip_retention_days: settings.grip_retention_days,
+            day_retention_days: settings.day_retention_days,
+            week_retention_days: settings.week_retention_days,
+        };
+        for (level, &days) in &settings.retention {
+            let slot = match level.as_str() {
+                "segment" => &mut config.segment_retention_days,
+                "grip" => &mut config.grip_retention_days,
+                "day" => &mut config.day_retention_days,
+                "week" => &mut config.week_retention_days,
+                other if is_protected_level(other) => {
+                    return Err(format!(
+                        "level '{}' is protected and never pruned; remove its retention",
+                        other
+                    ))
+                }
+                other => return Err(format!("unknown level '{}' in vector retention", other)),
+            };
+            *slot = days;
+        }
+        Ok(config)
+    }
 }
 
 /// Statistics from a prune operation.
@@ -86,6 +118,8 @@ pub struct PruneStats {
     pub grips_pruned: u32,
     pub days_pruned: u32,
     pub weeks_pruned: u32,
+    /// Vectors a dry run found eligible for pruning, by level (none removed)
+    pub would_remove: BTreeMap<String, u32>,
     pub errors: Vec<String>,
 }
 
@@ -104,12 +138,20 @@ impl PruneStats {
         }
     }
 
+    pub fn add_would_remove(&mut self, level: &str, count: u32) {
+        *self.would_remove.entry(level.to_string()).or_default() += count;
+    }
+
     pub fn total(&self) -> u32 {
         self.segments_pruned + self.grips_pruned + self.days_pruned + self.weeks_pruned
     }
 
+    pub fn total_would_remove(&self) -> u32 {
+        self.would_remove.values().sum()
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.total() == 0 && self.errors.is_empty()
+        self.total() == 0 && self.total_would_remove() == 0 && self.errors.is_empty()
     }
 
     pub fn has_errors(&self) -> bool {
@@ -170,6 +212,23 @@ mod tests {
         assert!(!is_protected_level("week"));
     }
 
+    #[test]
+    fn test_from_settings() {
+        let mut settings = VectorLifecycleSettings {
+            day_retention_days: 90,
+            ..Default::default()
+        };
+        settings.retention.insert("segment".to_string(), 7);
+        let config = VectorLifecycleConfig::from_settings(&settings).unwrap();
+        assert_eq!(config.segment_retention_days, 7);
+        assert_eq!(config.day_retention_days, 90);
+        assert_eq!(config.week_retention_days, 1825);
+
+        settings.retention.insert("month".to_string(), 365);
+        let err = VectorLifecycleConfig::from_settings(&settings).unwrap_err();
+        assert!(err.contains("protected"));
+    }
+
     #[test]
     fn test_prune_stats() {
         let mut stats = PruneStats::new();
@@ -183,6 +242,18 @@ mod tests {
         assert!(!stats.is_empty());
     }
 
+    #[test]
+    fn test_prune_stats_would_remove() {
+        let mut stats = PruneStats::new();
+        stats.add_would_remove("grip", 2);
+        stats.add_would_remove("grip", 1);
+        stats.add_would_remove("day", 4);
+        assert_eq!(stats.would_remove.get("grip"), Some(&3));
+        assert_eq!(stats.total_would_remove(), 7);
+        assert_eq!(stats.total(), 0);
+        assert!(!stats.is_empty());
+    }
+
     #[test]
     fn test_prune_stats_errors() {
         let mut stats = PruneStats::new();
@@ -105,6 +105,22 @@ impl VectorMetadata {
         Ok(Self { db })
     }
 
+    /// Open existing metadata storage without writing to it.
+    ///
+    /// Fails if the store doesn't exist.
+    pub fn open_read_only(path: impl AsRef<Path>) -> Result<Self, VectorError> {
+        let path = path.as_ref();
+
+        let mut opts = Options::default();
+        opts.create_if_missing(false);
+        opts.create_missing_column_families(false);
+
+        let db = DB::open_cf_for_read_only(&opts, path, [CF_VECTOR_META], false)?;
+
+        info!(path = ?path, "Opened read-only vector metadata storage");
+        Ok(Self { db })
+    }
+
     /// Get the column family handle
     fn cf(&self) -> &ColumnFamily {
         self.db
@@ -231,6 +247,21 @@ impl VectorMetadata {
             Ok(1) // Start from 1
         }
     }
+
+    /// Get the most recently assigned entry (highest vector ID).
+    pub fn latest(&self) -> Result<Option<VectorEntry>, VectorError> {
+        let mut iter = self.db.iterator_cf(self.cf(), rocksdb::IteratorMode::End);
+
+        match iter.next() {
+            Some(item) => {
+                let (_, value) = item?;
+                let entry: VectorEntry = serde_json::from_slice(&value)
+                    .map_err(|e| VectorError::Serialization(e.to_string()))?;
+                Ok(Some(entry))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -292,6 +323,21 @@ mod tests {
         assert_eq!(meta.next_vector_id().unwrap(), 43);
     }
 
+    #[test]
+    fn test_latest() {
+        let temp = TempDir::new().unwrap();
+        let meta = VectorMetadata::open(temp.path()).unwrap();
+
+        assert!(meta.latest().unwrap().is_none());
+
+        meta.put(&VectorEntry::new(7, DocType::TocNode, "toc:1", 0, "toc"))
+            .unwrap();
+        meta.put(&VectorEntry::new(3, DocType::Grip, "grip:1", 0, "grip"))
+            .unwrap();
+
+        assert_eq!(meta.latest().unwrap().unwrap().vector_id, 7);
+    }
+
     #[test]
     fn test_get_by_type() {
         let temp = TempDir::new().unwrap();
@@ -340,6 +386,28 @@ mod tests {
         assert_eq!(meta.count().unwrap(), 5);
     }
 
+    #[test]
+    fn test_open_read_only() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("meta");
+        assert!(VectorMetadata::open_read_only(&path).is_err());
+        assert!(!path.exists());
+
+        {
+            let meta = VectorMetadata::open(&path).unwrap();
+            meta.put(&VectorEntry::new(
+                1,
+                DocType::TocNode,
+                "toc:day:2024-01-01",
+                0,
+                "x",
+            ))
+            .unwrap();
+        }
+        let meta = VectorMetadata::open_read_only(&path).unwrap();
+        assert_eq!(meta.count().unwrap(), 1);
+    }
+
     #[test]
     fn test_text_preview_truncation() {
         let long_text = "x".repeat(500);
@@ -5,21 +5,27 @@
 //!
 //! Requirements: FR-09 (Outbox-driven indexing), FR-10 (Checkpoint-based recovery)
 
-use std::sync::{Arc, RwLock};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, RwLock};
 
 use chrono::Utc;
 use tracing::{debug, error, info, warn};
 
-use memory_embeddings::EmbeddingModel;
-use memory_types::TocNode;
+use memory_embeddings::{Embedding, EmbeddingModel};
+use memory_storage::Storage;
+use memory_types::{IndexCheckpoint, IndexType, TocNode};
 
 use crate::error::VectorError;
 use crate::hnsw::HnswIndex;
 use crate::index::VectorIndex;
 use crate::metadata::{DocType, VectorEntry, VectorMetadata};
 
-/// Checkpoint key for vector indexing
-pub const VECTOR_INDEX_CHECKPOINT: &str = "vector_index_last_processed";
+/// Checkpoint key for vector indexing.
+///
+/// Vector progress is stored as the same `IndexCheckpoint` record the
+/// indexing pipeline uses for `IndexType::Vector`.
+pub const VECTOR_INDEX_CHECKPOINT: &str = IndexType::Vector.checkpoint_key();
 
 /// Statistics from indexing run
 #[derive(Debug, Default, Clone)]
@@ -53,6 +59,10 @@ pub struct PipelineConfig {
     pub max_entries_per_run: usize,
     /// Whether to continue on individual entry errors
     pub continue_on_error: bool,
+    /// Worker threads embedding items while earlier items are inserted
+    /// (1 = embed and insert serially). Inserts stay in item order, so the
+    /// resulting index does not depend on this setting.
+    pub embed_concurrency: usize,
 }
 
 impl Default for PipelineConfig {
@@ -61,6 +71,7 @@ impl Default for PipelineConfig {
             batch_size: 32,
             max_entries_per_run: 1000,
             continue_on_error: true,
+            embed_concurrency: 1,
         }
     }
 }
@@ -151,6 +162,7 @@ pub struct VectorIndexPipeline<E: EmbeddingModel> {
     index: Arc<RwLock<HnswIndex>>,
     metadata: Arc<VectorMetadata>,
     config: PipelineConfig,
+    checkpoint_storage: Option<Arc<Storage>>,
 }
 
 impl<E: EmbeddingModel> VectorIndexPipeline<E> {
@@ -166,7 +178,109 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             index,
             metadata,
             config,
+            checkpoint_storage: None,
+        }
+    }
+
+    /// Persist progress of [`index_sequenced`](Self::index_sequenced) to
+    /// the shared `IndexCheckpoint` in storage.
+    pub fn with_checkpoint_storage(mut self, storage: Arc<Storage>) -> Self {
+        self.checkpoint_storage = Some(storage);
+        self
+    }
+
+    /// Load the persisted vector checkpoint, if any.
+    pub fn load_checkpoint(&self) -> Result<Option<IndexCheckpoint>, VectorError> {
+        let Some(storage) = &self.checkpoint_storage else {
+            return Ok(None);
+        };
+        match storage.get_checkpoint(VECTOR_INDEX_CHECKPOINT)? {
+            Some(bytes) => IndexCheckpoint::from_bytes(&bytes)
+                .map(Some)
+                .map_err(|e| VectorError::Serialization(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    fn save_checkpoint(&self, checkpoint: &IndexCheckpoint) -> Result<(), VectorError> {
+        if let Some(storage) = &self.checkpoint_storage {
+            let bytes = checkpoint
+                .to_bytes()
+                .map_err(|e| VectorError::Serialization(e.to_string()))?;
+            storage.put_checkpoint(VECTOR_INDEX_CHECKPOINT, &bytes)?;
         }
+        Ok(())
+    }
+
+    /// Index items tagged with their outbox sequence numbers, resuming
+    /// from the stored checkpoint.
+    ///
+    /// Items at or below the checkpoint's `last_sequence` are skipped.
+    /// After each batch the index is saved and the checkpoint advanced, so
+    /// an interrupted run picks up at the first unfinished batch. Without
+    /// checkpoint storage this behaves like [`index_items`](Self::index_items).
+    pub fn index_sequenced(
+        &self,
+        items: &[(u64, IndexableItem)],
+    ) -> Result<IndexingStats, VectorError> {
+        let mut checkpoint = self
+            .load_checkpoint()?
+            .unwrap_or_else(|| IndexCheckpoint::new(IndexType::Vector));
+        let pending: Vec<&(u64, IndexableItem)> = items
+            .iter()
+            .filter(|(seq, _)| *seq > checkpoint.last_sequence)
+            .collect();
+
+        let mut stats = IndexingStats::default();
+        if pending.is_empty() {
+            debug!(
+                last_sequence = checkpoint.last_sequence,
+                "No items past vector checkpoint"
+            );
+            return Ok(stats);
+        }
+
+        info!(
+            count = pending.len(),
+            from_sequence = checkpoint.last_sequence,
+            "Resuming vector indexing from checkpoint"
+        );
+
+        for batch in pending.chunks(self.config.batch_size) {
+            let batch_items: Vec<IndexableItem> =
+                batch.iter().map(|(_, item)| item.clone()).collect();
+            match self.process_batch(&batch_items) {
+                Ok(batch_stats) => stats.merge(&batch_stats),
+                Err(e) => {
+                    error!(error = %e, "Batch processing failed");
+                    if !self.config.continue_on_error {
+                        return Err(e);
+                    }
+                    stats.errors += batch.len();
+                }
+            }
+
+            // Save the index before advancing the checkpoint so a crash
+            // never records progress that is not on disk.
+            {
+                let index = self.index.read().map_err(|e| {
+                    VectorError::Index(format!("Failed to acquire read lock: {}", e))
+                })?;
+                index.save()?;
+            }
+            let last_seq = batch.iter().map(|(seq, _)| *seq).max().unwrap_or(0);
+            checkpoint.update(last_seq, batch.len() as u64);
+            self.save_checkpoint(&checkpoint)?;
+        }
+
+        info!(
+            processed = stats.entries_processed,
+            added = stats.vectors_added,
+            last_sequence = checkpoint.last_sequence,
+            "Vector indexing complete"
+        );
+
+        Ok(stats)
     }
 
     /// Index a batch of items.
@@ -220,6 +334,10 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
 
     /// Process a batch of items.
     fn process_batch(&self, items: &[IndexableItem]) -> Result<IndexingStats, VectorError> {
+        if self.config.embed_concurrency > 1 && items.len() > 1 {
+            return self.process_batch_concurrent(items, self.config.embed_concurrency);
+        }
+
         let mut stats = IndexingStats::default();
 
         for item in items {
@@ -242,16 +360,87 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
         Ok(stats)
     }
 
+    /// Process a batch with embedding spread over `workers` threads.
+    ///
+    /// Workers embed items in any order while this thread inserts finished
+    /// embeddings strictly in item order, so vector IDs match a serial run.
+    fn process_batch_concurrent(
+        &self,
+        items: &[IndexableItem],
+        workers: usize,
+    ) -> Result<IndexingStats, VectorError> {
+        let next = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::scope(|scope| {
+            for _ in 0..workers.min(items.len()) {
+                let tx = tx.clone();
+                let next = &next;
+                scope.spawn(move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    if tx.send((i, self.embed_item(item))).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(tx);
+
+            let mut stats = IndexingStats::default();
+            let mut ready = BTreeMap::new();
+            let mut next_insert = 0;
+
+            for (i, embedded) in rx {
+                ready.insert(i, embedded);
+                while let Some(embedded) = ready.remove(&next_insert) {
+                    let item = &items[next_insert];
+                    next_insert += 1;
+                    stats.entries_processed += 1;
+
+                    let outcome = embedded.and_then(|embedded| match embedded {
+                        Some((text, embedding)) => self.insert_item(item, &text, &embedding),
+                        None => Ok(false),
+                    });
+                    match outcome {
+                        Ok(true) => stats.vectors_added += 1,
+                        Ok(false) => stats.vectors_skipped += 1,
+                        Err(e) => {
+                            warn!(doc_id = %item.doc_id(), error = %e, "Failed to process item");
+                            if !self.config.continue_on_error {
+                                // Stop workers from picking up more items
+                                next.store(items.len(), Ordering::Relaxed);
+                                return Err(e);
+                            }
+                            stats.errors += 1;
+                        }
+                    }
+                }
+            }
+
+            Ok(stats)
+        })
+    }
+
     /// Process a single item.
     ///
     /// Returns true if vector was added, false if skipped.
     fn process_item(&self, item: &IndexableItem) -> Result<bool, VectorError> {
+        match self.embed_item(item)? {
+            Some((text, embedding)) => self.insert_item(item, &text, &embedding),
+            None => Ok(false),
+        }
+    }
+
+    /// Embed an item's text, or None if it is already indexed or empty.
+    fn embed_item(&self, item: &IndexableItem) -> Result<Option<(String, Embedding)>, VectorError> {
         let doc_id = item.doc_id();
 
         // Skip if already indexed
         if self.metadata.find_by_doc_id(doc_id)?.is_some() {
             debug!(doc_id = %doc_id, "Already indexed, skipping");
-            return Ok(false);
+            return Ok(None);
         }
 
         // Get text to embed
@@ -260,11 +449,29 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
         // Skip empty text
         if text.trim().is_empty() {
             debug!(doc_id = %doc_id, "Empty text, skipping");
-            return Ok(false);
+            return Ok(None);
         }
 
         // Generate embedding
         let embedding = self.embedder.embed(&text)?;
+        Ok(Some((text, embedding)))
+    }
+
+    /// Add an embedded item to the index and metadata.
+    ///
+    /// Returns false if the doc_id was indexed since the item was embedded
+    /// (e.g. a duplicate earlier in the same batch).
+    fn insert_item(
+        &self,
+        item: &IndexableItem,
+        text: &str,
+        embedding: &Embedding,
+    ) -> Result<bool, VectorError> {
+        let doc_id = item.doc_id();
+        if self.metadata.find_by_doc_id(doc_id)?.is_some() {
+            debug!(doc_id = %doc_id, "Already indexed, skipping");
+            return Ok(false);
+        }
 
         // Get next vector ID
         let vector_id = self.metadata.next_vector_id()?;
@@ -275,7 +482,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
                 .index
                 .write()
                 .map_err(|e| VectorError::Index(format!("Failed to acquire write lock: {}", e)))?;
-            index.add(vector_id, &embedding)?;
+            index.add(vector_id, embedding)?;
         }
 
         // Store metadata
@@ -284,7 +491,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             item.doc_type(),
             doc_id.to_string(),
             item.created_at(),
-            &text,
+            text,
         );
         self.metadata.put(&meta_entry)?;
 
@@ -316,6 +523,68 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
         self.process_item(&item)
     }
 
+    /// Insert or replace the vector for an item's doc_id.
+    ///
+    /// Unlike [`index_items`](Self::index_items), which skips documents that
+    /// are already indexed, this re-embeds the item and swaps out any existing
+    /// vectors for the same doc_id so exactly one remains. The new vector is
+    /// added before the old ones are tombstoned, so a failed embed or insert
+    /// leaves the previous vector in place.
+    ///
+    /// Returns true if a vector was written, false if the text was empty.
+    pub fn upsert(&self, item: IndexableItem) -> Result<bool, VectorError> {
+        let doc_id = item.doc_id();
+        let text = item.text();
+
+        if text.trim().is_empty() {
+            debug!(doc_id = %doc_id, "Empty text, skipping upsert");
+            return Ok(false);
+        }
+
+        let embedding = self.embedder.embed(&text)?;
+
+        let existing: Vec<VectorEntry> = self
+            .metadata
+            .get_all()?
+            .into_iter()
+            .filter(|entry| entry.doc_id == doc_id)
+            .collect();
+        let vector_id = self.metadata.next_vector_id()?;
+
+        // Swap vectors under a single write lock so searches never see the
+        // document twice or not at all.
+        {
+            let mut index = self
+                .index
+                .write()
+                .map_err(|e| VectorError::Index(format!("Failed to acquire write lock: {}", e)))?;
+            index.add(vector_id, &embedding)?;
+            for entry in &existing {
+                index.remove(entry.vector_id)?;
+            }
+        }
+
+        let meta_entry = VectorEntry::new(
+            vector_id,
+            item.doc_type(),
+            doc_id.to_string(),
+            item.created_at(),
+            &text,
+        );
+        self.metadata.put(&meta_entry)?;
+        for entry in &existing {
+            self.metadata.delete(entry.vector_id)?;
+        }
+
+        debug!(
+            vector_id = vector_id,
+            doc_id = %doc_id,
+            replaced = existing.len(),
+            "Upserted vector"
+        );
+        Ok(true)
+    }
+
     /// Rebuild entire vector index from scratch.
     ///
     /// Clears existing index and re-indexes all provided items.
@@ -343,7 +612,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
     /// Removes vectors older than age_days from the HNSW index.
     /// Does NOT delete primary data (TOC nodes, grips remain in RocksDB).
     pub fn prune(&self, age_days: u64) -> Result<usize, VectorError> {
-        self.prune_level(age_days, None)
+        self.prune_level(age_days, None, false)
     }
 
     /// Prune old vectors based on age with optional level filter.
@@ -354,11 +623,15 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
     /// - TOC nodes: "toc:{level}:{date}" -> extract level
     /// - Grips: doc_type == Grip -> "grip" level
     ///
+    /// With `dry_run`, nothing is removed and the count of vectors that
+    /// would be pruned is returned instead.
+    ///
     /// Does NOT delete primary data (TOC nodes, grips remain in RocksDB).
     pub fn prune_level(
         &self,
         age_days: u64,
         level_filter: Option<&str>,
+        dry_run: bool,
     ) -> Result<usize, VectorError> {
         let cutoff_ms = Utc::now().timestamp_millis() - (age_days as i64 * 24 * 60 * 60 * 1000);
 
@@ -366,6 +639,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             age_days = age_days,
             cutoff_ms = cutoff_ms,
             level = ?level_filter,
+            dry_run = dry_run,
             "Pruning old vectors"
         );
 
@@ -384,6 +658,11 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             }
 
             if entry.created_at < cutoff_ms {
+                if dry_run {
+                    pruned += 1;
+                    continue;
+                }
+
                 // Remove from HNSW index
                 {
                     let mut index = self.index.write().map_err(|e| {
@@ -404,7 +683,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             }
         }
 
-        if pruned > 0 {
+        if pruned > 0 && !dry_run {
             let index = self
                 .index
                 .read()
@@ -412,10 +691,54 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
             index.save()?;
         }
 
-        info!(pruned = pruned, level = ?level_filter, "Prune complete");
+        info!(pruned = pruned, level = ?level_filter, dry_run = dry_run, "Prune complete");
         Ok(pruned)
     }
 
+    /// Remove the vectors of a purged session.
+    ///
+    /// Vector metadata does not record session IDs, so `doc_ids` must list
+    /// the session's grip IDs (see `PurgeReport::doc_ids` in memory-storage);
+    /// IDs without a vector are ignored. Returns the number of vectors removed.
+    pub fn delete_by_session(
+        &self,
+        session_id: &str,
+        doc_ids: &[String],
+    ) -> Result<usize, VectorError> {
+        let doc_ids: HashSet<&str> = doc_ids.iter().map(String::as_str).collect();
+        let entries: Vec<VectorEntry> = self
+            .metadata
+            .get_all()?
+            .into_iter()
+            .filter(|entry| doc_ids.contains(entry.doc_id.as_str()))
+            .collect();
+
+        if entries.is_empty() {
+            return Ok(0);
+        }
+
+        {
+            let mut index = self
+                .index
+                .write()
+                .map_err(|e| VectorError::Index(format!("Failed to acquire write lock: {}", e)))?;
+            for entry in &entries {
+                index.remove(entry.vector_id)?;
+            }
+            index.save()?;
+        }
+        for entry in &entries {
+            self.metadata.delete(entry.vector_id)?;
+        }
+
+        info!(
+            session_id = %session_id,
+            removed = entries.len(),
+            "Deleted session vectors"
+        );
+        Ok(entries.len())
+    }
+
     /// Extract level from a vector entry.
     ///
     /// For TOC nodes: parses "toc:{level}:{date}" to get level.
@@ -449,7 +772,7 @@ impl<E: EmbeddingModel> VectorIndexPipeline<E> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use memory_embeddings::{Embedding, EmbeddingError};
+    use memory_embeddings::EmbeddingError;
 
     // Mock embedder for testing
     struct MockEmbedder {
@@ -477,6 +800,166 @@ mod tests {
         }
     }
 
+    /// Embeds text by byte histogram so different text gives different vectors.
+    struct TextEmbedder;
+
+    impl EmbeddingModel for TextEmbedder {
+        fn info(&self) -> &memory_embeddings::ModelInfo {
+            unimplemented!("info() not needed for tests")
+        }
+
+        fn embed(&self, text: &str) -> Result<Embedding, EmbeddingError> {
+            let mut values = vec![0.0f32; 16];
+            for byte in text.bytes() {
+                values[byte as usize % 16] += 1.0;
+            }
+            Ok(Embedding::new(values))
+        }
+    }
+
+    #[test]
+    fn test_upsert_replaces_existing_vector() {
+        use crate::hnsw::HnswConfig;
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let index = HnswIndex::open_or_create(
+            HnswConfig::new(16, temp.path().join("hnsw")).with_capacity(100),
+        )
+        .unwrap();
+        let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+        let embedder = Arc::new(TextEmbedder);
+        let pipeline = VectorIndexPipeline::new(
+            embedder.clone(),
+            Arc::new(RwLock::new(index)),
+            Arc::new(metadata),
+            PipelineConfig::default(),
+        );
+
+        let grip = |excerpt: &str| IndexableItem::Grip {
+            grip_id: "grip:1".to_string(),
+            excerpt: excerpt.to_string(),
+            created_at: 1705320000000,
+        };
+
+        assert!(pipeline.upsert(grip("aaaa original")).unwrap());
+        assert!(pipeline.upsert(grip("zzzz updated text")).unwrap());
+
+        let query = embedder.embed("zzzz updated text").unwrap();
+        let results = pipeline.index.read().unwrap().search(&query, 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].score > 0.99);
+
+        let entry = pipeline
+            .metadata
+            .get(results[0].vector_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry.doc_id, "grip:1");
+        assert_eq!(entry.text_preview, "zzzz updated text");
+        assert_eq!(pipeline.metadata.count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_index_sequenced_resumes_from_checkpoint() {
+        use crate::hnsw::HnswConfig;
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let storage = Arc::new(Storage::open(&temp.path().join("db")).unwrap());
+        let open_pipeline = || {
+            let index = HnswIndex::open_or_create(
+                HnswConfig::new(16, temp.path().join("hnsw")).with_capacity(100),
+            )
+            .unwrap();
+            let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+            VectorIndexPipeline::new(
+                Arc::new(TextEmbedder),
+                Arc::new(RwLock::new(index)),
+                Arc::new(metadata),
+                PipelineConfig {
+                    batch_size: 2,
+                    ..Default::default()
+                },
+            )
+            .with_checkpoint_storage(storage.clone())
+        };
+        let items: Vec<(u64, IndexableItem)> = (1..=4)
+            .map(|seq| {
+                (
+                    seq,
+                    IndexableItem::Grip {
+                        grip_id: format!("grip:{}", seq),
+                        excerpt: format!("excerpt number {}", seq),
+                        created_at: 1705320000000,
+                    },
+                )
+            })
+            .collect();
+
+        // First run is interrupted after the first two items
+        {
+            let pipeline = open_pipeline();
+            let stats = pipeline.index_sequenced(&items[..2]).unwrap();
+            assert_eq!(stats.vectors_added, 2);
+        }
+
+        // Restart: the checkpoint is reloaded and only new items are processed
+        let pipeline = open_pipeline();
+        let checkpoint = pipeline.load_checkpoint().unwrap().unwrap();
+        assert_eq!(checkpoint.index_type, IndexType::Vector);
+        assert_eq!(checkpoint.last_sequence, 2);
+
+        let stats = pipeline.index_sequenced(&items).unwrap();
+        assert_eq!(stats.entries_processed, 2);
+        assert_eq!(stats.vectors_added, 2);
+
+        let checkpoint = pipeline.load_checkpoint().unwrap().unwrap();
+        assert_eq!(checkpoint.last_sequence, 4);
+        assert_eq!(checkpoint.processed_count, 4);
+        assert_eq!(pipeline.metadata.count().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_delete_by_session() {
+        use crate::hnsw::HnswConfig;
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let index = HnswIndex::open_or_create(
+            HnswConfig::new(16, temp.path().join("hnsw")).with_capacity(100),
+        )
+        .unwrap();
+        let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+        let pipeline = VectorIndexPipeline::new(
+            Arc::new(TextEmbedder),
+            Arc::new(RwLock::new(index)),
+            Arc::new(metadata),
+            PipelineConfig::default(),
+        );
+
+        for (grip_id, excerpt) in [("grip:a", "aaaa"), ("grip:b", "bbbb")] {
+            let item = IndexableItem::Grip {
+                grip_id: grip_id.to_string(),
+                excerpt: excerpt.to_string(),
+                created_at: 1705320000000,
+            };
+            assert!(pipeline.upsert(item).unwrap());
+        }
+
+        let purged = vec!["grip:a".to_string(), "event-without-vector".to_string()];
+        assert_eq!(pipeline.delete_by_session("session-a", &purged).unwrap(), 1);
+
+        let remaining = pipeline.metadata.get_all().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].doc_id, "grip:b");
+
+        let query = TextEmbedder.embed("aaaa").unwrap();
+        let results = pipeline.index.read().unwrap().search(&query, 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].vector_id, remaining[0].vector_id);
+    }
+
     #[test]
     fn test_extract_node_text() {
         use memory_types::{TocBullet, TocLevel};
@@ -561,11 +1044,114 @@ mod tests {
         assert_eq!(stats1.errors, 1);
     }
 
+    #[test]
+    fn test_prune_level_dry_run_matches_prune() {
+        use crate::hnsw::HnswConfig;
+        use chrono::TimeZone;
+        use memory_types::TocLevel;
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let index = HnswIndex::open_or_create(
+            HnswConfig::new(16, temp.path().join("hnsw")).with_capacity(100),
+        )
+        .unwrap();
+        let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+        let pipeline = VectorIndexPipeline::new(
+            Arc::new(TextEmbedder),
+            Arc::new(RwLock::new(index)),
+            Arc::new(metadata),
+            PipelineConfig::default(),
+        );
+
+        let old = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let grip = |id: &str, created_at: i64| IndexableItem::Grip {
+            grip_id: id.to_string(),
+            excerpt: format!("excerpt for {}", id),
+            created_at,
+        };
+        let day = |date: &str| {
+            let node_id = format!("toc:day:{}", date);
+            let mut node = TocNode::new(node_id.clone(), TocLevel::Day, date.to_string(), old, old);
+            node.created_at = old;
+            IndexableItem::TocNode { node_id, node }
+        };
+        let items = vec![
+            grip("grip:old1", old.timestamp_millis()),
+            grip("grip:old2", old.timestamp_millis()),
+            grip("grip:new", Utc::now().timestamp_millis()),
+            day("2024-01-15"),
+        ];
+        assert_eq!(pipeline.index_items(&items).unwrap().vectors_added, 4);
+
+        let levels = ["segment", "grip", "day", "week"];
+        let would_remove: Vec<usize> = levels
+            .iter()
+            .map(|level| pipeline.prune_level(30, Some(level), true).unwrap())
+            .collect();
+        assert_eq!(would_remove, vec![0, 2, 1, 0]);
+        assert_eq!(pipeline.metadata.count().unwrap(), 4);
+
+        let removed: Vec<usize> = levels
+            .iter()
+            .map(|level| pipeline.prune_level(30, Some(level), false).unwrap())
+            .collect();
+        assert_eq!(removed, would_remove);
+        assert_eq!(pipeline.metadata.count().unwrap(), 1);
+    }
+
     #[test]
     fn test_pipeline_config_default() {
         let config = PipelineConfig::default();
         assert_eq!(config.batch_size, 32);
         assert_eq!(config.max_entries_per_run, 1000);
         assert!(config.continue_on_error);
+        assert_eq!(config.embed_concurrency, 1);
+    }
+
+    #[test]
+    fn test_concurrent_rebuild_matches_serial() {
+        use crate::hnsw::HnswConfig;
+        use tempfile::TempDir;
+
+        let items: Vec<IndexableItem> = (0..50)
+            .map(|i| IndexableItem::Grip {
+                grip_id: format!("grip:{i}"),
+                excerpt: format!("excerpt {} {}", i, "x".repeat(i % 7)),
+                created_at: 1705320000000 + i as i64,
+            })
+            .collect();
+
+        let rebuild = |embed_concurrency: usize| {
+            let temp = TempDir::new().unwrap();
+            let index = HnswIndex::open_or_create(
+                HnswConfig::new(16, temp.path().join("hnsw")).with_capacity(100),
+            )
+            .unwrap();
+            let metadata = VectorMetadata::open(temp.path().join("meta")).unwrap();
+            let pipeline = VectorIndexPipeline::new(
+                Arc::new(TextEmbedder),
+                Arc::new(RwLock::new(index)),
+                Arc::new(metadata),
+                PipelineConfig {
+                    batch_size: 16,
+                    embed_concurrency,
+                    ..PipelineConfig::default()
+                },
+            );
+            let stats = pipeline.rebuild(&items).unwrap();
+            assert_eq!(stats.vectors_added, items.len());
+
+            let entries: Vec<(u64, String, String)> = pipeline
+                .metadata
+                .get_all()
+                .unwrap()
+                .into_iter()
+                .map(|e| (e.vector_id, e.doc_id, e.text_preview))
+                .collect();
+            (entries, pipeline.stats().unwrap().vector_count)
+        };
+
+        assert_eq!(rebuild(4), rebuild(1));
     }
 }